@@ -69,6 +69,7 @@ mv --verbose --force \
     "target/${TARGET_MACOS}/release/zargo" \
     "target/${TARGET_MACOS}/release/znc" \
     "target/${TARGET_MACOS}/release/zvm" \
+    "target/${TARGET_MACOS}/release/zls" \
     "zinc-${VERSION_ZINC}-macos"
 zip --verbose -r \
     "zinc-release-${VERSION_ZINC}/zinc-${VERSION_ZINC}-macos.zip" \
@@ -233,7 +233,7 @@ impl fmt::Display for Error {
             Self::AccountIdNotFound => "Could not get the contract account ID".to_owned(),
             Self::ChangePubkey(inner) => format!("Changing the contract public key: {}", inner),
 
-            Self::VirtualMachine(inner) => format!("Runtime: {:?}", inner),
+            Self::VirtualMachine(inner) => format!("Runtime: {}", inner),
             Self::Database(inner) => match inner {
                 DatabaseError::NotFound { entity } => format!("{} not found", entity),
                 DatabaseError::AlreadyExists { entity } => format!("{} already exists", entity),
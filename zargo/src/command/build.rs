@@ -10,7 +10,7 @@ use structopt::StructOpt;
 
 use crate::error::Error;
 use crate::executable::compiler::Compiler;
-use crate::http::downloader::Downloader;
+use crate::http::resolver::Resolver;
 use crate::http::Client as HttpClient;
 use crate::network::Network;
 use crate::project::data::private_key::PrivateKey as PrivateKeyFile;
@@ -47,6 +47,10 @@ pub struct Command {
     /// Sets the network name, where the contract must be published to.
     #[structopt(long = "network", default_value = "localhost")]
     pub network: String,
+
+    /// Sets the compiler diagnostic messages format, either `human` or `json`.
+    #[structopt(long = "message-format")]
+    pub message_format: Option<String>,
 }
 
 impl Command {
@@ -59,6 +63,7 @@ impl Command {
         manifest_path: PathBuf,
         is_release: bool,
         network: Option<String>,
+        message_format: Option<String>,
     ) -> Self {
         Self {
             verbosity,
@@ -67,6 +72,7 @@ impl Command {
             is_release,
             network: network
                 .unwrap_or_else(|| Network::from(zksync::Network::Localhost).to_string()),
+            message_format,
         }
     }
 
@@ -101,8 +107,9 @@ impl Command {
                 .try_into_url()
                 .map_err(Error::NetworkUnimplemented)?;
             let http_client = HttpClient::new(url);
-            let mut downloader = Downloader::new(&http_client, &manifest_path);
-            downloader.download_dependency_list(dependencies).await?;
+            Resolver::new(&http_client, &manifest_path)?
+                .resolve(manifest.project.name.as_str(), dependencies)
+                .await?;
         }
 
         if self.is_release {
@@ -113,6 +120,7 @@ impl Command {
                 &manifest.project.version,
                 &manifest_path,
                 false,
+                self.message_format.as_deref(),
             )?;
         } else {
             Compiler::build_debug(
@@ -122,6 +130,7 @@ impl Command {
                 &manifest.project.version,
                 &manifest_path,
                 false,
+                self.message_format.as_deref(),
             )?;
         }
 
@@ -2,6 +2,7 @@
 //! The Zargo package manager `download` subcommand.
 //!
 
+use std::convert::TryFrom;
 use std::path::PathBuf;
 use std::str::FromStr;
 
@@ -9,6 +10,7 @@ use structopt::StructOpt;
 
 use crate::error::Error;
 use crate::http::downloader::Downloader;
+use crate::http::resolver::Resolver;
 use crate::http::Client as HttpClient;
 use crate::network::Network;
 
@@ -104,6 +106,13 @@ impl Command {
         let mut downloader = Downloader::new(&http_client, &project_path);
         downloader.download_project(name, version).await?;
 
+        let manifest = zinc_project::Manifest::try_from(&project_path)?;
+        if let Some(dependencies) = manifest.dependencies {
+            Resolver::new(&http_client, &project_path)?
+                .resolve(manifest.project.name.as_str(), dependencies)
+                .await?;
+        }
+
         Ok(())
     }
 }
@@ -0,0 +1,104 @@
+//!
+//! The Zargo package manager `fmt` subcommand.
+//!
+
+use std::convert::TryFrom;
+use std::fs;
+use std::path::PathBuf;
+
+use anyhow::Context;
+use colored::Colorize;
+use structopt::StructOpt;
+
+use crate::error::Error;
+use crate::project::src::Directory as SourceDirectory;
+
+///
+/// The Zargo package manager `fmt` subcommand.
+///
+#[derive(Debug, StructOpt)]
+#[structopt(about = "Formats the project source code")]
+pub struct Command {
+    /// Prints more logs, if passed several times.
+    #[structopt(short = "v", long = "verbose", parse(from_occurrences))]
+    pub verbosity: usize,
+
+    /// Suppresses output, if set.
+    #[structopt(short = "q", long = "quiet")]
+    pub quiet: bool,
+
+    /// The path to the Zinc project manifest file.
+    #[structopt(
+        long = "manifest-path",
+        parse(from_os_str),
+        default_value = "./Zargo.toml"
+    )]
+    pub manifest_path: PathBuf,
+
+    /// Lists the unformatted files and fails instead of rewriting them, if set.
+    #[structopt(long = "check")]
+    pub is_check: bool,
+}
+
+impl Command {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(verbosity: usize, quiet: bool, manifest_path: PathBuf, is_check: bool) -> Self {
+        Self {
+            verbosity,
+            quiet,
+            manifest_path,
+            is_check,
+        }
+    }
+
+    ///
+    /// Executes the command.
+    ///
+    pub fn execute(self) -> anyhow::Result<()> {
+        let _manifest = zinc_project::Manifest::try_from(&self.manifest_path)?;
+
+        let mut manifest_path = self.manifest_path;
+        if manifest_path.is_file() {
+            manifest_path.pop();
+        }
+
+        let mut unformatted = 0;
+        for path in SourceDirectory::files(&manifest_path)?.into_iter() {
+            let code =
+                fs::read_to_string(&path).with_context(|| path.to_string_lossy().to_string())?;
+            let formatted = zinc_formatter::format(code.as_str())
+                .with_context(|| path.to_string_lossy().to_string())?;
+            if formatted == code {
+                continue;
+            }
+
+            let relative_path = path.strip_prefix(&manifest_path).unwrap_or(&path);
+            if self.is_check {
+                unformatted += 1;
+                eprintln!(
+                    "  {} {}",
+                    "Unformatted".bright_red(),
+                    relative_path.to_string_lossy()
+                );
+                continue;
+            }
+
+            fs::write(&path, formatted).with_context(|| path.to_string_lossy().to_string())?;
+            if !self.quiet {
+                eprintln!(
+                    "   {} {}",
+                    "Formatted".bright_green(),
+                    relative_path.to_string_lossy()
+                );
+            }
+        }
+
+        if unformatted > 0 {
+            anyhow::bail!(Error::SourceCodeUnformatted(unformatted));
+        }
+
+        Ok(())
+    }
+}
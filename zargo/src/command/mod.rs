@@ -5,9 +5,12 @@
 pub mod build;
 pub mod call;
 pub mod clean;
+pub mod debug;
 pub mod download;
+pub mod fmt;
 pub mod init;
 pub mod new;
+pub mod profile;
 pub mod proof_check;
 pub mod prove;
 pub mod publish;
@@ -20,14 +23,15 @@ pub mod verify;
 
 use structopt::StructOpt;
 
-use crate::error::Error;
-
 use self::build::Command as BuildCommand;
 use self::call::Command as CallCommand;
 use self::clean::Command as CleanCommand;
+use self::debug::Command as DebugCommand;
 use self::download::Command as DownloadCommand;
+use self::fmt::Command as FmtCommand;
 use self::init::Command as InitCommand;
 use self::new::Command as NewCommand;
+use self::profile::Command as ProfileCommand;
 use self::proof_check::Command as ProofCheckCommand;
 use self::prove::Command as ProveCommand;
 use self::publish::Command as PublishCommand;
@@ -50,6 +54,8 @@ pub enum Command {
     Init(InitCommand),
     /// Removes the project build artifacts.
     Clean(CleanCommand),
+    /// Formats the project source code.
+    Fmt(FmtCommand),
 
     /// Builds the project at the given path.
     Build(BuildCommand),
@@ -57,6 +63,10 @@ pub enum Command {
     Run(RunCommand),
     /// Runs the project unit tests.
     Test(TestCommand),
+    /// Profiles the project constraints.
+    Profile(ProfileCommand),
+    /// Runs the project in the source-level step debugger.
+    Debug(DebugCommand),
 
     /// Generates a pair of proving and verifying keys.
     Setup(SetupCommand),
@@ -89,15 +99,18 @@ impl Command {
             Self::New(inner) => inner.execute()?,
             Self::Init(inner) => inner.execute()?,
             Self::Clean(inner) => inner.execute()?,
+            Self::Fmt(inner) => inner.execute()?,
 
             Self::Build(inner) => inner.execute().await?,
             Self::Run(inner) => inner.execute().await?,
             Self::Test(inner) => inner.execute().await?,
+            Self::Profile(inner) => inner.execute().await?,
+            Self::Debug(inner) => inner.execute().await?,
 
             Self::Setup(inner) => inner.execute()?,
-            Self::Prove(_inner) => anyhow::bail!(Error::ProofVerificationUnavailable),
-            Self::Verify(_inner) => anyhow::bail!(Error::ProofVerificationUnavailable),
-            Self::ProofCheck(_inner) => anyhow::bail!(Error::ProofVerificationUnavailable),
+            Self::Prove(inner) => inner.execute()?,
+            Self::Verify(inner) => inner.execute()?,
+            Self::ProofCheck(inner) => inner.execute()?,
 
             Self::Publish(inner) => {
                 inner.execute().await?;
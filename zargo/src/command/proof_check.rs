@@ -127,6 +127,7 @@ impl Command {
                 &manifest.project.version,
                 &manifest_path,
                 false,
+                None,
             )?;
         } else {
             Compiler::build_debug(
@@ -136,6 +137,7 @@ impl Command {
                 &manifest.project.version,
                 &manifest_path,
                 false,
+                None,
             )?;
         }
 
@@ -16,7 +16,7 @@ use zksync_types::tx::PackedEthSignature;
 use crate::error::Error;
 use crate::executable::compiler::Compiler;
 use crate::executable::virtual_machine::VirtualMachine;
-use crate::http::downloader::Downloader;
+use crate::http::resolver::Resolver;
 use crate::http::Client as HttpClient;
 use crate::network::Network;
 use crate::project::data::input::Input as InputFile;
@@ -175,8 +175,9 @@ impl Command {
                 .try_into_url()
                 .map_err(Error::NetworkUnimplemented)?;
             let http_client = HttpClient::new(url);
-            let mut downloader = Downloader::new(&http_client, &manifest_path);
-            downloader.download_dependency_list(dependencies).await?;
+            Resolver::new(&http_client, &manifest_path)?
+                .resolve(manifest.project.name.as_str(), dependencies)
+                .await?;
         }
 
         Compiler::build_release(
@@ -186,6 +187,7 @@ impl Command {
             &manifest.project.version,
             &manifest_path,
             false,
+            None,
         )?;
 
         let bytecode = BytecodeFile::try_from_path(&binary_path, true)?;
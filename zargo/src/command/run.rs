@@ -11,7 +11,7 @@ use structopt::StructOpt;
 use crate::error::Error;
 use crate::executable::compiler::Compiler;
 use crate::executable::virtual_machine::VirtualMachine;
-use crate::http::downloader::Downloader;
+use crate::http::resolver::Resolver;
 use crate::http::Client as HttpClient;
 use crate::network::Network;
 use crate::project::data::private_key::PrivateKey as PrivateKeyFile;
@@ -133,8 +133,9 @@ impl Command {
                 .try_into_url()
                 .map_err(Error::NetworkUnimplemented)?;
             let http_client = HttpClient::new(url);
-            let mut downloader = Downloader::new(&http_client, &manifest_path);
-            downloader.download_dependency_list(dependencies).await?;
+            Resolver::new(&http_client, &manifest_path)?
+                .resolve(manifest.project.name.as_str(), dependencies)
+                .await?;
         }
 
         if self.is_release {
@@ -145,6 +146,7 @@ impl Command {
                 &manifest.project.version,
                 &manifest_path,
                 false,
+                None,
             )?;
         } else {
             Compiler::build_debug(
@@ -154,6 +156,7 @@ impl Command {
                 &manifest.project.version,
                 &manifest_path,
                 false,
+                None,
             )?;
         }
 
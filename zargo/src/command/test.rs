@@ -11,7 +11,7 @@ use structopt::StructOpt;
 use crate::error::Error;
 use crate::executable::compiler::Compiler;
 use crate::executable::virtual_machine::VirtualMachine;
-use crate::http::downloader::Downloader;
+use crate::http::resolver::Resolver;
 use crate::http::Client as HttpClient;
 use crate::network::Network;
 use crate::project::target::deps::Directory as TargetDependenciesDirectory;
@@ -93,8 +93,9 @@ impl Command {
                 .try_into_url()
                 .map_err(Error::NetworkUnimplemented)?;
             let http_client = HttpClient::new(url);
-            let mut downloader = Downloader::new(&http_client, &manifest_path);
-            downloader.download_dependency_list(dependencies).await?;
+            Resolver::new(&http_client, &manifest_path)?
+                .resolve(manifest.project.name.as_str(), dependencies)
+                .await?;
         }
 
         Compiler::build_release(
@@ -104,6 +105,7 @@ impl Command {
             &manifest.project.version,
             &manifest_path,
             true,
+            None,
         )?;
 
         VirtualMachine::test(self.verbosity, self.quiet, &binary_path)?;
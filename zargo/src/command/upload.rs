@@ -12,7 +12,7 @@ use structopt::StructOpt;
 use crate::error::Error;
 use crate::executable::compiler::Compiler;
 use crate::executable::virtual_machine::VirtualMachine;
-use crate::http::downloader::Downloader;
+use crate::http::resolver::Resolver;
 use crate::http::Client as HttpClient;
 use crate::network::Network;
 use crate::project::data::verifying_key::VerifyingKey as VerifyingKeyFile;
@@ -82,6 +82,14 @@ impl Command {
 
         let manifest = zinc_project::Manifest::try_from(&self.manifest_path)?;
 
+        if let Some(ref dependencies) = manifest.dependencies {
+            for (name, dependency) in dependencies.iter() {
+                if let zinc_project::ManifestDependency::Path { .. } = dependency {
+                    anyhow::bail!(Error::PathDependencyNotUploadable(name.to_owned()));
+                }
+            }
+        }
+
         let mut manifest_path = self.manifest_path;
         if manifest_path.is_file() {
             manifest_path.pop();
@@ -124,8 +132,9 @@ impl Command {
                 .try_into_url()
                 .map_err(Error::NetworkUnimplemented)?;
             let http_client = HttpClient::new(url);
-            let mut downloader = Downloader::new(&http_client, &manifest_path);
-            downloader.download_dependency_list(dependencies).await?;
+            Resolver::new(&http_client, &manifest_path)?
+                .resolve(manifest.project.name.as_str(), dependencies)
+                .await?;
         }
 
         Compiler::build_release(
@@ -135,6 +144,7 @@ impl Command {
             &manifest.project.version,
             &manifest_path,
             false,
+            None,
         )?;
 
         let bytecode = BytecodeFile::try_from_path(&binary_path, true)?;
@@ -101,7 +101,27 @@ pub enum Error {
     #[error("project {0}: compiler version mismatch: expected {1}, found {2}")]
     CompilerVersionMismatch(String, String, String),
 
-    /// The command is temporarily unavailable.
-    #[error("the proof verification is temporarily unavailable")]
-    ProofVerificationUnavailable,
+    /// No dependency version satisfies the requirements.
+    #[error("dependency `{0}`: no version matching {1} found")]
+    DependencyVersionNotFound(String, String),
+
+    /// The dependency requirements cannot be satisfied by a single version.
+    #[error("dependency `{0}`: conflicting version requirements {1}")]
+    DependencyVersionConflict(String, String),
+
+    /// The downloaded dependency does not match the hash recorded in the lock file.
+    #[error("dependency {0}: checksum mismatch: expected {1}, found {2}")]
+    DependencyChecksumMismatch(String, String, String),
+
+    /// The project with path dependencies cannot be resolved by other users.
+    #[error("dependency `{0}` is specified by path and cannot be uploaded")]
+    PathDependencyNotUploadable(String),
+
+    /// The dependency resolution has not converged.
+    #[error("dependency resolution has not converged in {0} iterations")]
+    DependencyResolutionLimit(usize),
+
+    /// The project source code is not formatted.
+    #[error("{0} source code file(s) must be formatted with `zargo fmt`")]
+    SourceCodeUnformatted(usize),
 }
@@ -21,6 +21,8 @@ impl Compiler {
     ///
     /// If `is_test_only` is set, passes the flag to only build the project unit tests.
     ///
+    /// If `message_format` is set, passes it to the compiler to change the diagnostics format.
+    ///
     pub fn build_debug(
         verbosity: usize,
         quiet: bool,
@@ -28,6 +30,7 @@ impl Compiler {
         version: &semver::Version,
         manifest_path: &PathBuf,
         is_test_only: bool,
+        message_format: Option<&str>,
     ) -> anyhow::Result<()> {
         if !quiet {
             eprintln!("   {} {} v{}", "Compiling".bright_green(), name, version);
@@ -43,6 +46,10 @@ impl Compiler {
             } else {
                 vec![]
             })
+            .args(match message_format {
+                Some(message_format) => vec!["--message-format", message_format],
+                None => vec![],
+            })
             .spawn()
             .with_context(|| zinc_const::app_name::COMPILER)?;
 
@@ -64,6 +71,8 @@ impl Compiler {
     ///
     /// If `is_test_only` is set, passes the flag to only build the project unit tests.
     ///
+    /// If `message_format` is set, passes it to the compiler to change the diagnostics format.
+    ///
     pub fn build_release(
         verbosity: usize,
         quiet: bool,
@@ -71,6 +80,7 @@ impl Compiler {
         version: &semver::Version,
         manifest_path: &PathBuf,
         is_test_only: bool,
+        message_format: Option<&str>,
     ) -> anyhow::Result<()> {
         if !quiet {
             eprintln!("   {} {} v{}", "Compiling".bright_green(), name, version);
@@ -86,6 +96,10 @@ impl Compiler {
             } else {
                 vec![]
             })
+            .args(match message_format {
+                Some(message_format) => vec!["--message-format", message_format],
+                None => vec![],
+            })
             .arg("--opt-dfe")
             .spawn()
             .with_context(|| zinc_const::app_name::COMPILER)?;
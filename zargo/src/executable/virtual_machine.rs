@@ -116,6 +116,206 @@ impl VirtualMachine {
         Ok(())
     }
 
+    ///
+    /// Executes the virtual machine `profile` subcommand for circuit.
+    ///
+    pub fn profile_circuit(
+        verbosity: usize,
+        quiet: bool,
+        binary_path: &PathBuf,
+        input_path: &PathBuf,
+        folded_path: &PathBuf,
+    ) -> anyhow::Result<()> {
+        if !quiet {
+            eprintln!(
+                "   {} `{}` {}",
+                "Profiling".bright_green(),
+                binary_path.to_string_lossy(),
+                if verbosity > 0 {
+                    format!("-{}", "v".repeat(verbosity))
+                } else {
+                    String::new()
+                },
+            );
+        }
+
+        let mut process = process::Command::new(zinc_const::app_name::VIRTUAL_MACHINE)
+            .args(vec!["-v"; verbosity])
+            .args(if quiet { vec!["--quiet"] } else { vec![] })
+            .arg("profile")
+            .arg("--binary")
+            .arg(binary_path)
+            .arg("--input")
+            .arg(input_path)
+            .arg("--folded")
+            .arg(folded_path)
+            .spawn()
+            .with_context(|| zinc_const::app_name::VIRTUAL_MACHINE)?;
+
+        let status = process
+            .wait()
+            .with_context(|| zinc_const::app_name::VIRTUAL_MACHINE)?;
+
+        if !status.success() {
+            anyhow::bail!(Error::SubprocessFailure(status));
+        }
+
+        Ok(())
+    }
+
+    ///
+    /// Executes the virtual machine `profile` subcommand for contract.
+    ///
+    pub fn profile_contract(
+        verbosity: usize,
+        quiet: bool,
+        binary_path: &PathBuf,
+        input_path: &PathBuf,
+        folded_path: &PathBuf,
+        method: &str,
+    ) -> anyhow::Result<()> {
+        if !quiet {
+            eprintln!(
+                "   {} `{}` {}",
+                "Profiling".bright_green(),
+                binary_path.to_string_lossy(),
+                if verbosity > 0 {
+                    format!("-{}", "v".repeat(verbosity))
+                } else {
+                    String::new()
+                },
+            );
+        }
+
+        let mut process = process::Command::new(zinc_const::app_name::VIRTUAL_MACHINE)
+            .args(vec!["-v"; verbosity])
+            .args(if quiet { vec!["--quiet"] } else { vec![] })
+            .arg("profile")
+            .arg("--binary")
+            .arg(binary_path)
+            .arg("--input")
+            .arg(input_path)
+            .arg("--folded")
+            .arg(folded_path)
+            .arg("--method")
+            .arg(method)
+            .spawn()
+            .with_context(|| zinc_const::app_name::VIRTUAL_MACHINE)?;
+
+        let status = process
+            .wait()
+            .with_context(|| zinc_const::app_name::VIRTUAL_MACHINE)?;
+
+        if !status.success() {
+            anyhow::bail!(Error::SubprocessFailure(status));
+        }
+
+        Ok(())
+    }
+
+    ///
+    /// Executes the virtual machine `debug` subcommand for circuit.
+    ///
+    pub fn debug_circuit(
+        verbosity: usize,
+        quiet: bool,
+        binary_path: &PathBuf,
+        input_path: &PathBuf,
+        breakpoints: &[String],
+    ) -> anyhow::Result<()> {
+        if !quiet {
+            eprintln!(
+                "   {} `{}` {}",
+                "Debugging".bright_green(),
+                binary_path.to_string_lossy(),
+                if verbosity > 0 {
+                    format!("-{}", "v".repeat(verbosity))
+                } else {
+                    String::new()
+                },
+            );
+        }
+
+        let mut process = process::Command::new(zinc_const::app_name::VIRTUAL_MACHINE)
+            .args(vec!["-v"; verbosity])
+            .args(if quiet { vec!["--quiet"] } else { vec![] })
+            .arg("debug")
+            .arg("--binary")
+            .arg(binary_path)
+            .arg("--input")
+            .arg(input_path)
+            .args(
+                breakpoints
+                    .iter()
+                    .flat_map(|breakpoint| vec!["--break", breakpoint.as_str()]),
+            )
+            .spawn()
+            .with_context(|| zinc_const::app_name::VIRTUAL_MACHINE)?;
+
+        let status = process
+            .wait()
+            .with_context(|| zinc_const::app_name::VIRTUAL_MACHINE)?;
+
+        if !status.success() {
+            anyhow::bail!(Error::SubprocessFailure(status));
+        }
+
+        Ok(())
+    }
+
+    ///
+    /// Executes the virtual machine `debug` subcommand for contract.
+    ///
+    pub fn debug_contract(
+        verbosity: usize,
+        quiet: bool,
+        binary_path: &PathBuf,
+        input_path: &PathBuf,
+        breakpoints: &[String],
+        method: &str,
+    ) -> anyhow::Result<()> {
+        if !quiet {
+            eprintln!(
+                "   {} `{}` {}",
+                "Debugging".bright_green(),
+                binary_path.to_string_lossy(),
+                if verbosity > 0 {
+                    format!("-{}", "v".repeat(verbosity))
+                } else {
+                    String::new()
+                },
+            );
+        }
+
+        let mut process = process::Command::new(zinc_const::app_name::VIRTUAL_MACHINE)
+            .args(vec!["-v"; verbosity])
+            .args(if quiet { vec!["--quiet"] } else { vec![] })
+            .arg("debug")
+            .arg("--binary")
+            .arg(binary_path)
+            .arg("--input")
+            .arg(input_path)
+            .args(
+                breakpoints
+                    .iter()
+                    .flat_map(|breakpoint| vec!["--break", breakpoint.as_str()]),
+            )
+            .arg("--method")
+            .arg(method)
+            .spawn()
+            .with_context(|| zinc_const::app_name::VIRTUAL_MACHINE)?;
+
+        let status = process
+            .wait()
+            .with_context(|| zinc_const::app_name::VIRTUAL_MACHINE)?;
+
+        if !status.success() {
+            anyhow::bail!(Error::SubprocessFailure(status));
+        }
+
+        Ok(())
+    }
+
     ///
     /// Executes the virtual machine `test` subcommand.
     ///
@@ -148,9 +348,9 @@ impl VirtualMachine {
     /// Executes the virtual machine `setup` subcommand for circuit.
     ///
     pub fn setup_circuit(
-        _verbosity: usize,
+        verbosity: usize,
         quiet: bool,
-        _binary_path: &PathBuf,
+        binary_path: &PathBuf,
         proving_key_path: &PathBuf,
         verifying_key_path: &PathBuf,
     ) -> anyhow::Result<()> {
@@ -163,17 +363,26 @@ impl VirtualMachine {
             );
         }
 
-        let mut proving_key = std::fs::File::create(proving_key_path)
-            .with_context(|| proving_key_path.to_string_lossy().to_string())?;
-        proving_key
-            .write_all(vec![0u8; 8192].as_slice())
-            .with_context(|| proving_key_path.to_string_lossy().to_string())?;
+        let mut child = process::Command::new(zinc_const::app_name::VIRTUAL_MACHINE)
+            .args(vec!["-v"; verbosity])
+            .args(if quiet { vec!["--quiet"] } else { vec![] })
+            .arg("setup")
+            .arg("--binary")
+            .arg(binary_path)
+            .arg("--proving-key")
+            .arg(proving_key_path)
+            .arg("--verifying-key")
+            .arg(verifying_key_path)
+            .spawn()
+            .with_context(|| zinc_const::app_name::VIRTUAL_MACHINE)?;
+
+        let status = child
+            .wait()
+            .with_context(|| zinc_const::app_name::VIRTUAL_MACHINE)?;
 
-        let mut verifying_key = std::fs::File::create(verifying_key_path)
-            .with_context(|| verifying_key_path.to_string_lossy().to_string())?;
-        verifying_key
-            .write_all(vec![0u8; 1024].as_slice())
-            .with_context(|| verifying_key_path.to_string_lossy().to_string())?;
+        if !status.success() {
+            anyhow::bail!(Error::SubprocessFailure(status));
+        }
 
         Ok(())
     }
@@ -182,10 +391,10 @@ impl VirtualMachine {
     /// Executes the virtual machine `setup` subcommand for contract.
     ///
     pub fn setup_contract(
-        _verbosity: usize,
+        verbosity: usize,
         quiet: bool,
-        _binary_path: &PathBuf,
-        _method: &str,
+        binary_path: &PathBuf,
+        method: &str,
         proving_key_path: &PathBuf,
         verifying_key_path: &PathBuf,
     ) -> anyhow::Result<()> {
@@ -198,17 +407,28 @@ impl VirtualMachine {
             );
         }
 
-        let mut proving_key = std::fs::File::create(proving_key_path)
-            .with_context(|| proving_key_path.to_string_lossy().to_string())?;
-        proving_key
-            .write_all(vec![0u8; 8192].as_slice())
-            .with_context(|| proving_key_path.to_string_lossy().to_string())?;
+        let mut child = process::Command::new(zinc_const::app_name::VIRTUAL_MACHINE)
+            .args(vec!["-v"; verbosity])
+            .args(if quiet { vec!["--quiet"] } else { vec![] })
+            .arg("setup")
+            .arg("--binary")
+            .arg(binary_path)
+            .arg("--method")
+            .arg(method)
+            .arg("--proving-key")
+            .arg(proving_key_path)
+            .arg("--verifying-key")
+            .arg(verifying_key_path)
+            .spawn()
+            .with_context(|| zinc_const::app_name::VIRTUAL_MACHINE)?;
+
+        let status = child
+            .wait()
+            .with_context(|| zinc_const::app_name::VIRTUAL_MACHINE)?;
 
-        let mut verifying_key = std::fs::File::create(verifying_key_path)
-            .with_context(|| verifying_key_path.to_string_lossy().to_string())?;
-        verifying_key
-            .write_all(vec![0u8; 1024].as_slice())
-            .with_context(|| verifying_key_path.to_string_lossy().to_string())?;
+        if !status.success() {
+            anyhow::bail!(Error::SubprocessFailure(status));
+        }
 
         Ok(())
     }
@@ -432,9 +652,14 @@ impl VirtualMachine {
             .arg(input_path)
             .arg("--output")
             .arg(output_path)
+            .stderr(Stdio::inherit())
             .output()
             .with_context(|| zinc_const::app_name::VIRTUAL_MACHINE)?;
 
+        if !prover_output.status.success() {
+            anyhow::bail!(Error::SubprocessFailure(prover_output.status));
+        }
+
         if !quiet {
             eprintln!(
                 "   {} `{}` with `{}`",
@@ -512,9 +737,14 @@ impl VirtualMachine {
             .arg(output_path)
             .arg("--method")
             .arg(method)
+            .stderr(Stdio::inherit())
             .output()
             .with_context(|| zinc_const::app_name::VIRTUAL_MACHINE)?;
 
+        if !prover_output.status.success() {
+            anyhow::bail!(Error::SubprocessFailure(prover_output.status));
+        }
+
         if !quiet {
             eprintln!(
                 "   {} `{}` with `{}`",
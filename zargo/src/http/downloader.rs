@@ -2,12 +2,10 @@
 //! The Zandbox dependency downloader.
 //!
 
-use std::collections::HashMap;
 use std::collections::HashSet;
 use std::fs;
 use std::path::PathBuf;
 
-use async_recursion::async_recursion;
 use colored::Colorize;
 
 use crate::error::Error;
@@ -16,6 +14,9 @@ use crate::http::Client as HttpClient;
 ///
 /// The Zandbox dependency downloader.
 ///
+/// Only downloads the requested projects. The transitive dependencies are selected by the
+/// resolver.
+///
 pub struct Downloader<'a> {
     /// The HTTP client reference.
     client: &'a HttpClient,
@@ -43,7 +44,6 @@ impl<'a> Downloader<'a> {
     ///
     /// Downloads a project.
     ///
-    #[async_recursion]
     pub async fn download_project(
         &mut self,
         name: String,
@@ -71,23 +71,6 @@ impl<'a> Downloader<'a> {
         response.project.source.write_to(&project_path)?;
 
         self.downloads.insert((name, version));
-        if let Some(dependencies) = response.project.manifest.dependencies {
-            self.download_dependency_list(dependencies).await?;
-        }
-
-        Ok(())
-    }
-
-    ///
-    /// Downloads a dependency list.
-    ///
-    pub async fn download_dependency_list(
-        &mut self,
-        dependencies: HashMap<String, semver::Version>,
-    ) -> anyhow::Result<()> {
-        for (name, version) in dependencies.into_iter() {
-            self.download_dependency(name, version).await?;
-        }
 
         Ok(())
     }
@@ -95,7 +78,6 @@ impl<'a> Downloader<'a> {
     ///
     /// Downloads a dependency if it has not been downloaded yet.
     ///
-    #[async_recursion]
     pub async fn download_dependency(
         &mut self,
         name: String,
@@ -142,9 +124,6 @@ impl<'a> Downloader<'a> {
         response.project.source.write_to(&dependency_path)?;
 
         self.downloads.insert((name, version));
-        if let Some(dependencies) = response.project.manifest.dependencies {
-            self.download_dependency_list(dependencies).await?;
-        }
 
         Ok(())
     }
@@ -3,6 +3,7 @@
 //!
 
 pub mod downloader;
+pub mod resolver;
 
 use reqwest::Method;
 use reqwest::Url;
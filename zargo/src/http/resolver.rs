@@ -0,0 +1,506 @@
+//!
+//! The Zandbox dependency resolver.
+//!
+
+use std::collections::BTreeMap;
+use std::collections::BTreeSet;
+use std::collections::HashMap;
+use std::collections::HashSet;
+use std::convert::TryFrom;
+use std::fs;
+use std::path::PathBuf;
+
+use anyhow::Context;
+
+use crate::error::Error;
+use crate::http::downloader::Downloader;
+use crate::http::Client as HttpClient;
+
+///
+/// The Zandbox dependency resolver.
+///
+/// Selects a single version of each dependency, which satisfies all the requirements across
+/// the dependency graph, downloads the missing projects, and records the result in the lock file.
+///
+/// The lock file and the already downloaded dependencies are preferred, so the server is only
+/// queried if they cannot satisfy the requirements.
+///
+pub struct Resolver<'a> {
+    /// The HTTP client reference.
+    client: &'a HttpClient,
+    /// The project root directory path.
+    project_path: PathBuf,
+    /// The project dependencies directory path.
+    dependencies_path: PathBuf,
+    /// The existing project lock file.
+    lock: Option<zinc_project::Lock>,
+    /// The projects available on the server, which are requested at most once.
+    registry: Option<Vec<zinc_project::ManifestProject>>,
+}
+
+impl<'a> Resolver<'a> {
+    /// The maximum number of resolution passes, after which the resolution is considered failed.
+    const ITERATIONS_LIMIT: usize = 64;
+
+    ///
+    /// A shortcut constructor.
+    ///
+    /// Reads the lock file of the project at `project_path`, if it exists.
+    ///
+    pub fn new(client: &'a HttpClient, project_path: &PathBuf) -> anyhow::Result<Self> {
+        let lock = if zinc_project::Lock::exists_at(project_path) {
+            Some(zinc_project::Lock::try_from(project_path)?)
+        } else {
+            None
+        };
+
+        let mut dependencies_path = project_path.to_owned();
+        dependencies_path.push(zinc_const::directory::TARGET_DEPS);
+
+        Ok(Self {
+            client,
+            project_path: project_path.to_owned(),
+            dependencies_path,
+            lock,
+            registry: None,
+        })
+    }
+
+    ///
+    /// Resolves the project `dependencies` with their entire dependency tree and writes the
+    /// lock file.
+    ///
+    pub async fn resolve(
+        &mut self,
+        project_name: &str,
+        dependencies: HashMap<String, zinc_project::ManifestDependency>,
+    ) -> anyhow::Result<()> {
+        let mut downloader = Downloader::new(self.client, &self.project_path);
+
+        let mut selected: BTreeMap<String, semver::Version> = BTreeMap::new();
+        let mut is_stable = false;
+        for _ in 0..Self::ITERATIONS_LIMIT {
+            let requirements = self.requirements(project_name, &dependencies, &selected)?;
+
+            let mut next = BTreeMap::new();
+            for (name, requirements) in requirements.iter() {
+                let version = match selected.get(name) {
+                    Some(version)
+                        if requirements
+                            .iter()
+                            .all(|(_, requirement)| requirement.matches(version)) =>
+                    {
+                        version.to_owned()
+                    }
+                    _ => self.select(name.as_str(), requirements).await?,
+                };
+
+                downloader
+                    .download_dependency(name.to_owned(), version.clone())
+                    .await?;
+                next.insert(name.to_owned(), version);
+            }
+
+            if next == selected {
+                is_stable = true;
+                break;
+            }
+            selected = next;
+        }
+        if !is_stable {
+            anyhow::bail!(Error::DependencyResolutionLimit(Self::ITERATIONS_LIMIT));
+        }
+
+        let mut packages = Vec::with_capacity(selected.len());
+        for (name, version) in selected.into_iter() {
+            let path = self.dependency_path(name.as_str(), &version);
+
+            let checksum = zinc_project::Lock::checksum(&path)?;
+            if let Some(locked) = self.lock.as_ref().and_then(|lock| lock.get(name.as_str())) {
+                if locked.version == version && locked.checksum != checksum {
+                    anyhow::bail!(Error::DependencyChecksumMismatch(
+                        format!("{}-{}", name, version),
+                        locked.checksum.to_owned(),
+                        checksum,
+                    ));
+                }
+            }
+
+            let manifest = zinc_project::Manifest::try_from(&path)
+                .with_context(|| path.to_string_lossy().to_string())?;
+            let dependencies = manifest
+                .dependencies
+                .map(|dependencies| dependencies.into_iter().map(|(name, _)| name).collect())
+                .unwrap_or_default();
+
+            packages.push(zinc_project::LockPackage::new(
+                name,
+                version,
+                checksum,
+                dependencies,
+            ));
+        }
+
+        let lock = zinc_project::Lock::new(packages);
+        if self.lock.as_ref() != Some(&lock) {
+            lock.write_to(&self.project_path)?;
+            self.lock = Some(lock);
+        }
+
+        Ok(())
+    }
+
+    ///
+    /// Collects the version requirements imposed by the project and the `selected` dependencies.
+    ///
+    /// Each requirement is paired with the name of the project which has declared it.
+    ///
+    fn requirements(
+        &self,
+        project_name: &str,
+        dependencies: &HashMap<String, zinc_project::ManifestDependency>,
+        selected: &BTreeMap<String, semver::Version>,
+    ) -> anyhow::Result<BTreeMap<String, Vec<(String, semver::VersionReq)>>> {
+        let mut requirements = BTreeMap::new();
+        let mut visited = HashSet::new();
+
+        self.collect_requirements(
+            project_name.to_owned(),
+            &self.project_path,
+            dependencies,
+            &mut requirements,
+            &mut visited,
+        )?;
+
+        for (name, version) in selected.iter() {
+            let path = self.dependency_path(name.as_str(), version);
+            let manifest = zinc_project::Manifest::try_from(&path)
+                .with_context(|| path.to_string_lossy().to_string())?;
+
+            if let Some(dependencies) = manifest.dependencies {
+                self.collect_requirements(
+                    format!("{}-{}", name, version),
+                    &path,
+                    &dependencies,
+                    &mut requirements,
+                    &mut visited,
+                )?;
+            }
+        }
+
+        Ok(requirements)
+    }
+
+    ///
+    /// Collects the version requirements declared by the `dependent` project at `path`.
+    ///
+    /// The path dependencies are not downloaded, but their own requirements are collected
+    /// recursively. The `visited` set prevents infinite recursion on path dependency cycles,
+    /// which are reported later by the compiler.
+    ///
+    fn collect_requirements(
+        &self,
+        dependent: String,
+        path: &PathBuf,
+        dependencies: &HashMap<String, zinc_project::ManifestDependency>,
+        requirements: &mut BTreeMap<String, Vec<(String, semver::VersionReq)>>,
+        visited: &mut HashSet<PathBuf>,
+    ) -> anyhow::Result<()> {
+        for (name, dependency) in dependencies.iter() {
+            match dependency {
+                zinc_project::ManifestDependency::Version(requirement) => {
+                    requirements
+                        .entry(name.to_owned())
+                        .or_default()
+                        .push((dependent.clone(), requirement.to_owned()));
+                }
+                zinc_project::ManifestDependency::Path {
+                    path: dependency_path,
+                } => {
+                    let mut full_path = path.to_owned();
+                    full_path.push(dependency_path);
+                    let full_path = fs::canonicalize(&full_path)
+                        .with_context(|| full_path.to_string_lossy().to_string())?;
+                    if !visited.insert(full_path.clone()) {
+                        continue;
+                    }
+
+                    let manifest = zinc_project::Manifest::try_from(&full_path)
+                        .with_context(|| full_path.to_string_lossy().to_string())?;
+                    if let Some(dependencies) = manifest.dependencies {
+                        self.collect_requirements(
+                            format!("{}-{}", manifest.project.name, manifest.project.version),
+                            &full_path,
+                            &dependencies,
+                            requirements,
+                            visited,
+                        )?;
+                    }
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    ///
+    /// Selects the dependency version satisfying all the `requirements`.
+    ///
+    /// The locally available and locked versions are considered first, and the server is only
+    /// queried if none of them is suitable.
+    ///
+    async fn select(
+        &mut self,
+        name: &str,
+        requirements: &[(String, semver::VersionReq)],
+    ) -> anyhow::Result<semver::Version> {
+        let locked = self
+            .lock
+            .as_ref()
+            .and_then(|lock| lock.get(name))
+            .map(|package| package.version.to_owned());
+
+        let mut candidates = self.local_versions(name)?;
+        candidates.extend(locked.clone());
+
+        if !candidates
+            .iter()
+            .any(|version| Self::is_satisfied(requirements, version))
+        {
+            candidates.extend(self.registry_versions(name).await?);
+        }
+
+        Ok(Self::choose(
+            name,
+            requirements,
+            &candidates,
+            locked.as_ref(),
+        )?)
+    }
+
+    ///
+    /// Chooses the version satisfying all the `requirements` among the `candidates`.
+    ///
+    /// The `locked` version is preferred. Otherwise, the highest satisfying version is taken.
+    /// If there is no such version, the error tells whether the requirements conflict with each
+    /// other or just cannot be satisfied by any known version.
+    ///
+    fn choose(
+        name: &str,
+        requirements: &[(String, semver::VersionReq)],
+        candidates: &BTreeSet<semver::Version>,
+        locked: Option<&semver::Version>,
+    ) -> Result<semver::Version, Error> {
+        if let Some(locked) = locked {
+            if candidates.contains(locked) && Self::is_satisfied(requirements, locked) {
+                return Ok(locked.to_owned());
+            }
+        }
+
+        if let Some(version) = candidates
+            .iter()
+            .rev()
+            .find(|version| Self::is_satisfied(requirements, version))
+        {
+            return Ok(version.to_owned());
+        }
+
+        let description = requirements
+            .iter()
+            .map(|(dependent, requirement)| format!("`{}` by `{}`", requirement, dependent))
+            .collect::<Vec<String>>()
+            .join(", ");
+        let is_conflict = requirements.len() > 1
+            && requirements.iter().all(|(_, requirement)| {
+                candidates
+                    .iter()
+                    .any(|version| requirement.matches(version))
+            });
+
+        if is_conflict {
+            Err(Error::DependencyVersionConflict(
+                name.to_owned(),
+                description,
+            ))
+        } else {
+            Err(Error::DependencyVersionNotFound(
+                name.to_owned(),
+                description,
+            ))
+        }
+    }
+
+    ///
+    /// Checks if the `version` satisfies all the `requirements`.
+    ///
+    fn is_satisfied(
+        requirements: &[(String, semver::VersionReq)],
+        version: &semver::Version,
+    ) -> bool {
+        requirements
+            .iter()
+            .all(|(_, requirement)| requirement.matches(version))
+    }
+
+    ///
+    /// Returns the versions of the dependency already downloaded to the dependencies directory.
+    ///
+    fn local_versions(&self, name: &str) -> anyhow::Result<BTreeSet<semver::Version>> {
+        let mut versions = BTreeSet::new();
+        if !self.dependencies_path.exists() {
+            return Ok(versions);
+        }
+
+        let prefix = format!("{}-", name);
+        let directory = fs::read_dir(&self.dependencies_path)
+            .with_context(|| self.dependencies_path.to_string_lossy().to_string())?;
+        for entry in directory {
+            let entry =
+                entry.with_context(|| self.dependencies_path.to_string_lossy().to_string())?;
+            let file_name = entry.file_name().to_string_lossy().to_string();
+
+            if let Some(version) = file_name
+                .strip_prefix(prefix.as_str())
+                .and_then(|version| semver::Version::parse(version).ok())
+            {
+                versions.insert(version);
+            }
+        }
+
+        Ok(versions)
+    }
+
+    ///
+    /// Returns the versions of the dependency available on the server.
+    ///
+    async fn registry_versions(&mut self, name: &str) -> anyhow::Result<Vec<semver::Version>> {
+        if self.registry.is_none() {
+            self.registry = Some(self.client.metadata().await?.projects);
+        }
+
+        Ok(self
+            .registry
+            .as_ref()
+            .expect(zinc_const::panic::VALUE_ALWAYS_EXISTS)
+            .iter()
+            .filter(|project| project.name == name)
+            .map(|project| project.version.to_owned())
+            .collect())
+    }
+
+    ///
+    /// Returns the path to the downloaded dependency.
+    ///
+    fn dependency_path(&self, name: &str, version: &semver::Version) -> PathBuf {
+        let mut path = self.dependencies_path.to_owned();
+        path.push(format!("{}-{}", name, version));
+        path
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeSet;
+
+    use crate::error::Error;
+
+    use super::Resolver;
+
+    fn candidates(versions: &[&str]) -> BTreeSet<semver::Version> {
+        versions
+            .iter()
+            .map(|version| {
+                semver::Version::parse(version).expect(zinc_const::panic::TEST_DATA_VALID)
+            })
+            .collect()
+    }
+
+    fn requirements(requirements: &[(&str, &str)]) -> Vec<(String, semver::VersionReq)> {
+        requirements
+            .iter()
+            .map(|(dependent, requirement)| {
+                (
+                    dependent.to_string(),
+                    semver::VersionReq::parse(requirement)
+                        .expect(zinc_const::panic::TEST_DATA_VALID),
+                )
+            })
+            .collect()
+    }
+
+    #[test]
+    fn ok_highest_compatible() {
+        let result = Resolver::choose(
+            "math",
+            requirements(&[("main", "^0.2"), ("utils-0.1.0", ">=0.2.1")]).as_slice(),
+            &candidates(&["0.1.0", "0.2.0", "0.2.1", "0.2.5", "1.0.0"]),
+            None,
+        )
+        .expect(zinc_const::panic::TEST_DATA_VALID);
+
+        assert_eq!(result, semver::Version::new(0, 2, 5));
+    }
+
+    #[test]
+    fn ok_locked_preferred() {
+        let locked = semver::Version::new(0, 2, 1);
+
+        let result = Resolver::choose(
+            "math",
+            requirements(&[("main", "^0.2")]).as_slice(),
+            &candidates(&["0.2.0", "0.2.1", "0.2.5"]),
+            Some(&locked),
+        )
+        .expect(zinc_const::panic::TEST_DATA_VALID);
+
+        assert_eq!(result, locked);
+    }
+
+    #[test]
+    fn ok_locked_ignored_if_not_satisfying() {
+        let locked = semver::Version::new(0, 1, 0);
+
+        let result = Resolver::choose(
+            "math",
+            requirements(&[("main", "^0.2")]).as_slice(),
+            &candidates(&["0.1.0", "0.2.0"]),
+            Some(&locked),
+        )
+        .expect(zinc_const::panic::TEST_DATA_VALID);
+
+        assert_eq!(result, semver::Version::new(0, 2, 0));
+    }
+
+    #[test]
+    fn error_version_not_found() {
+        let result = Resolver::choose(
+            "math",
+            requirements(&[("main", "^2.0")]).as_slice(),
+            &candidates(&["0.1.0", "1.0.0"]),
+            None,
+        );
+
+        match result {
+            Err(Error::DependencyVersionNotFound(name, _)) => assert_eq!(name, "math"),
+            result => panic!("unexpected result: {:?}", result),
+        }
+    }
+
+    #[test]
+    fn error_version_conflict() {
+        let result = Resolver::choose(
+            "math",
+            requirements(&[("main", "^0.1"), ("utils-0.1.0", "^1.0")]).as_slice(),
+            &candidates(&["0.1.0", "1.0.0"]),
+            None,
+        );
+
+        match result {
+            Err(Error::DependencyVersionConflict(name, description)) => {
+                assert_eq!(name, "math");
+                assert!(description.contains("by `utils-0.1.0`"));
+            }
+            result => panic!("unexpected result: {:?}", result),
+        }
+    }
+}
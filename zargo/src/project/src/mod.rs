@@ -35,4 +35,40 @@ impl Directory {
         Ok(fs::create_dir_all(&Self::path(path))
             .with_context(|| path.to_string_lossy().to_string())?)
     }
+
+    ///
+    /// Returns the paths of all the source code files in the directory and its subdirectories.
+    ///
+    /// The paths are sorted to make the output of the commands processing them reproducible.
+    ///
+    pub fn files(path: &PathBuf) -> anyhow::Result<Vec<PathBuf>> {
+        let mut files = Vec::new();
+        Self::collect_files(&Self::path(path), &mut files)?;
+        files.sort();
+        Ok(files)
+    }
+
+    ///
+    /// Appends the paths of the source code files in `directory` and its subdirectories to
+    /// `files`.
+    ///
+    fn collect_files(directory: &PathBuf, files: &mut Vec<PathBuf>) -> anyhow::Result<()> {
+        for entry in
+            fs::read_dir(directory).with_context(|| directory.to_string_lossy().to_string())?
+        {
+            let path = entry
+                .with_context(|| directory.to_string_lossy().to_string())?
+                .path();
+
+            if path.is_dir() {
+                Self::collect_files(&path, files)?;
+            } else if path.extension().and_then(|extension| extension.to_str())
+                == Some(zinc_const::extension::SOURCE)
+            {
+                files.push(path);
+            }
+        }
+
+        Ok(())
+    }
 }
@@ -29,4 +29,22 @@ pub enum Error {
         /// The child project type.
         child_type: String,
     },
+    /// No version of the dependency satisfying the requirement has been downloaded.
+    #[error("dependency `{name}` with version requirement `{requirement}` is not found in the dependency directory")]
+    DependencyNotFound {
+        /// The dependency name.
+        name: String,
+        /// The dependency version requirement.
+        requirement: String,
+    },
+    /// The dependency project name differs from the one specified in the dependent manifest.
+    #[error("dependency `{expected}` at path {path} is named `{found}`")]
+    DependencyNameMismatch {
+        /// The dependency name specified in the dependent manifest.
+        expected: String,
+        /// The dependency project name.
+        found: String,
+        /// The dependency project path.
+        path: String,
+    },
 }
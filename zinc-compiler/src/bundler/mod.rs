@@ -8,13 +8,17 @@ pub mod error;
 use std::cell::RefCell;
 use std::collections::HashMap;
 use std::convert::TryFrom;
+use std::fs;
 use std::path::PathBuf;
 use std::rc::Rc;
 
 use anyhow::Context;
 
+use crate::error::Error as CompilerError;
 use crate::generator::zinc_vm::State as ZincVMState;
+use crate::semantic::analyzer::entry::Analyzer as EntryAnalyzer;
 use crate::semantic::scope::Scope;
+use crate::source::error::Error as SourceError;
 use crate::source::Source;
 
 use self::dependency::Dependency;
@@ -32,6 +36,8 @@ pub struct Bundler {
     /// The optimization flag.
     optimize_dead_function_elimination: bool,
 
+    /// The project lock file, if it exists.
+    lock: Option<zinc_project::Lock>,
     /// The compiled dependency modules cache.
     cache: HashMap<(String, semver::Version), Dependency>,
     /// The allocated dependency graph node indexes.
@@ -60,6 +66,7 @@ impl Bundler {
 
             optimize_dead_function_elimination,
 
+            lock: None,
             cache: HashMap::with_capacity(Self::DEPENDENCIES_INITIAL_CAPACITY),
             node_indexes: HashMap::with_capacity(Self::NODE_INDEXES_INITIAL_CAPACITY),
             graph: petgraph::Graph::new(),
@@ -73,10 +80,20 @@ impl Bundler {
         let manifest = zinc_project::Manifest::try_from(&self.project_path)
             .with_context(|| self.project_path.to_string_lossy().to_string())?;
 
-        let node_index = self.graph.add_node(manifest.project.clone());
+        if zinc_project::Lock::exists_at(&self.project_path) {
+            self.lock = Some(
+                zinc_project::Lock::try_from(&self.project_path)
+                    .with_context(|| self.project_path.to_string_lossy().to_string())?,
+            );
+        }
+
+        let node_index = self.node_index(&manifest.project);
 
+        let project_path = self.project_path.to_owned();
         let dependencies = match manifest.dependencies {
-            Some(ref dependencies) => self.compile_list(node_index, &dependencies)?,
+            Some(ref dependencies) => {
+                self.compile_list(node_index, &project_path, &dependencies)?
+            }
             None => HashMap::new(),
         };
 
@@ -91,17 +108,83 @@ impl Bundler {
         Ok(application.into_build())
     }
 
+    ///
+    /// Runs the semantic analysis of the project `source` with its entire dependency tree without
+    /// generating the bytecode.
+    ///
+    /// Used by the language server, which passes the source code of the documents opened in the
+    /// editor instead of reading them from the disk.
+    ///
+    pub fn analyze(&mut self, source: zinc_project::Source) -> anyhow::Result<Rc<RefCell<Scope>>> {
+        let manifest = zinc_project::Manifest::try_from(&self.project_path)
+            .with_context(|| self.project_path.to_string_lossy().to_string())?;
+
+        if zinc_project::Lock::exists_at(&self.project_path) {
+            self.lock = Some(
+                zinc_project::Lock::try_from(&self.project_path)
+                    .with_context(|| self.project_path.to_string_lossy().to_string())?,
+            );
+        }
+
+        let node_index = self.node_index(&manifest.project);
+
+        let project_path = self.project_path.to_owned();
+        let dependencies = match manifest.dependencies {
+            Some(ref dependencies) => {
+                self.compile_list(node_index, &project_path, &dependencies)?
+            }
+            None => HashMap::new(),
+        };
+
+        let source = Source::try_from_string(source, true)?;
+        let scope = EntryAnalyzer::define(source, manifest.project, dependencies, false)
+            .map_err(|error| vec![CompilerError::Semantic(error)])
+            .map_err(CompilerError::diagnostics)
+            .map_err(SourceError::Compiling)?;
+
+        Ok(scope)
+    }
+
     ///
     /// Compiles a dependency and stores its scope in the bundler instance cache.
     ///
+    /// The path dependencies are resolved relatively to the `parent_path` project directory.
+    ///
     fn compile_list(
         &mut self,
         parent_node_index: petgraph::graph::NodeIndex,
-        dependencies: &HashMap<String, semver::Version>,
+        parent_path: &PathBuf,
+        dependencies: &HashMap<String, zinc_project::ManifestDependency>,
     ) -> anyhow::Result<HashMap<String, Rc<RefCell<Scope>>>> {
         let mut compiled = HashMap::with_capacity(dependencies.len());
 
-        for (name, version) in dependencies.iter() {
+        for (name, dependency) in dependencies.iter() {
+            let path = match dependency {
+                zinc_project::ManifestDependency::Version(requirement) => {
+                    let version = self.resolve_version(name.as_str(), requirement)?;
+
+                    let mut path = self.dependencies_directory_path.to_owned();
+                    path.push(format!("{}-{}", name, version));
+                    path
+                }
+                zinc_project::ManifestDependency::Path { path } => {
+                    let mut dependency_path = parent_path.to_owned();
+                    dependency_path.push(path);
+                    dependency_path
+                }
+            };
+
+            let manifest = zinc_project::Manifest::try_from(&path)
+                .with_context(|| path.to_string_lossy().to_string())?;
+            if &manifest.project.name != name {
+                anyhow::bail!(Error::DependencyNameMismatch {
+                    expected: name.to_owned(),
+                    found: manifest.project.name,
+                    path: path.to_string_lossy().to_string(),
+                });
+            }
+            let version = manifest.project.version.clone();
+
             let scope = match self.cache.get(&(name.clone(), version.clone())) {
                 Some(dependency) => {
                     self.graph
@@ -111,18 +194,14 @@ impl Bundler {
                     dependency.scope.to_owned()
                 }
                 None => {
-                    let mut path = self.dependencies_directory_path.to_owned();
-                    path.push(format!("{}-{}", name, version));
-
-                    let manifest = zinc_project::Manifest::try_from(&path)
-                        .with_context(|| path.to_string_lossy().to_string())?;
-
                     let node_index = self.node_index(&manifest.project);
                     self.graph.add_edge(parent_node_index, node_index, ());
                     self.check_dependency(parent_node_index, node_index)?;
 
                     let dependencies = match manifest.dependencies {
-                        Some(dependencies) => self.compile_list(node_index, &dependencies)?,
+                        Some(dependencies) => {
+                            self.compile_list(node_index, &path, &dependencies)?
+                        }
                         None => HashMap::new(),
                     };
 
@@ -133,7 +212,7 @@ impl Bundler {
 
                     let dependency = Dependency::new(manifest.project, scope.clone(), node_index);
                     self.cache
-                        .insert((name.to_owned(), version.to_owned()), dependency.clone());
+                        .insert((name.to_owned(), version), dependency.clone());
                     dependency.scope
                 }
             };
@@ -144,6 +223,68 @@ impl Bundler {
         Ok(compiled)
     }
 
+    ///
+    /// Resolves the exact dependency version satisfying the `requirement`.
+    ///
+    /// The version recorded in the lock file is preferred. If there is no lock file or the locked
+    /// version does not satisfy the requirement, the highest satisfying version found in the
+    /// dependency directory is taken.
+    ///
+    fn resolve_version(
+        &self,
+        name: &str,
+        requirement: &semver::VersionReq,
+    ) -> anyhow::Result<semver::Version> {
+        if let Some(package) = self.lock.as_ref().and_then(|lock| lock.get(name)) {
+            let mut path = self.dependencies_directory_path.to_owned();
+            path.push(format!("{}-{}", name, package.version));
+
+            if requirement.matches(&package.version) && path.exists() {
+                return Ok(package.version.to_owned());
+            }
+        }
+
+        let prefix = format!("{}-", name);
+        let mut resolved: Option<semver::Version> = None;
+        let directory = fs::read_dir(&self.dependencies_directory_path).with_context(|| {
+            self.dependencies_directory_path
+                .to_string_lossy()
+                .to_string()
+        })?;
+        for entry in directory {
+            let entry = entry.with_context(|| {
+                self.dependencies_directory_path
+                    .to_string_lossy()
+                    .to_string()
+            })?;
+            let file_name = entry.file_name().to_string_lossy().to_string();
+
+            let version = match file_name
+                .strip_prefix(prefix.as_str())
+                .and_then(|version| semver::Version::parse(version).ok())
+            {
+                Some(version) => version,
+                None => continue,
+            };
+
+            if requirement.matches(&version)
+                && resolved
+                    .as_ref()
+                    .map(|resolved| &version > resolved)
+                    .unwrap_or(true)
+            {
+                resolved = Some(version);
+            }
+        }
+
+        resolved.ok_or_else(|| {
+            anyhow::anyhow!(Error::DependencyNotFound {
+                name: name.to_owned(),
+                requirement: requirement.to_string(),
+            })
+        })
+    }
+
     ///
     /// Checks the dependencies for validity:
     ///
@@ -0,0 +1,38 @@
+//!
+//! The compiler diagnostic message format.
+//!
+
+use std::fmt;
+use std::str::FromStr;
+
+///
+/// The compiler diagnostic message format.
+///
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum MessageFormat {
+    /// The user-friendly colored text with source code snippets.
+    Human,
+    /// The JSON objects, one per line, which are convenient for tools and editors.
+    Json,
+}
+
+impl FromStr for MessageFormat {
+    type Err = String;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "human" => Ok(Self::Human),
+            "json" => Ok(Self::Json),
+            another => Err(another.to_owned()),
+        }
+    }
+}
+
+impl fmt::Display for MessageFormat {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Human => write!(f, "human"),
+            Self::Json => write!(f, "json"),
+        }
+    }
+}
@@ -0,0 +1,306 @@
+//!
+//! The compiler diagnostic.
+//!
+
+#[cfg(test)]
+mod tests;
+
+pub mod message_format;
+
+use colored::Colorize;
+
+use zinc_lexical::Location;
+use zinc_lexical::FILE_INDEX;
+
+///
+/// The compiler diagnostic, which is the output representation of a compiler error.
+///
+/// Can be rendered either as the user-friendly colored text or as a JSON object.
+///
+#[derive(Debug, Clone)]
+pub struct Diagnostic {
+    /// The error code, if it is available.
+    pub code: Option<usize>,
+    /// The error message.
+    pub message: String,
+    /// The primary location, where the error has occurred.
+    pub location: Location,
+    /// The end of the erroneous part of the source code, if it spans several lines.
+    pub end: Option<Location>,
+    /// The secondary location, which helps the user to fix the error more easily.
+    pub reference: Option<Location>,
+    /// The optional error hint text.
+    pub help: Option<String>,
+}
+
+impl Diagnostic {
+    ///
+    /// Creates a diagnostic with an optional `help` message.
+    ///
+    /// The error has a location, that is, points to a specific place in the source code.
+    ///
+    pub fn line(
+        message: &str,
+        code: Option<usize>,
+        location: Location,
+        help: Option<&str>,
+    ) -> Self {
+        Self {
+            code,
+            message: message.to_owned(),
+            location,
+            end: None,
+            reference: None,
+            help: help.map(|help| help.to_owned()),
+        }
+    }
+
+    ///
+    /// Creates a diagnostic with an optional `help` message.
+    ///
+    /// The error has a second location reference, which helps the user to fix the error more easily.
+    ///
+    pub fn line_with_reference(
+        message: &str,
+        code: Option<usize>,
+        location: Location,
+        reference: Option<Location>,
+        help: Option<&str>,
+    ) -> Self {
+        Self {
+            code,
+            message: message.to_owned(),
+            location,
+            end: None,
+            reference,
+            help: help.map(|help| help.to_owned()),
+        }
+    }
+
+    ///
+    /// Creates a diagnostic with an optional `help` message.
+    ///
+    /// The error has two location bounds, which enclose the erroneous part of the source code.
+    ///
+    pub fn range(
+        message: &str,
+        code: Option<usize>,
+        start: Location,
+        end: Location,
+        help: Option<&str>,
+    ) -> Self {
+        Self {
+            code,
+            message: message.to_owned(),
+            location: start,
+            end: Some(end),
+            reference: None,
+            help: help.map(|help| help.to_owned()),
+        }
+    }
+
+    ///
+    /// Formats the diagnostics and appends the summary line with their count.
+    ///
+    pub fn format_many(diagnostics: &[Self]) -> String {
+        let mut strings: Vec<String> = diagnostics.iter().map(Self::format).collect();
+        strings.push(format!(
+            "\n{}: {}\n",
+            "error".bright_red(),
+            format!(
+                "aborting due to {} previous error{}",
+                diagnostics.len(),
+                if diagnostics.len() == 1 { "" } else { "s" }
+            )
+            .bright_white()
+        ));
+        strings.join("")
+    }
+
+    ///
+    /// Formats the diagnostic into the user-friendly readable output.
+    ///
+    pub fn format(&self) -> String {
+        match self.end {
+            Some(end) => self.format_range(end),
+            None => self.format_line(),
+        }
+    }
+
+    ///
+    /// Converts the diagnostic into a JSON object.
+    ///
+    /// The primary span is always the first one in the `spans` array.
+    ///
+    pub fn to_json(&self) -> serde_json::Value {
+        let mut spans = Vec::with_capacity(2);
+        spans.push(Self::span_to_json(
+            self.location,
+            self.end.unwrap_or(self.location),
+            true,
+        ));
+        if let Some(reference) = self.reference {
+            spans.push(Self::span_to_json(reference, reference, false));
+        }
+
+        serde_json::json!({
+            "code": self.code,
+            "level": "error",
+            "message": self.message,
+            "spans": spans,
+            "help": self.help,
+        })
+    }
+
+    ///
+    /// Converts a span between the `start` and `end` locations into a JSON object.
+    ///
+    fn span_to_json(start: Location, end: Location, is_primary: bool) -> serde_json::Value {
+        serde_json::json!({
+            "file": FILE_INDEX.get_path(start.file).to_string_lossy(),
+            "line_start": start.line,
+            "column_start": start.column,
+            "line_end": end.line,
+            "column_end": end.column,
+            "is_primary": is_primary,
+        })
+    }
+
+    ///
+    /// Formats a diagnostic pointing to a line with an optional reference.
+    ///
+    fn format_line(&self) -> String {
+        let index = FILE_INDEX
+            .inner
+            .read()
+            .expect(zinc_const::panic::SYNCHRONIZATION);
+        let context = index
+            .get(&self.location.file)
+            .expect(zinc_const::panic::VALIDATED_DURING_SOURCE_CODE_MAPPING)
+            .code
+            .lines()
+            .collect::<Vec<&str>>();
+        let line_number_length = self.location.line.to_string().len();
+        let mut strings = Vec::with_capacity(11);
+        strings.push(String::new());
+        let code = match self.code {
+            Some(code) => format!("error[{:04}]", code),
+            None => "error".to_owned(),
+        };
+        strings.push(format!(
+            "{}: {}",
+            code.bright_red(),
+            self.message.bright_white()
+        ));
+        if let Some(reference) = self.reference {
+            let context = index
+                .get(&reference.file)
+                .expect(zinc_const::panic::VALIDATED_DURING_SOURCE_CODE_MAPPING)
+                .code
+                .lines()
+                .collect::<Vec<&str>>();
+            let line_number_length = reference.line.to_string().len();
+            strings.push(format!(
+                "{}{}",
+                " ".repeat(line_number_length + 1),
+                "|".bright_cyan()
+            ));
+            if let Some(line) = context.get(reference.line - 1) {
+                strings.push(format!(
+                    "{}{}",
+                    (reference.line.to_string() + " | ").bright_cyan(),
+                    line
+                ));
+            }
+            strings.push(format!(
+                "{}{} {}{}",
+                " ".repeat(line_number_length + 1),
+                "|".bright_cyan(),
+                "_".repeat(reference.column - 1).bright_red(),
+                "^".bright_red()
+            ));
+        }
+        strings.push(format!(" {} {}", "-->".bright_cyan(), self.location));
+        strings.push(format!(
+            "{}{}",
+            " ".repeat(line_number_length + 1),
+            "|".bright_cyan()
+        ));
+        if let Some(line) = context.get(self.location.line - 1) {
+            strings.push(format!(
+                "{}{}",
+                (self.location.line.to_string() + " | ").bright_cyan(),
+                line
+            ));
+        }
+        strings.push(format!(
+            "{}{} {}{}",
+            " ".repeat(line_number_length + 1),
+            "|".bright_cyan(),
+            "_".repeat(self.location.column - 1).bright_red(),
+            "^".bright_red()
+        ));
+        if let Some(ref help) = self.help {
+            strings.push(format!("{}: {}", "help".bright_white(), help.bright_blue()));
+        }
+        strings.push(String::new());
+        strings.join("\n")
+    }
+
+    ///
+    /// Formats a diagnostic enclosing several lines between the primary location and `end`.
+    ///
+    fn format_range(&self, end: Location) -> String {
+        let start = self.location;
+
+        let index = FILE_INDEX
+            .inner
+            .read()
+            .expect(zinc_const::panic::SYNCHRONIZATION);
+        let context = index
+            .get(&start.file)
+            .expect(zinc_const::panic::VALIDATED_DURING_SOURCE_CODE_MAPPING)
+            .code
+            .lines()
+            .collect::<Vec<&str>>();
+        let line_number_length = end.line.to_string().len();
+        let mut strings = Vec::with_capacity(8 + end.line - start.line);
+        strings.push(String::new());
+        let code = match self.code {
+            Some(code) => format!("error[{:04}]", code),
+            None => "error".to_owned(),
+        };
+        strings.push(format!(
+            "{}: {}",
+            code.bright_red(),
+            self.message.bright_white()
+        ));
+        strings.push(format!(" {} {}", "-->".bright_cyan(), start));
+        strings.push(format!(
+            "{}{}",
+            " ".repeat(line_number_length + 1),
+            "|".bright_cyan()
+        ));
+        for line_number in start.line..=end.line {
+            if let Some(line) = context.get(line_number - 1) {
+                strings.push(format!(
+                    "{}{}",
+                    (line_number.to_string() + " | ").bright_cyan(),
+                    line
+                ));
+            }
+        }
+        strings.push(format!(
+            "{}{} {}{}",
+            " ".repeat(line_number_length + 1),
+            "|".bright_cyan(),
+            "_".repeat(end.column - 1).bright_red(),
+            "^".bright_red()
+        ));
+        if let Some(ref help) = self.help {
+            strings.push(format!("{}: {}", "help".bright_white(), help.bright_blue()));
+        }
+        strings.push(String::new());
+        strings.join("\n")
+    }
+}
@@ -0,0 +1,141 @@
+//!
+//! The compiler diagnostic tests.
+//!
+
+use std::path::PathBuf;
+
+use zinc_lexical::Location;
+use zinc_lexical::FILE_INDEX;
+
+use crate::diagnostic::Diagnostic;
+use crate::error::Error;
+use crate::semantic::error::Error as SemanticError;
+
+#[test]
+fn ok_json_line_with_reference() {
+    let file = FILE_INDEX.next(&PathBuf::from("./src/main.zn"), String::new());
+
+    let diagnostic = Diagnostic::line_with_reference(
+        "the message",
+        Some(48),
+        Location {
+            line: 3,
+            column: 5,
+            file,
+        },
+        Some(Location {
+            line: 2,
+            column: 17,
+            file,
+        }),
+        Some("the help"),
+    );
+
+    let expected = serde_json::json!({
+        "code": 48,
+        "level": "error",
+        "message": "the message",
+        "spans": [
+            {
+                "file": "./src/main.zn",
+                "line_start": 3,
+                "column_start": 5,
+                "line_end": 3,
+                "column_end": 5,
+                "is_primary": true,
+            },
+            {
+                "file": "./src/main.zn",
+                "line_start": 2,
+                "column_start": 17,
+                "line_end": 2,
+                "column_end": 17,
+                "is_primary": false,
+            },
+        ],
+        "help": "the help",
+    });
+
+    let result = diagnostic.to_json();
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn ok_json_range() {
+    let file = FILE_INDEX.next(&PathBuf::from("./src/main.zn"), String::new());
+
+    let diagnostic = Diagnostic::range(
+        "the message",
+        None,
+        Location {
+            line: 2,
+            column: 1,
+            file,
+        },
+        Location {
+            line: 4,
+            column: 3,
+            file,
+        },
+        None,
+    );
+
+    let expected = serde_json::json!({
+        "code": null,
+        "level": "error",
+        "message": "the message",
+        "spans": [
+            {
+                "file": "./src/main.zn",
+                "line_start": 2,
+                "column_start": 1,
+                "line_end": 4,
+                "column_end": 3,
+                "is_primary": true,
+            },
+        ],
+        "help": null,
+    });
+
+    let result = diagnostic.to_json();
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn ok_multiple_flattened() {
+    let file = FILE_INDEX.next(&PathBuf::from("./src/main.zn"), String::new());
+
+    let error = Error::Semantic(SemanticError::Multiple(vec![
+        SemanticError::ScopeItemUndeclared {
+            location: Location {
+                line: 2,
+                column: 5,
+                file,
+            },
+            name: "first".to_owned(),
+        },
+        SemanticError::ScopeItemUndeclared {
+            location: Location {
+                line: 3,
+                column: 5,
+                file,
+            },
+            name: "second".to_owned(),
+        },
+    ]));
+
+    let result = Error::diagnostics(vec![error])
+        .into_iter()
+        .map(|diagnostic| (diagnostic.code, diagnostic.location))
+        .collect::<Vec<(Option<usize>, Location)>>();
+
+    assert_eq!(
+        result,
+        vec![
+            (Some(28), Location::test(2, 5)),
+            (Some(28), Location::test(3, 5)),
+        ]
+    );
+}
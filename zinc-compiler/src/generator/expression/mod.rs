@@ -443,9 +443,14 @@ impl Expression {
     ///
     /// Translates an `require(...)` function call into the bytecode.
     ///
-    fn call_require(state: Rc<RefCell<ZincVMState>>, message: Option<String>, location: Location) {
+    fn call_require(
+        state: Rc<RefCell<ZincVMState>>,
+        message: Option<String>,
+        input_types: Vec<zinc_types::Type>,
+        location: Location,
+    ) {
         state.borrow_mut().push_instruction(
-            Instruction::Require(zinc_types::Require::new(message)),
+            Instruction::Require(zinc_types::Require::new(message, input_types)),
             Some(location),
         );
     }
@@ -817,9 +822,18 @@ impl IBytecodeWritable for Expression {
                             .collect(),
                         location,
                     ),
-                    Operator::CallRequire { message } => {
-                        Self::call_require(state.clone(), message, location)
-                    }
+                    Operator::CallRequire {
+                        message,
+                        argument_types,
+                    } => Self::call_require(
+                        state.clone(),
+                        message,
+                        argument_types
+                            .into_iter()
+                            .map(|r#type| r#type.into())
+                            .collect(),
+                        location,
+                    ),
                     Operator::CallContractFetch { fields } => {
                         Self::call_contract_fetch(state.clone(), fields, location)
                     }
@@ -834,6 +848,7 @@ impl IBytecodeWritable for Expression {
                         output_size,
                         location,
                     ),
+                    Operator::CallInline { function } => function.write(state.clone(), location),
                 },
             }
         }
@@ -7,7 +7,11 @@ pub mod builder;
 use std::cell::RefCell;
 use std::rc::Rc;
 
+use zinc_types::Instruction;
+
+use crate::generator::expression::operand::Operand;
 use crate::generator::expression::Expression as GeneratorExpression;
+use crate::generator::r#type::Type;
 use crate::generator::statement::Statement;
 use crate::generator::zinc_vm::State as ZincVMState;
 use crate::generator::IBytecodeWritable;
@@ -21,27 +25,108 @@ pub struct Expression {
     statements: Vec<Statement>,
     /// The optional block expressions, whose type is defaulted to `()` if unset.
     expression: Option<GeneratorExpression>,
+    /// The optional block expression type, which is required to write the interrupted block result.
+    expression_type: Option<Type>,
 }
 
 impl Expression {
     ///
     /// A shortcut constructor.
     ///
-    pub fn new(statements: Vec<Statement>, expression: Option<GeneratorExpression>) -> Self {
+    pub fn new(
+        statements: Vec<Statement>,
+        expression: Option<GeneratorExpression>,
+        expression_type: Option<Type>,
+    ) -> Self {
         Self {
             statements,
             expression,
+            expression_type,
         }
     }
-}
 
-impl IBytecodeWritable for Expression {
-    fn write_to_zinc_vm(self, state: Rc<RefCell<ZincVMState>>) {
-        for statement in self.statements.into_iter() {
+    ///
+    /// Whether the block has the result expression.
+    ///
+    pub fn has_expression(&self) -> bool {
+        self.expression.is_some()
+    }
+
+    ///
+    /// Appends the `operand` to the block result expression and resets the result type.
+    ///
+    /// Is used to write the padding of a generic enumeration value, whose type is only
+    /// known when the block result is checked against the expected type.
+    ///
+    pub fn coerce(&mut self, operand: Operand, r#type: Type) {
+        if let Some(ref mut expression) = self.expression {
+            expression.push_operand(operand);
+            self.expression_type = Some(r#type);
+        }
+    }
+
+    ///
+    /// Writes the statements starting from the current one.
+    ///
+    /// If a statement interrupts the function or loop with `return`, `break`, or `continue`,
+    /// the rest of the block is wrapped into a conditional block, which is only executed if
+    /// the interruption has not happened. Otherwise, the block result is defaulted to zeros.
+    ///
+    fn write_statements(
+        mut statements: std::vec::IntoIter<Statement>,
+        expression: Option<GeneratorExpression>,
+        expression_type: Option<Type>,
+        state: Rc<RefCell<ZincVMState>>,
+    ) {
+        while let Some(statement) = statements.next() {
+            let interruptions_count = state.borrow().interruptions_count();
             statement.write_to_zinc_vm(state.clone());
+            if state.borrow().interruptions_count() == interruptions_count {
+                continue;
+            }
+
+            if statements.len() == 0 && expression.is_none() {
+                return;
+            }
+            if !state.borrow_mut().push_interruption_guard(None) {
+                continue;
+            }
+
+            Self::write_statements(
+                statements,
+                expression,
+                expression_type.clone(),
+                state.clone(),
+            );
+
+            match expression_type {
+                Some(r#type) if r#type.size() > 0 => {
+                    state
+                        .borrow_mut()
+                        .push_instruction(Instruction::Else(zinc_types::Else), None);
+                    state.borrow_mut().push_zero_values(r#type, None);
+                }
+                _ => {}
+            }
+            state
+                .borrow_mut()
+                .push_instruction(Instruction::EndIf(zinc_types::EndIf), None);
+            return;
         }
-        if let Some(expression) = self.expression {
+
+        if let Some(expression) = expression {
             expression.write_to_zinc_vm(state);
         }
     }
 }
+
+impl IBytecodeWritable for Expression {
+    fn write_to_zinc_vm(self, state: Rc<RefCell<ZincVMState>>) {
+        Self::write_statements(
+            self.statements.into_iter(),
+            self.expression,
+            self.expression_type,
+            state,
+        );
+    }
+}
@@ -0,0 +1,117 @@
+//!
+//! The generator expression match binding.
+//!
+
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use zinc_lexical::Location;
+use zinc_types::Instruction;
+
+use crate::generator::r#type::Type;
+use crate::generator::zinc_vm::State as ZincVMState;
+
+///
+/// The match branch binding, which copies a part of the scrutinee into a variable.
+///
+#[derive(Debug, Clone)]
+pub struct Binding {
+    /// The variable name.
+    pub name: String,
+    /// The variable offset within the scrutinee.
+    pub offset: usize,
+    /// The variable type.
+    pub r#type: Type,
+    /// Whether the variable scalars must be casted to their types, which is required for
+    /// the enumeration payloads, since the union cells are shared by different variants.
+    pub is_casted: bool,
+}
+
+impl Binding {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(name: String, offset: usize, r#type: Type, is_casted: bool) -> Self {
+        Self {
+            name,
+            offset,
+            r#type,
+            is_casted,
+        }
+    }
+
+    ///
+    /// Defines the variable and returns its address.
+    ///
+    pub fn define(&self, state: Rc<RefCell<ZincVMState>>) -> usize {
+        state
+            .borrow_mut()
+            .define_named_variable(self.name.to_owned(), self.r#type.to_owned().into())
+    }
+
+    ///
+    /// Writes the binding, which copies the scrutinee part stored at `address` into the
+    /// variable at `variable_address`.
+    ///
+    /// The casts are executed under the branch condition, so the payload of another variant
+    /// does not violate the type constraints.
+    ///
+    pub fn write_to_zinc_vm(
+        self,
+        state: Rc<RefCell<ZincVMState>>,
+        address: usize,
+        variable_address: usize,
+        location: Location,
+    ) {
+        let size = self.r#type.size();
+        let r#type: zinc_types::Type = self.r#type.into();
+
+        if !self.is_casted {
+            state.borrow_mut().push_instruction(
+                Instruction::Load(zinc_types::Load::new(address + self.offset, size)),
+                Some(location),
+            );
+            state.borrow_mut().push_instruction(
+                Instruction::Store(zinc_types::Store::new(variable_address, size)),
+                Some(location),
+            );
+            return;
+        }
+
+        for (index, scalar_type) in r#type.into_flat_scalar_types().into_iter().enumerate() {
+            state.borrow_mut().push_instruction(
+                Instruction::Load(zinc_types::Load::new(address + self.offset + index, 1)),
+                Some(location),
+            );
+            state.borrow_mut().push_instruction(
+                Instruction::Cast(zinc_types::Cast::new(scalar_type)),
+                Some(location),
+            );
+            state.borrow_mut().push_instruction(
+                Instruction::Store(zinc_types::Store::new(variable_address + index, 1)),
+                Some(location),
+            );
+        }
+    }
+
+    ///
+    /// Writes the zero values into the variable at `variable_address`.
+    ///
+    /// The variable must be initialized this way before it is conditionally stored to,
+    /// since the conditional stores to uninitialized memory are discarded.
+    ///
+    pub fn write_initializer(
+        &self,
+        state: Rc<RefCell<ZincVMState>>,
+        variable_address: usize,
+        location: Location,
+    ) {
+        let size = state
+            .borrow_mut()
+            .push_zero_values(self.r#type.to_owned(), Some(location));
+        state.borrow_mut().push_instruction(
+            Instruction::Store(zinc_types::Store::new(variable_address, size)),
+            Some(location),
+        );
+    }
+}
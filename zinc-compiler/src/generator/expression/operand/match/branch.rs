@@ -0,0 +1,227 @@
+//!
+//! The generator expression match branch.
+//!
+
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use zinc_lexical::Location;
+use zinc_types::Instruction;
+
+use crate::generator::expression::operand::constant::boolean::Boolean as BooleanConstant;
+use crate::generator::expression::operand::r#match::binding::Binding;
+use crate::generator::expression::operand::r#match::pattern::Pattern;
+use crate::generator::expression::Expression as GeneratorExpression;
+use crate::generator::zinc_vm::State as ZincVMState;
+use crate::generator::IBytecodeWritable;
+
+///
+/// The match branch, which consists of the pattern alternatives with the variables bound by
+/// each of them, the optional guard, and the result expression.
+///
+#[derive(Debug, Clone)]
+pub struct Branch {
+    /// The branch pattern alternatives with the variables bound by each of them.
+    pub alternatives: Vec<(Pattern, Vec<Binding>)>,
+    /// The guard expression, which must be true for the branch to be taken.
+    pub guard: Option<GeneratorExpression>,
+    /// The branch result expression.
+    pub expression: GeneratorExpression,
+}
+
+impl Branch {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(
+        alternatives: Vec<(Pattern, Vec<Binding>)>,
+        guard: Option<GeneratorExpression>,
+        expression: GeneratorExpression,
+    ) -> Self {
+        Self {
+            alternatives,
+            guard,
+            expression,
+        }
+    }
+
+    ///
+    /// Writes the branch checking the scrutinee stored at `address`.
+    ///
+    /// The branch is left open with the `Else` instruction, so the next branch is written into
+    /// its `else` block, and the caller must write the closing `EndIf`.
+    ///
+    /// The guard is evaluated under the pattern condition, since it may use the pattern
+    /// bindings, and its result is stored into a temporary variable, which becomes the
+    /// branch condition.
+    ///
+    pub fn write_to_zinc_vm(
+        self,
+        state: Rc<RefCell<ZincVMState>>,
+        address: usize,
+        location: Location,
+    ) {
+        let condition = Pattern::new_any(
+            self.alternatives
+                .iter()
+                .map(|(pattern, _bindings)| pattern.to_owned())
+                .collect(),
+        );
+
+        match self.guard {
+            Some(guard) => {
+                let variable_addresses =
+                    Self::define_bindings(&self.alternatives, true, state.clone(), location);
+
+                let guard_address = state.borrow_mut().define_variable(None, 1);
+                BooleanConstant::new(false).write_to_zinc_vm(state.clone());
+                state.borrow_mut().push_instruction(
+                    Instruction::Store(zinc_types::Store::new(guard_address, 1)),
+                    Some(location),
+                );
+
+                condition.write_to_zinc_vm(state.clone(), address, location);
+                state
+                    .borrow_mut()
+                    .push_instruction(Instruction::If(zinc_types::If), Some(location));
+                Self::write_bindings(
+                    self.alternatives,
+                    variable_addresses,
+                    state.clone(),
+                    address,
+                    location,
+                );
+                guard.write_to_zinc_vm(state.clone());
+                state.borrow_mut().push_instruction(
+                    Instruction::Store(zinc_types::Store::new(guard_address, 1)),
+                    Some(location),
+                );
+                state
+                    .borrow_mut()
+                    .push_instruction(Instruction::EndIf(zinc_types::EndIf), Some(location));
+
+                state.borrow_mut().push_instruction(
+                    Instruction::Load(zinc_types::Load::new(guard_address, 1)),
+                    Some(location),
+                );
+                state
+                    .borrow_mut()
+                    .push_instruction(Instruction::If(zinc_types::If), Some(location));
+            }
+            None => {
+                condition.write_to_zinc_vm(state.clone(), address, location);
+                state
+                    .borrow_mut()
+                    .push_instruction(Instruction::If(zinc_types::If), Some(location));
+                let variable_addresses =
+                    Self::define_bindings(&self.alternatives, false, state.clone(), location);
+                Self::write_bindings(
+                    self.alternatives,
+                    variable_addresses,
+                    state.clone(),
+                    address,
+                    location,
+                );
+            }
+        }
+
+        self.expression.write_to_zinc_vm(state.clone());
+        state
+            .borrow_mut()
+            .push_instruction(Instruction::Else(zinc_types::Else), Some(location));
+    }
+
+    ///
+    /// Writes the fallback branch, whose pattern is not checked, since it is the last one and
+    /// covers all the remaining values.
+    ///
+    pub fn write_fallback_to_zinc_vm(
+        self,
+        state: Rc<RefCell<ZincVMState>>,
+        address: usize,
+        location: Location,
+    ) {
+        let variable_addresses =
+            Self::define_bindings(&self.alternatives, false, state.clone(), location);
+        Self::write_bindings(
+            self.alternatives,
+            variable_addresses,
+            state.clone(),
+            address,
+            location,
+        );
+        self.expression.write_to_zinc_vm(state);
+    }
+
+    ///
+    /// Defines the variables bound by the first alternative and returns their addresses.
+    ///
+    /// If there are several alternatives or the branch is guarded, the variables are
+    /// initialized with zero values, since they are stored under some condition afterwards
+    /// and used outside of it.
+    ///
+    fn define_bindings(
+        alternatives: &[(Pattern, Vec<Binding>)],
+        is_guarded: bool,
+        state: Rc<RefCell<ZincVMState>>,
+        location: Location,
+    ) -> Vec<usize> {
+        let bindings = match alternatives.first() {
+            Some((_pattern, bindings)) => bindings,
+            None => return vec![],
+        };
+
+        let mut variable_addresses = Vec::with_capacity(bindings.len());
+        for binding in bindings.iter() {
+            let variable_address = binding.define(state.clone());
+            if is_guarded || alternatives.len() > 1 {
+                binding.write_initializer(state.clone(), variable_address, location);
+            }
+            variable_addresses.push(variable_address);
+        }
+        variable_addresses
+    }
+
+    ///
+    /// Writes the bindings of the matched alternative into the variables at
+    /// `variable_addresses`.
+    ///
+    /// If there are several alternatives, each of them stores its values under its own
+    /// condition. The alternatives are written in the reverse order, so the values of
+    /// the first matching one are stored last.
+    ///
+    fn write_bindings(
+        mut alternatives: Vec<(Pattern, Vec<Binding>)>,
+        variable_addresses: Vec<usize>,
+        state: Rc<RefCell<ZincVMState>>,
+        address: usize,
+        location: Location,
+    ) {
+        if variable_addresses.is_empty() {
+            return;
+        }
+
+        if alternatives.len() == 1 {
+            let (_pattern, bindings) = alternatives.remove(0);
+            for (binding, variable_address) in bindings.into_iter().zip(variable_addresses) {
+                binding.write_to_zinc_vm(state.clone(), address, variable_address, location);
+            }
+            return;
+        }
+
+        for (pattern, bindings) in alternatives.into_iter().rev() {
+            pattern.write_to_zinc_vm(state.clone(), address, location);
+            state
+                .borrow_mut()
+                .push_instruction(Instruction::If(zinc_types::If), Some(location));
+            for (binding, variable_address) in
+                bindings.into_iter().zip(variable_addresses.iter().copied())
+            {
+                binding.write_to_zinc_vm(state.clone(), address, variable_address, location);
+            }
+            state
+                .borrow_mut()
+                .push_instruction(Instruction::EndIf(zinc_types::EndIf), Some(location));
+        }
+    }
+}
@@ -2,7 +2,9 @@
 //! The generator expression match operand builder.
 //!
 
-use crate::generator::expression::operand::constant::Constant;
+use crate::generator::expression::operand::r#match::binding::Binding;
+use crate::generator::expression::operand::r#match::branch::Branch;
+use crate::generator::expression::operand::r#match::pattern::Pattern;
 use crate::generator::expression::operand::r#match::Expression as MatchExpression;
 use crate::generator::expression::Expression as GeneratorExpression;
 use crate::generator::r#type::Type;
@@ -19,12 +21,13 @@ pub struct Builder {
     scrutinee: Option<GeneratorExpression>,
     /// The scrutinee (matched) expression type.
     scrutinee_type: Option<Type>,
-    /// The branches ordered array, where each branch consists of a pattern and result expression.
-    branches: Vec<(Constant, GeneratorExpression)>,
+    /// The branches ordered array, where each branch consists of the pattern alternatives with
+    /// their bindings, the optional guard, and the result expression.
+    branches: Vec<Branch>,
     /// The binding branch, which is the last fallback branch.
     binding_branch: Option<(GeneratorExpression, String)>,
-    /// The wildcard `_` branch, which is the last fallback branch. Ignored if `binding_branch` is set.
-    wildcard_branch: Option<GeneratorExpression>,
+    /// The fallback branch, which is the last branch, whose pattern is exhaustive. Ignored if `binding_branch` is set.
+    fallback_branch: Option<Branch>,
 }
 
 impl Builder {
@@ -44,10 +47,17 @@ impl Builder {
     }
 
     ///
-    /// Pushes a branch, which consists of a `pattern` and `expression`.
+    /// Pushes a branch, which consists of the pattern `alternatives` with their bindings,
+    /// the optional `guard`, and `expression`.
     ///
-    pub fn push_branch(&mut self, pattern: Constant, expression: GeneratorExpression) {
-        self.branches.push((pattern, expression));
+    pub fn push_branch(
+        &mut self,
+        alternatives: Vec<(Pattern, Vec<Binding>)>,
+        guard: Option<GeneratorExpression>,
+        expression: GeneratorExpression,
+    ) {
+        self.branches
+            .push(Branch::new(alternatives, guard, expression));
     }
 
     ///
@@ -58,10 +68,14 @@ impl Builder {
     }
 
     ///
-    /// Sets the corresponding builder value.
+    /// Sets the last exhaustive branch, whose pattern does not have to be checked.
     ///
-    pub fn set_wildcard_branch(&mut self, value: GeneratorExpression) {
-        self.wildcard_branch = Some(value);
+    pub fn set_fallback_branch(
+        &mut self,
+        alternatives: Vec<(Pattern, Vec<Binding>)>,
+        value: GeneratorExpression,
+    ) {
+        self.fallback_branch = Some(Branch::new(alternatives, None, value));
     }
 
     ///
@@ -92,20 +106,20 @@ impl Builder {
         });
 
         match self.binding_branch.take() {
-            Some(binding_branch) => MatchExpression::new(
+            Some((expression, name)) => MatchExpression::new(
                 location,
                 scrutinee,
                 scrutinee_type,
                 self.branches,
-                Some(binding_branch),
-                None,
+                Branch::new(vec![(Pattern::new_all(vec![]), vec![])], None, expression),
+                Some(name),
             ),
             None => {
-                let wildcard_branch = self.wildcard_branch.take().unwrap_or_else(|| {
+                let fallback_branch = self.fallback_branch.take().unwrap_or_else(|| {
                     panic!(
                         "{}{}",
                         zinc_const::panic::BUILDER_REQUIRES_VALUE,
-                        "wildcard branch"
+                        "fallback branch"
                     )
                 });
                 MatchExpression::new(
@@ -113,8 +127,8 @@ impl Builder {
                     scrutinee,
                     scrutinee_type,
                     self.branches,
+                    fallback_branch,
                     None,
-                    Some(wildcard_branch),
                 )
             }
         }
@@ -2,20 +2,24 @@
 //! The generator expression match operand.
 //!
 
+pub mod binding;
+pub mod branch;
 pub mod builder;
+pub mod pattern;
 
 use std::cell::RefCell;
 use std::rc::Rc;
 
 use zinc_types::Instruction;
 
-use crate::generator::expression::operand::constant::Constant;
 use crate::generator::expression::Expression as GeneratorExpression;
 use crate::generator::r#type::Type;
 use crate::generator::zinc_vm::State as ZincVMState;
 use crate::generator::IBytecodeWritable;
 use zinc_lexical::Location;
 
+use self::branch::Branch;
+
 ///
 /// The match expression.
 ///
@@ -27,12 +31,13 @@ pub struct Expression {
     scrutinee: GeneratorExpression,
     /// The scrutinee (matched) expression type.
     scrutinee_type: Type,
-    /// The branches ordered array, where each branch consists of a pattern and result expression.
-    branches: Vec<(Constant, GeneratorExpression)>,
-    /// The binding branch, which is the last fallback branch.
-    binding_branch: Option<(GeneratorExpression, String)>,
-    /// The wildcard `_` branch, which is the last fallback branch. Ignored if `binding_branch` is set.
-    wildcard_branch: Option<GeneratorExpression>,
+    /// The branches ordered array, where each branch consists of the pattern alternatives with
+    /// their bindings, the optional guard, and the result expression.
+    branches: Vec<Branch>,
+    /// The fallback branch, which is executed if no other branch has matched. Its pattern is not checked.
+    fallback_branch: Branch,
+    /// The binding name, if the fallback branch binds the whole scrutinee.
+    binding_name: Option<String>,
 }
 
 impl Expression {
@@ -43,17 +48,17 @@ impl Expression {
         location: Location,
         scrutinee: GeneratorExpression,
         scrutinee_type: Type,
-        branches: Vec<(Constant, GeneratorExpression)>,
-        binding_branch: Option<(GeneratorExpression, String)>,
-        wildcard_branch: Option<GeneratorExpression>,
+        branches: Vec<Branch>,
+        fallback_branch: Branch,
+        binding_name: Option<String>,
     ) -> Self {
         Self {
             location,
             scrutinee,
             scrutinee_type,
             branches,
-            binding_branch,
-            wildcard_branch,
+            fallback_branch,
+            binding_name,
         }
     }
 }
@@ -63,49 +68,30 @@ impl IBytecodeWritable for Expression {
         let branch_count = self.branches.len();
         let scrutinee_size = self.scrutinee_type.size();
 
-        let (binding_branch, binding_name) = match self.binding_branch {
-            Some((binding_branch, binding_name)) => (Some(binding_branch), Some(binding_name)),
-            None => (None, None),
+        let scrutinee_address = match self.binding_name {
+            Some(binding_name) => state
+                .borrow_mut()
+                .define_named_variable(binding_name, self.scrutinee_type.clone().into()),
+            None => state.borrow_mut().define_variable(None, scrutinee_size),
         };
 
-        let scrutinee_address = state
-            .borrow_mut()
-            .define_variable(binding_name, scrutinee_size);
-
         self.scrutinee.write_to_zinc_vm(state.clone());
         state.borrow_mut().push_instruction(
             Instruction::Store(zinc_types::Store::new(scrutinee_address, scrutinee_size)),
             Some(self.location),
         );
 
-        for (branch_pattern, branch_expression) in self.branches.into_iter() {
-            state.borrow_mut().push_instruction(
-                Instruction::Load(zinc_types::Load::new(scrutinee_address, scrutinee_size)),
-                Some(self.location),
-            );
-            branch_pattern.write_to_zinc_vm(state.clone());
-            state
-                .borrow_mut()
-                .push_instruction(Instruction::Eq(zinc_types::Eq), Some(self.location));
-            state
-                .borrow_mut()
-                .push_instruction(Instruction::If(zinc_types::If), Some(self.location));
-            branch_expression.write_to_zinc_vm(state.clone());
-            state
-                .borrow_mut()
-                .push_instruction(Instruction::Else(zinc_types::Else), Some(self.location));
+        for branch in self.branches.into_iter() {
+            branch.write_to_zinc_vm(state.clone(), scrutinee_address, self.location);
         }
 
-        if let Some(binding_branch) = binding_branch {
-            binding_branch.write_to_zinc_vm(state.clone());
-        } else if let Some(wildcard_branch) = self.wildcard_branch {
-            wildcard_branch.write_to_zinc_vm(state.clone());
-        }
+        self.fallback_branch.write_fallback_to_zinc_vm(
+            state.clone(),
+            scrutinee_address,
+            self.location,
+        );
 
-        state
-            .borrow_mut()
-            .push_instruction(Instruction::EndIf(zinc_types::EndIf), Some(self.location));
-        for _ in 0..branch_count - 1 {
+        for _ in 0..branch_count {
             state
                 .borrow_mut()
                 .push_instruction(Instruction::EndIf(zinc_types::EndIf), Some(self.location));
@@ -0,0 +1,142 @@
+//!
+//! The generator expression match pattern.
+//!
+
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use zinc_lexical::Location;
+use zinc_types::Instruction;
+
+use crate::generator::expression::operand::constant::boolean::Boolean as BooleanConstant;
+use crate::generator::expression::operand::constant::Constant;
+use crate::generator::zinc_vm::State as ZincVMState;
+use crate::generator::IBytecodeWritable;
+
+///
+/// The match branch pattern, which is written as a boolean condition checking the scrutinee
+/// stored at some address in the data stack.
+///
+#[derive(Debug, Clone)]
+pub enum Pattern {
+    /// Matches if the scrutinee scalar at `offset` is equal to the `constant`.
+    Constant {
+        /// The scalar offset within the scrutinee.
+        offset: usize,
+        /// The scalar constant to compare with.
+        constant: Constant,
+    },
+    /// Matches if the scrutinee scalar at `offset` is within the inclusive range.
+    Range {
+        /// The scalar offset within the scrutinee.
+        offset: usize,
+        /// The range start constant.
+        start: Constant,
+        /// The range inclusive end constant.
+        end: Constant,
+    },
+    /// Matches if all the inner patterns match. The empty list always matches.
+    All(Vec<Self>),
+    /// Matches if any of the inner patterns matches. The empty list never matches.
+    Any(Vec<Self>),
+}
+
+impl Pattern {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new_constant(offset: usize, constant: Constant) -> Self {
+        Self::Constant { offset, constant }
+    }
+
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new_range(offset: usize, start: Constant, end: Constant) -> Self {
+        Self::Range { offset, start, end }
+    }
+
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new_all(patterns: Vec<Self>) -> Self {
+        Self::All(patterns)
+    }
+
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new_any(patterns: Vec<Self>) -> Self {
+        Self::Any(patterns)
+    }
+
+    ///
+    /// Writes the condition, which checks the scrutinee stored at `address`.
+    ///
+    pub fn write_to_zinc_vm(
+        self,
+        state: Rc<RefCell<ZincVMState>>,
+        address: usize,
+        location: Location,
+    ) {
+        match self {
+            Self::Constant { offset, constant } => {
+                state.borrow_mut().push_instruction(
+                    Instruction::Load(zinc_types::Load::new(address + offset, 1)),
+                    Some(location),
+                );
+                constant.write_to_zinc_vm(state.clone());
+                state
+                    .borrow_mut()
+                    .push_instruction(Instruction::Eq(zinc_types::Eq), Some(location));
+            }
+            Self::Range { offset, start, end } => {
+                state.borrow_mut().push_instruction(
+                    Instruction::Load(zinc_types::Load::new(address + offset, 1)),
+                    Some(location),
+                );
+                start.write_to_zinc_vm(state.clone());
+                state
+                    .borrow_mut()
+                    .push_instruction(Instruction::Ge(zinc_types::Ge), Some(location));
+                state.borrow_mut().push_instruction(
+                    Instruction::Load(zinc_types::Load::new(address + offset, 1)),
+                    Some(location),
+                );
+                end.write_to_zinc_vm(state.clone());
+                state
+                    .borrow_mut()
+                    .push_instruction(Instruction::Le(zinc_types::Le), Some(location));
+                state
+                    .borrow_mut()
+                    .push_instruction(Instruction::And(zinc_types::And), Some(location));
+            }
+            Self::All(patterns) if patterns.is_empty() => {
+                BooleanConstant::new(true).write_to_zinc_vm(state)
+            }
+            Self::All(patterns) => {
+                for (index, pattern) in patterns.into_iter().enumerate() {
+                    pattern.write_to_zinc_vm(state.clone(), address, location);
+                    if index > 0 {
+                        state
+                            .borrow_mut()
+                            .push_instruction(Instruction::And(zinc_types::And), Some(location));
+                    }
+                }
+            }
+            Self::Any(patterns) if patterns.is_empty() => {
+                BooleanConstant::new(false).write_to_zinc_vm(state)
+            }
+            Self::Any(patterns) => {
+                for (index, pattern) in patterns.into_iter().enumerate() {
+                    pattern.write_to_zinc_vm(state.clone(), address, location);
+                    if index > 0 {
+                        state
+                            .borrow_mut()
+                            .push_instruction(Instruction::Or(zinc_types::Or), Some(location));
+                    }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,58 @@
+//!
+//! The generator inlined closure.
+//!
+
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use zinc_lexical::Location;
+
+use crate::generator::expression::Expression;
+use crate::generator::statement::r#let::Statement as LetStatement;
+use crate::generator::zinc_vm::State as ZincVMState;
+use crate::generator::IBytecodeWritable;
+use crate::semantic::binding::Binding;
+
+///
+/// The generator inlined closure.
+///
+/// Writing the closure binds the arguments on the top of the evaluation stack to the closure
+/// parameters, and leaves the closure result there. The parameters are visible only within
+/// the closure body.
+///
+#[derive(Debug, Clone)]
+pub struct Closure {
+    /// The closure location in the source code.
+    pub location: Location,
+    /// The bindings of all the closure parameters.
+    pub bindings: Vec<Binding>,
+    /// The closure body expression.
+    pub body: Expression,
+}
+
+impl Closure {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(location: Location, bindings: Vec<Binding>, body: Expression) -> Self {
+        Self {
+            location,
+            bindings,
+            body,
+        }
+    }
+}
+
+impl IBytecodeWritable for Closure {
+    fn write_to_zinc_vm(self, state: Rc<RefCell<ZincVMState>>) {
+        let variable_addresses = state.borrow().variable_addresses();
+
+        LetStatement::new(self.location, self.bindings, Expression::new())
+            .write_to_zinc_vm(state.clone());
+        self.body.write_to_zinc_vm(state.clone());
+
+        state
+            .borrow_mut()
+            .set_variable_addresses(variable_addresses);
+    }
+}
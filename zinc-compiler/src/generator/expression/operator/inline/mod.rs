@@ -0,0 +1,437 @@
+//!
+//! The generator inlined standard library function.
+//!
+
+pub mod closure;
+
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use num::BigInt;
+use num::Zero;
+
+use zinc_lexical::Location;
+use zinc_types::Instruction;
+
+use crate::generator::expression::operand::constant::boolean::Boolean as BooleanConstant;
+use crate::generator::expression::operand::constant::integer::Integer as IntegerConstant;
+use crate::generator::r#type::Type;
+use crate::generator::zinc_vm::State as ZincVMState;
+use crate::generator::IBytecodeWritable;
+
+use self::closure::Closure;
+
+///
+/// The generator inlined standard library function.
+///
+/// The functions are unrolled at compile time, so their closures are written once for each
+/// array element. The function arguments are expected on the top of the evaluation stack.
+///
+#[derive(Debug, Clone)]
+pub enum Function {
+    /// The `std::array::map` function.
+    ArrayMap {
+        /// The array size.
+        array_size: usize,
+        /// The array element size.
+        element_size: usize,
+        /// The closure applied to each element.
+        closure: Closure,
+    },
+    /// The `std::array::fold` function.
+    ArrayFold {
+        /// The array size.
+        array_size: usize,
+        /// The array element size.
+        element_size: usize,
+        /// The accumulator size.
+        accumulator_size: usize,
+        /// The closure combining the accumulator with each element.
+        closure: Closure,
+    },
+    /// The `std::array::zip` function.
+    ArrayZip {
+        /// The size of both arrays.
+        array_size: usize,
+        /// The first array element size.
+        first_element_size: usize,
+        /// The second array element size.
+        second_element_size: usize,
+    },
+    /// The `std::array::any` function.
+    ArrayAny {
+        /// The array size.
+        array_size: usize,
+        /// The array element size.
+        element_size: usize,
+        /// The predicate closure.
+        closure: Closure,
+    },
+    /// The `std::array::all` function.
+    ArrayAll {
+        /// The array size.
+        array_size: usize,
+        /// The array element size.
+        element_size: usize,
+        /// The predicate closure.
+        closure: Closure,
+    },
+    /// The `std::array::position` function.
+    ArrayPosition {
+        /// The array size.
+        array_size: usize,
+        /// The array element size.
+        element_size: usize,
+        /// The `std::option::Option` result type.
+        result_type: Type,
+        /// The predicate closure.
+        closure: Closure,
+    },
+    /// The `std::array::filter_count` function.
+    ArrayFilterCount {
+        /// The array size.
+        array_size: usize,
+        /// The array element size.
+        element_size: usize,
+        /// The predicate closure.
+        closure: Closure,
+    },
+    /// The `std::string::concat` function, whose arguments are already adjacent on the stack.
+    StringConcat,
+    /// The `std::string::to_bytes` function.
+    StringToBytes {
+        /// The string literal bytes.
+        bytes: Vec<u8>,
+    },
+}
+
+impl Function {
+    ///
+    /// Writes the function call to the bytecode.
+    ///
+    pub fn write(self, state: Rc<RefCell<ZincVMState>>, location: Location) {
+        match self {
+            Self::ArrayMap {
+                array_size,
+                element_size,
+                closure,
+            } => {
+                let array_address =
+                    Self::store_temporary(state.clone(), array_size * element_size, location);
+
+                for index in 0..array_size {
+                    Self::load(
+                        state.clone(),
+                        array_address + index * element_size,
+                        element_size,
+                        location,
+                    );
+                    closure.clone().write_to_zinc_vm(state.clone());
+                }
+            }
+            Self::ArrayFold {
+                array_size,
+                element_size,
+                accumulator_size,
+                closure,
+            } => {
+                let accumulator_address =
+                    Self::store_temporary(state.clone(), accumulator_size, location);
+                let array_address =
+                    Self::store_temporary(state.clone(), array_size * element_size, location);
+
+                for index in 0..array_size {
+                    Self::load(
+                        state.clone(),
+                        accumulator_address,
+                        accumulator_size,
+                        location,
+                    );
+                    Self::load(
+                        state.clone(),
+                        array_address + index * element_size,
+                        element_size,
+                        location,
+                    );
+                    closure.clone().write_to_zinc_vm(state.clone());
+                    state.borrow_mut().push_instruction(
+                        Instruction::Store(zinc_types::Store::new(
+                            accumulator_address,
+                            accumulator_size,
+                        )),
+                        Some(location),
+                    );
+                }
+
+                Self::load(state, accumulator_address, accumulator_size, location);
+            }
+            Self::ArrayZip {
+                array_size,
+                first_element_size,
+                second_element_size,
+            } => {
+                let second_address = Self::store_temporary(
+                    state.clone(),
+                    array_size * second_element_size,
+                    location,
+                );
+                let first_address =
+                    Self::store_temporary(state.clone(), array_size * first_element_size, location);
+
+                for index in 0..array_size {
+                    Self::load(
+                        state.clone(),
+                        first_address + index * first_element_size,
+                        first_element_size,
+                        location,
+                    );
+                    Self::load(
+                        state.clone(),
+                        second_address + index * second_element_size,
+                        second_element_size,
+                        location,
+                    );
+                }
+            }
+            Self::ArrayAny {
+                array_size,
+                element_size,
+                closure,
+            } => Self::write_predicate_reduction(
+                state,
+                array_size,
+                element_size,
+                closure,
+                false,
+                location,
+            ),
+            Self::ArrayAll {
+                array_size,
+                element_size,
+                closure,
+            } => Self::write_predicate_reduction(
+                state,
+                array_size,
+                element_size,
+                closure,
+                true,
+                location,
+            ),
+            Self::ArrayPosition {
+                array_size,
+                element_size,
+                result_type,
+                closure,
+            } => Self::write_position(
+                state,
+                array_size,
+                element_size,
+                result_type,
+                closure,
+                location,
+            ),
+            Self::ArrayFilterCount {
+                array_size,
+                element_size,
+                closure,
+            } => Self::write_filter_count(state, array_size, element_size, closure, location),
+            Self::StringConcat => {}
+            Self::StringToBytes { bytes } => {
+                for byte in bytes.into_iter() {
+                    IntegerConstant::new(BigInt::from(byte), false, zinc_const::bitlength::BYTE)
+                        .write_to_zinc_vm(state.clone());
+                }
+            }
+        }
+    }
+
+    ///
+    /// Writes the `any` or `all` predicate reduction, which starts with the `initial` value,
+    /// and is combined with each predicate result using `||` or `&&` respectively.
+    ///
+    fn write_predicate_reduction(
+        state: Rc<RefCell<ZincVMState>>,
+        array_size: usize,
+        element_size: usize,
+        closure: Closure,
+        initial: bool,
+        location: Location,
+    ) {
+        let array_address =
+            Self::store_temporary(state.clone(), array_size * element_size, location);
+
+        BooleanConstant::new(initial).write_to_zinc_vm(state.clone());
+        for index in 0..array_size {
+            Self::load(
+                state.clone(),
+                array_address + index * element_size,
+                element_size,
+                location,
+            );
+            closure.clone().write_to_zinc_vm(state.clone());
+
+            let instruction = if initial {
+                Instruction::And(zinc_types::And)
+            } else {
+                Instruction::Or(zinc_types::Or)
+            };
+            state
+                .borrow_mut()
+                .push_instruction(instruction, Some(location));
+        }
+    }
+
+    ///
+    /// Writes the `position` search, which stores the index of the first element satisfying
+    /// the predicate to the `std::option::Option::Some` result.
+    ///
+    fn write_position(
+        state: Rc<RefCell<ZincVMState>>,
+        array_size: usize,
+        element_size: usize,
+        result_type: Type,
+        closure: Closure,
+        location: Location,
+    ) {
+        let (tag_bitlength, tag_none, tag_some) = match result_type {
+            Type::Enumeration {
+                bitlength,
+                ref variants,
+                ..
+            } => {
+                let tag = |name: &str| {
+                    variants
+                        .iter()
+                        .find(|(variant, _value)| variant == name)
+                        .map(|(_variant, value)| value.to_owned())
+                        .expect(zinc_const::panic::VALIDATED_DURING_SEMANTIC_ANALYSIS)
+                };
+                (bitlength, tag("None"), tag("Some"))
+            }
+            _ => panic!(zinc_const::panic::VALIDATED_DURING_SEMANTIC_ANALYSIS),
+        };
+        let index_bitlength = zinc_const::bitlength::INDEX;
+        let result_size = result_type.size();
+
+        let array_address =
+            Self::store_temporary(state.clone(), array_size * element_size, location);
+
+        BooleanConstant::new(false).write_to_zinc_vm(state.clone());
+        let found_address = Self::store_temporary(state.clone(), Type::boolean().size(), location);
+
+        IntegerConstant::new(tag_none, false, tag_bitlength).write_to_zinc_vm(state.clone());
+        IntegerConstant::new(BigInt::zero(), false, index_bitlength)
+            .write_to_zinc_vm(state.clone());
+        let result_address = Self::store_temporary(state.clone(), result_size, location);
+
+        for index in 0..array_size {
+            Self::load(
+                state.clone(),
+                array_address + index * element_size,
+                element_size,
+                location,
+            );
+            closure.clone().write_to_zinc_vm(state.clone());
+            Self::load(
+                state.clone(),
+                found_address,
+                Type::boolean().size(),
+                location,
+            );
+            state
+                .borrow_mut()
+                .push_instruction(Instruction::Not(zinc_types::Not), Some(location));
+            state
+                .borrow_mut()
+                .push_instruction(Instruction::And(zinc_types::And), Some(location));
+            state
+                .borrow_mut()
+                .push_instruction(Instruction::If(zinc_types::If), Some(location));
+
+            BooleanConstant::new(true).write_to_zinc_vm(state.clone());
+            state.borrow_mut().push_instruction(
+                Instruction::Store(zinc_types::Store::new(
+                    found_address,
+                    Type::boolean().size(),
+                )),
+                Some(location),
+            );
+            IntegerConstant::new(tag_some.clone(), false, tag_bitlength)
+                .write_to_zinc_vm(state.clone());
+            IntegerConstant::new(BigInt::from(index), false, index_bitlength)
+                .write_to_zinc_vm(state.clone());
+            state.borrow_mut().push_instruction(
+                Instruction::Store(zinc_types::Store::new(result_address, result_size)),
+                Some(location),
+            );
+
+            state
+                .borrow_mut()
+                .push_instruction(Instruction::EndIf(zinc_types::EndIf), Some(location));
+        }
+
+        Self::load(state, result_address, result_size, location);
+    }
+
+    ///
+    /// Writes the `filter_count` sum, where each predicate result is casted to the index type
+    /// and added to the count starting with zero.
+    ///
+    fn write_filter_count(
+        state: Rc<RefCell<ZincVMState>>,
+        array_size: usize,
+        element_size: usize,
+        closure: Closure,
+        location: Location,
+    ) {
+        let index_type = zinc_types::ScalarType::Integer(zinc_types::IntegerType {
+            is_signed: false,
+            bitlength: zinc_const::bitlength::INDEX,
+        });
+
+        let array_address =
+            Self::store_temporary(state.clone(), array_size * element_size, location);
+
+        IntegerConstant::new(BigInt::zero(), false, zinc_const::bitlength::INDEX)
+            .write_to_zinc_vm(state.clone());
+        for index in 0..array_size {
+            Self::load(
+                state.clone(),
+                array_address + index * element_size,
+                element_size,
+                location,
+            );
+            closure.clone().write_to_zinc_vm(state.clone());
+            state.borrow_mut().push_instruction(
+                Instruction::Cast(zinc_types::Cast::new(index_type.clone())),
+                Some(location),
+            );
+            state
+                .borrow_mut()
+                .push_instruction(Instruction::Add(zinc_types::Add), Some(location));
+        }
+    }
+
+    ///
+    /// Stores the value of `size` on the top of the evaluation stack to a new temporary
+    /// variable, returning its address.
+    ///
+    fn store_temporary(state: Rc<RefCell<ZincVMState>>, size: usize, location: Location) -> usize {
+        let address = state.borrow_mut().define_variable(None, size);
+        state.borrow_mut().push_instruction(
+            Instruction::Store(zinc_types::Store::new(address, size)),
+            Some(location),
+        );
+        address
+    }
+
+    ///
+    /// Loads the value of `size` at `address` to the top of the evaluation stack.
+    ///
+    fn load(state: Rc<RefCell<ZincVMState>>, address: usize, size: usize, location: Location) {
+        state.borrow_mut().push_instruction(
+            Instruction::Load(zinc_types::Load::new(address, size)),
+            Some(location),
+        );
+    }
+}
@@ -2,6 +2,8 @@
 //! The generator expression operator.
 //!
 
+pub mod inline;
+
 use zinc_types::LibraryFunctionIdentifier;
 
 use crate::generator::expression::operand::place::Place;
@@ -12,6 +14,8 @@ use crate::semantic::element::access::dot::stack_field::StackField as StackField
 use crate::semantic::element::access::index::Index as IndexAccess;
 use crate::semantic::element::r#type::Type as SemanticType;
 
+use self::inline::Function as InlineFunction;
+
 ///
 /// The expression operator which is translated to some specific instructions.
 ///
@@ -283,8 +287,10 @@ pub enum Operator {
     },
     /// The `require(...)` function call operator.
     CallRequire {
-        /// The optional error description message.
+        /// The optional error description message with `{}` placeholders.
         message: Option<String>,
+        /// The interpolated argument types.
+        argument_types: Vec<Type>,
     },
     /// The `<Contract>::fetch(...)` function call operator.
     CallContractFetch {
@@ -300,6 +306,11 @@ pub enum Operator {
         /// The function result type size.
         output_size: usize,
     },
+    /// The standard library function call, which is inlined at compile time.
+    CallInline {
+        /// The inlined function with its closure.
+        function: InlineFunction,
+    },
 }
 
 impl Operator {
@@ -766,8 +777,15 @@ impl Operator {
     ///
     /// A shortcut constructor.
     ///
-    pub fn call_require(message: Option<String>) -> Self {
-        Self::CallRequire { message }
+    pub fn call_require(message: Option<String>, argument_types: Vec<SemanticType>) -> Self {
+        Self::CallRequire {
+            message,
+            argument_types: argument_types
+                .as_slice()
+                .iter()
+                .filter_map(Type::try_from_semantic)
+                .collect(),
+        }
     }
 
     ///
@@ -792,6 +810,13 @@ impl Operator {
         }
     }
 
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn call_inline(function: InlineFunction) -> Self {
+        Self::CallInline { function }
+    }
+
     ///
     /// Returns the first operand's integer inferred type, if the type was inferred for the literal
     /// to adopt the other operand's fixed type.
@@ -0,0 +1,52 @@
+//!
+//! The generator `continue` statement.
+//!
+
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use zinc_lexical::Location;
+use zinc_types::Instruction;
+
+use crate::generator::expression::operand::constant::boolean::Boolean as BooleanConstant;
+use crate::generator::r#type::Type;
+use crate::generator::zinc_vm::State as ZincVMState;
+use crate::generator::IBytecodeWritable;
+
+///
+/// The generator `continue` statement.
+///
+#[derive(Debug, Clone)]
+pub struct Statement {
+    /// The statement location in the source code.
+    pub location: Location,
+}
+
+impl Statement {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(location: Location) -> Self {
+        Self { location }
+    }
+}
+
+impl IBytecodeWritable for Statement {
+    fn write_to_zinc_vm(self, state: Rc<RefCell<ZincVMState>>) {
+        let (_broken_address, continued_address) = state
+            .borrow()
+            .top_loop()
+            .expect(zinc_const::panic::VALIDATED_DURING_SEMANTIC_ANALYSIS);
+
+        BooleanConstant::new(true).write_to_zinc_vm(state.clone());
+        state.borrow_mut().push_instruction(
+            Instruction::Store(zinc_types::Store::new(
+                continued_address,
+                Type::boolean().size(),
+            )),
+            Some(self.location),
+        );
+
+        state.borrow_mut().interrupt(false);
+    }
+}
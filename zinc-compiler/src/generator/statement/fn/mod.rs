@@ -11,6 +11,7 @@ use zinc_lexical::Location;
 use zinc_types::Instruction;
 
 use crate::generator::expression::operand::block::Expression;
+use crate::generator::expression::operand::constant::boolean::Boolean as BooleanConstant;
 use crate::generator::r#type::Type;
 use crate::generator::zinc_vm::State as ZincVMState;
 use crate::generator::IBytecodeWritable;
@@ -43,6 +44,8 @@ pub struct Statement {
     pub role: Role,
     /// The function attibutes, e.g. the unit test ones.
     pub attributes: Vec<Attribute>,
+    /// Whether the function is interrupted with a `return` statement.
+    pub is_interrupted: bool,
 }
 
 impl Statement {
@@ -60,6 +63,7 @@ impl Statement {
         type_id: usize,
         role: Role,
         attributes: Vec<Attribute>,
+        is_interrupted: bool,
     ) -> Self {
         let input_arguments = bindings
             .into_iter()
@@ -81,6 +85,7 @@ impl Statement {
             type_id,
             role,
             attributes,
+            is_interrupted,
         }
     }
 }
@@ -121,16 +126,86 @@ impl IBytecodeWritable for Statement {
         }
 
         for (name, _is_mutable, r#type) in self.input_arguments.into_iter() {
-            let size = match r#type {
-                Type::Contract { .. } => Type::eth_address().size(),
-                argument_type => argument_type.size(),
+            let r#type = match r#type {
+                Type::Contract { .. } => Type::eth_address(),
+                argument_type => argument_type,
             };
 
-            state.borrow_mut().define_variable(Some(name), size);
+            state.borrow_mut().define_named_variable(name, r#type.into());
         }
 
+        if self.is_interrupted {
+            BooleanConstant::new(false).write_to_zinc_vm(state.clone());
+            let flag_address = state
+                .borrow_mut()
+                .define_variable(None, Type::boolean().size());
+            state.borrow_mut().push_instruction(
+                Instruction::Store(zinc_types::Store::new(flag_address, Type::boolean().size())),
+                Some(self.location),
+            );
+
+            let value = if output_size > 0 {
+                let value_type = match (&self.role, &self.output_type) {
+                    (Role::ContractConstuctor { .. }, _) => self.output_type.clone(),
+                    (_, Type::Contract { .. }) => Type::eth_address(),
+                    (_, output_type) => output_type.to_owned(),
+                };
+                let value_size = state
+                    .borrow_mut()
+                    .push_zero_values(value_type, Some(self.location));
+                let value_address = state.borrow_mut().define_variable(None, value_size);
+                state.borrow_mut().push_instruction(
+                    Instruction::Store(zinc_types::Store::new(value_address, value_size)),
+                    Some(self.location),
+                );
+                Some((value_address, value_size))
+            } else {
+                None
+            };
+
+            state.borrow_mut().set_function_return(flag_address, value);
+        }
+
+        let has_expression = self.body.has_expression();
         self.body.write_to_zinc_vm(state.clone());
 
+        let function_return = state.borrow().function_return();
+        if let Some((flag_address, Some((value_address, value_size)))) = function_return {
+            if has_expression {
+                let result_address = state.borrow_mut().define_variable(None, value_size);
+                state.borrow_mut().push_instruction(
+                    Instruction::Store(zinc_types::Store::new(result_address, value_size)),
+                    Some(self.location),
+                );
+                state.borrow_mut().push_instruction(
+                    Instruction::Load(zinc_types::Load::new(flag_address, Type::boolean().size())),
+                    Some(self.location),
+                );
+                state
+                    .borrow_mut()
+                    .push_instruction(Instruction::If(zinc_types::If), Some(self.location));
+                state.borrow_mut().push_instruction(
+                    Instruction::Load(zinc_types::Load::new(value_address, value_size)),
+                    Some(self.location),
+                );
+                state
+                    .borrow_mut()
+                    .push_instruction(Instruction::Else(zinc_types::Else), Some(self.location));
+                state.borrow_mut().push_instruction(
+                    Instruction::Load(zinc_types::Load::new(result_address, value_size)),
+                    Some(self.location),
+                );
+                state
+                    .borrow_mut()
+                    .push_instruction(Instruction::EndIf(zinc_types::EndIf), Some(self.location));
+            } else {
+                state.borrow_mut().push_instruction(
+                    Instruction::Load(zinc_types::Load::new(value_address, value_size)),
+                    Some(self.location),
+                );
+            }
+        }
+
         match self.role {
             Role::ContractConstuctor { project, .. } => {
                 let field_types: Vec<zinc_types::ContractFieldType> = match self.output_type {
@@ -42,6 +42,8 @@ pub struct Statement {
     pub while_condition: Option<GeneratorExpression>,
     /// The loop body.
     pub body: BlockExpression,
+    /// Whether the loop is interrupted with a `return`, `break`, or `continue` statement.
+    pub is_interrupted: bool,
 }
 
 impl Statement {
@@ -59,6 +61,7 @@ impl Statement {
         index_variable_bitlength: usize,
         while_condition: Option<GeneratorExpression>,
         body: BlockExpression,
+        is_interrupted: bool,
     ) -> Self {
         Self {
             location,
@@ -70,6 +73,26 @@ impl Statement {
             index_variable_bitlength,
             while_condition,
             body,
+            is_interrupted,
+        }
+    }
+
+    ///
+    /// Writes the loop body, which is skipped if the loop is interrupted.
+    ///
+    fn write_body(
+        body: BlockExpression,
+        is_interrupted: bool,
+        location: Location,
+        state: Rc<RefCell<ZincVMState>>,
+    ) {
+        if is_interrupted && state.borrow_mut().push_interruption_guard(Some(location)) {
+            body.write_to_zinc_vm(state.clone());
+            state
+                .borrow_mut()
+                .push_instruction(Instruction::EndIf(zinc_types::EndIf), Some(location));
+        } else {
+            body.write_to_zinc_vm(state);
         }
     }
 }
@@ -81,7 +104,7 @@ impl IBytecodeWritable for Statement {
         let index_size = index_type.size();
         let index_address = state
             .borrow_mut()
-            .define_variable(Some(self.index_variable_name), index_size);
+            .define_named_variable(self.index_variable_name, index_type.clone().into());
         IntegerConstant::new(
             self.initial_value.clone(),
             self.index_variable_is_signed,
@@ -108,11 +131,46 @@ impl IBytecodeWritable for Statement {
             None
         };
 
+        let continued_address = if self.is_interrupted {
+            let broken_address = state
+                .borrow_mut()
+                .define_variable(None, Type::boolean().size());
+            BooleanConstant::new(false).write_to_zinc_vm(state.clone());
+            state.borrow_mut().push_instruction(
+                Instruction::Store(zinc_types::Store::new(
+                    broken_address,
+                    Type::boolean().size(),
+                )),
+                Some(self.location),
+            );
+
+            let continued_address = state
+                .borrow_mut()
+                .define_variable(None, Type::boolean().size());
+            state
+                .borrow_mut()
+                .push_loop(broken_address, continued_address);
+            Some(continued_address)
+        } else {
+            None
+        };
+
         state.borrow_mut().push_instruction(
             Instruction::LoopBegin(zinc_types::LoopBegin::new(self.iterations_count)),
             Some(self.location),
         );
 
+        if let Some(continued_address) = continued_address {
+            BooleanConstant::new(false).write_to_zinc_vm(state.clone());
+            state.borrow_mut().push_instruction(
+                Instruction::Store(zinc_types::Store::new(
+                    continued_address,
+                    Type::boolean().size(),
+                )),
+                Some(self.location),
+            );
+        }
+
         if let (Some(while_condition), Some(while_allowed_address)) =
             (self.while_condition, while_allowed_address)
         {
@@ -145,12 +203,12 @@ impl IBytecodeWritable for Statement {
             state
                 .borrow_mut()
                 .push_instruction(Instruction::If(zinc_types::If), Some(self.location));
-            self.body.write_to_zinc_vm(state.clone());
+            Self::write_body(self.body, self.is_interrupted, self.location, state.clone());
             state
                 .borrow_mut()
                 .push_instruction(Instruction::EndIf(zinc_types::EndIf), Some(self.location));
         } else {
-            self.body.write_to_zinc_vm(state.clone());
+            Self::write_body(self.body, self.is_interrupted, self.location, state.clone());
         }
 
         if self.is_reversed {
@@ -224,5 +282,9 @@ impl IBytecodeWritable for Statement {
             Instruction::LoopEnd(zinc_types::LoopEnd),
             Some(self.location),
         );
+
+        if self.is_interrupted {
+            state.borrow_mut().pop_loop();
+        }
     }
 }
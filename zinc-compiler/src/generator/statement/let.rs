@@ -55,7 +55,7 @@ impl IBytecodeWritable for Statement {
                     let size = Type::eth_address().size();
                     let address = state
                         .borrow_mut()
-                        .define_variable(Some(binding.identifier.name), size);
+                        .define_named_variable(binding.identifier.name, Type::eth_address().into());
                     state.borrow_mut().push_instruction(
                         Instruction::Store(zinc_types::Store::new(address, size)),
                         Some(self.location),
@@ -63,14 +63,13 @@ impl IBytecodeWritable for Statement {
                 }
                 r#type => {
                     let size = r#type.size();
-                    let address = state.borrow_mut().define_variable(
-                        if !binding.is_wildcard {
-                            Some(binding.identifier.name)
-                        } else {
-                            None
-                        },
-                        size,
-                    );
+                    let address = if !binding.is_wildcard {
+                        state
+                            .borrow_mut()
+                            .define_named_variable(binding.identifier.name, r#type.clone().into())
+                    } else {
+                        state.borrow_mut().define_variable(None, size)
+                    };
 
                     if let Some(scalar_type) = r#type.into() {
                         state.borrow_mut().push_instruction(
@@ -0,0 +1,65 @@
+//!
+//! The generator `return` statement.
+//!
+
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use zinc_lexical::Location;
+use zinc_types::Instruction;
+
+use crate::generator::expression::operand::constant::boolean::Boolean as BooleanConstant;
+use crate::generator::expression::Expression;
+use crate::generator::r#type::Type;
+use crate::generator::zinc_vm::State as ZincVMState;
+use crate::generator::IBytecodeWritable;
+
+///
+/// The generator `return` statement.
+///
+#[derive(Debug, Clone)]
+pub struct Statement {
+    /// The statement location in the source code.
+    pub location: Location,
+    /// The optional returned expression.
+    pub expression: Option<Expression>,
+}
+
+impl Statement {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(location: Location, expression: Option<Expression>) -> Self {
+        Self {
+            location,
+            expression,
+        }
+    }
+}
+
+impl IBytecodeWritable for Statement {
+    fn write_to_zinc_vm(self, state: Rc<RefCell<ZincVMState>>) {
+        let (flag_address, value) = state
+            .borrow()
+            .function_return()
+            .expect(zinc_const::panic::VALIDATED_DURING_SEMANTIC_ANALYSIS);
+
+        if let Some(expression) = self.expression {
+            expression.write_to_zinc_vm(state.clone());
+        }
+        if let Some((value_address, value_size)) = value {
+            state.borrow_mut().push_instruction(
+                Instruction::Store(zinc_types::Store::new(value_address, value_size)),
+                Some(self.location),
+            );
+        }
+
+        BooleanConstant::new(true).write_to_zinc_vm(state.clone());
+        state.borrow_mut().push_instruction(
+            Instruction::Store(zinc_types::Store::new(flag_address, Type::boolean().size())),
+            Some(self.location),
+        );
+
+        state.borrow_mut().interrupt(true);
+    }
+}
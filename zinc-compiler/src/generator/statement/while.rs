@@ -0,0 +1,254 @@
+//!
+//! The generator `while` statement.
+//!
+
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use zinc_lexical::Location;
+use zinc_types::Instruction;
+
+use crate::generator::expression::operand::block::Expression as BlockExpression;
+use crate::generator::expression::operand::constant::boolean::Boolean as BooleanConstant;
+use crate::generator::expression::Expression as GeneratorExpression;
+use crate::generator::r#type::Type;
+use crate::generator::zinc_vm::State as ZincVMState;
+use crate::generator::IBytecodeWritable;
+
+///
+/// The generator `while` statement.
+///
+#[derive(Debug, Clone)]
+pub struct Statement {
+    /// The statement location in the source code.
+    pub location: Location,
+    /// The loop condition, which suppresses the rest of the loop side effects once false.
+    pub condition: GeneratorExpression,
+    /// The maximal number of loop iterations.
+    pub iterations_count: usize,
+    /// The loop body.
+    pub body: BlockExpression,
+    /// Whether the loop is interrupted with a `return`, `break`, or `continue` statement.
+    pub is_interrupted: bool,
+}
+
+impl Statement {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(
+        location: Location,
+        condition: GeneratorExpression,
+        iterations_count: usize,
+        body: BlockExpression,
+        is_interrupted: bool,
+    ) -> Self {
+        Self {
+            location,
+            condition,
+            iterations_count,
+            body,
+            is_interrupted,
+        }
+    }
+
+    ///
+    /// Writes the loop body, which is skipped if the loop is interrupted.
+    ///
+    fn write_body(
+        body: BlockExpression,
+        is_interrupted: bool,
+        location: Location,
+        state: Rc<RefCell<ZincVMState>>,
+    ) {
+        if is_interrupted && state.borrow_mut().push_interruption_guard(Some(location)) {
+            body.write_to_zinc_vm(state.clone());
+            state
+                .borrow_mut()
+                .push_instruction(Instruction::EndIf(zinc_types::EndIf), Some(location));
+        } else {
+            body.write_to_zinc_vm(state);
+        }
+    }
+
+    ///
+    /// Writes the check failing if the condition is still true after the last iteration.
+    ///
+    /// The check is skipped if the loop has been finished with `break` or `return`.
+    ///
+    fn write_bound_check(
+        condition: GeneratorExpression,
+        iterations_count: usize,
+        allowed_address: usize,
+        broken_address: Option<usize>,
+        location: Location,
+        state: Rc<RefCell<ZincVMState>>,
+    ) {
+        state.borrow_mut().push_instruction(
+            Instruction::Load(zinc_types::Load::new(
+                allowed_address,
+                Type::boolean().size(),
+            )),
+            Some(location),
+        );
+
+        let returned_address = state
+            .borrow()
+            .function_return()
+            .map(|(flag_address, _value)| flag_address);
+        for address in broken_address.into_iter().chain(returned_address) {
+            state.borrow_mut().push_instruction(
+                Instruction::Load(zinc_types::Load::new(address, Type::boolean().size())),
+                Some(location),
+            );
+            state
+                .borrow_mut()
+                .push_instruction(Instruction::Not(zinc_types::Not), Some(location));
+            state
+                .borrow_mut()
+                .push_instruction(Instruction::And(zinc_types::And), Some(location));
+        }
+
+        state
+            .borrow_mut()
+            .push_instruction(Instruction::If(zinc_types::If), Some(location));
+        condition.write_to_zinc_vm(state.clone());
+        state
+            .borrow_mut()
+            .push_instruction(Instruction::Not(zinc_types::Not), Some(location));
+        state.borrow_mut().push_instruction(
+            Instruction::Require(zinc_types::Require::new(
+                Some(format!(
+                    "the `while` loop bound of {} iterations is exhausted",
+                    iterations_count
+                )),
+                vec![],
+            )),
+            Some(location),
+        );
+        state
+            .borrow_mut()
+            .push_instruction(Instruction::EndIf(zinc_types::EndIf), Some(location));
+    }
+}
+
+impl IBytecodeWritable for Statement {
+    fn write_to_zinc_vm(self, state: Rc<RefCell<ZincVMState>>) {
+        let allowed_address = state
+            .borrow_mut()
+            .define_variable(None, Type::boolean().size());
+        BooleanConstant::new(true).write_to_zinc_vm(state.clone());
+        state.borrow_mut().push_instruction(
+            Instruction::Store(zinc_types::Store::new(
+                allowed_address,
+                Type::boolean().size(),
+            )),
+            Some(self.location),
+        );
+
+        let (broken_address, continued_address) = if self.is_interrupted {
+            let broken_address = state
+                .borrow_mut()
+                .define_variable(None, Type::boolean().size());
+            BooleanConstant::new(false).write_to_zinc_vm(state.clone());
+            state.borrow_mut().push_instruction(
+                Instruction::Store(zinc_types::Store::new(
+                    broken_address,
+                    Type::boolean().size(),
+                )),
+                Some(self.location),
+            );
+
+            let continued_address = state
+                .borrow_mut()
+                .define_variable(None, Type::boolean().size());
+            state
+                .borrow_mut()
+                .push_loop(broken_address, continued_address);
+            (Some(broken_address), Some(continued_address))
+        } else {
+            (None, None)
+        };
+
+        state.borrow_mut().push_instruction(
+            Instruction::LoopBegin(zinc_types::LoopBegin::new(self.iterations_count)),
+            Some(self.location),
+        );
+
+        if let Some(continued_address) = continued_address {
+            BooleanConstant::new(false).write_to_zinc_vm(state.clone());
+            state.borrow_mut().push_instruction(
+                Instruction::Store(zinc_types::Store::new(
+                    continued_address,
+                    Type::boolean().size(),
+                )),
+                Some(self.location),
+            );
+        }
+
+        state.borrow_mut().push_instruction(
+            Instruction::Load(zinc_types::Load::new(
+                allowed_address,
+                Type::boolean().size(),
+            )),
+            Some(self.location),
+        );
+        state
+            .borrow_mut()
+            .push_instruction(Instruction::If(zinc_types::If), Some(self.location));
+        self.condition.clone().write_to_zinc_vm(state.clone());
+        state
+            .borrow_mut()
+            .push_instruction(Instruction::Not(zinc_types::Not), Some(self.location));
+        state
+            .borrow_mut()
+            .push_instruction(Instruction::If(zinc_types::If), Some(self.location));
+        BooleanConstant::new(false).write_to_zinc_vm(state.clone());
+        state.borrow_mut().push_instruction(
+            Instruction::Store(zinc_types::Store::new(
+                allowed_address,
+                Type::boolean().size(),
+            )),
+            Some(self.location),
+        );
+        state
+            .borrow_mut()
+            .push_instruction(Instruction::EndIf(zinc_types::EndIf), Some(self.location));
+        state
+            .borrow_mut()
+            .push_instruction(Instruction::EndIf(zinc_types::EndIf), Some(self.location));
+
+        state.borrow_mut().push_instruction(
+            Instruction::Load(zinc_types::Load::new(
+                allowed_address,
+                Type::boolean().size(),
+            )),
+            Some(self.location),
+        );
+        state
+            .borrow_mut()
+            .push_instruction(Instruction::If(zinc_types::If), Some(self.location));
+        Self::write_body(self.body, self.is_interrupted, self.location, state.clone());
+        state
+            .borrow_mut()
+            .push_instruction(Instruction::EndIf(zinc_types::EndIf), Some(self.location));
+
+        state.borrow_mut().push_instruction(
+            Instruction::LoopEnd(zinc_types::LoopEnd),
+            Some(self.location),
+        );
+
+        if self.is_interrupted {
+            state.borrow_mut().pop_loop();
+        }
+
+        Self::write_bound_check(
+            self.condition,
+            self.iterations_count,
+            allowed_address,
+            broken_address,
+            self.location,
+            state,
+        );
+    }
+}
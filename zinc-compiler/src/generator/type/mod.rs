@@ -6,6 +6,7 @@ pub mod contract_field;
 
 use num::BigInt;
 
+use crate::semantic::element::r#type::enumeration::payload::Payload as EnumerationPayload;
 use crate::semantic::element::r#type::Type as SemanticType;
 use crate::semantic::scope::intrinsic::IntrinsicTypeId;
 
@@ -42,6 +43,8 @@ pub enum Type {
         bitlength: usize,
         /// The enumeration variant list.
         variants: Vec<(String, BigInt)>,
+        /// The variant payload types, which are empty for the C-like enumerations.
+        payloads: Vec<Self>,
     },
     /// The IR array type.
     Array {
@@ -133,10 +136,15 @@ impl Type {
     ///
     /// A shortcut constructor.
     ///
-    pub fn enumeration(bitlength: usize, variants: Vec<(String, BigInt)>) -> Self {
+    pub fn enumeration(
+        bitlength: usize,
+        variants: Vec<(String, BigInt)>,
+        payloads: Vec<Self>,
+    ) -> Self {
         Self::Enumeration {
             bitlength,
             variants,
+            payloads,
         }
     }
 
@@ -191,7 +199,9 @@ impl Type {
             Self::IntegerUnsigned { .. } => 1,
             Self::IntegerSigned { .. } => 1,
             Self::Field => 1,
-            Self::Enumeration { .. } => 1,
+            Self::Enumeration { payloads, .. } => {
+                1 + payloads.iter().map(Self::size).max().unwrap_or_default()
+            }
             Self::Array { r#type, size } => r#type.size() * size,
             Self::Tuple { types } => types.iter().map(|r#type| r#type.size()).sum(),
             Self::Structure { fields } => fields.iter().map(|(_name, r#type)| r#type.size()).sum(),
@@ -264,6 +274,27 @@ impl Type {
                     .into_iter()
                     .zip(inner.values.to_owned())
                     .collect::<Vec<(String, BigInt)>>(),
+                inner
+                    .payloads
+                    .to_owned()
+                    .unwrap_or_default()
+                    .iter()
+                    .map(|payload| match payload {
+                        EnumerationPayload::Unit => Some(Self::unit()),
+                        EnumerationPayload::Tuple(types) => types
+                            .iter()
+                            .map(Self::try_from_semantic)
+                            .collect::<Option<Vec<Self>>>()
+                            .map(Self::tuple),
+                        EnumerationPayload::Structure(fields) => fields
+                            .iter()
+                            .map(|(name, r#type)| {
+                                Self::try_from_semantic(r#type).map(|r#type| (name.to_owned(), r#type))
+                            })
+                            .collect::<Option<Vec<(String, Self)>>>()
+                            .map(Self::structure),
+                    })
+                    .collect::<Option<Vec<Self>>>()?,
             )),
             SemanticType::Contract(inner) => {
                 match inner
@@ -280,6 +311,7 @@ impl Type {
             SemanticType::Range(_) => None,
             SemanticType::RangeInclusive(_) => None,
             SemanticType::Function(_) => None,
+            SemanticType::Trait(_) => None,
         }
     }
 }
@@ -305,9 +337,11 @@ impl Into<zinc_types::Type> for Type {
             Self::Enumeration {
                 bitlength,
                 variants,
+                payloads,
             } => zinc_types::Type::Enumeration {
                 bitlength,
                 variants,
+                payloads: payloads.into_iter().map(|r#type| r#type.into()).collect(),
             },
             Self::Array { r#type, size } => {
                 let element_type: zinc_types::Type = (*r#type).into();
@@ -10,6 +10,9 @@ use std::cell::RefCell;
 use std::collections::HashMap;
 use std::rc::Rc;
 
+use num::BigInt;
+use num::Zero;
+
 use zinc_lexical::Location;
 use zinc_lexical::FILE_INDEX;
 use zinc_types::Instruction;
@@ -47,6 +50,13 @@ pub struct State {
     data_stack_pointer: usize,
     /// The location pointer used to pass debug information to the VM.
     current_location: Location,
+
+    /// The current function `return` flag address and the optional return value address and size.
+    function_return: Option<(usize, Option<(usize, usize)>)>,
+    /// The `break` and `continue` flag addresses of the interrupted loops being written.
+    loops: Vec<(usize, usize)>,
+    /// The interruption counters of the current function and the interrupted loops being written.
+    interruptions: Vec<usize>,
 }
 
 impl State {
@@ -65,6 +75,9 @@ impl State {
     /// The application unit tests hashmap default capacity.
     const UNIT_TESTS_INITIAL_CAPACITY: usize = 16;
 
+    /// The interrupted loop stack default capacity.
+    const LOOPS_INITIAL_CAPACITY: usize = 4;
+
     ///
     /// Creates a new bytecode generator state instance.
     ///
@@ -81,6 +94,10 @@ impl State {
             variable_addresses: HashMap::with_capacity(Self::VARIABLE_ADDRESSES_INITIAL_CAPACITY),
             data_stack_pointer: 0,
             current_location: Location::default(),
+
+            function_return: None,
+            loops: Vec::with_capacity(Self::LOOPS_INITIAL_CAPACITY),
+            interruptions: Vec::with_capacity(Self::LOOPS_INITIAL_CAPACITY + 1),
         }
     }
 
@@ -107,6 +124,21 @@ impl State {
         self.variable_addresses.get(name).copied()
     }
 
+    ///
+    /// Returns the variable addresses, which are visible at the current point of the function.
+    ///
+    pub fn variable_addresses(&self) -> HashMap<String, usize> {
+        self.variable_addresses.clone()
+    }
+
+    ///
+    /// Restores the variable addresses, e.g. hiding the parameters of an inlined closure, which
+    /// could have shadowed the outer variables.
+    ///
+    pub fn set_variable_addresses(&mut self, value: HashMap<String, usize>) {
+        self.variable_addresses = value;
+    }
+
     ///
     /// Sets the contract storage field types.
     ///
@@ -122,6 +154,10 @@ impl State {
         let address = self.instructions.len();
         self.function_addresses.insert(type_id, address);
         self.data_stack_pointer = 0;
+        self.function_return = None;
+        self.loops.clear();
+        self.interruptions.clear();
+        self.interruptions.push(0);
 
         self.instructions
             .push(Instruction::FileMarker(zinc_types::FileMarker::new(
@@ -213,6 +249,137 @@ impl State {
         start_address
     }
 
+    ///
+    /// Defines a named variable like `define_variable` and writes the variable debug marker,
+    /// which is used by the debuggers to print the variable value.
+    ///
+    pub fn define_named_variable(&mut self, identifier: String, r#type: zinc_types::Type) -> usize {
+        self.instructions.push(Instruction::VariableMarker(
+            zinc_types::VariableMarker::new(
+                identifier.clone(),
+                self.data_stack_pointer,
+                r#type.clone(),
+            ),
+        ));
+
+        self.define_variable(Some(identifier), r#type.size())
+    }
+
+    ///
+    /// Sets the `return` flag address and the optional return value address and size of the
+    /// current function.
+    ///
+    pub fn set_function_return(&mut self, flag_address: usize, value: Option<(usize, usize)>) {
+        self.function_return = Some((flag_address, value));
+    }
+
+    ///
+    /// Returns the `return` flag address and the optional return value address and size of the
+    /// current function.
+    ///
+    pub fn function_return(&self) -> Option<(usize, Option<(usize, usize)>)> {
+        self.function_return
+    }
+
+    ///
+    /// Starts an interrupted loop with the `break` and `continue` flag addresses.
+    ///
+    pub fn push_loop(&mut self, broken_address: usize, continued_address: usize) {
+        self.loops.push((broken_address, continued_address));
+        self.interruptions.push(0);
+    }
+
+    ///
+    /// Finishes the innermost interrupted loop.
+    ///
+    pub fn pop_loop(&mut self) {
+        self.loops.pop();
+        self.interruptions.pop();
+    }
+
+    ///
+    /// Returns the `break` and `continue` flag addresses of the innermost interrupted loop.
+    ///
+    pub fn top_loop(&self) -> Option<(usize, usize)> {
+        self.loops.last().copied()
+    }
+
+    ///
+    /// Registers an interruption written to the bytecode.
+    ///
+    /// The `return` statement interrupts the function and all the loops being written, whereas
+    /// `break` and `continue` only interrupt the innermost loop.
+    ///
+    pub fn interrupt(&mut self, is_function: bool) {
+        if is_function {
+            for counter in self.interruptions.iter_mut() {
+                *counter += 1;
+            }
+        } else if let Some(counter) = self.interruptions.last_mut() {
+            *counter += 1;
+        }
+    }
+
+    ///
+    /// Returns the number of interruptions written in the innermost function or loop.
+    ///
+    pub fn interruptions_count(&self) -> usize {
+        self.interruptions.last().copied().unwrap_or_default()
+    }
+
+    ///
+    /// Writes the condition checking that neither the function nor the innermost loop has been
+    /// interrupted, and opens the conditional block, which must be closed by the caller.
+    ///
+    /// Returns `false` if there are no interruption flags, so nothing is written.
+    ///
+    pub fn push_interruption_guard(&mut self, location: Option<Location>) -> bool {
+        let mut flags = Vec::with_capacity(3);
+        if let Some((flag_address, _)) = self.function_return {
+            flags.push(flag_address);
+        }
+        if let Some((broken_address, continued_address)) = self.top_loop() {
+            flags.push(broken_address);
+            flags.push(continued_address);
+        }
+        if flags.is_empty() {
+            return false;
+        }
+
+        for (index, address) in flags.into_iter().enumerate() {
+            self.push_instruction(
+                Instruction::Load(zinc_types::Load::new(address, Type::boolean().size())),
+                location,
+            );
+            if index > 0 {
+                self.push_instruction(Instruction::Or(zinc_types::Or), location);
+            }
+        }
+        self.push_instruction(Instruction::Not(zinc_types::Not), location);
+        self.push_instruction(Instruction::If(zinc_types::If), location);
+
+        true
+    }
+
+    ///
+    /// Writes the zero values of the `type` scalar components, which are used as the default
+    /// results of the interrupted blocks and functions.
+    ///
+    /// Returns the number of the written values.
+    ///
+    pub fn push_zero_values(&mut self, r#type: Type, location: Option<Location>) -> usize {
+        let r#type: zinc_types::Type = r#type.into();
+        let scalar_types = r#type.into_flat_scalar_types();
+        let size = scalar_types.len();
+        for scalar_type in scalar_types.into_iter() {
+            self.push_instruction(
+                Instruction::Push(zinc_types::Push::new(BigInt::zero(), scalar_type)),
+                location,
+            );
+        }
+        size
+    }
+
     ///
     /// Writes the instruction along with its location debug information.
     ///
@@ -332,6 +499,7 @@ impl State {
                     methods,
                     unit_tests,
                     self.instructions,
+                    self.manifest.project.storage_hasher.unwrap_or_default(),
                 )
             }
             None if !self.entries.is_empty() => {
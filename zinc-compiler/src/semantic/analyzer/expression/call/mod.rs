@@ -14,6 +14,7 @@ use crate::generator::expression::operand::constant::Constant as GeneratorConsta
 use crate::generator::expression::operand::Operand as GeneratorExpressionOperand;
 use crate::generator::expression::operator::Operator as GeneratorExpressionOperator;
 use crate::generator::r#type::contract_field::ContractField as GeneratorContractField;
+use crate::semantic::analyzer::expression::variant::Analyzer as VariantAnalyzer;
 use crate::semantic::element::r#type::function::intrinsic::Function as IntrinsicFunctionType;
 use crate::semantic::element::r#type::function::Function as FunctionType;
 use crate::semantic::element::r#type::Type;
@@ -45,6 +46,25 @@ impl Analyzer {
     ) -> Result<(Element, GeneratorExpressionElement), Error> {
         let function_location = operand_1.location();
 
+        if let Element::Path(ref path) = operand_1 {
+            if let Some((enumeration, index, _tag)) = VariantAnalyzer::resolve(scope.clone(), path)?
+            {
+                let arguments = match operand_2 {
+                    Element::ArgumentList(values) => values.arguments,
+                    _ => panic!(zinc_const::panic::VALIDATED_DURING_SYNTAX_ANALYSIS),
+                };
+
+                let (element, padding) =
+                    VariantAnalyzer::tuple(scope, location, enumeration, index, arguments)?;
+                return Ok((
+                    element,
+                    GeneratorExpressionElement::Operand(GeneratorExpressionOperand::Constant(
+                        padding,
+                    )),
+                ));
+            }
+        }
+
         let function = match operand_1 {
             Element::Type(Type::Function(function)) => function,
             Element::Path(path) => match *Scope::resolve_path(scope.clone(), &path)?.borrow() {
@@ -98,8 +118,24 @@ impl Analyzer {
             }
         }
 
+        let function = match function {
+            FunctionType::Generic(function) => {
+                let mut argument_types = Vec::with_capacity(argument_list.arguments.len());
+                for element in argument_list.arguments.iter() {
+                    argument_types.push(Type::from_element(element, scope.clone())?);
+                }
+
+                FunctionType::Runtime(function.instantiate(location, argument_types)?)
+            }
+            function => function,
+        };
+
         let mut input_size = 0;
         for element in argument_list.arguments.iter() {
+            if let Element::Closure(_) = element {
+                continue;
+            }
+
             input_size += Type::from_element(element, scope.clone())?.size();
         }
 
@@ -132,13 +168,14 @@ impl Analyzer {
                         )
                     }
                     IntrinsicFunctionType::Require(function) => {
-                        let (return_type, message) =
+                        let (return_type, message, argument_types) =
                             function.call(function_location.unwrap_or(location), argument_list)?;
 
                         let element =
                             Value::try_from_type(&return_type, false, None).map(Element::Value)?;
 
-                        let intermediate = GeneratorExpressionOperator::call_require(message);
+                        let intermediate =
+                            GeneratorExpressionOperator::call_require(message, argument_types);
 
                         (
                             element,
@@ -208,17 +245,21 @@ impl Analyzer {
 
                         let intrinsic_identifier = function.library_identifier();
 
-                        let return_type =
+                        let (return_type, inline_function) =
                             function.call(function_location.unwrap_or(location), argument_list)?;
 
                         let element =
                             Value::try_from_type(&return_type, false, None).map(Element::Value)?;
 
-                        let intermediate = GeneratorExpressionOperator::call_library(
-                            intrinsic_identifier,
-                            input_size,
-                            return_type.size(),
-                        );
+                        let intermediate = match inline_function {
+                            Some(function) => GeneratorExpressionOperator::call_inline(function),
+                            None => GeneratorExpressionOperator::call_library(
+                                intrinsic_identifier
+                                    .expect(zinc_const::panic::VALIDATED_DURING_SEMANTIC_ANALYSIS),
+                                input_size,
+                                return_type.size(),
+                            ),
+                        };
 
                         (
                             element,
@@ -238,7 +279,6 @@ impl Analyzer {
                     });
                 }
 
-                let location = function.location;
                 let type_id = function.type_id;
 
                 let return_type = function.call(argument_list)?;
@@ -277,6 +317,9 @@ impl Analyzer {
                     GeneratorExpressionElement::Operand(intermediate),
                 )
             }
+            FunctionType::Generic(_) => {
+                panic!(zinc_const::panic::VALIDATED_DURING_SEMANTIC_ANALYSIS)
+            }
             FunctionType::Test(function) => {
                 return Err(Error::UnitTestCallForbidden {
                     location: function_location.unwrap_or(location),
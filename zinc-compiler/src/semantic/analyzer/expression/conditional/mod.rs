@@ -12,6 +12,7 @@ use zinc_syntax::ConditionalExpression;
 
 use crate::generator::expression::operand::conditional::builder::Builder as GeneratorConditionalExpressionBuilder;
 use crate::generator::expression::operand::Operand as GeneratorExpressionOperand;
+use crate::generator::r#type::Type as GeneratorType;
 use crate::semantic::analyzer::expression::block::Analyzer as BlockAnalyzer;
 use crate::semantic::analyzer::expression::Analyzer as ExpressionAnalyzer;
 use crate::semantic::analyzer::rule::Rule as TranslationRule;
@@ -98,27 +99,47 @@ impl Analyzer {
         builder.set_condition(condition);
 
         scope_stack.push(None, ScopeType::Conditional);
-        let (main_result, main_block) = BlockAnalyzer::analyze(
+        let (mut main_result, mut main_block) = BlockAnalyzer::analyze(
             scope_stack.top(),
             conditional.main_block,
             TranslationRule::Value,
         )?;
-        let main_type = Type::from_element(&main_result, scope_stack.top())?;
+        let mut main_type = Type::from_element(&main_result, scope_stack.top())?;
         scope_stack.pop();
-        builder.set_main_block(main_block);
 
         let else_type = if let Some(else_block) = conditional.else_block {
             scope_stack.push(None, ScopeType::Conditional);
-            let (else_result, else_block) =
+            let (mut else_result, mut else_block) =
                 BlockAnalyzer::analyze(scope_stack.top(), else_block, TranslationRule::Value)?;
-            let else_type = Type::from_element(&else_result, scope_stack.top())?;
+            let mut else_type = Type::from_element(&else_result, scope_stack.top())?;
             scope_stack.pop();
+
+            let (main_expected, else_expected) =
+                match main_result.unify(&else_result, main_expression_location)? {
+                    Some(r#type) => (r#type.clone(), r#type),
+                    None => (else_type.clone(), main_type.clone()),
+                };
+            if let Some(padding) = else_result.coerce(&else_expected) {
+                if let Some(r#type) = GeneratorType::try_from_semantic(&else_expected) {
+                    else_block.coerce(padding, r#type);
+                }
+                else_type = else_expected;
+            }
+            if let Some(padding) = main_result.coerce(&main_expected) {
+                if let Some(r#type) = GeneratorType::try_from_semantic(&main_expected) {
+                    main_block.coerce(padding, r#type);
+                }
+                main_type = main_expected;
+            }
+            else_result.validate_complete(else_expression_location)?;
             builder.set_else_block(else_block);
 
             else_type
         } else {
             Type::unit(None)
         };
+        main_result.validate_complete(main_expression_location)?;
+        builder.set_main_block(main_block);
 
         if main_type != else_type {
             return Err(Error::ConditionalBranchTypesMismatch {
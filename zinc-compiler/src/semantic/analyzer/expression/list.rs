@@ -5,6 +5,8 @@
 use std::cell::RefCell;
 use std::rc::Rc;
 
+use zinc_syntax::ExpressionOperand;
+use zinc_syntax::ExpressionTreeNode;
 use zinc_syntax::ListExpression;
 
 use crate::generator::expression::operand::list::builder::Builder as GeneratorListExpressionBuilder;
@@ -12,6 +14,7 @@ use crate::generator::expression::operand::Operand as GeneratorExpressionOperand
 use crate::semantic::analyzer::expression::Analyzer as ExpressionAnalyzer;
 use crate::semantic::analyzer::rule::Rule as TranslationRule;
 use crate::semantic::element::argument_list::ArgumentList as ArgumentListElement;
+use crate::semantic::element::closure::Closure as ClosureElement;
 use crate::semantic::element::Element;
 use crate::semantic::error::Error;
 use crate::semantic::scope::Scope;
@@ -52,6 +55,16 @@ impl Analyzer {
         let mut builder = GeneratorListExpressionBuilder::default();
 
         for expression in list.elements.into_iter() {
+            if let ExpressionTreeNode::Operand(ExpressionOperand::Closure(ref closure)) =
+                *expression.value
+            {
+                arguments.push(Element::Closure(ClosureElement::new(
+                    scope.clone(),
+                    closure.to_owned(),
+                )));
+                continue;
+            }
+
             let (element, expression) =
                 ExpressionAnalyzer::new(scope.clone(), TranslationRule::Value)
                     .analyze(expression)?;
@@ -4,16 +4,20 @@
 
 use std::convert::TryFrom;
 
+use num::BigInt;
+
 use zinc_syntax::BooleanLiteral;
 use zinc_syntax::IntegerLiteral;
 use zinc_syntax::StringLiteral;
 
 use crate::generator::expression::operand::constant::Constant as GeneratorConstant;
 use crate::generator::expression::operand::Operand as GeneratorExpressionOperand;
+use crate::semantic::element::constant::array::Array as ArrayConstant;
 use crate::semantic::element::constant::boolean::Boolean as BooleanConstant;
 use crate::semantic::element::constant::integer::Integer as IntegerConstant;
 use crate::semantic::element::constant::string::String as StringConstant;
 use crate::semantic::element::constant::Constant;
+use crate::semantic::element::r#type::Type;
 use crate::semantic::element::Element;
 use crate::semantic::error::Error;
 
@@ -66,4 +70,41 @@ impl Analyzer {
             literal.into(),
         ))))
     }
+
+    ///
+    /// Analyzes the byte string literal, which is converted to a constant array of `u8`.
+    ///
+    /// Returns the semantic element and the intermediate representation if it is available.
+    ///
+    pub fn byte_string(
+        literal: StringLiteral,
+    ) -> Result<(Element, Option<GeneratorExpressionOperand>), Error> {
+        let location = literal.location;
+        let string: String = literal.into();
+
+        let values = string
+            .into_bytes()
+            .into_iter()
+            .map(|byte| {
+                Constant::Integer(IntegerConstant::new(
+                    location,
+                    BigInt::from(byte),
+                    false,
+                    zinc_const::bitlength::BYTE,
+                    false,
+                ))
+            })
+            .collect();
+        let constant = Constant::Array(ArrayConstant::new_with_values(
+            location,
+            Type::integer_unsigned(None, zinc_const::bitlength::BYTE),
+            values,
+        ));
+
+        let intermediate = GeneratorConstant::try_from_semantic(&constant)
+            .map(GeneratorExpressionOperand::Constant);
+        let element = Element::Constant(constant);
+
+        Ok((element, intermediate))
+    }
 }
@@ -0,0 +1,576 @@
+//!
+//! The match pattern destructuring.
+//!
+
+use std::cell::RefCell;
+use std::convert::TryFrom;
+use std::rc::Rc;
+
+use num::BigInt;
+use num::One;
+
+use zinc_lexical::Location;
+use zinc_syntax::ExpressionTree;
+use zinc_syntax::Identifier;
+use zinc_syntax::IntegerLiteral;
+use zinc_syntax::MatchPattern;
+use zinc_syntax::MatchPatternVariant;
+
+use crate::generator::expression::operand::constant::integer::Integer as GeneratorIntegerConstant;
+use crate::generator::expression::operand::constant::Constant as GeneratorConstant;
+use crate::generator::expression::operand::r#match::pattern::Pattern as GeneratorPattern;
+use crate::semantic::analyzer::expression::variant::Analyzer as VariantAnalyzer;
+use crate::semantic::analyzer::expression::Analyzer as ExpressionAnalyzer;
+use crate::semantic::analyzer::rule::Rule as TranslationRule;
+use crate::semantic::casting::Caster;
+use crate::semantic::element::constant::boolean::Boolean as BooleanConstant;
+use crate::semantic::element::constant::integer::Integer as IntegerConstant;
+use crate::semantic::element::constant::Constant;
+use crate::semantic::element::r#type::enumeration::payload::Payload as EnumerationPayload;
+use crate::semantic::element::r#type::enumeration::Enumeration as EnumerationType;
+use crate::semantic::element::r#type::i_typed::ITyped;
+use crate::semantic::element::r#type::structure::Structure as StructureType;
+use crate::semantic::element::r#type::Type;
+use crate::semantic::element::Element;
+use crate::semantic::error::Error;
+use crate::semantic::scope::Scope;
+
+use super::exhausting::Constructor as ExhaustingConstructor;
+use super::exhausting::Pattern as ExhaustingPattern;
+
+///
+/// The match pattern destructuring, which translates a pattern into the conditions checking
+/// the scrutinee parts and the variables bound to them.
+///
+/// The offsets are counted in the data stack cells from the beginning of the scrutinee.
+///
+pub struct Destructuring {
+    /// The scope, where the pattern paths are resolved.
+    scope: Rc<RefCell<Scope>>,
+    /// The scrutinee location, which is referenced in the errors.
+    reference: Location,
+    /// Whether the enumeration variant payload is being destructured.
+    is_payload: bool,
+    /// The conditions, which must be true for the pattern to match.
+    pub conditions: Vec<GeneratorPattern>,
+    /// The variables bound by the pattern with their types, offsets, and flags telling whether
+    /// the variables are bound to an enumeration payload and must be casted.
+    pub bindings: Vec<(Identifier, Type, usize, bool)>,
+}
+
+impl Destructuring {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(scope: Rc<RefCell<Scope>>, reference: Location) -> Self {
+        Self {
+            scope,
+            reference,
+            is_payload: false,
+            conditions: vec![],
+            bindings: vec![],
+        }
+    }
+
+    ///
+    /// Expands the alternatives nested into the `pattern`, so each of the returned patterns
+    /// is free of them, e.g. `(1 | 2, x)` is expanded into `(1, x)` and `(2, x)`.
+    ///
+    pub fn expand(pattern: MatchPattern) -> Vec<MatchPattern> {
+        let location = pattern.location;
+
+        match pattern.variant {
+            MatchPatternVariant::Alternatives(alternatives) => {
+                alternatives.into_iter().flat_map(Self::expand).collect()
+            }
+            MatchPatternVariant::Tuple(patterns) => Self::product(patterns)
+                .into_iter()
+                .map(|patterns| MatchPattern::new(location, MatchPatternVariant::Tuple(patterns)))
+                .collect(),
+            MatchPatternVariant::PathTuple { path, patterns } => Self::product(patterns)
+                .into_iter()
+                .map(|patterns| {
+                    MatchPattern::new(
+                        location,
+                        MatchPatternVariant::PathTuple {
+                            path: path.clone(),
+                            patterns,
+                        },
+                    )
+                })
+                .collect(),
+            MatchPatternVariant::PathStructure { path, fields } => {
+                let (identifiers, patterns): (Vec<Identifier>, Vec<MatchPattern>) =
+                    fields.into_iter().unzip();
+
+                Self::product(patterns)
+                    .into_iter()
+                    .map(|patterns| {
+                        MatchPattern::new(
+                            location,
+                            MatchPatternVariant::PathStructure {
+                                path: path.clone(),
+                                fields: identifiers.iter().cloned().zip(patterns).collect(),
+                            },
+                        )
+                    })
+                    .collect()
+            }
+            variant => vec![MatchPattern::new(location, variant)],
+        }
+    }
+
+    ///
+    /// Returns the cartesian product of the expanded `patterns`.
+    ///
+    fn product(patterns: Vec<MatchPattern>) -> Vec<Vec<MatchPattern>> {
+        let mut result = vec![vec![]];
+
+        for pattern in patterns.into_iter() {
+            let alternatives = Self::expand(pattern);
+            result = result
+                .into_iter()
+                .flat_map(|prefix: Vec<MatchPattern>| {
+                    alternatives.iter().map(move |alternative| {
+                        let mut patterns = prefix.clone();
+                        patterns.push(alternative.to_owned());
+                        patterns
+                    })
+                })
+                .collect();
+        }
+
+        result
+    }
+
+    ///
+    /// Destructures the `pattern` of the scrutinee part of `r#type` at `offset`.
+    ///
+    /// Returns the pattern reduced to the constructors, which is used to check the `match`
+    /// expression exhaustiveness. The pattern must be expanded with `expand` beforehand.
+    ///
+    pub fn pattern(
+        &mut self,
+        pattern: MatchPattern,
+        r#type: &Type,
+        offset: usize,
+    ) -> Result<ExhaustingPattern, Error> {
+        let location = pattern.location;
+
+        match pattern.variant {
+            MatchPatternVariant::Binding(identifier) => {
+                self.bindings
+                    .push((identifier, r#type.to_owned(), offset, self.is_payload));
+                Ok(ExhaustingPattern::Wildcard)
+            }
+            MatchPatternVariant::Wildcard => Ok(ExhaustingPattern::Wildcard),
+            MatchPatternVariant::BooleanLiteral(boolean) => {
+                let constant = BooleanConstant::from(boolean);
+                self.check_type(location, &constant.r#type(), r#type)?;
+
+                let value = constant.inner;
+                self.push_condition(offset, Constant::Boolean(constant));
+                Ok(ExhaustingPattern::Constructor(
+                    ExhaustingConstructor::Boolean(value),
+                    vec![],
+                ))
+            }
+            MatchPatternVariant::IntegerLiteral(integer) => {
+                let constant = IntegerConstant::try_from(&integer)?;
+                if Caster::cast(&constant.r#type(), r#type).is_err() {
+                    return Err(self.invalid_type(location, r#type, &constant.r#type()));
+                }
+
+                let value = constant.value.clone();
+                self.push_condition(offset, Constant::Integer(constant));
+                Ok(ExhaustingPattern::Constructor(
+                    ExhaustingConstructor::Range(value.clone(), value),
+                    vec![],
+                ))
+            }
+            MatchPatternVariant::Range { start, end } => {
+                self.range(location, start, end, r#type, offset)
+            }
+            MatchPatternVariant::Tuple(patterns) => self.tuple(location, patterns, r#type, offset),
+            MatchPatternVariant::Path(ref path)
+                if self.resolve_variant(path.to_owned())?.is_some() =>
+            {
+                self.variant(pattern, r#type, offset)
+            }
+            MatchPatternVariant::Path(path) => {
+                let location = path.location;
+
+                let constant =
+                    match ExpressionAnalyzer::new(self.scope.clone(), TranslationRule::Value)
+                        .analyze(path)?
+                    {
+                        (Element::Constant(constant @ Constant::Boolean(_)), _intermediate)
+                        | (Element::Constant(constant @ Constant::Integer(_)), _intermediate) => {
+                            constant
+                        }
+                        (element, _intermediate) => {
+                            return Err(Error::MatchBranchPatternPathExpectedConstant {
+                                location,
+                                found: element.to_string(),
+                            });
+                        }
+                    };
+                self.check_type(pattern.location, &constant.r#type(), r#type)?;
+
+                let constructor = match constant {
+                    Constant::Boolean(ref boolean) => ExhaustingConstructor::Boolean(boolean.inner),
+                    Constant::Integer(ref integer) => {
+                        ExhaustingConstructor::Range(integer.value.clone(), integer.value.clone())
+                    }
+                    _ => panic!(zinc_const::panic::VALIDATED_DURING_SEMANTIC_ANALYSIS),
+                };
+                self.push_condition(offset, constant);
+                Ok(ExhaustingPattern::Constructor(constructor, vec![]))
+            }
+            MatchPatternVariant::PathStructure { path, fields } => {
+                match self.resolve_structure(path.clone())? {
+                    Some(structure) => self.structure(location, structure, fields, r#type, offset),
+                    None => self.variant(
+                        MatchPattern::new(
+                            location,
+                            MatchPatternVariant::PathStructure { path, fields },
+                        ),
+                        r#type,
+                        offset,
+                    ),
+                }
+            }
+            MatchPatternVariant::PathTuple { .. } => self.variant(pattern, r#type, offset),
+            MatchPatternVariant::Alternatives(_) => {
+                panic!(zinc_const::panic::VALIDATED_DURING_SEMANTIC_ANALYSIS)
+            }
+        }
+    }
+
+    ///
+    /// Destructures the enumeration variant `pattern` of the scrutinee part of `r#type`
+    /// at `offset`.
+    ///
+    fn variant(
+        &mut self,
+        pattern: MatchPattern,
+        r#type: &Type,
+        offset: usize,
+    ) -> Result<ExhaustingPattern, Error> {
+        let location = pattern.location;
+
+        let (path, payload) = match pattern.variant {
+            MatchPatternVariant::Path(path) => (path, None),
+            MatchPatternVariant::PathTuple { path, patterns } => (path, Some(Ok(patterns))),
+            MatchPatternVariant::PathStructure { path, fields } => (path, Some(Err(fields))),
+            _ => panic!(zinc_const::panic::VALIDATED_DURING_SEMANTIC_ANALYSIS),
+        };
+
+        let (enumeration, index, tag) = match self.resolve_variant(path.clone())? {
+            Some((enumeration, index, tag)) => match r#type {
+                Type::Enumeration(expected) if expected.is_same_family(&enumeration) => {
+                    (expected.to_owned(), index, tag)
+                }
+                _ => {
+                    return Err(self.invalid_type(
+                        location,
+                        r#type,
+                        &Type::Enumeration(enumeration),
+                    ))
+                }
+            },
+            None => {
+                let (element, _intermediate) =
+                    ExpressionAnalyzer::new(self.scope.clone(), TranslationRule::Value)
+                        .analyze(path)?;
+                let found = Type::from_element(&element, self.scope.clone())?;
+                return Err(self.invalid_type(location, r#type, &found));
+            }
+        };
+        let variant = format!("{}::{}", enumeration.identifier, enumeration.names[index]);
+
+        self.conditions
+            .push(GeneratorPattern::new_constant(offset, tag));
+
+        let declared = enumeration
+            .payload(index)
+            .cloned()
+            .expect(zinc_const::panic::VALIDATED_DURING_SEMANTIC_ANALYSIS);
+        let mut offset = offset + 1;
+        let is_payload = self.is_payload;
+        self.is_payload = true;
+        let fields = match (declared, payload) {
+            (EnumerationPayload::Unit, None) => vec![],
+            (EnumerationPayload::Tuple(types), Some(Ok(patterns))) => {
+                if types.len() != patterns.len() {
+                    return Err(Error::TypeVariantPayloadCount {
+                        location,
+                        variant,
+                        expected: types.len(),
+                        found: patterns.len(),
+                    });
+                }
+
+                let mut fields = Vec::with_capacity(types.len());
+                for (pattern, r#type) in patterns.into_iter().zip(types.iter()) {
+                    fields.push(self.pattern(pattern, r#type, offset)?);
+                    offset += r#type.size();
+                }
+                fields
+            }
+            (EnumerationPayload::Structure(fields), Some(Err(patterns))) => {
+                self.fields(fields.as_slice(), patterns, variant, offset)?
+            }
+            (declared, payload) => {
+                let found = match payload {
+                    None => "no payload",
+                    Some(Ok(_)) => "a tuple payload",
+                    Some(Err(_)) => "a structure payload",
+                };
+                let expected = match declared {
+                    EnumerationPayload::Unit => "no payload",
+                    EnumerationPayload::Tuple(_) => "a tuple payload",
+                    EnumerationPayload::Structure(_) => "a structure payload",
+                };
+
+                return Err(Error::TypeVariantPayloadMismatch {
+                    location,
+                    variant,
+                    expected: expected.to_owned(),
+                    found: found.to_owned(),
+                });
+            }
+        };
+        self.is_payload = is_payload;
+
+        Ok(ExhaustingPattern::Constructor(
+            ExhaustingConstructor::Variant(index),
+            fields,
+        ))
+    }
+
+    ///
+    /// Destructures the structure `fields` patterns of the scrutinee part of `r#type`
+    /// at `offset`.
+    ///
+    fn structure(
+        &mut self,
+        location: Location,
+        structure: StructureType,
+        fields: Vec<(Identifier, MatchPattern)>,
+        r#type: &Type,
+        offset: usize,
+    ) -> Result<ExhaustingPattern, Error> {
+        let expected = match r#type {
+            Type::Structure(expected) if expected == &structure => expected,
+            _ => return Err(self.invalid_type(location, r#type, &Type::Structure(structure))),
+        };
+
+        let fields = self.fields(
+            expected.fields.as_slice(),
+            fields,
+            expected.identifier.to_owned(),
+            offset,
+        )?;
+
+        Ok(ExhaustingPattern::Constructor(
+            ExhaustingConstructor::Single,
+            fields,
+        ))
+    }
+
+    ///
+    /// Destructures the structure-like field `patterns`, which are matched against the
+    /// `declared` fields starting at `offset`.
+    ///
+    /// Returns the field patterns in the declaration order, where the omitted fields are
+    /// matched with wildcards.
+    ///
+    fn fields(
+        &mut self,
+        declared: &[(String, Type)],
+        patterns: Vec<(Identifier, MatchPattern)>,
+        r#type: String,
+        offset: usize,
+    ) -> Result<Vec<ExhaustingPattern>, Error> {
+        let mut fields = vec![ExhaustingPattern::Wildcard; declared.len()];
+
+        for (identifier, pattern) in patterns.into_iter() {
+            let mut field_offset = offset;
+            let mut field = None;
+            for (position, (name, r#type)) in declared.iter().enumerate() {
+                if name == &identifier.name {
+                    field = Some((position, r#type));
+                    break;
+                }
+                field_offset += r#type.size();
+            }
+
+            match field {
+                Some((position, field_type)) => {
+                    fields[position] = self.pattern(pattern, field_type, field_offset)?;
+                }
+                None => {
+                    return Err(Error::StructureFieldDoesNotExist {
+                        location: identifier.location,
+                        r#type,
+                        field_name: identifier.name,
+                    });
+                }
+            }
+        }
+
+        Ok(fields)
+    }
+
+    ///
+    /// Destructures the tuple `patterns` of the scrutinee part of `r#type` at `offset`.
+    ///
+    fn tuple(
+        &mut self,
+        location: Location,
+        patterns: Vec<MatchPattern>,
+        r#type: &Type,
+        mut offset: usize,
+    ) -> Result<ExhaustingPattern, Error> {
+        let types = match r#type {
+            Type::Tuple(tuple) if tuple.types.len() == patterns.len() => tuple.types.to_owned(),
+            _ => {
+                return Err(Error::MatchBranchPatternInvalidType {
+                    location,
+                    expected: r#type.to_string(),
+                    found: format!("tuple ({})", vec!["_"; patterns.len()].join(", ")),
+                    reference: self.reference,
+                })
+            }
+        };
+
+        let mut fields = Vec::with_capacity(types.len());
+        for (pattern, r#type) in patterns.into_iter().zip(types.iter()) {
+            fields.push(self.pattern(pattern, r#type, offset)?);
+            offset += r#type.size();
+        }
+
+        Ok(ExhaustingPattern::Constructor(
+            ExhaustingConstructor::Single,
+            fields,
+        ))
+    }
+
+    ///
+    /// Destructures the inclusive range pattern of the scrutinee part of `r#type` at `offset`.
+    ///
+    /// The range bounds are written as constants of the scrutinee type, since the comparison
+    /// operands must be of the same type.
+    ///
+    fn range(
+        &mut self,
+        location: Location,
+        start: IntegerLiteral,
+        end: IntegerLiteral,
+        r#type: &Type,
+        offset: usize,
+    ) -> Result<ExhaustingPattern, Error> {
+        let start = IntegerConstant::try_from(&start)?;
+        let end = IntegerConstant::try_from(&end)?;
+
+        let (is_signed, bitlength) = match r#type {
+            Type::IntegerUnsigned { bitlength, .. } => (false, *bitlength),
+            Type::IntegerSigned { bitlength, .. } => (true, *bitlength),
+            Type::Field(_) => (false, zinc_const::bitlength::FIELD),
+            _ => return Err(self.invalid_type(location, r#type, &end.r#type())),
+        };
+        let maximum = if is_signed {
+            (BigInt::one() << (bitlength - 1)) - BigInt::one()
+        } else {
+            (BigInt::one() << bitlength) - BigInt::one()
+        };
+        if end.value > maximum {
+            return Err(self.invalid_type(location, r#type, &end.r#type()));
+        }
+        if start.value > end.value {
+            return Err(Error::MatchBranchPatternRangeInvalid {
+                location,
+                start: start.value.to_string(),
+                end: end.value.to_string(),
+            });
+        }
+
+        self.conditions.push(GeneratorPattern::new_range(
+            offset,
+            GeneratorConstant::Integer(GeneratorIntegerConstant::new(
+                start.value.clone(),
+                is_signed,
+                bitlength,
+            )),
+            GeneratorConstant::Integer(GeneratorIntegerConstant::new(
+                end.value.clone(),
+                is_signed,
+                bitlength,
+            )),
+        ));
+
+        Ok(ExhaustingPattern::Constructor(
+            ExhaustingConstructor::Range(start.value, end.value),
+            vec![],
+        ))
+    }
+
+    ///
+    /// Resolves the pattern `path` to a data-carrying enumeration variant.
+    ///
+    fn resolve_variant(
+        &self,
+        path: ExpressionTree,
+    ) -> Result<Option<(EnumerationType, usize, GeneratorConstant)>, Error> {
+        match ExpressionAnalyzer::new(self.scope.clone(), TranslationRule::Path).analyze(path)? {
+            (Element::Path(path), _intermediate) => {
+                VariantAnalyzer::resolve(self.scope.clone(), &path)
+            }
+            _ => Ok(None),
+        }
+    }
+
+    ///
+    /// Resolves the pattern `path` to a structure type.
+    ///
+    fn resolve_structure(&self, path: ExpressionTree) -> Result<Option<StructureType>, Error> {
+        match ExpressionAnalyzer::new(self.scope.clone(), TranslationRule::Type).analyze(path)? {
+            (Element::Type(Type::Structure(structure)), _intermediate) => Ok(Some(structure)),
+            _ => Ok(None),
+        }
+    }
+
+    ///
+    /// Pushes the condition, which compares the scrutinee scalar at `offset` with `constant`.
+    ///
+    fn push_condition(&mut self, offset: usize, constant: Constant) {
+        let constant = GeneratorConstant::try_from_semantic(&constant)
+            .expect(zinc_const::panic::VALIDATED_DURING_SEMANTIC_ANALYSIS);
+        self.conditions
+            .push(GeneratorPattern::new_constant(offset, constant));
+    }
+
+    ///
+    /// Checks if the pattern type is the same as the scrutinee part one.
+    ///
+    fn check_type(&self, location: Location, found: &Type, expected: &Type) -> Result<(), Error> {
+        if found != expected {
+            return Err(self.invalid_type(location, expected, found));
+        }
+
+        Ok(())
+    }
+
+    ///
+    /// Creates the pattern type mismatch error.
+    ///
+    fn invalid_type(&self, location: Location, expected: &Type, found: &Type) -> Error {
+        Error::MatchBranchPatternInvalidType {
+            location,
+            expected: expected.to_string(),
+            found: found.to_string(),
+            reference: self.reference,
+        }
+    }
+}
@@ -8,18 +8,53 @@ use num::BigInt;
 use num::One;
 use num::Zero;
 
-use crate::semantic::element::r#type::enumeration::Enumeration;
 use zinc_lexical::Location;
 
+use crate::semantic::element::r#type::Type;
+
+///
+/// The pattern constructor, which is the pattern part checked against the scrutinee value.
+///
+#[derive(Debug, Clone, PartialEq)]
+pub enum Constructor {
+    /// The boolean value.
+    Boolean(bool),
+    /// The inclusive range of integer values. Single integers and enumeration values are
+    /// represented as one-value ranges.
+    Range(BigInt, BigInt),
+    /// The data-carrying enumeration variant with its index.
+    Variant(usize),
+    /// The only constructor of a tuple or structure type.
+    Single,
+}
+
+///
+/// The pattern, which is reduced to the constructors and wildcards for the exhaustiveness
+/// and reachability checking.
+///
+#[derive(Debug, Clone, PartialEq)]
+pub enum Pattern {
+    /// Matches any value, e.g. a wildcard or binding.
+    Wildcard,
+    /// Matches the values built with the constructor, whose fields match the inner patterns.
+    Constructor(Constructor, Vec<Pattern>),
+}
+
 ///
 /// The object, describing the `match` expression exhaustion process.
 ///
+/// A pattern is useful, if there is a value matched by it, but not matched by any of the
+/// previous patterns. A branch is unreachable, if its pattern is not useful, and the `match`
+/// expression is exhausted, if the wildcard is not useful anymore.
+///
+#[derive(Clone)]
 pub struct Data {
-    /// The patterns, which appear in the `match` expression.
-    patterns: HashMap<BigInt, Location>,
-    /// The enumeration type, is the `match` expressions matches one.
-    /// In this case, all the enumeration variant must be covered at least once.
-    enumeration_type: Option<Enumeration>,
+    /// The scrutinee type.
+    r#type: Type,
+    /// The patterns, which appear in the `match` expression before the checked one.
+    patterns: Vec<Pattern>,
+    /// The top-level literal patterns, which are used to detect the duplicates.
+    literals: HashMap<BigInt, Location>,
 }
 
 impl Data {
@@ -29,60 +64,261 @@ impl Data {
     ///
     /// A shortcut constructor.
     ///
-    pub fn new() -> Self {
+    pub fn new(r#type: Type) -> Self {
         Self {
-            patterns: HashMap::with_capacity(Self::DEFAULT_INITIAL_PATTERN_HASHMAP_SIZE),
-            enumeration_type: None,
+            r#type,
+            patterns: Vec::with_capacity(Self::DEFAULT_INITIAL_PATTERN_HASHMAP_SIZE),
+            literals: HashMap::with_capacity(Self::DEFAULT_INITIAL_PATTERN_HASHMAP_SIZE),
         }
     }
 
     ///
-    /// Inserts a boolean pattern to the exhaustion hashmap.
+    /// Inserts the `pattern` located at `location`.
     ///
-    pub fn insert_boolean(&mut self, value: bool, location: Location) -> Option<Location> {
-        self.patterns
-            .insert(if value { BigInt::one() } else { BigInt::zero() }, location)
+    pub fn insert(&mut self, pattern: Pattern, location: Location) {
+        match pattern {
+            Pattern::Constructor(Constructor::Boolean(value), _) => {
+                self.literals
+                    .insert(if value { BigInt::one() } else { BigInt::zero() }, location);
+            }
+            Pattern::Constructor(Constructor::Range(ref start, ref end), _) if start == end => {
+                self.literals.insert(start.to_owned(), location);
+            }
+            _ => {}
+        }
+
+        self.patterns.push(pattern);
     }
 
     ///
-    /// Inserts an integer pattern to the exhaustion hashmap.
+    /// Returns the location of the same top-level literal pattern, if it has occurred before.
     ///
-    pub fn insert_integer(
-        &mut self,
-        value: BigInt,
-        enumeration_type: Option<Enumeration>,
-        location: Location,
-    ) -> Option<Location> {
-        self.enumeration_type = enumeration_type;
-        self.patterns.insert(value, location)
+    pub fn duplicate(&self, pattern: &Pattern) -> Option<Location> {
+        match pattern {
+            Pattern::Constructor(Constructor::Boolean(value), _) => self
+                .literals
+                .get(&if *value {
+                    BigInt::one()
+                } else {
+                    BigInt::zero()
+                })
+                .copied(),
+            Pattern::Constructor(Constructor::Range(start, end), _) if start == end => {
+                self.literals.get(start).copied()
+            }
+            _ => None,
+        }
     }
 
     ///
-    /// Checks if the boolean patterns cover all the possible boolean values.
+    /// Checks if the `pattern` matches some value, which is not matched by the previous patterns.
     ///
-    pub fn has_exhausted_boolean(&self) -> bool {
-        let mut current = self.patterns.keys().cloned().collect::<Vec<BigInt>>();
-        current.sort();
+    pub fn is_useful(&self, pattern: &Pattern) -> bool {
+        let rows: Vec<Vec<Pattern>> = self
+            .patterns
+            .iter()
+            .map(|pattern| vec![pattern.to_owned()])
+            .collect();
 
-        let full = vec![BigInt::zero(), BigInt::one()];
+        Self::is_useful_vector(
+            rows.as_slice(),
+            &[pattern.to_owned()],
+            &[self.r#type.to_owned()],
+        )
+    }
 
-        current == full
+    ///
+    /// Checks if the patterns cover all the possible values of the scrutinee type.
+    ///
+    pub fn is_exhausted(&self) -> bool {
+        !self.is_useful(&Pattern::Wildcard)
     }
 
     ///
-    /// Checks if the integer patterns cover all the possible integer values.
+    /// Checks if the pattern `vector` is useful with respect to the pattern `rows`, where each
+    /// column has the corresponding type from `types`.
     ///
-    pub fn has_exhausted_integer(&self) -> bool {
-        match self.enumeration_type {
-            Some(ref enumeration) => {
-                let mut current = self.patterns.keys().cloned().collect::<Vec<BigInt>>();
-                current.sort();
+    fn is_useful_vector(rows: &[Vec<Pattern>], vector: &[Pattern], types: &[Type]) -> bool {
+        let (r#type, types) = match types.split_first() {
+            Some(split) => split,
+            None => return rows.is_empty(),
+        };
+
+        let heads: Vec<&Constructor> = rows
+            .iter()
+            .filter_map(|row| match row.first() {
+                Some(Pattern::Constructor(constructor, _)) => Some(constructor),
+                _ => None,
+            })
+            .collect();
+
+        let constructors = match vector.first() {
+            Some(Pattern::Constructor(constructor, _)) => {
+                Self::split(constructor, heads.as_slice())
+            }
+            _ => match Self::constructors(r#type) {
+                Some(constructors) => constructors
+                    .iter()
+                    .flat_map(|constructor| Self::split(constructor, heads.as_slice()))
+                    .collect(),
+                None => {
+                    let rows: Vec<Vec<Pattern>> = rows
+                        .iter()
+                        .filter(|row| matches!(row.first(), Some(Pattern::Wildcard)))
+                        .map(|row| row[1..].to_vec())
+                        .collect();
 
-                let mut enum_values = enumeration.values.to_owned();
-                enum_values.sort();
-                current == enum_values
+                    return Self::is_useful_vector(rows.as_slice(), &vector[1..], types);
+                }
+            },
+        };
+
+        constructors.iter().any(|constructor| {
+            let mut fields = Self::fields(r#type, constructor);
+            let arity = fields.len();
+            fields.extend_from_slice(types);
+
+            let rows: Vec<Vec<Pattern>> = rows
+                .iter()
+                .filter_map(|row| Self::specialize(row.as_slice(), constructor, arity))
+                .collect();
+            let vector = Self::specialize(vector, constructor, arity)
+                .expect(zinc_const::panic::VALIDATED_DURING_SEMANTIC_ANALYSIS);
+
+            Self::is_useful_vector(rows.as_slice(), vector.as_slice(), fields.as_slice())
+        })
+    }
+
+    ///
+    /// Returns all the constructors of the `type`, or `None`, if its values can be only matched
+    /// with a wildcard.
+    ///
+    fn constructors(r#type: &Type) -> Option<Vec<Constructor>> {
+        match r#type {
+            Type::Boolean(_) => Some(vec![
+                Constructor::Boolean(false),
+                Constructor::Boolean(true),
+            ]),
+            Type::IntegerUnsigned { bitlength, .. } => Some(vec![Constructor::Range(
+                BigInt::zero(),
+                (BigInt::one() << *bitlength) - BigInt::one(),
+            )]),
+            Type::IntegerSigned { bitlength, .. } => Some(vec![Constructor::Range(
+                -(BigInt::one() << (*bitlength - 1)),
+                (BigInt::one() << (*bitlength - 1)) - BigInt::one(),
+            )]),
+            Type::Field(_) => Some(vec![Constructor::Range(
+                BigInt::zero(),
+                (BigInt::one() << zinc_const::bitlength::FIELD) - BigInt::one(),
+            )]),
+            Type::Enumeration(enumeration) if enumeration.is_data() => Some(
+                (0..enumeration.names.len())
+                    .map(Constructor::Variant)
+                    .collect(),
+            ),
+            Type::Enumeration(enumeration) => Some(
+                enumeration
+                    .values
+                    .iter()
+                    .map(|value| Constructor::Range(value.to_owned(), value.to_owned()))
+                    .collect(),
+            ),
+            Type::Unit(_) | Type::Tuple(_) | Type::Structure(_) => Some(vec![Constructor::Single]),
+            _ => None,
+        }
+    }
+
+    ///
+    /// Returns the field types of the `constructor` of the `type`.
+    ///
+    fn fields(r#type: &Type, constructor: &Constructor) -> Vec<Type> {
+        match (r#type, constructor) {
+            (Type::Enumeration(enumeration), Constructor::Variant(index)) => enumeration
+                .payload(*index)
+                .map(|payload| payload.types())
+                .unwrap_or_default(),
+            (Type::Tuple(tuple), Constructor::Single) => tuple.types.to_owned(),
+            (Type::Structure(structure), Constructor::Single) => structure
+                .fields
+                .iter()
+                .map(|(_name, r#type)| r#type.to_owned())
+                .collect(),
+            _ => vec![],
+        }
+    }
+
+    ///
+    /// Splits the `constructor` into the parts, each of which is either fully covered by or
+    /// disjoint with every constructor from `heads`.
+    ///
+    /// Only the integer ranges are split, whereas other constructors are returned as is.
+    ///
+    fn split(constructor: &Constructor, heads: &[&Constructor]) -> Vec<Constructor> {
+        let (start, end) = match constructor {
+            Constructor::Range(start, end) => (start, end),
+            constructor => return vec![constructor.to_owned()],
+        };
+
+        let mut boundaries = Vec::with_capacity(heads.len() * 2);
+        for head in heads.iter() {
+            if let Constructor::Range(head_start, head_end) = head {
+                for boundary in [head_start.to_owned(), head_end + BigInt::one()].iter() {
+                    if boundary > start && boundary <= end {
+                        boundaries.push(boundary.to_owned());
+                    }
+                }
+            }
+        }
+        boundaries.sort();
+        boundaries.dedup();
+
+        let mut parts = Vec::with_capacity(boundaries.len() + 1);
+        let mut part_start = start.to_owned();
+        for boundary in boundaries.into_iter() {
+            parts.push(Constructor::Range(
+                part_start,
+                boundary.to_owned() - BigInt::one(),
+            ));
+            part_start = boundary;
+        }
+        parts.push(Constructor::Range(part_start, end.to_owned()));
+        parts
+    }
+
+    ///
+    /// Specializes the pattern `row` by the `constructor` with `arity` fields.
+    ///
+    /// Returns the row with its first pattern replaced with the constructor fields patterns,
+    /// or `None`, if the first pattern does not cover the constructor.
+    ///
+    fn specialize(
+        row: &[Pattern],
+        constructor: &Constructor,
+        arity: usize,
+    ) -> Option<Vec<Pattern>> {
+        let (first, rest) = row.split_first()?;
+
+        let mut result = match first {
+            Pattern::Wildcard => vec![Pattern::Wildcard; arity],
+            Pattern::Constructor(head, fields) if Self::covers(head, constructor) => {
+                fields.to_owned()
+            }
+            Pattern::Constructor(..) => return None,
+        };
+        result.extend_from_slice(rest);
+
+        Some(result)
+    }
+
+    ///
+    /// Checks if the `head` constructor covers all the values of the `constructor`.
+    ///
+    fn covers(head: &Constructor, constructor: &Constructor) -> bool {
+        match (head, constructor) {
+            (Constructor::Range(head_start, head_end), Constructor::Range(start, end)) => {
+                head_start <= start && end <= head_end
             }
-            None => false,
+            (head, constructor) => head == constructor,
         }
     }
 }
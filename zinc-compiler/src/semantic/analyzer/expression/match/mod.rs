@@ -5,35 +5,41 @@
 #[cfg(test)]
 mod tests;
 
+pub mod destructuring;
 pub mod exhausting;
 
 use std::cell::RefCell;
-use std::convert::TryFrom;
 use std::rc::Rc;
 
+use zinc_lexical::Location;
+use zinc_syntax::ExpressionTree;
 use zinc_syntax::MatchExpression;
+use zinc_syntax::MatchPattern;
 use zinc_syntax::MatchPatternVariant;
 
-use crate::generator::expression::operand::constant::Constant as GeneratorConstant;
+use crate::generator::expression::operand::r#match::binding::Binding as GeneratorBinding;
 use crate::generator::expression::operand::r#match::builder::Builder as GeneratorMatchExpressionBuilder;
+use crate::generator::expression::operand::r#match::pattern::Pattern as GeneratorPattern;
 use crate::generator::expression::operand::Operand as GeneratorExpressionOperand;
+use crate::generator::expression::Expression as GeneratorExpression;
 use crate::generator::r#type::Type as GeneratorType;
 use crate::semantic::analyzer::expression::Analyzer as ExpressionAnalyzer;
 use crate::semantic::analyzer::rule::Rule as TranslationRule;
-use crate::semantic::casting::Caster;
-use crate::semantic::element::constant::boolean::Boolean as BooleanConstant;
-use crate::semantic::element::constant::integer::Integer as IntegerConstant;
 use crate::semantic::element::constant::unit::Unit as UnitConstant;
 use crate::semantic::element::constant::Constant;
 use crate::semantic::element::r#type::i_typed::ITyped;
 use crate::semantic::element::r#type::Type;
+use crate::semantic::element::value::Value;
 use crate::semantic::element::Element;
 use crate::semantic::error::Error;
 use crate::semantic::scope::r#type::Type as ScopeType;
 use crate::semantic::scope::stack::Stack as ScopeStack;
 use crate::semantic::scope::Scope;
 
+use self::destructuring::Destructuring;
+use self::exhausting::Constructor as ExhaustingConstructor;
 use self::exhausting::Data as ExhaustingData;
+use self::exhausting::Pattern as ExhaustingPattern;
 
 ///
 /// The `match` expression semantic analyzer.
@@ -82,8 +88,16 @@ impl Analyzer {
         let (scrutinee_result, scrutinee_expression) =
             ExpressionAnalyzer::new(scope_stack.top(), TranslationRule::Value)
                 .analyze(r#match.scrutinee)?;
+        if let Element::Value(Value::Enumeration(ref value)) = scrutinee_result {
+            value.validate_complete(scrutinee_location)?;
+        }
         let scrutinee_type = Type::from_element(&scrutinee_result, scope_stack.top())?;
-        if scrutinee_type.is_scalar() {
+        let is_matchable = match scrutinee_type {
+            Type::Enumeration(ref inner) => inner.is_data() || scrutinee_type.is_scalar(),
+            Type::Tuple(_) | Type::Structure(_) => true,
+            ref r#type => r#type.is_scalar(),
+        };
+        if is_matchable {
             builder.set_scrutinee(
                 scrutinee_expression,
                 GeneratorType::try_from_semantic(&scrutinee_type)
@@ -100,184 +114,69 @@ impl Analyzer {
             return Err(Error::MatchLessThanTwoBranches { location });
         }
 
-        let first_branch_expression_location = r#match.branches[0].1.location;
-        let mut is_exhausted = false;
-        let mut exhausting_data = ExhaustingData::new();
+        let first_branch_expression_location = r#match.branches[0].2.location;
+        let mut exhausting_data = ExhaustingData::new(scrutinee_type.clone());
         let mut match_result = None;
 
-        for (pattern, expression) in r#match.branches.into_iter() {
-            let pattern_location = pattern.location;
+        for (pattern, guard, expression) in r#match.branches.into_iter() {
             let expression_location = expression.location;
 
-            if is_exhausted {
-                return Err(Error::MatchBranchUnreachable {
-                    location: pattern.location,
-                });
-            }
-
-            let result = match pattern.variant {
-                MatchPatternVariant::BooleanLiteral(boolean) => {
-                    let location = boolean.location;
-
-                    let constant = BooleanConstant::from(boolean);
-                    let pattern_type = constant.r#type();
-                    if pattern_type != scrutinee_type {
-                        return Err(Error::MatchBranchPatternInvalidType {
-                            location: pattern_location,
-                            expected: scrutinee_type.to_string(),
-                            found: pattern_type.to_string(),
-                            reference: scrutinee_location,
-                        });
-                    }
-
-                    if let Some(duplicate) =
-                        exhausting_data.insert_boolean(constant.inner, location)
-                    {
-                        return Err(Error::MatchBranchDuplicate {
-                            location,
-                            reference: duplicate,
-                        });
-                    }
-
-                    let constant =
-                        GeneratorConstant::try_from_semantic(&Constant::Boolean(constant))
-                            .expect(zinc_const::panic::VALIDATED_DURING_SYNTAX_ANALYSIS);
-                    let (result, branch) =
-                        ExpressionAnalyzer::new(scope_stack.top(), TranslationRule::Value)
-                            .analyze(expression)?;
-
-                    if exhausting_data.has_exhausted_boolean() {
-                        is_exhausted = true;
-                        builder.set_wildcard_branch(branch);
-                    } else {
-                        builder.push_branch(constant, branch);
-                    }
-
-                    result
+            let binding = match pattern.variant {
+                MatchPatternVariant::Binding(ref identifier) if guard.is_none() => {
+                    Some(identifier.name.to_owned())
                 }
-                MatchPatternVariant::IntegerLiteral(integer) => {
-                    let location = integer.location;
-
-                    let constant = IntegerConstant::try_from(&integer)?;
-                    let pattern_type = constant.r#type();
-                    if Caster::cast(&pattern_type, &scrutinee_type).is_err() {
-                        return Err(Error::MatchBranchPatternInvalidType {
-                            location: pattern_location,
-                            expected: scrutinee_type.to_string(),
-                            found: pattern_type.to_string(),
-                            reference: scrutinee_location,
-                        });
-                    }
-
-                    if let Some(duplicate) =
-                        exhausting_data.insert_integer(constant.value.clone(), None, location)
-                    {
-                        return Err(Error::MatchBranchDuplicate {
-                            location,
-                            reference: duplicate,
-                        });
-                    }
-
-                    let constant =
-                        GeneratorConstant::try_from_semantic(&Constant::Integer(constant))
-                            .expect(zinc_const::panic::VALIDATED_DURING_SYNTAX_ANALYSIS);
-                    let (result, branch) =
-                        ExpressionAnalyzer::new(scope_stack.top(), TranslationRule::Value)
-                            .analyze(expression)?;
-
-                    if exhausting_data.has_exhausted_integer() {
-                        is_exhausted = true;
-                        builder.set_wildcard_branch(branch);
-                    } else {
-                        builder.push_branch(constant, branch);
-                    }
+                _ => None,
+            };
 
-                    result
-                }
-                MatchPatternVariant::Path(path) => {
-                    let location = path.location;
-
-                    let constant =
-                        match ExpressionAnalyzer::new(scope_stack.top(), TranslationRule::Value)
-                            .analyze(path)?
-                        {
-                            (Element::Constant(constant), _intermediate) => {
-                                if let Constant::Integer(ref integer) = constant {
-                                    if let Some(duplicate) = exhausting_data.insert_integer(
-                                        integer.value.to_owned(),
-                                        integer.enumeration.to_owned(),
-                                        location,
-                                    ) {
-                                        return Err(Error::MatchBranchDuplicate {
-                                            location,
-                                            reference: duplicate,
-                                        });
-                                    }
-                                }
-                                constant
-                            }
-                            (element, _intermediate) => {
-                                return Err(Error::MatchBranchPatternPathExpectedConstant {
-                                    location,
-                                    found: element.to_string(),
-                                });
-                            }
-                        };
-                    let pattern_type = constant.r#type();
-                    if pattern_type != scrutinee_type {
-                        return Err(Error::MatchBranchPatternInvalidType {
-                            location: pattern_location,
-                            expected: scrutinee_type.to_string(),
-                            found: pattern_type.to_string(),
-                            reference: scrutinee_location,
-                        });
+            let mut branch_exhausting_data = exhausting_data.clone();
+            let alternatives = Self::alternatives(
+                scope_stack.top(),
+                pattern,
+                &scrutinee_type,
+                scrutinee_location,
+                &mut branch_exhausting_data,
+            )?;
+
+            scope_stack.push(None, ScopeType::Block);
+            let mut generator_alternatives = Vec::with_capacity(alternatives.len());
+            for (index, (destructuring, _pattern)) in alternatives.into_iter().enumerate() {
+                let mut bindings = Vec::with_capacity(destructuring.bindings.len());
+                for (identifier, r#type, offset, is_casted) in destructuring.bindings.into_iter() {
+                    if let Some(generator_type) = GeneratorType::try_from_semantic(&r#type) {
+                        bindings.push(GeneratorBinding::new(
+                            identifier.name.to_owned(),
+                            offset,
+                            generator_type,
+                            is_casted,
+                        ));
                     }
-
-                    let constant = GeneratorConstant::try_from_semantic(&constant)
-                        .expect(zinc_const::panic::VALIDATED_DURING_SYNTAX_ANALYSIS);
-                    let (result, branch) =
-                        ExpressionAnalyzer::new(scope_stack.top(), TranslationRule::Value)
-                            .analyze(expression)?;
-
-                    if exhausting_data.has_exhausted_integer() {
-                        is_exhausted = true;
-                        builder.set_wildcard_branch(branch);
-                    } else {
-                        builder.push_branch(constant, branch);
+                    if index == 0 {
+                        Scope::define_variable(scope_stack.top(), identifier, false, r#type)?;
                     }
-
-                    result
                 }
-                MatchPatternVariant::Binding(identifier) => {
-                    is_exhausted = true;
-
-                    scope_stack.push(None, ScopeType::Block);
-                    Scope::define_variable(
-                        scope_stack.top(),
-                        identifier.clone(),
-                        false,
-                        scrutinee_type.clone(),
-                    )?;
-                    let (result, branch) =
-                        ExpressionAnalyzer::new(scope_stack.top(), TranslationRule::Value)
-                            .analyze(expression)?;
-                    scope_stack.pop();
-
-                    builder.set_binding_branch(branch, identifier.name);
-
-                    result
-                }
-                MatchPatternVariant::Wildcard => {
-                    is_exhausted = true;
-                    let (result, branch) =
-                        ExpressionAnalyzer::new(scope_stack.top(), TranslationRule::Value)
-                            .analyze(expression)?;
-
-                    builder.set_wildcard_branch(branch);
+                generator_alternatives.push((
+                    GeneratorPattern::new_all(destructuring.conditions),
+                    bindings,
+                ));
+            }
+            let guard = match guard {
+                Some(guard) => Some(Self::guard(scope_stack.top(), guard)?),
+                None => None,
+            };
+            let (result, branch) =
+                Self::branch(scope_stack.top(), expression, match_result.as_ref())?;
+            scope_stack.pop();
 
-                    result
+            if guard.is_none() {
+                exhausting_data = branch_exhausting_data;
+            }
+            match binding {
+                Some(name) => builder.set_binding_branch(branch, name),
+                None if guard.is_none() && exhausting_data.is_exhausted() => {
+                    builder.set_fallback_branch(generator_alternatives, branch)
                 }
-            };
+                None => builder.push_branch(generator_alternatives, guard, branch),
+            }
 
             let result_type = Type::from_element(&result, scope_stack.top())?;
             if let Some(ref match_result) = match_result {
@@ -297,7 +196,7 @@ impl Analyzer {
             }
         }
 
-        if !is_exhausted {
+        if !exhausting_data.is_exhausted() {
             return Err(Error::MatchNotExhausted { location });
         }
 
@@ -311,6 +210,155 @@ impl Analyzer {
         Ok((element, intermediate))
     }
 
+    ///
+    /// Analyzes the branch expression.
+    ///
+    /// If the branch result is an incomplete generic enumeration value, e.g. `Option::None`,
+    /// it is coerced to the type of the `first` branch result.
+    ///
+    fn branch(
+        scope: Rc<RefCell<Scope>>,
+        expression: ExpressionTree,
+        first: Option<&Element>,
+    ) -> Result<(Element, GeneratorExpression), Error> {
+        let location = expression.location;
+
+        let (mut result, mut branch) =
+            ExpressionAnalyzer::new(scope.clone(), TranslationRule::Value).analyze(expression)?;
+        if let Some(first) = first {
+            let expected = Type::from_element(first, scope)?;
+            if let Some(padding) = result.coerce(&expected) {
+                branch.push_operand(padding);
+            }
+        }
+        result.validate_complete(location)?;
+
+        Ok((result, branch))
+    }
+
+    ///
+    /// Destructures the branch `pattern` alternatives and checks if each of them is useful,
+    /// that is, matches some values, which are not matched by the previous branches and
+    /// alternatives.
+    ///
+    /// The alternatives are inserted into `exhausting_data`, which must be discarded by
+    /// the caller if the branch is guarded.
+    ///
+    fn alternatives(
+        scope: Rc<RefCell<Scope>>,
+        pattern: MatchPattern,
+        scrutinee_type: &Type,
+        scrutinee_location: Location,
+        exhausting_data: &mut ExhaustingData,
+    ) -> Result<Vec<(Destructuring, ExhaustingPattern)>, Error> {
+        let mut alternatives: Vec<(Destructuring, ExhaustingPattern)> = Vec::new();
+
+        for alternative in Destructuring::expand(pattern).into_iter() {
+            let location = alternative.location;
+
+            let mut destructuring = Destructuring::new(scope.clone(), scrutinee_location);
+            let pattern = destructuring.pattern(alternative, scrutinee_type, 0)?;
+            if let Some(reference) = exhausting_data.duplicate(&pattern) {
+                return Err(Error::MatchBranchDuplicate {
+                    location,
+                    reference,
+                });
+            }
+            if !exhausting_data.is_useful(&pattern) {
+                return Err(Error::MatchBranchUnreachable { location });
+            }
+            exhausting_data.insert(pattern.clone(), location);
+
+            destructuring
+                .bindings
+                .sort_by(|(first, ..), (second, ..)| first.name.cmp(&second.name));
+            if let Some((first, _pattern)) = alternatives.first() {
+                let expected: Vec<(&str, &Type)> = first
+                    .bindings
+                    .iter()
+                    .map(|(identifier, r#type, ..)| (identifier.name.as_str(), r#type))
+                    .collect();
+                let found: Vec<(&str, &Type)> = destructuring
+                    .bindings
+                    .iter()
+                    .map(|(identifier, r#type, ..)| (identifier.name.as_str(), r#type))
+                    .collect();
+
+                if let Some((name, _type)) = expected
+                    .iter()
+                    .find(|binding| !found.contains(binding))
+                    .or_else(|| found.iter().find(|binding| !expected.contains(binding)))
+                {
+                    return Err(Error::MatchBranchAlternativeBindingMismatch {
+                        location,
+                        name: (*name).to_owned(),
+                    });
+                }
+            }
+
+            alternatives.push((destructuring, pattern));
+        }
+
+        Ok(alternatives)
+    }
+
+    ///
+    /// Analyzes the branch guard expression, which must be boolean.
+    ///
+    fn guard(
+        scope: Rc<RefCell<Scope>>,
+        guard: ExpressionTree,
+    ) -> Result<GeneratorExpression, Error> {
+        let location = guard.location;
+
+        let (element, intermediate) =
+            ExpressionAnalyzer::new(scope.clone(), TranslationRule::Value).analyze(guard)?;
+        match Type::from_element(&element, scope)? {
+            Type::Boolean(_) => Ok(intermediate),
+            r#type => Err(Error::MatchBranchGuardExpectedBoolean {
+                location,
+                found: r#type.to_string(),
+            }),
+        }
+    }
+
+    ///
+    /// Evaluates the constant branch guard expression, which must be boolean.
+    ///
+    fn constant_guard(scope: Rc<RefCell<Scope>>, guard: ExpressionTree) -> Result<bool, Error> {
+        let location = guard.location;
+
+        match ExpressionAnalyzer::new(scope, TranslationRule::Constant).analyze(guard)? {
+            (Element::Constant(Constant::Boolean(boolean)), _) => Ok(boolean.inner),
+            (Element::Constant(constant), _) => Err(Error::MatchBranchGuardExpectedBoolean {
+                location,
+                found: constant.r#type().to_string(),
+            }),
+            (element, _) => Err(Error::ExpressionNonConstantElement {
+                location,
+                found: element.to_string(),
+            }),
+        }
+    }
+
+    ///
+    /// Checks if the constant scalar `scrutinee` is matched by the `pattern`.
+    ///
+    fn is_matched(pattern: &ExhaustingPattern, scrutinee: &Constant) -> bool {
+        match (pattern, scrutinee) {
+            (ExhaustingPattern::Wildcard, _) => true,
+            (
+                ExhaustingPattern::Constructor(ExhaustingConstructor::Boolean(value), _),
+                Constant::Boolean(boolean),
+            ) => *value == boolean.inner,
+            (
+                ExhaustingPattern::Constructor(ExhaustingConstructor::Range(start, end), _),
+                Constant::Integer(integer),
+            ) => start <= &integer.value && &integer.value <= end,
+            _ => false,
+        }
+    }
+
     ///
     /// Returns the constant match semantic element.
     ///
@@ -344,250 +392,79 @@ impl Analyzer {
             return Err(Error::MatchLessThanTwoBranches { location });
         }
 
-        let first_branch_expression_location = r#match.branches[0].1.location;
-        let mut is_exhausted = false;
-        let mut exhausting_data = ExhaustingData::new();
+        let first_branch_expression_location = r#match.branches[0].2.location;
+        let mut exhausting_data = ExhaustingData::new(scrutinee_type.clone());
+        let mut first_result_type = None;
         let mut match_result = None;
 
-        for (pattern, expression) in r#match.branches.into_iter() {
-            let pattern_location = pattern.location;
+        for (pattern, guard, expression) in r#match.branches.into_iter() {
             let expression_location = expression.location;
 
-            if is_exhausted {
-                return Err(Error::MatchBranchUnreachable {
-                    location: pattern.location,
-                });
-            }
-
-            let result = match pattern.variant {
-                MatchPatternVariant::BooleanLiteral(boolean) => {
-                    let location = boolean.location;
-
-                    let constant = BooleanConstant::from(boolean);
-                    let pattern_type = constant.r#type();
-                    if pattern_type != scrutinee_type {
-                        return Err(Error::MatchBranchPatternInvalidType {
-                            location: pattern_location,
-                            expected: scrutinee_type.to_string(),
-                            found: pattern_type.to_string(),
-                            reference: scrutinee_location,
-                        });
-                    }
-
-                    if let Some(duplicate) =
-                        exhausting_data.insert_boolean(constant.inner, location)
-                    {
-                        return Err(Error::MatchBranchDuplicate {
-                            location,
-                            reference: duplicate,
-                        });
-                    }
-
-                    let expression_location = expression.location;
-                    let (result, _) =
-                        ExpressionAnalyzer::new(scope_stack.top(), TranslationRule::Constant)
-                            .analyze(expression)?;
-                    match result {
-                        Element::Constant(ref result) => {
-                            if Constant::Boolean(constant) == scrutinee_result {
-                                match_result = Some(result.to_owned());
-                            }
-                        }
-                        element => {
-                            return Err(Error::ExpressionNonConstantElement {
-                                location: expression_location,
-                                found: element.to_string(),
-                            });
-                        }
-                    }
-
-                    if exhausting_data.has_exhausted_boolean() {
-                        is_exhausted = true;
-                    }
-
-                    result
-                }
-                MatchPatternVariant::IntegerLiteral(integer) => {
-                    let location = integer.location;
-
-                    let constant = IntegerConstant::try_from(&integer)?;
-                    let pattern_type = constant.r#type();
-                    if Caster::cast(&pattern_type, &scrutinee_type).is_err() {
-                        return Err(Error::MatchBranchPatternInvalidType {
-                            location: pattern_location,
-                            expected: scrutinee_type.to_string(),
-                            found: pattern_type.to_string(),
-                            reference: scrutinee_location,
-                        });
-                    }
-
-                    if let Some(duplicate) =
-                        exhausting_data.insert_integer(constant.value.clone(), None, location)
-                    {
-                        return Err(Error::MatchBranchDuplicate {
-                            location,
-                            reference: duplicate,
-                        });
-                    }
-
-                    let expression_location = expression.location;
-                    let (result, _) =
-                        ExpressionAnalyzer::new(scope_stack.top(), TranslationRule::Constant)
-                            .analyze(expression)?;
-                    match result {
-                        Element::Constant(ref result) => {
-                            if Constant::Integer(constant) == scrutinee_result {
-                                match_result = Some(result.to_owned());
-                            }
-                        }
-                        element => {
-                            return Err(Error::ExpressionNonConstantElement {
-                                location: expression_location,
-                                found: element.to_string(),
-                            });
-                        }
-                    }
-
-                    if exhausting_data.has_exhausted_integer() {
-                        is_exhausted = true;
-                    }
-
-                    result
-                }
-                MatchPatternVariant::Path(path) => {
-                    let location = path.location;
-
-                    let constant =
-                        match ExpressionAnalyzer::new(scope_stack.top(), TranslationRule::Constant)
-                            .analyze(path)?
-                        {
-                            (Element::Constant(constant), _intermediate) => {
-                                if let Constant::Integer(ref integer) = constant {
-                                    if let Some(duplicate) = exhausting_data.insert_integer(
-                                        integer.value.to_owned(),
-                                        integer.enumeration.to_owned(),
-                                        location,
-                                    ) {
-                                        return Err(Error::MatchBranchDuplicate {
-                                            location,
-                                            reference: duplicate,
-                                        });
-                                    }
-                                }
-                                constant
-                            }
-                            (element, _intermediate) => {
-                                return Err(Error::MatchBranchPatternPathExpectedConstant {
-                                    location,
-                                    found: element.to_string(),
-                                });
-                            }
-                        };
-                    let pattern_type = constant.r#type();
-                    if pattern_type != scrutinee_type {
-                        return Err(Error::MatchBranchPatternInvalidType {
-                            location: pattern_location,
-                            expected: scrutinee_type.to_string(),
-                            found: pattern_type.to_string(),
-                            reference: scrutinee_location,
-                        });
-                    }
-
-                    let expression_location = expression.location;
-                    let (result, _) =
-                        ExpressionAnalyzer::new(scope_stack.top(), TranslationRule::Constant)
-                            .analyze(expression)?;
-                    match result {
-                        Element::Constant(ref result) => {
-                            if constant == scrutinee_result {
-                                match_result = Some(result.to_owned());
-                            }
-                        }
-                        element => {
-                            return Err(Error::ExpressionNonConstantElement {
-                                location: expression_location,
-                                found: element.to_string(),
-                            });
-                        }
-                    }
-
-                    if exhausting_data.has_exhausted_integer() {
-                        is_exhausted = true;
-                    }
-
-                    result
-                }
-                MatchPatternVariant::Binding(identifier) => {
-                    is_exhausted = true;
-
-                    scope_stack.push(None, ScopeType::Block);
+            let mut branch_exhausting_data = exhausting_data.clone();
+            let alternatives = Self::alternatives(
+                scope_stack.top(),
+                pattern,
+                &scrutinee_type,
+                scrutinee_location,
+                &mut branch_exhausting_data,
+            )?;
+            let is_matched = alternatives
+                .iter()
+                .any(|(_destructuring, pattern)| Self::is_matched(pattern, &scrutinee_result));
+
+            scope_stack.push(None, ScopeType::Block);
+            if let Some((destructuring, _pattern)) = alternatives.into_iter().next() {
+                for (identifier, _type, _offset, _is_casted) in destructuring.bindings.into_iter() {
                     Scope::define_constant(
                         scope_stack.top(),
-                        identifier.clone(),
+                        identifier,
                         scrutinee_result.clone(),
                     )?;
-                    let expression_location = expression.location;
-                    let (result, _) =
-                        ExpressionAnalyzer::new(scope_stack.top(), TranslationRule::Constant)
-                            .analyze(expression)?;
-                    scope_stack.pop();
-
-                    match result {
-                        Element::Constant(ref result) => {
-                            if match_result.is_none() {
-                                match_result = Some(result.to_owned());
-                            }
-                        }
-                        element => {
-                            return Err(Error::ExpressionNonConstantElement {
-                                location: expression_location,
-                                found: element.to_string(),
-                            });
-                        }
-                    }
-
-                    result
                 }
-                MatchPatternVariant::Wildcard => {
-                    is_exhausted = true;
-                    let expression_location = expression.location;
-                    let (result, _) =
-                        ExpressionAnalyzer::new(scope_stack.top(), TranslationRule::Constant)
-                            .analyze(expression)?;
-
-                    match result {
-                        Element::Constant(ref result) => {
-                            if match_result.is_none() {
-                                match_result = Some(result.to_owned());
-                            }
-                        }
-                        element => {
-                            return Err(Error::ExpressionNonConstantElement {
-                                location: expression_location,
-                                found: element.to_string(),
-                            });
-                        }
-                    }
-
-                    result
+            }
+            let is_guarded = guard.is_some();
+            let is_taken = match guard {
+                Some(guard) => Self::constant_guard(scope_stack.top(), guard)? && is_matched,
+                None => is_matched,
+            };
+            let (result, _) = ExpressionAnalyzer::new(scope_stack.top(), TranslationRule::Constant)
+                .analyze(expression)?;
+            scope_stack.pop();
+
+            let result = match result {
+                Element::Constant(result) => result,
+                element => {
+                    return Err(Error::ExpressionNonConstantElement {
+                        location: expression_location,
+                        found: element.to_string(),
+                    });
                 }
             };
 
-            let result_type = Type::from_element(&result, scope_stack.top())?;
-            if let Some(ref match_result) = match_result {
-                let match_result_type = match_result.r#type();
-                if result_type != match_result_type {
+            if !is_guarded {
+                exhausting_data = branch_exhausting_data;
+            }
+
+            let result_type = result.r#type();
+            match first_result_type {
+                Some(ref first_result_type) if &result_type != first_result_type => {
                     return Err(Error::MatchBranchExpressionInvalidType {
                         location: expression_location,
-                        expected: match_result_type.to_string(),
+                        expected: first_result_type.to_string(),
                         found: result_type.to_string(),
                         reference: first_branch_expression_location,
                     });
                 }
+                Some(_) => {}
+                None => first_result_type = Some(result_type),
+            }
+
+            if is_taken && match_result.is_none() {
+                match_result = Some(result);
             }
         }
 
-        if !is_exhausted {
+        if !exhausting_data.is_exhausted() {
             return Err(Error::MatchNotExhausted { location });
         }
 
@@ -400,3 +400,315 @@ fn main() {
 
     assert_eq!(result, expected);
 }
+
+#[test]
+fn ok_data_enumeration_refutable_payload() {
+    let input = r#"
+use std::option::Option;
+
+fn main(value: Option<u8>) -> u8 {
+    match value {
+        Option::Some(0) => 1,
+        Option::Some(inner) => inner,
+        Option::None => 0,
+    }
+}
+"#;
+
+    assert!(crate::semantic::tests::compile_entry(input).is_ok());
+}
+
+#[test]
+fn error_not_exhausted_data_enumeration() {
+    let input = r#"
+use std::option::Option;
+
+fn main(value: Option<u8>) -> u8 {
+    match value {
+        Option::Some(0) => 1,
+        Option::None => 0,
+    }
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::MatchNotExhausted {
+        location: Location::test(5, 5),
+    }));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_branch_unreachable_data_enumeration() {
+    let input = r#"
+use std::option::Option;
+
+fn main(value: Option<u8>) -> u8 {
+    match value {
+        Option::Some(inner) => inner,
+        Option::Some(42) => 42,
+        Option::None => 0,
+    }
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::MatchBranchUnreachable {
+        location: Location::test(7, 9),
+    }));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_branch_pattern_payload_mismatch() {
+    let input = r#"
+use std::option::Option;
+
+fn main(value: Option<u8>) -> u8 {
+    match value {
+        Option::Some => 1,
+        Option::None => 0,
+    }
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::TypeVariantPayloadMismatch {
+        location: Location::test(6, 9),
+        variant: "Option<u8>::Some".to_owned(),
+        expected: "a tuple payload".to_owned(),
+        found: "no payload".to_owned(),
+    }));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn ok_tuple() {
+    let input = r#"
+fn main(value: (u8, bool)) -> u8 {
+    match value {
+        (0, _) => 0,
+        (inner, true) => inner,
+        (_, false) => 1,
+    }
+}
+"#;
+
+    assert!(crate::semantic::tests::compile_entry(input).is_ok());
+}
+
+#[test]
+fn ok_structure() {
+    let input = r#"
+struct Point {
+    x: u8,
+    y: u8,
+}
+
+fn main(point: Point) -> u8 {
+    match point {
+        Point { x: 0, y } => y,
+        Point { x } => x,
+    }
+}
+"#;
+
+    assert!(crate::semantic::tests::compile_entry(input).is_ok());
+}
+
+#[test]
+fn ok_alternatives_and_ranges() {
+    let input = r#"
+fn main(value: u8) -> u8 {
+    match value {
+        1 | 2 => 10,
+        3..=9 => 20,
+        _ => 0,
+    }
+}
+"#;
+
+    assert!(crate::semantic::tests::compile_entry(input).is_ok());
+}
+
+#[test]
+fn ok_ranges_exhaustive() {
+    let input = r#"
+fn main(value: u8) -> u8 {
+    match value {
+        0..=127 => 0,
+        128..=255 => 1,
+    }
+}
+"#;
+
+    assert!(crate::semantic::tests::compile_entry(input).is_ok());
+}
+
+#[test]
+fn ok_guard() {
+    let input = r#"
+fn main(value: (u8, u8)) -> u8 {
+    match value {
+        (a, b) if a > b => a,
+        (_, b) => b,
+    }
+}
+"#;
+
+    assert!(crate::semantic::tests::compile_entry(input).is_ok());
+}
+
+#[test]
+fn ok_constant_range() {
+    let input = r#"
+const VALUE: u8 = match 42 {
+    0..=9 => 1,
+    10..=99 if true => 2,
+    _ => 3,
+};
+
+fn main() -> u8 {
+    VALUE
+}
+"#;
+
+    assert!(crate::semantic::tests::compile_entry(input).is_ok());
+}
+
+#[test]
+fn error_not_exhausted_tuple() {
+    let input = r#"
+fn main(value: (bool, bool)) -> u8 {
+    match value {
+        (true, _) => 1,
+        (_, true) => 2,
+    }
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::MatchNotExhausted {
+        location: Location::test(3, 5),
+    }));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_not_exhausted_guard() {
+    let input = r#"
+fn main(value: u8) -> u8 {
+    match value {
+        0..=10 => 0,
+        inner if inner > 10 => 1,
+    }
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::MatchNotExhausted {
+        location: Location::test(3, 5),
+    }));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_branch_unreachable_alternative() {
+    let input = r#"
+fn main(value: u8) -> u8 {
+    match value {
+        0..=9 => 0,
+        10 | 5 => 1,
+        _ => 2,
+    }
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::MatchBranchUnreachable {
+        location: Location::test(5, 14),
+    }));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_branch_guard_expected_boolean() {
+    let input = r#"
+fn main(value: u8) -> u8 {
+    match value {
+        inner if inner => 0,
+        _ => 1,
+    }
+}
+"#;
+
+    let expected = Err(Error::Semantic(
+        SemanticError::MatchBranchGuardExpectedBoolean {
+            location: Location::test(4, 18),
+            found: Type::integer_unsigned(None, zinc_const::bitlength::BYTE).to_string(),
+        },
+    ));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_branch_pattern_range_invalid() {
+    let input = r#"
+fn main(value: u8) -> u8 {
+    match value {
+        9..=1 => 0,
+        _ => 1,
+    }
+}
+"#;
+
+    let expected = Err(Error::Semantic(
+        SemanticError::MatchBranchPatternRangeInvalid {
+            location: Location::test(4, 9),
+            start: "9".to_owned(),
+            end: "1".to_owned(),
+        },
+    ));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_branch_alternative_binding_mismatch() {
+    let input = r#"
+fn main(value: (u8, u8)) -> u8 {
+    match value {
+        (inner, 0) | (0, other) => 0,
+        _ => 1,
+    }
+}
+"#;
+
+    let expected = Err(Error::Semantic(
+        SemanticError::MatchBranchAlternativeBindingMismatch {
+            location: Location::test(4, 22),
+            name: "inner".to_owned(),
+        },
+    ));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
@@ -20,6 +20,7 @@ pub mod structure;
 pub mod tuple;
 pub mod tuple_index;
 pub mod r#type;
+pub mod variant;
 
 use std::cell::RefCell;
 use std::ops::Add;
@@ -79,6 +80,7 @@ use self::stack::Stack as EvaluationStack;
 use self::structure::Analyzer as StructureAnalyzer;
 use self::tuple::Analyzer as TupleAnalyzer;
 use self::tuple_index::Analyzer as TupleIndexAnalyzer;
+use self::variant::Analyzer as VariantAnalyzer;
 
 ///
 /// The expression semantic analyzer.
@@ -537,6 +539,7 @@ impl Analyzer {
 
                 ExpressionOperator::Call => {
                     self.left_local(tree.left, operator, rule)?;
+                    self.variant_tag()?;
 
                     // forces the constant translation rule, which prevents the arguments to be written to the IR
                     let rule = match self.evaluation_stack.top() {
@@ -566,9 +569,12 @@ impl Analyzer {
 
                 ExpressionOperator::Structure => {
                     self.left_local(tree.left, operator, rule)?;
+                    self.variant_tag()?;
                     self.right_local(tree.right, operator, rule)?;
 
-                    self.structure()?;
+                    if let Some(intermediate) = self.structure()? {
+                        self.intermediate.push_operand(intermediate);
+                    }
                 }
             },
         }
@@ -1072,7 +1078,10 @@ impl Analyzer {
     ///
     /// Analyzes the structure initialization operation.
     ///
-    fn structure(&mut self) -> Result<(), Error> {
+    /// If the enumeration variant is initialized with a structure literal, the padding is
+    /// returned to be written after the literal.
+    ///
+    fn structure(&mut self) -> Result<Option<GeneratorExpressionOperand>, Error> {
         let (operand_2, _) = Self::evaluate(
             self.scope_stack.top(),
             self.evaluation_stack.pop(),
@@ -1084,9 +1093,38 @@ impl Analyzer {
             TranslationRule::Type,
         )?;
 
+        if let Element::Path(ref path) = operand_1 {
+            if let Some((enumeration, index, _tag)) =
+                VariantAnalyzer::resolve(self.scope_stack.top(), path)?
+            {
+                let (result, padding) =
+                    VariantAnalyzer::structure(path.location, enumeration, index, operand_2)?;
+                self.evaluation_stack.push(StackElement::Evaluated(result));
+
+                return Ok(Some(GeneratorExpressionOperand::Constant(padding)));
+            }
+        }
+
         let result = Element::structure(operand_1, operand_2, self.scope_stack.top())?;
         self.evaluation_stack.push(StackElement::Evaluated(result));
 
+        Ok(None)
+    }
+
+    ///
+    /// Writes the variant tag, if the called or initialized item on the top of the evaluation
+    /// stack is a data-carrying enumeration variant.
+    ///
+    /// The tag must precede the payload, which is written by the right operand.
+    ///
+    fn variant_tag(&mut self) -> Result<(), Error> {
+        if let StackElement::Evaluated(ref element) = self.evaluation_stack.top() {
+            if let Some(tag) = VariantAnalyzer::tag(self.scope_stack.top(), element)? {
+                self.intermediate
+                    .push_operand(GeneratorExpressionOperand::Constant(tag));
+            }
+        }
+
         Ok(())
     }
 
@@ -1109,6 +1147,7 @@ impl Analyzer {
                 ExpressionOperand::LiteralString(inner) => {
                     Ok((LiteralAnalyzer::string(inner)?, None))
                 }
+                ExpressionOperand::LiteralByteString(inner) => LiteralAnalyzer::byte_string(inner),
                 ExpressionOperand::TupleIndex(inner) => {
                     Ok((TupleIndexAnalyzer::integer(inner)?, None))
                 }
@@ -1134,6 +1173,9 @@ impl Analyzer {
                     ConditionalAnalyzer::analyze(scope, inner, rule)
                 }
                 ExpressionOperand::Match(inner) => MatchAnalyzer::analyze(scope, inner, rule),
+                ExpressionOperand::Closure(inner) => Err(Error::ClosureOutsideOfArgument {
+                    location: inner.location,
+                }),
             },
             StackElement::Evaluated(inner) => match inner {
                 Element::Path(path) => PathTranslator::translate(scope, path, rule),
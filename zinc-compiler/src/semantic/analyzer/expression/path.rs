@@ -8,6 +8,7 @@ use std::rc::Rc;
 use crate::generator::expression::operand::constant::Constant as GeneratorConstant;
 use crate::generator::expression::operand::Operand as GeneratorExpressionOperand;
 use crate::generator::r#type::Type as GeneratorType;
+use crate::semantic::analyzer::expression::variant::Analyzer as VariantAnalyzer;
 use crate::semantic::analyzer::rule::Rule as TranslationRule;
 use crate::semantic::element::path::Path;
 use crate::semantic::element::place::memory_type::MemoryType;
@@ -60,6 +61,17 @@ impl Translator {
                     ))
                 }
                 ScopeItem::Variant(ref variant) => {
+                    if let Some((enumeration, index, tag)) =
+                        VariantAnalyzer::from_constant(&variant.constant)
+                    {
+                        let (element, intermediate) =
+                            VariantAnalyzer::unit(location, enumeration, index, tag)?;
+                        return Ok((
+                            element,
+                            Some(GeneratorExpressionOperand::Constant(intermediate)),
+                        ));
+                    }
+
                     let mut constant = variant.constant.to_owned();
                     constant.set_location(location);
 
@@ -81,6 +93,10 @@ impl Translator {
                     location,
                     found: field.identifier.to_owned(),
                 }),
+                ScopeItem::Implementation(_) => Err(Error::ScopeItemUndeclared {
+                    location,
+                    name: path_last_identifier.name,
+                }),
             },
             TranslationRule::Value => match *Scope::resolve_path(scope, &path)?.borrow() {
                 ScopeItem::Variable(ref variable) => {
@@ -113,6 +129,17 @@ impl Translator {
                     Ok((element, intermediate))
                 }
                 ScopeItem::Variant(ref variant) => {
+                    if let Some((enumeration, index, tag)) =
+                        VariantAnalyzer::from_constant(&variant.constant)
+                    {
+                        let (element, intermediate) =
+                            VariantAnalyzer::unit(location, enumeration, index, tag)?;
+                        return Ok((
+                            element,
+                            Some(GeneratorExpressionOperand::Constant(intermediate)),
+                        ));
+                    }
+
                     let mut constant = variant.constant.to_owned();
                     constant.set_location(location);
 
@@ -143,6 +170,10 @@ impl Translator {
                     location,
                     found: field.identifier.to_owned(),
                 }),
+                ScopeItem::Implementation(_) => Err(Error::ScopeItemUndeclared {
+                    location,
+                    name: path_last_identifier.name,
+                }),
             },
             TranslationRule::Constant => match *Scope::resolve_path(scope, &path)?.borrow() {
                 ScopeItem::Constant(ref constant) => {
@@ -0,0 +1,181 @@
+//!
+//! The enumeration variant constructor semantic analyzer.
+//!
+
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use zinc_lexical::Location;
+
+use crate::generator::expression::operand::constant::Constant as GeneratorConstant;
+use crate::semantic::element::constant::Constant;
+use crate::semantic::element::path::Path;
+use crate::semantic::element::r#type::enumeration::payload::Payload as EnumerationPayload;
+use crate::semantic::element::r#type::enumeration::Enumeration as EnumerationType;
+use crate::semantic::element::r#type::i_typed::ITyped;
+use crate::semantic::element::r#type::Type;
+use crate::semantic::element::value::enumeration::Enumeration as EnumerationValue;
+use crate::semantic::element::value::Value;
+use crate::semantic::element::Element;
+use crate::semantic::error::Error;
+use crate::semantic::scope::item::Item as ScopeItem;
+use crate::semantic::scope::Scope;
+
+///
+/// The enumeration variant constructor semantic analyzer.
+///
+/// The data-carrying enumeration values are written as the variant tag, the payload, and
+/// the zero padding. The tag is written before the payload expression is analyzed, so the
+/// payload and padding follow it in the data stack.
+///
+pub struct Analyzer {}
+
+impl Analyzer {
+    ///
+    /// Resolves the `path` to a data-carrying enumeration variant.
+    ///
+    /// Returns the enumeration type, the variant index, and the variant tag constant,
+    /// or `None` if the path points to something else.
+    ///
+    pub fn resolve(
+        scope: Rc<RefCell<Scope>>,
+        path: &Path,
+    ) -> Result<Option<(EnumerationType, usize, GeneratorConstant)>, Error> {
+        let constant = match *Scope::resolve_path(scope, path)?.borrow() {
+            ScopeItem::Variant(ref variant) => variant.constant.to_owned(),
+            _ => return Ok(None),
+        };
+
+        Ok(Self::from_constant(&constant))
+    }
+
+    ///
+    /// Checks if the `constant` is a data-carrying enumeration variant.
+    ///
+    /// Returns the enumeration type, the variant index, and the variant tag constant.
+    ///
+    pub fn from_constant(
+        constant: &Constant,
+    ) -> Option<(EnumerationType, usize, GeneratorConstant)> {
+        match constant {
+            Constant::Integer(ref integer) => {
+                let enumeration = integer.enumeration.as_ref()?;
+                if !enumeration.is_data() {
+                    return None;
+                }
+
+                let index = enumeration.variant_index(&integer.value)?;
+                let tag = GeneratorConstant::try_from_semantic(constant)?;
+                Some((enumeration.to_owned(), index, tag))
+            }
+            _ => None,
+        }
+    }
+
+    ///
+    /// Returns the variant tag, if the `element` is a path to a data-carrying enumeration
+    /// variant, which is going to be called or initialized with a structure literal.
+    ///
+    pub fn tag(
+        scope: Rc<RefCell<Scope>>,
+        element: &Element,
+    ) -> Result<Option<GeneratorConstant>, Error> {
+        match element {
+            Element::Path(path) => {
+                Ok(Self::resolve(scope, path)?.map(|(_enumeration, _index, tag)| tag))
+            }
+            _ => Ok(None),
+        }
+    }
+
+    ///
+    /// Analyzes the unit variant, e.g. `Option::None`.
+    ///
+    /// Returns the value and the tag followed by the padding.
+    ///
+    pub fn unit(
+        location: Location,
+        enumeration: EnumerationType,
+        index: usize,
+        tag: GeneratorConstant,
+    ) -> Result<(Element, GeneratorConstant), Error> {
+        let (value, padding) = EnumerationValue::try_from_variant(
+            location,
+            enumeration,
+            index,
+            EnumerationPayload::Unit,
+        )?;
+
+        Ok((
+            Element::Value(Value::Enumeration(value)),
+            GeneratorConstant::Group(vec![tag, padding]),
+        ))
+    }
+
+    ///
+    /// Analyzes the tuple variant constructor, e.g. `Option::Some(42)`.
+    ///
+    /// Returns the value and the padding.
+    ///
+    pub fn tuple(
+        scope: Rc<RefCell<Scope>>,
+        location: Location,
+        enumeration: EnumerationType,
+        index: usize,
+        arguments: Vec<Element>,
+    ) -> Result<(Element, GeneratorConstant), Error> {
+        let mut types = Vec::with_capacity(arguments.len());
+        for argument in arguments.iter() {
+            types.push(Type::from_element(argument, scope.clone())?);
+        }
+
+        let (value, padding) = EnumerationValue::try_from_variant(
+            location,
+            enumeration,
+            index,
+            EnumerationPayload::Tuple(types),
+        )?;
+
+        Ok((Element::Value(Value::Enumeration(value)), padding))
+    }
+
+    ///
+    /// Analyzes the structure variant constructor, e.g. `Shape::Circle { radius: 5 }`.
+    ///
+    /// Returns the value and the padding.
+    ///
+    pub fn structure(
+        location: Location,
+        enumeration: EnumerationType,
+        index: usize,
+        literal: Element,
+    ) -> Result<(Element, GeneratorConstant), Error> {
+        let fields: Vec<(String, Type)> = match literal {
+            Element::Value(Value::Structure(ref structure)) => structure
+                .fields
+                .iter()
+                .map(|(name, _location, r#type)| (name.to_owned(), r#type.to_owned()))
+                .collect(),
+            Element::Constant(Constant::Structure(ref structure)) => structure
+                .values
+                .iter()
+                .map(|(identifier, constant)| (identifier.name.to_owned(), constant.r#type()))
+                .collect(),
+            element => {
+                return Err(Error::OperatorStructureSecondOperandExpectedLiteral {
+                    location: element.location().unwrap_or(location),
+                    found: element.to_string(),
+                })
+            }
+        };
+
+        let (value, padding) = EnumerationValue::try_from_variant(
+            location,
+            enumeration,
+            index,
+            EnumerationPayload::Structure(fields),
+        )?;
+
+        Ok((Element::Value(Value::Enumeration(value)), padding))
+    }
+}
@@ -37,6 +37,8 @@ impl Analyzer {
     /// Returns the module without the hoisted statements and the implementation scopes which
     /// must be defined forcibly.
     ///
+    /// The declaration errors of the hoisted statements are collected and returned at once.
+    ///
     #[allow(clippy::type_complexity)]
     pub fn declare(
         scope: Rc<RefCell<Scope>>,
@@ -49,82 +51,88 @@ impl Analyzer {
         let mut instant_statements = Vec::with_capacity(module.statements.len());
         let mut implementation_scopes = Vec::with_capacity(module.statements.len());
 
+        let mut errors = Vec::new();
         for hoisted_statement in module.statements.into_iter() {
-            match hoisted_statement {
+            let result = match hoisted_statement {
                 ModuleLocalStatement::Const(statement) => {
-                    Scope::declare_constant(scope.clone(), statement)?;
+                    Scope::declare_constant(scope.clone(), statement)
                 }
                 ModuleLocalStatement::Type(statement) => {
-                    Scope::declare_type(scope.clone(), TypeStatementVariant::Type(statement))?;
+                    Scope::declare_type(scope.clone(), TypeStatementVariant::Type(statement))
                 }
                 ModuleLocalStatement::Struct(statement) => {
-                    Scope::declare_type(scope.clone(), TypeStatementVariant::Struct(statement))?;
+                    Scope::declare_type(scope.clone(), TypeStatementVariant::Struct(statement))
                 }
                 ModuleLocalStatement::Enum(statement) => {
-                    Scope::declare_type(scope.clone(), TypeStatementVariant::Enum(statement))?;
+                    Scope::declare_type(scope.clone(), TypeStatementVariant::Enum(statement))
                 }
                 ModuleLocalStatement::Fn(statement) => {
                     if !is_entry
                         && statement.identifier.name.as_str()
                             == zinc_const::source::FUNCTION_MAIN_IDENTIFIER
                     {
-                        return Err(Error::FunctionMainBeyondEntry {
+                        Err(Error::FunctionMainBeyondEntry {
                             location: statement.location,
-                        });
-                    }
-
-                    if is_entry
+                        })
+                    } else if is_entry
                         && statement.identifier.name.as_str()
                             == zinc_const::source::FUNCTION_MAIN_IDENTIFIER
                         && statement.is_constant
                     {
-                        return Err(Error::EntryPointConstant {
+                        Err(Error::EntryPointConstant {
                             location: statement.location,
-                        });
+                        })
+                    } else {
+                        Scope::declare_type(scope.clone(), TypeStatementVariant::Fn(statement))
                     }
-
-                    Scope::declare_type(scope.clone(), TypeStatementVariant::Fn(statement))?;
                 }
                 ModuleLocalStatement::Mod(statement) => {
-                    let module = match modules.remove(statement.identifier.name.as_str()) {
-                        Some(module) => module,
-                        None => {
-                            return Err(Error::ModuleFileNotFound {
-                                location: statement.identifier.location,
-                                name: statement.identifier.name,
-                            });
+                    match modules.remove(statement.identifier.name.as_str()) {
+                        Some(module) => {
+                            ModStatementAnalyzer::analyze(statement).and_then(|identifier| {
+                                Scope::declare_module(
+                                    scope.clone(),
+                                    identifier,
+                                    module,
+                                    scope_crate.clone(),
+                                    dependencies.clone(),
+                                )
+                            })
                         }
-                    };
-
-                    let identifier = ModStatementAnalyzer::analyze(statement)?;
-
-                    Scope::declare_module(
-                        scope.clone(),
-                        identifier,
-                        module,
-                        scope_crate.clone(),
-                        dependencies.clone(),
-                    )?;
+                        None => Err(Error::ModuleFileNotFound {
+                            location: statement.identifier.location,
+                            name: statement.identifier.name,
+                        }),
+                    }
                 }
                 ModuleLocalStatement::Contract(statement) => {
                     if is_entry {
-                        Scope::declare_contract(scope.clone(), statement)?;
+                        Scope::declare_contract(scope.clone(), statement)
                     } else {
-                        return Err(Error::ContractBeyondEntry {
+                        Err(Error::ContractBeyondEntry {
                             location: statement.location,
-                        });
+                        })
                     }
                 }
+                ModuleLocalStatement::Trait(statement) => {
+                    Scope::declare_type(scope.clone(), TypeStatementVariant::Trait(statement))
+                }
                 ModuleLocalStatement::Impl(statement) => {
-                    let scope = ImplStatementAnalyzer::declare(scope.clone(), statement)?;
-                    implementation_scopes.push(scope);
+                    ImplStatementAnalyzer::declare(scope.clone(), statement)
+                        .map(|scope| implementation_scopes.push(scope))
                 }
                 ModuleLocalStatement::Use(statement) => {
-                    instant_statements.push(ModuleLocalStatement::Use(statement))
+                    instant_statements.push(ModuleLocalStatement::Use(statement));
+                    Ok(())
                 }
-                ModuleLocalStatement::Empty(_location) => {}
+                ModuleLocalStatement::Empty(_location) => Ok(()),
+            };
+
+            if let Err(error) = result {
+                Error::push(&mut errors, error);
             }
         }
+        Error::collect(errors)?;
 
         module.statements = instant_statements;
 
@@ -137,6 +145,8 @@ impl Analyzer {
     /// 3. Resolves the implementation scopes forcibly.
     /// 4. Resolves the hoisted items forcibly.
     ///
+    /// The errors are collected through all the steps and returned at once.
+    ///
     pub fn define(
         scope: Rc<RefCell<Scope>>,
         module: SyntaxModule,
@@ -149,18 +159,26 @@ impl Analyzer {
             Scope::insert_item(scope.clone(), Keyword::Super.to_string(), super_item);
         }
 
+        let mut errors = Vec::new();
+
         for statement in module.statements.into_iter() {
             if let ModuleLocalStatement::Use(statement) = statement {
-                UseStatementAnalyzer::define(scope.clone(), statement)?;
+                if let Err(error) = UseStatementAnalyzer::define(scope.clone(), statement) {
+                    Error::push(&mut errors, error);
+                }
             }
         }
 
         for implementation_scope in implementation_scopes.into_iter() {
-            implementation_scope.borrow().define()?;
+            if let Err(error) = implementation_scope.borrow().define() {
+                Error::push(&mut errors, error);
+            }
         }
 
-        scope.borrow().define()?;
+        if let Err(error) = scope.borrow().define() {
+            Error::push(&mut errors, error);
+        }
 
-        Ok(())
+        Error::collect(errors)
     }
 }
@@ -0,0 +1,38 @@
+//!
+//! The `break` statement semantic analyzer.
+//!
+
+#[cfg(test)]
+mod tests;
+
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use zinc_syntax::BreakStatement;
+
+use crate::generator::statement::r#break::Statement as GeneratorBreakStatement;
+use crate::semantic::error::Error;
+use crate::semantic::scope::Scope;
+
+///
+/// The `break` statement semantic analyzer.
+///
+pub struct Analyzer {}
+
+impl Analyzer {
+    ///
+    /// Analyzes the `break` statement and returns its IR for the next compiler phase.
+    ///
+    pub fn analyze(
+        scope: Rc<RefCell<Scope>>,
+        statement: BreakStatement,
+    ) -> Result<GeneratorBreakStatement, Error> {
+        let location = statement.location;
+
+        if !Scope::interrupt_loop(scope) {
+            return Err(Error::BreakStatementBeyondLoop { location });
+        }
+
+        Ok(GeneratorBreakStatement::new(location))
+    }
+}
@@ -0,0 +1,38 @@
+//!
+//! The `continue` statement semantic analyzer.
+//!
+
+#[cfg(test)]
+mod tests;
+
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use zinc_syntax::ContinueStatement;
+
+use crate::generator::statement::r#continue::Statement as GeneratorContinueStatement;
+use crate::semantic::error::Error;
+use crate::semantic::scope::Scope;
+
+///
+/// The `continue` statement semantic analyzer.
+///
+pub struct Analyzer {}
+
+impl Analyzer {
+    ///
+    /// Analyzes the `continue` statement and returns its IR for the next compiler phase.
+    ///
+    pub fn analyze(
+        scope: Rc<RefCell<Scope>>,
+        statement: ContinueStatement,
+    ) -> Result<GeneratorContinueStatement, Error> {
+        let location = statement.location;
+
+        if !Scope::interrupt_loop(scope) {
+            return Err(Error::ContinueStatementBeyondLoop { location });
+        }
+
+        Ok(GeneratorContinueStatement::new(location))
+    }
+}
@@ -0,0 +1,63 @@
+//!
+//! The `continue` statement tests.
+//!
+
+use zinc_lexical::Location;
+
+use crate::error::Error;
+use crate::semantic::error::Error as SemanticError;
+
+#[test]
+fn ok_ordinar() {
+    let input = r#"
+fn main() {
+    for i in 0..10 {
+        if i == 5 {
+            continue;
+        }
+        dbg!("{}", i);
+    }
+}
+"#;
+
+    assert!(crate::semantic::tests::compile_entry(input).is_ok());
+}
+
+#[test]
+fn ok_nested_loops() {
+    let input = r#"
+fn main() {
+    for i in 0..10 {
+        for j in 0..10 {
+            if j > i {
+                continue;
+            }
+            dbg!("{} {}", i, j);
+        }
+    }
+}
+"#;
+
+    assert!(crate::semantic::tests::compile_entry(input).is_ok());
+}
+
+#[test]
+fn error_beyond_loop() {
+    let input = r#"
+fn main() {
+    if true {
+        continue;
+    }
+}
+"#;
+
+    let expected = Err(Error::Semantic(
+        SemanticError::ContinueStatementBeyondLoop {
+            location: Location::test(4, 9),
+        },
+    ));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
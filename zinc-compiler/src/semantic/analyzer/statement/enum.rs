@@ -6,9 +6,17 @@ use std::cell::RefCell;
 use std::rc::Rc;
 
 use zinc_syntax::EnumStatement;
+use zinc_syntax::VariantPayload;
 
+use crate::semantic::element::r#type::enumeration::payload::Payload as EnumerationPayload;
+use crate::semantic::element::r#type::enumeration::template::Template as EnumerationTemplate;
+use crate::semantic::element::r#type::enumeration::Enumeration;
 use crate::semantic::element::r#type::Type;
 use crate::semantic::error::Error;
+use crate::semantic::scope::item::r#type::index::INDEX as TYPE_INDEX;
+use crate::semantic::scope::item::r#type::Type as ScopeTypeItem;
+use crate::semantic::scope::item::Item as ScopeItem;
+use crate::semantic::scope::r#type::Type as ScopeType;
 use crate::semantic::scope::Scope;
 
 ///
@@ -20,15 +28,177 @@ impl Analyzer {
     ///
     /// Defines a compile-time only enumeration type.
     ///
+    /// The generic enumeration is defined as a template, whose payloads are resolved separately
+    /// for each instance.
+    ///
     pub fn define(scope: Rc<RefCell<Scope>>, statement: EnumStatement) -> Result<Type, Error> {
+        if let Some(ref generics) = statement.generics {
+            let generics = generics
+                .iter()
+                .map(|generic| generic.identifier.name.to_owned())
+                .collect();
+
+            return Type::generic_enumeration(
+                statement.location,
+                statement.identifier.name.clone(),
+                statement.variants.clone(),
+                generics,
+                EnumerationTemplate::new(statement, scope.clone()),
+                scope,
+            );
+        }
+
+        let payloads = Self::payloads(scope.clone(), &statement)?;
+
         let r#type = Type::enumeration(
             statement.location,
             statement.identifier.name,
             statement.variants,
+            payloads,
             vec![],
             scope,
         )?;
 
+        if !r#type.is_instantiatable(false) {
+            return Err(Error::TypeInstantiationForbidden {
+                location: statement.location,
+                found: r#type.to_string(),
+            });
+        }
+
         Ok(r#type)
     }
+
+    ///
+    /// Instantiates the generic enumeration with the generic `arguments`.
+    ///
+    /// The arguments are declared as type aliases in the instance scope, which is a child of
+    /// the generic enumeration one, so the payloads are resolved as in a non-generic enumeration.
+    ///
+    pub fn instantiate(
+        scope: Rc<RefCell<Scope>>,
+        statement: EnumStatement,
+        arguments: Vec<Type>,
+    ) -> Result<Enumeration, Error> {
+        let generics = statement.generics.to_owned().unwrap_or_default();
+        if generics.len() != arguments.len() {
+            return Err(Error::TypeInvalidGenericsNumber {
+                location: statement.location,
+                r#type: statement.identifier.name,
+                expected: generics.len(),
+                found: arguments.len(),
+            });
+        }
+
+        let identifier = format!(
+            "{}<{}>",
+            statement.identifier.name,
+            arguments
+                .iter()
+                .map(|argument| argument.to_string())
+                .collect::<Vec<String>>()
+                .join(", ")
+        );
+
+        let scope = Scope::new_child(identifier.clone(), ScopeType::Enumeration, scope);
+        for (generic, argument) in generics.iter().zip(arguments.iter()) {
+            let item = ScopeItem::Type(ScopeTypeItem::new_defined(
+                Some(generic.identifier.location),
+                argument.to_owned(),
+                false,
+                None,
+            ))
+            .wrap();
+            Scope::define_item(scope.clone(), generic.identifier.to_owned(), item)?;
+        }
+
+        let payloads = Self::payloads(scope.clone(), &statement)?.unwrap_or_default();
+
+        let type_id = TYPE_INDEX.next(format!("enumeration {}", identifier));
+        let mut enumeration = Enumeration::new(
+            statement.location,
+            identifier,
+            type_id,
+            statement.variants.as_slice(),
+            Some(payloads),
+            generics
+                .into_iter()
+                .map(|generic| generic.identifier.name)
+                .collect(),
+            scope,
+        )?;
+        enumeration.params = Some(arguments);
+
+        let r#type = Type::Enumeration(enumeration);
+        if !r#type.is_instantiatable(false) {
+            return Err(Error::TypeInstantiationForbidden {
+                location: statement.location,
+                found: r#type.to_string(),
+            });
+        }
+
+        match r#type {
+            Type::Enumeration(enumeration) => Ok(enumeration),
+            _ => panic!(zinc_const::panic::VALIDATED_DURING_SEMANTIC_ANALYSIS),
+        }
+    }
+
+    ///
+    /// Resolves the variant payloads in the `scope`, checking for duplicate fields.
+    ///
+    /// Returns `None` if no variant carries a payload, that is, for a C-like enumeration.
+    ///
+    fn payloads(
+        scope: Rc<RefCell<Scope>>,
+        statement: &EnumStatement,
+    ) -> Result<Option<Vec<EnumerationPayload>>, Error> {
+        if statement
+            .variants
+            .iter()
+            .all(|variant| variant.payload.is_none())
+        {
+            return Ok(None);
+        }
+
+        let mut payloads = Vec::with_capacity(statement.variants.len());
+        for variant in statement.variants.iter() {
+            let payload = match variant.payload {
+                None => EnumerationPayload::Unit,
+                Some(VariantPayload::Tuple(ref types)) => {
+                    let mut result = Vec::with_capacity(types.len());
+                    for r#type in types.iter() {
+                        result.push(Type::try_from_syntax(r#type.to_owned(), scope.clone())?);
+                    }
+                    EnumerationPayload::Tuple(result)
+                }
+                Some(VariantPayload::Structure(ref fields)) => {
+                    let mut result: Vec<(String, Type)> = Vec::with_capacity(fields.len());
+                    for field in fields.iter() {
+                        if result
+                            .iter()
+                            .any(|(name, _type)| name == &field.identifier.name)
+                        {
+                            return Err(Error::TypeDuplicateField {
+                                location: field.location,
+                                r#type: format!(
+                                    "{}::{}",
+                                    statement.identifier.name, variant.identifier.name
+                                ),
+                                field_name: field.identifier.name.to_owned(),
+                            });
+                        }
+
+                        result.push((
+                            field.identifier.name.to_owned(),
+                            Type::try_from_syntax(field.r#type.to_owned(), scope.clone())?,
+                        ));
+                    }
+                    EnumerationPayload::Structure(result)
+                }
+            };
+            payloads.push(payload);
+        }
+
+        Ok(Some(payloads))
+    }
 }
@@ -8,8 +8,10 @@ use std::rc::Rc;
 
 use zinc_lexical::Keyword;
 use zinc_syntax::FnStatement;
+use zinc_syntax::FunctionLocalStatement;
 use zinc_syntax::Identifier;
 
+use crate::generator::r#type::Type as GeneratorType;
 use crate::generator::statement::r#fn::role::Role as GeneratorFunctionRole;
 use crate::generator::statement::r#fn::Statement as GeneratorFunctionStatement;
 use crate::semantic::analyzer::attribute::Attribute;
@@ -18,6 +20,8 @@ use crate::semantic::analyzer::rule::Rule as TranslationRule;
 use crate::semantic::binding::Binder;
 use crate::semantic::element::r#type::Type;
 use crate::semantic::error::Error;
+use crate::semantic::scope::item::r#type::Type as ScopeTypeItem;
+use crate::semantic::scope::item::Item as ScopeItem;
 use crate::semantic::scope::r#type::Type as ScopeType;
 use crate::semantic::scope::stack::Stack as ScopeStack;
 use crate::semantic::scope::Scope;
@@ -49,6 +53,28 @@ impl Analyzer {
             attributes.push(attribute);
         }
 
+        if statement.generics.is_some() {
+            let is_contract_entry = statement.is_public
+                && matches!(RefCell::borrow(&scope).r#type(), ScopeType::Contract);
+            if statement.is_constant || is_contract_entry || attributes.contains(&Attribute::Test) {
+                return Err(Error::FunctionGenericsForbidden {
+                    location: statement.location,
+                    function: statement.identifier.name,
+                });
+            }
+
+            return Ok((
+                Type::generic_function(
+                    statement.location,
+                    statement.identifier.name.clone(),
+                    statement,
+                    attributes,
+                    scope,
+                ),
+                None,
+            ));
+        }
+
         if attributes.contains(&Attribute::Test) {
             return Self::test(scope, statement, attributes)
                 .map(|(r#type, intermediate)| (r#type, Some(intermediate)));
@@ -57,18 +83,59 @@ impl Analyzer {
         if statement.is_constant {
             Self::constant(scope, statement, attributes).map(|r#type| (r#type, None))
         } else {
-            Self::runtime(scope, statement, attributes)
+            Self::runtime(scope, statement, attributes, vec![])
                 .map(|(r#type, intermediate)| (r#type, Some(intermediate)))
         }
     }
 
+    ///
+    /// Analyzes a generic function statement instance with the generic `arguments` and returns
+    /// its IR for the next compiler phase.
+    ///
+    /// The instance identifier is extended with the generic arguments, e.g. `max<u8>`, so each
+    /// instance becomes a separate runtime function.
+    ///
+    pub fn instantiate(
+        scope: Rc<RefCell<Scope>>,
+        mut statement: FnStatement,
+        attributes: Vec<Attribute>,
+        arguments: Vec<Type>,
+    ) -> Result<(Type, GeneratorFunctionStatement), Error> {
+        let generics = statement.generics.take().unwrap_or_default();
+
+        statement.identifier.name = format!(
+            "{}<{}>",
+            statement.identifier.name,
+            arguments
+                .iter()
+                .map(|argument| argument.to_string())
+                .collect::<Vec<String>>()
+                .join(", ")
+        );
+
+        Self::runtime(
+            scope,
+            statement,
+            attributes,
+            generics
+                .into_iter()
+                .map(|generic| generic.identifier)
+                .zip(arguments)
+                .collect(),
+        )
+    }
+
     ///
     /// Analyzes a runtime function statement and returns its IR for the next compiler phase.
     ///
+    /// The `generics` are declared as type aliases in the function scope, if the function is
+    /// a generic one's instance.
+    ///
     fn runtime(
         scope: Rc<RefCell<Scope>>,
         statement: FnStatement,
         attributes: Vec<Attribute>,
+        generics: Vec<(Identifier, Type)>,
     ) -> Result<(Type, GeneratorFunctionStatement), Error> {
         let scope_type = RefCell::borrow(&scope).r#type();
         let mut scope_stack = if scope_type.is_implementation() {
@@ -90,6 +157,17 @@ impl Analyzer {
             scope_stack
         };
 
+        for (identifier, r#type) in generics.into_iter() {
+            let item = ScopeItem::Type(ScopeTypeItem::new_defined(
+                Some(identifier.location),
+                r#type,
+                false,
+                None,
+            ))
+            .wrap();
+            Scope::define_item(scope_stack.top(), identifier, item)?;
+        }
+
         let bindings = Binder::bind_arguments(statement.argument_bindings, scope_stack.top())?;
 
         let expected_type = match statement.return_type {
@@ -107,6 +185,16 @@ impl Analyzer {
             });
         }
 
+        scope_stack.top().borrow_mut().set_return(
+            statement.identifier.name.clone(),
+            expected_type.clone(),
+            statement
+                .return_type
+                .as_ref()
+                .map(|r#type| r#type.location)
+                .unwrap_or(statement.location),
+        );
+
         let return_expression_location = match statement
             .body
             .expression
@@ -121,13 +209,25 @@ impl Analyzer {
                 .map(|statement| statement.location())
                 .unwrap_or(statement.location),
         };
+        let is_diverging = statement.body.expression.is_none()
+            && matches!(
+                statement.body.statements.last(),
+                Some(FunctionLocalStatement::Return(_))
+            );
 
-        let (result, intermediate) =
+        let (mut result, mut intermediate) =
             BlockAnalyzer::analyze(scope_stack.top(), statement.body, TranslationRule::Value)?;
+        let is_interrupted = RefCell::borrow(&scope_stack.top()).is_interrupted();
         scope_stack.pop();
 
+        if let Some(padding) = result.coerce(&expected_type) {
+            if let Some(r#type) = GeneratorType::try_from_semantic(&expected_type) {
+                intermediate.coerce(padding, r#type);
+            }
+        }
+
         let result_type = Type::from_element(&result, scope_stack.top())?;
-        if expected_type != result_type {
+        if expected_type != result_type && !is_diverging {
             return Err(Error::FunctionReturnType {
                 location: return_expression_location,
                 function: statement.identifier.name.clone(),
@@ -195,6 +295,7 @@ impl Analyzer {
             type_id,
             role,
             attributes,
+            is_interrupted,
         );
 
         Ok((r#type, intermediate))
@@ -321,8 +422,14 @@ impl Analyzer {
         }
 
         scope_stack.push(Some(statement.identifier.name.clone()), ScopeType::Function);
+        scope_stack.top().borrow_mut().set_return(
+            statement.identifier.name.clone(),
+            Type::unit(None),
+            statement.location,
+        );
         let (_result, intermediate) =
             BlockAnalyzer::analyze(scope_stack.top(), statement.body, TranslationRule::Value)?;
+        let is_interrupted = RefCell::borrow(&scope_stack.top()).is_interrupted();
         scope_stack.pop();
 
         let (r#type, type_id) =
@@ -338,6 +445,7 @@ impl Analyzer {
             type_id,
             GeneratorFunctionRole::UnitTest,
             attributes,
+            is_interrupted,
         );
 
         Ok((r#type, intermediate))
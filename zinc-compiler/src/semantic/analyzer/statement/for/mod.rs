@@ -104,6 +104,7 @@ impl Analyzer {
         let (_element, body) =
             BlockAnalyzer::analyze(scope_stack.top(), statement.block, TranslationRule::Value)?;
 
+        let is_interrupted = RefCell::borrow(&scope_stack.top()).is_interrupted();
         scope_stack.pop();
 
         let is_reversed = range_start > range_end;
@@ -131,6 +132,7 @@ impl Analyzer {
             index_bitlength,
             while_condition,
             body,
+            is_interrupted,
         ))
     }
 }
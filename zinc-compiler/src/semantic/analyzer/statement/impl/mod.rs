@@ -9,9 +9,15 @@ use std::cell::RefCell;
 use std::rc::Rc;
 
 use zinc_lexical::Keyword;
+use zinc_lexical::Location;
+use zinc_syntax::Identifier;
 use zinc_syntax::ImplStatement;
 use zinc_syntax::ImplementationLocalStatement;
 
+use crate::semantic::binding::Binder;
+use crate::semantic::element::r#type::function::runtime::Function as RuntimeFunction;
+use crate::semantic::element::r#type::function::Function as FunctionType;
+use crate::semantic::element::r#type::r#trait::Trait as TraitType;
 use crate::semantic::element::r#type::Type;
 use crate::semantic::error::Error;
 use crate::semantic::scope::item::r#type::state::State as ScopeTypeItemState;
@@ -19,6 +25,7 @@ use crate::semantic::scope::item::r#type::statement::Statement as TypeStatementV
 use crate::semantic::scope::item::r#type::statement::Statement as ScopeTypeItemStatement;
 use crate::semantic::scope::item::r#type::Type as ScopeTypeItem;
 use crate::semantic::scope::item::Item as ScopeItem;
+use crate::semantic::scope::r#type::Type as ScopeType;
 use crate::semantic::scope::Scope;
 
 ///
@@ -32,6 +39,9 @@ impl Analyzer {
     ///
     /// Also declares the `Self` alias for the type being implemented.
     ///
+    /// If a trait is implemented, the implementation is declared in the type scope to be
+    /// checked against the trait during the second pass.
+    ///
     pub fn declare(
         scope: Rc<RefCell<Scope>>,
         statement: ImplStatement,
@@ -46,6 +56,12 @@ impl Analyzer {
                     ref inner,
                     ref scope,
                 }) => match inner {
+                    ScopeTypeItemStatement::Struct(ref inner) if inner.generics.is_some() => {
+                        return Err(Error::ImplStatementGenericType {
+                            location: identifier_location,
+                            found: statement.identifier.name,
+                        })
+                    }
                     ScopeTypeItemStatement::Struct(_) => scope.to_owned(),
                     ScopeTypeItemStatement::Enum(_) => scope.to_owned(),
                     ref _statement => {
@@ -58,6 +74,12 @@ impl Analyzer {
                 Some(ScopeTypeItemState::Defined {
                     inner: ref r#type, ..
                 }) => match r#type {
+                    Type::Structure(ref inner) if inner.template.is_some() => {
+                        return Err(Error::ImplStatementGenericType {
+                            location: identifier_location,
+                            found: statement.identifier.name,
+                        })
+                    }
                     Type::Structure(ref inner) => inner.scope.to_owned(),
                     Type::Enumeration(ref inner) => inner.scope.to_owned(),
                     ref _type => {
@@ -67,6 +89,7 @@ impl Analyzer {
                         })
                     }
                 },
+                Some(ScopeTypeItemState::Failed) => return Err(Error::reported()),
                 None => {
                     return Err(Error::ScopeReferenceLoop {
                         location: identifier_location,
@@ -83,6 +106,21 @@ impl Analyzer {
 
         Scope::insert_item(scope.clone(), Keyword::SelfUppercase.to_string(), item);
 
+        if let Some(r#trait) = statement.r#trait {
+            let methods = statement
+                .statements
+                .iter()
+                .filter_map(|statement| match statement {
+                    ImplementationLocalStatement::Fn(statement) => {
+                        Some(statement.identifier.to_owned())
+                    }
+                    _ => None,
+                })
+                .collect();
+
+            Scope::declare_implementation(scope.clone(), statement.location, r#trait, methods)?;
+        }
+
         for hoisted_statement in statement.statements.into_iter() {
             match hoisted_statement {
                 ImplementationLocalStatement::Const(statement) => {
@@ -97,4 +135,146 @@ impl Analyzer {
 
         Ok(scope)
     }
+
+    ///
+    /// Resolves the trait being implemented in the implemented type `scope`.
+    ///
+    pub fn resolve_trait(
+        scope: Rc<RefCell<Scope>>,
+        identifier: &Identifier,
+    ) -> Result<TraitType, Error> {
+        let item = RefCell::borrow(&scope).resolve_item(identifier, true)?;
+        let item = RefCell::borrow(&item);
+
+        match *item {
+            ScopeItem::Type(ref r#type) => match r#type.define()? {
+                Type::Trait(inner) => Ok(inner),
+                r#type => Err(Error::ImplStatementExpectedTrait {
+                    location: identifier.location,
+                    found: r#type.to_string(),
+                }),
+            },
+            ref item => Err(Error::ImplStatementExpectedTrait {
+                location: identifier.location,
+                found: item.to_string(),
+            }),
+        }
+    }
+
+    ///
+    /// Checks whether the `methods` declared in the implementation match the trait ones.
+    ///
+    /// The trait method prototypes are resolved in the implemented type `scope`, so `Self`
+    /// means the implemented type in both the trait and implementation.
+    ///
+    pub fn check_trait(
+        scope: Rc<RefCell<Scope>>,
+        location: Location,
+        r#trait: &TraitType,
+        methods: Vec<Identifier>,
+    ) -> Result<(), Error> {
+        for method in methods.iter() {
+            if !r#trait
+                .methods
+                .iter()
+                .any(|prototype| prototype.identifier.name == method.name)
+            {
+                return Err(Error::ImplStatementTraitMethodUnexpected {
+                    location: method.location,
+                    r#trait: r#trait.identifier.to_owned(),
+                    method: method.name.to_owned(),
+                });
+            }
+        }
+
+        for prototype in r#trait.methods.iter() {
+            let method = match methods
+                .iter()
+                .find(|method| method.name == prototype.identifier.name)
+            {
+                Some(method) => method,
+                None => {
+                    return Err(Error::ImplStatementTraitMethodMissing {
+                        location,
+                        r#trait: r#trait.identifier.to_owned(),
+                        method: prototype.identifier.name.to_owned(),
+                    })
+                }
+            };
+
+            let prototype_scope = Scope::new_child(
+                prototype.identifier.name.to_owned(),
+                ScopeType::Function,
+                scope.clone(),
+            );
+            let bindings =
+                Binder::bind_arguments(prototype.argument_bindings.to_owned(), prototype_scope)?;
+            let return_type = match prototype.return_type {
+                Some(ref r#type) => Type::try_from_syntax(r#type.to_owned(), scope.clone())?,
+                None => Type::unit(None),
+            };
+
+            let item = RefCell::borrow(&scope).resolve_item(method, false)?;
+            let found = match *RefCell::borrow(&item) {
+                ScopeItem::Type(ref r#type) => r#type.define()?,
+                ref item => {
+                    return Err(Error::ImplStatementExpectedTrait {
+                        location: method.location,
+                        found: item.to_string(),
+                    })
+                }
+            };
+
+            let function = match found {
+                Type::Function(FunctionType::Runtime(function)) => function,
+                found => {
+                    return Err(Error::ImplStatementTraitMethodSignature {
+                        location: method.location,
+                        r#trait: r#trait.identifier.to_owned(),
+                        method: method.name.to_owned(),
+                        expected: RuntimeFunction::new(
+                            prototype.location,
+                            prototype.identifier.name.to_owned(),
+                            r#trait.type_id,
+                            bindings,
+                            return_type,
+                        )
+                        .to_string(),
+                        found: found.to_string(),
+                    });
+                }
+            };
+
+            let is_compatible = function.bindings.len() == bindings.len()
+                && function.return_type.as_ref() == &return_type
+                && function.is_mutable()
+                    == bindings
+                        .first()
+                        .map(|binding| binding.is_mutable)
+                        .unwrap_or_default()
+                && function
+                    .bindings
+                    .iter()
+                    .zip(bindings.iter())
+                    .all(|(found, expected)| found.r#type == expected.r#type);
+            if !is_compatible {
+                return Err(Error::ImplStatementTraitMethodSignature {
+                    location: method.location,
+                    r#trait: r#trait.identifier.to_owned(),
+                    method: method.name.to_owned(),
+                    expected: RuntimeFunction::new(
+                        prototype.location,
+                        prototype.identifier.name.to_owned(),
+                        r#trait.type_id,
+                        bindings,
+                        return_type,
+                    )
+                    .to_string(),
+                    found: function.to_string(),
+                });
+            }
+        }
+
+        Ok(())
+    }
 }
@@ -29,14 +29,20 @@ impl Analyzer {
         scope: Rc<RefCell<Scope>>,
         statement: LetStatement,
     ) -> Result<Option<GeneratorDeclarationStatement>, Error> {
-        let (element, expression) = ExpressionAnalyzer::new(scope.clone(), TranslationRule::Value)
-            .analyze(statement.expression)?;
+        let location = statement.expression.location;
+        let (mut element, mut expression) =
+            ExpressionAnalyzer::new(scope.clone(), TranslationRule::Value)
+                .analyze(statement.expression)?;
 
         let r#type = if let Some(r#type) = statement.binding.r#type {
             let r#type = Type::try_from_syntax(r#type, scope.clone())?;
+            if let Some(padding) = element.coerce(&r#type) {
+                expression.push_operand(padding);
+            }
             element.cast(Element::Type(r#type.clone()))?;
             r#type
         } else {
+            element.validate_complete(location)?;
             Type::from_element(&element, scope.clone())?
         };
 
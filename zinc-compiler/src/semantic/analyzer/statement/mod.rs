@@ -2,7 +2,9 @@
 //! The statement semantic analyzer.
 //!
 
+pub mod r#break;
 pub mod r#const;
+pub mod r#continue;
 pub mod contract;
 pub mod r#enum;
 pub mod field;
@@ -11,6 +13,9 @@ pub mod r#for;
 pub mod r#impl;
 pub mod r#let;
 pub mod module;
+pub mod r#return;
 pub mod r#struct;
+pub mod r#trait;
 pub mod r#type;
 pub mod r#use;
+pub mod r#while;
@@ -0,0 +1,68 @@
+//!
+//! The `return` statement semantic analyzer.
+//!
+
+#[cfg(test)]
+mod tests;
+
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use zinc_syntax::ReturnStatement;
+
+use crate::generator::statement::r#return::Statement as GeneratorReturnStatement;
+use crate::semantic::analyzer::expression::Analyzer as ExpressionAnalyzer;
+use crate::semantic::analyzer::rule::Rule as TranslationRule;
+use crate::semantic::element::r#type::Type;
+use crate::semantic::error::Error;
+use crate::semantic::scope::Scope;
+
+///
+/// The `return` statement semantic analyzer.
+///
+pub struct Analyzer {}
+
+impl Analyzer {
+    ///
+    /// Analyzes the `return` statement and returns its IR for the next compiler phase.
+    ///
+    pub fn analyze(
+        scope: Rc<RefCell<Scope>>,
+        statement: ReturnStatement,
+    ) -> Result<GeneratorReturnStatement, Error> {
+        let location = statement.location;
+
+        let (function, expected_type, reference) = Scope::interrupt_function(scope.clone())
+            .ok_or(Error::ReturnStatementBeyondFunction { location })?;
+
+        let (result_type, result_location, intermediate) = match statement.expression {
+            Some(expression) => {
+                let location = expression.location;
+                let (mut result, mut intermediate) =
+                    ExpressionAnalyzer::new(scope.clone(), TranslationRule::Value)
+                        .analyze(expression)?;
+                if let Some(padding) = result.coerce(&expected_type) {
+                    intermediate.push_operand(padding);
+                }
+                (
+                    Type::from_element(&result, scope)?,
+                    location,
+                    Some(intermediate),
+                )
+            }
+            None => (Type::unit(None), location, None),
+        };
+
+        if expected_type != result_type {
+            return Err(Error::FunctionReturnType {
+                location: result_location,
+                function,
+                expected: expected_type.to_string(),
+                found: result_type.to_string(),
+                reference,
+            });
+        }
+
+        Ok(GeneratorReturnStatement::new(location, intermediate))
+    }
+}
@@ -0,0 +1,145 @@
+//!
+//! The `return` statement tests.
+//!
+
+use zinc_lexical::Location;
+
+use crate::error::Error;
+use crate::semantic::element::r#type::Type;
+use crate::semantic::error::Error as SemanticError;
+
+#[test]
+fn ok_early() {
+    let input = r#"
+fn abs_diff(a: u8, b: u8) -> u8 {
+    if a > b {
+        return a - b;
+    }
+    b - a
+}
+
+fn main() -> u8 {
+    abs_diff(42, 25)
+}
+"#;
+
+    assert!(crate::semantic::tests::compile_entry(input).is_ok());
+}
+
+#[test]
+fn ok_last_statement() {
+    let input = r#"
+fn main() -> u8 {
+    let value = 42;
+    return value;
+}
+"#;
+
+    assert!(crate::semantic::tests::compile_entry(input).is_ok());
+}
+
+#[test]
+fn ok_unit() {
+    let input = r#"
+fn main() {
+    for i in 0..10 {
+        if i == 5 {
+            return;
+        }
+        dbg!("{}", i);
+    }
+}
+"#;
+
+    assert!(crate::semantic::tests::compile_entry(input).is_ok());
+}
+
+#[test]
+fn ok_nested_loops() {
+    let input = r#"
+fn find(values: [[u8; 4]; 4], needle: u8) -> (u8, u8) {
+    for i in 0..4 {
+        for j in 0..4 {
+            if values[i][j] == needle {
+                return (i, j);
+            }
+        }
+    }
+    (0, 0)
+}
+
+fn main() -> (u8, u8) {
+    find([[1, 2, 3, 4]; 4], 3)
+}
+"#;
+
+    assert!(crate::semantic::tests::compile_entry(input).is_ok());
+}
+
+#[test]
+fn error_beyond_function() {
+    let input = r#"
+const fn constant() -> u8 {
+    return 42;
+}
+
+fn main() -> u8 {
+    constant()
+}
+"#;
+
+    let expected = Err(Error::Semantic(
+        SemanticError::ReturnStatementBeyondFunction {
+            location: Location::test(3, 5),
+        },
+    ));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_type_mismatch() {
+    let input = r#"
+fn main() -> u8 {
+    if true {
+        return false;
+    }
+    42
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::FunctionReturnType {
+        location: Location::test(4, 16),
+        function: "main".to_owned(),
+        expected: Type::integer_unsigned(None, zinc_const::bitlength::BYTE).to_string(),
+        found: Type::boolean(None).to_string(),
+        reference: Location::test(2, 14),
+    }));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_type_mismatch_unit() {
+    let input = r#"
+fn main() -> u8 {
+    return;
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::FunctionReturnType {
+        location: Location::test(3, 5),
+        function: "main".to_owned(),
+        expected: Type::integer_unsigned(None, zinc_const::bitlength::BYTE).to_string(),
+        found: Type::unit(None).to_string(),
+        reference: Location::test(2, 14),
+    }));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
@@ -3,12 +3,18 @@
 //!
 
 use std::cell::RefCell;
+use std::collections::HashMap;
 use std::rc::Rc;
 
 use zinc_syntax::StructStatement;
 
+use crate::semantic::element::r#type::structure::template::Template as StructureTemplate;
+use crate::semantic::element::r#type::structure::Structure;
 use crate::semantic::element::r#type::Type;
 use crate::semantic::error::Error;
+use crate::semantic::scope::item::r#type::Type as ScopeTypeItem;
+use crate::semantic::scope::item::Item as ScopeItem;
+use crate::semantic::scope::r#type::Type as ScopeType;
 use crate::semantic::scope::Scope;
 
 ///
@@ -20,26 +26,27 @@ impl Analyzer {
     ///
     /// Defines a compile-time only structure type.
     ///
+    /// The generic structure is defined as a template, whose fields are resolved separately
+    /// for each instance.
+    ///
     pub fn define(scope: Rc<RefCell<Scope>>, statement: StructStatement) -> Result<Type, Error> {
-        let mut fields: Vec<(String, Type)> = Vec::with_capacity(statement.fields.len());
-        for field in statement.fields.into_iter() {
-            if fields
+        if let Some(ref generics) = statement.generics {
+            let generics = generics
                 .iter()
-                .any(|(name, _type)| name == &field.identifier.name)
-            {
-                return Err(Error::TypeDuplicateField {
-                    location: field.location,
-                    r#type: statement.identifier.name,
-                    field_name: field.identifier.name,
-                });
-            }
+                .map(|generic| generic.identifier.name.to_owned())
+                .collect();
 
-            fields.push((
-                field.identifier.name,
-                Type::try_from_syntax(field.r#type, scope.clone())?,
+            return Ok(Type::generic_structure(
+                Some(statement.location),
+                statement.identifier.name.clone(),
+                generics,
+                StructureTemplate::new(statement, scope.clone()),
+                scope,
             ));
         }
 
+        let fields = Self::fields(scope.clone(), &statement)?;
+
         let r#type = Type::structure(
             Some(statement.location),
             statement.identifier.name,
@@ -57,4 +64,104 @@ impl Analyzer {
 
         Ok(r#type)
     }
+
+    ///
+    /// Instantiates the generic structure with the generic `arguments`.
+    ///
+    /// The arguments are declared as type aliases in the instance scope, which is a child of
+    /// the generic structure one, so the fields are resolved as in a non-generic structure.
+    ///
+    pub fn instantiate(
+        scope: Rc<RefCell<Scope>>,
+        statement: StructStatement,
+        arguments: Vec<Type>,
+    ) -> Result<Structure, Error> {
+        let generics = statement.generics.to_owned().unwrap_or_default();
+        if generics.len() != arguments.len() {
+            return Err(Error::TypeInvalidGenericsNumber {
+                location: statement.location,
+                r#type: statement.identifier.name,
+                expected: generics.len(),
+                found: arguments.len(),
+            });
+        }
+
+        let identifier = format!(
+            "{}<{}>",
+            statement.identifier.name,
+            arguments
+                .iter()
+                .map(|argument| argument.to_string())
+                .collect::<Vec<String>>()
+                .join(", ")
+        );
+
+        let scope = Scope::new_child(identifier.clone(), ScopeType::Structure, scope);
+        let mut params = HashMap::with_capacity(arguments.len());
+        for (generic, argument) in generics.iter().zip(arguments) {
+            let item = ScopeItem::Type(ScopeTypeItem::new_defined(
+                Some(generic.identifier.location),
+                argument.clone(),
+                false,
+                None,
+            ))
+            .wrap();
+            Scope::define_item(scope.clone(), generic.identifier.to_owned(), item)?;
+            params.insert(generic.identifier.name.to_owned(), argument);
+        }
+
+        let fields = Self::fields(scope.clone(), &statement)?;
+
+        let mut r#type = Type::structure(
+            Some(statement.location),
+            identifier,
+            fields,
+            Some(generics.into_iter().map(|generic| generic.identifier.name).collect()),
+            scope,
+        );
+
+        if !r#type.is_instantiatable(false) {
+            return Err(Error::TypeInstantiationForbidden {
+                location: statement.location,
+                found: r#type.to_string(),
+            });
+        }
+
+        match r#type {
+            Type::Structure(ref mut structure) => {
+                structure.params = Some(params);
+                Ok(structure.to_owned())
+            }
+            _ => panic!(zinc_const::panic::VALIDATED_DURING_SEMANTIC_ANALYSIS),
+        }
+    }
+
+    ///
+    /// Resolves the structure fields in the `scope`, checking for duplicates.
+    ///
+    fn fields(
+        scope: Rc<RefCell<Scope>>,
+        statement: &StructStatement,
+    ) -> Result<Vec<(String, Type)>, Error> {
+        let mut fields: Vec<(String, Type)> = Vec::with_capacity(statement.fields.len());
+        for field in statement.fields.iter() {
+            if fields
+                .iter()
+                .any(|(name, _type)| name == &field.identifier.name)
+            {
+                return Err(Error::TypeDuplicateField {
+                    location: field.location,
+                    r#type: statement.identifier.name.to_owned(),
+                    field_name: field.identifier.name.to_owned(),
+                });
+            }
+
+            fields.push((
+                field.identifier.name.to_owned(),
+                Type::try_from_syntax(field.r#type.to_owned(), scope.clone())?,
+            ));
+        }
+
+        Ok(fields)
+    }
 }
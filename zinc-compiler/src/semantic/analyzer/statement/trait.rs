@@ -0,0 +1,53 @@
+//!
+//! The `trait` statement semantic analyzer.
+//!
+
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use zinc_syntax::TraitStatement;
+
+use crate::semantic::element::r#type::Type;
+use crate::semantic::error::Error;
+use crate::semantic::scope::Scope;
+
+///
+/// The `trait` statement semantic analyzer.
+///
+pub struct Analyzer {}
+
+impl Analyzer {
+    ///
+    /// Defines a compile-time only trait type.
+    ///
+    /// The method prototypes are resolved separately for each implementation, since they may
+    /// reference the `Self` type.
+    ///
+    pub fn define(_scope: Rc<RefCell<Scope>>, statement: TraitStatement) -> Result<Type, Error> {
+        for (index, method) in statement.methods.iter().enumerate() {
+            if method.generics.is_some() {
+                return Err(Error::FunctionGenericsForbidden {
+                    location: method.location,
+                    function: method.identifier.name.to_owned(),
+                });
+            }
+
+            if let Some(previous) = statement.methods[..index]
+                .iter()
+                .find(|previous| previous.identifier.name == method.identifier.name)
+            {
+                return Err(Error::ScopeItemRedeclared {
+                    location: method.identifier.location,
+                    name: method.identifier.name.to_owned(),
+                    reference: Some(previous.identifier.location),
+                });
+            }
+        }
+
+        Ok(Type::r#trait(
+            statement.location,
+            statement.identifier.name,
+            statement.methods,
+        ))
+    }
+}
@@ -0,0 +1,99 @@
+//!
+//! The `while` statement semantic analyzer.
+//!
+
+#[cfg(test)]
+mod tests;
+
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use num::ToPrimitive;
+
+use zinc_syntax::WhileStatement;
+
+use crate::generator::statement::r#while::Statement as GeneratorWhileLoopStatement;
+use crate::semantic::analyzer::expression::block::Analyzer as BlockAnalyzer;
+use crate::semantic::analyzer::expression::Analyzer as ExpressionAnalyzer;
+use crate::semantic::analyzer::rule::Rule as TranslationRule;
+use crate::semantic::element::constant::Constant;
+use crate::semantic::element::r#type::Type;
+use crate::semantic::element::Element;
+use crate::semantic::error::Error;
+use crate::semantic::scope::r#type::Type as ScopeType;
+use crate::semantic::scope::stack::Stack as ScopeStack;
+use crate::semantic::scope::Scope;
+
+///
+/// The `while` statement semantic analyzer.
+///
+pub struct Analyzer {}
+
+impl Analyzer {
+    ///
+    /// Defines a while-loop and returns its IR for the next compiler phase.
+    ///
+    /// The loop is unrolled into the constant number of iterations specified by its bound.
+    ///
+    pub fn define(
+        scope: Rc<RefCell<Scope>>,
+        statement: WhileStatement,
+    ) -> Result<GeneratorWhileLoopStatement, Error> {
+        let location = statement.location;
+        let bound_location = statement.bound.location;
+
+        let mut scope_stack = ScopeStack::new(scope);
+
+        let iterations_count =
+            match ExpressionAnalyzer::new(scope_stack.top(), TranslationRule::Constant)
+                .analyze(statement.bound)?
+            {
+                (Element::Constant(Constant::Integer(integer)), _intermediate) => {
+                    integer.value.to_usize().ok_or(Error::InvalidInteger {
+                        location: bound_location,
+                        inner: zinc_math::Error::Overflow {
+                            value: integer.value.to_owned(),
+                            is_signed: false,
+                            bitlength: integer.bitlength,
+                        },
+                    })?
+                }
+                (element, _intermediate) => {
+                    return Err(Error::WhileStatementBoundExpectedConstantInteger {
+                        location: bound_location,
+                        found: element.to_string(),
+                    });
+                }
+            };
+
+        scope_stack.push(None, ScopeType::Loop);
+
+        let condition_location = statement.condition.location;
+        let (condition_result, condition) =
+            ExpressionAnalyzer::new(scope_stack.top(), TranslationRule::Value)
+                .analyze(statement.condition)?;
+        match Type::from_element(&condition_result, scope_stack.top())? {
+            Type::Boolean(_) => {}
+            r#type => {
+                return Err(Error::WhileStatementExpectedBooleanCondition {
+                    location: condition_location,
+                    found: r#type.to_string(),
+                });
+            }
+        }
+
+        let (_element, body) =
+            BlockAnalyzer::analyze(scope_stack.top(), statement.block, TranslationRule::Value)?;
+
+        let is_interrupted = RefCell::borrow(&scope_stack.top()).is_interrupted();
+        scope_stack.pop();
+
+        Ok(GeneratorWhileLoopStatement::new(
+            location,
+            condition,
+            iterations_count,
+            body,
+            is_interrupted,
+        ))
+    }
+}
@@ -0,0 +1,119 @@
+//!
+//! The `while` statement tests.
+//!
+
+use zinc_lexical::Location;
+
+use crate::error::Error;
+use crate::semantic::element::constant::boolean::Boolean as BooleanConstant;
+use crate::semantic::element::constant::Constant;
+use crate::semantic::element::r#type::Type;
+use crate::semantic::element::Element;
+use crate::semantic::error::Error as SemanticError;
+
+#[test]
+fn ok_ordinar() {
+    let input = r#"
+fn main(x: u8) -> u8 {
+    let mut low = 0;
+    let mut high = x;
+    while low < high bound 8 {
+        let middle = (low + high + 1) / 2;
+        if middle * middle <= x {
+            low = middle;
+        } else {
+            high = middle - 1;
+        }
+    }
+    low
+}
+"#;
+
+    assert!(crate::semantic::tests::compile_entry(input).is_ok());
+}
+
+#[test]
+fn ok_constant_bound() {
+    let input = r#"
+const LIMIT: u8 = 4;
+
+fn main() {
+    let mut i = 0;
+    while i < 3 bound LIMIT * 2 {
+        i += 1;
+    }
+}
+"#;
+
+    assert!(crate::semantic::tests::compile_entry(input).is_ok());
+}
+
+#[test]
+fn ok_break_continue() {
+    let input = r#"
+fn main() {
+    let mut i = 0;
+    while i < 10 bound 10 {
+        i += 1;
+        if i == 2 {
+            continue;
+        }
+        if i == 5 {
+            break;
+        }
+    }
+}
+"#;
+
+    assert!(crate::semantic::tests::compile_entry(input).is_ok());
+}
+
+#[test]
+fn error_expected_boolean_condition() {
+    let input = r#"
+fn main() {
+    let mut i = 0;
+    while 42 bound 10 {
+        i += 1;
+    }
+}
+"#;
+
+    let expected = Err(Error::Semantic(
+        SemanticError::WhileStatementExpectedBooleanCondition {
+            location: Location::test(4, 11),
+            found: Type::integer_unsigned(None, zinc_const::bitlength::BYTE).to_string(),
+        },
+    ));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_bound_expected_constant_integer() {
+    let input = r#"
+fn main() {
+    let mut i = 0;
+    while i < 10 bound true {
+        i += 1;
+    }
+}
+"#;
+
+    let expected = Err(Error::Semantic(
+        SemanticError::WhileStatementBoundExpectedConstantInteger {
+            location: Location::test(4, 24),
+            found: Element::Constant(Constant::Boolean(BooleanConstant::new(
+                Location::test(4, 24),
+                true,
+            )))
+            .to_string(),
+        },
+    ));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
@@ -30,6 +30,7 @@ impl Caster {
     /// enum<b1> -> i<b2>
     /// enum<b1> -> u<b2>
     /// enum<b1> -> field
+    /// (data-carrying enumerations cannot be casted to integers)
     /// T -> T (no effect, no errors)
     ///
     /// `b1` and `b2` are bitlengths
@@ -45,9 +46,9 @@ impl Caster {
             (Type::IntegerSigned { .. }, Type::IntegerSigned { .. }) => Ok(()),
             (Type::IntegerSigned { .. }, Type::IntegerUnsigned { .. }) => Ok(()),
             (Type::IntegerSigned { .. }, Type::Field(_)) => Ok(()),
-            (Type::Enumeration(_), Type::IntegerSigned { .. }) => Ok(()),
-            (Type::Enumeration(_), Type::IntegerUnsigned { .. }) => Ok(()),
-            (Type::Enumeration(_), Type::Field(_)) => Ok(()),
+            (Type::Enumeration(inner), Type::IntegerSigned { .. }) if !inner.is_data() => Ok(()),
+            (Type::Enumeration(inner), Type::IntegerUnsigned { .. }) if !inner.is_data() => Ok(()),
+            (Type::Enumeration(inner), Type::Field(_)) if !inner.is_data() => Ok(()),
             (from, to) if from == to => Ok(()),
 
             (from @ Type::IntegerUnsigned { .. }, to) => Err(Error::CastingToInvalidType {
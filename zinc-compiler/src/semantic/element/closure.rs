@@ -0,0 +1,141 @@
+//!
+//! The semantic analyzer closure element.
+//!
+
+use std::cell::RefCell;
+use std::fmt;
+use std::rc::Rc;
+
+use zinc_lexical::Location;
+use zinc_syntax::Binding as SyntaxBinding;
+use zinc_syntax::ClosureExpression;
+use zinc_syntax::ExpressionTree;
+
+use crate::generator::expression::operator::inline::closure::Closure as GeneratorClosure;
+use crate::semantic::analyzer::expression::Analyzer as ExpressionAnalyzer;
+use crate::semantic::analyzer::rule::Rule as TranslationRule;
+use crate::semantic::binding::Binder;
+use crate::semantic::element::r#type::Type;
+use crate::semantic::error::Error;
+use crate::semantic::scope::r#type::Type as ScopeType;
+use crate::semantic::scope::stack::Stack as ScopeStack;
+use crate::semantic::scope::Scope;
+
+///
+/// The closure, which is passed as a function argument and inlined at compile time.
+///
+/// The closure body is analyzed by the called function, when the parameter types are known.
+///
+#[derive(Debug, Clone)]
+pub struct Closure {
+    /// The closure location in the code.
+    pub location: Location,
+    /// The closure parameter bindings.
+    pub bindings: Vec<SyntaxBinding>,
+    /// The closure body expression.
+    pub body: ExpressionTree,
+    /// The scope where the closure is defined, which is visible from the closure body.
+    pub scope: Rc<RefCell<Scope>>,
+}
+
+impl Closure {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(scope: Rc<RefCell<Scope>>, expression: ClosureExpression) -> Self {
+        Self {
+            location: expression.location,
+            bindings: expression.bindings,
+            body: *expression.body,
+            scope,
+        }
+    }
+
+    ///
+    /// Analyzes the closure body, binding its parameters to the `argument_types` passed by the
+    /// called function.
+    ///
+    /// If the `expected` return type is specified, the closure result is checked against it.
+    ///
+    /// Returns the closure result type and intermediate representation.
+    ///
+    pub fn call(
+        self,
+        argument_types: Vec<Type>,
+        expected: Option<&Type>,
+    ) -> Result<(Type, GeneratorClosure), Error> {
+        if self.bindings.len() != argument_types.len() {
+            return Err(Error::ClosureParameterCount {
+                location: self.location,
+                expected: argument_types.len(),
+                found: self.bindings.len(),
+            });
+        }
+
+        let mut scope_stack = ScopeStack::new(self.scope);
+        scope_stack.push(None, ScopeType::Closure);
+
+        let mut bindings = Vec::with_capacity(self.bindings.len());
+        for (index, (binding, r#type)) in self.bindings.into_iter().zip(argument_types).enumerate()
+        {
+            if let Some(annotation) = binding.r#type {
+                let location = annotation.location;
+                let annotation = Type::try_from_syntax(annotation, scope_stack.top())?;
+                if annotation != r#type {
+                    return Err(Error::ClosureParameterType {
+                        location,
+                        position: index + 1,
+                        expected: r#type.to_string(),
+                        found: annotation.to_string(),
+                    });
+                }
+            }
+
+            bindings.extend(Binder::bind_variables(
+                binding.pattern,
+                r#type,
+                scope_stack.top(),
+            )?);
+        }
+
+        let location = self.body.location;
+        let (mut result, mut intermediate) =
+            ExpressionAnalyzer::new(scope_stack.top(), TranslationRule::Value)
+                .analyze(self.body)?;
+
+        let result_type = match expected {
+            Some(expected) => {
+                if let Some(padding) = result.coerce(expected) {
+                    intermediate.push_operand(padding);
+                }
+
+                let result_type = Type::from_element(&result, scope_stack.top())?;
+                if &result_type != expected {
+                    return Err(Error::ClosureReturnType {
+                        location,
+                        expected: expected.to_string(),
+                        found: result_type.to_string(),
+                    });
+                }
+                result_type
+            }
+            None => {
+                result.validate_complete(location)?;
+                Type::from_element(&result, scope_stack.top())?
+            }
+        };
+
+        scope_stack.pop();
+
+        Ok((
+            result_type,
+            GeneratorClosure::new(self.location, bindings, intermediate),
+        ))
+    }
+}
+
+impl fmt::Display for Closure {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "closure with {} parameters", self.bindings.len())
+    }
+}
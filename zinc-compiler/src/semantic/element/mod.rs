@@ -7,6 +7,7 @@ mod tests;
 
 pub mod access;
 pub mod argument_list;
+pub mod closure;
 pub mod constant;
 pub mod path;
 pub mod place;
@@ -32,6 +33,7 @@ use std::rc::Rc;
 use zinc_lexical::Location;
 use zinc_syntax::Identifier;
 
+use crate::generator::expression::operand::Operand as GeneratorExpressionOperand;
 use crate::generator::expression::operator::Operator as GeneratorExpressionOperator;
 use crate::semantic::element::r#type::i_typed::ITyped;
 use crate::semantic::error::Error;
@@ -41,6 +43,7 @@ use crate::semantic::scope::Scope;
 use self::access::dot::Dot as DotAccessVariant;
 use self::access::index::Index as IndexAccess;
 use self::argument_list::ArgumentList;
+use self::closure::Closure;
 use self::constant::Constant;
 use self::path::Path;
 use self::place::Place;
@@ -64,6 +67,8 @@ pub enum Element {
     Type(Type),
     /// The second operand of the function call operator
     ArgumentList(ArgumentList),
+    /// The closure passed as a function argument
+    Closure(Closure),
 
     /// Path to be defined in the scope
     Path(Path),
@@ -1704,24 +1709,50 @@ impl Element {
     ///
     pub fn structure(self, other: Self, scope: Rc<RefCell<Scope>>) -> Result<Self, Error> {
         match self {
-            Element::Type(Type::Structure(r#type)) => match other {
-                Element::Value(Value::Structure(mut structure)) => {
-                    structure.validate(r#type)?;
+            Element::Type(Type::Structure(mut r#type)) => {
+                if let Some(template) = r#type.template.clone() {
+                    let fields: Vec<(String, Type)> = match other {
+                        Element::Value(Value::Structure(ref structure)) => structure
+                            .fields
+                            .iter()
+                            .map(|(name, _location, r#type)| (name.to_owned(), r#type.to_owned()))
+                            .collect(),
+                        Element::Constant(Constant::Structure(ref structure)) => structure
+                            .values
+                            .iter()
+                            .map(|(identifier, constant)| {
+                                (identifier.name.to_owned(), constant.r#type())
+                            })
+                            .collect(),
+                        _ => vec![],
+                    };
 
-                    Ok(Self::Value(Value::Structure(structure)))
+                    let location = other
+                        .location()
+                        .or(r#type.location)
+                        .expect(zinc_const::panic::VALUE_ALWAYS_EXISTS);
+                    r#type = template.infer(location, fields.as_slice())?;
                 }
-                Element::Constant(Constant::Structure(mut structure)) => {
-                    structure.validate(r#type)?;
 
-                    Ok(Self::Constant(Constant::Structure(structure)))
+                match other {
+                    Element::Value(Value::Structure(mut structure)) => {
+                        structure.validate(r#type)?;
+
+                        Ok(Self::Value(Value::Structure(structure)))
+                    }
+                    Element::Constant(Constant::Structure(mut structure)) => {
+                        structure.validate(r#type)?;
+
+                        Ok(Self::Constant(Constant::Structure(structure)))
+                    }
+                    element => Err(Error::OperatorStructureSecondOperandExpectedLiteral {
+                        location: element
+                            .location()
+                            .expect(zinc_const::panic::VALUE_ALWAYS_EXISTS),
+                        found: element.to_string(),
+                    }),
                 }
-                element => Err(Error::OperatorStructureSecondOperandExpectedLiteral {
-                    location: element
-                        .location()
-                        .expect(zinc_const::panic::VALUE_ALWAYS_EXISTS),
-                    found: element.to_string(),
-                }),
-            },
+            }
             Element::Type(Type::Contract(r#type)) => match other {
                 Element::Value(Value::Structure(structure)) => {
                     let mut contract = structure.into_contract(scope);
@@ -1750,6 +1781,45 @@ impl Element {
         }
     }
 
+    ///
+    /// Coerces the incomplete generic enumeration value, e.g. `Option::None`, to the `expected`
+    /// enumeration instance.
+    ///
+    /// Returns the padding operand, which must be written after the element, or `None` if
+    /// there is nothing to coerce.
+    ///
+    pub fn coerce(&mut self, expected: &Type) -> Option<GeneratorExpressionOperand> {
+        match self {
+            Self::Value(Value::Enumeration(ref mut value)) => value
+                .coerce(expected)
+                .map(GeneratorExpressionOperand::Constant),
+            _ => None,
+        }
+    }
+
+    ///
+    /// Infers the enumeration instance, which both incomplete generic enumeration values
+    /// can be coerced to, e.g. the conditional expression branch results.
+    ///
+    pub fn unify(&self, other: &Self, location: Location) -> Result<Option<Type>, Error> {
+        match (self, other) {
+            (Self::Value(Value::Enumeration(first)), Self::Value(Value::Enumeration(second))) => {
+                first.unify(second, location)
+            }
+            _ => Ok(None),
+        }
+    }
+
+    ///
+    /// Checks if the element is not an incomplete generic enumeration value.
+    ///
+    pub fn validate_complete(&self, location: Location) -> Result<(), Error> {
+        match self {
+            Self::Value(Value::Enumeration(ref value)) => value.validate_complete(location),
+            _ => Ok(()),
+        }
+    }
+
     ///
     /// The semantic element location in the source code.
     ///
@@ -1759,6 +1829,7 @@ impl Element {
             Self::Constant(inner) => Some(inner.location()),
             Self::Type(inner) => inner.location(),
             Self::ArgumentList(inner) => Some(inner.location),
+            Self::Closure(inner) => Some(inner.location),
             Self::Path(inner) => Some(inner.location),
             Self::Place(inner) => Some(inner.identifier.location),
             Self::TupleIndex(inner) => Some(inner.location),
@@ -1775,6 +1846,7 @@ impl fmt::Display for Element {
             Self::Constant(inner) => write!(f, "constant {}", inner),
             Self::Type(inner) => write!(f, "type {}", inner),
             Self::ArgumentList(inner) => write!(f, "argument list {}", inner),
+            Self::Closure(inner) => write!(f, "{}", inner),
             Self::Path(inner) => write!(f, "path {}", inner),
             Self::Place(inner) => write!(f, "place {}", inner),
             Self::TupleIndex(inner) => write!(f, "tuple field {}", inner),
@@ -5,21 +5,30 @@
 #[cfg(test)]
 mod tests;
 
+pub mod payload;
+pub mod template;
+
 use std::cell::RefCell;
 use std::convert::TryFrom;
 use std::fmt;
 use std::rc::Rc;
 
 use num::BigInt;
+use num::One;
+use num::Zero;
 
 use zinc_lexical::Location;
 use zinc_syntax::Variant;
 
 use crate::semantic::element::constant::integer::Integer as IntegerConstant;
 use crate::semantic::element::constant::Constant;
+use crate::semantic::element::r#type::Type;
 use crate::semantic::error::Error;
 use crate::semantic::scope::Scope;
 
+use self::payload::Payload;
+use self::template::Template;
+
 ///
 /// Describes an enumeration type.
 ///
@@ -27,6 +36,9 @@ use crate::semantic::scope::Scope;
 /// and the implementation `scope`, which contains the enumeration variants and
 /// reference to its parent scope.
 ///
+/// If some variants carry payloads, the enumeration is a data-carrying one, which is laid out
+/// as the variant tag followed by a union large enough to hold the largest payload.
+///
 #[derive(Debug, Clone)]
 pub struct Enumeration {
     /// The enumeration type location in the code.
@@ -41,37 +53,48 @@ pub struct Enumeration {
     pub names: Vec<String>,
     /// The ordered list of the variant values.
     pub values: Vec<BigInt>,
+    /// The ordered list of the variant payloads, which is `None` for the C-like enumerations.
+    /// The list is empty for a generic enumeration, which has not been instantiated yet.
+    pub payloads: Option<Vec<Payload>>,
     /// The ordered list of the enumeration generic type arguments.
     pub generics: Vec<String>,
+    /// The enumeration generic type actual arguments, which are set for the generic instances.
+    pub params: Option<Vec<Type>>,
     /// The enumeration scope, where its methods and associated items are declared.
     pub scope: Rc<RefCell<Scope>>,
+    /// The generic enumeration template, which is instantiated when the generic arguments are set.
+    pub template: Option<Rc<Template>>,
 }
 
 impl Enumeration {
     ///
     /// A shortcut constructor.
     ///
+    /// The variant values are taken from the variant literals. If a literal is omitted, the value
+    /// is the previous variant one plus one, starting from zero.
+    ///
     pub fn new(
         location: Location,
         identifier: String,
         type_id: usize,
-        variants: Vec<Variant>,
+        variants: &[Variant],
+        payloads: Option<Vec<Payload>>,
         generics: Vec<String>,
         scope: Rc<RefCell<Scope>>,
     ) -> Result<Self, Error> {
-        let mut variants_bigint = Vec::with_capacity(variants.len());
+        let mut names = Vec::with_capacity(variants.len());
+        let mut bigints: Vec<BigInt> = Vec::with_capacity(variants.len());
         for variant in variants.iter() {
-            let value = IntegerConstant::try_from(&variant.literal)?;
-            variants_bigint.push((variant.identifier.to_owned(), value.value.to_owned()));
+            let value = match variant.literal {
+                Some(ref literal) => IntegerConstant::try_from(literal)?.value,
+                None => bigints
+                    .last()
+                    .map(|value| value + BigInt::one())
+                    .unwrap_or_else(BigInt::zero),
+            };
+            names.push(variant.identifier.name.to_owned());
+            bigints.push(value);
         }
-        let names: Vec<String> = variants_bigint
-            .iter()
-            .map(|(identifier, _value)| identifier.name.to_owned())
-            .collect();
-        let bigints: Vec<BigInt> = variants_bigint
-            .iter()
-            .map(|(_identifier, value)| value.to_owned())
-            .collect();
         for (index, bigint) in bigints.iter().enumerate() {
             if bigints.iter().filter(|value| value == &bigint).count() > 1 {
                 let variant = variants
@@ -93,29 +116,133 @@ impl Enumeration {
             location,
         )?;
 
-        let enumeration = Self {
+        Ok(Self {
             location: Some(location),
             identifier,
             type_id,
             bitlength: minimal_bitlength,
             names,
             values: bigints,
+            payloads,
             generics,
-            scope: scope.clone(),
-        };
+            params: None,
+            scope,
+            template: None,
+        })
+    }
+
+    ///
+    /// Defines the enumeration `variants` as constants in the enumeration scope.
+    ///
+    pub fn define_variants(&self, variants: &[Variant]) -> Result<(), Error> {
+        for (variant, value) in variants.iter().zip(self.values.iter()) {
+            let mut constant = IntegerConstant::new(
+                variant.identifier.location,
+                value.to_owned(),
+                false,
+                self.bitlength,
+                false,
+            );
+
+            constant.set_enumeration(self.clone());
+
+            Scope::define_variant(
+                self.scope.clone(),
+                variant.identifier.to_owned(),
+                Constant::Integer(constant),
+            )?;
+        }
 
-        for (identifier, value) in variants_bigint.into_iter() {
-            let identifier_location = identifier.location;
+        Ok(())
+    }
 
-            let mut constant =
-                IntegerConstant::new(identifier_location, value, false, minimal_bitlength, false);
+    ///
+    /// Whether the enumeration variants carry payloads.
+    ///
+    pub fn is_data(&self) -> bool {
+        self.payloads.is_some()
+    }
 
-            constant.set_enumeration(enumeration.clone());
+    ///
+    /// Whether the enumeration is a generic one, which has not been instantiated yet.
+    ///
+    pub fn is_generic_template(&self) -> bool {
+        self.template.is_some() && self.params.is_none()
+    }
 
-            Scope::define_variant(scope.clone(), identifier, Constant::Integer(constant))?;
+    ///
+    /// Checks if the `other` enumeration is the same type or another instance of the same
+    /// generic enumeration.
+    ///
+    pub fn is_same_family(&self, other: &Self) -> bool {
+        match (self.template.as_ref(), other.template.as_ref()) {
+            (Some(template), Some(other)) => Rc::ptr_eq(template, other),
+            _ => self.type_id == other.type_id,
         }
+    }
+
+    ///
+    /// Returns the index of the variant with the tag `value`.
+    ///
+    pub fn variant_index(&self, value: &BigInt) -> Option<usize> {
+        self.values.iter().position(|variant| variant == value)
+    }
+
+    ///
+    /// Returns the payload of the variant at `index`.
+    ///
+    /// Returns `None` for C-like and uninstantiated generic enumerations.
+    ///
+    pub fn payload(&self, index: usize) -> Option<&Payload> {
+        self.payloads
+            .as_ref()
+            .and_then(|payloads| payloads.get(index))
+    }
 
-        Ok(enumeration)
+    ///
+    /// Returns the size of the union, which is large enough to hold the largest payload.
+    ///
+    pub fn union_size(&self) -> usize {
+        self.payloads
+            .as_ref()
+            .and_then(|payloads| payloads.iter().map(Payload::size).max())
+            .unwrap_or_default()
+    }
+
+    ///
+    /// Validates and sets the generic type arguments.
+    ///
+    pub fn set_generics(
+        &mut self,
+        location: Location,
+        generics: Option<Vec<Type>>,
+    ) -> Result<(), Error> {
+        match (self.template.clone(), generics) {
+            (Some(template), Some(actual)) if self.params.is_none() => {
+                if self.generics.len() != actual.len() {
+                    return Err(Error::TypeInvalidGenericsNumber {
+                        location,
+                        r#type: self.identifier.to_owned(),
+                        expected: self.generics.len(),
+                        found: actual.len(),
+                    });
+                }
+
+                *self = template.instantiate(location, actual)?;
+                Ok(())
+            }
+            (Some(_template), None) if self.params.is_some() => Ok(()),
+            (Some(_template), None) => Err(Error::TypeExpectedGenerics {
+                location,
+                r#type: self.identifier.to_owned(),
+                expected: self.generics.len(),
+            }),
+            (_, Some(_types)) => Err(Error::TypeUnexpectedGenerics {
+                location,
+                r#type: self.identifier.to_owned(),
+            }),
+            (None, None) => Ok(()),
+        }
     }
 }
 
@@ -0,0 +1,80 @@
+//!
+//! The semantic analyzer enumeration variant payload.
+//!
+
+use std::fmt;
+
+use crate::semantic::element::r#type::Type;
+
+///
+/// The data-carrying enumeration variant payload.
+///
+/// The payload is stored in the union part of the enumeration value, right after the tag.
+///
+#[derive(Debug, Clone, PartialEq)]
+pub enum Payload {
+    /// The variant without a payload, e.g. `Option::None`.
+    Unit,
+    /// The variant with a tuple payload, e.g. `Option::Some(T)`.
+    Tuple(Vec<Type>),
+    /// The variant with a structure payload, e.g. `Shape::Circle { radius: u8 }`.
+    Structure(Vec<(String, Type)>),
+}
+
+impl Payload {
+    ///
+    /// Returns the payload element types in the declaration order.
+    ///
+    pub fn types(&self) -> Vec<Type> {
+        match self {
+            Self::Unit => vec![],
+            Self::Tuple(types) => types.to_owned(),
+            Self::Structure(fields) => fields
+                .iter()
+                .map(|(_name, r#type)| r#type.to_owned())
+                .collect(),
+        }
+    }
+
+    ///
+    /// Returns the payload size in the virtual machine data stack.
+    ///
+    pub fn size(&self) -> usize {
+        self.types().iter().map(Type::size).sum()
+    }
+
+    ///
+    /// Checks if the payload elements can be instantiated.
+    ///
+    pub fn is_instantiatable(&self) -> bool {
+        self.types()
+            .iter()
+            .all(|r#type| r#type.is_instantiatable(false))
+    }
+}
+
+impl fmt::Display for Payload {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Unit => write!(f, ""),
+            Self::Tuple(types) => write!(
+                f,
+                "({})",
+                types
+                    .iter()
+                    .map(|r#type| r#type.to_string())
+                    .collect::<Vec<String>>()
+                    .join(", ")
+            ),
+            Self::Structure(fields) => write!(
+                f,
+                " {{ {} }}",
+                fields
+                    .iter()
+                    .map(|(name, r#type)| format!("{}: {}", name, r#type))
+                    .collect::<Vec<String>>()
+                    .join(", ")
+            ),
+        }
+    }
+}
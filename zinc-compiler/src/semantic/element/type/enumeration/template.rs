@@ -0,0 +1,165 @@
+//!
+//! The semantic analyzer generic enumeration template.
+//!
+
+use std::cell::Cell;
+use std::cell::RefCell;
+use std::fmt;
+use std::rc::Rc;
+
+use zinc_lexical::Location;
+use zinc_syntax::EnumStatement;
+use zinc_syntax::VariantPayload;
+
+use crate::semantic::analyzer::statement::r#enum::Analyzer as EnumStatementAnalyzer;
+use crate::semantic::element::r#type::enumeration::Enumeration;
+use crate::semantic::element::r#type::inference::Inference;
+use crate::semantic::element::r#type::r#trait::Trait;
+use crate::semantic::element::r#type::Type;
+use crate::semantic::error::Error;
+use crate::semantic::scope::Scope;
+
+///
+/// The generic enumeration definition, which is monomorphized for each set of generic arguments.
+///
+/// The instances are cached, so the same arguments always produce the same enumeration type.
+///
+pub struct Template {
+    /// The enumeration statement syntax representation.
+    pub statement: EnumStatement,
+    /// The enumeration scope, which is the parent of the instance scopes.
+    pub scope: Rc<RefCell<Scope>>,
+    /// The instances created so far with their generic arguments.
+    pub instances: RefCell<Vec<(Vec<Type>, Enumeration)>>,
+    /// Whether an instance is being analyzed, which is used to detect reference loops.
+    pub is_instantiating: Cell<bool>,
+}
+
+impl Template {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(statement: EnumStatement, scope: Rc<RefCell<Scope>>) -> Self {
+        Self {
+            statement,
+            scope,
+            instances: RefCell::new(vec![]),
+            is_instantiating: Cell::new(false),
+        }
+    }
+
+    ///
+    /// Returns the enumeration instance for the generic `arguments`, creating it if necessary.
+    ///
+    /// The arguments are checked against the generic parameter trait bounds before a new
+    /// instance is created.
+    ///
+    pub fn instantiate(
+        self: &Rc<Self>,
+        location: Location,
+        arguments: Vec<Type>,
+    ) -> Result<Enumeration, Error> {
+        if let Some((_arguments, enumeration)) = self
+            .instances
+            .borrow()
+            .iter()
+            .find(|(instance_arguments, _enumeration)| instance_arguments == &arguments)
+        {
+            return Ok(enumeration.to_owned());
+        }
+
+        Trait::check_bounds(
+            location,
+            self.scope.clone(),
+            self.statement.generics.as_deref().unwrap_or_default(),
+            arguments.as_slice(),
+        )?;
+
+        if self.is_instantiating.replace(true) {
+            return Err(Error::ScopeReferenceLoop { location });
+        }
+        let result = EnumStatementAnalyzer::instantiate(
+            self.scope.clone(),
+            self.statement.clone(),
+            arguments.clone(),
+        );
+        self.is_instantiating.set(false);
+
+        let mut enumeration = result?;
+        enumeration.template = Some(self.clone());
+        self.instances
+            .borrow_mut()
+            .push((arguments, enumeration.clone()));
+        Ok(enumeration)
+    }
+
+    ///
+    /// Infers the generic arguments from the payload `types` of the variant at `index`
+    /// and returns the instance.
+    ///
+    /// Returns `None` if some generic arguments cannot be inferred from the payload,
+    /// e.g. for `Option::None`.
+    ///
+    pub fn infer(
+        self: &Rc<Self>,
+        location: Location,
+        index: usize,
+        types: &[Type],
+    ) -> Result<Option<Enumeration>, Error> {
+        self.infer_variants(location, &[(index, types)])
+    }
+
+    ///
+    /// Infers the generic arguments from the payload types of several variants, e.g. of
+    /// `Result::Ok(42)` and `Result::Err(false)` in the branches of a conditional expression,
+    /// and returns the instance.
+    ///
+    /// Returns `None` if some generic arguments cannot be inferred from the payloads.
+    ///
+    pub fn infer_variants(
+        self: &Rc<Self>,
+        location: Location,
+        variants: &[(usize, &[Type])],
+    ) -> Result<Option<Enumeration>, Error> {
+        let mut inference = Inference::new(self.statement.generics.to_owned().unwrap_or_default());
+
+        for (index, types) in variants.iter() {
+            match self
+                .statement
+                .variants
+                .get(*index)
+                .and_then(|variant| variant.payload.as_ref())
+            {
+                Some(VariantPayload::Tuple(formal)) => {
+                    for (formal, actual) in formal.iter().zip(types.iter()) {
+                        inference.infer(formal, actual);
+                    }
+                }
+                Some(VariantPayload::Structure(fields)) => {
+                    for (field, actual) in fields.iter().zip(types.iter()) {
+                        inference.infer(&field.r#type, actual);
+                    }
+                }
+                None => {}
+            }
+        }
+
+        if !inference.is_complete() {
+            return Ok(None);
+        }
+
+        let arguments = inference.finish(location, self.statement.identifier.name.as_str())?;
+        self.instantiate(location, arguments).map(Some)
+    }
+}
+
+impl fmt::Debug for Template {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "template {} with {} instances",
+            self.statement.identifier.name,
+            self.instances.borrow().len()
+        )
+    }
+}
@@ -0,0 +1,196 @@
+//!
+//! The semantic analyzer generic function element.
+//!
+
+#[cfg(test)]
+mod tests;
+
+use std::cell::Cell;
+use std::cell::RefCell;
+use std::fmt;
+use std::rc::Rc;
+
+use zinc_lexical::Location;
+use zinc_syntax::BindingPatternVariant;
+use zinc_syntax::FnStatement;
+
+use crate::generator::statement::r#fn::Statement as GeneratorFunctionStatement;
+use crate::semantic::analyzer::attribute::Attribute;
+use crate::semantic::analyzer::statement::r#fn::Analyzer as FnStatementAnalyzer;
+use crate::semantic::element::r#type::function::runtime::Function as RuntimeFunction;
+use crate::semantic::element::r#type::function::Function as FunctionType;
+use crate::semantic::element::r#type::inference::Inference;
+use crate::semantic::element::r#type::r#trait::Trait;
+use crate::semantic::element::r#type::Type;
+use crate::semantic::error::Error;
+use crate::semantic::scope::Scope;
+
+/// The monomorphized instance with its generic arguments and intermediate representation.
+type Instance = (Vec<Type>, RuntimeFunction, GeneratorFunctionStatement);
+
+///
+/// The semantic analyzer generic function element.
+///
+/// The function is monomorphized at each call site, where the generic arguments are inferred
+/// from the actual argument types. Each distinct set of generic arguments produces a separate
+/// runtime function with its own intermediate representation.
+///
+#[derive(Debug, Clone)]
+pub struct Function {
+    /// The location where the function is called.
+    pub location: Location,
+    /// The function identifier.
+    pub identifier: String,
+    /// The unique function type ID.
+    pub type_id: usize,
+    /// The function statement syntax representation, which is analyzed for each instance.
+    pub statement: FnStatement,
+    /// The function outer attributes.
+    pub attributes: Vec<Attribute>,
+    /// The scope where the function is declared.
+    pub scope: Rc<RefCell<Scope>>,
+    /// The instances created so far with their generic arguments and intermediate representation.
+    pub instances: Rc<RefCell<Vec<Instance>>>,
+    /// Whether an instance is being analyzed, which is used to detect reference loops.
+    pub is_instantiating: Rc<Cell<bool>>,
+}
+
+impl Function {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(
+        location: Location,
+        identifier: String,
+        type_id: usize,
+        statement: FnStatement,
+        attributes: Vec<Attribute>,
+        scope: Rc<RefCell<Scope>>,
+    ) -> Self {
+        Self {
+            location,
+            identifier,
+            type_id,
+            statement,
+            attributes,
+            scope,
+            instances: Rc::new(RefCell::new(vec![])),
+            is_instantiating: Rc::new(Cell::new(false)),
+        }
+    }
+
+    ///
+    /// Whether the function must be called from mutable context.
+    ///
+    pub fn is_mutable(&self) -> bool {
+        self.statement
+            .argument_bindings
+            .first()
+            .map(|binding| {
+                matches!(
+                    binding.pattern.variant,
+                    BindingPatternVariant::Binding {
+                        is_mutable: true,
+                        ..
+                    }
+                )
+            })
+            .unwrap_or_default()
+    }
+
+    ///
+    /// Infers the generic arguments from the `argument_types` and returns the runtime
+    /// function instance, analyzing it if it has not been created yet.
+    ///
+    /// The `location` is the call site location, which is used for error reporting.
+    ///
+    pub fn instantiate(
+        self,
+        location: Location,
+        argument_types: Vec<Type>,
+    ) -> Result<RuntimeFunction, Error> {
+        let mut inference = Inference::new(self.statement.generics.to_owned().unwrap_or_default());
+        for (binding, r#type) in self
+            .statement
+            .argument_bindings
+            .iter()
+            .zip(argument_types.iter())
+        {
+            if let Some(ref formal) = binding.r#type {
+                inference.infer(formal, r#type);
+            }
+        }
+        let arguments = inference.finish(location, self.identifier.as_str())?;
+
+        if let Some((_arguments, function, _intermediate)) = self
+            .instances
+            .borrow()
+            .iter()
+            .find(|(instance_arguments, _function, _intermediate)| instance_arguments == &arguments)
+        {
+            let mut function = function.to_owned();
+            function.location = self.location;
+            return Ok(function);
+        }
+
+        Trait::check_bounds(
+            location,
+            self.scope.clone(),
+            self.statement.generics.as_deref().unwrap_or_default(),
+            arguments.as_slice(),
+        )?;
+
+        if self.is_instantiating.replace(true) {
+            return Err(Error::ScopeReferenceLoop { location });
+        }
+        let result = FnStatementAnalyzer::instantiate(
+            self.scope.clone(),
+            self.statement.clone(),
+            self.attributes.clone(),
+            arguments.clone(),
+        );
+        self.is_instantiating.set(false);
+
+        let (r#type, intermediate) = result?;
+        let mut function = match r#type {
+            Type::Function(FunctionType::Runtime(function)) => function,
+            _ => panic!(zinc_const::panic::VALIDATED_DURING_SEMANTIC_ANALYSIS),
+        };
+        self.instances
+            .borrow_mut()
+            .push((arguments, function.clone(), intermediate));
+
+        function.location = self.location;
+        Ok(function)
+    }
+
+    ///
+    /// Returns the intermediate representation of all the instances.
+    ///
+    pub fn get_intermediate(&self) -> Vec<GeneratorFunctionStatement> {
+        self.instances
+            .borrow()
+            .iter()
+            .map(|(_arguments, _function, intermediate)| intermediate.to_owned())
+            .collect()
+    }
+}
+
+impl fmt::Display for Function {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "fn {}<{}>",
+            self.identifier,
+            self.statement
+                .generics
+                .as_ref()
+                .map(|generics| generics
+                    .iter()
+                    .map(|generic| generic.identifier.name.to_owned())
+                    .collect::<Vec<String>>()
+                    .join(", "))
+                .unwrap_or_default(),
+        )
+    }
+}
@@ -0,0 +1,137 @@
+//!
+//! The generic function tests.
+//!
+
+use zinc_lexical::Location;
+
+use crate::error::Error;
+use crate::semantic::error::Error as SemanticError;
+
+#[test]
+fn ok_monomorphized() {
+    let input = r#"
+fn max<T>(a: T, b: T) -> T {
+    if a > b { a } else { b }
+}
+
+fn main() -> u16 {
+    max(1 as u8, 2) as u16 + max(3 as u16, 4 as u16)
+}
+"#;
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert!(result.is_ok());
+}
+
+#[test]
+fn ok_nested_generic_structure() {
+    let input = r#"
+struct Pair<T> {
+    a: T,
+    b: T,
+}
+
+fn first<T>(pair: Pair<T>) -> T {
+    pair.a
+}
+
+fn main() -> bool {
+    first(Pair { a: true, b: false })
+}
+"#;
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert!(result.is_ok());
+}
+
+#[test]
+fn error_argument_type() {
+    let input = r#"
+fn max<T>(a: T, b: T) -> T {
+    if a > b { a } else { b }
+}
+
+fn main() -> u8 {
+    max(1 as u8, true)
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::FunctionArgumentType {
+        location: Location::test(7, 18),
+        function: "max<u8>".to_owned(),
+        name: "b".to_owned(),
+        position: 2,
+        expected: "u8".to_owned(),
+        found: "bool".to_owned(),
+    }));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_argument_not_inferred() {
+    let input = r#"
+fn zero<T>() -> u8 {
+    0
+}
+
+fn main() -> u8 {
+    zero()
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::TypeGenericArgumentNotInferred {
+        location: Location::test(7, 9),
+        r#type: "zero".to_owned(),
+        name: "T".to_owned(),
+    }));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_constant_forbidden() {
+    let input = r#"
+const fn identity<T>(value: T) -> T {
+    value
+}
+
+fn main() {}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::FunctionGenericsForbidden {
+        location: Location::test(2, 1),
+        function: "identity".to_owned(),
+    }));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_recursion() {
+    let input = r#"
+fn identity<T>(value: T) -> T {
+    identity(value)
+}
+
+fn main() -> u8 {
+    identity(42 as u8)
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::ScopeReferenceLoop {
+        location: Location::test(3, 13),
+    }));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
@@ -17,6 +17,7 @@ use zinc_lexical::Location;
 use zinc_types::LibraryFunctionIdentifier;
 
 use crate::semantic::element::r#type::contract::Contract as ContractType;
+use crate::semantic::element::r#type::structure::Structure as StructureType;
 
 use self::contract_fetch::Function as ContractFetchFunction;
 use self::contract_transfer::Function as ContractTransferFunction;
@@ -33,7 +34,19 @@ use self::stdlib::convert_from_bits_field::Function as StdConvertFromBitsFieldFu
 use self::stdlib::convert_from_bits_signed::Function as StdConvertFromBitsSignedFunction;
 use self::stdlib::convert_from_bits_unsigned::Function as StdConvertFromBitsUnsignedFunction;
 use self::stdlib::convert_to_bits::Function as StdConvertToBitsFunction;
+use self::stdlib::crypto_blake2s::Function as StdCryptoBlake2sFunction;
+use self::stdlib::crypto_ecc_point_add::Function as StdCryptoEccPointAddFunction;
+use self::stdlib::crypto_ecc_point_double::Function as StdCryptoEccPointDoubleFunction;
+use self::stdlib::crypto_ecc_point_generator::Function as StdCryptoEccPointGeneratorFunction;
+use self::stdlib::crypto_ecc_point_is_on_curve::Function as StdCryptoEccPointIsOnCurveFunction;
+use self::stdlib::crypto_ecc_point_mul::Function as StdCryptoEccPointMulFunction;
+use self::stdlib::crypto_ecc_point_negate::Function as StdCryptoEccPointNegateFunction;
+use self::stdlib::crypto_ecdsa_verify::Function as StdCryptoEcdsaVerifyFunction;
+use self::stdlib::crypto_eddsa_signature_verify::Function as StdCryptoEddsaSignatureVerifyFunction;
+use self::stdlib::crypto_keccak256::Function as StdCryptoKeccak256Function;
 use self::stdlib::crypto_pedersen::Function as StdConvertPedersenFunction;
+use self::stdlib::crypto_poseidon::Function as StdCryptoPoseidonFunction;
+use self::stdlib::crypto_rescue::Function as StdCryptoRescueFunction;
 use self::stdlib::crypto_schnorr_signature_verify::Function as StdCryptoSchnorrSignatureVerifyFunction;
 use self::stdlib::crypto_sha256::Function as StdCryptoSha256Function;
 use self::stdlib::ff_invert::Function as StdFfInvertFunction;
@@ -85,6 +98,24 @@ impl Function {
         Self::ContractFetch(ContractFetchFunction::new(contract_type))
     }
 
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn ecc_point_generator(point_type: StructureType) -> Self {
+        Self::StandardLibrary(StandardLibraryFunction::CryptoEccPointGenerator(
+            StdCryptoEccPointGeneratorFunction::new(point_type),
+        ))
+    }
+
+    ///
+    /// A shortcut constructor.
+    ///
+    /// Used for the functions which are inlined at compile time and have no library identifier.
+    ///
+    pub fn standard_library(function: StandardLibraryFunction) -> Self {
+        Self::StandardLibrary(function)
+    }
+
     ///
     /// A shortcut constructor.
     ///
@@ -101,6 +132,51 @@ impl Function {
                     StdCryptoSchnorrSignatureVerifyFunction::default(),
                 ))
             }
+            LibraryFunctionIdentifier::CryptoBlake2s => Self::StandardLibrary(
+                StandardLibraryFunction::CryptoBlake2s(StdCryptoBlake2sFunction::default()),
+            ),
+            LibraryFunctionIdentifier::CryptoPoseidon => Self::StandardLibrary(
+                StandardLibraryFunction::CryptoPoseidon(StdCryptoPoseidonFunction::default()),
+            ),
+            LibraryFunctionIdentifier::CryptoRescue => Self::StandardLibrary(
+                StandardLibraryFunction::CryptoRescue(StdCryptoRescueFunction::default()),
+            ),
+            LibraryFunctionIdentifier::CryptoKeccak256 => Self::StandardLibrary(
+                StandardLibraryFunction::CryptoKeccak256(StdCryptoKeccak256Function::default()),
+            ),
+            LibraryFunctionIdentifier::CryptoEcdsaVerify => Self::StandardLibrary(
+                StandardLibraryFunction::CryptoEcdsaVerify(StdCryptoEcdsaVerifyFunction::default()),
+            ),
+            LibraryFunctionIdentifier::CryptoEddsaSignatureVerify => {
+                Self::StandardLibrary(StandardLibraryFunction::CryptoEddsaSignatureVerify(
+                    StdCryptoEddsaSignatureVerifyFunction::default(),
+                ))
+            }
+            LibraryFunctionIdentifier::CryptoEccPointAdd => Self::StandardLibrary(
+                StandardLibraryFunction::CryptoEccPointAdd(StdCryptoEccPointAddFunction::default()),
+            ),
+            LibraryFunctionIdentifier::CryptoEccPointDouble => {
+                Self::StandardLibrary(StandardLibraryFunction::CryptoEccPointDouble(
+                    StdCryptoEccPointDoubleFunction::default(),
+                ))
+            }
+            LibraryFunctionIdentifier::CryptoEccPointMul => Self::StandardLibrary(
+                StandardLibraryFunction::CryptoEccPointMul(StdCryptoEccPointMulFunction::default()),
+            ),
+            LibraryFunctionIdentifier::CryptoEccPointIsOnCurve => {
+                Self::StandardLibrary(StandardLibraryFunction::CryptoEccPointIsOnCurve(
+                    StdCryptoEccPointIsOnCurveFunction::default(),
+                ))
+            }
+            LibraryFunctionIdentifier::CryptoEccPointNegate => {
+                Self::StandardLibrary(StandardLibraryFunction::CryptoEccPointNegate(
+                    StdCryptoEccPointNegateFunction::default(),
+                ))
+            }
+            LibraryFunctionIdentifier::CryptoEccPointGenerator => {
+                // the generator stores the point type and is created with its own constructor
+                panic!(zinc_const::panic::VALIDATED_DURING_SEMANTIC_ANALYSIS)
+            }
 
             LibraryFunctionIdentifier::ConvertToBits => Self::StandardLibrary(
                 StandardLibraryFunction::ConvertToBits(StdConvertToBitsFunction::default()),
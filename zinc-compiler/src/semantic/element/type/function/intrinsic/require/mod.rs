@@ -49,7 +49,10 @@ impl Function {
     /// The number of arguments, not including the optional ones.
     pub const ARGUMENT_COUNT_MANDATORY: usize = 1;
 
-    /// The number of arguments, including the optional ones.
+    /// The position of the first interpolated value in the function argument list.
+    pub const ARGUMENT_INDEX_VALUES: usize = 2;
+
+    /// The number of arguments, including the optional message, but not the interpolated values.
     pub const ARGUMENT_COUNT_OPTIONAL: usize = 2;
 
     ///
@@ -59,7 +62,7 @@ impl Function {
         self,
         location: Location,
         argument_list: ArgumentList,
-    ) -> Result<(Type, Option<String>), Error> {
+    ) -> Result<(Type, Option<String>, Vec<Type>), Error> {
         let mut actual_params = Vec::with_capacity(argument_list.arguments.len());
         for (index, element) in argument_list.arguments.into_iter().enumerate() {
             let location = element.location();
@@ -130,22 +133,32 @@ impl Function {
             None => None,
         };
 
-        if actual_params.len() > Self::ARGUMENT_COUNT_OPTIONAL {
-            return Err(Error::FunctionArgumentCount {
+        let arguments_expected_count = string
+            .as_ref()
+            .map(|string| string.matches("{}").count())
+            .unwrap_or_default();
+        if actual_params.len() > Self::ARGUMENT_COUNT_OPTIONAL
+            && arguments_expected_count != actual_params.len() - Self::ARGUMENT_INDEX_VALUES
+        {
+            return Err(Error::FunctionRequireArgumentCount {
                 location,
-                function: self.identifier.to_owned(),
-                expected: Self::ARGUMENT_COUNT_OPTIONAL,
+                expected: arguments_expected_count + Self::ARGUMENT_INDEX_VALUES,
                 found: actual_params.len(),
-                reference: None,
             });
         }
 
-        Ok((Type::unit(None), string))
+        let argument_types: Vec<Type> = actual_params
+            .into_iter()
+            .skip(Self::ARGUMENT_INDEX_VALUES)
+            .map(|(r#type, _is_constant, _string, _location)| r#type)
+            .collect();
+
+        Ok((Type::unit(None), string, argument_types))
     }
 }
 
 impl fmt::Display for Function {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "{}(condition: bool, [message: str])", self.identifier)
+        write!(f, "{}(condition: bool, [message: str, ...])", self.identifier)
     }
 }
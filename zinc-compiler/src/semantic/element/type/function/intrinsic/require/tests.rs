@@ -38,13 +38,34 @@ fn main() {
 }
 "#;
 
-    let expected = Err(Error::Semantic(SemanticError::FunctionArgumentCount {
-        location: Location::test(3, 5),
-        function: RequireFunction::IDENTIFIER.to_owned(),
-        expected: RequireFunction::ARGUMENT_COUNT_OPTIONAL,
-        found: RequireFunction::ARGUMENT_COUNT_OPTIONAL + 1,
-        reference: None,
-    }));
+    let expected = Err(Error::Semantic(
+        SemanticError::FunctionRequireArgumentCount {
+            location: Location::test(3, 5),
+            expected: RequireFunction::ARGUMENT_COUNT_OPTIONAL,
+            found: RequireFunction::ARGUMENT_COUNT_OPTIONAL + 1,
+        },
+    ));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_argument_count_placeholders() {
+    let input = r#"
+fn main() {
+    require(true, "{} is less than {}", 42);
+}
+"#;
+
+    let expected = Err(Error::Semantic(
+        SemanticError::FunctionRequireArgumentCount {
+            location: Location::test(3, 5),
+            expected: RequireFunction::ARGUMENT_INDEX_VALUES + 2,
+            found: RequireFunction::ARGUMENT_INDEX_VALUES + 1,
+        },
+    ));
 
     let result = crate::semantic::tests::compile_entry(input);
 
@@ -0,0 +1,143 @@
+//!
+//! The semantic analyzer standard library `std::array::any` function element.
+//!
+
+use std::fmt;
+use std::ops::Deref;
+
+use zinc_lexical::Location;
+
+use crate::generator::expression::operator::inline::Function as GeneratorInlineFunction;
+use crate::semantic::element::argument_list::ArgumentList;
+use crate::semantic::element::r#type::i_typed::ITyped;
+use crate::semantic::element::r#type::Type;
+use crate::semantic::element::Element;
+use crate::semantic::error::Error;
+
+///
+/// The semantic analyzer standard library `std::array::any` function element.
+///
+#[derive(Debug, Clone)]
+pub struct Function {
+    /// The location where the function is called.
+    pub location: Option<Location>,
+    /// The function identifier.
+    pub identifier: &'static str,
+}
+
+impl Default for Function {
+    fn default() -> Self {
+        Self {
+            location: None,
+            identifier: Self::IDENTIFIER,
+        }
+    }
+}
+
+impl Function {
+    /// The function identifier.
+    pub const IDENTIFIER: &'static str = "any";
+
+    /// The position of the `array` argument in the function argument list.
+    pub const ARGUMENT_INDEX_ARRAY: usize = 0;
+
+    /// The position of the `predicate` argument in the function argument list.
+    pub const ARGUMENT_INDEX_CLOSURE: usize = 1;
+
+    /// The expected number of the function arguments.
+    pub const ARGUMENT_COUNT: usize = 2;
+
+    ///
+    /// Calls the function with the `argument_list`, validating the call.
+    ///
+    /// Returns the result type and the function to be inlined.
+    ///
+    pub fn call(
+        self,
+        location: Location,
+        argument_list: ArgumentList,
+    ) -> Result<(Type, GeneratorInlineFunction), Error> {
+        if argument_list.arguments.len() != Self::ARGUMENT_COUNT {
+            return Err(Error::FunctionArgumentCount {
+                location,
+                function: self.identifier.to_owned(),
+                expected: Self::ARGUMENT_COUNT,
+                found: argument_list.arguments.len(),
+                reference: None,
+            });
+        }
+
+        let mut arguments = argument_list.arguments.into_iter();
+        let array = arguments
+            .next()
+            .expect(zinc_const::panic::VALUE_ALWAYS_EXISTS);
+        let closure = arguments
+            .next()
+            .expect(zinc_const::panic::VALUE_ALWAYS_EXISTS);
+
+        let array_location = array
+            .location()
+            .expect(zinc_const::panic::VALUE_ALWAYS_EXISTS);
+        let array_type = match array {
+            Element::Value(value) => value.r#type(),
+            Element::Constant(constant) => constant.r#type(),
+            element => {
+                return Err(Error::FunctionArgumentNotEvaluable {
+                    location: array_location,
+                    function: self.identifier.to_owned(),
+                    position: Self::ARGUMENT_INDEX_ARRAY + 1,
+                    found: element.to_string(),
+                })
+            }
+        };
+        let (input_element_type, input_array_size) = match array_type {
+            Type::Array(array) => (array.r#type.deref().to_owned(), array.size),
+            r#type => {
+                return Err(Error::FunctionArgumentType {
+                    location: array_location,
+                    function: self.identifier.to_owned(),
+                    name: "array".to_owned(),
+                    position: Self::ARGUMENT_INDEX_ARRAY + 1,
+                    expected: "[T; N]".to_owned(),
+                    found: r#type.to_string(),
+                })
+            }
+        };
+
+        let closure = match closure {
+            Element::Closure(closure) => closure,
+            element => {
+                return Err(Error::FunctionArgumentType {
+                    location: element
+                        .location()
+                        .expect(zinc_const::panic::VALUE_ALWAYS_EXISTS),
+                    function: self.identifier.to_owned(),
+                    name: "predicate".to_owned(),
+                    position: Self::ARGUMENT_INDEX_CLOSURE + 1,
+                    expected: format!("|{}| -> bool", input_element_type),
+                    found: element.to_string(),
+                })
+            }
+        };
+        let (_result_type, closure) =
+            closure.call(vec![input_element_type.clone()], Some(&Type::boolean(None)))?;
+
+        let function = GeneratorInlineFunction::ArrayAny {
+            array_size: input_array_size,
+            element_size: input_element_type.size(),
+            closure,
+        };
+
+        Ok((Type::boolean(Some(location)), function))
+    }
+}
+
+impl fmt::Display for Function {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "array::{}(array: [T; N], predicate: |T| -> bool) -> bool",
+            self.identifier,
+        )
+    }
+}
@@ -13,6 +13,7 @@ use crate::semantic::element::r#type::i_typed::ITyped;
 use crate::semantic::element::r#type::Type;
 use crate::semantic::element::Element;
 use crate::semantic::error::Error;
+use crate::semantic::scope::intrinsic::IntrinsicScope;
 use crate::semantic::scope::intrinsic::IntrinsicTypeId;
 
 ///
@@ -147,10 +148,7 @@ impl Function {
             });
         }
 
-        Ok(Type::tuple(
-            None,
-            vec![value_type.to_owned(), Type::boolean(None)],
-        ))
+        IntrinsicScope::option(location, value_type.to_owned())
     }
 }
 
@@ -158,7 +156,7 @@ impl fmt::Display for Function {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(
             f,
-            "collections::MTreeMap<K, V>::{}(key: K) -> (V, bool)",
+            "collections::MTreeMap<K, V>::{}(key: K) -> std::option::Option<V>",
             self.identifier
         )
     }
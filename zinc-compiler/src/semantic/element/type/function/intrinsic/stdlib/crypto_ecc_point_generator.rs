@@ -0,0 +1,78 @@
+//!
+//! The semantic analyzer standard library `std::crypto::ecc::Point::generator` function element.
+//!
+
+use std::fmt;
+
+use zinc_lexical::Location;
+use zinc_types::LibraryFunctionIdentifier;
+
+use crate::semantic::element::argument_list::ArgumentList;
+use crate::semantic::element::r#type::structure::Structure as StructureType;
+use crate::semantic::element::r#type::Type;
+use crate::semantic::error::Error;
+
+///
+/// The semantic analyzer standard library `std::crypto::ecc::Point::generator` function element.
+///
+/// Unlike the other point functions, it stores the point type, since it has no arguments
+/// to take the return type from.
+///
+#[derive(Debug, Clone)]
+pub struct Function {
+    /// The location where the function is called.
+    pub location: Option<Location>,
+    /// The unique intrinsic function identifier.
+    pub library_identifier: LibraryFunctionIdentifier,
+    /// The function identifier.
+    pub identifier: &'static str,
+    /// The `std::crypto::ecc::Point` structure type.
+    pub point_type: StructureType,
+}
+
+impl Function {
+    /// The function identifier.
+    pub const IDENTIFIER: &'static str = "generator";
+
+    /// The expected number of the function arguments.
+    pub const ARGUMENT_COUNT: usize = 0;
+
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(point_type: StructureType) -> Self {
+        Self {
+            location: None,
+            library_identifier: LibraryFunctionIdentifier::CryptoEccPointGenerator,
+            identifier: Self::IDENTIFIER,
+            point_type,
+        }
+    }
+
+    ///
+    /// Calls the function with the `argument_list`, validating the call.
+    ///
+    pub fn call(self, location: Location, argument_list: ArgumentList) -> Result<Type, Error> {
+        if argument_list.arguments.len() != Self::ARGUMENT_COUNT {
+            return Err(Error::FunctionArgumentCount {
+                location,
+                function: self.identifier.to_owned(),
+                expected: Self::ARGUMENT_COUNT,
+                found: argument_list.arguments.len(),
+                reference: None,
+            });
+        }
+
+        Ok(Type::Structure(self.point_type))
+    }
+}
+
+impl fmt::Display for Function {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "crypto::ecc::Point::{}() -> std::crypto::ecc::Point",
+            self.identifier
+        )
+    }
+}
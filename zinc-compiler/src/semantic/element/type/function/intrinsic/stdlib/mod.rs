@@ -5,9 +5,16 @@
 #[cfg(test)]
 mod tests;
 
+pub mod array_all;
+pub mod array_any;
+pub mod array_filter_count;
+pub mod array_fold;
+pub mod array_map;
 pub mod array_pad;
+pub mod array_position;
 pub mod array_reverse;
 pub mod array_truncate;
+pub mod array_zip;
 pub mod collections_mtreemap_contains;
 pub mod collections_mtreemap_get;
 pub mod collections_mtreemap_insert;
@@ -16,23 +23,45 @@ pub mod convert_from_bits_field;
 pub mod convert_from_bits_signed;
 pub mod convert_from_bits_unsigned;
 pub mod convert_to_bits;
+pub mod crypto_blake2s;
+pub mod crypto_ecc_point_add;
+pub mod crypto_ecc_point_double;
+pub mod crypto_ecc_point_generator;
+pub mod crypto_ecc_point_is_on_curve;
+pub mod crypto_ecc_point_mul;
+pub mod crypto_ecc_point_negate;
+pub mod crypto_ecdsa_verify;
+pub mod crypto_eddsa_signature_verify;
+pub mod crypto_keccak256;
 pub mod crypto_pedersen;
+pub mod crypto_poseidon;
+pub mod crypto_rescue;
 pub mod crypto_schnorr_signature_verify;
 pub mod crypto_sha256;
 pub mod ff_invert;
+pub mod string_concat;
+pub mod string_to_bytes;
 
 use std::fmt;
 
 use zinc_lexical::Location;
 use zinc_types::LibraryFunctionIdentifier;
 
+use crate::generator::expression::operator::inline::Function as GeneratorInlineFunction;
 use crate::semantic::element::argument_list::ArgumentList;
 use crate::semantic::element::r#type::Type;
 use crate::semantic::error::Error;
 
+use self::array_all::Function as ArrayAllFunction;
+use self::array_any::Function as ArrayAnyFunction;
+use self::array_filter_count::Function as ArrayFilterCountFunction;
+use self::array_fold::Function as ArrayFoldFunction;
+use self::array_map::Function as ArrayMapFunction;
 use self::array_pad::Function as ArrayPadFunction;
+use self::array_position::Function as ArrayPositionFunction;
 use self::array_reverse::Function as ArrayReverseFunction;
 use self::array_truncate::Function as ArrayTruncateFunction;
+use self::array_zip::Function as ArrayZipFunction;
 use self::collections_mtreemap_contains::Function as MTreeMapContainsFunction;
 use self::collections_mtreemap_get::Function as MTreeMapGetFunction;
 use self::collections_mtreemap_insert::Function as MTreeMapInsertFunction;
@@ -41,10 +70,24 @@ use self::convert_from_bits_field::Function as FromBitsFieldFunction;
 use self::convert_from_bits_signed::Function as FromBitsSignedFunction;
 use self::convert_from_bits_unsigned::Function as FromBitsUnsignedFunction;
 use self::convert_to_bits::Function as ToBitsFunction;
+use self::crypto_blake2s::Function as Blake2sFunction;
+use self::crypto_ecc_point_add::Function as EccPointAddFunction;
+use self::crypto_ecc_point_double::Function as EccPointDoubleFunction;
+use self::crypto_ecc_point_generator::Function as EccPointGeneratorFunction;
+use self::crypto_ecc_point_is_on_curve::Function as EccPointIsOnCurveFunction;
+use self::crypto_ecc_point_mul::Function as EccPointMulFunction;
+use self::crypto_ecc_point_negate::Function as EccPointNegateFunction;
+use self::crypto_ecdsa_verify::Function as EcdsaVerifyFunction;
+use self::crypto_eddsa_signature_verify::Function as EddsaSignatureVerifyFunction;
+use self::crypto_keccak256::Function as Keccak256Function;
 use self::crypto_pedersen::Function as PedersenFunction;
+use self::crypto_poseidon::Function as PoseidonFunction;
+use self::crypto_rescue::Function as RescueFunction;
 use self::crypto_schnorr_signature_verify::Function as SchnorrSignatureVerifyFunction;
 use self::crypto_sha256::Function as Sha256Function;
 use self::ff_invert::Function as FfInvertFunction;
+use self::string_concat::Function as StringConcatFunction;
+use self::string_to_bytes::Function as StringToBytesFunction;
 
 ///
 /// The semantic analyzer standard library function element.
@@ -57,6 +100,30 @@ pub enum Function {
     CryptoPedersen(PedersenFunction),
     /// The `std::crypto::schnorr::Signature::verify` function variant.
     CryptoSchnorrSignatureVerify(SchnorrSignatureVerifyFunction),
+    /// The `std::crypto::blake2s` function variant.
+    CryptoBlake2s(Blake2sFunction),
+    /// The `std::crypto::poseidon` function variant.
+    CryptoPoseidon(PoseidonFunction),
+    /// The `std::crypto::rescue` function variant.
+    CryptoRescue(RescueFunction),
+    /// The `std::crypto::keccak256` function variant.
+    CryptoKeccak256(Keccak256Function),
+    /// The `std::crypto::ecdsa::verify` function variant.
+    CryptoEcdsaVerify(EcdsaVerifyFunction),
+    /// The `std::crypto::eddsa::Signature::verify` function variant.
+    CryptoEddsaSignatureVerify(EddsaSignatureVerifyFunction),
+    /// The `std::crypto::ecc::Point::add` function variant.
+    CryptoEccPointAdd(EccPointAddFunction),
+    /// The `std::crypto::ecc::Point::double` function variant.
+    CryptoEccPointDouble(EccPointDoubleFunction),
+    /// The `std::crypto::ecc::Point::mul` function variant.
+    CryptoEccPointMul(EccPointMulFunction),
+    /// The `std::crypto::ecc::Point::generator` function variant.
+    CryptoEccPointGenerator(EccPointGeneratorFunction),
+    /// The `std::crypto::ecc::Point::is_on_curve` function variant.
+    CryptoEccPointIsOnCurve(EccPointIsOnCurveFunction),
+    /// The `std::crypto::ecc::Point::negate` function variant.
+    CryptoEccPointNegate(EccPointNegateFunction),
 
     /// The `std::convert::to_bits` function variant.
     ConvertToBits(ToBitsFunction),
@@ -73,6 +140,25 @@ pub enum Function {
     ArrayTruncate(ArrayTruncateFunction),
     /// The `std::array::pad` function variant.
     ArrayPad(ArrayPadFunction),
+    /// The `std::array::map` function variant.
+    ArrayMap(ArrayMapFunction),
+    /// The `std::array::fold` function variant.
+    ArrayFold(ArrayFoldFunction),
+    /// The `std::array::zip` function variant.
+    ArrayZip(ArrayZipFunction),
+    /// The `std::array::any` function variant.
+    ArrayAny(ArrayAnyFunction),
+    /// The `std::array::all` function variant.
+    ArrayAll(ArrayAllFunction),
+    /// The `std::array::position` function variant.
+    ArrayPosition(ArrayPositionFunction),
+    /// The `std::array::filter_count` function variant.
+    ArrayFilterCount(ArrayFilterCountFunction),
+
+    /// The `std::string::concat` function variant.
+    StringConcat(StringConcatFunction),
+    /// The `std::string::to_bytes` function variant.
+    StringToBytes(StringToBytesFunction),
 
     /// The `std::ff::invert` function variant.
     FfInvert(FfInvertFunction),
@@ -91,11 +177,30 @@ impl Function {
     ///
     /// Calls the function with the `argument_list`, validating the call.
     ///
-    pub fn call(self, location: Location, argument_list: ArgumentList) -> Result<Type, Error> {
-        match self {
+    /// Returns the result type and the function to be inlined, if the function is not
+    /// implemented in the virtual machine.
+    ///
+    pub fn call(
+        self,
+        location: Location,
+        argument_list: ArgumentList,
+    ) -> Result<(Type, Option<GeneratorInlineFunction>), Error> {
+        let result = match self {
             Self::CryptoSha256(inner) => inner.call(location, argument_list),
             Self::CryptoPedersen(inner) => inner.call(location, argument_list),
             Self::CryptoSchnorrSignatureVerify(inner) => inner.call(location, argument_list),
+            Self::CryptoBlake2s(inner) => inner.call(location, argument_list),
+            Self::CryptoPoseidon(inner) => inner.call(location, argument_list),
+            Self::CryptoRescue(inner) => inner.call(location, argument_list),
+            Self::CryptoKeccak256(inner) => inner.call(location, argument_list),
+            Self::CryptoEcdsaVerify(inner) => inner.call(location, argument_list),
+            Self::CryptoEddsaSignatureVerify(inner) => inner.call(location, argument_list),
+            Self::CryptoEccPointAdd(inner) => inner.call(location, argument_list),
+            Self::CryptoEccPointDouble(inner) => inner.call(location, argument_list),
+            Self::CryptoEccPointMul(inner) => inner.call(location, argument_list),
+            Self::CryptoEccPointGenerator(inner) => inner.call(location, argument_list),
+            Self::CryptoEccPointIsOnCurve(inner) => inner.call(location, argument_list),
+            Self::CryptoEccPointNegate(inner) => inner.call(location, argument_list),
 
             Self::ConvertToBits(inner) => inner.call(location, argument_list),
             Self::ConvertFromBitsUnsigned(inner) => inner.call(location, argument_list),
@@ -105,6 +210,18 @@ impl Function {
             Self::ArrayReverse(inner) => inner.call(location, argument_list),
             Self::ArrayTruncate(inner) => inner.call(location, argument_list),
             Self::ArrayPad(inner) => inner.call(location, argument_list),
+            Self::ArrayMap(inner) => return Self::inline(inner.call(location, argument_list)),
+            Self::ArrayFold(inner) => return Self::inline(inner.call(location, argument_list)),
+            Self::ArrayZip(inner) => return Self::inline(inner.call(location, argument_list)),
+            Self::ArrayAny(inner) => return Self::inline(inner.call(location, argument_list)),
+            Self::ArrayAll(inner) => return Self::inline(inner.call(location, argument_list)),
+            Self::ArrayPosition(inner) => return Self::inline(inner.call(location, argument_list)),
+            Self::ArrayFilterCount(inner) => {
+                return Self::inline(inner.call(location, argument_list))
+            }
+
+            Self::StringConcat(inner) => return Self::inline(inner.call(location, argument_list)),
+            Self::StringToBytes(inner) => return Self::inline(inner.call(location, argument_list)),
 
             Self::FfInvert(inner) => inner.call(location, argument_list),
 
@@ -112,7 +229,9 @@ impl Function {
             Self::CollectionsMTreeMapContains(inner) => inner.call(location, argument_list),
             Self::CollectionsMTreeMapInsert(inner) => inner.call(location, argument_list),
             Self::CollectionsMTreeMapRemove(inner) => inner.call(location, argument_list),
-        }
+        };
+
+        result.map(|r#type| (r#type, None))
     }
 
     ///
@@ -123,6 +242,18 @@ impl Function {
             Self::CryptoSha256(inner) => inner.identifier,
             Self::CryptoPedersen(inner) => inner.identifier,
             Self::CryptoSchnorrSignatureVerify(inner) => inner.identifier,
+            Self::CryptoBlake2s(inner) => inner.identifier,
+            Self::CryptoPoseidon(inner) => inner.identifier,
+            Self::CryptoRescue(inner) => inner.identifier,
+            Self::CryptoKeccak256(inner) => inner.identifier,
+            Self::CryptoEcdsaVerify(inner) => inner.identifier,
+            Self::CryptoEddsaSignatureVerify(inner) => inner.identifier,
+            Self::CryptoEccPointAdd(inner) => inner.identifier,
+            Self::CryptoEccPointDouble(inner) => inner.identifier,
+            Self::CryptoEccPointMul(inner) => inner.identifier,
+            Self::CryptoEccPointGenerator(inner) => inner.identifier,
+            Self::CryptoEccPointIsOnCurve(inner) => inner.identifier,
+            Self::CryptoEccPointNegate(inner) => inner.identifier,
 
             Self::ConvertToBits(inner) => inner.identifier,
             Self::ConvertFromBitsUnsigned(inner) => inner.identifier,
@@ -132,6 +263,16 @@ impl Function {
             Self::ArrayReverse(inner) => inner.identifier,
             Self::ArrayTruncate(inner) => inner.identifier,
             Self::ArrayPad(inner) => inner.identifier,
+            Self::ArrayMap(inner) => inner.identifier,
+            Self::ArrayFold(inner) => inner.identifier,
+            Self::ArrayZip(inner) => inner.identifier,
+            Self::ArrayAny(inner) => inner.identifier,
+            Self::ArrayAll(inner) => inner.identifier,
+            Self::ArrayPosition(inner) => inner.identifier,
+            Self::ArrayFilterCount(inner) => inner.identifier,
+
+            Self::StringConcat(inner) => inner.identifier,
+            Self::StringToBytes(inner) => inner.identifier,
 
             Self::FfInvert(inner) => inner.identifier,
 
@@ -145,27 +286,51 @@ impl Function {
     ///
     /// The unique standard library function identifier.
     ///
-    pub fn library_identifier(&self) -> LibraryFunctionIdentifier {
+    /// Returns `None` for the functions inlined at compile time.
+    ///
+    pub fn library_identifier(&self) -> Option<LibraryFunctionIdentifier> {
         match self {
-            Self::CryptoSha256(inner) => inner.library_identifier,
-            Self::CryptoPedersen(inner) => inner.library_identifier,
-            Self::CryptoSchnorrSignatureVerify(inner) => inner.library_identifier,
-
-            Self::ConvertToBits(inner) => inner.library_identifier,
-            Self::ConvertFromBitsUnsigned(inner) => inner.library_identifier,
-            Self::ConvertFromBitsSigned(inner) => inner.library_identifier,
-            Self::ConvertFromBitsField(inner) => inner.library_identifier,
-
-            Self::ArrayReverse(inner) => inner.library_identifier,
-            Self::ArrayTruncate(inner) => inner.library_identifier,
-            Self::ArrayPad(inner) => inner.library_identifier,
-
-            Self::FfInvert(inner) => inner.library_identifier,
-
-            Self::CollectionsMTreeMapGet(inner) => inner.library_identifier,
-            Self::CollectionsMTreeMapContains(inner) => inner.library_identifier,
-            Self::CollectionsMTreeMapInsert(inner) => inner.library_identifier,
-            Self::CollectionsMTreeMapRemove(inner) => inner.library_identifier,
+            Self::CryptoSha256(inner) => Some(inner.library_identifier),
+            Self::CryptoPedersen(inner) => Some(inner.library_identifier),
+            Self::CryptoSchnorrSignatureVerify(inner) => Some(inner.library_identifier),
+            Self::CryptoBlake2s(inner) => Some(inner.library_identifier),
+            Self::CryptoPoseidon(inner) => Some(inner.library_identifier),
+            Self::CryptoRescue(inner) => Some(inner.library_identifier),
+            Self::CryptoKeccak256(inner) => Some(inner.library_identifier),
+            Self::CryptoEcdsaVerify(inner) => Some(inner.library_identifier),
+            Self::CryptoEddsaSignatureVerify(inner) => Some(inner.library_identifier),
+            Self::CryptoEccPointAdd(inner) => Some(inner.library_identifier),
+            Self::CryptoEccPointDouble(inner) => Some(inner.library_identifier),
+            Self::CryptoEccPointMul(inner) => Some(inner.library_identifier),
+            Self::CryptoEccPointGenerator(inner) => Some(inner.library_identifier),
+            Self::CryptoEccPointIsOnCurve(inner) => Some(inner.library_identifier),
+            Self::CryptoEccPointNegate(inner) => Some(inner.library_identifier),
+
+            Self::ConvertToBits(inner) => Some(inner.library_identifier),
+            Self::ConvertFromBitsUnsigned(inner) => Some(inner.library_identifier),
+            Self::ConvertFromBitsSigned(inner) => Some(inner.library_identifier),
+            Self::ConvertFromBitsField(inner) => Some(inner.library_identifier),
+
+            Self::ArrayReverse(inner) => Some(inner.library_identifier),
+            Self::ArrayTruncate(inner) => Some(inner.library_identifier),
+            Self::ArrayPad(inner) => Some(inner.library_identifier),
+            Self::ArrayMap(_) => None,
+            Self::ArrayFold(_) => None,
+            Self::ArrayZip(_) => None,
+            Self::ArrayAny(_) => None,
+            Self::ArrayAll(_) => None,
+            Self::ArrayPosition(_) => None,
+            Self::ArrayFilterCount(_) => None,
+
+            Self::StringConcat(_) => None,
+            Self::StringToBytes(_) => None,
+
+            Self::FfInvert(inner) => Some(inner.library_identifier),
+
+            Self::CollectionsMTreeMapGet(inner) => Some(inner.library_identifier),
+            Self::CollectionsMTreeMapContains(inner) => Some(inner.library_identifier),
+            Self::CollectionsMTreeMapInsert(inner) => Some(inner.library_identifier),
+            Self::CollectionsMTreeMapRemove(inner) => Some(inner.library_identifier),
         }
     }
 
@@ -177,6 +342,18 @@ impl Function {
             Self::CryptoSha256(_) => false,
             Self::CryptoPedersen(_) => false,
             Self::CryptoSchnorrSignatureVerify(_) => false,
+            Self::CryptoBlake2s(_) => false,
+            Self::CryptoPoseidon(_) => false,
+            Self::CryptoRescue(_) => false,
+            Self::CryptoKeccak256(_) => false,
+            Self::CryptoEcdsaVerify(_) => false,
+            Self::CryptoEddsaSignatureVerify(_) => false,
+            Self::CryptoEccPointAdd(_) => false,
+            Self::CryptoEccPointDouble(_) => false,
+            Self::CryptoEccPointMul(_) => false,
+            Self::CryptoEccPointGenerator(_) => false,
+            Self::CryptoEccPointIsOnCurve(_) => false,
+            Self::CryptoEccPointNegate(_) => false,
 
             Self::ConvertToBits(_) => false,
             Self::ConvertFromBitsUnsigned(_) => false,
@@ -186,6 +363,16 @@ impl Function {
             Self::ArrayReverse(_) => false,
             Self::ArrayTruncate(_) => false,
             Self::ArrayPad(_) => false,
+            Self::ArrayMap(_) => false,
+            Self::ArrayFold(_) => false,
+            Self::ArrayZip(_) => false,
+            Self::ArrayAny(_) => false,
+            Self::ArrayAll(_) => false,
+            Self::ArrayPosition(_) => false,
+            Self::ArrayFilterCount(_) => false,
+
+            Self::StringConcat(_) => false,
+            Self::StringToBytes(_) => false,
 
             Self::FfInvert(_) => false,
 
@@ -204,6 +391,18 @@ impl Function {
             Self::CryptoSha256(inner) => inner.location = Some(location),
             Self::CryptoPedersen(inner) => inner.location = Some(location),
             Self::CryptoSchnorrSignatureVerify(inner) => inner.location = Some(location),
+            Self::CryptoBlake2s(inner) => inner.location = Some(location),
+            Self::CryptoPoseidon(inner) => inner.location = Some(location),
+            Self::CryptoRescue(inner) => inner.location = Some(location),
+            Self::CryptoKeccak256(inner) => inner.location = Some(location),
+            Self::CryptoEcdsaVerify(inner) => inner.location = Some(location),
+            Self::CryptoEddsaSignatureVerify(inner) => inner.location = Some(location),
+            Self::CryptoEccPointAdd(inner) => inner.location = Some(location),
+            Self::CryptoEccPointDouble(inner) => inner.location = Some(location),
+            Self::CryptoEccPointMul(inner) => inner.location = Some(location),
+            Self::CryptoEccPointGenerator(inner) => inner.location = Some(location),
+            Self::CryptoEccPointIsOnCurve(inner) => inner.location = Some(location),
+            Self::CryptoEccPointNegate(inner) => inner.location = Some(location),
 
             Self::ConvertToBits(inner) => inner.location = Some(location),
             Self::ConvertFromBitsUnsigned(inner) => inner.location = Some(location),
@@ -213,6 +412,16 @@ impl Function {
             Self::ArrayReverse(inner) => inner.location = Some(location),
             Self::ArrayTruncate(inner) => inner.location = Some(location),
             Self::ArrayPad(inner) => inner.location = Some(location),
+            Self::ArrayMap(inner) => inner.location = Some(location),
+            Self::ArrayFold(inner) => inner.location = Some(location),
+            Self::ArrayZip(inner) => inner.location = Some(location),
+            Self::ArrayAny(inner) => inner.location = Some(location),
+            Self::ArrayAll(inner) => inner.location = Some(location),
+            Self::ArrayPosition(inner) => inner.location = Some(location),
+            Self::ArrayFilterCount(inner) => inner.location = Some(location),
+
+            Self::StringConcat(inner) => inner.location = Some(location),
+            Self::StringToBytes(inner) => inner.location = Some(location),
 
             Self::FfInvert(inner) => inner.location = Some(location),
 
@@ -231,6 +440,18 @@ impl Function {
             Self::CryptoSha256(inner) => inner.location,
             Self::CryptoPedersen(inner) => inner.location,
             Self::CryptoSchnorrSignatureVerify(inner) => inner.location,
+            Self::CryptoBlake2s(inner) => inner.location,
+            Self::CryptoPoseidon(inner) => inner.location,
+            Self::CryptoRescue(inner) => inner.location,
+            Self::CryptoKeccak256(inner) => inner.location,
+            Self::CryptoEcdsaVerify(inner) => inner.location,
+            Self::CryptoEddsaSignatureVerify(inner) => inner.location,
+            Self::CryptoEccPointAdd(inner) => inner.location,
+            Self::CryptoEccPointDouble(inner) => inner.location,
+            Self::CryptoEccPointMul(inner) => inner.location,
+            Self::CryptoEccPointGenerator(inner) => inner.location,
+            Self::CryptoEccPointIsOnCurve(inner) => inner.location,
+            Self::CryptoEccPointNegate(inner) => inner.location,
 
             Self::ConvertToBits(inner) => inner.location,
             Self::ConvertFromBitsUnsigned(inner) => inner.location,
@@ -240,6 +461,16 @@ impl Function {
             Self::ArrayReverse(inner) => inner.location,
             Self::ArrayTruncate(inner) => inner.location,
             Self::ArrayPad(inner) => inner.location,
+            Self::ArrayMap(inner) => inner.location,
+            Self::ArrayFold(inner) => inner.location,
+            Self::ArrayZip(inner) => inner.location,
+            Self::ArrayAny(inner) => inner.location,
+            Self::ArrayAll(inner) => inner.location,
+            Self::ArrayPosition(inner) => inner.location,
+            Self::ArrayFilterCount(inner) => inner.location,
+
+            Self::StringConcat(inner) => inner.location,
+            Self::StringToBytes(inner) => inner.location,
 
             Self::FfInvert(inner) => inner.location,
 
@@ -249,6 +480,15 @@ impl Function {
             Self::CollectionsMTreeMapRemove(inner) => inner.location,
         }
     }
+
+    ///
+    /// Wraps the inlined function call result.
+    ///
+    fn inline(
+        result: Result<(Type, GeneratorInlineFunction), Error>,
+    ) -> Result<(Type, Option<GeneratorInlineFunction>), Error> {
+        result.map(|(r#type, function)| (r#type, Some(function)))
+    }
 }
 
 impl fmt::Display for Function {
@@ -257,6 +497,18 @@ impl fmt::Display for Function {
             Self::CryptoSha256(inner) => write!(f, "{}", inner),
             Self::CryptoPedersen(inner) => write!(f, "{}", inner),
             Self::CryptoSchnorrSignatureVerify(inner) => write!(f, "{}", inner),
+            Self::CryptoBlake2s(inner) => write!(f, "{}", inner),
+            Self::CryptoPoseidon(inner) => write!(f, "{}", inner),
+            Self::CryptoRescue(inner) => write!(f, "{}", inner),
+            Self::CryptoKeccak256(inner) => write!(f, "{}", inner),
+            Self::CryptoEcdsaVerify(inner) => write!(f, "{}", inner),
+            Self::CryptoEddsaSignatureVerify(inner) => write!(f, "{}", inner),
+            Self::CryptoEccPointAdd(inner) => write!(f, "{}", inner),
+            Self::CryptoEccPointDouble(inner) => write!(f, "{}", inner),
+            Self::CryptoEccPointMul(inner) => write!(f, "{}", inner),
+            Self::CryptoEccPointGenerator(inner) => write!(f, "{}", inner),
+            Self::CryptoEccPointIsOnCurve(inner) => write!(f, "{}", inner),
+            Self::CryptoEccPointNegate(inner) => write!(f, "{}", inner),
 
             Self::ConvertToBits(inner) => write!(f, "{}", inner),
             Self::ConvertFromBitsUnsigned(inner) => write!(f, "{}", inner),
@@ -266,6 +518,16 @@ impl fmt::Display for Function {
             Self::ArrayReverse(inner) => write!(f, "{}", inner),
             Self::ArrayTruncate(inner) => write!(f, "{}", inner),
             Self::ArrayPad(inner) => write!(f, "{}", inner),
+            Self::ArrayMap(inner) => write!(f, "{}", inner),
+            Self::ArrayFold(inner) => write!(f, "{}", inner),
+            Self::ArrayZip(inner) => write!(f, "{}", inner),
+            Self::ArrayAny(inner) => write!(f, "{}", inner),
+            Self::ArrayAll(inner) => write!(f, "{}", inner),
+            Self::ArrayPosition(inner) => write!(f, "{}", inner),
+            Self::ArrayFilterCount(inner) => write!(f, "{}", inner),
+
+            Self::StringConcat(inner) => write!(f, "{}", inner),
+            Self::StringToBytes(inner) => write!(f, "{}", inner),
 
             Self::FfInvert(inner) => write!(f, "{}", inner),
 
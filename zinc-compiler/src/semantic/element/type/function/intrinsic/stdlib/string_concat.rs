@@ -0,0 +1,136 @@
+//!
+//! The semantic analyzer standard library `std::string::concat` function element.
+//!
+
+use std::fmt;
+
+use zinc_lexical::Location;
+
+use crate::generator::expression::operator::inline::Function as GeneratorInlineFunction;
+use crate::semantic::element::argument_list::ArgumentList;
+use crate::semantic::element::r#type::i_typed::ITyped;
+use crate::semantic::element::r#type::Type;
+use crate::semantic::element::Element;
+use crate::semantic::error::Error;
+
+///
+/// The semantic analyzer standard library `std::string::concat` function element.
+///
+#[derive(Debug, Clone)]
+pub struct Function {
+    /// The location where the function is called.
+    pub location: Option<Location>,
+    /// The function identifier.
+    pub identifier: &'static str,
+}
+
+impl Default for Function {
+    fn default() -> Self {
+        Self {
+            location: None,
+            identifier: Self::IDENTIFIER,
+        }
+    }
+}
+
+impl Function {
+    /// The function identifier.
+    pub const IDENTIFIER: &'static str = "concat";
+
+    /// The position of the `first` argument in the function argument list.
+    pub const ARGUMENT_INDEX_FIRST: usize = 0;
+
+    /// The position of the `second` argument in the function argument list.
+    pub const ARGUMENT_INDEX_SECOND: usize = 1;
+
+    /// The expected number of the function arguments.
+    pub const ARGUMENT_COUNT: usize = 2;
+
+    ///
+    /// Calls the function with the `argument_list`, validating the call.
+    ///
+    /// Returns the result type and the function to be inlined.
+    ///
+    pub fn call(
+        self,
+        location: Location,
+        argument_list: ArgumentList,
+    ) -> Result<(Type, GeneratorInlineFunction), Error> {
+        let mut actual_params = Vec::with_capacity(argument_list.arguments.len());
+        for (index, element) in argument_list.arguments.into_iter().enumerate() {
+            let location = element.location();
+
+            let r#type = match element {
+                Element::Value(value) => value.r#type(),
+                Element::Constant(constant) => constant.r#type(),
+                element => {
+                    return Err(Error::FunctionArgumentNotEvaluable {
+                        location: location.expect(zinc_const::panic::VALUE_ALWAYS_EXISTS),
+                        function: self.identifier.to_owned(),
+                        position: index + 1,
+                        found: element.to_string(),
+                    })
+                }
+            };
+
+            actual_params.push((r#type, location));
+        }
+
+        if actual_params.len() != Self::ARGUMENT_COUNT {
+            return Err(Error::FunctionArgumentCount {
+                location,
+                function: self.identifier.to_owned(),
+                expected: Self::ARGUMENT_COUNT,
+                found: actual_params.len(),
+                reference: None,
+            });
+        }
+
+        let byte = Type::integer_unsigned(None, zinc_const::bitlength::BYTE);
+
+        let first_size = match actual_params.get(Self::ARGUMENT_INDEX_FIRST) {
+            Some((Type::Array(array), _location)) if array.r#type.as_ref() == &byte => array.size,
+            Some((r#type, location)) => {
+                return Err(Error::FunctionArgumentType {
+                    location: location.expect(zinc_const::panic::VALUE_ALWAYS_EXISTS),
+                    function: self.identifier.to_owned(),
+                    name: "first".to_owned(),
+                    position: Self::ARGUMENT_INDEX_FIRST + 1,
+                    expected: format!("[{}; N]", byte),
+                    found: r#type.to_string(),
+                })
+            }
+            None => panic!(zinc_const::panic::VALIDATED_DURING_SEMANTIC_ANALYSIS),
+        };
+
+        let second_size = match actual_params.get(Self::ARGUMENT_INDEX_SECOND) {
+            Some((Type::Array(array), _location)) if array.r#type.as_ref() == &byte => array.size,
+            Some((r#type, location)) => {
+                return Err(Error::FunctionArgumentType {
+                    location: location.expect(zinc_const::panic::VALUE_ALWAYS_EXISTS),
+                    function: self.identifier.to_owned(),
+                    name: "second".to_owned(),
+                    position: Self::ARGUMENT_INDEX_SECOND + 1,
+                    expected: format!("[{}; M]", byte),
+                    found: r#type.to_string(),
+                })
+            }
+            None => panic!(zinc_const::panic::VALIDATED_DURING_SEMANTIC_ANALYSIS),
+        };
+
+        Ok((
+            Type::array(Some(location), byte, first_size + second_size),
+            GeneratorInlineFunction::StringConcat,
+        ))
+    }
+}
+
+impl fmt::Display for Function {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "string::{}(first: [u8; N], second: [u8; M]) -> [u8; N + M]",
+            self.identifier,
+        )
+    }
+}
@@ -0,0 +1,124 @@
+//!
+//! The semantic analyzer standard library `std::string::to_bytes` function element.
+//!
+
+use std::fmt;
+
+use zinc_lexical::Location;
+
+use crate::generator::expression::operator::inline::Function as GeneratorInlineFunction;
+use crate::semantic::element::argument_list::ArgumentList;
+use crate::semantic::element::constant::Constant;
+use crate::semantic::element::r#type::i_typed::ITyped;
+use crate::semantic::element::r#type::Type;
+use crate::semantic::element::Element;
+use crate::semantic::error::Error;
+
+///
+/// The semantic analyzer standard library `std::string::to_bytes` function element.
+///
+#[derive(Debug, Clone)]
+pub struct Function {
+    /// The location where the function is called.
+    pub location: Option<Location>,
+    /// The function identifier.
+    pub identifier: &'static str,
+}
+
+impl Default for Function {
+    fn default() -> Self {
+        Self {
+            location: None,
+            identifier: Self::IDENTIFIER,
+        }
+    }
+}
+
+impl Function {
+    /// The function identifier.
+    pub const IDENTIFIER: &'static str = "to_bytes";
+
+    /// The position of the `string` argument in the function argument list.
+    pub const ARGUMENT_INDEX_STRING: usize = 0;
+
+    /// The expected number of the function arguments.
+    pub const ARGUMENT_COUNT: usize = 1;
+
+    ///
+    /// Calls the function with the `argument_list`, validating the call.
+    ///
+    /// Returns the result type and the function to be inlined.
+    ///
+    pub fn call(
+        self,
+        location: Location,
+        argument_list: ArgumentList,
+    ) -> Result<(Type, GeneratorInlineFunction), Error> {
+        if argument_list.arguments.len() != Self::ARGUMENT_COUNT {
+            return Err(Error::FunctionArgumentCount {
+                location,
+                function: self.identifier.to_owned(),
+                expected: Self::ARGUMENT_COUNT,
+                found: argument_list.arguments.len(),
+                reference: None,
+            });
+        }
+
+        let string = match argument_list
+            .arguments
+            .into_iter()
+            .next()
+            .expect(zinc_const::panic::VALUE_ALWAYS_EXISTS)
+        {
+            Element::Constant(Constant::String(string)) => string.inner,
+            Element::Constant(constant) => {
+                return Err(Error::FunctionArgumentType {
+                    location: constant.location(),
+                    function: self.identifier.to_owned(),
+                    name: "string".to_owned(),
+                    position: Self::ARGUMENT_INDEX_STRING + 1,
+                    expected: Type::string(None).to_string(),
+                    found: constant.r#type().to_string(),
+                })
+            }
+            Element::Value(value) => {
+                return Err(Error::FunctionArgumentConstantness {
+                    location: value
+                        .location()
+                        .expect(zinc_const::panic::VALUE_ALWAYS_EXISTS),
+                    function: self.identifier.to_owned(),
+                    name: "string".to_owned(),
+                    position: Self::ARGUMENT_INDEX_STRING + 1,
+                    found: value.r#type().to_string(),
+                })
+            }
+            element => {
+                return Err(Error::FunctionArgumentNotEvaluable {
+                    location: element
+                        .location()
+                        .expect(zinc_const::panic::VALUE_ALWAYS_EXISTS),
+                    function: self.identifier.to_owned(),
+                    position: Self::ARGUMENT_INDEX_STRING + 1,
+                    found: element.to_string(),
+                })
+            }
+        };
+
+        let bytes = string.into_bytes();
+
+        Ok((
+            Type::array(
+                Some(location),
+                Type::integer_unsigned(None, zinc_const::bitlength::BYTE),
+                bytes.len(),
+            ),
+            GeneratorInlineFunction::StringToBytes { bytes },
+        ))
+    }
+}
+
+impl fmt::Display for Function {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "string::{}(string: str) -> [u8; N]", self.identifier)
+    }
+}
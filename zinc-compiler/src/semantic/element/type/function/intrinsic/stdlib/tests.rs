@@ -10,23 +10,40 @@ use zinc_lexical::Keyword;
 use zinc_lexical::Location;
 
 use crate::error::Error;
+use crate::semantic::element::constant::boolean::Boolean as BooleanConstant;
 use crate::semantic::element::constant::integer::Integer as IntegerConstant;
+use crate::semantic::element::constant::Constant;
 use crate::semantic::element::r#type::function::intrinsic::stdlib::collections_mtreemap_get::Function as CollectionsMTreeMapGetFunction;
 use crate::semantic::element::r#type::function::intrinsic::stdlib::collections_mtreemap_contains::Function as CollectionsMTreeMapContainsFunction;
 use crate::semantic::element::r#type::function::intrinsic::stdlib::collections_mtreemap_insert::Function as CollectionsMTreeMapInsertFunction;
 use crate::semantic::element::r#type::function::intrinsic::stdlib::collections_mtreemap_remove::Function as CollectionsMTreeMapRemoveFunction;
+use crate::semantic::element::r#type::function::intrinsic::stdlib::array_filter_count::Function as ArrayFilterCountFunction;
+use crate::semantic::element::r#type::function::intrinsic::stdlib::array_map::Function as ArrayMapFunction;
 use crate::semantic::element::r#type::function::intrinsic::stdlib::array_pad::Function as ArrayPadFunction;
 use crate::semantic::element::r#type::function::intrinsic::stdlib::array_reverse::Function as ArrayReverseFunction;
 use crate::semantic::element::r#type::function::intrinsic::stdlib::array_truncate::Function as ArrayTruncateFunction;
+use crate::semantic::element::r#type::function::intrinsic::stdlib::array_zip::Function as ArrayZipFunction;
 use crate::semantic::element::r#type::function::intrinsic::stdlib::convert_from_bits_field::Function as ConvertFromBitsFieldFunction;
 use crate::semantic::element::r#type::function::intrinsic::stdlib::convert_from_bits_signed::Function as ConvertFromBitsSignedFunction;
 use crate::semantic::element::r#type::function::intrinsic::stdlib::convert_from_bits_unsigned::Function as ConvertFromBitsUnsignedFunction;
 use crate::semantic::element::r#type::function::intrinsic::stdlib::convert_to_bits::Function as ConvertToBitsFunction;
+use crate::semantic::element::r#type::function::intrinsic::stdlib::crypto_blake2s::Function as CryptoBlake2sFunction;
+use crate::semantic::element::r#type::function::intrinsic::stdlib::crypto_ecc_point_add::Function as CryptoEccPointAddFunction;
+use crate::semantic::element::r#type::function::intrinsic::stdlib::crypto_ecc_point_generator::Function as CryptoEccPointGeneratorFunction;
+use crate::semantic::element::r#type::function::intrinsic::stdlib::crypto_ecc_point_mul::Function as CryptoEccPointMulFunction;
+use crate::semantic::element::r#type::function::intrinsic::stdlib::crypto_ecdsa_verify::Function as CryptoEcdsaVerifyFunction;
+use crate::semantic::element::r#type::function::intrinsic::stdlib::crypto_eddsa_signature_verify::Function as CryptoEddsaSignatureVerifyFunction;
+use crate::semantic::element::r#type::function::intrinsic::stdlib::crypto_keccak256::Function as CryptoKeccak256Function;
 use crate::semantic::element::r#type::function::intrinsic::stdlib::crypto_pedersen::Function as CryptoPedersenFunction;
+use crate::semantic::element::r#type::function::intrinsic::stdlib::crypto_poseidon::Function as CryptoPoseidonFunction;
+use crate::semantic::element::r#type::function::intrinsic::stdlib::crypto_rescue::Function as CryptoRescueFunction;
 use crate::semantic::element::r#type::function::intrinsic::stdlib::crypto_schnorr_signature_verify::Function as CryptoSchnorrSignatureVerifyFunction;
 use crate::semantic::element::r#type::function::intrinsic::stdlib::crypto_sha256::Function as CryptoSha256Function;
 use crate::semantic::element::r#type::function::intrinsic::stdlib::ff_invert::Function as FfInvertFunction;
+use crate::semantic::element::r#type::function::intrinsic::stdlib::string_concat::Function as StringConcatFunction;
+use crate::semantic::element::r#type::function::intrinsic::stdlib::string_to_bytes::Function as StringToBytesFunction;
 use crate::semantic::element::r#type::Type;
+use crate::semantic::element::Element;
 use crate::semantic::error::Error as SemanticError;
 
 #[test]
@@ -311,6 +328,418 @@ fn main() {
     assert_eq!(result, expected);
 }
 
+#[test]
+fn error_crypto_blake2s_argument_count_greater() {
+    let input = r#"
+fn main() {
+    std::crypto::blake2s([true; 8], 42);
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::FunctionArgumentCount {
+        location: Location::test(3, 5),
+        function: CryptoBlake2sFunction::IDENTIFIER.to_owned(),
+        expected: CryptoBlake2sFunction::ARGUMENT_COUNT,
+        found: CryptoBlake2sFunction::ARGUMENT_COUNT + 1,
+        reference: None,
+    }));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_crypto_blake2s_argument_1_preimage_expected_bit_array_size_multiple_8() {
+    let input = r#"
+fn main() {
+    std::crypto::blake2s([true; 4]);
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::FunctionArgumentType {
+        location: Location::test(3, 26),
+        function: CryptoBlake2sFunction::IDENTIFIER.to_owned(),
+        name: "preimage".to_owned(),
+        position: CryptoBlake2sFunction::ARGUMENT_INDEX_PREIMAGE + 1,
+        expected: format!("[bool; N], N > 0, N % {} == 0", zinc_const::bitlength::BYTE),
+        found: Type::array(Some(Location::test(3, 26)), Type::boolean(None), 4).to_string(),
+    }));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_crypto_poseidon_argument_1_preimage_expected_field_array() {
+    let input = r#"
+fn main() {
+    std::crypto::poseidon([true; 2]);
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::FunctionArgumentType {
+        location: Location::test(3, 27),
+        function: CryptoPoseidonFunction::IDENTIFIER.to_owned(),
+        name: "preimage".to_owned(),
+        position: CryptoPoseidonFunction::ARGUMENT_INDEX_PREIMAGE + 1,
+        expected: format!(
+            "[field; N], 0 < N <= {}",
+            zinc_const::limit::POSEIDON_HASH_INPUT_FIELDS
+        ),
+        found: Type::array(Some(Location::test(3, 27)), Type::boolean(None), 2).to_string(),
+    }));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_crypto_rescue_argument_1_preimage_expected_field_array_size_limit() {
+    let input = r#"
+fn main() {
+    std::crypto::rescue([0 as field; 17]);
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::FunctionArgumentType {
+        location: Location::test(3, 25),
+        function: CryptoRescueFunction::IDENTIFIER.to_owned(),
+        name: "preimage".to_owned(),
+        position: CryptoRescueFunction::ARGUMENT_INDEX_PREIMAGE + 1,
+        expected: format!(
+            "[field; N], 0 < N <= {}",
+            zinc_const::limit::RESCUE_HASH_INPUT_FIELDS
+        ),
+        found: Type::array(
+            Some(Location::test(3, 25)),
+            Type::field(None),
+            zinc_const::limit::RESCUE_HASH_INPUT_FIELDS + 1,
+        )
+        .to_string(),
+    }));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_crypto_keccak256_argument_1_preimage_expected_bit_array_size_multiple_8() {
+    let input = r#"
+fn main() {
+    std::crypto::keccak256([true; 12]);
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::FunctionArgumentType {
+        location: Location::test(3, 28),
+        function: CryptoKeccak256Function::IDENTIFIER.to_owned(),
+        name: "preimage".to_owned(),
+        position: CryptoKeccak256Function::ARGUMENT_INDEX_PREIMAGE + 1,
+        expected: format!("[bool; N], N > 0, N % {} == 0", zinc_const::bitlength::BYTE),
+        found: Type::array(Some(Location::test(3, 28)), Type::boolean(None), 12).to_string(),
+    }));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_crypto_ecdsa_verify_argument_count_lesser() {
+    let input = r#"
+fn main() {
+    let msg_hash = [false; 256];
+    let signature = [false; 520];
+    std::crypto::ecdsa::verify(msg_hash, signature);
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::FunctionArgumentCount {
+        location: Location::test(5, 5),
+        function: CryptoEcdsaVerifyFunction::IDENTIFIER.to_owned(),
+        expected: CryptoEcdsaVerifyFunction::ARGUMENT_COUNT,
+        found: CryptoEcdsaVerifyFunction::ARGUMENT_COUNT - 1,
+        reference: None,
+    }));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_crypto_ecdsa_verify_argument_1_msg_hash_expected_bit_array() {
+    let input = r#"
+fn main() {
+    let msg_hash = [false; 248];
+    let signature = [false; 520];
+    std::crypto::ecdsa::verify(msg_hash, signature, 0x42 as u160);
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::FunctionArgumentType {
+        location: Location::test(5, 32),
+        function: CryptoEcdsaVerifyFunction::IDENTIFIER.to_owned(),
+        name: "msg_hash".to_owned(),
+        position: CryptoEcdsaVerifyFunction::ARGUMENT_INDEX_MESSAGE_HASH + 1,
+        expected: format!("[bool; {}]", zinc_const::bitlength::KECCAK256_HASH),
+        found: Type::array(Some(Location::test(3, 20)), Type::boolean(None), 248).to_string(),
+    }));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_crypto_ecdsa_verify_argument_2_signature_expected_bit_array() {
+    let input = r#"
+fn main() {
+    let msg_hash = [false; 256];
+    let signature = [false; 512];
+    std::crypto::ecdsa::verify(msg_hash, signature, 0x42 as u160);
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::FunctionArgumentType {
+        location: Location::test(5, 42),
+        function: CryptoEcdsaVerifyFunction::IDENTIFIER.to_owned(),
+        name: "signature".to_owned(),
+        position: CryptoEcdsaVerifyFunction::ARGUMENT_INDEX_SIGNATURE + 1,
+        expected: format!("[bool; {}]", zinc_const::bitlength::ETH_SIGNATURE),
+        found: Type::array(Some(Location::test(4, 21)), Type::boolean(None), 512).to_string(),
+    }));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_crypto_ecdsa_verify_argument_3_eth_address_expected_u160() {
+    let input = r#"
+fn main() {
+    let msg_hash = [false; 256];
+    let signature = [false; 520];
+    std::crypto::ecdsa::verify(msg_hash, signature, 0x42 as u248);
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::FunctionArgumentType {
+        location: Location::test(5, 53),
+        function: CryptoEcdsaVerifyFunction::IDENTIFIER.to_owned(),
+        name: "eth_address".to_owned(),
+        position: CryptoEcdsaVerifyFunction::ARGUMENT_INDEX_ETH_ADDRESS + 1,
+        expected: Type::integer_unsigned(None, zinc_const::bitlength::ETH_ADDRESS).to_string(),
+        found: Type::integer_unsigned(None, zinc_const::bitlength::INTEGER_MAX).to_string(),
+    }));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_crypto_ecc_point_generator_argument_count_greater() {
+    let input = r#"
+use std::crypto::ecc::Point;
+
+fn main() {
+    let point = Point::generator(42 as field);
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::FunctionArgumentCount {
+        location: Location::test(5, 17),
+        function: CryptoEccPointGeneratorFunction::IDENTIFIER.to_owned(),
+        expected: CryptoEccPointGeneratorFunction::ARGUMENT_COUNT,
+        found: CryptoEccPointGeneratorFunction::ARGUMENT_COUNT + 1,
+        reference: None,
+    }));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_crypto_ecc_point_add_argument_count_lesser() {
+    let input = r#"
+use std::crypto::ecc::Point;
+
+fn main() {
+    let point = Point::generator();
+    let result = point.add();
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::FunctionArgumentCount {
+        location: Location::test(6, 27),
+        function: CryptoEccPointAddFunction::IDENTIFIER.to_owned(),
+        expected: CryptoEccPointAddFunction::ARGUMENT_COUNT,
+        found: CryptoEccPointAddFunction::ARGUMENT_COUNT - 1,
+        reference: None,
+    }));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_crypto_ecc_point_add_argument_1_self_expected_point() {
+    let input = r#"
+use std::crypto::ecc::Point;
+
+fn main() {
+    let point = Point::generator();
+    let result = Point::add(42 as field, point);
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::FunctionArgumentType {
+        location: Location::test(6, 29),
+        function: CryptoEccPointAddFunction::IDENTIFIER.to_owned(),
+        name: Keyword::SelfLowercase.to_string(),
+        position: CryptoEccPointAddFunction::ARGUMENT_INDEX_SELF + 1,
+        expected: "std::crypto::ecc::Point { x: field, y: field }".to_owned(),
+        found: Type::field(None).to_string(),
+    }));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_crypto_ecc_point_add_argument_2_other_expected_point() {
+    let input = r#"
+use std::crypto::ecc::Point;
+
+fn main() {
+    let point = Point::generator();
+    let result = point.add(42 as field);
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::FunctionArgumentType {
+        location: Location::test(6, 28),
+        function: CryptoEccPointAddFunction::IDENTIFIER.to_owned(),
+        name: "other".to_owned(),
+        position: CryptoEccPointAddFunction::ARGUMENT_INDEX_OTHER + 1,
+        expected: "std::crypto::ecc::Point { x: field, y: field }".to_owned(),
+        found: Type::field(None).to_string(),
+    }));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_crypto_ecc_point_mul_argument_2_scalar_expected_bit_array() {
+    let input = r#"
+use std::crypto::ecc::Point;
+
+fn main() {
+    let point = Point::generator();
+    let scalar = [false; 255];
+    let result = point.mul(scalar);
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::FunctionArgumentType {
+        location: Location::test(7, 28),
+        function: CryptoEccPointMulFunction::IDENTIFIER.to_owned(),
+        name: "scalar".to_owned(),
+        position: CryptoEccPointMulFunction::ARGUMENT_INDEX_SCALAR + 1,
+        expected: format!("[bool; N], 0 < N <= {}", zinc_const::bitlength::FIELD),
+        found: format!("array [{}; {}]", Type::boolean(None), 255),
+    }));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_crypto_eddsa_signature_verify_argument_1_signature_expected_signature() {
+    let input = r#"
+use std::crypto::ecc::Point;
+use std::crypto::eddsa;
+use std::crypto::schnorr;
+
+fn main() {
+    let signature = schnorr::Signature {
+        r: Point { x: 1 as field, y: 2 as field },
+        s: 3 as field,
+        pk: Point { x: 4 as field, y: 5 as field },
+    };
+    let message = [true; 8];
+
+    eddsa::Signature::verify(signature, message);
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::FunctionArgumentType {
+        location: Location::test(14, 30),
+        function: CryptoEddsaSignatureVerifyFunction::IDENTIFIER.to_owned(),
+        name: "signature".to_owned(),
+        position: CryptoEddsaSignatureVerifyFunction::ARGUMENT_INDEX_SIGNATURE + 1,
+        expected: "std::crypto::eddsa::Signature { r: std::crypto::ecc::Point, s: field, pk: std::crypto::ecc::Point }".to_owned(),
+        found: "structure Signature".to_owned(),
+    }));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_crypto_eddsa_signature_verify_argument_2_message_expected_bit_array_size_limit() {
+    let input = r#"
+use std::crypto::ecc::Point;
+use std::crypto::eddsa::Signature;
+
+fn main() {
+    let signature = Signature {
+        r: Point { x: 1 as field, y: 2 as field },
+        s: 3 as field,
+        pk: Point { x: 4 as field, y: 5 as field },
+    };
+    let message = [true; 744];
+
+    signature.verify(message);
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::FunctionArgumentType {
+        location: Location::test(13, 22),
+        function: CryptoEddsaSignatureVerifyFunction::IDENTIFIER.to_owned(),
+        name: "message".to_owned(),
+        position: CryptoEddsaSignatureVerifyFunction::ARGUMENT_INDEX_MESSAGE + 1,
+        expected: format!(
+            "[bool; N], 0 < N <= {}, N % {} == 0",
+            zinc_const::limit::EDDSA_MESSAGE_BITS,
+            zinc_const::bitlength::BYTE
+        ),
+        found: Type::array(
+            Some(Location::test(13, 34)),
+            Type::boolean(None),
+            zinc_const::limit::EDDSA_MESSAGE_BITS + zinc_const::bitlength::BYTE,
+        )
+        .to_string(),
+    }));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
 #[test]
 fn error_crypto_schnorr_signature_verify_argument_count_lesser() {
     let input = r#"
@@ -1940,3 +2369,205 @@ contract Test {
 
     assert_eq!(result, expected);
 }
+
+#[test]
+fn error_array_map_argument_2_f_expected_closure() {
+    let input = r#"
+fn main() {
+    std::array::map([1, 2], 42);
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::FunctionArgumentType {
+        location: Location::test(3, 29),
+        function: ArrayMapFunction::IDENTIFIER.to_owned(),
+        name: "f".to_owned(),
+        position: ArrayMapFunction::ARGUMENT_INDEX_CLOSURE + 1,
+        expected: format!(
+            "|{}| -> U",
+            Type::integer_unsigned(None, zinc_const::bitlength::BYTE)
+        ),
+        found: Element::Constant(Constant::Integer(IntegerConstant::new(
+            Location::test(3, 29),
+            BigInt::from(42),
+            false,
+            zinc_const::bitlength::BYTE,
+            true,
+        )))
+        .to_string(),
+    }));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_array_map_closure_parameter_count() {
+    let input = r#"
+fn main() {
+    std::array::map([1, 2], |a, b| a);
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::ClosureParameterCount {
+        location: Location::test(3, 29),
+        expected: 1,
+        found: 2,
+    }));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_array_any_closure_parameter_type() {
+    let input = r#"
+fn main() {
+    std::array::any([1, 2], |x: bool| x);
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::ClosureParameterType {
+        location: Location::test(3, 33),
+        position: 1,
+        expected: Type::integer_unsigned(None, zinc_const::bitlength::BYTE).to_string(),
+        found: Type::boolean(None).to_string(),
+    }));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_array_all_closure_return_type() {
+    let input = r#"
+fn main() {
+    std::array::all([1, 2], |x| x + 1);
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::ClosureReturnType {
+        location: Location::test(3, 35),
+        expected: Type::boolean(None).to_string(),
+        found: Type::integer_unsigned(None, zinc_const::bitlength::BYTE).to_string(),
+    }));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_array_zip_argument_2_second_size_mismatch() {
+    let input = r#"
+fn main() {
+    std::array::zip([1, 2], [true; 3]);
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::FunctionArgumentType {
+        location: Location::test(3, 29),
+        function: ArrayZipFunction::IDENTIFIER.to_owned(),
+        name: "second".to_owned(),
+        position: ArrayZipFunction::ARGUMENT_INDEX_SECOND + 1,
+        expected: "[B; 2]".to_owned(),
+        found: Type::array(None, Type::boolean(None), 3).to_string(),
+    }));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_closure_outside_of_argument() {
+    let input = r#"
+fn main() {
+    let f = |x| x;
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::ClosureOutsideOfArgument {
+        location: Location::test(3, 13),
+    }));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_string_concat_argument_2_second_expected_bytes() {
+    let input = r#"
+fn main() {
+    std::string::concat(b"hello", [true; 2]);
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::FunctionArgumentType {
+        location: Location::test(3, 35),
+        function: StringConcatFunction::IDENTIFIER.to_owned(),
+        name: "second".to_owned(),
+        position: StringConcatFunction::ARGUMENT_INDEX_SECOND + 1,
+        expected: "[u8; M]".to_owned(),
+        found: Type::array(None, Type::boolean(None), 2).to_string(),
+    }));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_string_to_bytes_argument_1_string_expected_string() {
+    let input = r#"
+fn main() {
+    std::string::to_bytes(42);
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::FunctionArgumentType {
+        location: Location::test(3, 27),
+        function: StringToBytesFunction::IDENTIFIER.to_owned(),
+        name: "string".to_owned(),
+        position: StringToBytesFunction::ARGUMENT_INDEX_STRING + 1,
+        expected: Type::string(None).to_string(),
+        found: Type::integer_unsigned(None, zinc_const::bitlength::BYTE).to_string(),
+    }));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_array_filter_count_argument_2_predicate_expected_closure() {
+    let input = r#"
+fn main() {
+    std::array::filter_count([1, 2], true);
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::FunctionArgumentType {
+        location: Location::test(3, 38),
+        function: ArrayFilterCountFunction::IDENTIFIER.to_owned(),
+        name: "predicate".to_owned(),
+        position: ArrayFilterCountFunction::ARGUMENT_INDEX_CLOSURE + 1,
+        expected: format!(
+            "|{}| -> bool",
+            Type::integer_unsigned(None, zinc_const::bitlength::BYTE)
+        ),
+        found: Element::Constant(Constant::Boolean(BooleanConstant::new(
+            Location::test(3, 38),
+            true,
+        )))
+        .to_string(),
+    }));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
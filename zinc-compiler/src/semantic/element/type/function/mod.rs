@@ -3,21 +3,30 @@
 //!
 
 pub mod constant;
+pub mod generic;
 pub mod intrinsic;
 pub mod runtime;
 pub mod test;
 
+use std::cell::RefCell;
 use std::fmt;
+use std::rc::Rc;
 
 use zinc_lexical::Location;
 use zinc_syntax::BlockExpression;
+use zinc_syntax::FnStatement;
 use zinc_types::LibraryFunctionIdentifier;
 
+use crate::semantic::analyzer::attribute::Attribute;
 use crate::semantic::binding::Binding;
 use crate::semantic::element::r#type::contract::Contract as ContractType;
+use crate::semantic::element::r#type::structure::Structure as StructureType;
 use crate::semantic::element::r#type::Type;
+use crate::semantic::scope::Scope;
 
 use self::constant::Function as ConstantFunction;
+use self::generic::Function as GenericFunction;
+use self::intrinsic::stdlib::Function as StandardLibraryFunction;
 use self::intrinsic::Function as IntrinsicFunction;
 use self::runtime::Function as RuntimeFunction;
 use self::test::Function as TestFunction;
@@ -39,6 +48,8 @@ pub enum Function {
     /// Constant functions declared anywhere within a project. There are executed at compile-time
     /// only and do not produce the intermediate representation.
     Constant(ConstantFunction),
+    /// Generic runtime functions, which are monomorphized into runtime ones at the call sites.
+    Generic(GenericFunction),
     /// Unit test functions. They produce the intermediate representation and are run as separate
     /// entry points in the special test mode.
     Test(TestFunction),
@@ -66,6 +77,20 @@ impl Function {
         Self::Intrinsic(IntrinsicFunction::contract_fetch(contract_type))
     }
 
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn ecc_point_generator(point_type: StructureType) -> Self {
+        Self::Intrinsic(IntrinsicFunction::ecc_point_generator(point_type))
+    }
+
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn standard_library(function: StandardLibraryFunction) -> Self {
+        Self::Intrinsic(IntrinsicFunction::standard_library(function))
+    }
+
     ///
     /// A shortcut constructor.
     ///
@@ -113,6 +138,22 @@ impl Function {
         ))
     }
 
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn generic(
+        location: Location,
+        identifier: String,
+        type_id: usize,
+        statement: FnStatement,
+        attributes: Vec<Attribute>,
+        scope: Rc<RefCell<Scope>>,
+    ) -> Self {
+        Self::Generic(GenericFunction::new(
+            location, identifier, type_id, statement, attributes, scope,
+        ))
+    }
+
     ///
     /// A shortcut constructor.
     ///
@@ -128,6 +169,7 @@ impl Function {
             Self::Intrinsic(inner) => inner.identifier().to_owned(),
             Self::Runtime(inner) => inner.identifier.to_owned(),
             Self::Constant(inner) => inner.identifier.to_owned(),
+            Self::Generic(inner) => inner.identifier.to_owned(),
             Self::Test(inner) => inner.identifier.to_owned(),
         }
     }
@@ -140,6 +182,7 @@ impl Function {
             Self::Intrinsic(inner) => inner.is_mutable(),
             Self::Runtime(inner) => inner.is_mutable(),
             Self::Constant(inner) => inner.is_mutable(),
+            Self::Generic(inner) => inner.is_mutable(),
             Self::Test(_) => false,
         }
     }
@@ -152,6 +195,7 @@ impl Function {
             Self::Intrinsic(inner) => inner.set_location(value),
             Self::Runtime(inner) => inner.location = value,
             Self::Constant(inner) => inner.location = value,
+            Self::Generic(inner) => inner.location = value,
             Self::Test(inner) => inner.location = value,
         }
     }
@@ -164,6 +208,7 @@ impl Function {
             Self::Intrinsic(inner) => inner.location(),
             Self::Runtime(inner) => Some(inner.location),
             Self::Constant(inner) => Some(inner.location),
+            Self::Generic(inner) => Some(inner.location),
             Self::Test(inner) => Some(inner.location),
         }
     }
@@ -175,6 +220,7 @@ impl fmt::Display for Function {
             Self::Intrinsic(inner) => write!(f, "{}", inner),
             Self::Runtime(inner) => write!(f, "{}", inner),
             Self::Constant(inner) => write!(f, "{}", inner),
+            Self::Generic(inner) => write!(f, "{}", inner),
             Self::Test(inner) => write!(f, "{}", inner),
         }
     }
@@ -0,0 +1,136 @@
+//!
+//! The semantic analyzer generic type arguments inference.
+//!
+
+use std::collections::HashMap;
+
+use zinc_lexical::Location;
+use zinc_syntax::ExpressionOperand;
+use zinc_syntax::ExpressionTreeNode;
+use zinc_syntax::GenericParameter;
+use zinc_syntax::Type as SyntaxType;
+use zinc_syntax::TypeVariant as SyntaxTypeVariant;
+
+use crate::semantic::element::r#type::Type;
+use crate::semantic::error::Error;
+
+///
+/// The generic type arguments inference.
+///
+/// Matches the syntax types written in terms of the generic parameters against the actual
+/// semantic types, e.g. the function argument types or structure literal field types.
+///
+/// The first occurrence of a parameter defines its argument. The conflicting occurrences are
+/// not reported here, since they are caught later as ordinary type mismatches.
+///
+pub struct Inference {
+    /// The generic parameters in the declaration order.
+    parameters: Vec<GenericParameter>,
+    /// The inferred generic arguments.
+    arguments: HashMap<String, Type>,
+}
+
+impl Inference {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(parameters: Vec<GenericParameter>) -> Self {
+        Self {
+            parameters,
+            arguments: HashMap::new(),
+        }
+    }
+
+    ///
+    /// Infers the generic arguments used in the `formal` type from the `actual` one.
+    ///
+    pub fn infer(&mut self, formal: &SyntaxType, actual: &Type) {
+        match (&formal.variant, actual) {
+            (SyntaxTypeVariant::Array { inner, .. }, Type::Array(actual)) => {
+                self.infer(inner.as_ref(), actual.r#type.as_ref());
+            }
+            (SyntaxTypeVariant::Tuple { inners }, Type::Tuple(actual))
+                if inners.len() == actual.types.len() =>
+            {
+                for (formal, actual) in inners.iter().zip(actual.types.iter()) {
+                    self.infer(formal, actual);
+                }
+            }
+            (SyntaxTypeVariant::Alias { path, generics }, actual) => {
+                match (path.left.as_ref(), path.right.as_ref(), path.value.as_ref()) {
+                    (
+                        None,
+                        None,
+                        ExpressionTreeNode::Operand(ExpressionOperand::Identifier(identifier)),
+                    ) if generics.is_none()
+                        && self
+                            .parameters
+                            .iter()
+                            .any(|parameter| parameter.identifier.name == identifier.name) =>
+                    {
+                        self.arguments
+                            .entry(identifier.name.to_owned())
+                            .or_insert_with(|| actual.to_owned());
+                        return;
+                    }
+                    _ => {}
+                }
+
+                match (generics, actual) {
+                    (Some(generics), Type::Structure(actual)) => {
+                        if let (Some(names), Some(params)) =
+                            (actual.generics.as_ref(), actual.params.as_ref())
+                        {
+                            for (formal, name) in generics.iter().zip(names.iter()) {
+                                if let Some(actual) = params.get(name) {
+                                    self.infer(formal, actual);
+                                }
+                            }
+                        }
+                    }
+                    (Some(generics), Type::Enumeration(actual)) => {
+                        if let Some(params) = actual.params.as_ref() {
+                            for (formal, actual) in generics.iter().zip(params.iter()) {
+                                self.infer(formal, actual);
+                            }
+                        }
+                    }
+                    _ => {}
+                }
+            }
+            _ => {}
+        }
+    }
+
+    ///
+    /// Checks if all the generic arguments have been inferred.
+    ///
+    pub fn is_complete(&self) -> bool {
+        self.parameters
+            .iter()
+            .all(|parameter| self.arguments.contains_key(parameter.identifier.name.as_str()))
+    }
+
+    ///
+    /// Returns the inferred generic arguments in the declaration order.
+    ///
+    /// Returns an error if some parameter has not been inferred.
+    ///
+    pub fn finish(mut self, location: Location, item: &str) -> Result<Vec<Type>, Error> {
+        let mut arguments = Vec::with_capacity(self.parameters.len());
+        for parameter in self.parameters.into_iter() {
+            match self.arguments.remove(parameter.identifier.name.as_str()) {
+                Some(argument) => arguments.push(argument),
+                None => {
+                    return Err(Error::TypeGenericArgumentNotInferred {
+                        location,
+                        r#type: item.to_owned(),
+                        name: parameter.identifier.name,
+                    })
+                }
+            }
+        }
+
+        Ok(arguments)
+    }
+}
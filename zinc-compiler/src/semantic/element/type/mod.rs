@@ -10,9 +10,11 @@ pub mod contract;
 pub mod enumeration;
 pub mod function;
 pub mod i_typed;
+pub mod inference;
 pub mod range;
 pub mod range_inclusive;
 pub mod structure;
+pub mod r#trait;
 pub mod tuple;
 
 use std::cell::RefCell;
@@ -22,10 +24,13 @@ use std::rc::Rc;
 
 use zinc_lexical::Location;
 use zinc_syntax::BlockExpression;
+use zinc_syntax::FnPrototype;
+use zinc_syntax::FnStatement;
 use zinc_syntax::Type as SyntaxType;
 use zinc_syntax::TypeVariant as SyntaxTypeVariant;
 use zinc_syntax::Variant;
 
+use crate::semantic::analyzer::attribute::Attribute;
 use crate::semantic::analyzer::expression::Analyzer as ExpressionAnalyzer;
 use crate::semantic::analyzer::rule::Rule as TranslationRule;
 use crate::semantic::binding::Binding;
@@ -40,18 +45,22 @@ use crate::semantic::scope::Scope;
 use self::array::Array;
 use self::contract::field::Field as ContractField;
 use self::contract::Contract;
+use self::enumeration::payload::Payload as EnumerationPayload;
+use self::enumeration::template::Template as EnumerationTemplate;
 use self::enumeration::Enumeration;
 use self::function::Function;
 use self::i_typed::ITyped;
 use self::range::Range;
 use self::range_inclusive::RangeInclusive;
+use self::structure::template::Template;
+use self::r#trait::Trait;
 use self::structure::Structure;
 use self::tuple::Tuple;
 
 ///
 /// The semantic type is converted from a syntax type during syntax analysis.
 ///
-/// `Structure`, `Enumeration`, `Function`, `Contract` are resolved from the scope hierarchy.
+/// `Structure`, `Enumeration`, `Function`, `Contract`, `Trait` are resolved from the scope hierarchy.
 ///
 #[derive(Debug, Clone)]
 pub enum Type {
@@ -93,6 +102,8 @@ pub enum Type {
     Function(Function),
     /// The special contract type declared with a `contract` statement.
     Contract(Contract),
+    /// The special trait type declared with a `trait` statement.
+    Trait(Trait),
 }
 
 impl Type {
@@ -215,20 +226,89 @@ impl Type {
         ))
     }
 
+    ///
+    /// A helper type constructor, which allocates a unique sequence ID for the type.
+    ///
+    /// The generic structure is a template, which is instantiated for each set of
+    /// generic arguments.
+    ///
+    pub fn generic_structure(
+        location: Option<Location>,
+        identifier: String,
+        generics: Vec<String>,
+        template: Template,
+        scope: Rc<RefCell<Scope>>,
+    ) -> Self {
+        let type_id = TYPE_INDEX.next(format!("structure {}", identifier));
+
+        let mut structure = Structure::new(
+            location,
+            identifier,
+            type_id,
+            vec![],
+            Some(generics),
+            None,
+            scope,
+        );
+        structure.template = Some(Rc::new(template));
+        Self::Structure(structure)
+    }
+
     ///
     /// A helper type constructor, which allocates a unique sequence ID for the type.
     ///
     pub fn enumeration(
+        location: Location,
+        identifier: String,
+        variants: Vec<Variant>,
+        payloads: Option<Vec<EnumerationPayload>>,
+        generics: Vec<String>,
+        scope: Rc<RefCell<Scope>>,
+    ) -> Result<Self, Error> {
+        let type_id = TYPE_INDEX.next(format!("enumeration {}", identifier));
+
+        let enumeration = Enumeration::new(
+            location,
+            identifier,
+            type_id,
+            variants.as_slice(),
+            payloads,
+            generics,
+            scope,
+        )?;
+        enumeration.define_variants(variants.as_slice())?;
+        Ok(Self::Enumeration(enumeration))
+    }
+
+    ///
+    /// A helper type constructor, which allocates a unique sequence ID for the type.
+    ///
+    /// The generic enumeration is a template, which is instantiated for each set of
+    /// generic arguments. Its variants are defined before the instantiation, so they can be
+    /// referenced like `Option::None`, with the generic arguments inferred from the payload.
+    ///
+    pub fn generic_enumeration(
         location: Location,
         identifier: String,
         variants: Vec<Variant>,
         generics: Vec<String>,
+        template: EnumerationTemplate,
         scope: Rc<RefCell<Scope>>,
     ) -> Result<Self, Error> {
         let type_id = TYPE_INDEX.next(format!("enumeration {}", identifier));
 
-        Enumeration::new(location, identifier, type_id, variants, generics, scope)
-            .map(Self::Enumeration)
+        let mut enumeration = Enumeration::new(
+            location,
+            identifier,
+            type_id,
+            variants.as_slice(),
+            Some(vec![]),
+            generics,
+            scope,
+        )?;
+        enumeration.template = Some(Rc::new(template));
+        enumeration.define_variants(variants.as_slice())?;
+        Ok(Self::Enumeration(enumeration))
     }
 
     ///
@@ -254,6 +334,23 @@ impl Type {
         )
     }
 
+    ///
+    /// A helper type constructor, which allocates a unique sequence ID for the type.
+    ///
+    pub fn generic_function(
+        location: Location,
+        identifier: String,
+        statement: FnStatement,
+        attributes: Vec<Attribute>,
+        scope: Rc<RefCell<Scope>>,
+    ) -> Self {
+        let type_id = TYPE_INDEX.next(format!("function {}", identifier));
+
+        Self::Function(Function::generic(
+            location, identifier, type_id, statement, attributes, scope,
+        ))
+    }
+
     ///
     /// A helper type constructor, which allocates a unique sequence ID for the type.
     ///
@@ -303,6 +400,15 @@ impl Type {
         Contract::new(location, identifier, project, type_id, fields, scope).map(Self::Contract)
     }
 
+    ///
+    /// A helper type constructor, which allocates a unique sequence ID for the type.
+    ///
+    pub fn r#trait(location: Location, identifier: String, methods: Vec<FnPrototype>) -> Self {
+        let type_id = TYPE_INDEX.next(format!("trait {}", identifier));
+
+        Self::Trait(Trait::new(location, identifier, type_id, methods))
+    }
+
     ///
     /// Returns the type size in the virtual machine data stack.
     ///
@@ -326,9 +432,11 @@ impl Type {
                 .iter()
                 .map(|(_name, r#type)| r#type.size())
                 .sum(),
+            Self::Enumeration(inner) if inner.is_data() => 1 + inner.union_size(),
             Self::Enumeration(_inner) => 1,
             Self::Contract(_inner) => 1,
             Self::Function(_inner) => 0,
+            Self::Trait(_inner) => 0,
         }
     }
 
@@ -336,13 +444,15 @@ impl Type {
     /// Checks if the type is scalar (a primitive non-unit type).
     ///
     pub fn is_scalar(&self) -> bool {
-        matches!(self,
-            Self::Boolean(_) |
-            Self::IntegerUnsigned { .. } |
-            Self::IntegerSigned { .. } |
-            Self::Field(_) |
-            Self::Enumeration { .. }
-        )
+        match self {
+            Self::Enumeration(inner) => !inner.is_data(),
+            _ => matches!(self,
+                Self::Boolean(_) |
+                Self::IntegerUnsigned { .. } |
+                Self::IntegerSigned { .. } |
+                Self::Field(_)
+            ),
+        }
     }
 
     ///
@@ -350,12 +460,14 @@ impl Type {
     /// enumeration values).
     ///
     pub fn is_scalar_unsigned(&self) -> bool {
-        matches!(self,
-            Self::Boolean(_) |
-            Self::IntegerUnsigned { .. } |
-            Self::Field(_) |
-            Self::Enumeration { .. }
-        )
+        match self {
+            Self::Enumeration(inner) => !inner.is_data(),
+            _ => matches!(self,
+                Self::Boolean(_) |
+                Self::IntegerUnsigned { .. } |
+                Self::Field(_)
+            ),
+        }
     }
 
     ///
@@ -369,11 +481,10 @@ impl Type {
     /// Checks if the type is an unsigned integer one (unsigned integers, fields and enumeration values).
     ///
     pub fn is_integer_unsigned(&self) -> bool {
-        matches!(self,
-            Self::IntegerUnsigned { .. } |
-            Self::Field(_) |
-            Self::Enumeration { .. }
-        )
+        match self {
+            Self::Enumeration(inner) => !inner.is_data(),
+            _ => matches!(self, Self::IntegerUnsigned { .. } | Self::Field(_)),
+        }
     }
 
     ///
@@ -414,7 +525,8 @@ impl Type {
     pub fn is_source_function(&self) -> bool {
         matches!(self,
             Self::Function(Function::Runtime(_)) |
-            Self::Function(Function::Constant(_))
+            Self::Function(Function::Constant(_)) |
+            Self::Function(Function::Generic(_))
         )
     }
 
@@ -454,13 +566,22 @@ impl Type {
                         .map(|(_name, r#type)| r#type)
                         .all(|r#type| Self::is_instantiatable(r#type, false))
             }
-            Self::Enumeration(_) => true,
+            Self::Enumeration(inner) => {
+                !inner.is_generic_template()
+                    && inner
+                        .payloads
+                        .to_owned()
+                        .unwrap_or_default()
+                        .iter()
+                        .all(EnumerationPayload::is_instantiatable)
+            }
             Self::Function(_) => false,
             Self::Contract(inner) => inner
                 .fields
                 .iter()
                 .map(|field| &field.r#type)
                 .all(|r#type| Self::is_instantiatable(r#type, true)),
+            Self::Trait(_) => false,
         }
     }
 
@@ -487,6 +608,7 @@ impl Type {
     ) -> Result<(), Error> {
         match self {
             Self::Structure(inner) => inner.set_generics(location, generics),
+            Self::Enumeration(inner) => inner.set_generics(location, generics),
             ref r#type if generics.is_some() => Err(Error::TypeUnexpectedGenerics {
                 location: self.location().unwrap_or(location),
                 r#type: r#type.to_string(),
@@ -604,6 +726,11 @@ impl Type {
                 r#type.set_location(place.identifier.location);
                 r#type
             }
+            Element::Closure(closure) => {
+                return Err(Error::ClosureOutsideOfArgument {
+                    location: closure.location,
+                })
+            }
             _ => panic!(zinc_const::panic::VALIDATED_DURING_SYNTAX_ANALYSIS),
         })
     }
@@ -627,6 +754,7 @@ impl Type {
             Self::Enumeration(inner) => inner.location = Some(value),
             Self::Function(inner) => inner.set_location(value),
             Self::Contract(inner) => inner.location = value,
+            Self::Trait(inner) => inner.location = value,
         }
     }
 
@@ -649,6 +777,7 @@ impl Type {
             Self::Enumeration(inner) => inner.location,
             Self::Function(inner) => inner.location(),
             Self::Contract(inner) => Some(inner.location),
+            Self::Trait(inner) => Some(inner.location),
         }
     }
 }
@@ -679,6 +808,7 @@ impl PartialEq<Type> for Type {
             (Self::Structure(inner_1), Self::Structure(inner_2)) => inner_1 == inner_2,
             (Self::Enumeration(inner_1), Self::Enumeration(inner_2)) => inner_1 == inner_2,
             (Self::Contract(inner_1), Self::Contract(inner_2)) => inner_1 == inner_2,
+            (Self::Trait(inner_1), Self::Trait(inner_2)) => inner_1 == inner_2,
             _ => false,
         }
     }
@@ -701,6 +831,7 @@ impl fmt::Display for Type {
             Self::Enumeration(inner) => write!(f, "enumeration {}", inner),
             Self::Function(inner) => write!(f, "function {}", inner),
             Self::Contract(inner) => write!(f, "contract {}", inner),
+            Self::Trait(inner) => write!(f, "trait {}", inner),
         }
     }
 }
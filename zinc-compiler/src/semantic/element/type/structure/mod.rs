@@ -5,6 +5,8 @@
 #[cfg(test)]
 mod tests;
 
+pub mod template;
+
 use std::cell::RefCell;
 use std::collections::HashMap;
 use std::fmt;
@@ -16,6 +18,8 @@ use crate::semantic::element::r#type::Type;
 use crate::semantic::error::Error;
 use crate::semantic::scope::Scope;
 
+use self::template::Template;
+
 ///
 /// Describes a structure type.
 ///
@@ -25,6 +29,7 @@ use crate::semantic::scope::Scope;
 /// - data `fields`
 /// - the implementation `scope`, which contains the reference to its parent scope
 /// - the generic formal and actual arguments
+/// - the `template` if the structure is a user-defined generic one
 ///
 #[derive(Debug, Clone)]
 pub struct Structure {
@@ -43,6 +48,8 @@ pub struct Structure {
     pub params: Option<HashMap<String, Type>>,
     /// The structure scope, where its methods and associated items are declared.
     pub scope: Rc<RefCell<Scope>>,
+    /// The generic structure template, which is instantiated when the generic arguments are set.
+    pub template: Option<Rc<Template>>,
 }
 
 impl Structure {
@@ -66,6 +73,7 @@ impl Structure {
             generics,
             params,
             scope,
+            template: None,
         }
     }
 
@@ -88,6 +96,11 @@ impl Structure {
                     });
                 }
 
+                if let Some(template) = self.template.clone() {
+                    *self = template.instantiate(location, actual)?;
+                    return Ok(());
+                }
+
                 let mut params = HashMap::with_capacity(actual.len());
                 for (name, r#type) in formal.iter().zip(actual.into_iter()) {
                     params.insert(name.to_owned(), r#type);
@@ -96,6 +109,7 @@ impl Structure {
 
                 Ok(())
             }
+            (Some(_names), None) if self.params.is_some() => Ok(()),
             (Some(names), None) => Err(Error::TypeExpectedGenerics {
                 location,
                 r#type: self.identifier.to_owned(),
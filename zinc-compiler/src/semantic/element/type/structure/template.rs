@@ -0,0 +1,122 @@
+//!
+//! The semantic analyzer generic structure template.
+//!
+
+use std::cell::Cell;
+use std::cell::RefCell;
+use std::fmt;
+use std::rc::Rc;
+
+use zinc_lexical::Location;
+use zinc_syntax::StructStatement;
+
+use crate::semantic::analyzer::statement::r#struct::Analyzer as StructStatementAnalyzer;
+use crate::semantic::element::r#type::inference::Inference;
+use crate::semantic::element::r#type::r#trait::Trait;
+use crate::semantic::element::r#type::structure::Structure;
+use crate::semantic::element::r#type::Type;
+use crate::semantic::error::Error;
+use crate::semantic::scope::Scope;
+
+///
+/// The generic structure definition, which is monomorphized for each set of generic arguments.
+///
+/// The instances are cached, so the same arguments always produce the same structure type.
+///
+pub struct Template {
+    /// The structure statement syntax representation.
+    pub statement: StructStatement,
+    /// The structure scope, which is the parent of the instance scopes.
+    pub scope: Rc<RefCell<Scope>>,
+    /// The instances created so far with their generic arguments.
+    pub instances: RefCell<Vec<(Vec<Type>, Structure)>>,
+    /// Whether an instance is being analyzed, which is used to detect reference loops.
+    pub is_instantiating: Cell<bool>,
+}
+
+impl Template {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(statement: StructStatement, scope: Rc<RefCell<Scope>>) -> Self {
+        Self {
+            statement,
+            scope,
+            instances: RefCell::new(vec![]),
+            is_instantiating: Cell::new(false),
+        }
+    }
+
+    ///
+    /// Returns the structure instance for the generic `arguments`, creating it if necessary.
+    ///
+    /// The arguments are checked against the generic parameter trait bounds before a new
+    /// instance is created.
+    ///
+    pub fn instantiate(
+        &self,
+        location: Location,
+        arguments: Vec<Type>,
+    ) -> Result<Structure, Error> {
+        if let Some((_arguments, structure)) = self
+            .instances
+            .borrow()
+            .iter()
+            .find(|(instance_arguments, _structure)| instance_arguments == &arguments)
+        {
+            return Ok(structure.to_owned());
+        }
+
+        Trait::check_bounds(
+            location,
+            self.scope.clone(),
+            self.statement.generics.as_deref().unwrap_or_default(),
+            arguments.as_slice(),
+        )?;
+
+        if self.is_instantiating.replace(true) {
+            return Err(Error::ScopeReferenceLoop { location });
+        }
+        let result = StructStatementAnalyzer::instantiate(
+            self.scope.clone(),
+            self.statement.clone(),
+            arguments.clone(),
+        );
+        self.is_instantiating.set(false);
+
+        let structure = result?;
+        self.instances
+            .borrow_mut()
+            .push((arguments, structure.clone()));
+        Ok(structure)
+    }
+
+    ///
+    /// Infers the generic arguments from the structure literal `fields` and returns the instance.
+    ///
+    pub fn infer(&self, location: Location, fields: &[(String, Type)]) -> Result<Structure, Error> {
+        let mut inference = Inference::new(self.statement.generics.to_owned().unwrap_or_default());
+        for field in self.statement.fields.iter() {
+            if let Some((_name, r#type)) = fields
+                .iter()
+                .find(|(name, _type)| name == &field.identifier.name)
+            {
+                inference.infer(&field.r#type, r#type);
+            }
+        }
+        let arguments = inference.finish(location, self.statement.identifier.name.as_str())?;
+
+        self.instantiate(location, arguments)
+    }
+}
+
+impl fmt::Debug for Template {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "template {} with {} instances",
+            self.statement.identifier.name,
+            self.instances.borrow().len()
+        )
+    }
+}
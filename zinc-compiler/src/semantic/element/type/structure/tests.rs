@@ -100,3 +100,96 @@ contract Test {
 
     assert_eq!(result, expected);
 }
+
+#[test]
+fn ok_generic() {
+    let input = r#"
+struct Pair<T> {
+    a: T,
+    b: T,
+}
+
+fn main() -> u16 {
+    let inferred = Pair { a: 1 as u8, b: 2 };
+    let annotated: Pair<u16> = Pair { a: 3 as u16, b: 4 as u16 };
+    inferred.a as u16 + annotated.b
+}
+"#;
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert!(result.is_ok());
+}
+
+#[test]
+fn error_generic_invalid_field_type() {
+    let input = r#"
+struct Pair<T> {
+    a: T,
+    b: T,
+}
+
+fn main() {
+    let pair = Pair { a: 1 as u8, b: true };
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::StructureFieldInvalidType {
+        location: Location::test(8, 35),
+        r#type: "Pair<u8>".to_owned(),
+        field_name: "b".to_owned(),
+        expected: "u8".to_owned(),
+        found: "bool".to_owned(),
+    }));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_generic_invalid_generics_number() {
+    let input = r#"
+struct Pair<T> {
+    a: T,
+    b: T,
+}
+
+fn main() {
+    let pair: Pair<u8, u8> = Pair { a: 1, b: 2 };
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::TypeInvalidGenericsNumber {
+        location: Location::test(8, 15),
+        r#type: "Pair".to_owned(),
+        expected: 1,
+        found: 2,
+    }));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_generic_reference_loop() {
+    let input = r#"
+struct List<T> {
+    value: T,
+    next: List<T>,
+}
+
+fn main() {
+    let list = List { value: 1 as u8 };
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::ScopeReferenceLoop {
+        location: Location::test(4, 11),
+    }));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
@@ -0,0 +1,130 @@
+//!
+//! The semantic analyzer trait type element.
+//!
+
+#[cfg(test)]
+mod tests;
+
+use std::cell::RefCell;
+use std::fmt;
+use std::rc::Rc;
+
+use zinc_lexical::Location;
+use zinc_syntax::FnPrototype;
+use zinc_syntax::GenericParameter;
+
+use crate::semantic::element::r#type::Type;
+use crate::semantic::error::Error;
+use crate::semantic::scope::item::Item as ScopeItem;
+use crate::semantic::scope::Scope;
+
+///
+/// Describes a trait type.
+///
+/// The trait methods are only checked against the implementations, since all the calls are
+/// dispatched statically to the implementing type methods.
+///
+#[derive(Debug, Clone)]
+pub struct Trait {
+    /// The trait type location in the code.
+    pub location: Location,
+    /// The trait type identifier.
+    pub identifier: String,
+    /// The unique trait type ID.
+    pub type_id: usize,
+    /// The trait method prototypes.
+    pub methods: Vec<FnPrototype>,
+}
+
+impl Trait {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(
+        location: Location,
+        identifier: String,
+        type_id: usize,
+        methods: Vec<FnPrototype>,
+    ) -> Self {
+        Self {
+            location,
+            identifier,
+            type_id,
+            methods,
+        }
+    }
+
+    ///
+    /// Checks whether the trait is implemented for the `type`.
+    ///
+    /// Only structures and enumerations can implement traits.
+    ///
+    pub fn is_implemented_for(&self, r#type: &Type) -> Result<bool, Error> {
+        let scope = match r#type {
+            Type::Structure(inner) => inner.scope.to_owned(),
+            Type::Enumeration(inner) => inner.scope.to_owned(),
+            _ => return Ok(false),
+        };
+
+        Scope::implements_trait(scope, self.type_id)
+    }
+
+    ///
+    /// Checks whether the generic `arguments` implement the traits required by the `parameters`
+    /// bounds, which are resolved in the `scope` of the generic item.
+    ///
+    /// The `location` is the call site or structure literal location, which is used for
+    /// error reporting.
+    ///
+    pub fn check_bounds(
+        location: Location,
+        scope: Rc<RefCell<Scope>>,
+        parameters: &[GenericParameter],
+        arguments: &[Type],
+    ) -> Result<(), Error> {
+        for (parameter, argument) in parameters.iter().zip(arguments.iter()) {
+            for bound in parameter.bounds.iter() {
+                let item = RefCell::borrow(&scope).resolve_item(bound, true)?;
+                let r#trait = match *RefCell::borrow(&item) {
+                    ScopeItem::Type(ref r#type) => match r#type.define()? {
+                        Type::Trait(inner) => inner,
+                        r#type => {
+                            return Err(Error::TypeExpectedTrait {
+                                location: bound.location,
+                                found: r#type.to_string(),
+                            })
+                        }
+                    },
+                    ref item => {
+                        return Err(Error::TypeExpectedTrait {
+                            location: bound.location,
+                            found: item.to_string(),
+                        })
+                    }
+                };
+
+                if !r#trait.is_implemented_for(argument)? {
+                    return Err(Error::TypeTraitNotImplemented {
+                        location,
+                        r#type: argument.to_string(),
+                        r#trait: r#trait.identifier,
+                    });
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl PartialEq<Self> for Trait {
+    fn eq(&self, other: &Self) -> bool {
+        self.type_id == other.type_id
+    }
+}
+
+impl fmt::Display for Trait {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.identifier)
+    }
+}
@@ -0,0 +1,251 @@
+//!
+//! The trait tests.
+//!
+
+use zinc_lexical::Location;
+
+use crate::error::Error;
+use crate::semantic::error::Error as SemanticError;
+
+#[test]
+fn ok_static_dispatch() {
+    let input = r#"
+trait Shape {
+    fn area(self) -> u8;
+}
+
+struct Square {
+    side: u8,
+}
+
+impl Shape for Square {
+    fn area(self) -> u8 {
+        self.side * self.side
+    }
+}
+
+fn main() -> u8 {
+    let square = Square { side: 4 };
+    square.area()
+}
+"#;
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert!(result.is_ok());
+}
+
+#[test]
+fn ok_generic_bound() {
+    let input = r#"
+trait Shape {
+    fn area(self) -> u8;
+    fn scale(mut self, factor: u8);
+}
+
+struct Square {
+    side: u8,
+}
+
+impl Shape for Square {
+    fn area(self) -> u8 {
+        self.side * self.side
+    }
+
+    fn scale(mut self, factor: u8) {
+        self.side *= factor;
+    }
+}
+
+struct Boxed<T: Shape> {
+    inner: T,
+}
+
+fn area<T: Shape>(value: T) -> u8 {
+    value.area()
+}
+
+fn main() -> u8 {
+    let boxed = Boxed { inner: Square { side: 4 } };
+    area(boxed.inner)
+}
+"#;
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert!(result.is_ok());
+}
+
+#[test]
+fn error_impl_expected_trait() {
+    let input = r#"
+struct Square {
+    side: u8,
+}
+
+struct Circle {
+    radius: u64,
+}
+
+impl Square for Circle {}
+
+fn main() {}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::ImplStatementExpectedTrait {
+        location: Location::test(10, 6),
+        found: "structure Square".to_owned(),
+    }));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_impl_method_missing() {
+    let input = r#"
+trait Shape {
+    fn area(self) -> u8;
+}
+
+struct Square {
+    side: u8,
+}
+
+impl Shape for Square {}
+
+fn main() {}
+"#;
+
+    let expected = Err(Error::Semantic(
+        SemanticError::ImplStatementTraitMethodMissing {
+            location: Location::test(10, 6),
+            r#trait: "Shape".to_owned(),
+            method: "area".to_owned(),
+        },
+    ));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_impl_method_unexpected() {
+    let input = r#"
+trait Shape {}
+
+struct Square {
+    side: u8,
+}
+
+impl Shape for Square {
+    fn area(self) -> u8 {
+        self.side * self.side
+    }
+}
+
+fn main() {}
+"#;
+
+    let expected = Err(Error::Semantic(
+        SemanticError::ImplStatementTraitMethodUnexpected {
+            location: Location::test(9, 8),
+            r#trait: "Shape".to_owned(),
+            method: "area".to_owned(),
+        },
+    ));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_impl_method_signature() {
+    let input = r#"
+trait Shape {
+    fn area(self) -> u8;
+}
+
+struct Square {
+    side: u8,
+}
+
+impl Shape for Square {
+    fn area(self) -> bool {
+        false
+    }
+}
+
+fn main() {}
+"#;
+
+    let expected = Err(Error::Semantic(
+        SemanticError::ImplStatementTraitMethodSignature {
+            location: Location::test(11, 8),
+            r#trait: "Shape".to_owned(),
+            method: "area".to_owned(),
+            expected: "fn area(self: structure Square) -> u8".to_owned(),
+            found: "fn area(self: structure Square) -> bool".to_owned(),
+        },
+    ));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_bound_expected_trait() {
+    let input = r#"
+struct Square {
+    side: u8,
+}
+
+fn area<T: Square>(value: T) -> u8 {
+    0
+}
+
+fn main() -> u8 {
+    area(Square { side: 4 })
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::TypeExpectedTrait {
+        location: Location::test(6, 12),
+        found: "structure Square".to_owned(),
+    }));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_bound_not_implemented() {
+    let input = r#"
+trait Shape {
+    fn area(self) -> u8;
+}
+
+fn area<T: Shape>(value: T) -> u8 {
+    value.area()
+}
+
+fn main() -> u8 {
+    area(42 as u64)
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::TypeTraitNotImplemented {
+        location: Location::test(11, 9),
+        r#type: "u64".to_owned(),
+        r#trait: "Shape".to_owned(),
+    }));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
@@ -0,0 +1,361 @@
+//!
+//! The semantic analyzer enumeration value element.
+//!
+
+#[cfg(test)]
+mod tests;
+
+use std::fmt;
+
+use num::BigInt;
+use num::Zero;
+
+use zinc_lexical::Location;
+use zinc_syntax::VariantPayload;
+
+use crate::generator::expression::operand::constant::integer::Integer as GeneratorIntegerConstant;
+use crate::generator::expression::operand::constant::Constant as GeneratorConstant;
+use crate::semantic::element::r#type::enumeration::payload::Payload;
+use crate::semantic::element::r#type::enumeration::Enumeration as EnumerationType;
+use crate::semantic::element::r#type::i_typed::ITyped;
+use crate::semantic::element::r#type::Type;
+use crate::semantic::error::Error;
+
+///
+/// The data-carrying enumeration runtime value.
+///
+/// The value is laid out as the variant tag followed by the payload and zero padding up to the
+/// enumeration union size.
+///
+/// If the generic arguments cannot be inferred from the payload, e.g. for `Option::None`,
+/// the value type is the generic enumeration itself, and the value is incomplete until it is
+/// coerced to an instance, e.g. with a `let` type annotation.
+///
+#[derive(Debug, Clone, PartialEq)]
+pub struct Enumeration {
+    /// The value location in the code.
+    pub location: Option<Location>,
+    /// The value type.
+    pub r#type: EnumerationType,
+    /// The variant index, which is only known for values created with a variant constructor.
+    pub variant: Option<usize>,
+    /// The payload element types, which are used to coerce an incomplete value.
+    pub payload: Vec<Type>,
+}
+
+impl Enumeration {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(location: Option<Location>, r#type: EnumerationType) -> Self {
+        Self {
+            location,
+            r#type,
+            variant: None,
+            payload: vec![],
+        }
+    }
+
+    ///
+    /// Validates the variant `payload` and creates a value of the variant at `index`.
+    ///
+    /// Returns the value and the padding, which must be written after the payload to fill
+    /// the enumeration union. If the generic arguments of the enumeration cannot be inferred
+    /// from the payload, the value is incomplete, and the padding is written when the value is
+    /// coerced to an instance.
+    ///
+    pub fn try_from_variant(
+        location: Location,
+        mut r#type: EnumerationType,
+        index: usize,
+        payload: Payload,
+    ) -> Result<(Self, GeneratorConstant), Error> {
+        let variant = format!(
+            "{}::{}",
+            r#type.identifier,
+            r#type
+                .names
+                .get(index)
+                .expect(zinc_const::panic::VALIDATED_DURING_SEMANTIC_ANALYSIS)
+        );
+
+        if let Some(template) = r#type.template.clone() {
+            if r#type.is_generic_template() {
+                match template.infer(location, index, payload.types().as_slice())? {
+                    Some(instance) => r#type = instance,
+                    None => {
+                        let declared = template
+                            .statement
+                            .variants
+                            .get(index)
+                            .and_then(|variant| variant.payload.as_ref());
+                        Self::validate_shape(location, variant.as_str(), declared, &payload)?;
+
+                        let value = Self {
+                            location: Some(location),
+                            r#type,
+                            variant: Some(index),
+                            payload: payload.types(),
+                        };
+                        return Ok((value, GeneratorConstant::Group(vec![])));
+                    }
+                }
+            }
+        }
+
+        let expected = r#type
+            .payload(index)
+            .cloned()
+            .expect(zinc_const::panic::VALIDATED_DURING_SEMANTIC_ANALYSIS);
+        match (&expected, &payload) {
+            (Payload::Unit, Payload::Unit) => {}
+            (Payload::Tuple(expected), Payload::Tuple(found)) => {
+                if expected.len() != found.len() {
+                    return Err(Error::TypeVariantPayloadCount {
+                        location,
+                        variant,
+                        expected: expected.len(),
+                        found: found.len(),
+                    });
+                }
+
+                for (position, (expected, found)) in expected.iter().zip(found.iter()).enumerate()
+                {
+                    if expected != found {
+                        return Err(Error::TypeVariantPayloadInvalidType {
+                            location,
+                            variant,
+                            position: position + 1,
+                            expected: expected.to_string(),
+                            found: found.to_string(),
+                        });
+                    }
+                }
+            }
+            (Payload::Structure(expected), Payload::Structure(found)) => {
+                for (position, (expected_name, expected_type)) in expected.iter().enumerate() {
+                    match found.get(position) {
+                        Some((name, _type)) if name != expected_name => {
+                            return Err(Error::StructureFieldExpected {
+                                location,
+                                r#type: variant,
+                                position: position + 1,
+                                expected: expected_name.to_owned(),
+                                found: name.to_owned(),
+                            });
+                        }
+                        Some((_name, r#type)) if r#type != expected_type => {
+                            return Err(Error::StructureFieldInvalidType {
+                                location,
+                                r#type: variant,
+                                field_name: expected_name.to_owned(),
+                                expected: expected_type.to_string(),
+                                found: r#type.to_string(),
+                            });
+                        }
+                        Some(_) => {}
+                        None => {
+                            return Err(Error::StructureFieldCount {
+                                location,
+                                r#type: variant,
+                                expected: expected.len(),
+                                found: found.len(),
+                            });
+                        }
+                    }
+                }
+
+                if found.len() > expected.len() {
+                    return Err(Error::StructureFieldCount {
+                        location,
+                        r#type: variant,
+                        expected: expected.len(),
+                        found: found.len(),
+                    });
+                }
+            }
+            (expected, found) => {
+                return Err(Error::TypeVariantPayloadMismatch {
+                    location,
+                    variant,
+                    expected: Self::describe(expected),
+                    found: Self::describe(found),
+                });
+            }
+        }
+
+        let padding = Self::padding(r#type.union_size() - expected.size());
+        let value = Self {
+            location: Some(location),
+            r#type,
+            variant: Some(index),
+            payload: expected.types(),
+        };
+        Ok((value, padding))
+    }
+
+    ///
+    /// Whether the value type is an enumeration instance, that is, the generic arguments
+    /// have been inferred.
+    ///
+    pub fn is_complete(&self) -> bool {
+        !self.r#type.is_generic_template()
+    }
+
+    ///
+    /// Coerces the incomplete value to the `expected` instance of its generic enumeration.
+    ///
+    /// Returns the padding, which must be written after the value, or `None` if the value
+    /// cannot be coerced.
+    ///
+    pub fn coerce(&mut self, expected: &Type) -> Option<GeneratorConstant> {
+        if self.is_complete() {
+            return None;
+        }
+
+        let expected = match expected {
+            Type::Enumeration(expected)
+                if !expected.is_generic_template() && expected.is_same_family(&self.r#type) =>
+            {
+                expected
+            }
+            _ => return None,
+        };
+
+        let payload = expected.payload(self.variant?)?;
+        if payload.types() != self.payload {
+            return None;
+        }
+
+        let padding = Self::padding(expected.union_size() - payload.size());
+        self.r#type = expected.to_owned();
+        Some(padding)
+    }
+
+    ///
+    /// Infers the enumeration instance from the payloads of two incomplete values of the same
+    /// generic enumeration, e.g. `Result::Ok(42)` and `Result::Err(false)`.
+    ///
+    /// Returns `None` if the instance cannot be inferred from the values.
+    ///
+    pub fn unify(&self, other: &Self, location: Location) -> Result<Option<Type>, Error> {
+        if self.is_complete() || other.is_complete() || !self.r#type.is_same_family(&other.r#type)
+        {
+            return Ok(None);
+        }
+
+        let (template, first, second) =
+            match (self.r#type.template.as_ref(), self.variant, other.variant) {
+                (Some(template), Some(first), Some(second)) => (template, first, second),
+                _ => return Ok(None),
+            };
+
+        template
+            .infer_variants(
+                location,
+                &[
+                    (first, self.payload.as_slice()),
+                    (second, other.payload.as_slice()),
+                ],
+            )
+            .map(|instance| instance.map(Type::Enumeration))
+    }
+
+    ///
+    /// Checks if the generic arguments of the value type have been inferred.
+    ///
+    pub fn validate_complete(&self, location: Location) -> Result<(), Error> {
+        if self.is_complete() {
+            return Ok(());
+        }
+
+        Err(Error::TypeGenericArgumentNotInferred {
+            location: self.location.unwrap_or(location),
+            r#type: self.r#type.identifier.to_owned(),
+            name: self.r#type.generics.first().cloned().unwrap_or_default(),
+        })
+    }
+
+    ///
+    /// Returns the zero padding of `size` field elements.
+    ///
+    pub fn padding(size: usize) -> GeneratorConstant {
+        GeneratorConstant::Group(vec![
+            GeneratorConstant::Integer(GeneratorIntegerConstant::new(
+                BigInt::zero(),
+                false,
+                zinc_const::bitlength::FIELD,
+            ));
+            size
+        ])
+    }
+
+    ///
+    /// Validates the payload kind and length of a generic enumeration variant, whose
+    /// element types are not known yet.
+    ///
+    fn validate_shape(
+        location: Location,
+        variant: &str,
+        declared: Option<&VariantPayload>,
+        payload: &Payload,
+    ) -> Result<(), Error> {
+        let (expected, length) = match declared {
+            None => ("no payload", 0),
+            Some(VariantPayload::Tuple(types)) => ("a tuple payload", types.len()),
+            Some(VariantPayload::Structure(fields)) => ("a structure payload", fields.len()),
+        };
+
+        let found = Self::describe(payload);
+        if expected != found {
+            return Err(Error::TypeVariantPayloadMismatch {
+                location,
+                variant: variant.to_owned(),
+                expected: expected.to_owned(),
+                found,
+            });
+        }
+
+        if length != payload.types().len() {
+            return Err(Error::TypeVariantPayloadCount {
+                location,
+                variant: variant.to_owned(),
+                expected: length,
+                found: payload.types().len(),
+            });
+        }
+
+        Ok(())
+    }
+
+    ///
+    /// Describes the payload kind for error messages.
+    ///
+    fn describe(payload: &Payload) -> String {
+        match payload {
+            Payload::Unit => "no payload",
+            Payload::Tuple(_) => "a tuple payload",
+            Payload::Structure(_) => "a structure payload",
+        }
+        .to_owned()
+    }
+}
+
+impl ITyped for Enumeration {
+    fn r#type(&self) -> Type {
+        Type::Enumeration(self.r#type.to_owned())
+    }
+
+    fn has_the_same_type_as(&self, other: &Self) -> bool {
+        self.r#type == other.r#type
+    }
+}
+
+impl fmt::Display for Enumeration {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.variant.and_then(|index| self.r#type.names.get(index)) {
+            Some(name) => write!(f, "<runtime> {}::{}", self.r#type, name),
+            None => write!(f, "<runtime> {}", self.r#type),
+        }
+    }
+}
@@ -0,0 +1,223 @@
+//!
+//! The enumeration value element tests.
+//!
+
+use zinc_lexical::Location;
+
+use crate::error::Error;
+use crate::semantic::error::Error as SemanticError;
+
+#[test]
+fn ok_tuple_variant() {
+    let input = r#"
+enum Shape {
+    Empty,
+    Rectangle(u8, u8),
+}
+
+fn main() -> u8 {
+    let shape = Shape::Rectangle(4, 6);
+    match shape {
+        Shape::Rectangle(width, height) => width * height,
+        Shape::Empty => 0,
+    }
+}
+"#;
+
+    assert!(crate::semantic::tests::compile_entry(input).is_ok());
+}
+
+#[test]
+fn ok_structure_variant() {
+    let input = r#"
+enum Shape {
+    Empty,
+    Circle { radius: u8 },
+}
+
+fn main() -> u8 {
+    let shape = Shape::Circle { radius: 5 };
+    match shape {
+        Shape::Circle { radius } => radius * radius * 3,
+        Shape::Empty => 0,
+    }
+}
+"#;
+
+    assert!(crate::semantic::tests::compile_entry(input).is_ok());
+}
+
+#[test]
+fn ok_option_coerced_by_let() {
+    let input = r#"
+use std::option::Option;
+
+fn main() -> u8 {
+    let value: Option<u8> = Option::None;
+    match value {
+        Option::Some(inner) => inner,
+        Option::None => 0,
+    }
+}
+"#;
+
+    assert!(crate::semantic::tests::compile_entry(input).is_ok());
+}
+
+#[test]
+fn ok_result_coerced_by_return_type() {
+    let input = r#"
+use std::result::Result;
+
+fn check(value: u8) -> Result<u8, bool> {
+    if value > 10 {
+        return Result::Err(false);
+    }
+
+    Result::Ok(value)
+}
+
+fn main() -> u8 {
+    match check(5) {
+        Result::Ok(value) => value,
+        Result::Err(_) => 0,
+    }
+}
+"#;
+
+    assert!(crate::semantic::tests::compile_entry(input).is_ok());
+}
+
+#[test]
+fn ok_result_inferred_from_branches() {
+    let input = r#"
+use std::result::Result;
+
+fn main(value: u8) -> u8 {
+    let result = if value > 10 { Result::Err(false) } else { Result::Ok(value) };
+    match result {
+        Result::Ok(inner) => inner,
+        Result::Err(_) => 0,
+    }
+}
+"#;
+
+    assert!(crate::semantic::tests::compile_entry(input).is_ok());
+}
+
+#[test]
+fn error_generic_argument_not_inferred() {
+    let input = r#"
+use std::option::Option;
+
+fn main() {
+    let value = Option::None;
+}
+"#;
+
+    let expected = Err(Error::Semantic(
+        SemanticError::TypeGenericArgumentNotInferred {
+            location: Location::test(5, 17),
+            r#type: "Option".to_owned(),
+            name: "T".to_owned(),
+        },
+    ));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_payload_mismatch() {
+    let input = r#"
+enum Shape {
+    Empty,
+    Rectangle(u8, u8),
+}
+
+fn main() {
+    let shape = Shape::Empty(42);
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::TypeVariantPayloadMismatch {
+        location: Location::test(8, 29),
+        variant: "Shape::Empty".to_owned(),
+        expected: "no payload".to_owned(),
+        found: "a tuple payload".to_owned(),
+    }));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_payload_count() {
+    let input = r#"
+enum Shape {
+    Empty,
+    Rectangle(u8, u8),
+}
+
+fn main() {
+    let shape = Shape::Rectangle(4);
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::TypeVariantPayloadCount {
+        location: Location::test(8, 33),
+        variant: "Shape::Rectangle".to_owned(),
+        expected: 2,
+        found: 1,
+    }));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_payload_invalid_type() {
+    let input = r#"
+enum Shape {
+    Empty,
+    Rectangle(u8, u8),
+}
+
+fn main() {
+    let shape = Shape::Rectangle(4, true);
+}
+"#;
+
+    let expected = Err(Error::Semantic(
+        SemanticError::TypeVariantPayloadInvalidType {
+            location: Location::test(8, 33),
+            variant: "Shape::Rectangle".to_owned(),
+            position: 2,
+            expected: "u8".to_owned(),
+            found: "bool".to_owned(),
+        },
+    ));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_casting_to_integer() {
+    let input = r#"
+enum Shape {
+    Empty,
+    Rectangle(u8, u8),
+}
+
+fn main() -> u8 {
+    Shape::Empty as u8
+}
+"#;
+
+    assert!(crate::semantic::tests::compile_entry(input).is_err());
+}
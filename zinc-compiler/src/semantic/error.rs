@@ -1417,6 +1417,66 @@ pub enum Error {
         /// The number of found generics.
         found: usize,
     },
+    /// The generic argument cannot be inferred from the function arguments or structure fields.
+    TypeGenericArgumentNotInferred {
+        /// The call or structure literal location.
+        location: Location,
+        /// The generic item name.
+        r#type: String,
+        /// The generic parameter name.
+        name: String,
+    },
+    /// The generic parameter bound is not a trait.
+    TypeExpectedTrait {
+        /// The bound location in the code.
+        location: Location,
+        /// The stringified invalid item.
+        found: String,
+    },
+    /// The generic argument does not implement the trait required by the parameter bound.
+    TypeTraitNotImplemented {
+        /// The call or structure literal location.
+        location: Location,
+        /// The generic argument type.
+        r#type: String,
+        /// The required trait identifier.
+        r#trait: String,
+    },
+    /// The enumeration variant is constructed or matched with a different payload kind.
+    TypeVariantPayloadMismatch {
+        /// The constructor or pattern location.
+        location: Location,
+        /// The variant path, e.g. `Option::Some`.
+        variant: String,
+        /// The stringified declared payload.
+        expected: String,
+        /// The stringified provided payload.
+        found: String,
+    },
+    /// The number of the variant payload elements is different from the declared one.
+    TypeVariantPayloadCount {
+        /// The constructor or pattern location.
+        location: Location,
+        /// The variant path, e.g. `Option::Some`.
+        variant: String,
+        /// The declared number of the payload elements.
+        expected: usize,
+        /// The provided number of the payload elements.
+        found: usize,
+    },
+    /// The variant payload element type is different from the declared one.
+    TypeVariantPayloadInvalidType {
+        /// The payload element location.
+        location: Location,
+        /// The variant path, e.g. `Option::Some`.
+        variant: String,
+        /// The payload element position, starting from 1.
+        position: usize,
+        /// The declared payload element type.
+        expected: String,
+        /// The provided payload element type.
+        found: String,
+    },
 
     /// The actual arguments number does not match the formal arguments number.
     FunctionArgumentCount {
@@ -1440,6 +1500,15 @@ pub enum Error {
         /// The actual number of arguments.
         found: usize,
     },
+    /// The actual arguments number does not match the `require` message placeholders number.
+    FunctionRequireArgumentCount {
+        /// The error location data.
+        location: Location,
+        /// The expected number of arguments.
+        expected: usize,
+        /// The actual number of arguments.
+        found: usize,
+    },
     /// The argument type does not match the expected one.
     FunctionArgumentType {
         /// The error location data.
@@ -1520,6 +1589,13 @@ pub enum Error {
         /// The function identifier.
         function: &'static str,
     },
+    /// Only runtime functions, which are not contract entries, can have generic parameters.
+    FunctionGenericsForbidden {
+        /// The function location.
+        location: Location,
+        /// The function identifier.
+        function: String,
+    },
     /// An array is tried to be truncated to a bigger size. The `pad` function must be used instead.
     FunctionStdlibArrayTruncatingToBiggerSize {
         /// The error location data.
@@ -1546,6 +1622,41 @@ pub enum Error {
         value: String,
     },
 
+    /// The closure is used somewhere else than as a function argument.
+    ClosureOutsideOfArgument {
+        /// The error location data.
+        location: Location,
+    },
+    /// The closure parameter count does not match the one expected by the called function.
+    ClosureParameterCount {
+        /// The error location data.
+        location: Location,
+        /// The number of parameters passed by the called function.
+        expected: usize,
+        /// The number of the closure parameters.
+        found: usize,
+    },
+    /// The closure parameter type annotation does not match the type of the passed value.
+    ClosureParameterType {
+        /// The error location data.
+        location: Location,
+        /// The position of the invalid parameter.
+        position: usize,
+        /// The stringified type passed by the called function.
+        expected: String,
+        /// The stringified type annotation.
+        found: String,
+    },
+    /// The closure returns a value of type unexpected by the called function.
+    ClosureReturnType {
+        /// The error location data.
+        location: Location,
+        /// The stringified expected return type.
+        expected: String,
+        /// The stringified actual return type.
+        found: String,
+    },
+
     /// The unit test function cannot be called.
     UnitTestCallForbidden {
         /// The error location data.
@@ -1661,7 +1772,7 @@ pub enum Error {
         reference: Location,
     },
 
-    /// Only primitive types can act as scrutinee types (be matched) for now.
+    /// Only scalar, enumeration, tuple and structure types can act as scrutinee types (be matched).
     MatchScrutineeInvalidType {
         /// The error location data.
         location: Location,
@@ -1720,6 +1831,29 @@ pub enum Error {
         /// The first branch location, which helps user to find the error.
         reference: Location,
     },
+    /// The `match` branch guard is not of boolean type.
+    MatchBranchGuardExpectedBoolean {
+        /// The error location data.
+        location: Location,
+        /// The invalid type, which is actually found.
+        found: String,
+    },
+    /// The range pattern start is greater than its inclusive end.
+    MatchBranchPatternRangeInvalid {
+        /// The error location data.
+        location: Location,
+        /// The range start value.
+        start: String,
+        /// The range inclusive end value.
+        end: String,
+    },
+    /// The alternative patterns of a branch do not bind the same variables with the same types.
+    MatchBranchAlternativeBindingMismatch {
+        /// The error location data.
+        location: Location,
+        /// The variable name, which is bound inconsistently.
+        name: String,
+    },
 
     /// The `while` condition is not of boolean type.
     ForStatementWhileExpectedBooleanCondition {
@@ -1735,6 +1869,35 @@ pub enum Error {
         /// The stringified invalid bounds element.
         found: String,
     },
+    /// The `while` loop condition is not of boolean type.
+    WhileStatementExpectedBooleanCondition {
+        /// The condition expression location.
+        location: Location,
+        /// The stringified invalid condition type.
+        found: String,
+    },
+    /// The `while` loop iterations bound is not a constant integer expression.
+    WhileStatementBoundExpectedConstantInteger {
+        /// The loop bound expression location.
+        location: Location,
+        /// The stringified invalid bound element.
+        found: String,
+    },
+    /// The `break` statement is used beyond a loop.
+    BreakStatementBeyondLoop {
+        /// The error location data.
+        location: Location,
+    },
+    /// The `continue` statement is used beyond a loop.
+    ContinueStatementBeyondLoop {
+        /// The error location data.
+        location: Location,
+    },
+    /// The `return` statement is used beyond a runtime function, e.g. in a constant function.
+    ReturnStatementBeyondFunction {
+        /// The error location data.
+        location: Location,
+    },
 
     /// Only structure or enumeration types can have an implementation, but another type was found.
     ImplStatementExpectedStructureOrEnumeration {
@@ -1743,6 +1906,51 @@ pub enum Error {
         /// The invalid type identifier.
         found: String,
     },
+    /// The implementation of generic structures is not supported yet.
+    ImplStatementGenericType {
+        /// The generic type location in the code.
+        location: Location,
+        /// The generic type identifier.
+        found: String,
+    },
+    /// The item before the `for` keyword is not a trait.
+    ImplStatementExpectedTrait {
+        /// The invalid item location in the code.
+        location: Location,
+        /// The stringified invalid item.
+        found: String,
+    },
+    /// The trait implementation does not implement a trait method.
+    ImplStatementTraitMethodMissing {
+        /// The trait identifier location in the `impl` statement.
+        location: Location,
+        /// The trait identifier.
+        r#trait: String,
+        /// The missing method identifier.
+        method: String,
+    },
+    /// The trait implementation declares a method, which is not a member of the trait.
+    ImplStatementTraitMethodUnexpected {
+        /// The unexpected method location in the code.
+        location: Location,
+        /// The trait identifier.
+        r#trait: String,
+        /// The unexpected method identifier.
+        method: String,
+    },
+    /// The trait method implementation signature does not match the trait method prototype.
+    ImplStatementTraitMethodSignature {
+        /// The method implementation location in the code.
+        location: Location,
+        /// The trait identifier.
+        r#trait: String,
+        /// The method identifier.
+        method: String,
+        /// The stringified signature declared in the trait.
+        expected: String,
+        /// The stringified signature found in the implementation.
+        found: String,
+    },
 
     /// The element after the `use` keyword must be a path to an item.
     UseStatementExpectedPath {
@@ -1863,9 +2071,57 @@ pub enum Error {
         /// The module name, source code for which is absent.
         name: String,
     },
+
+    /// Several errors collected from the items, which have been analyzed independently.
+    ///
+    /// The empty list means that the errors have been already reported elsewhere.
+    Multiple(Vec<Self>),
 }
 
 impl Error {
+    ///
+    /// A shortcut constructor.
+    ///
+    /// Is returned by items which have failed to be defined, and whose errors have been
+    /// already reported.
+    ///
+    pub fn reported() -> Self {
+        Self::Multiple(vec![])
+    }
+
+    ///
+    /// Pushes the `error` to the `errors` list, flattening the nested lists.
+    ///
+    /// The duplicates are skipped, as the same error may be propagated through several items.
+    ///
+    pub fn push(errors: &mut Vec<Self>, error: Self) {
+        match error {
+            Self::Multiple(inner) => {
+                for error in inner.into_iter() {
+                    Self::push(errors, error);
+                }
+            }
+            error => {
+                if !errors.contains(&error) {
+                    errors.push(error);
+                }
+            }
+        }
+    }
+
+    ///
+    /// Converts the collected `errors` list into a result.
+    ///
+    /// A single error is returned as is to make the result look like an ordinary one.
+    ///
+    pub fn collect(mut errors: Vec<Self>) -> Result<(), Self> {
+        match errors.len() {
+            0 => Ok(()),
+            1 => Err(errors.remove(0)),
+            _ => Err(Self::Multiple(errors)),
+        }
+    }
+
     ///
     /// Returns the semantic error code.
     ///
@@ -1900,8 +2156,18 @@ impl Error {
 
             Self::ForStatementWhileExpectedBooleanCondition { .. } => 19,
             Self::ForStatementBoundsExpectedConstantRangeExpression { .. } => 20,
+            Self::WhileStatementExpectedBooleanCondition { .. } => 262,
+            Self::WhileStatementBoundExpectedConstantInteger { .. } => 263,
+            Self::BreakStatementBeyondLoop { .. } => 253,
+            Self::ContinueStatementBeyondLoop { .. } => 254,
+            Self::ReturnStatementBeyondFunction { .. } => 255,
 
             Self::ImplStatementExpectedStructureOrEnumeration { .. } => 21,
+            Self::ImplStatementGenericType { .. } => 244,
+            Self::ImplStatementExpectedTrait { .. } => 247,
+            Self::ImplStatementTraitMethodMissing { .. } => 248,
+            Self::ImplStatementTraitMethodUnexpected { .. } => 249,
+            Self::ImplStatementTraitMethodSignature { .. } => 250,
 
             Self::UseStatementExpectedPath { .. } => 22,
 
@@ -1934,9 +2200,19 @@ impl Error {
             Self::TypeUnexpectedGenerics { .. } => 40,
             Self::TypeExpectedGenerics { .. } => 41,
             Self::TypeInvalidGenericsNumber { .. } => 42,
+            Self::TypeGenericArgumentNotInferred { .. } => 245,
+            Self::TypeExpectedTrait { .. } => 251,
+            Self::TypeTraitNotImplemented { .. } => 252,
+            Self::TypeVariantPayloadMismatch { .. } => 256,
+            Self::TypeVariantPayloadCount { .. } => 257,
+            Self::TypeVariantPayloadInvalidType { .. } => 258,
+            Self::MatchBranchGuardExpectedBoolean { .. } => 259,
+            Self::MatchBranchPatternRangeInvalid { .. } => 260,
+            Self::MatchBranchAlternativeBindingMismatch { .. } => 261,
 
             Self::FunctionArgumentCount { .. } => 43,
             Self::FunctionDebugArgumentCount { .. } => 44,
+            Self::FunctionRequireArgumentCount { .. } => 268,
             Self::FunctionArgumentType { .. } => 45,
             Self::FunctionArgumentConstantness { .. } => 46,
             Self::FunctionArgumentNotEvaluable { .. } => 47,
@@ -1945,10 +2221,16 @@ impl Error {
             Self::FunctionCallMutableFromImmutable { .. } => 50,
             Self::FunctionUnexpectedExclamationMark { .. } => 51,
             Self::FunctionExpectedExclamationMark { .. } => 52,
+            Self::FunctionGenericsForbidden { .. } => 246,
             Self::FunctionStdlibArrayTruncatingToBiggerSize { .. } => 53,
             Self::FunctionStdlibArrayPaddingToLesserSize { .. } => 54,
             Self::FunctionStdlibArrayNewLengthInvalid { .. } => 55,
 
+            Self::ClosureOutsideOfArgument { .. } => 264,
+            Self::ClosureParameterCount { .. } => 265,
+            Self::ClosureParameterType { .. } => 266,
+            Self::ClosureReturnType { .. } => 267,
+
             Self::InvalidInteger {
                 inner: zinc_math::Error::NumberParsing(_),
                 ..
@@ -2155,6 +2437,8 @@ impl Error {
             Self::UnitTestConstantForbidden { .. } => 236,
             Self::UnitTestCannotHaveArguments { .. } => 237,
             Self::UnitTestCannotReturnValue { .. } => 238,
+
+            Self::Multiple(inner) => inner.first().map(Self::code).unwrap_or_default(),
         }
     }
 }
@@ -5,11 +5,34 @@
 use std::cell::RefCell;
 use std::rc::Rc;
 
+use zinc_lexical::Location;
+use zinc_syntax::EnumStatement;
+use zinc_syntax::ExpressionOperand;
+use zinc_syntax::ExpressionTree;
+use zinc_syntax::ExpressionTreeNode;
+use zinc_syntax::GenericParameter;
+use zinc_syntax::Identifier;
+use zinc_syntax::Type as SyntaxType;
+use zinc_syntax::TypeVariant as SyntaxTypeVariant;
+use zinc_syntax::Variant;
+use zinc_syntax::VariantPayload;
 use zinc_types::LibraryFunctionIdentifier;
 
+use crate::semantic::analyzer::statement::r#enum::Analyzer as EnumStatementAnalyzer;
+use crate::semantic::element::r#type::function::intrinsic::stdlib::array_all::Function as StdArrayAllFunction;
+use crate::semantic::element::r#type::function::intrinsic::stdlib::array_any::Function as StdArrayAnyFunction;
+use crate::semantic::element::r#type::function::intrinsic::stdlib::array_filter_count::Function as StdArrayFilterCountFunction;
+use crate::semantic::element::r#type::function::intrinsic::stdlib::array_fold::Function as StdArrayFoldFunction;
+use crate::semantic::element::r#type::function::intrinsic::stdlib::array_map::Function as StdArrayMapFunction;
+use crate::semantic::element::r#type::function::intrinsic::stdlib::array_position::Function as StdArrayPositionFunction;
+use crate::semantic::element::r#type::function::intrinsic::stdlib::array_zip::Function as StdArrayZipFunction;
+use crate::semantic::element::r#type::function::intrinsic::stdlib::string_concat::Function as StdStringConcatFunction;
+use crate::semantic::element::r#type::function::intrinsic::stdlib::string_to_bytes::Function as StdStringToBytesFunction;
+use crate::semantic::element::r#type::function::intrinsic::stdlib::Function as StandardLibraryFunction;
 use crate::semantic::element::r#type::function::Function as FunctionType;
 use crate::semantic::element::r#type::structure::Structure as StructureType;
 use crate::semantic::element::r#type::Type;
+use crate::semantic::error::Error;
 use crate::semantic::scope::item::module::Module as ScopeModuleItem;
 use crate::semantic::scope::item::r#type::Type as ScopeTypeItem;
 use crate::semantic::scope::item::variable::Variable as ScopeVariableItem;
@@ -21,9 +44,30 @@ use crate::semantic::scope::Scope;
 ///
 /// The intrinsic items are functions `dbg!` and `require` and the `std` and `zksync` libraries.
 ///
+/// The generic enumerations `std::option::Option` and `std::result::Result` are declared as
+/// syntax trees, since their payloads are resolved for each instance like in the user code.
+///
 #[derive(Debug)]
 pub struct IntrinsicScope {}
 
+thread_local! {
+    /// The `enum Option<T> { None, Some(T) }` type, which is shared by all modules, so the
+    /// instances created in different modules are the same types.
+    static STD_OPTION: Type = IntrinsicScope::generic_enumeration(
+        "Option",
+        &["T"],
+        vec![("None", None), ("Some", Some(vec!["T"]))],
+    );
+
+    /// The `enum Result<T, E> { Ok(T), Err(E) }` type, which is shared by all modules, so the
+    /// instances created in different modules are the same types.
+    static STD_RESULT: Type = IntrinsicScope::generic_enumeration(
+        "Result",
+        &["T", "E"],
+        vec![("Ok", Some(vec!["T"])), ("Err", Some(vec!["E"]))],
+    );
+}
+
 ///
 /// The intrinsic structures type IDs.
 ///
@@ -36,6 +80,8 @@ pub enum IntrinsicTypeId {
     ZkSyncTransaction = 2,
     /// The `std::collections::MTreeMap` structure type ID.
     StdCollectionsMTreeMap = 3,
+    /// The `std::crypto::eddsa::Signature` structure type ID.
+    StdCryptoEddsaSignature = 4,
 }
 
 impl IntrinsicScope {
@@ -85,6 +131,21 @@ impl IntrinsicScope {
         scope
     }
 
+    ///
+    /// Returns the `std::option::Option` instance with the `argument`.
+    ///
+    pub fn option(location: Location, argument: Type) -> Result<Type, Error> {
+        STD_OPTION.with(|r#type| match r#type {
+            Type::Enumeration(enumeration) => enumeration
+                .template
+                .as_ref()
+                .expect(zinc_const::panic::INTRINSIC_ITEMS_VALID)
+                .instantiate(location, vec![argument])
+                .map(Type::Enumeration),
+            _ => panic!(zinc_const::panic::INTRINSIC_ITEMS_VALID),
+        })
+    }
+
     ///
     /// Initializes the `std` module scope.
     ///
@@ -127,6 +188,15 @@ impl IntrinsicScope {
             ))
             .wrap(),
         );
+        Scope::insert_item(
+            scope.clone(),
+            "string".to_owned(),
+            ScopeItem::Module(ScopeModuleItem::new_built_in(
+                "string".to_owned(),
+                Self::module_string(),
+            ))
+            .wrap(),
+        );
         Scope::insert_item(
             scope.clone(),
             "collections".to_owned(),
@@ -136,6 +206,24 @@ impl IntrinsicScope {
             ))
             .wrap(),
         );
+        Scope::insert_item(
+            scope.clone(),
+            "option".to_owned(),
+            ScopeItem::Module(ScopeModuleItem::new_built_in(
+                "option".to_owned(),
+                Self::module_option(),
+            ))
+            .wrap(),
+        );
+        Scope::insert_item(
+            scope.clone(),
+            "result".to_owned(),
+            ScopeItem::Module(ScopeModuleItem::new_built_in(
+                "result".to_owned(),
+                Self::module_result(),
+            ))
+            .wrap(),
+        );
 
         scope
     }
@@ -148,6 +236,18 @@ impl IntrinsicScope {
 
         let sha256 = FunctionType::library(LibraryFunctionIdentifier::CryptoSha256);
         let pedersen = FunctionType::library(LibraryFunctionIdentifier::CryptoPedersen);
+        let blake2s = FunctionType::library(LibraryFunctionIdentifier::CryptoBlake2s);
+        let poseidon = FunctionType::library(LibraryFunctionIdentifier::CryptoPoseidon);
+        let rescue = FunctionType::library(LibraryFunctionIdentifier::CryptoRescue);
+        let keccak256 = FunctionType::library(LibraryFunctionIdentifier::CryptoKeccak256);
+
+        let ecdsa_scope = Scope::new_intrinsic("ecdsa").wrap();
+        let ecdsa_verify = FunctionType::library(LibraryFunctionIdentifier::CryptoEcdsaVerify);
+        Scope::insert_item(
+            ecdsa_scope.clone(),
+            ecdsa_verify.identifier(),
+            ScopeItem::Type(ScopeTypeItem::new_built_in(Type::Function(ecdsa_verify))).wrap(),
+        );
 
         let schnorr_scope = Scope::new_intrinsic("schnorr").wrap();
         let schnorr_signature_scope = Scope::new_intrinsic("Signature").wrap();
@@ -158,9 +258,10 @@ impl IntrinsicScope {
             schnorr_verify.identifier(),
             ScopeItem::Type(ScopeTypeItem::new_built_in(Type::Function(schnorr_verify))).wrap(),
         );
+        let ecc_point_scope = Scope::new_intrinsic("Point").wrap();
         let ecc_point = StructureType::new(
             None,
-            "Point".to_owned(),
+            ecc_point_scope.borrow().name(),
             IntrinsicTypeId::StdCryptoEccPoint as usize,
             vec![
                 ("x".to_owned(), Type::field(None)),
@@ -168,7 +269,58 @@ impl IntrinsicScope {
             ],
             None,
             None,
-            schnorr_scope.clone(),
+            ecc_point_scope.clone(),
+        );
+        let ecc_point_generator = FunctionType::ecc_point_generator(ecc_point.clone());
+        Scope::insert_item(
+            ecc_point_scope.clone(),
+            ecc_point_generator.identifier(),
+            ScopeItem::Type(ScopeTypeItem::new_built_in(Type::Function(
+                ecc_point_generator,
+            )))
+            .wrap(),
+        );
+        let ecc_point_add = FunctionType::library(LibraryFunctionIdentifier::CryptoEccPointAdd);
+        Scope::insert_item(
+            ecc_point_scope.clone(),
+            ecc_point_add.identifier(),
+            ScopeItem::Type(ScopeTypeItem::new_built_in(Type::Function(ecc_point_add))).wrap(),
+        );
+        let ecc_point_double =
+            FunctionType::library(LibraryFunctionIdentifier::CryptoEccPointDouble);
+        Scope::insert_item(
+            ecc_point_scope.clone(),
+            ecc_point_double.identifier(),
+            ScopeItem::Type(ScopeTypeItem::new_built_in(Type::Function(
+                ecc_point_double,
+            )))
+            .wrap(),
+        );
+        let ecc_point_mul = FunctionType::library(LibraryFunctionIdentifier::CryptoEccPointMul);
+        Scope::insert_item(
+            ecc_point_scope.clone(),
+            ecc_point_mul.identifier(),
+            ScopeItem::Type(ScopeTypeItem::new_built_in(Type::Function(ecc_point_mul))).wrap(),
+        );
+        let ecc_point_is_on_curve =
+            FunctionType::library(LibraryFunctionIdentifier::CryptoEccPointIsOnCurve);
+        Scope::insert_item(
+            ecc_point_scope.clone(),
+            ecc_point_is_on_curve.identifier(),
+            ScopeItem::Type(ScopeTypeItem::new_built_in(Type::Function(
+                ecc_point_is_on_curve,
+            )))
+            .wrap(),
+        );
+        let ecc_point_negate =
+            FunctionType::library(LibraryFunctionIdentifier::CryptoEccPointNegate);
+        Scope::insert_item(
+            ecc_point_scope.clone(),
+            ecc_point_negate.identifier(),
+            ScopeItem::Type(ScopeTypeItem::new_built_in(Type::Function(
+                ecc_point_negate,
+            )))
+            .wrap(),
         );
         let schnorr_signature = StructureType::new(
             None,
@@ -192,6 +344,37 @@ impl IntrinsicScope {
             .wrap(),
         );
 
+        let eddsa_scope = Scope::new_intrinsic("eddsa").wrap();
+        let eddsa_signature_scope = Scope::new_intrinsic("Signature").wrap();
+        let eddsa_verify =
+            FunctionType::library(LibraryFunctionIdentifier::CryptoEddsaSignatureVerify);
+        Scope::insert_item(
+            eddsa_signature_scope.clone(),
+            eddsa_verify.identifier(),
+            ScopeItem::Type(ScopeTypeItem::new_built_in(Type::Function(eddsa_verify))).wrap(),
+        );
+        let eddsa_signature = StructureType::new(
+            None,
+            eddsa_signature_scope.borrow().name(),
+            IntrinsicTypeId::StdCryptoEddsaSignature as usize,
+            vec![
+                ("r".to_owned(), Type::Structure(ecc_point.clone())),
+                ("s".to_owned(), Type::field(None)),
+                ("pk".to_owned(), Type::Structure(ecc_point.clone())),
+            ],
+            None,
+            None,
+            eddsa_signature_scope.clone(),
+        );
+        Scope::insert_item(
+            eddsa_scope.clone(),
+            eddsa_signature_scope.borrow().name(),
+            ScopeItem::Type(ScopeTypeItem::new_built_in(Type::Structure(
+                eddsa_signature,
+            )))
+            .wrap(),
+        );
+
         let ecc_scope = Scope::new_intrinsic("ecc").wrap();
         Scope::insert_item(
             ecc_scope.clone(),
@@ -209,6 +392,26 @@ impl IntrinsicScope {
             pedersen.identifier(),
             ScopeItem::Type(ScopeTypeItem::new_built_in(Type::Function(pedersen))).wrap(),
         );
+        Scope::insert_item(
+            scope.clone(),
+            blake2s.identifier(),
+            ScopeItem::Type(ScopeTypeItem::new_built_in(Type::Function(blake2s))).wrap(),
+        );
+        Scope::insert_item(
+            scope.clone(),
+            poseidon.identifier(),
+            ScopeItem::Type(ScopeTypeItem::new_built_in(Type::Function(poseidon))).wrap(),
+        );
+        Scope::insert_item(
+            scope.clone(),
+            rescue.identifier(),
+            ScopeItem::Type(ScopeTypeItem::new_built_in(Type::Function(rescue))).wrap(),
+        );
+        Scope::insert_item(
+            scope.clone(),
+            keccak256.identifier(),
+            ScopeItem::Type(ScopeTypeItem::new_built_in(Type::Function(keccak256))).wrap(),
+        );
         Scope::insert_item(
             scope.clone(),
             ecc_scope.borrow().name(),
@@ -227,6 +430,24 @@ impl IntrinsicScope {
             ))
             .wrap(),
         );
+        Scope::insert_item(
+            scope.clone(),
+            ecdsa_scope.borrow().name(),
+            ScopeItem::Module(ScopeModuleItem::new_built_in(
+                ecdsa_scope.borrow().name(),
+                ecdsa_scope.clone(),
+            ))
+            .wrap(),
+        );
+        Scope::insert_item(
+            scope.clone(),
+            eddsa_scope.borrow().name(),
+            ScopeItem::Module(ScopeModuleItem::new_built_in(
+                eddsa_scope.borrow().name(),
+                eddsa_scope.clone(),
+            ))
+            .wrap(),
+        );
 
         scope
     }
@@ -284,22 +505,48 @@ impl IntrinsicScope {
         let reverse = FunctionType::library(LibraryFunctionIdentifier::ArrayReverse);
         let truncate = FunctionType::library(LibraryFunctionIdentifier::ArrayTruncate);
         let pad = FunctionType::library(LibraryFunctionIdentifier::ArrayPad);
-
-        Scope::insert_item(
-            scope.clone(),
-            reverse.identifier(),
-            ScopeItem::Type(ScopeTypeItem::new_built_in(Type::Function(reverse))).wrap(),
-        );
-        Scope::insert_item(
-            scope.clone(),
-            truncate.identifier(),
-            ScopeItem::Type(ScopeTypeItem::new_built_in(Type::Function(truncate))).wrap(),
-        );
-        Scope::insert_item(
-            scope.clone(),
-            pad.identifier(),
-            ScopeItem::Type(ScopeTypeItem::new_built_in(Type::Function(pad))).wrap(),
-        );
+        let map = FunctionType::standard_library(StandardLibraryFunction::ArrayMap(
+            StdArrayMapFunction::default(),
+        ));
+        let fold = FunctionType::standard_library(StandardLibraryFunction::ArrayFold(
+            StdArrayFoldFunction::default(),
+        ));
+        let zip = FunctionType::standard_library(StandardLibraryFunction::ArrayZip(
+            StdArrayZipFunction::default(),
+        ));
+        let any = FunctionType::standard_library(StandardLibraryFunction::ArrayAny(
+            StdArrayAnyFunction::default(),
+        ));
+        let all = FunctionType::standard_library(StandardLibraryFunction::ArrayAll(
+            StdArrayAllFunction::default(),
+        ));
+        let position = FunctionType::standard_library(StandardLibraryFunction::ArrayPosition(
+            StdArrayPositionFunction::default(),
+        ));
+        let filter_count = FunctionType::standard_library(
+            StandardLibraryFunction::ArrayFilterCount(StdArrayFilterCountFunction::default()),
+        );
+
+        for function in vec![
+            reverse,
+            truncate,
+            pad,
+            map,
+            fold,
+            zip,
+            any,
+            all,
+            position,
+            filter_count,
+        ]
+        .into_iter()
+        {
+            Scope::insert_item(
+                scope.clone(),
+                function.identifier(),
+                ScopeItem::Type(ScopeTypeItem::new_built_in(Type::Function(function))).wrap(),
+            );
+        }
 
         scope
     }
@@ -321,6 +568,30 @@ impl IntrinsicScope {
         scope
     }
 
+    ///
+    /// Initializes the `std::string` module scope.
+    ///
+    fn module_string() -> Rc<RefCell<Scope>> {
+        let scope = Scope::new_intrinsic("string").wrap();
+
+        let concat = FunctionType::standard_library(StandardLibraryFunction::StringConcat(
+            StdStringConcatFunction::default(),
+        ));
+        let to_bytes = FunctionType::standard_library(StandardLibraryFunction::StringToBytes(
+            StdStringToBytesFunction::default(),
+        ));
+
+        for function in vec![concat, to_bytes].into_iter() {
+            Scope::insert_item(
+                scope.clone(),
+                function.identifier(),
+                ScopeItem::Type(ScopeTypeItem::new_built_in(Type::Function(function))).wrap(),
+            );
+        }
+
+        scope
+    }
+
     ///
     /// Initializes the `std::collections` module scope.
     ///
@@ -390,6 +661,104 @@ impl IntrinsicScope {
         scope
     }
 
+    ///
+    /// Initializes the `std::option` module scope.
+    ///
+    fn module_option() -> Rc<RefCell<Scope>> {
+        let scope = Scope::new_intrinsic("option").wrap();
+
+        Scope::insert_item(
+            scope.clone(),
+            "Option".to_owned(),
+            ScopeItem::Type(ScopeTypeItem::new_built_in(
+                STD_OPTION.with(|r#type| r#type.to_owned()),
+            ))
+            .wrap(),
+        );
+
+        scope
+    }
+
+    ///
+    /// Initializes the `std::result` module scope.
+    ///
+    fn module_result() -> Rc<RefCell<Scope>> {
+        let scope = Scope::new_intrinsic("result").wrap();
+
+        Scope::insert_item(
+            scope.clone(),
+            "Result".to_owned(),
+            ScopeItem::Type(ScopeTypeItem::new_built_in(
+                STD_RESULT.with(|r#type| r#type.to_owned()),
+            ))
+            .wrap(),
+        );
+
+        scope
+    }
+
+    ///
+    /// Defines the generic enumeration `identifier` with the `generics`.
+    ///
+    /// The `variants` are the variant names with the optional tuple payloads, whose elements
+    /// are the generic parameter names.
+    ///
+    fn generic_enumeration(
+        identifier: &'static str,
+        generics: &[&str],
+        variants: Vec<(&str, Option<Vec<&str>>)>,
+    ) -> Type {
+        let location = Location::default();
+
+        let generics = generics
+            .iter()
+            .map(|name| {
+                GenericParameter::new(
+                    location,
+                    Identifier::new(location, (*name).to_owned()),
+                    vec![],
+                )
+            })
+            .collect();
+        let variants = variants
+            .into_iter()
+            .map(|(name, payload)| {
+                let payload = payload.map(|types| {
+                    VariantPayload::new_tuple(
+                        types
+                            .into_iter()
+                            .map(|name| {
+                                let path = ExpressionTree::new(
+                                    location,
+                                    ExpressionTreeNode::Operand(ExpressionOperand::Identifier(
+                                        Identifier::new(location, name.to_owned()),
+                                    )),
+                                );
+                                SyntaxType::new(location, SyntaxTypeVariant::alias(path, None))
+                            })
+                            .collect(),
+                    )
+                });
+                Variant::new(
+                    location,
+                    Identifier::new(location, name.to_owned()),
+                    None,
+                    payload,
+                )
+            })
+            .collect();
+
+        let statement = EnumStatement::new(
+            location,
+            Identifier::new(location, identifier.to_owned()),
+            Some(generics),
+            variants,
+        );
+
+        EnumStatementAnalyzer::define(Scope::new_intrinsic(identifier).wrap(), statement)
+            .expect(zinc_const::panic::INTRINSIC_ITEMS_VALID)
+    }
+
     ///
     /// Initializes the `zksync` module scope.
     ///
@@ -73,12 +73,21 @@ impl Constant {
     /// is reentered before the item being defined is put back into `variant`, which means that
     /// the item is taken twice during its resolution process.
     ///
+    /// If the definition fails, the item is marked as failed, so the other items referencing it
+    /// fail silently instead of reporting a false reference loop or the same error again.
+    ///
     pub fn define(&self) -> Result<ConstantElement, Error> {
         let variant = self.state.borrow_mut().take();
 
         match variant {
             Some(State::Declared { inner, scope }) => {
-                let defined = ConstStatementAnalyzer::define(scope, inner)?;
+                let defined = match ConstStatementAnalyzer::define(scope, inner) {
+                    Ok(defined) => defined,
+                    Err(error) => {
+                        self.state.replace(Some(State::Failed));
+                        return Err(error);
+                    }
+                };
                 self.state.replace(Some(State::Defined {
                     inner: defined.clone(),
                 }));
@@ -92,6 +101,11 @@ impl Constant {
 
                 Ok(inner)
             }
+            Some(State::Failed) => {
+                self.state.replace(Some(State::Failed));
+
+                Err(Error::reported())
+            }
             None => Err(Error::ScopeReferenceLoop {
                 location: self.location,
             }),
@@ -104,6 +118,7 @@ impl fmt::Display for Constant {
         match self.state.borrow().as_ref() {
             Some(State::Declared { inner, .. }) => write!(f, "{}", inner.identifier.name),
             Some(State::Defined { inner, .. }) => write!(f, "{}", inner),
+            Some(State::Failed) => write!(f, "<failed {}>", self.location),
             None => write!(f, "<resolving {}>", self.location),
         }
     }
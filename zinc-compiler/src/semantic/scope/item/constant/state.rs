@@ -11,7 +11,7 @@ use crate::semantic::element::constant::Constant as ConstantElement;
 use crate::semantic::scope::Scope;
 
 ///
-/// The definition state, which is either `declared` or `defined`.
+/// The definition state, which is either `declared`, `defined`, or `failed`.
 ///
 #[derive(Debug, Clone)]
 pub enum State {
@@ -27,4 +27,6 @@ pub enum State {
         /// The semantic constant element.
         inner: ConstantElement,
     },
+    /// The definition has failed, and the error has been already reported.
+    Failed,
 }
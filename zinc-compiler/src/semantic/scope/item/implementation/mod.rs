@@ -0,0 +1,111 @@
+//!
+//! The semantic analyzer scope trait implementation item.
+//!
+
+pub mod state;
+
+use std::cell::RefCell;
+use std::fmt;
+use std::rc::Rc;
+
+use zinc_lexical::Location;
+use zinc_syntax::Identifier;
+
+use crate::semantic::analyzer::statement::r#impl::Analyzer as ImplStatementAnalyzer;
+use crate::semantic::element::r#type::r#trait::Trait as TraitType;
+use crate::semantic::error::Error;
+use crate::semantic::scope::item::index::INDEX as ITEM_INDEX;
+use crate::semantic::scope::Scope;
+
+use self::state::State;
+
+///
+/// The trait implementation item, declared using an `impl Trait for Type` statement.
+///
+/// The item is stored in the implemented type scope, so the type can be checked for
+/// implementing a trait, e.g. when it is passed as a generic argument with a trait bound.
+///
+#[derive(Debug, Clone)]
+pub struct Implementation {
+    /// The location, where the implementation is declared.
+    pub location: Location,
+    /// The unique implementation ID, allocated upon declaration.
+    pub item_id: usize,
+    /// The definition state, which is either `declared` or `defined`.
+    pub state: RefCell<Option<State>>,
+}
+
+impl Implementation {
+    ///
+    /// Creates a declared trait implementation, which must be defined during the second pass
+    /// or when the implemented type is checked for implementing a trait for the first time.
+    ///
+    /// Is used during the `impl` statement hoisting.
+    ///
+    pub fn new_declared(
+        location: Location,
+        r#trait: Identifier,
+        methods: Vec<Identifier>,
+        scope: Rc<RefCell<Scope>>,
+    ) -> Self {
+        let item_id = ITEM_INDEX.next(format!("impl {}", r#trait.name));
+
+        Self {
+            location,
+            item_id,
+            state: RefCell::new(Some(State::Declared {
+                r#trait,
+                methods,
+                scope,
+            })),
+        }
+    }
+
+    ///
+    /// Defines the declared trait implementation.
+    ///
+    /// The trait is put into the `defined` state before the methods are checked, since the
+    /// methods may require their type to implement the trait, e.g. passing `self` to a generic
+    /// function with a trait bound.
+    ///
+    pub fn define(&self) -> Result<TraitType, Error> {
+        let variant = self.state.borrow_mut().take();
+
+        match variant {
+            Some(State::Declared {
+                r#trait,
+                methods,
+                scope,
+            }) => {
+                let defined = ImplStatementAnalyzer::resolve_trait(scope.clone(), &r#trait)?;
+                self.state.replace(Some(State::Defined {
+                    r#trait: defined.clone(),
+                }));
+
+                ImplStatementAnalyzer::check_trait(scope, r#trait.location, &defined, methods)?;
+
+                Ok(defined)
+            }
+            Some(State::Defined { r#trait }) => {
+                self.state.replace(Some(State::Defined {
+                    r#trait: r#trait.clone(),
+                }));
+
+                Ok(r#trait)
+            }
+            None => Err(Error::ScopeReferenceLoop {
+                location: self.location,
+            }),
+        }
+    }
+}
+
+impl fmt::Display for Implementation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.state.borrow().as_ref() {
+            Some(State::Declared { r#trait, .. }) => write!(f, "{}", r#trait.name),
+            Some(State::Defined { r#trait }) => write!(f, "{}", r#trait),
+            None => write!(f, "<resolving {}>", self.location),
+        }
+    }
+}
@@ -0,0 +1,32 @@
+//!
+//! The semantic analyzer scope trait implementation item state.
+//!
+
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use zinc_syntax::Identifier;
+
+use crate::semantic::element::r#type::r#trait::Trait as TraitType;
+use crate::semantic::scope::Scope;
+
+///
+/// The definition state, which is either `declared` or `defined`.
+///
+#[derive(Debug, Clone)]
+pub enum State {
+    /// Waiting to be defined during the second pass.
+    Declared {
+        /// The implemented trait identifier.
+        r#trait: Identifier,
+        /// The identifiers of the methods declared in the `impl` block.
+        methods: Vec<Identifier>,
+        /// The implemented type scope, where the methods are declared.
+        scope: Rc<RefCell<Scope>>,
+    },
+    /// Defined element ready to be used from anywhere.
+    Defined {
+        /// The implemented trait.
+        r#trait: TraitType,
+    },
+}
@@ -4,6 +4,7 @@
 
 pub mod constant;
 pub mod field;
+pub mod implementation;
 pub mod index;
 pub mod module;
 pub mod r#type;
@@ -15,11 +16,15 @@ use std::fmt;
 use std::rc::Rc;
 
 use crate::generator::statement::Statement as GeneratorStatement;
+use crate::semantic::element::r#type::i_typed::ITyped;
+use crate::semantic::element::r#type::Type as SemanticType;
 use crate::semantic::error::Error;
+use crate::semantic::scope::Scope;
 use zinc_lexical::Location;
 
 use self::constant::Constant;
 use self::field::Field;
+use self::implementation::Implementation;
 use self::module::Module;
 use self::r#type::Type;
 use self::variable::Variable;
@@ -70,6 +75,8 @@ pub enum Item {
     Type(Type),
     /// The module item. See the inner element description.
     Module(Module),
+    /// The trait implementation item. See the inner element description.
+    Implementation(Implementation),
 }
 
 impl Item {
@@ -99,6 +106,9 @@ impl Item {
             Self::Module(inner) => {
                 inner.define()?;
             }
+            Self::Implementation(inner) => {
+                inner.define()?;
+            }
         }
 
         Ok(())
@@ -115,6 +125,43 @@ impl Item {
             Self::Variant(inner) => Some(inner.location),
             Self::Type(inner) => inner.location,
             Self::Module(inner) => inner.location,
+            Self::Implementation(inner) => Some(inner.location),
+        }
+    }
+
+    ///
+    /// The resolved semantic type of the item.
+    ///
+    /// Defines the item if it has not been defined yet. Returns `None` for modules and
+    /// implementations, which do not have a type, and for items whose definition fails.
+    ///
+    pub fn r#type(&self) -> Option<SemanticType> {
+        match self {
+            Self::Variable(inner) => Some(inner.r#type.to_owned()),
+            Self::Field(inner) => Some(inner.r#type.to_owned()),
+            Self::Constant(inner) => inner.define().ok().map(|constant| constant.r#type()),
+            Self::Variant(inner) => Some(inner.constant.r#type()),
+            Self::Type(inner) => inner.define().ok(),
+            Self::Module(_) => None,
+            Self::Implementation(_) => None,
+        }
+    }
+
+    ///
+    /// The scope, whose items are accessible via the `::` path operator after the item name.
+    ///
+    /// Such items are modules, structures, enumerations, and contracts.
+    ///
+    pub fn namespace(&self) -> Option<Rc<RefCell<Scope>>> {
+        match self {
+            Self::Module(inner) => inner.define().ok(),
+            Self::Type(inner) => match inner.define().ok()? {
+                SemanticType::Enumeration(inner) => Some(inner.scope),
+                SemanticType::Structure(inner) => Some(inner.scope),
+                SemanticType::Contract(inner) => Some(inner.scope),
+                _ => None,
+            },
+            _ => None,
         }
     }
 
@@ -129,6 +176,7 @@ impl Item {
             Self::Variant(inner) => inner.item_id,
             Self::Type(inner) => inner.item_id,
             Self::Module(inner) => inner.item_id,
+            Self::Implementation(inner) => inner.item_id,
         }
     }
 
@@ -143,6 +191,7 @@ impl Item {
             Self::Variant(_) => vec![],
             Self::Type(inner) => inner.get_intermediate(),
             Self::Module(inner) => inner.get_intermediate(),
+            Self::Implementation(_) => vec![],
         }
     }
 }
@@ -156,6 +205,7 @@ impl fmt::Display for Item {
             Self::Variant(inner) => write!(f, "variant {}", inner),
             Self::Type(inner) => write!(f, "type {}", inner),
             Self::Module(inner) => write!(f, "module {}", inner),
+            Self::Implementation(inner) => write!(f, "implementation {}", inner),
         }
     }
 }
@@ -52,6 +52,10 @@ impl Index {
             "structure std::collections::MTreeMap".to_owned(),
             IntrinsicTypeId::StdCollectionsMTreeMap as usize,
         );
+        index.next_with_id(
+            "structure std::crypto::eddsa::Signature".to_owned(),
+            IntrinsicTypeId::StdCryptoEddsaSignature as usize,
+        );
         index
     }
 
@@ -18,6 +18,7 @@ use crate::semantic::analyzer::statement::contract::Analyzer as ContractStatemen
 use crate::semantic::analyzer::statement::r#enum::Analyzer as EnumStatementAnalyzer;
 use crate::semantic::analyzer::statement::r#fn::Analyzer as FnStatementAnalyzer;
 use crate::semantic::analyzer::statement::r#struct::Analyzer as StructStatementAnalyzer;
+use crate::semantic::analyzer::statement::r#trait::Analyzer as TraitStatementAnalyzer;
 use crate::semantic::analyzer::statement::r#type::Analyzer as TypeStatementAnalyzer;
 use crate::semantic::element::r#type::Type as TypeElement;
 use crate::semantic::error::Error;
@@ -29,7 +30,7 @@ use self::state::State;
 use self::statement::Statement as TypeStatementVariant;
 
 ///
-/// The type item, declared using a `type`, `struct`, `enum`, or another statement.
+/// The type item, declared using a `type`, `struct`, `enum`, `trait`, or another statement.
 ///
 #[derive(Debug, Clone)]
 pub struct Type {
@@ -148,31 +149,45 @@ impl Type {
     /// is reentered before the item being defined is put back into `variant`, which means that
     /// the item is taken twice during its resolution process.
     ///
+    /// If the definition fails, the item is marked as failed, so the other items referencing it
+    /// fail silently instead of reporting a false reference loop or the same error again.
+    ///
     pub fn define(&self) -> Result<TypeElement, Error> {
         let variant = self.state.borrow_mut().take();
 
         match variant {
             Some(State::Declared { inner, scope }) => {
-                let (r#type, intermediate) = match inner {
+                let result = match inner {
                     TypeStatementVariant::Type(inner) => {
-                        (TypeStatementAnalyzer::define(scope, inner)?, None)
+                        TypeStatementAnalyzer::define(scope, inner).map(|r#type| (r#type, None))
                     }
                     TypeStatementVariant::Struct(inner) => {
-                        (StructStatementAnalyzer::define(scope, inner)?, None)
+                        StructStatementAnalyzer::define(scope, inner).map(|r#type| (r#type, None))
                     }
                     TypeStatementVariant::Enum(inner) => {
-                        (EnumStatementAnalyzer::define(scope, inner)?, None)
+                        EnumStatementAnalyzer::define(scope, inner).map(|r#type| (r#type, None))
+                    }
+                    TypeStatementVariant::Trait(inner) => {
+                        TraitStatementAnalyzer::define(scope, inner).map(|r#type| (r#type, None))
                     }
                     TypeStatementVariant::Fn(inner) => FnStatementAnalyzer::define(scope, inner)
                         .map(|(r#type, intermediate)| {
                             (r#type, intermediate.map(GeneratorStatement::Fn))
-                        })?,
+                        }),
                     TypeStatementVariant::Contract(inner) => ContractStatementAnalyzer::define(
                         scope, inner,
                     )
                     .map(|(r#type, intermediate)| {
                         (r#type, Some(GeneratorStatement::Contract(intermediate)))
-                    })?,
+                    }),
+                };
+
+                let (r#type, intermediate) = match result {
+                    Ok(result) => result,
+                    Err(error) => {
+                        self.state.replace(Some(State::Failed));
+                        return Err(error);
+                    }
                 };
 
                 self.state.replace(Some(State::Defined {
@@ -193,6 +208,11 @@ impl Type {
 
                 Ok(inner)
             }
+            Some(State::Failed) => {
+                self.state.replace(Some(State::Failed));
+
+                Err(Error::reported())
+            }
             None => Err(Error::ScopeReferenceLoop {
                 location: self.location.expect(zinc_const::panic::VALUE_ALWAYS_EXISTS),
             }),
@@ -232,6 +252,7 @@ impl fmt::Display for Type {
         match self.state.borrow().as_ref() {
             Some(State::Declared { inner, .. }) => write!(f, "{}", inner.identifier().name),
             Some(State::Defined { inner, .. }) => write!(f, "{}", inner),
+            Some(State::Failed) => write!(f, "<failed>"),
             None => match self.location {
                 Some(location) => write!(f, "<resolving {}>", location),
                 None => write!(f, "<resolving>"),
@@ -6,12 +6,13 @@ use std::cell::RefCell;
 use std::rc::Rc;
 
 use crate::generator::statement::Statement as GeneratorStatement;
+use crate::semantic::element::r#type::function::Function as FunctionElement;
 use crate::semantic::element::r#type::Type as TypeElement;
 use crate::semantic::scope::item::r#type::statement::Statement as TypeStatementVariant;
 use crate::semantic::scope::Scope;
 
 ///
-/// The definition state, which is either `declared` or `defined`.
+/// The definition state, which is either `declared`, `defined`, or `failed`.
 ///
 #[derive(Debug, Clone)]
 #[allow(clippy::large_enum_variant)]
@@ -30,6 +31,8 @@ pub enum State {
         /// There bytecode generator IR representation, if exists.
         intermediate: Option<GeneratorStatement>,
     },
+    /// The definition has failed, and the error has been already reported.
+    Failed,
 }
 
 impl State {
@@ -42,6 +45,11 @@ impl State {
                 inner,
                 intermediate,
             } => match inner {
+                TypeElement::Function(FunctionElement::Generic(ref inner)) => inner
+                    .get_intermediate()
+                    .into_iter()
+                    .map(GeneratorStatement::Fn)
+                    .collect(),
                 TypeElement::Function(_) => match intermediate.to_owned().take() {
                     Some(intermediate) => vec![intermediate],
                     None => vec![],
@@ -59,6 +67,7 @@ impl State {
                 _ => vec![],
             },
             Self::Declared { .. } => vec![],
+            Self::Failed => vec![],
         }
     }
 }
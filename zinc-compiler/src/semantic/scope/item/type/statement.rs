@@ -8,6 +8,7 @@ use zinc_syntax::EnumStatement;
 use zinc_syntax::FnStatement;
 use zinc_syntax::Identifier;
 use zinc_syntax::StructStatement;
+use zinc_syntax::TraitStatement;
 use zinc_syntax::TypeStatement;
 
 ///
@@ -25,6 +26,8 @@ pub enum Statement {
     Fn(FnStatement),
     /// The `contract` statement.
     Contract(ContractStatement),
+    /// The `trait` statement.
+    Trait(TraitStatement),
 }
 
 impl Statement {
@@ -38,6 +41,7 @@ impl Statement {
             Self::Enum(inner) => inner.location,
             Self::Fn(inner) => inner.location,
             Self::Contract(inner) => inner.location,
+            Self::Trait(inner) => inner.location,
         }
     }
 
@@ -51,6 +55,7 @@ impl Statement {
             Self::Enum(inner) => &inner.identifier,
             Self::Fn(inner) => &inner.identifier,
             Self::Contract(inner) => &inner.identifier,
+            Self::Trait(inner) => &inner.identifier,
         }
     }
 }
@@ -10,6 +10,7 @@ pub mod item;
 pub mod stack;
 pub mod r#type;
 
+use std::cell::Cell;
 use std::cell::RefCell;
 use std::collections::HashMap;
 use std::rc::Rc;
@@ -32,6 +33,7 @@ use crate::source::Source;
 use self::intrinsic::IntrinsicScope;
 use self::item::constant::Constant as ConstantItem;
 use self::item::field::Field as FieldItem;
+use self::item::implementation::Implementation as ImplementationItem;
 use self::item::module::Module as ModuleItem;
 use self::item::r#type::statement::Statement as TypeStatementVariant;
 use self::item::r#type::Type as TypeItem;
@@ -57,6 +59,11 @@ pub struct Scope {
     parent: Option<Rc<RefCell<Self>>>,
     /// The hashmap with items declared at the current scope level, with item names as keys.
     items: RefCell<HashMap<String, Rc<RefCell<Item>>>>,
+    /// The function identifier, return type, and its location in the function prototype, which
+    /// are only set for runtime function scopes.
+    r#return: Option<(String, SemanticType, Location)>,
+    /// Whether the function or loop is interrupted with `return`, `break`, or `continue`.
+    is_interrupted: Cell<bool>,
 }
 
 impl Scope {
@@ -75,6 +82,8 @@ impl Scope {
             r#type,
             parent,
             items: RefCell::new(HashMap::with_capacity(Self::ITEMS_INITIAL_CAPACITY)),
+            r#return: None,
+            is_interrupted: Cell::new(false),
         }
     }
 
@@ -110,6 +119,8 @@ impl Scope {
             r#type,
             parent: Some(IntrinsicScope::initialize()),
             items: RefCell::new(items),
+            r#return: None,
+            is_interrupted: Cell::new(false),
         }
     }
 
@@ -122,6 +133,8 @@ impl Scope {
             r#type: ScopeType::Intrinsic,
             parent: None,
             items: RefCell::new(HashMap::with_capacity(Self::ITEMS_INITIAL_CAPACITY)),
+            r#return: None,
+            is_interrupted: Cell::new(false),
         }
     }
 
@@ -157,6 +170,21 @@ impl Scope {
         self.parent.to_owned()
     }
 
+    ///
+    /// Sets the function identifier, return type, and its location in the function prototype,
+    /// which allows `return` statements within the function scope.
+    ///
+    pub fn set_return(&mut self, identifier: String, r#type: SemanticType, location: Location) {
+        self.r#return = Some((identifier, r#type, location));
+    }
+
+    ///
+    /// Whether the function or loop is interrupted with `return`, `break`, or `continue`.
+    ///
+    pub fn is_interrupted(&self) -> bool {
+        self.is_interrupted.get()
+    }
+
     ///
     /// Wraps the scope into `Rc<RefCell<_>>` simplifying most of initializations.
     ///
@@ -191,23 +219,87 @@ impl Scope {
         }
     }
 
+    ///
+    /// Marks the enclosing runtime function and all the loops on the way to it as interrupted
+    /// with a `return` statement.
+    ///
+    /// Returns the function identifier, return type, and its location in the function prototype,
+    /// or `None` if the `return` statement is not allowed in the `scope`.
+    ///
+    pub fn interrupt_function(
+        scope: Rc<RefCell<Scope>>,
+    ) -> Option<(String, SemanticType, Location)> {
+        let mut current = scope;
+        loop {
+            let parent = {
+                let scope = RefCell::borrow(&current);
+                match scope.r#type {
+                    ScopeType::Function => {
+                        if scope.r#return.is_some() {
+                            scope.is_interrupted.set(true);
+                        }
+                        return scope.r#return.to_owned();
+                    }
+                    ScopeType::Loop => scope.is_interrupted.set(true),
+                    ScopeType::Conditional | ScopeType::Block => {}
+                    _ => return None,
+                }
+                scope.parent.to_owned()?
+            };
+            current = parent;
+        }
+    }
+
+    ///
+    /// Marks the innermost loop as interrupted with a `break` or `continue` statement.
+    ///
+    /// Returns `false` if there is no loop within the enclosing function.
+    ///
+    pub fn interrupt_loop(scope: Rc<RefCell<Scope>>) -> bool {
+        let mut current = scope;
+        loop {
+            let parent = {
+                let scope = RefCell::borrow(&current);
+                match scope.r#type {
+                    ScopeType::Loop => {
+                        scope.is_interrupted.set(true);
+                        return true;
+                    }
+                    ScopeType::Conditional | ScopeType::Block => {}
+                    _ => return false,
+                }
+                match scope.parent {
+                    Some(ref parent) => parent.to_owned(),
+                    None => return false,
+                }
+            };
+            current = parent;
+        }
+    }
+
     ///
     /// Internally defines all the items in the order they have been declared.
     ///
+    /// If an item cannot be defined, the error is saved and the rest of the items are
+    /// still defined, so all the errors are returned at once.
+    ///
     pub fn define(&self) -> Result<(), Error> {
         let mut items: Vec<(String, Rc<RefCell<Item>>)> =
             self.items.clone().into_inner().into_iter().collect();
         items.sort_by_key(|(_name, item)| RefCell::borrow(&item).item_id());
 
+        let mut errors = Vec::new();
         for (name, item) in items.into_iter() {
             if Keyword::is_alias(name.as_str()) {
                 continue;
             }
 
-            RefCell::borrow(&item).define()?;
+            if let Err(error) = RefCell::borrow(&item).define() {
+                Error::push(&mut errors, error);
+            }
         }
 
-        Ok(())
+        Error::collect(errors)
     }
 
     ///
@@ -474,6 +566,66 @@ impl Scope {
         Ok(())
     }
 
+    ///
+    /// Declares a trait implementation in the implemented type `scope`, saving the trait
+    /// identifier and the implemented `methods` to check them later during the second pass.
+    ///
+    /// The item name cannot be written in the source code, so it is never resolved by name.
+    ///
+    pub fn declare_implementation(
+        scope: Rc<RefCell<Scope>>,
+        location: Location,
+        r#trait: Identifier,
+        methods: Vec<Identifier>,
+    ) -> Result<(), Error> {
+        let name = format!("{} {}", Keyword::Impl, r#trait.name);
+        if let Some(item) = RefCell::borrow(&scope).items.borrow().get(name.as_str()) {
+            return Err(Error::ScopeItemRedeclared {
+                location: r#trait.location,
+                name,
+                reference: RefCell::borrow(&item).location(),
+            });
+        }
+
+        let item = Item::Implementation(ImplementationItem::new_declared(
+            location,
+            r#trait,
+            methods,
+            scope.clone(),
+        ));
+
+        RefCell::borrow(&scope)
+            .items
+            .borrow_mut()
+            .insert(name, item.wrap());
+
+        Ok(())
+    }
+
+    ///
+    /// Checks whether the type, whose implementation `scope` is passed, implements the trait
+    /// with `type_id`.
+    ///
+    pub fn implements_trait(scope: Rc<RefCell<Scope>>, type_id: usize) -> Result<bool, Error> {
+        let implementations: Vec<Rc<RefCell<Item>>> = RefCell::borrow(&scope)
+            .items
+            .borrow()
+            .values()
+            .filter(|item| matches!(*RefCell::borrow(item), Item::Implementation(_)))
+            .cloned()
+            .collect();
+
+        for item in implementations.into_iter() {
+            if let Item::Implementation(ref implementation) = *RefCell::borrow(&item) {
+                if implementation.define()?.type_id == type_id {
+                    return Ok(true);
+                }
+            }
+        }
+
+        Ok(false)
+    }
+
     ///
     /// Defines a `contract` type, also checks whether it is the only contract in the scope.
     ///
@@ -631,6 +783,20 @@ impl Scope {
         }
     }
 
+    ///
+    /// Returns the items declared at the current scope level, sorted by their names.
+    ///
+    pub fn get_items(&self) -> Vec<(String, Rc<RefCell<Item>>)> {
+        let mut items: Vec<(String, Rc<RefCell<Item>>)> = self
+            .items
+            .borrow()
+            .iter()
+            .map(|(name, item)| (name.to_owned(), item.to_owned()))
+            .collect();
+        items.sort_by(|(a, _), (b, _)| a.cmp(b));
+        items
+    }
+
     ///
     /// Resolves the `std::collections::MTreeMap` type.
     ///
@@ -9,6 +9,7 @@ use zinc_lexical::Keyword;
 use zinc_lexical::Location;
 
 use crate::error::Error;
+use crate::semantic::element::r#type::Type;
 use crate::semantic::error::Error as SemanticError;
 use crate::source::Source;
 
@@ -1216,3 +1217,63 @@ fn main() -> u8 { Call { value: 42 }.call() }
 
     assert_eq!(result, expected);
 }
+
+#[test]
+fn error_multiple_items() {
+    let input = r#"
+fn first() -> bool {
+    42
+}
+
+fn second() -> u8 {
+    true
+}
+
+fn main() -> bool {
+    first()
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::Multiple(vec![
+        SemanticError::FunctionReturnType {
+            location: Location::test(3, 5),
+            function: "first".to_owned(),
+            expected: Type::boolean(None).to_string(),
+            found: Type::integer_unsigned(None, zinc_const::bitlength::BYTE).to_string(),
+            reference: Location::test(2, 15),
+        },
+        SemanticError::FunctionReturnType {
+            location: Location::test(7, 5),
+            function: "second".to_owned(),
+            expected: Type::integer_unsigned(None, zinc_const::bitlength::BYTE).to_string(),
+            found: Type::boolean(None).to_string(),
+            reference: Location::test(6, 16),
+        },
+    ])));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_multiple_reference_to_failed() {
+    let input = r#"
+const A: u8 = C;
+
+const B: u8 = A + 1;
+
+fn main() -> u8 {
+    B
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::ScopeItemUndeclared {
+        location: Location::test(2, 15),
+        name: "C".to_owned(),
+    }));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
@@ -35,6 +35,8 @@ pub enum Type {
     Loop,
     /// The ordinar expression block.
     Block,
+    /// The closure body, which cannot be interrupted with `return`, `break`, or `continue`.
+    Closure,
 }
 
 impl Type {
@@ -56,7 +56,9 @@ pub(crate) fn compile_module_with_modules(
     scope_crate: Rc<RefCell<Scope>>,
     scope_super: Rc<RefCell<Scope>>,
 ) -> Result<Rc<RefCell<Scope>>, Error> {
-    let module = Parser::default().parse(code, file)?;
+    let module = Parser::default()
+        .parse(code, file)
+        .map_err(|mut errors| Error::from(errors.remove(0)))?;
     let (module, implementation_scopes) = ModuleAnalyzer::declare(
         scope.clone(),
         module,
@@ -172,8 +172,8 @@ impl Directory {
     ) -> anyhow::Result<Rc<RefCell<Scope>>> {
         Ok(
             EntryAnalyzer::define(Source::Directory(self), project, dependencies, true)
-                .map_err(CompilerError::Semantic)
-                .map_err(|error| error.format())
+                .map_err(|error| vec![CompilerError::Semantic(error)])
+                .map_err(CompilerError::diagnostics)
                 .map_err(Error::Compiling)?,
         )
     }
@@ -193,8 +193,8 @@ impl Directory {
             dependencies,
             false,
         )
-        .map_err(CompilerError::Semantic)
-        .map_err(|error| error.format())
+        .map_err(|error| vec![CompilerError::Semantic(error)])
+        .map_err(CompilerError::diagnostics)
         .map_err(Error::Compiling)?;
 
         let state = ZincVMState::new(manifest).wrap();
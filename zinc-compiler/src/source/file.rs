@@ -59,8 +59,8 @@ impl File {
                     .as_str(),
                 next_file_id,
             )
-            .map_err(CompilerError::from)
-            .map_err(|error| error.format())
+            .map_err(|errors| errors.into_iter().map(CompilerError::from).collect())
+            .map_err(CompilerError::diagnostics)
             .map_err(Error::Compiling)?;
 
         Ok(Self {
@@ -114,8 +114,8 @@ impl File {
                     .as_str(),
                 next_file_id,
             )
-            .map_err(CompilerError::from)
-            .map_err(|error| error.format())
+            .map_err(|errors| errors.into_iter().map(CompilerError::from).collect())
+            .map_err(CompilerError::diagnostics)
             .map_err(Error::Compiling)?;
 
         Ok(Self {
@@ -137,8 +137,8 @@ impl File {
     ) -> anyhow::Result<Rc<RefCell<Scope>>> {
         Ok(
             EntryAnalyzer::define(Source::File(self), project, dependencies, true)
-                .map_err(CompilerError::Semantic)
-                .map_err(|error| error.format())
+                .map_err(|error| vec![CompilerError::Semantic(error)])
+                .map_err(CompilerError::diagnostics)
                 .map_err(Error::Compiling)?,
         )
     }
@@ -158,8 +158,8 @@ impl File {
             dependencies,
             false,
         )
-        .map_err(CompilerError::Semantic)
-        .map_err(|error| error.format())
+        .map_err(|error| vec![CompilerError::Semantic(error)])
+        .map_err(CompilerError::diagnostics)
         .map_err(Error::Compiling)?;
 
         let state = ZincVMState::new(manifest).wrap();
@@ -198,8 +198,8 @@ impl File {
 
         let tree = Parser::default()
             .parse(code, next_file_id)
-            .map_err(CompilerError::from)
-            .map_err(|error| error.format())
+            .map_err(|errors| errors.into_iter().map(CompilerError::from).collect())
+            .map_err(CompilerError::diagnostics)
             .map_err(Error::Compiling)?;
 
         FILE_INDEX.next(&path, code.to_owned());
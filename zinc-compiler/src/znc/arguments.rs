@@ -6,6 +6,8 @@ use std::path::PathBuf;
 
 use structopt::StructOpt;
 
+use zinc_compiler::MessageFormat;
+
 ///
 /// The Zinc compiler arguments.
 ///
@@ -39,6 +41,10 @@ pub struct Arguments {
     /// Enables the dead function code elimination optimization.
     #[structopt(long = "opt-dfe")]
     pub optimize_dead_function_elimination: bool,
+
+    /// The diagnostic messages format, either `human` or `json`.
+    #[structopt(long = "message-format", default_value = "human")]
+    pub message_format: MessageFormat,
 }
 
 impl Arguments {
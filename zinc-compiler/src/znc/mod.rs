@@ -13,6 +13,8 @@ use std::thread;
 use anyhow::Context;
 
 use zinc_compiler::Bundler;
+use zinc_compiler::MessageFormat;
+use zinc_compiler::SourceError;
 
 use self::arguments::Arguments;
 
@@ -20,10 +22,20 @@ use self::arguments::Arguments;
 /// The application entry point.
 ///
 fn main() {
-    process::exit(match main_inner() {
+    let args = Arguments::new();
+    let message_format = args.message_format;
+
+    process::exit(match main_inner(args) {
         Ok(()) => zinc_const::exit_code::SUCCESS,
         Err(error) => {
-            log::error!("{:?}", error);
+            match (message_format, error.downcast_ref::<SourceError>()) {
+                (MessageFormat::Json, Some(SourceError::Compiling(diagnostics))) => {
+                    for diagnostic in diagnostics.iter() {
+                        println!("{}", diagnostic.to_json());
+                    }
+                }
+                _ => log::error!("{:?}", error),
+            }
             zinc_const::exit_code::FAILURE
         }
     })
@@ -32,9 +44,7 @@ fn main() {
 ///
 /// The auxiliary `main` function to facilitate the `?` error conversion operator.
 ///
-fn main_inner() -> anyhow::Result<()> {
-    let args = Arguments::new();
-
+fn main_inner(args: Arguments) -> anyhow::Result<()> {
     zinc_logger::initialize(zinc_const::app_name::COMPILER, args.verbosity, args.quiet);
 
     let optimize_dead_function_elimination = args.optimize_dead_function_elimination;
@@ -22,3 +22,9 @@ pub static ZANDBOX: &str = "zandbox";
 
 /// The zkSync account public key changer default application name.
 pub static KEY_CHANGER: &str = "key-changer";
+
+/// The language server default application name.
+pub static LANGUAGE_SERVER: &str = "zls";
+
+/// The debug adapter default application name.
+pub static DEBUG_ADAPTER: &str = "zdap";
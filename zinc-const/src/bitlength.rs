@@ -23,6 +23,12 @@ pub const FIELD_PADDED: usize = FIELD + (BYTE - FIELD % BYTE);
 /// The `sha256` hash bitlength.
 pub const SHA256_HASH: usize = crate::size::SHA256_HASH * BYTE;
 
+/// The `blake2s` hash bitlength.
+pub const BLAKE2S_HASH: usize = crate::size::BLAKE2S_HASH * BYTE;
+
+/// The `keccak256` hash bitlength.
+pub const KECCAK256_HASH: usize = crate::size::KECCAK256_HASH * BYTE;
+
 /// The zkSync token ID bitlength.
 pub const TOKEN_ID: usize = BYTE * 2;
 
@@ -31,3 +37,6 @@ pub const BALANCE: usize = self::INTEGER_MAX;
 
 /// The ETH address bitlength.
 pub const ETH_ADDRESS: usize = crate::size::ETH_ADDRESS * BYTE;
+
+/// The ETH signature bitlength.
+pub const ETH_SIGNATURE: usize = crate::size::ETH_SIGNATURE * BYTE;
@@ -5,6 +5,9 @@
 /// The manifest file extension.
 pub static MANIFEST: &str = "toml";
 
+/// The lock file extension.
+pub static LOCK: &str = "lock";
+
 /// The source code file extension.
 pub static SOURCE: &str = "zn";
 
@@ -13,3 +16,6 @@ pub static BINARY: &str = "znb";
 
 /// The JSON data file extension.
 pub static JSON: &str = "json";
+
+/// The folded call stacks file extension.
+pub static FOLDED: &str = "folded";
@@ -5,6 +5,9 @@
 /// The project manifest file name.
 pub static MANIFEST: &str = "Zargo";
 
+/// The project lock file name.
+pub static LOCK: &str = "Zargo";
+
 /// The circuit or contract entry file name.
 pub static APPLICATION_ENTRY: &str = "main";
 
@@ -32,5 +35,8 @@ pub static VERIFYING_KEY: &str = "verifying_key";
 /// The private key file default name (testnet only!).
 pub static PRIVATE_KEY: &str = "private_key";
 
+/// The constraint profile file default name.
+pub static PROFILE: &str = "profile";
+
 /// The integration test scenario file default name.
 pub static SCENARIO: &str = "scenario";
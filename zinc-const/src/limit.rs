@@ -8,12 +8,27 @@ pub const PEDERSEN_HASH_INPUT_BYTES: usize = 64;
 /// The `pedersen` hash maximal input size in bits.
 pub const PEDERSEN_HASH_INPUT_BITS: usize = PEDERSEN_HASH_INPUT_BYTES * crate::bitlength::BYTE;
 
+/// The `poseidon` hash maximal input size in field elements.
+pub const POSEIDON_HASH_INPUT_FIELDS: usize = 16;
+
+/// The `rescue` hash maximal input size in field elements.
+pub const RESCUE_HASH_INPUT_FIELDS: usize = 16;
+
 /// The `schnorr` message maximal size in bytes.
 pub const SCHNORR_MESSAGE_BYTES: usize = 31;
 
 /// The `schnorr` message maximal size in bits.
 pub const SCHNORR_MESSAGE_BITS: usize = SCHNORR_MESSAGE_BYTES * crate::bitlength::BYTE;
 
+/// The `eddsa` message maximal size in bytes, which is the zkSync transaction message padding.
+pub const EDDSA_MESSAGE_BYTES: usize = 92;
+
+/// The `eddsa` message maximal size in bits.
+pub const EDDSA_MESSAGE_BITS: usize = EDDSA_MESSAGE_BYTES * crate::bitlength::BYTE;
+
+/// The `MTreeMap` sparse Merkle tree depth, that is, the number of key hash bits used as the slot.
+pub const MTREEMAP_DEPTH: usize = 32;
+
 /// The Zinc compiler inner thread stack size.
 pub const COMPILER_STACK_SIZE: usize = 64 * 1024 * 1024;
 
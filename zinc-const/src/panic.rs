@@ -26,6 +26,9 @@ pub static BUILDER_REQUIRES_VALUE: &str = "The builder requires a value: ";
 /// The unit test data validity is checked by the test authors.
 pub static TEST_DATA_VALID: &str = "Test data is always valid";
 
+/// The intrinsic library items validity is checked by the compiler authors.
+pub static INTRINSIC_ITEMS_VALID: &str = "Intrinsic items are always valid";
+
 /// The source code mapping compiler phase responsibility.
 pub static VALIDATED_DURING_SOURCE_CODE_MAPPING: &str = "Validated during source code mapping";
 
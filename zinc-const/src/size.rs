@@ -5,6 +5,12 @@
 /// The `sha256` hash size.
 pub const SHA256_HASH: usize = 32;
 
+/// The `blake2s` hash size.
+pub const BLAKE2S_HASH: usize = 32;
+
+/// The `keccak256` hash size.
+pub const KECCAK256_HASH: usize = 32;
+
 /// The ETH address size.
 pub const ETH_ADDRESS: usize = 20;
 
@@ -13,3 +19,6 @@ pub const ETH_PUBLIC_KEY: usize = 64;
 
 /// The ETH private key size.
 pub const ETH_PRIVATE_KEY: usize = 32;
+
+/// The ETH signature size, that is, `r`, `s` and the recovery byte `v`.
+pub const ETH_SIGNATURE: usize = 65;
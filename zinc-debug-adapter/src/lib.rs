@@ -0,0 +1,9 @@
+//!
+//! The Zinc debug adapter library.
+//!
+
+pub(crate) mod protocol;
+pub(crate) mod runner;
+pub(crate) mod server;
+
+pub use self::server::Server;
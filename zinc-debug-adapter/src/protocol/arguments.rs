@@ -0,0 +1,80 @@
+//!
+//! The debug adapter protocol request arguments.
+//!
+
+use std::path::PathBuf;
+
+use serde::Deserialize;
+
+///
+/// The `launch` request arguments.
+///
+/// Either the input file or the unit test name must be specified.
+///
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Launch {
+    /// The path to the binary bytecode file.
+    pub binary: PathBuf,
+    /// The path to the input JSON file.
+    pub input: Option<PathBuf>,
+    /// The method name to debug, if the application is a contract.
+    pub method: Option<String>,
+    /// The unit test name to debug instead of the entry function.
+    pub test: Option<String>,
+    /// Whether the execution stops at the first source code line.
+    #[serde(default)]
+    pub stop_on_entry: bool,
+    /// The project directory, which the bytecode source file paths are relative to.
+    pub cwd: Option<PathBuf>,
+}
+
+///
+/// The source file reference.
+///
+#[derive(Debug, Deserialize)]
+pub struct Source {
+    /// The source file path.
+    pub path: Option<String>,
+}
+
+///
+/// The source code line breakpoint.
+///
+#[derive(Debug, Deserialize)]
+pub struct SourceBreakpoint {
+    /// The line number, starting from 1.
+    pub line: usize,
+}
+
+///
+/// The `setBreakpoints` request arguments.
+///
+#[derive(Debug, Deserialize)]
+pub struct SetBreakpoints {
+    /// The source file, whose breakpoints are replaced.
+    pub source: Source,
+    /// The new breakpoints of the source file.
+    #[serde(default)]
+    pub breakpoints: Vec<SourceBreakpoint>,
+}
+
+///
+/// The `scopes` request arguments.
+///
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Scopes {
+    /// The stack frame identifier returned by the `stackTrace` request.
+    pub frame_id: usize,
+}
+
+///
+/// The `variables` request arguments.
+///
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Variables {
+    /// The reference returned by the `scopes` or `variables` request.
+    pub variables_reference: usize,
+}
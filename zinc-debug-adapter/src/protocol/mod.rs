@@ -0,0 +1,126 @@
+//!
+//! The debug adapter protocol.
+//!
+
+pub mod arguments;
+pub mod request;
+
+use std::io::BufRead;
+use std::io::Write;
+
+use serde::Serialize;
+
+use self::request::Request;
+
+/// The message header with the content length in bytes.
+const CONTENT_LENGTH: &str = "Content-Length:";
+
+///
+/// Reads a message, returning `None` at the end of the stream.
+///
+/// The message consists of the header lines terminated with an empty line, and the JSON content
+/// of the length specified in the `Content-Length` header.
+///
+pub fn read<R: BufRead>(reader: &mut R) -> anyhow::Result<Option<serde_json::Value>> {
+    let mut content_length = None;
+    loop {
+        let mut line = String::new();
+        if reader.read_line(&mut line)? == 0 {
+            return Ok(None);
+        }
+
+        let line = line.trim_end();
+        if line.is_empty() {
+            break;
+        }
+        if let Some(value) = line.strip_prefix(CONTENT_LENGTH) {
+            content_length = Some(value.trim().parse::<usize>()?);
+        }
+    }
+
+    let content_length = content_length
+        .ok_or_else(|| anyhow::anyhow!("the `{}` header is missing", CONTENT_LENGTH))?;
+    let mut content = vec![0; content_length];
+    reader.read_exact(content.as_mut_slice())?;
+
+    Ok(Some(serde_json::from_slice(content.as_slice())?))
+}
+
+///
+/// Writes the `message` with the `Content-Length` header.
+///
+pub fn write<W: Write, M: Serialize>(writer: &mut W, message: &M) -> anyhow::Result<()> {
+    let content = serde_json::to_vec(message)?;
+    write!(writer, "{} {}\r\n\r\n", CONTENT_LENGTH, content.len())?;
+    writer.write_all(content.as_slice())?;
+    writer.flush()?;
+
+    Ok(())
+}
+
+///
+/// The protocol message writer, which numbers the responses and events.
+///
+pub struct Writer<W: Write> {
+    /// The client output, usually the standard output.
+    inner: W,
+    /// The sequence number of the last message.
+    seq: usize,
+}
+
+impl<W: Write> Writer<W> {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(inner: W) -> Self {
+        Self { inner, seq: 0 }
+    }
+
+    ///
+    /// Writes the response to the `request`.
+    ///
+    /// The error is sent to the client as an unsuccessful response with the error message.
+    ///
+    pub fn response(
+        &mut self,
+        request: &Request,
+        result: Result<serde_json::Value, String>,
+    ) -> anyhow::Result<()> {
+        self.seq += 1;
+
+        let mut message = serde_json::json!({
+            "seq": self.seq,
+            "type": "response",
+            "request_seq": request.seq,
+            "command": request.command,
+        });
+        match result {
+            Ok(body) => {
+                message["success"] = serde_json::Value::Bool(true);
+                message["body"] = body;
+            }
+            Err(error) => {
+                message["success"] = serde_json::Value::Bool(false);
+                message["message"] = serde_json::Value::String(error);
+            }
+        }
+
+        write(&mut self.inner, &message)
+    }
+
+    ///
+    /// Writes the event with the `name` and `body`.
+    ///
+    pub fn event(&mut self, name: &str, body: serde_json::Value) -> anyhow::Result<()> {
+        self.seq += 1;
+
+        let message = serde_json::json!({
+            "seq": self.seq,
+            "type": "event",
+            "event": name,
+            "body": body,
+        });
+
+        write(&mut self.inner, &message)
+    }
+}
@@ -0,0 +1,28 @@
+//!
+//! The debug adapter protocol request.
+//!
+
+use serde::Deserialize;
+
+///
+/// The client request.
+///
+#[derive(Debug, Clone, Deserialize)]
+pub struct Request {
+    /// The sequence number of the request.
+    pub seq: usize,
+    /// The request command, e.g. `launch` or `stackTrace`.
+    pub command: String,
+    /// The command arguments.
+    #[serde(default)]
+    pub arguments: serde_json::Value,
+}
+
+impl Request {
+    ///
+    /// Parses the command arguments.
+    ///
+    pub fn arguments<A: serde::de::DeserializeOwned>(&self) -> Result<A, String> {
+        serde_json::from_value(self.arguments.to_owned()).map_err(|error| error.to_string())
+    }
+}
@@ -0,0 +1,21 @@
+//!
+//! The debug adapter runner command.
+//!
+
+use zinc_vm::DebuggerBreakpoint;
+use zinc_vm::DebuggerResume;
+
+///
+/// The command sent by the server to the virtual machine thread.
+///
+#[derive(Debug, Clone)]
+pub enum Command {
+    /// Resumes the stopped execution.
+    Resume(DebuggerResume),
+    /// Replaces the active breakpoints.
+    SetBreakpoints(Vec<DebuggerBreakpoint>),
+    /// Stops the running execution before the next source code line.
+    Pause,
+    /// Interrupts the execution.
+    Disconnect,
+}
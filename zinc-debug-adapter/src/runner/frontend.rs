@@ -0,0 +1,73 @@
+//!
+//! The debug adapter runner front-end.
+//!
+
+use std::sync::mpsc;
+
+use zinc_vm::DebuggerBreakpoint;
+use zinc_vm::DebuggerResume;
+use zinc_vm::DebuggerSession;
+use zinc_vm::IDebuggerFrontend;
+use zinc_vm::IEngine;
+
+use crate::runner::command::Command;
+use crate::runner::snapshot::Snapshot;
+use crate::server::message::Message;
+
+///
+/// The debugger front-end, which sends the stopped execution snapshots to the server and
+/// waits for its commands.
+///
+pub struct Frontend {
+    /// The server commands.
+    commands: mpsc::Receiver<Command>,
+    /// The server message queue.
+    messages: mpsc::Sender<Message>,
+}
+
+impl Frontend {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(commands: mpsc::Receiver<Command>, messages: mpsc::Sender<Message>) -> Self {
+        Self { commands, messages }
+    }
+}
+
+impl IDebuggerFrontend for Frontend {
+    fn stop<E: IEngine>(
+        &mut self,
+        session: &mut DebuggerSession<E>,
+    ) -> Result<DebuggerResume, zinc_vm::Error> {
+        self.messages
+            .send(Message::Stopped(Snapshot::new(session)))
+            .map_err(|_| zinc_vm::Error::DebuggerInterrupted)?;
+
+        loop {
+            match self.commands.recv() {
+                Ok(Command::Resume(resume)) => return Ok(resume),
+                Ok(Command::SetBreakpoints(breakpoints)) => *session.breakpoints = breakpoints,
+                Ok(Command::Pause) => {}
+                Ok(Command::Disconnect) | Err(mpsc::RecvError) => {
+                    return Err(zinc_vm::Error::DebuggerInterrupted)
+                }
+            }
+        }
+    }
+
+    fn poll(&mut self, breakpoints: &mut Vec<DebuggerBreakpoint>) -> Result<bool, zinc_vm::Error> {
+        let mut is_paused = false;
+
+        loop {
+            match self.commands.try_recv() {
+                Ok(Command::Resume(_resume)) => {}
+                Ok(Command::SetBreakpoints(new_breakpoints)) => *breakpoints = new_breakpoints,
+                Ok(Command::Pause) => is_paused = true,
+                Ok(Command::Disconnect) | Err(mpsc::TryRecvError::Disconnected) => {
+                    return Err(zinc_vm::Error::DebuggerInterrupted)
+                }
+                Err(mpsc::TryRecvError::Empty) => return Ok(is_paused),
+            }
+        }
+    }
+}
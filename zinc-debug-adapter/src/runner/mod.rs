@@ -0,0 +1,64 @@
+//!
+//! The debug adapter runner, which executes the application in the virtual machine thread.
+//!
+
+pub mod command;
+pub mod frontend;
+pub mod snapshot;
+pub mod target;
+
+use std::sync::mpsc;
+
+use zinc_vm::Bn256;
+use zinc_vm::CircuitFacade;
+use zinc_vm::ContractFacade;
+use zinc_vm::Debugger;
+use zinc_vm::DebuggerBreakpoint;
+
+use crate::server::message::Message;
+
+use self::command::Command;
+use self::frontend::Frontend;
+use self::target::Target;
+
+///
+/// Runs the `target` under the debugger controlled by the server `commands`.
+///
+/// The stops and the execution result are sent to the server `messages` queue. The output is
+/// `None` for unit tests and interrupted executions.
+///
+pub fn run(
+    target: Target,
+    breakpoints: Vec<DebuggerBreakpoint>,
+    stop_on_entry: bool,
+    commands: mpsc::Receiver<Command>,
+    messages: mpsc::Sender<Message>,
+) {
+    let frontend = Frontend::new(commands, messages.clone());
+    let mut debugger = Debugger::new(target.instructions(), breakpoints, stop_on_entry, frontend);
+
+    let result = match target {
+        Target::Circuit { circuit, arguments } => CircuitFacade::new(circuit)
+            .debug::<Bn256, _>(arguments, &mut debugger)
+            .map(|output| Some(output.result.into_json())),
+        Target::Contract { contract, input } => ContractFacade::new(contract)
+            .debug::<Bn256, _>(input, &mut debugger)
+            .map(|output| Some(output.result.into_json())),
+        Target::CircuitTest { circuit, name } => CircuitFacade::new(circuit)
+            .debug_test::<Bn256, _>(name.as_str(), &mut debugger)
+            .map(|()| None),
+        Target::ContractTest { contract, name } => ContractFacade::new(contract)
+            .debug_test::<Bn256, _>(name.as_str(), &mut debugger)
+            .map(|()| None),
+    };
+
+    let result = match result {
+        Ok(output) => Ok(output),
+        Err(zinc_vm::Error::DebuggerInterrupted) => Ok(None),
+        Err(error) => Err(error.to_string()),
+    };
+
+    if messages.send(Message::Exited(result)).is_err() {
+        log::debug!("The server has stopped before the execution end");
+    }
+}
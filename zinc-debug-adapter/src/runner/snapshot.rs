@@ -0,0 +1,113 @@
+//!
+//! The debug adapter stopped execution snapshot.
+//!
+
+use zinc_vm::DebuggerReason;
+use zinc_vm::DebuggerSession;
+use zinc_vm::IEngine;
+
+///
+/// The variable of a function frame.
+///
+#[derive(Debug, Clone)]
+pub struct Local {
+    /// The source code variable name.
+    pub name: String,
+    /// The variable type.
+    pub r#type: String,
+    /// The variable value, which is `None` if the variable is not initialized yet.
+    pub value: Option<serde_json::Value>,
+}
+
+///
+/// The function frame of the stopped execution.
+///
+#[derive(Debug, Clone)]
+pub struct Frame {
+    /// The function identifier.
+    pub function: String,
+    /// The source file path relative to the project directory.
+    pub file: Option<String>,
+    /// The line number of the next instruction to execute in the frame.
+    pub line: Option<usize>,
+    /// The variables of the function.
+    pub locals: Vec<Local>,
+    /// The data stack cells of the frame with their absolute addresses.
+    pub data_stack: Vec<(usize, Option<String>)>,
+    /// The storage of the contract instance referenced by the `self` variable.
+    pub storage: Option<serde_json::Value>,
+}
+
+///
+/// The stopped execution state, which is sent from the virtual machine thread to the server.
+///
+#[derive(Debug, Clone)]
+pub struct Snapshot {
+    /// The reason of the execution stop.
+    pub reason: DebuggerReason,
+    /// The function frames, starting from the current one.
+    pub frames: Vec<Frame>,
+    /// The evaluation stack values, starting from the top of the stack.
+    pub evaluation_stack: Vec<String>,
+}
+
+impl Snapshot {
+    ///
+    /// Copies the state of the stopped execution `session`.
+    ///
+    pub fn new<E: IEngine>(session: &DebuggerSession<E>) -> Self {
+        let storages = session.storages();
+
+        let frames = session
+            .frames()
+            .into_iter()
+            .map(|frame| {
+                let storage = frame
+                    .locals
+                    .iter()
+                    .find_map(|(variable, value)| match value {
+                        Some(zinc_types::Value::Scalar(zinc_types::ScalarValue::Integer(
+                            address,
+                            _,
+                        ))) if variable.name
+                            == zinc_lexical::Keyword::SelfLowercase.to_string() =>
+                        {
+                            Some(address)
+                        }
+                        _ => None,
+                    })
+                    .and_then(|address| {
+                        storages
+                            .iter()
+                            .find(|(storage_address, _storage)| storage_address == address)
+                    })
+                    .map(|(_address, storage)| storage.to_owned().into_json());
+
+                let locals = frame
+                    .locals
+                    .into_iter()
+                    .map(|(variable, value)| Local {
+                        name: variable.name.to_owned(),
+                        r#type: variable.r#type.to_string(),
+                        value: value.map(zinc_types::Value::into_json),
+                    })
+                    .collect();
+
+                Frame {
+                    function: frame.line.function_name().to_owned(),
+                    file: frame.line.file.to_owned(),
+                    line: frame.line.line,
+                    locals,
+                    data_stack: frame.data_stack,
+                    storage,
+                }
+            })
+            .collect();
+
+        Self {
+            reason: session.reason,
+            frames,
+            evaluation_stack: session.evaluation_stack(),
+        }
+    }
+}
@@ -0,0 +1,183 @@
+//!
+//! The debug adapter runner target.
+//!
+
+use std::collections::HashMap;
+use std::convert::TryFrom;
+use std::fs;
+use std::path::Path;
+
+use num::BigInt;
+use num::Zero;
+
+use zinc_vm::ContractInput;
+
+///
+/// The application entry to debug with its input data.
+///
+pub enum Target {
+    /// The circuit entry function.
+    Circuit {
+        /// The circuit bytecode.
+        circuit: zinc_types::Circuit,
+        /// The entry function arguments.
+        arguments: zinc_types::Value,
+    },
+    /// The contract method.
+    Contract {
+        /// The contract bytecode.
+        contract: zinc_types::Contract,
+        /// The method arguments, storages, and transaction.
+        input: ContractInput,
+    },
+    /// The circuit unit test.
+    CircuitTest {
+        /// The circuit bytecode.
+        circuit: zinc_types::Circuit,
+        /// The unit test name.
+        name: String,
+    },
+    /// The contract unit test.
+    ContractTest {
+        /// The contract bytecode.
+        contract: zinc_types::Contract,
+        /// The unit test name.
+        name: String,
+    },
+}
+
+impl Target {
+    ///
+    /// Reads the bytecode and the input files.
+    ///
+    /// If the unit test `name` is specified, the input file is not read.
+    ///
+    pub fn new(
+        binary_path: &Path,
+        input_path: Option<&Path>,
+        method: Option<String>,
+        test: Option<String>,
+    ) -> anyhow::Result<Self> {
+        let bytecode = fs::read(binary_path)
+            .map_err(|error| anyhow::anyhow!("{}: {}", binary_path.to_string_lossy(), error))?;
+        let application = zinc_types::Application::try_from_slice(bytecode.as_slice())
+            .map_err(|error| anyhow::anyhow!("application decoding: {}", error))?;
+
+        if let Some(name) = test {
+            return match application {
+                zinc_types::Application::Circuit(circuit) => {
+                    Ok(Self::CircuitTest { circuit, name })
+                }
+                zinc_types::Application::Contract(contract) => {
+                    Ok(Self::ContractTest { contract, name })
+                }
+                zinc_types::Application::Library(_library) => {
+                    anyhow::bail!("the library unit tests cannot be debugged")
+                }
+            };
+        }
+
+        let input_path =
+            input_path.ok_or_else(|| anyhow::anyhow!("the input file path is missing"))?;
+        let input_template = fs::read_to_string(input_path)
+            .map_err(|error| anyhow::anyhow!("{}: {}", input_path.to_string_lossy(), error))?;
+        let input: zinc_types::InputBuild = serde_json::from_str(input_template.as_str())?;
+
+        match (application, input) {
+            (
+                zinc_types::Application::Circuit(circuit),
+                zinc_types::InputBuild::Circuit { arguments },
+            ) => {
+                let arguments =
+                    zinc_types::Value::try_from_typed_json(arguments, circuit.input.clone())?;
+
+                Ok(Self::Circuit { circuit, arguments })
+            }
+            (
+                zinc_types::Application::Contract(contract),
+                zinc_types::InputBuild::Contract {
+                    arguments,
+                    msg: transaction,
+                    storages,
+                },
+            ) => {
+                let method_name =
+                    method.ok_or_else(|| anyhow::anyhow!("the contract method name is missing"))?;
+                let method = contract
+                    .methods
+                    .get(method_name.as_str())
+                    .cloned()
+                    .ok_or_else(|| anyhow::anyhow!("method `{}` does not exist", method_name))?;
+
+                let method_arguments =
+                    arguments
+                        .get(method_name.as_str())
+                        .cloned()
+                        .ok_or_else(|| {
+                            anyhow::anyhow!("method `{}` arguments are missing", method_name)
+                        })?;
+                let mut method_arguments =
+                    zinc_types::Value::try_from_typed_json(method_arguments, method.input)?;
+                if method_name != zinc_const::contract::CONSTRUCTOR_IDENTIFIER {
+                    method_arguments.insert_contract_instance(BigInt::zero());
+                }
+
+                let mut input_storages = HashMap::with_capacity(storages.len());
+                for (address, value) in storages.into_iter() {
+                    let address: zksync_types::Address =
+                        address.trim_start_matches("0x").parse().map_err(|_| {
+                            anyhow::anyhow!("invalid storage address `{}`", address)
+                        })?;
+
+                    let fields = match value {
+                        serde_json::Value::Array(fields) => fields,
+                        value => anyhow::bail!("invalid contract storage format `{}`", value),
+                    };
+                    let mut storage_values = Vec::with_capacity(contract.storage.len());
+                    for (field, value) in contract.storage.clone().into_iter().zip(fields) {
+                        storage_values.push(zinc_types::ContractFieldValue::new(
+                            field.name,
+                            zinc_types::Value::try_from_typed_json(value, field.r#type)?,
+                            field.is_public,
+                            field.is_implicit,
+                        ));
+                    }
+                    input_storages.insert(address, zinc_types::Value::Contract(storage_values));
+                }
+
+                let transaction = zinc_types::TransactionMsg::try_from(&transaction)
+                    .map_err(|error| anyhow::anyhow!("invalid transaction: {}", error))?;
+
+                Ok(Self::Contract {
+                    contract,
+                    input: ContractInput::new(
+                        method_arguments,
+                        input_storages,
+                        method_name,
+                        transaction,
+                    ),
+                })
+            }
+            (zinc_types::Application::Library(_library), _) => {
+                anyhow::bail!("libraries cannot be debugged, only their unit tests")
+            }
+            (_application, _input) => {
+                anyhow::bail!("the input file does not match the application type")
+            }
+        }
+    }
+
+    ///
+    /// Returns the bytecode instructions.
+    ///
+    pub fn instructions(&self) -> &[zinc_types::Instruction] {
+        match self {
+            Self::Circuit { circuit, .. } | Self::CircuitTest { circuit, .. } => {
+                circuit.instructions.as_slice()
+            }
+            Self::Contract { contract, .. } | Self::ContractTest { contract, .. } => {
+                contract.instructions.as_slice()
+            }
+        }
+    }
+}
@@ -0,0 +1,24 @@
+//!
+//! The debug adapter server message.
+//!
+
+use crate::protocol::request::Request;
+use crate::runner::snapshot::Snapshot;
+
+///
+/// The message handled by the server loop.
+///
+/// The client requests and the virtual machine events are sent to a single queue, so the
+/// server handles them in order.
+///
+#[derive(Debug)]
+pub enum Message {
+    /// The client request.
+    Request(Request),
+    /// The client has closed the connection.
+    Closed,
+    /// The execution has stopped before a source code line.
+    Stopped(Snapshot),
+    /// The execution has finished with the output JSON or an error.
+    Exited(Result<Option<serde_json::Value>, String>),
+}
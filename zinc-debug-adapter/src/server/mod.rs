@@ -0,0 +1,651 @@
+//!
+//! The debug adapter server.
+//!
+
+#[cfg(test)]
+mod tests;
+
+pub mod message;
+pub mod reference;
+
+use std::collections::BTreeMap;
+use std::env;
+use std::io::BufRead;
+use std::io::Write;
+use std::path::Path;
+use std::path::PathBuf;
+use std::sync::mpsc;
+use std::thread;
+
+use zinc_vm::DebugInfo;
+use zinc_vm::DebuggerBreakpoint;
+use zinc_vm::DebuggerReason;
+use zinc_vm::DebuggerResume;
+
+use crate::protocol;
+use crate::protocol::arguments::Launch as LaunchArguments;
+use crate::protocol::arguments::Scopes as ScopesArguments;
+use crate::protocol::arguments::SetBreakpoints as SetBreakpointsArguments;
+use crate::protocol::arguments::Variables as VariablesArguments;
+use crate::protocol::request::Request;
+use crate::protocol::Writer;
+use crate::runner;
+use crate::runner::command::Command;
+use crate::runner::snapshot::Snapshot;
+use crate::runner::target::Target;
+
+use self::message::Message;
+use self::reference::Reference;
+
+///
+/// The debug adapter server, which handles the client requests and controls the virtual machine
+/// thread.
+///
+/// The virtual machine runs the application with a single thread of execution, which is
+/// reported to the client as the thread with the `THREAD_ID` identifier.
+///
+pub struct Server<W: Write> {
+    /// The client output.
+    writer: Writer<W>,
+    /// The message queue sender, which is cloned to the reader and virtual machine threads.
+    sender: mpsc::Sender<Message>,
+    /// The message queue receiver.
+    receiver: mpsc::Receiver<Message>,
+
+    /// The project directory, which the bytecode source file paths are relative to.
+    project_path: PathBuf,
+    /// The launched application, which is waiting for the configuration to be done.
+    target: Option<Target>,
+    /// Whether the execution stops at the first source code line.
+    stop_on_entry: bool,
+    /// The source code lines of the launched application.
+    debug_info: Option<DebugInfo>,
+    /// The breakpoint line numbers with the source file paths as keys.
+    breakpoints: BTreeMap<String, Vec<usize>>,
+
+    /// The virtual machine thread command sender, which is set while the execution is running.
+    commands: Option<mpsc::Sender<Command>>,
+    /// The virtual machine thread handle.
+    runner: Option<thread::JoinHandle<()>>,
+    /// The state of the stopped execution.
+    snapshot: Option<Snapshot>,
+    /// The variables containers, whose indexes starting from 1 are sent to the client.
+    references: Vec<Reference>,
+}
+
+impl<W: Write> Server<W> {
+    /// The identifier of the only thread of execution.
+    const THREAD_ID: usize = 1;
+
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(writer: W) -> Self {
+        let (sender, receiver) = mpsc::channel();
+
+        Self {
+            writer: Writer::new(writer),
+            sender,
+            receiver,
+
+            project_path: PathBuf::new(),
+            target: None,
+            stop_on_entry: false,
+            debug_info: None,
+            breakpoints: BTreeMap::new(),
+
+            commands: None,
+            runner: None,
+            snapshot: None,
+            references: Vec::new(),
+        }
+    }
+
+    ///
+    /// The server capabilities sent to the client during the initialization.
+    ///
+    pub fn capabilities() -> serde_json::Value {
+        serde_json::json!({
+            "supportsConfigurationDoneRequest": true,
+        })
+    }
+
+    ///
+    /// Runs the server loop until the client disconnects or closes the connection.
+    ///
+    /// The requests are read from the `reader` in a separate thread, so the pause requests are
+    /// handled while the virtual machine is running.
+    ///
+    pub fn run<R: BufRead + Send + 'static>(mut self, mut reader: R) -> anyhow::Result<()> {
+        let sender = self.sender.clone();
+        thread::spawn(move || {
+            loop {
+                let message = match protocol::read(&mut reader) {
+                    Ok(Some(message)) => message,
+                    Ok(None) => break,
+                    Err(error) => {
+                        log::error!("Reading error: {}", error);
+                        break;
+                    }
+                };
+                if message["type"] != "request" {
+                    log::debug!("Message ignored: {}", message);
+                    continue;
+                }
+
+                let request = match serde_json::from_value::<Request>(message) {
+                    Ok(request) => request,
+                    Err(error) => {
+                        log::error!("Invalid request: {}", error);
+                        continue;
+                    }
+                };
+                if sender.send(Message::Request(request)).is_err() {
+                    return;
+                }
+            }
+            let _ = sender.send(Message::Closed);
+        });
+
+        while let Ok(message) = self.receiver.recv() {
+            match message {
+                Message::Request(request) => {
+                    log::debug!("Request `{}`", request.command);
+                    if !self.request(request)? {
+                        break;
+                    }
+                }
+                Message::Closed => break,
+                Message::Stopped(snapshot) => self.stopped(snapshot)?,
+                Message::Exited(result) => self.exited(result)?,
+            }
+        }
+
+        if let Some(commands) = self.commands.take() {
+            let _ = commands.send(Command::Disconnect);
+        }
+        if let Some(runner) = self.runner.take() {
+            if runner.join().is_err() {
+                log::error!("The virtual machine thread has panicked");
+            }
+        }
+
+        Ok(())
+    }
+
+    ///
+    /// Handles a client request.
+    ///
+    /// Returns `false` if the client has disconnected.
+    ///
+    fn request(&mut self, request: Request) -> anyhow::Result<bool> {
+        let result = match request.command.as_str() {
+            "initialize" => Ok(Self::capabilities()),
+            "launch" => request
+                .arguments()
+                .and_then(|arguments| self.launch(arguments)),
+            "setBreakpoints" => request
+                .arguments()
+                .and_then(|arguments| self.set_breakpoints(arguments)),
+            "setExceptionBreakpoints" => Ok(serde_json::json!({})),
+            "configurationDone" => self.configuration_done(),
+            "threads" => Ok(serde_json::json!({
+                "threads": [{ "id": Self::THREAD_ID, "name": "main" }],
+            })),
+            "stackTrace" => self.stack_trace(),
+            "scopes" => request
+                .arguments()
+                .and_then(|arguments| self.scopes(arguments)),
+            "variables" => request
+                .arguments()
+                .and_then(|arguments| self.variables(arguments)),
+            "continue" => self
+                .resume(DebuggerResume::Continue)
+                .map(|()| serde_json::json!({ "allThreadsContinued": true })),
+            "next" => self
+                .resume(DebuggerResume::Next)
+                .map(|()| serde_json::json!({})),
+            "stepIn" => self
+                .resume(DebuggerResume::Step)
+                .map(|()| serde_json::json!({})),
+            "stepOut" => self
+                .resume(DebuggerResume::Finish)
+                .map(|()| serde_json::json!({})),
+            "pause" => self.send(Command::Pause).map(|()| serde_json::json!({})),
+            "disconnect" => {
+                self.writer.response(&request, Ok(serde_json::json!({})))?;
+                return Ok(false);
+            }
+            command => Err(format!("command `{}` is not supported", command)),
+        };
+
+        let is_launched = request.command == "launch" && result.is_ok();
+        self.writer.response(&request, result)?;
+        if is_launched {
+            self.writer.event("initialized", serde_json::json!({}))?;
+        }
+
+        Ok(true)
+    }
+
+    ///
+    /// Reads the application and its input, which are run after the configuration is done.
+    ///
+    fn launch(&mut self, arguments: LaunchArguments) -> Result<serde_json::Value, String> {
+        self.project_path = match arguments.cwd {
+            Some(cwd) => cwd,
+            None => env::current_dir().map_err(|error| error.to_string())?,
+        };
+
+        let binary_path = self.project_path.join(arguments.binary);
+        let input_path = arguments.input.map(|path| self.project_path.join(path));
+        let target = Target::new(
+            binary_path.as_path(),
+            input_path.as_deref(),
+            arguments.method,
+            arguments.test,
+        )
+        .map_err(|error| error.to_string())?;
+
+        self.debug_info = Some(DebugInfo::new(target.instructions()));
+        self.target = Some(target);
+        self.stop_on_entry = arguments.stop_on_entry;
+
+        Ok(serde_json::json!({}))
+    }
+
+    ///
+    /// Replaces the breakpoints of the source file.
+    ///
+    /// The breakpoint is verified if there is an instruction at its line.
+    ///
+    fn set_breakpoints(
+        &mut self,
+        arguments: SetBreakpointsArguments,
+    ) -> Result<serde_json::Value, String> {
+        let path = arguments
+            .source
+            .path
+            .ok_or_else(|| "the source file path is missing".to_owned())?;
+        let lines: Vec<usize> = arguments
+            .breakpoints
+            .into_iter()
+            .map(|breakpoint| breakpoint.line)
+            .collect();
+
+        let breakpoints: Vec<serde_json::Value> = lines
+            .iter()
+            .map(|line| {
+                let is_verified =
+                    self.debug_info
+                        .as_ref()
+                        .map(|debug_info| {
+                            debug_info.lines.iter().any(|instruction_line| {
+                                instruction_line.matches(path.as_str(), *line)
+                            })
+                        })
+                        .unwrap_or_default();
+
+                serde_json::json!({
+                    "verified": is_verified,
+                    "line": line,
+                    "source": { "path": path },
+                })
+            })
+            .collect();
+
+        if lines.is_empty() {
+            self.breakpoints.remove(path.as_str());
+        } else {
+            self.breakpoints.insert(path, lines);
+        }
+        if self.commands.is_some() {
+            let _ = self.send(Command::SetBreakpoints(self.debugger_breakpoints()));
+        }
+
+        Ok(serde_json::json!({ "breakpoints": breakpoints }))
+    }
+
+    ///
+    /// Starts the virtual machine thread.
+    ///
+    fn configuration_done(&mut self) -> Result<serde_json::Value, String> {
+        let target = self
+            .target
+            .take()
+            .ok_or_else(|| "the application is not launched".to_owned())?;
+
+        let (commands, receiver) = mpsc::channel();
+        let messages = self.sender.clone();
+        let breakpoints = self.debugger_breakpoints();
+        let stop_on_entry = self.stop_on_entry;
+        let runner = thread::Builder::new()
+            .name(zinc_const::app_name::VIRTUAL_MACHINE.to_owned())
+            .spawn(move || runner::run(target, breakpoints, stop_on_entry, receiver, messages))
+            .map_err(|error| error.to_string())?;
+
+        self.commands = Some(commands);
+        self.runner = Some(runner);
+
+        Ok(serde_json::json!({}))
+    }
+
+    ///
+    /// Returns the function frames of the stopped execution.
+    ///
+    fn stack_trace(&self) -> Result<serde_json::Value, String> {
+        let snapshot = self.snapshot()?;
+
+        let frames: Vec<serde_json::Value> = snapshot
+            .frames
+            .iter()
+            .enumerate()
+            .map(|(index, frame)| {
+                let mut stack_frame = serde_json::json!({
+                    "id": index,
+                    "name": frame.function,
+                    "line": frame.line.unwrap_or_default(),
+                    "column": 1,
+                });
+                if let Some(file) = frame.file.as_deref() {
+                    let path = self.source_path(file);
+                    stack_frame["source"] = serde_json::json!({
+                        "name": path.file_name().map(|name| name.to_string_lossy()),
+                        "path": path.to_string_lossy(),
+                    });
+                }
+                stack_frame
+            })
+            .collect();
+
+        Ok(serde_json::json!({
+            "stackFrames": frames,
+            "totalFrames": frames.len(),
+        }))
+    }
+
+    ///
+    /// Returns the variables scopes of the function frame.
+    ///
+    /// The storage scope is only available in the contract methods.
+    ///
+    fn scopes(&mut self, arguments: ScopesArguments) -> Result<serde_json::Value, String> {
+        let frame = self
+            .snapshot()?
+            .frames
+            .get(arguments.frame_id)
+            .ok_or_else(|| format!("stack frame {} does not exist", arguments.frame_id))?;
+        let has_storage = frame.storage.is_some();
+
+        let mut scopes = vec![
+            self.scope("Locals", Reference::Locals(arguments.frame_id)),
+            self.scope("Data stack", Reference::DataStack(arguments.frame_id)),
+            self.scope("Evaluation stack", Reference::EvaluationStack),
+        ];
+        if has_storage {
+            scopes.push(self.scope("Storage", Reference::Storage(arguments.frame_id)));
+        }
+
+        Ok(serde_json::json!({ "scopes": scopes }))
+    }
+
+    ///
+    /// Expands the variables container.
+    ///
+    fn variables(&mut self, arguments: VariablesArguments) -> Result<serde_json::Value, String> {
+        let reference = arguments
+            .variables_reference
+            .checked_sub(1)
+            .and_then(|index| self.references.get(index))
+            .cloned()
+            .ok_or_else(|| {
+                format!(
+                    "variables reference {} does not exist",
+                    arguments.variables_reference
+                )
+            })?;
+
+        let snapshot = self
+            .snapshot
+            .as_ref()
+            .ok_or_else(|| "the execution is not stopped".to_owned())?;
+        let references = &mut self.references;
+
+        let variables: Vec<serde_json::Value> = match reference {
+            Reference::Locals(frame) => snapshot.frames[frame]
+                .locals
+                .iter()
+                .map(|local| {
+                    Self::variable(
+                        references,
+                        local.name.to_owned(),
+                        local.value.as_ref(),
+                        Some(local.r#type.as_str()),
+                    )
+                })
+                .collect(),
+            Reference::DataStack(frame) => snapshot.frames[frame]
+                .data_stack
+                .iter()
+                .map(|(address, value)| {
+                    let value = value.to_owned().map(serde_json::Value::String);
+                    Self::variable(references, format!("[{}]", address), value.as_ref(), None)
+                })
+                .collect(),
+            Reference::EvaluationStack => snapshot
+                .evaluation_stack
+                .iter()
+                .enumerate()
+                .map(|(index, value)| {
+                    let value = serde_json::Value::String(value.to_owned());
+                    Self::variable(references, index.to_string(), Some(&value), None)
+                })
+                .collect(),
+            Reference::Storage(frame) => match snapshot.frames[frame].storage.as_ref() {
+                Some(storage) => Self::children(references, storage),
+                None => vec![],
+            },
+            Reference::Value(value) => Self::children(references, &value),
+        };
+
+        Ok(serde_json::json!({ "variables": variables }))
+    }
+
+    ///
+    /// Resumes the stopped execution.
+    ///
+    fn resume(&mut self, resume: DebuggerResume) -> Result<(), String> {
+        if self.snapshot.take().is_none() {
+            return Err("the execution is not stopped".to_owned());
+        }
+        self.references.clear();
+
+        self.send(Command::Resume(resume))
+    }
+
+    ///
+    /// Sends the `command` to the virtual machine thread.
+    ///
+    fn send(&self, command: Command) -> Result<(), String> {
+        self.commands
+            .as_ref()
+            .ok_or_else(|| "the execution is not running".to_owned())?
+            .send(command)
+            .map_err(|_| "the execution has finished".to_owned())
+    }
+
+    ///
+    /// Notifies the client about the execution stop.
+    ///
+    fn stopped(&mut self, snapshot: Snapshot) -> anyhow::Result<()> {
+        let reason = match snapshot.reason {
+            DebuggerReason::Entry => "entry",
+            DebuggerReason::Breakpoint => "breakpoint",
+            DebuggerReason::Step => "step",
+            DebuggerReason::Pause => "pause",
+        };
+
+        self.references.clear();
+        self.snapshot = Some(snapshot);
+
+        self.writer.event(
+            "stopped",
+            serde_json::json!({
+                "reason": reason,
+                "threadId": Self::THREAD_ID,
+                "allThreadsStopped": true,
+            }),
+        )
+    }
+
+    ///
+    /// Writes the execution output or error and notifies the client about the execution end.
+    ///
+    fn exited(&mut self, result: Result<Option<serde_json::Value>, String>) -> anyhow::Result<()> {
+        self.commands = None;
+        self.snapshot = None;
+        self.references.clear();
+        if let Some(runner) = self.runner.take() {
+            if runner.join().is_err() {
+                log::error!("The virtual machine thread has panicked");
+            }
+        }
+
+        let exit_code = match result {
+            Ok(Some(output)) => {
+                self.output("stdout", serde_json::to_string_pretty(&output)? + "\n")?;
+                zinc_const::exit_code::SUCCESS
+            }
+            Ok(None) => zinc_const::exit_code::SUCCESS,
+            Err(error) => {
+                self.output("stderr", format!("error: {}\n", error))?;
+                zinc_const::exit_code::FAILURE
+            }
+        };
+
+        self.writer
+            .event("exited", serde_json::json!({ "exitCode": exit_code }))?;
+        self.writer.event("terminated", serde_json::json!({}))
+    }
+
+    ///
+    /// Writes the `output` to the client console.
+    ///
+    fn output(&mut self, category: &str, output: String) -> anyhow::Result<()> {
+        self.writer.event(
+            "output",
+            serde_json::json!({
+                "category": category,
+                "output": output,
+            }),
+        )
+    }
+
+    ///
+    /// Returns the state of the stopped execution.
+    ///
+    fn snapshot(&self) -> Result<&Snapshot, String> {
+        self.snapshot
+            .as_ref()
+            .ok_or_else(|| "the execution is not stopped".to_owned())
+    }
+
+    ///
+    /// Creates a variables scope with the `name`.
+    ///
+    fn scope(&mut self, name: &str, reference: Reference) -> serde_json::Value {
+        self.references.push(reference);
+
+        serde_json::json!({
+            "name": name,
+            "variablesReference": self.references.len(),
+            "expensive": false,
+        })
+    }
+
+    ///
+    /// Creates the variables of the structure fields or the array elements.
+    ///
+    fn children(
+        references: &mut Vec<Reference>,
+        value: &serde_json::Value,
+    ) -> Vec<serde_json::Value> {
+        match value {
+            serde_json::Value::Object(fields) => fields
+                .iter()
+                .map(|(name, value)| Self::variable(references, name.to_owned(), Some(value), None))
+                .collect(),
+            serde_json::Value::Array(elements) => elements
+                .iter()
+                .enumerate()
+                .map(|(index, value)| {
+                    Self::variable(references, index.to_string(), Some(value), None)
+                })
+                .collect(),
+            _ => vec![],
+        }
+    }
+
+    ///
+    /// Creates a variable, adding a reference to its fields or elements if there are any.
+    ///
+    /// The value is shown as `<unknown>` if the variable is not initialized yet.
+    ///
+    fn variable(
+        references: &mut Vec<Reference>,
+        name: String,
+        value: Option<&serde_json::Value>,
+        r#type: Option<&str>,
+    ) -> serde_json::Value {
+        let (value, reference) = match value {
+            Some(serde_json::Value::String(string)) => (string.to_owned(), 0),
+            Some(serde_json::Value::Array(elements)) if !elements.is_empty() => {
+                references.push(Reference::Value(serde_json::Value::Array(
+                    elements.to_owned(),
+                )));
+                (format!("[{}]", elements.len()), references.len())
+            }
+            Some(serde_json::Value::Object(fields)) if !fields.is_empty() => {
+                references.push(Reference::Value(serde_json::Value::Object(
+                    fields.to_owned(),
+                )));
+                ("{...}".to_owned(), references.len())
+            }
+            Some(value) => (value.to_string(), 0),
+            None => ("<unknown>".to_owned(), 0),
+        };
+
+        let mut variable = serde_json::json!({
+            "name": name,
+            "value": value,
+            "variablesReference": reference,
+        });
+        if let Some(r#type) = r#type {
+            variable["type"] = serde_json::Value::String(r#type.to_owned());
+        }
+        variable
+    }
+
+    ///
+    /// Returns the breakpoints of all the source files.
+    ///
+    fn debugger_breakpoints(&self) -> Vec<DebuggerBreakpoint> {
+        self.breakpoints
+            .iter()
+            .flat_map(|(path, lines)| {
+                lines
+                    .iter()
+                    .map(move |line| DebuggerBreakpoint::new(path.to_owned(), *line))
+            })
+            .collect()
+    }
+
+    ///
+    /// Returns the path of the bytecode source `file` in the project directory.
+    ///
+    fn source_path(&self, file: &str) -> PathBuf {
+        let file = Path::new(file);
+        self.project_path
+            .join(file.strip_prefix(".").unwrap_or(file))
+    }
+}
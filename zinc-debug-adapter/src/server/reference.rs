@@ -0,0 +1,22 @@
+//!
+//! The debug adapter variables reference.
+//!
+
+///
+/// The variables container, which is expanded by the `variables` request.
+///
+/// The references are only valid while the execution is stopped.
+///
+#[derive(Debug, Clone)]
+pub enum Reference {
+    /// The variables of the function frame with the index.
+    Locals(usize),
+    /// The data stack cells of the function frame with the index.
+    DataStack(usize),
+    /// The evaluation stack values.
+    EvaluationStack,
+    /// The contract storage fields of the function frame with the index.
+    Storage(usize),
+    /// The structure fields or the array elements.
+    Value(serde_json::Value),
+}
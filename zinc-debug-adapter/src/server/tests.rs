@@ -0,0 +1,246 @@
+//!
+//! The debug adapter server tests.
+//!
+
+use std::collections::HashMap;
+use std::fs;
+use std::io;
+use std::io::BufRead;
+use std::io::BufReader;
+use std::io::Read;
+use std::io::Write;
+use std::path::PathBuf;
+use std::process;
+use std::sync::mpsc;
+use std::thread;
+
+use num::BigInt;
+
+use crate::protocol;
+use crate::server::Server;
+
+///
+/// The reading end of the in-memory connection.
+///
+struct PipeReader {
+    receiver: mpsc::Receiver<Vec<u8>>,
+    buffer: io::Cursor<Vec<u8>>,
+}
+
+impl Read for PipeReader {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        loop {
+            let size = self.buffer.read(buf)?;
+            if size > 0 {
+                return Ok(size);
+            }
+
+            match self.receiver.recv() {
+                Ok(data) => self.buffer = io::Cursor::new(data),
+                Err(mpsc::RecvError) => return Ok(0),
+            }
+        }
+    }
+}
+
+///
+/// The writing end of the in-memory connection.
+///
+struct PipeWriter(mpsc::Sender<Vec<u8>>);
+
+impl Write for PipeWriter {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.0
+            .send(buf.to_vec())
+            .map_err(|_| io::Error::from(io::ErrorKind::BrokenPipe))?;
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+fn pipe() -> (BufReader<PipeReader>, PipeWriter) {
+    let (sender, receiver) = mpsc::channel();
+    let reader = PipeReader {
+        receiver,
+        buffer: io::Cursor::new(vec![]),
+    };
+    (BufReader::new(reader), PipeWriter(sender))
+}
+
+///
+/// The test client, which sends the requests and checks the server messages.
+///
+struct Client<R: BufRead> {
+    reader: R,
+    writer: PipeWriter,
+    seq: usize,
+}
+
+impl<R: BufRead> Client<R> {
+    fn request(&mut self, command: &str, arguments: serde_json::Value) {
+        self.seq += 1;
+        protocol::write(
+            &mut self.writer,
+            &serde_json::json!({
+                "seq": self.seq,
+                "type": "request",
+                "command": command,
+                "arguments": arguments,
+            }),
+        )
+        .expect(zinc_const::panic::TEST_DATA_VALID);
+    }
+
+    fn receive(&mut self) -> serde_json::Value {
+        protocol::read(&mut self.reader)
+            .expect(zinc_const::panic::TEST_DATA_VALID)
+            .expect(zinc_const::panic::TEST_DATA_VALID)
+    }
+
+    fn response(&mut self, command: &str) -> serde_json::Value {
+        let message = self.receive();
+        assert_eq!(message["type"], "response");
+        assert_eq!(message["command"], command);
+        assert_eq!(message["success"], true, "{}", message);
+        message["body"].to_owned()
+    }
+
+    fn event(&mut self, name: &str) -> serde_json::Value {
+        let message = self.receive();
+        assert_eq!(message["type"], "event");
+        assert_eq!(message["event"], name, "{}", message);
+        message["body"].to_owned()
+    }
+}
+
+fn project() -> PathBuf {
+    let instructions = vec![
+        zinc_types::FileMarker::new("./src/main.zn".to_owned()).into(),
+        zinc_types::FunctionMarker::new("main".to_owned()).into(),
+        zinc_types::VariableMarker::new(
+            "a".to_owned(),
+            0,
+            zinc_types::Type::Scalar(zinc_types::ScalarType::Field),
+        )
+        .into(),
+        zinc_types::LineMarker::new(2).into(),
+        zinc_types::Push::new_field(BigInt::from(5)).into(),
+        zinc_types::Store::new(0, 1).into(),
+        zinc_types::LineMarker::new(3).into(),
+        zinc_types::Load::new(0, 1).into(),
+        zinc_types::Return::new(1).into(),
+    ];
+    let circuit = zinc_types::Circuit::new(
+        "test".to_owned(),
+        0,
+        zinc_types::Type::Unit,
+        zinc_types::Type::Scalar(zinc_types::ScalarType::Field),
+        HashMap::new(),
+        instructions,
+    );
+
+    let path = std::env::temp_dir().join(format!("zdap-test-{}", process::id()));
+    fs::create_dir_all(&path).expect(zinc_const::panic::TEST_DATA_VALID);
+    fs::write(
+        path.join("main.znb"),
+        zinc_types::Application::Circuit(circuit).into_vec(),
+    )
+    .expect(zinc_const::panic::TEST_DATA_VALID);
+    fs::write(
+        path.join("input.json"),
+        serde_json::json!({ "type": "circuit", "arguments": null }).to_string(),
+    )
+    .expect(zinc_const::panic::TEST_DATA_VALID);
+
+    path
+}
+
+#[test]
+fn ok_breakpoint_and_variables() {
+    let path = project();
+    let source_path = path.join("src").join("main.zn");
+
+    let (server_reader, client_writer) = pipe();
+    let (client_reader, server_writer) = pipe();
+    let server = thread::spawn(move || Server::new(server_writer).run(server_reader));
+    let mut client = Client {
+        reader: client_reader,
+        writer: client_writer,
+        seq: 0,
+    };
+
+    client.request("initialize", serde_json::json!({ "adapterID": "zinc" }));
+    let body = client.response("initialize");
+    assert_eq!(body["supportsConfigurationDoneRequest"], true);
+
+    client.request(
+        "launch",
+        serde_json::json!({
+            "binary": "main.znb",
+            "input": "input.json",
+            "cwd": path,
+        }),
+    );
+    client.response("launch");
+    client.event("initialized");
+
+    client.request(
+        "setBreakpoints",
+        serde_json::json!({
+            "source": { "path": source_path },
+            "breakpoints": [{ "line": 3 }, { "line": 10 }],
+        }),
+    );
+    let body = client.response("setBreakpoints");
+    assert_eq!(body["breakpoints"][0]["verified"], true);
+    assert_eq!(body["breakpoints"][1]["verified"], false);
+
+    client.request("configurationDone", serde_json::json!({}));
+    client.response("configurationDone");
+    let body = client.event("stopped");
+    assert_eq!(body["reason"], "breakpoint");
+
+    client.request("stackTrace", serde_json::json!({ "threadId": 1 }));
+    let body = client.response("stackTrace");
+    assert_eq!(body["totalFrames"], 1);
+    assert_eq!(body["stackFrames"][0]["name"], "main");
+    assert_eq!(body["stackFrames"][0]["line"], 3);
+    assert_eq!(
+        body["stackFrames"][0]["source"]["path"],
+        source_path.to_string_lossy().as_ref()
+    );
+
+    client.request("scopes", serde_json::json!({ "frameId": 0 }));
+    let body = client.response("scopes");
+    assert_eq!(body["scopes"][0]["name"], "Locals");
+    let reference = body["scopes"][0]["variablesReference"].to_owned();
+
+    client.request(
+        "variables",
+        serde_json::json!({ "variablesReference": reference }),
+    );
+    let body = client.response("variables");
+    assert_eq!(body["variables"][0]["name"], "a");
+    assert_eq!(body["variables"][0]["value"], "0x5");
+    assert_eq!(body["variables"][0]["type"], "field");
+
+    client.request("continue", serde_json::json!({ "threadId": 1 }));
+    client.response("continue");
+    let body = client.event("output");
+    assert_eq!(body["output"], "\"0x5\"\n");
+    let body = client.event("exited");
+    assert_eq!(body["exitCode"], 0);
+    client.event("terminated");
+
+    client.request("disconnect", serde_json::json!({}));
+    client.response("disconnect");
+
+    server
+        .join()
+        .expect(zinc_const::panic::SYNCHRONIZATION)
+        .expect(zinc_const::panic::TEST_DATA_VALID);
+    fs::remove_dir_all(path).expect(zinc_const::panic::TEST_DATA_VALID);
+}
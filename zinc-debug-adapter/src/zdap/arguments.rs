@@ -0,0 +1,32 @@
+//!
+//! The Zinc debug adapter arguments.
+//!
+
+use structopt::StructOpt;
+
+///
+/// The Zinc debug adapter arguments.
+///
+#[derive(Debug, StructOpt)]
+#[structopt(
+    name = zinc_const::app_name::DEBUG_ADAPTER,
+    about = "The Zinc debug adapter"
+)]
+pub struct Arguments {
+    /// Prints more logs, if passed several times.
+    #[structopt(short = "v", long = "verbose", parse(from_occurrences))]
+    pub verbosity: usize,
+
+    /// Suppresses output, if set.
+    #[structopt(short = "q", long = "quiet")]
+    pub quiet: bool,
+}
+
+impl Arguments {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new() -> Self {
+        Self::from_args()
+    }
+}
@@ -0,0 +1,45 @@
+//!
+//! The Zinc debug adapter binary.
+//!
+
+pub(crate) mod arguments;
+
+use std::io;
+use std::process;
+
+use zinc_debug_adapter::Server;
+
+use self::arguments::Arguments;
+
+///
+/// The application entry point.
+///
+fn main() {
+    process::exit(match main_inner(Arguments::new()) {
+        Ok(()) => zinc_const::exit_code::SUCCESS,
+        Err(error) => {
+            log::error!("{:?}", error);
+            zinc_const::exit_code::FAILURE
+        }
+    })
+}
+
+///
+/// The auxiliary `main` function to facilitate the `?` error conversion operator.
+///
+/// The adapter communicates with the client via the standard input and output, so the logs are
+/// written to the standard error stream.
+///
+fn main_inner(args: Arguments) -> anyhow::Result<()> {
+    zinc_logger::initialize(
+        zinc_const::app_name::DEBUG_ADAPTER,
+        args.verbosity,
+        args.quiet,
+    );
+
+    Server::new(io::stdout()).run(io::BufReader::new(io::stdin()))?;
+
+    log::info!("Shutdown");
+
+    Ok(())
+}
@@ -0,0 +1,29 @@
+//!
+//! The formatter error.
+//!
+
+use thiserror::Error;
+
+use zinc_syntax::ParsingError;
+
+///
+/// The formatter error.
+///
+#[derive(Debug, Error, PartialEq)]
+pub enum Error {
+    /// The source code cannot be parsed, so it is left untouched.
+    #[error("the source code contains {} syntax error(s), which must be fixed before formatting", .0.len())]
+    Parsing(Vec<ParsingError>),
+}
+
+impl From<Vec<ParsingError>> for Error {
+    fn from(inner: Vec<ParsingError>) -> Self {
+        Self::Parsing(inner)
+    }
+}
+
+impl From<zinc_lexical::Error> for Error {
+    fn from(inner: zinc_lexical::Error) -> Self {
+        Self::Parsing(vec![ParsingError::Lexical(inner)])
+    }
+}
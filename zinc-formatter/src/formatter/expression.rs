@@ -0,0 +1,927 @@
+//!
+//! The formatter expressions, types, and patterns.
+//!
+
+use zinc_lexical::IntegerLiteral as LexicalIntegerLiteral;
+use zinc_lexical::Location;
+use zinc_syntax::ArrayExpressionVariant;
+use zinc_syntax::Binding;
+use zinc_syntax::BindingPattern;
+use zinc_syntax::BindingPatternVariant;
+use zinc_syntax::ClosureExpression;
+use zinc_syntax::ConditionalExpression;
+use zinc_syntax::ExpressionOperand;
+use zinc_syntax::ExpressionOperator;
+use zinc_syntax::ExpressionTree;
+use zinc_syntax::ExpressionTreeNode;
+use zinc_syntax::GenericParameter;
+use zinc_syntax::IntegerLiteral;
+use zinc_syntax::Literal;
+use zinc_syntax::MatchExpression;
+use zinc_syntax::MatchPattern;
+use zinc_syntax::MatchPatternVariant;
+use zinc_syntax::StringLiteral;
+use zinc_syntax::Type;
+use zinc_syntax::TypeVariant;
+
+use crate::formatter::Formatter;
+
+impl Formatter {
+    /// The precedence of the operands, which never require parentheses.
+    const PRECEDENCE_OPERAND: usize = 17;
+
+    ///
+    /// Writes an expression, inserting the parentheses where the precedence requires them.
+    ///
+    /// The line breaks around the binary operators and before the method calls are kept, and
+    /// the continuation lines are indented one level deeper.
+    ///
+    pub(crate) fn expression(&mut self, tree: &ExpressionTree) {
+        let operator = match *tree.value {
+            ExpressionTreeNode::Operand(ref operand) => return self.operand(operand),
+            ExpressionTreeNode::Operator(operator) => operator,
+        };
+        let precedence = Self::precedence(tree);
+
+        let continuation = self.continuation;
+        self.operator_expression(tree, operator, precedence);
+        if self.continuation != continuation {
+            if let Some(depth) = self.continuation {
+                self.depth = depth;
+            }
+            self.continuation = continuation;
+        }
+    }
+
+    ///
+    /// Writes an operator expression.
+    ///
+    fn operator_expression(
+        &mut self,
+        tree: &ExpressionTree,
+        operator: ExpressionOperator,
+        precedence: usize,
+    ) {
+        match operator {
+            ExpressionOperator::Not
+            | ExpressionOperator::BitwiseNot
+            | ExpressionOperator::Negation => {
+                self.write(Self::operator(operator));
+                if let Some(ref operand) = tree.left {
+                    self.child(operand, Self::precedence(operand) < precedence);
+                }
+            }
+            ExpressionOperator::Index => {
+                if let Some(ref operand) = tree.left {
+                    self.child(operand, Self::precedence(operand) < precedence);
+                }
+                self.write("[");
+                if let Some(ref index) = tree.right {
+                    self.expression(index);
+                }
+                self.write("]");
+            }
+            ExpressionOperator::Dot | ExpressionOperator::Path => {
+                if let Some(ref operand) = tree.left {
+                    // `tuple.0.1` is tokenized as `tuple` `.` `0.1`, so it is written as `(tuple.0).1`
+                    let is_tuple_index_chain = operator == ExpressionOperator::Dot
+                        && Self::is_tuple_index_access(tree)
+                        && Self::is_tuple_index_access(operand);
+
+                    self.child(
+                        operand,
+                        Self::precedence(operand) < precedence || is_tuple_index_chain,
+                    );
+                }
+                if operator == ExpressionOperator::Dot && self.source.is_line_start(tree.location) {
+                    self.line_break();
+                }
+                self.write(Self::operator(operator));
+                if let Some(ref operand) = tree.right {
+                    self.child(operand, Self::precedence(operand) <= precedence);
+                }
+            }
+            ExpressionOperator::CallIntrinsic | ExpressionOperator::Call => {
+                if let Some(ref operand) = tree.left {
+                    self.child(operand, Self::precedence(operand) < precedence);
+                }
+                self.write(Self::operator(operator));
+                if let Some(ref arguments) = tree.right {
+                    self.expression(arguments);
+                }
+            }
+            ExpressionOperator::Structure => {
+                if let Some(ref path) = tree.left {
+                    self.expression(path);
+                }
+                self.write(" ");
+                if let Some(ref structure) = tree.right {
+                    self.expression(structure);
+                }
+            }
+            operator => {
+                let is_associative = !matches!(
+                    operator,
+                    ExpressionOperator::Assignment
+                        | ExpressionOperator::AssignmentBitwiseOr
+                        | ExpressionOperator::AssignmentBitwiseXor
+                        | ExpressionOperator::AssignmentBitwiseAnd
+                        | ExpressionOperator::AssignmentBitwiseShiftLeft
+                        | ExpressionOperator::AssignmentBitwiseShiftRight
+                        | ExpressionOperator::AssignmentAddition
+                        | ExpressionOperator::AssignmentSubtraction
+                        | ExpressionOperator::AssignmentMultiplication
+                        | ExpressionOperator::AssignmentDivision
+                        | ExpressionOperator::AssignmentRemainder
+                        | ExpressionOperator::Range
+                        | ExpressionOperator::RangeInclusive
+                        | ExpressionOperator::Equals
+                        | ExpressionOperator::NotEquals
+                        | ExpressionOperator::GreaterEquals
+                        | ExpressionOperator::LesserEquals
+                        | ExpressionOperator::Greater
+                        | ExpressionOperator::Lesser
+                );
+
+                // `a && b` is parenthesized within `||` and `^^` for readability
+                let are_ands_parenthesized =
+                    matches!(operator, ExpressionOperator::Or | ExpressionOperator::Xor);
+
+                if let Some(ref operand) = tree.left {
+                    let child = Self::precedence(operand);
+                    self.child(
+                        operand,
+                        child < precedence
+                            || (child == precedence && !is_associative)
+                            || (are_ands_parenthesized && Self::is_and(operand)),
+                    );
+                }
+                match operator {
+                    ExpressionOperator::Range | ExpressionOperator::RangeInclusive => {
+                        self.write(Self::operator(operator))
+                    }
+                    operator if self.source.is_line_start(tree.location) => {
+                        self.line_break();
+                        self.write(Self::operator(operator));
+                        self.write(" ");
+                    }
+                    operator if self.source.is_line_end(tree.location) => {
+                        self.write(" ");
+                        self.write(Self::operator(operator));
+                        self.line_break();
+                    }
+                    operator => {
+                        self.write(" ");
+                        self.write(Self::operator(operator));
+                        self.write(" ");
+                    }
+                }
+                if let Some(ref operand) = tree.right {
+                    self.child(
+                        operand,
+                        Self::precedence(operand) <= precedence
+                            || (are_ands_parenthesized && Self::is_and(operand)),
+                    );
+                }
+            }
+        }
+    }
+
+    ///
+    /// Writes a type.
+    ///
+    pub(crate) fn r#type(&mut self, r#type: &Type) {
+        match r#type.variant {
+            TypeVariant::Unit => self.write("()"),
+            TypeVariant::Boolean => self.write("bool"),
+            TypeVariant::IntegerUnsigned { bitlength } => {
+                self.write(format!("u{}", bitlength).as_str())
+            }
+            TypeVariant::IntegerSigned { bitlength } => {
+                self.write(format!("i{}", bitlength).as_str())
+            }
+            TypeVariant::Field => self.write("field"),
+            TypeVariant::Array {
+                ref inner,
+                ref size,
+            } => {
+                self.write("[");
+                self.r#type(inner);
+                self.write("; ");
+                self.expression(size);
+                self.write("]");
+            }
+            TypeVariant::Tuple { ref inners } => {
+                self.write("(");
+                for (index, inner) in inners.iter().enumerate() {
+                    if index > 0 {
+                        self.write(", ");
+                    }
+                    self.r#type(inner);
+                }
+                if inners.len() == 1 {
+                    self.write(",");
+                }
+                self.write(")");
+            }
+            TypeVariant::Alias {
+                ref path,
+                ref generics,
+            } => {
+                self.expression(path);
+                if let Some(generics) = generics {
+                    self.write("<");
+                    for (index, generic) in generics.iter().enumerate() {
+                        if index > 0 {
+                            self.write(", ");
+                        }
+                        self.r#type(generic);
+                    }
+                    self.write(">");
+                }
+            }
+        }
+    }
+
+    ///
+    /// Writes a parenthesized comma-separated binding list, e.g. the function arguments.
+    ///
+    /// The list is written on multiple lines, if its first binding is not on the same line with
+    /// the opening parenthesis found at `location` in the source code.
+    ///
+    pub(crate) fn bindings(&mut self, location: Option<Location>, bindings: &[Binding]) {
+        let is_multiline = match (location, bindings.first()) {
+            (Some(location), Some(binding)) => binding.location.line != location.line,
+            _ => false,
+        };
+
+        if is_multiline {
+            let end = location.and_then(|location| self.source.closing(location));
+            self.open("(");
+            for binding in bindings.iter() {
+                self.item(binding.location);
+                self.binding(binding);
+                self.write(",");
+                self.newline();
+            }
+            self.close(")", end);
+        } else {
+            self.write("(");
+            for (index, binding) in bindings.iter().enumerate() {
+                if index > 0 {
+                    self.write(", ");
+                }
+                self.binding(binding);
+            }
+            self.write(")");
+        }
+    }
+
+    ///
+    /// Writes a binding with its optional type.
+    ///
+    pub(crate) fn binding(&mut self, binding: &Binding) {
+        self.binding_pattern(&binding.pattern);
+        if let Some(ref r#type) = binding.r#type {
+            self.write(": ");
+            self.r#type(r#type);
+        }
+    }
+
+    ///
+    /// Writes a generic parameter list, like `<T: Trait + Other>`.
+    ///
+    pub(crate) fn generic_parameters(&mut self, generics: &[GenericParameter]) {
+        self.write("<");
+        for (index, generic) in generics.iter().enumerate() {
+            if index > 0 {
+                self.write(", ");
+            }
+            self.write(generic.identifier.name.as_str());
+            for (index, bound) in generic.bounds.iter().enumerate() {
+                self.write(if index == 0 { ": " } else { " + " });
+                self.write(bound.name.as_str());
+            }
+        }
+        self.write(">");
+    }
+
+    ///
+    /// Writes a literal.
+    ///
+    pub(crate) fn literal(&mut self, literal: &Literal) {
+        match literal {
+            Literal::Boolean(inner) => self.write(inner.inner.to_string().as_str()),
+            Literal::Integer(inner) => self.integer(inner),
+            Literal::String(inner) => self.string(inner),
+        }
+    }
+
+    ///
+    /// Writes an integer literal as it is written in the source code.
+    ///
+    pub(crate) fn integer(&mut self, literal: &IntegerLiteral) {
+        let text = match self.source.literal(literal.location) {
+            Some(text) => text,
+            None => match literal.inner {
+                LexicalIntegerLiteral::Binary { ref inner } => format!("0b{}", inner),
+                LexicalIntegerLiteral::Octal { ref inner } => format!("0o{}", inner),
+                LexicalIntegerLiteral::Decimal {
+                    ref integer,
+                    ref fractional,
+                    ref exponent,
+                } => {
+                    let mut text = integer.to_owned();
+                    if let Some(fractional) = fractional {
+                        text.push('.');
+                        text.push_str(fractional.as_str());
+                    }
+                    if let Some(exponent) = exponent {
+                        text.push('E');
+                        text.push_str(exponent.as_str());
+                    }
+                    text
+                }
+                LexicalIntegerLiteral::Hexadecimal { ref inner } => format!("0x{}", inner),
+            },
+        };
+        self.write(text.as_str());
+    }
+
+    ///
+    /// Writes a string literal as it is written in the source code.
+    ///
+    pub(crate) fn string(&mut self, literal: &StringLiteral) {
+        let text = self.source.literal(literal.location).unwrap_or_else(|| {
+            let inner: String = literal.inner.inner.to_owned();
+            format!("\"{}\"", inner.replace('\\', "\\\\").replace('\"', "\\\""))
+        });
+        self.write(text.as_str());
+    }
+
+    ///
+    /// Writes a byte string literal, whose contents start after the `b` prefix.
+    ///
+    pub(crate) fn byte_string(&mut self, literal: &StringLiteral) {
+        self.write("b");
+        self.string(&StringLiteral::new(
+            literal.location.shifted_right(1),
+            literal.inner.to_owned(),
+        ));
+    }
+
+    ///
+    /// The location where the expression begins, which is the location of its leftmost part.
+    ///
+    pub(crate) fn start(tree: &ExpressionTree) -> Location {
+        match tree.left {
+            Some(ref left) => {
+                let left = Self::start(left);
+                if Self::is_before(left, tree.location) {
+                    left
+                } else {
+                    tree.location
+                }
+            }
+            None => tree.location,
+        }
+    }
+
+    ///
+    /// Writes an expression operand.
+    ///
+    fn operand(&mut self, operand: &ExpressionOperand) {
+        match operand {
+            ExpressionOperand::LiteralUnit(_) => self.write("()"),
+            ExpressionOperand::LiteralBoolean(inner) => {
+                self.write(inner.inner.to_string().as_str())
+            }
+            ExpressionOperand::LiteralInteger(inner) => self.integer(inner),
+            ExpressionOperand::LiteralString(inner) => self.string(inner),
+            ExpressionOperand::LiteralByteString(inner) => self.byte_string(inner),
+            ExpressionOperand::TupleIndex(inner) => match inner.literal.inner {
+                LexicalIntegerLiteral::Decimal { ref integer, .. } => self.write(integer.as_str()),
+                _ => self.integer(&inner.literal),
+            },
+            ExpressionOperand::Identifier(inner) => self.write(inner.name.as_str()),
+            ExpressionOperand::Type(inner) => self.r#type(inner),
+            ExpressionOperand::Array(inner) => match inner.variant {
+                ArrayExpressionVariant::List { ref elements } => {
+                    self.list(inner.location, elements.as_slice(), "[", "]")
+                }
+                ArrayExpressionVariant::Repeated {
+                    ref expression,
+                    ref size_expression,
+                } => {
+                    self.write("[");
+                    self.expression(expression);
+                    self.write("; ");
+                    self.expression(size_expression);
+                    self.write("]");
+                }
+            },
+            ExpressionOperand::Tuple(inner) if inner.elements.len() == 1 => {
+                self.write("(");
+                self.expression(&inner.elements[0]);
+                self.write(",)");
+            }
+            ExpressionOperand::Tuple(inner) => {
+                self.list(inner.location, inner.elements.as_slice(), "(", ")")
+            }
+            ExpressionOperand::Structure(inner) => {
+                let end = self.source.closing(inner.location);
+                let is_multiline = inner
+                    .fields
+                    .first()
+                    .map(|(identifier, _)| identifier.location.line != inner.location.line)
+                    .unwrap_or_default();
+
+                if is_multiline {
+                    self.open("{");
+                    for (identifier, expression) in inner.fields.iter() {
+                        self.item(identifier.location);
+                        self.write(identifier.name.as_str());
+                        self.write(": ");
+                        self.expression(expression);
+                        self.write(",");
+                        self.newline();
+                    }
+                    self.close("}", end);
+                } else {
+                    self.write("{ ");
+                    for (index, (identifier, expression)) in inner.fields.iter().enumerate() {
+                        if index > 0 {
+                            self.write(", ");
+                        }
+                        self.write(identifier.name.as_str());
+                        self.write(": ");
+                        self.expression(expression);
+                    }
+                    self.write(" }");
+                }
+            }
+            ExpressionOperand::List(inner) => {
+                self.list(inner.location, inner.elements.as_slice(), "(", ")")
+            }
+            ExpressionOperand::Block(inner) => self.block(inner),
+            ExpressionOperand::Conditional(inner) => self.conditional(inner),
+            ExpressionOperand::Match(inner) => self.r#match(inner),
+            ExpressionOperand::Closure(inner) => self.closure(inner),
+        }
+    }
+
+    ///
+    /// Writes a comma-separated expression list, e.g. an array or call arguments.
+    ///
+    /// The list is written on multiple lines, if its first element is not on the same line
+    /// with the opening bracket in the source code.
+    ///
+    fn list(&mut self, location: Location, elements: &[ExpressionTree], open: &str, close: &str) {
+        let is_multiline = elements
+            .first()
+            .map(|element| Self::start(element).line != location.line)
+            .unwrap_or_default();
+
+        if is_multiline {
+            let end = self.source.closing(location);
+            self.open(open);
+            for element in elements.iter() {
+                self.item(Self::start(element));
+                self.expression(element);
+                self.write(",");
+                self.newline();
+            }
+            self.close(close, end);
+        } else {
+            self.write(open);
+            for (index, element) in elements.iter().enumerate() {
+                if index > 0 {
+                    self.write(", ");
+                }
+                self.expression(element);
+            }
+            self.write(close);
+        }
+    }
+
+    ///
+    /// Writes a conditional expression, including its `else if` chain.
+    ///
+    fn conditional(&mut self, conditional: &ConditionalExpression) {
+        self.write("if ");
+        self.expression(&conditional.condition);
+        self.write(" ");
+        self.block(&conditional.main_block);
+
+        if let Some(ref block) = conditional.else_block {
+            self.write(" else ");
+
+            if let (true, Some(expression)) = (block.statements.is_empty(), &block.expression) {
+                if let ExpressionTreeNode::Operand(ExpressionOperand::Conditional(ref inner)) =
+                    *expression.value
+                {
+                    if block.location == inner.location {
+                        return self.conditional(inner);
+                    }
+                }
+            }
+
+            self.block(block);
+        }
+    }
+
+    ///
+    /// Writes a closure expression, like `|acc, x| acc + x`.
+    ///
+    fn closure(&mut self, closure: &ClosureExpression) {
+        self.write("|");
+        for (index, binding) in closure.bindings.iter().enumerate() {
+            if index > 0 {
+                self.write(", ");
+            }
+            self.binding(binding);
+        }
+        self.write("| ");
+        self.expression(&closure.body);
+    }
+
+    ///
+    /// Writes a match expression.
+    ///
+    /// The match expressions are kept on one line, if they are written so in the source code,
+    /// and the others have every branch on a separate line.
+    ///
+    fn r#match(&mut self, r#match: &MatchExpression) {
+        self.write("match ");
+        self.expression(&r#match.scrutinee);
+        self.write(" ");
+
+        let end = match r#match.branches.first() {
+            Some((pattern, _, _)) => self.source.closing_curly_before(pattern.location),
+            None => {
+                self.write("{}");
+                return;
+            }
+        };
+
+        if let Some(end) = end {
+            if end.line == r#match.location.line
+                && !self.has_comments(Some(end))
+                && self.single_line(|formatter| {
+                    formatter.write("{ ");
+                    for (index, (pattern, guard, expression)) in r#match.branches.iter().enumerate()
+                    {
+                        if index > 0 {
+                            formatter.write(", ");
+                        }
+                        formatter.match_pattern(pattern);
+                        if let Some(guard) = guard {
+                            formatter.write(" if ");
+                            formatter.expression(guard);
+                        }
+                        formatter.write(" => ");
+                        formatter.expression(expression);
+                    }
+                    formatter.write(" }");
+                })
+            {
+                return;
+            }
+        }
+
+        self.open("{");
+        for (pattern, guard, expression) in r#match.branches.iter() {
+            self.item(pattern.location);
+            self.match_pattern(pattern);
+            if let Some(guard) = guard {
+                self.write(" if ");
+                self.expression(guard);
+            }
+            self.write(" => ");
+            self.expression(expression);
+            self.write(",");
+            self.newline();
+        }
+        self.close("}", end);
+    }
+
+    ///
+    /// Writes a match branch pattern.
+    ///
+    fn match_pattern(&mut self, pattern: &MatchPattern) {
+        match pattern.variant {
+            MatchPatternVariant::BooleanLiteral(ref inner) => {
+                self.write(inner.inner.to_string().as_str())
+            }
+            MatchPatternVariant::IntegerLiteral(ref inner) => self.integer(inner),
+            MatchPatternVariant::Binding(ref inner) => self.write(inner.name.as_str()),
+            MatchPatternVariant::Path(ref inner) => self.expression(inner),
+            MatchPatternVariant::PathTuple {
+                ref path,
+                ref patterns,
+            } => {
+                self.expression(path);
+                self.write("(");
+                for (index, pattern) in patterns.iter().enumerate() {
+                    if index > 0 {
+                        self.write(", ");
+                    }
+                    self.match_pattern(pattern);
+                }
+                self.write(")");
+            }
+            MatchPatternVariant::PathStructure {
+                ref path,
+                ref fields,
+            } => {
+                self.expression(path);
+                self.write(" { ");
+                for (index, (identifier, pattern)) in fields.iter().enumerate() {
+                    if index > 0 {
+                        self.write(", ");
+                    }
+                    self.write(identifier.name.as_str());
+                    match pattern.variant {
+                        MatchPatternVariant::Binding(ref binding)
+                            if binding.name == identifier.name => {}
+                        _ => {
+                            self.write(": ");
+                            self.match_pattern(pattern);
+                        }
+                    }
+                }
+                self.write(" }");
+            }
+            MatchPatternVariant::Tuple(ref patterns) => {
+                self.write("(");
+                for (index, pattern) in patterns.iter().enumerate() {
+                    if index > 0 {
+                        self.write(", ");
+                    }
+                    self.match_pattern(pattern);
+                }
+                if patterns.len() == 1 {
+                    self.write(",");
+                }
+                self.write(")");
+            }
+            MatchPatternVariant::Range { ref start, ref end } => {
+                self.integer(start);
+                self.write("..=");
+                self.integer(end);
+            }
+            MatchPatternVariant::Alternatives(ref patterns) => {
+                for (index, pattern) in patterns.iter().enumerate() {
+                    if index > 0 {
+                        self.write(" | ");
+                    }
+                    self.match_pattern(pattern);
+                }
+            }
+            MatchPatternVariant::Wildcard => self.write("_"),
+        }
+    }
+
+    ///
+    /// Writes a binding pattern.
+    ///
+    fn binding_pattern(&mut self, pattern: &BindingPattern) {
+        match pattern.variant {
+            BindingPatternVariant::Binding {
+                ref identifier,
+                is_mutable,
+            } => {
+                if is_mutable {
+                    self.write("mut ");
+                }
+                self.write(identifier.name.as_str());
+            }
+            BindingPatternVariant::BindingList { ref bindings } => {
+                self.write("(");
+                for (index, binding) in bindings.iter().enumerate() {
+                    if index > 0 {
+                        self.write(", ");
+                    }
+                    self.binding_pattern(binding);
+                }
+                if bindings.len() == 1 {
+                    self.write(",");
+                }
+                self.write(")");
+            }
+            BindingPatternVariant::Wildcard => self.write("_"),
+        }
+    }
+
+    ///
+    /// Writes a subexpression, optionally wrapped into parentheses.
+    ///
+    /// The type castings are also parenthesized if they are so in the source code.
+    ///
+    fn child(&mut self, tree: &ExpressionTree, is_parenthesized: bool) {
+        let is_parenthesized = is_parenthesized || self.is_parenthesized_casting(tree);
+
+        if is_parenthesized {
+            self.write("(");
+        }
+        self.expression(tree);
+        if is_parenthesized {
+            self.write(")");
+        }
+    }
+
+    ///
+    /// Checks whether the expression is a tuple element access, like `tuple.0`.
+    ///
+    fn is_tuple_index_access(tree: &ExpressionTree) -> bool {
+        let is_dot = matches!(
+            *tree.value,
+            ExpressionTreeNode::Operator(ExpressionOperator::Dot)
+        );
+        let is_tuple_index = matches!(
+            tree.right.as_deref().map(|right| right.value.as_ref()),
+            Some(ExpressionTreeNode::Operand(ExpressionOperand::TupleIndex(
+                _
+            )))
+        );
+
+        is_dot && is_tuple_index
+    }
+
+    ///
+    /// Checks whether the expression is a type casting, which is parenthesized in the source
+    /// code, like `(value as u8)`.
+    ///
+    fn is_parenthesized_casting(&self, tree: &ExpressionTree) -> bool {
+        if !Self::is_casting(tree) {
+            return false;
+        }
+
+        let end = match tree.right.as_deref().map(|right| right.value.as_ref()) {
+            Some(ExpressionTreeNode::Operand(ExpressionOperand::Type(r#type))) => {
+                match self.type_end(r#type) {
+                    Some(end) => end,
+                    None => return false,
+                }
+            }
+            _ => return false,
+        };
+        let closing = match self.source.next(end) {
+            Some(closing) => closing,
+            None => return false,
+        };
+
+        let mut location = Self::start(tree);
+        while let Some(opening) = self.source.parenthesis_before(location) {
+            if self.source.closing(opening) == Some(closing) {
+                return true;
+            }
+            location = opening;
+        }
+        false
+    }
+
+    ///
+    /// The location of the last token of the type, if it can be found.
+    ///
+    fn type_end(&self, r#type: &Type) -> Option<Location> {
+        match r#type.variant {
+            TypeVariant::Unit | TypeVariant::Array { .. } | TypeVariant::Tuple { .. } => {
+                self.source.closing(r#type.location)
+            }
+            TypeVariant::Alias {
+                ref path,
+                generics: None,
+            } => {
+                let mut path = path;
+                while let Some(ref right) = path.right {
+                    path = right;
+                }
+                Some(path.location)
+            }
+            TypeVariant::Alias { .. } => None,
+            _ => Some(r#type.location),
+        }
+    }
+
+    ///
+    /// Checks whether the expression is a type casting, like `value as u8`.
+    ///
+    fn is_casting(tree: &ExpressionTree) -> bool {
+        matches!(
+            *tree.value,
+            ExpressionTreeNode::Operator(ExpressionOperator::Casting)
+        )
+    }
+
+    ///
+    /// Checks whether the expression is a logical conjunction, like `a && b`.
+    ///
+    fn is_and(tree: &ExpressionTree) -> bool {
+        matches!(
+            *tree.value,
+            ExpressionTreeNode::Operator(ExpressionOperator::And)
+        )
+    }
+
+    ///
+    /// The expression precedence, where the greater values are bound tighter.
+    ///
+    fn precedence(tree: &ExpressionTree) -> usize {
+        let operator = match *tree.value {
+            ExpressionTreeNode::Operand(_) => return Self::PRECEDENCE_OPERAND,
+            ExpressionTreeNode::Operator(operator) => operator,
+        };
+
+        match operator {
+            ExpressionOperator::Assignment
+            | ExpressionOperator::AssignmentBitwiseOr
+            | ExpressionOperator::AssignmentBitwiseXor
+            | ExpressionOperator::AssignmentBitwiseAnd
+            | ExpressionOperator::AssignmentBitwiseShiftLeft
+            | ExpressionOperator::AssignmentBitwiseShiftRight
+            | ExpressionOperator::AssignmentAddition
+            | ExpressionOperator::AssignmentSubtraction
+            | ExpressionOperator::AssignmentMultiplication
+            | ExpressionOperator::AssignmentDivision
+            | ExpressionOperator::AssignmentRemainder => 1,
+            ExpressionOperator::Range | ExpressionOperator::RangeInclusive => 2,
+            ExpressionOperator::Or => 3,
+            ExpressionOperator::Xor => 4,
+            ExpressionOperator::And => 5,
+            ExpressionOperator::Equals
+            | ExpressionOperator::NotEquals
+            | ExpressionOperator::GreaterEquals
+            | ExpressionOperator::LesserEquals
+            | ExpressionOperator::Greater
+            | ExpressionOperator::Lesser => 6,
+            ExpressionOperator::BitwiseOr => 7,
+            ExpressionOperator::BitwiseXor => 8,
+            ExpressionOperator::BitwiseAnd => 9,
+            ExpressionOperator::BitwiseShiftLeft | ExpressionOperator::BitwiseShiftRight => 10,
+            ExpressionOperator::Addition | ExpressionOperator::Subtraction => 11,
+            ExpressionOperator::Multiplication
+            | ExpressionOperator::Division
+            | ExpressionOperator::Remainder => 12,
+            ExpressionOperator::Casting => 13,
+            ExpressionOperator::Not
+            | ExpressionOperator::BitwiseNot
+            | ExpressionOperator::Negation => 14,
+            ExpressionOperator::Index
+            | ExpressionOperator::Dot
+            | ExpressionOperator::CallIntrinsic
+            | ExpressionOperator::Call => 15,
+            ExpressionOperator::Path | ExpressionOperator::Structure => 16,
+        }
+    }
+
+    ///
+    /// The operator representation in the source code.
+    ///
+    fn operator(operator: ExpressionOperator) -> &'static str {
+        match operator {
+            ExpressionOperator::Assignment => "=",
+            ExpressionOperator::AssignmentBitwiseOr => "|=",
+            ExpressionOperator::AssignmentBitwiseXor => "^=",
+            ExpressionOperator::AssignmentBitwiseAnd => "&=",
+            ExpressionOperator::AssignmentBitwiseShiftLeft => "<<=",
+            ExpressionOperator::AssignmentBitwiseShiftRight => ">>=",
+            ExpressionOperator::AssignmentAddition => "+=",
+            ExpressionOperator::AssignmentSubtraction => "-=",
+            ExpressionOperator::AssignmentMultiplication => "*=",
+            ExpressionOperator::AssignmentDivision => "/=",
+            ExpressionOperator::AssignmentRemainder => "%=",
+            ExpressionOperator::Range => "..",
+            ExpressionOperator::RangeInclusive => "..=",
+            ExpressionOperator::Or => "||",
+            ExpressionOperator::Xor => "^^",
+            ExpressionOperator::And => "&&",
+            ExpressionOperator::Equals => "==",
+            ExpressionOperator::NotEquals => "!=",
+            ExpressionOperator::GreaterEquals => ">=",
+            ExpressionOperator::LesserEquals => "<=",
+            ExpressionOperator::Greater => ">",
+            ExpressionOperator::Lesser => "<",
+            ExpressionOperator::BitwiseOr => "|",
+            ExpressionOperator::BitwiseXor => "^",
+            ExpressionOperator::BitwiseAnd => "&",
+            ExpressionOperator::BitwiseShiftLeft => "<<",
+            ExpressionOperator::BitwiseShiftRight => ">>",
+            ExpressionOperator::Addition => "+",
+            ExpressionOperator::Subtraction => "-",
+            ExpressionOperator::Multiplication => "*",
+            ExpressionOperator::Division => "/",
+            ExpressionOperator::Remainder => "%",
+            ExpressionOperator::Casting => "as",
+            ExpressionOperator::Not => "!",
+            ExpressionOperator::BitwiseNot => "~",
+            ExpressionOperator::Negation => "-",
+            ExpressionOperator::Index => "[]",
+            ExpressionOperator::Dot => ".",
+            ExpressionOperator::CallIntrinsic => "!",
+            ExpressionOperator::Call => "",
+            ExpressionOperator::Path => "::",
+            ExpressionOperator::Structure => "",
+        }
+    }
+}
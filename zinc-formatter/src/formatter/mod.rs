@@ -0,0 +1,728 @@
+//!
+//! The formatter.
+//!
+
+pub mod expression;
+
+use zinc_lexical::Location;
+use zinc_lexical::Symbol;
+use zinc_syntax::Attribute;
+use zinc_syntax::AttributeElement;
+use zinc_syntax::AttributeElementVariant;
+use zinc_syntax::BlockExpression;
+use zinc_syntax::ConstStatement;
+use zinc_syntax::ContractLocalStatement;
+use zinc_syntax::FieldStatement;
+use zinc_syntax::FnPrototype;
+use zinc_syntax::FnStatement;
+use zinc_syntax::FunctionLocalStatement;
+use zinc_syntax::ImplementationLocalStatement;
+use zinc_syntax::Module;
+use zinc_syntax::ModuleLocalStatement;
+use zinc_syntax::Parser;
+use zinc_syntax::VariantPayload;
+
+use crate::error::Error;
+use crate::source::Source;
+
+///
+/// The formatter, which pretty-prints the syntax tree in the canonical style.
+///
+/// The style rules:
+/// - the indentation is four spaces;
+/// - every statement, structure field, enumeration variant, and match branch is written on
+///   a separate line, and the lists spanning multiple lines have trailing commas;
+/// - the binary operators are surrounded with spaces, except for the range ones;
+/// - the parentheses are only written where the operator precedence requires them;
+/// - the empty statements are removed, and at most one blank line is kept between items.
+///
+/// The comments are kept and written before the next item, or at the end of the previous line
+/// if they were trailing a code line in the original source code. The `\r\n` line terminators
+/// are kept if the first source code line is terminated so.
+///
+pub struct Formatter {
+    /// The parsed syntax tree.
+    module: Option<Module>,
+    /// The source code index.
+    source: Source,
+    /// The formatted source code.
+    output: String,
+    /// The current indentation level.
+    depth: usize,
+    /// The index of the next comment to be written.
+    next_comment: usize,
+    /// Whether nothing has been written since the beginning of the current block.
+    is_block_start: bool,
+    /// The indentation level of the expression being continued on the next lines, if any.
+    continuation: Option<usize>,
+    /// Whether the source code lines are terminated with `\r\n`.
+    is_crlf: bool,
+}
+
+impl Formatter {
+    /// The file identifier used for the tokens and syntax trees.
+    ///
+    /// Such locations are never looked up in the global file index.
+    const FILE_ID: usize = 0;
+
+    /// The indentation of a single level.
+    const INDENT: &'static str = "    ";
+
+    ///
+    /// Parses the `input` and prepares the formatter.
+    ///
+    pub fn new(input: &str) -> Result<Self, Error> {
+        let module = Parser::default().parse(input, Self::FILE_ID)?;
+        let source = Source::new(input, Self::FILE_ID)?;
+
+        Ok(Self {
+            module: Some(module),
+            source,
+            output: String::with_capacity(input.len()),
+            depth: 0,
+            next_comment: 0,
+            is_block_start: true,
+            continuation: None,
+            is_crlf: input
+                .find('\n')
+                .map(|index| input[..index].ends_with('\r'))
+                .unwrap_or_default(),
+        })
+    }
+
+    ///
+    /// Formats the source code.
+    ///
+    pub fn format(mut self) -> String {
+        if let Some(module) = self.module.take() {
+            for statement in module.statements.iter() {
+                if let ModuleLocalStatement::Empty(_) = statement {
+                    continue;
+                }
+
+                let location = match statement {
+                    ModuleLocalStatement::Fn(inner) => Self::fn_start(inner),
+                    statement => statement.location(),
+                };
+                self.item(location);
+                self.module_local_statement(statement);
+                self.newline();
+            }
+        }
+
+        self.comments(None);
+
+        if self.output.ends_with("\n\n") {
+            self.output.truncate(self.output.trim_end().len());
+            self.output.push('\n');
+        }
+
+        if self.is_crlf {
+            return self.output.replace("\r\n", "\n").replace('\n', "\r\n");
+        }
+        self.output
+    }
+
+    ///
+    /// Writes a module-level statement.
+    ///
+    fn module_local_statement(&mut self, statement: &ModuleLocalStatement) {
+        match statement {
+            ModuleLocalStatement::Const(inner) => self.const_statement(inner),
+            ModuleLocalStatement::Type(inner) => {
+                self.visibility(inner.location);
+                self.write("type ");
+                self.write(inner.identifier.name.as_str());
+                self.write(" = ");
+                self.r#type(&inner.r#type);
+                self.write(";");
+            }
+            ModuleLocalStatement::Struct(inner) => {
+                self.visibility(inner.location);
+                self.write("struct ");
+                self.write(inner.identifier.name.as_str());
+                if let Some(ref generics) = inner.generics {
+                    self.generic_parameters(generics);
+                }
+                self.write(" ");
+
+                let end = self.source.closing_curly_after(inner.location);
+                if inner.fields.is_empty() && !self.has_comments(end) {
+                    self.write("{}");
+                    return;
+                }
+
+                self.open("{");
+                for field in inner.fields.iter() {
+                    self.item(field.location);
+                    self.write(field.identifier.name.as_str());
+                    self.write(": ");
+                    self.r#type(&field.r#type);
+                    self.write(",");
+                    self.newline();
+                }
+                self.close("}", end);
+            }
+            ModuleLocalStatement::Enum(inner) => {
+                self.visibility(inner.location);
+                self.write("enum ");
+                self.write(inner.identifier.name.as_str());
+                if let Some(ref generics) = inner.generics {
+                    self.generic_parameters(generics);
+                }
+                self.write(" ");
+
+                let end = self.source.closing_curly_after(inner.location);
+                if inner.variants.is_empty() && !self.has_comments(end) {
+                    self.write("{}");
+                    return;
+                }
+
+                self.open("{");
+                for variant in inner.variants.iter() {
+                    self.item(variant.location);
+                    self.write(variant.identifier.name.as_str());
+                    match variant.payload {
+                        Some(VariantPayload::Tuple(ref types)) => {
+                            self.write("(");
+                            for (index, r#type) in types.iter().enumerate() {
+                                if index > 0 {
+                                    self.write(", ");
+                                }
+                                self.r#type(r#type);
+                            }
+                            self.write(")");
+                        }
+                        Some(VariantPayload::Structure(ref fields)) => {
+                            self.write(" { ");
+                            for (index, field) in fields.iter().enumerate() {
+                                if index > 0 {
+                                    self.write(", ");
+                                }
+                                self.write(field.identifier.name.as_str());
+                                self.write(": ");
+                                self.r#type(&field.r#type);
+                            }
+                            self.write(" }");
+                        }
+                        None => {}
+                    }
+                    if let Some(ref literal) = variant.literal {
+                        self.write(" = ");
+                        self.integer(literal);
+                    }
+                    self.write(",");
+                    self.newline();
+                }
+                self.close("}", end);
+            }
+            ModuleLocalStatement::Fn(inner) => self.fn_statement(inner),
+            ModuleLocalStatement::Mod(inner) => {
+                self.visibility(inner.location);
+                self.write("mod ");
+                self.write(inner.identifier.name.as_str());
+                self.write(";");
+            }
+            ModuleLocalStatement::Use(inner) => {
+                self.visibility(inner.location);
+                self.write("use ");
+                self.expression(&inner.path);
+                if let Some(ref alias) = inner.alias_identifier {
+                    self.write(" as ");
+                    self.write(alias.name.as_str());
+                }
+                self.write(";");
+            }
+            ModuleLocalStatement::Trait(inner) => {
+                self.visibility(inner.location);
+                self.write("trait ");
+                self.write(inner.identifier.name.as_str());
+                self.write(" ");
+
+                let end = self.source.closing_curly_after(inner.location);
+                if inner.methods.is_empty() && !self.has_comments(end) {
+                    self.write("{}");
+                    return;
+                }
+
+                self.open("{");
+                for method in inner.methods.iter() {
+                    self.item(method.location);
+                    self.prototype(method);
+                    self.write(";");
+                    self.newline();
+                }
+                self.close("}", end);
+            }
+            ModuleLocalStatement::Impl(inner) => {
+                self.write("impl ");
+                if let Some(ref r#trait) = inner.r#trait {
+                    self.write(r#trait.name.as_str());
+                    self.write(" for ");
+                }
+                self.write(inner.identifier.name.as_str());
+                self.write(" ");
+
+                let end = self.source.closing_curly_after(inner.location);
+                let statements: Vec<&ImplementationLocalStatement> = inner
+                    .statements
+                    .iter()
+                    .filter(|statement| {
+                        !matches!(statement, ImplementationLocalStatement::Empty(_))
+                    })
+                    .collect();
+                if statements.is_empty() && !self.has_comments(end) {
+                    self.write("{}");
+                    return;
+                }
+
+                self.open("{");
+                for statement in statements.into_iter() {
+                    match statement {
+                        ImplementationLocalStatement::Const(inner) => {
+                            self.item(inner.location);
+                            self.const_statement(inner);
+                        }
+                        ImplementationLocalStatement::Fn(inner) => {
+                            self.item(Self::fn_start(inner));
+                            self.fn_statement(inner);
+                        }
+                        ImplementationLocalStatement::Empty(_) => continue,
+                    }
+                    self.newline();
+                }
+                self.close("}", end);
+            }
+            ModuleLocalStatement::Contract(inner) => {
+                self.visibility(inner.location);
+                self.write("contract ");
+                self.write(inner.identifier.name.as_str());
+                self.write(" ");
+
+                let end = self.source.closing_curly_after(inner.location);
+                let statements: Vec<&ContractLocalStatement> = inner
+                    .statements
+                    .iter()
+                    .filter(|statement| !matches!(statement, ContractLocalStatement::Empty(_)))
+                    .collect();
+                if statements.is_empty() && !self.has_comments(end) {
+                    self.write("{}");
+                    return;
+                }
+
+                self.open("{");
+                for statement in statements.into_iter() {
+                    match statement {
+                        ContractLocalStatement::Field(inner) => {
+                            self.item(inner.location);
+                            self.field_statement(inner);
+                        }
+                        ContractLocalStatement::Const(inner) => {
+                            self.item(inner.location);
+                            self.const_statement(inner);
+                        }
+                        ContractLocalStatement::Fn(inner) => {
+                            self.item(Self::fn_start(inner));
+                            self.fn_statement(inner);
+                        }
+                        ContractLocalStatement::Empty(_) => continue,
+                    }
+                    self.newline();
+                }
+                self.close("}", end);
+            }
+            ModuleLocalStatement::Empty(_) => {}
+        }
+    }
+
+    ///
+    /// Writes a function-level statement.
+    ///
+    /// The expression statements ending with a block are written without the semicolon, unless
+    /// it is the last statement of a block, where the semicolon changes the block result.
+    ///
+    fn function_local_statement(&mut self, statement: &FunctionLocalStatement, is_last: bool) {
+        match statement {
+            FunctionLocalStatement::Let(inner) => {
+                self.write("let ");
+                self.binding(&inner.binding);
+                self.write(" = ");
+                self.expression(&inner.expression);
+                self.write(";");
+            }
+            FunctionLocalStatement::Const(inner) => self.const_statement(inner),
+            FunctionLocalStatement::For(inner) => {
+                self.write("for ");
+                self.write(inner.index_identifier.name.as_str());
+                self.write(" in ");
+                self.expression(&inner.bounds_expression);
+                if let Some(ref condition) = inner.while_condition {
+                    self.write(" while ");
+                    self.expression(condition);
+                }
+                self.write(" ");
+                self.block(&inner.block);
+            }
+            FunctionLocalStatement::While(inner) => {
+                self.write("while ");
+                self.expression(&inner.condition);
+                self.write(" bound ");
+                self.expression(&inner.bound);
+                self.write(" ");
+                self.block(&inner.block);
+            }
+            FunctionLocalStatement::Return(inner) => {
+                self.write("return");
+                if let Some(ref expression) = inner.expression {
+                    self.write(" ");
+                    self.expression(expression);
+                }
+                self.write(";");
+            }
+            FunctionLocalStatement::Break(_) => self.write("break;"),
+            FunctionLocalStatement::Continue(_) => self.write("continue;"),
+            FunctionLocalStatement::Expression(inner) => {
+                self.expression(inner);
+                if is_last || !inner.can_be_unterminated() {
+                    self.write(";");
+                }
+            }
+            FunctionLocalStatement::Empty(_) => {}
+        }
+    }
+
+    ///
+    /// Writes a constant statement.
+    ///
+    fn const_statement(&mut self, statement: &ConstStatement) {
+        self.visibility(statement.location);
+        self.write("const ");
+        self.write(statement.identifier.name.as_str());
+        self.write(": ");
+        self.r#type(&statement.r#type);
+        self.write(" = ");
+        self.expression(&statement.expression);
+        self.write(";");
+    }
+
+    ///
+    /// Writes a contract field statement.
+    ///
+    fn field_statement(&mut self, statement: &FieldStatement) {
+        if statement.is_public {
+            self.write("pub ");
+        }
+        self.write(statement.identifier.name.as_str());
+        self.write(": ");
+        self.r#type(&statement.r#type);
+        self.write(";");
+    }
+
+    ///
+    /// Writes a function statement with its attributes.
+    ///
+    fn fn_statement(&mut self, statement: &FnStatement) {
+        for attribute in statement.attributes.iter() {
+            self.attribute(attribute);
+            self.newline();
+        }
+
+        if statement.is_public {
+            self.write("pub ");
+        }
+        if statement.is_constant {
+            self.write("const ");
+        }
+        self.write("fn ");
+        self.write(statement.identifier.name.as_str());
+        if let Some(ref generics) = statement.generics {
+            self.generic_parameters(generics);
+        }
+        let parenthesis = self
+            .source
+            .find_after(statement.identifier.location, Symbol::ParenthesisLeft);
+        self.bindings(parenthesis, statement.argument_bindings.as_slice());
+        if let Some(ref r#type) = statement.return_type {
+            self.write(" -> ");
+            self.r#type(r#type);
+        }
+        self.write(" ");
+        self.block(&statement.body);
+    }
+
+    ///
+    /// Writes a trait method prototype.
+    ///
+    fn prototype(&mut self, prototype: &FnPrototype) {
+        self.write("fn ");
+        self.write(prototype.identifier.name.as_str());
+        if let Some(ref generics) = prototype.generics {
+            self.generic_parameters(generics);
+        }
+        let parenthesis = self
+            .source
+            .find_after(prototype.identifier.location, Symbol::ParenthesisLeft);
+        self.bindings(parenthesis, prototype.argument_bindings.as_slice());
+        if let Some(ref r#type) = prototype.return_type {
+            self.write(" -> ");
+            self.r#type(r#type);
+        }
+    }
+
+    ///
+    /// Writes an attribute, like `#[test]`.
+    ///
+    fn attribute(&mut self, attribute: &Attribute) {
+        self.write(if attribute.is_inner { "#![" } else { "#[" });
+        self.attribute_elements(attribute.elements.as_slice());
+        self.write("]");
+    }
+
+    ///
+    /// Writes a comma-separated attribute element list.
+    ///
+    fn attribute_elements(&mut self, elements: &[AttributeElement]) {
+        for (index, element) in elements.iter().enumerate() {
+            if index > 0 {
+                self.write(", ");
+            }
+
+            self.expression(&element.path);
+            match element.variant {
+                Some(AttributeElementVariant::Value(ref literal)) => {
+                    self.write(" = ");
+                    self.literal(literal);
+                }
+                Some(AttributeElementVariant::Nested(ref elements)) => {
+                    self.write("(");
+                    self.attribute_elements(elements.as_slice());
+                    self.write(")");
+                }
+                None => {}
+            }
+        }
+    }
+
+    ///
+    /// Writes a block expression.
+    ///
+    /// The empty blocks are written as `{}`. The blocks consisting of a single expression are
+    /// kept on one line, if they are written so in the source code, and the others span
+    /// multiple lines.
+    ///
+    fn block(&mut self, block: &BlockExpression) {
+        let end = self.source.closing(block.location);
+        let statements: Vec<&FunctionLocalStatement> = block
+            .statements
+            .iter()
+            .filter(|statement| !matches!(statement, FunctionLocalStatement::Empty(_)))
+            .collect();
+        if statements.is_empty() && block.expression.is_none() && !self.has_comments(end) {
+            self.write("{}");
+            return;
+        }
+
+        if let (true, Some(expression), Some(end)) = (statements.is_empty(), &block.expression, end)
+        {
+            if end.line == block.location.line
+                && !self.has_comments(Some(end))
+                && self.single_line(|formatter| {
+                    formatter.write("{ ");
+                    formatter.expression(expression);
+                    formatter.write(" }");
+                })
+            {
+                return;
+            }
+        }
+
+        self.open("{");
+        let count = statements.len();
+        for (index, statement) in statements.into_iter().enumerate() {
+            let location = match statement {
+                FunctionLocalStatement::Expression(inner) => Self::start(inner),
+                statement => statement.location(),
+            };
+            self.item(location);
+            self.function_local_statement(
+                statement,
+                index + 1 == count && block.expression.is_none(),
+            );
+            self.newline();
+        }
+        if let Some(ref expression) = block.expression {
+            self.item(Self::start(expression));
+            self.expression(expression);
+            self.newline();
+        }
+        self.close("}", end);
+    }
+
+    ///
+    /// Writes a construction with `write`, and keeps it only if it fits on the current line.
+    ///
+    /// Returns `false` and rolls the output back otherwise.
+    ///
+    fn single_line<W>(&mut self, write: W) -> bool
+    where
+        W: FnOnce(&mut Self),
+    {
+        let length = self.output.len();
+        let next_comment = self.next_comment;
+        let is_block_start = self.is_block_start;
+
+        write(self);
+        if self.output[length..].contains('\n') {
+            self.output.truncate(length);
+            self.next_comment = next_comment;
+            self.is_block_start = is_block_start;
+            return false;
+        }
+
+        true
+    }
+
+    ///
+    /// Writes the `pub` keyword if the item at `location` is public.
+    ///
+    fn visibility(&mut self, location: Location) {
+        if self.source.is_public(location) {
+            self.write("pub ");
+        }
+    }
+
+    ///
+    /// Starts a new item, e.g. a statement or a structure field, at `location`.
+    ///
+    /// Writes the comments preceding the item and keeps a blank line before it, if there is
+    /// one in the source code.
+    ///
+    fn item(&mut self, location: Location) {
+        self.comments(Some(location));
+        self.blank_line(location.line);
+        self.is_block_start = false;
+    }
+
+    ///
+    /// Writes the comments found before `location`, or all the remaining ones if it is `None`.
+    ///
+    fn comments(&mut self, location: Option<Location>) {
+        while let Some(comment) = self.source.comments.get(self.next_comment).cloned() {
+            if let Some(location) = location {
+                if !Self::is_before(comment.location, location) {
+                    break;
+                }
+            }
+            self.next_comment += 1;
+
+            if comment.is_trailing && self.output.ends_with('\n') {
+                self.output.pop();
+                self.output.push(' ');
+                self.output.push_str(comment.text.as_str());
+                self.output.push('\n');
+                continue;
+            }
+
+            self.blank_line(comment.location.line);
+            self.is_block_start = false;
+            self.write(comment.text.as_str());
+            self.newline();
+        }
+    }
+
+    ///
+    /// Checks whether there are unwritten comments before `location`.
+    ///
+    fn has_comments(&self, location: Option<Location>) -> bool {
+        match (self.source.comments.get(self.next_comment), location) {
+            (Some(comment), Some(location)) => Self::is_before(comment.location, location),
+            _ => false,
+        }
+    }
+
+    ///
+    /// Writes a blank line if the item at `line` is preceded by one in the source code.
+    ///
+    /// The blank lines are never written at the beginning of a block.
+    ///
+    fn blank_line(&mut self, line: usize) {
+        if self.is_block_start || !self.source.is_blank(line - 1) {
+            return;
+        }
+
+        if !self.output.is_empty() && !self.output.ends_with("\n\n") {
+            self.newline();
+        }
+    }
+
+    ///
+    /// Opens a multi-line bracketed list or block.
+    ///
+    fn open(&mut self, bracket: &str) {
+        self.write(bracket);
+        self.newline();
+        self.depth += 1;
+        self.is_block_start = true;
+    }
+
+    ///
+    /// Closes a multi-line bracketed list or block, whose closing bracket is found at `end`.
+    ///
+    fn close(&mut self, bracket: &str, end: Option<Location>) {
+        if end.is_some() {
+            self.comments(end);
+        }
+        self.depth -= 1;
+        self.is_block_start = false;
+        self.write(bracket);
+    }
+
+    ///
+    /// Writes the text, which must not contain line breaks, except for the block comments.
+    ///
+    fn write(&mut self, text: &str) {
+        if self.output.is_empty() || self.output.ends_with('\n') {
+            for _ in 0..self.depth {
+                self.output.push_str(Self::INDENT);
+            }
+        }
+        self.output.push_str(text);
+    }
+
+    ///
+    /// Continues the current expression on the next line, which is indented one level deeper
+    /// than the first one.
+    ///
+    fn line_break(&mut self) {
+        self.newline();
+        if self.continuation.map(|depth| depth + 1) != Some(self.depth) {
+            self.continuation = Some(self.depth);
+            self.depth += 1;
+        }
+    }
+
+    ///
+    /// Terminates the current line.
+    ///
+    fn newline(&mut self) {
+        self.output.push('\n');
+    }
+
+    ///
+    /// The location where the function statement begins, including its attributes.
+    ///
+    fn fn_start(statement: &FnStatement) -> Location {
+        statement
+            .attributes
+            .first()
+            .map(|attribute| attribute.location)
+            .unwrap_or(statement.location)
+    }
+
+    ///
+    /// Checks whether `location` precedes `other` in the source code.
+    ///
+    fn is_before(location: Location, other: Location) -> bool {
+        (location.line, location.column) < (other.line, other.column)
+    }
+}
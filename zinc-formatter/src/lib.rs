@@ -0,0 +1,20 @@
+//!
+//! The Zinc source code formatter.
+//!
+
+#[cfg(test)]
+mod tests;
+
+pub(crate) mod error;
+pub(crate) mod formatter;
+pub(crate) mod source;
+
+pub use self::error::Error;
+pub use self::formatter::Formatter;
+
+///
+/// Formats the Zinc source code `input` in the canonical style.
+///
+pub fn format(input: &str) -> Result<String, Error> {
+    Ok(Formatter::new(input)?.format())
+}
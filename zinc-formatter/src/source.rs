@@ -0,0 +1,280 @@
+//!
+//! The formatter source code index.
+//!
+
+use std::collections::HashMap;
+
+use zinc_lexical::Comment;
+use zinc_lexical::Keyword;
+use zinc_lexical::Lexeme;
+use zinc_lexical::Location;
+use zinc_lexical::Symbol;
+use zinc_lexical::Token;
+use zinc_lexical::TokenStream;
+
+use crate::error::Error;
+
+///
+/// The source code comment with its location.
+///
+#[derive(Debug, Clone)]
+pub struct SourceComment {
+    /// The comment location.
+    pub location: Location,
+    /// The comment text including the `//` or `/* */` delimiters.
+    pub text: String,
+    /// Whether the comment follows some code on the same line.
+    pub is_trailing: bool,
+}
+
+///
+/// The source code index, which keeps the information dropped by the syntax parser, that is,
+/// the comments, blank lines, bracket pairs, and the literal representations.
+///
+#[derive(Debug)]
+pub struct Source {
+    /// The source code lines without the line terminators.
+    lines: Vec<Vec<char>>,
+    /// The code tokens, that is, all the tokens except the comments.
+    tokens: Vec<Token>,
+    /// The code token indexes with their lines and columns as keys.
+    indexes: HashMap<(usize, usize), usize>,
+    /// The closing bracket locations with the opening bracket lines and columns as keys.
+    pairs: HashMap<(usize, usize), Location>,
+    /// The comments in the order of appearance.
+    pub comments: Vec<SourceComment>,
+}
+
+impl Source {
+    ///
+    /// Splits the `input` into tokens and builds the index.
+    ///
+    pub fn new(input: &str, file: usize) -> Result<Self, Error> {
+        let lines = input
+            .lines()
+            .map(|line| line.trim_end_matches('\r').chars().collect())
+            .collect();
+
+        let mut stream = TokenStream::new_with_comments(input, file);
+        let mut tokens = Vec::new();
+        let mut indexes = HashMap::new();
+        let mut pairs = HashMap::new();
+        let mut comments = Vec::new();
+
+        let mut openings = Vec::new();
+        let mut last_line = 0;
+        loop {
+            let token = stream.next()?;
+            let line = token.location.line;
+
+            match token.lexeme {
+                Lexeme::Eof => break,
+                Lexeme::Comment(ref comment) => {
+                    let text = match comment {
+                        Comment::Line { inner } => format!("//{}", inner.trim_end()),
+                        Comment::Block { inner } => format!("/*{}*/", inner),
+                    };
+                    comments.push(SourceComment {
+                        location: token.location,
+                        text,
+                        is_trailing: last_line == line,
+                    });
+                }
+                Lexeme::Symbol(Symbol::BracketCurlyLeft)
+                | Lexeme::Symbol(Symbol::BracketSquareLeft)
+                | Lexeme::Symbol(Symbol::ParenthesisLeft) => openings.push(token.location),
+                Lexeme::Symbol(Symbol::BracketCurlyRight)
+                | Lexeme::Symbol(Symbol::BracketSquareRight)
+                | Lexeme::Symbol(Symbol::ParenthesisRight) => {
+                    if let Some(opening) = openings.pop() {
+                        pairs.insert(Self::key(opening), token.location);
+                    }
+                }
+                _ => {}
+            }
+
+            if !matches!(token.lexeme, Lexeme::Comment(_)) {
+                indexes.insert(Self::key(token.location), tokens.len());
+                tokens.push(token);
+            }
+            last_line = line;
+        }
+
+        Ok(Self {
+            lines,
+            tokens,
+            indexes,
+            pairs,
+            comments,
+        })
+    }
+
+    ///
+    /// Checks whether the item at `location` is preceded by the `pub` keyword.
+    ///
+    /// The syntax tree only stores the visibility of functions and fields.
+    ///
+    pub fn is_public(&self, location: Location) -> bool {
+        self.indexes
+            .get(&Self::key(location))
+            .and_then(|index| index.checked_sub(1))
+            .map(|index| matches!(self.tokens[index].lexeme, Lexeme::Keyword(Keyword::Pub)))
+            .unwrap_or_default()
+    }
+
+    ///
+    /// Checks whether the token at `location` is the first one on its line.
+    ///
+    pub fn is_line_start(&self, location: Location) -> bool {
+        self.indexes
+            .get(&Self::key(location))
+            .and_then(|index| index.checked_sub(1))
+            .map(|index| self.tokens[index].location.line < location.line)
+            .unwrap_or_default()
+    }
+
+    ///
+    /// Checks whether the token at `location` is the last one on its line.
+    ///
+    pub fn is_line_end(&self, location: Location) -> bool {
+        self.indexes
+            .get(&Self::key(location))
+            .and_then(|index| self.tokens.get(index + 1))
+            .map(|token| token.location.line > location.line)
+            .unwrap_or_default()
+    }
+
+    ///
+    /// Returns the location of the parenthesis immediately preceding the token at `location`.
+    ///
+    /// The syntax tree does not store the parentheses, which are not required by the operator
+    /// precedence.
+    ///
+    pub fn parenthesis_before(&self, location: Location) -> Option<Location> {
+        let index = self.indexes.get(&Self::key(location))?.checked_sub(1)?;
+        let token = &self.tokens[index];
+        match token.lexeme {
+            Lexeme::Symbol(Symbol::ParenthesisLeft) => Some(token.location),
+            _ => None,
+        }
+    }
+
+    ///
+    /// Returns the location of the token following the one at `location`.
+    ///
+    pub fn next(&self, location: Location) -> Option<Location> {
+        let index = self.indexes.get(&Self::key(location))?;
+        self.tokens.get(index + 1).map(|token| token.location)
+    }
+
+    ///
+    /// Returns the location of the bracket closing the one at `location`.
+    ///
+    pub fn closing(&self, location: Location) -> Option<Location> {
+        self.pairs.get(&Self::key(location)).copied()
+    }
+
+    ///
+    /// Returns the location of the first `symbol` found at or after `location`.
+    ///
+    pub fn find_after(&self, location: Location, symbol: Symbol) -> Option<Location> {
+        let index = *self.indexes.get(&Self::key(location))?;
+        let symbol = Lexeme::Symbol(symbol);
+        self.tokens[index..]
+            .iter()
+            .find(|token| token.lexeme == symbol)
+            .map(|token| token.location)
+    }
+
+    ///
+    /// Returns the location of the curly bracket closing the first one found at or after
+    /// `location`.
+    ///
+    pub fn closing_curly_after(&self, location: Location) -> Option<Location> {
+        self.find_after(location, Symbol::BracketCurlyLeft)
+            .and_then(|location| self.closing(location))
+    }
+
+    ///
+    /// Returns the location of the curly bracket closing the last one found before `location`.
+    ///
+    pub fn closing_curly_before(&self, location: Location) -> Option<Location> {
+        let index = *self.indexes.get(&Self::key(location))?;
+        self.tokens[..index]
+            .iter()
+            .rev()
+            .find(|token| token.lexeme == Lexeme::Symbol(Symbol::BracketCurlyLeft))
+            .and_then(|token| self.closing(token.location))
+    }
+
+    ///
+    /// Returns the literal at `location` as it is written in the source code.
+    ///
+    /// The lexical analyzer drops the digit separators and the string escape sequences, so the
+    /// original representation is preserved instead of being restored from the lexeme.
+    ///
+    pub fn literal(&self, location: Location) -> Option<String> {
+        let line = self.lines.get(location.line.checked_sub(1)?)?;
+        let start = location.column.checked_sub(1)?;
+        let characters = line.get(start..)?;
+
+        let mut size = 0;
+        match characters.first()? {
+            '\"' => {
+                let mut is_escaped = false;
+                for (index, character) in characters.iter().enumerate().skip(1) {
+                    match character {
+                        '\\' if !is_escaped => is_escaped = true,
+                        '\"' if !is_escaped => {
+                            size = index + 1;
+                            break;
+                        }
+                        _ => is_escaped = false,
+                    }
+                }
+            }
+            character if character.is_ascii_digit() => {
+                let mut has_point = false;
+                while let Some(character) = characters.get(size) {
+                    if character.is_ascii_alphanumeric() || *character == '_' {
+                        size += 1;
+                    } else if *character == '.'
+                        && !has_point
+                        && characters
+                            .get(size + 1)
+                            .map(char::is_ascii_digit)
+                            .unwrap_or_default()
+                    {
+                        has_point = true;
+                        size += 1;
+                    } else {
+                        break;
+                    }
+                }
+            }
+            _ => return None,
+        }
+
+        if size == 0 {
+            return None;
+        }
+        Some(characters[..size].iter().collect())
+    }
+
+    ///
+    /// Checks whether the source code `line` is empty or consists of whitespaces.
+    ///
+    pub fn is_blank(&self, line: usize) -> bool {
+        line.checked_sub(1)
+            .and_then(|index| self.lines.get(index))
+            .map(|line| line.iter().all(|character| character.is_whitespace()))
+            .unwrap_or_default()
+    }
+
+    ///
+    /// Converts the location into a hashable key.
+    ///
+    fn key(location: Location) -> (usize, usize) {
+        (location.line, location.column)
+    }
+}
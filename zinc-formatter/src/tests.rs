@@ -0,0 +1,491 @@
+//!
+//! The formatter tests.
+//!
+
+use crate::error::Error;
+
+fn check(input: &str, expected: &str) {
+    let result = crate::format(input).expect("Formatting failure");
+    assert_eq!(result, expected);
+
+    let again = crate::format(result.as_str()).expect("Formatting failure");
+    assert_eq!(again, expected, "The formatting is not idempotent");
+}
+
+#[test]
+fn ok_canonical_unchanged() {
+    let input = r#"//!
+//! The module.
+//!
+
+use std::crypto::sha256;
+
+const LIMIT: u8 = 10;
+
+pub struct Data {
+    a: u8,
+    b: (u8, bool),
+}
+
+enum List {
+    First = 1,
+    Second = 2,
+}
+
+///
+/// The entry point.
+///
+fn main(witness: u8) -> u8 {
+    let mut sum = 0;
+    for i in 0..LIMIT while sum < 100 {
+        sum += i * witness;
+    }
+
+    if sum > 50 { sum } else { 0 }
+}
+
+#[test]
+fn default() {
+    require(main(1) == 45);
+}
+"#;
+
+    check(input, input);
+}
+
+#[test]
+fn ok_indentation_and_spacing() {
+    let input = r#"
+fn   main(a:u8,b : u8)->u8{
+let c=a+b*2;
+  if c>10{
+c-10
+}else{c}
+}
+"#;
+
+    let expected = r#"fn main(a: u8, b: u8) -> u8 {
+    let c = a + b * 2;
+    if c > 10 {
+        c - 10
+    } else { c }
+}
+"#;
+
+    check(input, expected);
+}
+
+#[test]
+fn ok_comments_preserved() {
+    let input = r#"// the constant
+const A: u8 = 1; // trailing
+
+fn main() -> u8 {
+    /* block */
+    let b = A;
+
+
+    // the result
+    b
+}
+"#;
+
+    let expected = r#"// the constant
+const A: u8 = 1; // trailing
+
+fn main() -> u8 {
+    /* block */
+    let b = A;
+
+    // the result
+    b
+}
+"#;
+
+    check(input, expected);
+}
+
+#[test]
+fn ok_data_enumeration() {
+    let input = r#"
+enum Shape<T>{
+  Empty,
+  Rectangle(T,T),
+  Circle{radius:T}
+}
+
+fn area(shape:Shape<u8>)->u8{
+    match shape {
+      Shape::Rectangle(width,height)=>width*height,
+      Shape::Circle{radius:r}=>r*r*3,
+      Shape::Empty=>0
+    }
+}
+"#;
+    let expected = r#"enum Shape<T> {
+    Empty,
+    Rectangle(T, T),
+    Circle { radius: T },
+}
+
+fn area(shape: Shape<u8>) -> u8 {
+    match shape {
+        Shape::Rectangle(width, height) => width * height,
+        Shape::Circle { radius: r } => r * r * 3,
+        Shape::Empty => 0,
+    }
+}
+"#;
+
+    check(input, expected);
+}
+
+#[test]
+fn ok_match_patterns() {
+    let input = r#"
+fn fee(amount:u8, kind:(bool,u8))->u8{
+    match kind {
+      (true,_)=>0,
+      (false,1|2)=>1,
+      (false,level) if level>amount=>level,
+      (false,3..=9)=>2,
+      _=>3
+    }
+}
+"#;
+    let expected = r#"fn fee(amount: u8, kind: (bool, u8)) -> u8 {
+    match kind {
+        (true, _) => 0,
+        (false, 1 | 2) => 1,
+        (false, level) if level > amount => level,
+        (false, 3..=9) => 2,
+        _ => 3,
+    }
+}
+"#;
+
+    check(input, expected);
+}
+
+#[test]
+fn ok_while_bound() {
+    let input = r#"
+fn root(x:u16)->u16{
+    let mut low=0;
+    let mut high=x;
+    while low<high  bound   16{
+        let middle=(low+high+1)/2;
+        if middle*middle<=x { low=middle; } else { high=middle-1; }
+    }
+    low
+}
+"#;
+    let expected = r#"fn root(x: u16) -> u16 {
+    let mut low = 0;
+    let mut high = x;
+    while low < high bound 16 {
+        let middle = (low + high + 1) / 2;
+        if middle * middle <= x {
+            low = middle;
+        } else {
+            high = middle - 1;
+        }
+    }
+    low
+}
+"#;
+
+    check(input, expected);
+}
+
+#[test]
+fn ok_closure() {
+    let input = r#"
+fn main(values:[u8;4])->(u8,bool){
+    let sum=std::array::fold(values,0,|acc,x:u8|acc+x);
+    (sum,std::array::any(values,|x|x==0||x>100))
+}
+"#;
+    let expected = r#"fn main(values: [u8; 4]) -> (u8, bool) {
+    let sum = std::array::fold(values, 0, |acc, x: u8| acc + x);
+    (sum, std::array::any(values, |x| x == 0 || x > 100))
+}
+"#;
+
+    check(input, expected);
+}
+
+#[test]
+fn ok_byte_string() {
+    let input = r#"
+fn main(balance:u64,amount:u64)->[u8;12]{
+    require(balance>=amount,"balance {} is less than {}",balance,amount);
+    std::string::concat(b"hello, ",b"w\"rld")
+}
+"#;
+    let expected = r#"fn main(balance: u64, amount: u64) -> [u8; 12] {
+    require(balance >= amount, "balance {} is less than {}", balance, amount);
+    std::string::concat(b"hello, ", b"w\"rld")
+}
+"#;
+
+    check(input, expected);
+}
+
+#[test]
+fn ok_comment_in_empty_block() {
+    let input = r#"fn main() {
+    // nothing to do
+}
+"#;
+
+    check(input, input);
+}
+
+#[test]
+fn ok_parentheses_removed() {
+    let input = r#"fn main() -> u8 {
+    let a = ((1 + 2)) * (3);
+    let b = 1 + (2 * 3);
+    let c = (1 - 2) - 3;
+    let d = 1 - (2 - 3);
+    (a + b + c + d)
+}
+"#;
+
+    let expected = r#"fn main() -> u8 {
+    let a = (1 + 2) * 3;
+    let b = 1 + 2 * 3;
+    let c = 1 - 2 - 3;
+    let d = 1 - (2 - 3);
+    a + b + c + d
+}
+"#;
+
+    check(input, expected);
+}
+
+#[test]
+fn ok_parentheses_kept_for_readability() {
+    let input = r#"fn main(a: bool, b: bool, c: u8) -> bool {
+    a && b || (c as u8) > 1 && a
+}
+"#;
+
+    let expected = r#"fn main(a: bool, b: bool, c: u8) -> bool {
+    (a && b) || ((c as u8) > 1 && a)
+}
+"#;
+
+    check(input, expected);
+}
+
+#[test]
+fn ok_literals_preserved() {
+    let input = r#"const A: u248 = 1_000_000;
+const B: u8 = 0xff;
+const C: u248 = 1.5_E3;
+
+fn main() {
+    require(A > 0, "escaped \"quotes\"");
+}
+"#;
+
+    check(input, input);
+}
+
+#[test]
+fn ok_visibility_preserved() {
+    let input = r#"pub mod inner;
+pub use inner::Data;
+pub type Alias = u8;
+pub const VALUE: u8 = 42;
+
+contract Test {
+    pub value: u8;
+    hidden: u8;
+
+    pub fn get(self) -> u8 {
+        self.value
+    }
+}
+"#;
+
+    check(input, input);
+}
+
+#[test]
+fn ok_empty_statements_removed() {
+    let input = r#"const A: u8 = 1;;
+
+fn main() {
+    let a = 1;;
+    ;
+}
+"#;
+
+    let expected = r#"const A: u8 = 1;
+
+fn main() {
+    let a = 1;
+}
+"#;
+
+    check(input, expected);
+}
+
+#[test]
+fn ok_multiline_lists_preserved() {
+    let input = r#"fn main(
+    a: u8,
+    b: u8
+) -> [u8; 3] {
+    [
+        a,
+        b,
+        a + b
+    ]
+}
+"#;
+
+    let expected = r#"fn main(
+    a: u8,
+    b: u8,
+) -> [u8; 3] {
+    [
+        a,
+        b,
+        a + b,
+    ]
+}
+"#;
+
+    check(input, expected);
+}
+
+#[test]
+fn ok_structure_literal() {
+    let input = r#"fn main() -> Data {
+    let a = Data { a: 1, b: 2 };
+    Data {
+        a: 1,
+        b: a.b
+    }
+}
+"#;
+
+    let expected = r#"fn main() -> Data {
+    let a = Data { a: 1, b: 2 };
+    Data {
+        a: 1,
+        b: a.b,
+    }
+}
+"#;
+
+    check(input, expected);
+}
+
+#[test]
+fn ok_match() {
+    let input = r#"fn main(value: u8) -> u8 {
+    let a = match value { 1 => 10, _ => 0 };
+    match value {
+        1 => 10,
+        2 => { 20 },
+        _ => 0
+    }
+}
+"#;
+
+    let expected = r#"fn main(value: u8) -> u8 {
+    let a = match value { 1 => 10, _ => 0 };
+    match value {
+        1 => 10,
+        2 => { 20 },
+        _ => 0,
+    }
+}
+"#;
+
+    check(input, expected);
+}
+
+#[test]
+fn ok_else_if() {
+    let input = r#"fn main(a: u8) -> u8 {
+    if a == 1 {
+        1
+    } else if a == 2 {
+        2
+    } else {
+        3
+    }
+}
+"#;
+
+    check(input, input);
+}
+
+#[test]
+fn ok_line_breaks_preserved() {
+    let input = r#"fn main() -> u8 {
+    let a = 1 +
+    2 +
+    3;
+    Data::new()
+    .first()
+    .second()
+}
+"#;
+
+    let expected = r#"fn main() -> u8 {
+    let a = 1 +
+        2 +
+        3;
+    Data::new()
+        .first()
+        .second()
+}
+"#;
+
+    check(input, expected);
+}
+
+#[test]
+fn ok_tuple_index_chain() {
+    let input = r#"fn main() -> u8 {
+    let tuple = ((1, 2), 3);
+    (tuple.0).1
+}
+"#;
+
+    check(input, input);
+}
+
+#[test]
+fn ok_single_element_tuples() {
+    let input = r#"fn main() -> (u8,) {
+    let (a,): (u8,) = (1,);
+    (a,)
+}
+"#;
+
+    check(input, input);
+}
+
+#[test]
+fn ok_crlf_preserved() {
+    let input = "fn main() {\r\n    let a = 1;\r\n}\r\n";
+
+    check(input, input);
+}
+
+#[test]
+fn error_syntax() {
+    let input = r#"fn main( {}"#;
+
+    let result = crate::format(input);
+
+    assert!(matches!(result, Err(Error::Parsing(_))));
+}
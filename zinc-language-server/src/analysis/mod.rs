@@ -0,0 +1,333 @@
+//!
+//! The language server project analysis.
+//!
+
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::path::Path;
+use std::path::PathBuf;
+use std::rc::Rc;
+
+use lsp_types::Diagnostic as LspDiagnostic;
+use lsp_types::DiagnosticRelatedInformation;
+use lsp_types::DiagnosticSeverity;
+use lsp_types::Location as LspLocation;
+use lsp_types::NumberOrString;
+use lsp_types::Position;
+use lsp_types::Range;
+use lsp_types::Url;
+
+use zinc_compiler::Bundler;
+use zinc_compiler::Diagnostic;
+use zinc_compiler::Scope;
+use zinc_compiler::ScopeItem;
+use zinc_compiler::SemanticPath;
+use zinc_compiler::SourceError;
+use zinc_lexical::Location;
+use zinc_lexical::FILE_INDEX;
+use zinc_syntax::Identifier;
+
+use crate::document::Document;
+
+///
+/// The result of the semantic analysis of a project.
+///
+#[derive(Debug, Default)]
+pub struct Analysis {
+    /// The entry module scope, if the project has been analyzed without errors.
+    pub scope: Option<Rc<RefCell<Scope>>>,
+    /// The diagnostics grouped by the document URL.
+    pub diagnostics: HashMap<Url, Vec<LspDiagnostic>>,
+}
+
+impl Analysis {
+    /// The diagnostic source name shown by the editor.
+    const DIAGNOSTIC_SOURCE: &'static str = "zinc";
+
+    ///
+    /// Runs the semantic analysis of the project at `project_path`.
+    ///
+    /// The source code of the `documents` opened in the editor takes precedence over the source
+    /// code files stored on the disk.
+    ///
+    pub fn run(project_path: &PathBuf, documents: &HashMap<Url, Document>) -> Self {
+        match Self::analyze(project_path, documents) {
+            Ok(scope) => Self {
+                scope: Some(scope),
+                diagnostics: HashMap::new(),
+            },
+            Err(error) => {
+                let mut diagnostics: HashMap<Url, Vec<LspDiagnostic>> = HashMap::new();
+
+                match error.downcast_ref::<SourceError>() {
+                    Some(SourceError::Compiling(inner)) => {
+                        for diagnostic in inner.iter() {
+                            if let Some((url, diagnostic)) =
+                                Self::convert_diagnostic(project_path, diagnostic)
+                            {
+                                diagnostics.entry(url).or_default().push(diagnostic);
+                            }
+                        }
+                    }
+                    _ => {
+                        let mut manifest_path = project_path.to_owned();
+                        manifest_path.push(format!(
+                            "{}.{}",
+                            zinc_const::file_name::MANIFEST,
+                            zinc_const::extension::MANIFEST
+                        ));
+
+                        if let Ok(url) = Url::from_file_path(manifest_path) {
+                            diagnostics.entry(url).or_default().push(LspDiagnostic::new(
+                                Range::default(),
+                                Some(DiagnosticSeverity::ERROR),
+                                None,
+                                Some(Self::DIAGNOSTIC_SOURCE.to_owned()),
+                                format!("{:#}", error),
+                                None,
+                                None,
+                            ));
+                        }
+                    }
+                }
+
+                Self {
+                    scope: None,
+                    diagnostics,
+                }
+            }
+        }
+    }
+
+    ///
+    /// Finds the project directory containing the document at `path`, that is, the closest
+    /// parent directory with the project manifest file.
+    ///
+    pub fn project_path(path: &Path) -> Option<PathBuf> {
+        let manifest_name = format!(
+            "{}.{}",
+            zinc_const::file_name::MANIFEST,
+            zinc_const::extension::MANIFEST
+        );
+
+        path.ancestors()
+            .skip(1)
+            .find(|directory| directory.join(manifest_name.as_str()).is_file())
+            .map(Path::to_path_buf)
+    }
+
+    ///
+    /// Returns the scope of the module stored in the file at `path`.
+    ///
+    /// The module is found by its path relative to the project source code directory, e.g.
+    /// `src/foo/bar.zn` and `src/foo/bar/mod.zn` are both resolved as `foo::bar`.
+    ///
+    pub fn module_scope(&self, project_path: &PathBuf, path: &Path) -> Option<Rc<RefCell<Scope>>> {
+        let mut source_directory_path = project_path.to_owned();
+        source_directory_path.push(zinc_const::directory::SOURCE);
+
+        let relative = path.strip_prefix(&source_directory_path).ok()?;
+        let mut names: Vec<String> = relative
+            .with_extension("")
+            .iter()
+            .map(|name| name.to_string_lossy().to_string())
+            .collect();
+        match names.last().map(String::as_str) {
+            Some(name) if name == zinc_const::file_name::MODULE_ENTRY => {
+                names.pop();
+            }
+            Some(name)
+                if names.len() == 1
+                    && (name == zinc_const::file_name::APPLICATION_ENTRY
+                        || name == zinc_const::file_name::LIBRARY_ENTRY) =>
+            {
+                names.pop();
+            }
+            _ => {}
+        }
+
+        let mut scope = self.scope.to_owned()?;
+        for name in names.into_iter() {
+            let item = RefCell::borrow(&scope)
+                .resolve_item(&Identifier::new(Location::default(), name), false)
+                .ok()?;
+            let namespace = RefCell::borrow(&item).namespace()?;
+            scope = namespace;
+        }
+        Some(scope)
+    }
+
+    ///
+    /// Resolves the item at the path consisting of `elements` within the module `scope`.
+    ///
+    pub fn resolve(
+        scope: Rc<RefCell<Scope>>,
+        elements: Vec<Identifier>,
+    ) -> Option<Rc<RefCell<ScopeItem>>> {
+        let location = elements.first()?.location;
+        Scope::resolve_path(scope, &SemanticPath::new_complex(location, elements)).ok()
+    }
+
+    ///
+    /// Returns the items available for completion after the path consisting of `elements`.
+    ///
+    /// If the path is empty, the items of the module `scope` and all its parent scopes are
+    /// returned, including the intrinsic `std` and `zksync` libraries.
+    ///
+    pub fn completions(
+        scope: Rc<RefCell<Scope>>,
+        elements: Vec<Identifier>,
+    ) -> Vec<(String, Rc<RefCell<ScopeItem>>)> {
+        if elements.is_empty() {
+            let mut items = Vec::new();
+            let mut current = Some(scope);
+            while let Some(scope) = current {
+                items.extend(RefCell::borrow(&scope).get_items());
+                current = RefCell::borrow(&scope).parent();
+            }
+            return items;
+        }
+
+        Self::resolve(scope, elements)
+            .and_then(|item| RefCell::borrow(&item).namespace())
+            .map(|namespace| RefCell::borrow(&namespace).get_items())
+            .unwrap_or_default()
+    }
+
+    ///
+    /// Converts the compiler location into the language server protocol location.
+    ///
+    /// The virtual source code file paths are relative to the project directory.
+    ///
+    pub fn convert_location(project_path: &Path, location: Location) -> Option<LspLocation> {
+        let path = FILE_INDEX.get_path(location.file);
+        let path = if path.is_relative() {
+            project_path.join(path)
+        } else {
+            path
+        };
+
+        let url = Url::from_file_path(path).ok()?;
+        let start = Document::location_to_position(location);
+        let end = Position::new(
+            start.line,
+            start.character + Self::word_length(location) as u32,
+        );
+
+        Some(LspLocation::new(url, Range::new(start, end)))
+    }
+
+    ///
+    /// Converts the compiler diagnostic into the language server protocol one.
+    ///
+    /// The help message is appended to the diagnostic message, and the reference location is
+    /// passed as the related information.
+    ///
+    fn convert_diagnostic(
+        project_path: &Path,
+        diagnostic: &Diagnostic,
+    ) -> Option<(Url, LspDiagnostic)> {
+        let mut location = Self::convert_location(project_path, diagnostic.location)?;
+        if let Some(end) = diagnostic.end {
+            location.range.end = Document::location_to_position(end);
+        }
+
+        let message = match diagnostic.help {
+            Some(ref help) => format!("{}\nhelp: {}", diagnostic.message, help),
+            None => diagnostic.message.to_owned(),
+        };
+
+        let related_information = diagnostic
+            .reference
+            .and_then(|reference| Self::convert_location(project_path, reference))
+            .map(|reference| {
+                vec![DiagnosticRelatedInformation {
+                    location: reference,
+                    message: "referenced here".to_owned(),
+                }]
+            });
+
+        let diagnostic = LspDiagnostic::new(
+            location.range,
+            Some(DiagnosticSeverity::ERROR),
+            diagnostic
+                .code
+                .map(|code| NumberOrString::String(format!("{:04}", code))),
+            Some(Self::DIAGNOSTIC_SOURCE.to_owned()),
+            message,
+            related_information,
+            None,
+        );
+
+        Some((location.uri, diagnostic))
+    }
+
+    ///
+    /// Runs the bundler over the virtual project source code.
+    ///
+    fn analyze(
+        project_path: &PathBuf,
+        documents: &HashMap<Url, Document>,
+    ) -> anyhow::Result<Rc<RefCell<Scope>>> {
+        let mut source_directory_path = project_path.to_owned();
+        source_directory_path.push(zinc_const::directory::SOURCE);
+
+        let mut dependencies_directory_path = project_path.to_owned();
+        dependencies_directory_path.push(zinc_const::directory::TARGET_DEPS);
+
+        let mut source =
+            zinc_project::Source::try_from_path(&source_directory_path, project_path, true)?;
+        Self::overlay(&mut source, project_path, documents);
+
+        Bundler::new(project_path.to_owned(), dependencies_directory_path, false).analyze(source)
+    }
+
+    ///
+    /// Replaces the virtual source code files with the documents opened in the editor.
+    ///
+    fn overlay(
+        source: &mut zinc_project::Source,
+        project_path: &PathBuf,
+        documents: &HashMap<Url, Document>,
+    ) {
+        match source {
+            zinc_project::Source::File(file) => {
+                let document = Url::from_file_path(project_path.join(file.path.as_str()))
+                    .ok()
+                    .and_then(|url| documents.get(&url));
+                if let Some(document) = document {
+                    file.code = document.text.to_owned();
+                }
+            }
+            zinc_project::Source::Directory(directory) => {
+                for module in directory.modules.values_mut() {
+                    Self::overlay(module, project_path, documents);
+                }
+            }
+        }
+    }
+
+    ///
+    /// The length of the word starting at `location`, which is used to highlight the erroneous
+    /// identifier or keyword. Defaults to one character for other tokens.
+    ///
+    fn word_length(location: Location) -> usize {
+        let index = FILE_INDEX
+            .inner
+            .read()
+            .expect(zinc_const::panic::SYNCHRONIZATION);
+
+        let length = index
+            .get(&location.file)
+            .and_then(|data| data.code.lines().nth(location.line.saturating_sub(1)))
+            .map(|line| {
+                line.chars()
+                    .skip(location.column.saturating_sub(1))
+                    .take_while(|character| character.is_alphanumeric() || *character == '_')
+                    .count()
+            })
+            .unwrap_or_default();
+
+        length.max(1)
+    }
+}
@@ -0,0 +1,224 @@
+//!
+//! The language server text document.
+//!
+
+#[cfg(test)]
+mod tests;
+
+pub mod symbol;
+
+use lsp_types::DocumentSymbol;
+use lsp_types::Position;
+use lsp_types::Range;
+
+use zinc_lexical::Keyword;
+use zinc_lexical::Lexeme;
+use zinc_lexical::Location;
+use zinc_lexical::Symbol;
+use zinc_lexical::Token;
+use zinc_lexical::TokenStream;
+use zinc_syntax::Identifier;
+use zinc_syntax::Parser;
+
+use self::symbol::Builder as SymbolBuilder;
+
+///
+/// The text document opened in the editor.
+///
+/// The document text may differ from the file contents on the disk until the document is saved.
+///
+#[derive(Debug, Clone)]
+pub struct Document {
+    /// The document text, which is fully synchronized on every change.
+    pub text: String,
+}
+
+impl Document {
+    ///
+    /// The file identifier used for the tokens and syntax trees of the standalone documents.
+    ///
+    /// Such locations are never looked up in the global file index.
+    ///
+    const FILE_ID: usize = 0;
+
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(text: String) -> Self {
+        Self { text }
+    }
+
+    ///
+    /// Returns the symbol tree of the document module-level items.
+    ///
+    /// Returns an empty list if the document contains syntax errors.
+    ///
+    pub fn symbols(&self) -> Vec<DocumentSymbol> {
+        match Parser::default().parse(self.text.as_str(), Self::FILE_ID) {
+            Ok(module) => SymbolBuilder::module(&module),
+            Err(_errors) => vec![],
+        }
+    }
+
+    ///
+    /// Returns the path expression elements up to the identifier under the cursor `position`,
+    /// and the range of that identifier.
+    ///
+    /// For example, with the cursor on `crypto` in `std::crypto::sha256`, the elements are
+    /// `std` and `crypto`.
+    ///
+    pub fn path_at(&self, position: Position) -> Option<(Vec<Identifier>, Range)> {
+        let location = Self::position_to_location(position);
+        let tokens = self.tokens();
+
+        let index = tokens.iter().position(|token| {
+            Self::path_element(token).is_some()
+                && token.location.line == location.line
+                && token.location.column <= location.column
+                && location.column <= token.location.column + Self::length(token)
+        })?;
+
+        let identifier = Self::path_element(&tokens[index])?;
+        let range = Range::new(
+            Self::location_to_position(identifier.location),
+            Self::location_to_position(
+                identifier
+                    .location
+                    .shifted_right(Self::length(&tokens[index])),
+            ),
+        );
+
+        let mut elements = Self::path_before(&tokens[..index]);
+        elements.push(identifier);
+
+        Some((elements, range))
+    }
+
+    ///
+    /// Returns the path expression elements preceding the `::` operator before the cursor
+    /// `position`, which is being completed.
+    ///
+    /// If the identifier under the cursor is not a part of a path, the list is empty.
+    ///
+    pub fn completion_prefix(&self, position: Position) -> Vec<Identifier> {
+        let location = Self::position_to_location(position);
+        let tokens = self.tokens();
+
+        let mut end = tokens
+            .iter()
+            .take_while(|token| {
+                token.location.line < location.line
+                    || (token.location.line == location.line
+                        && token.location.column + Self::length(token) <= location.column)
+            })
+            .count();
+
+        if let Some(token) = end.checked_sub(1).and_then(|index| tokens.get(index)) {
+            if Self::path_element(token).is_some()
+                && token.location.line == location.line
+                && token.location.column + Self::length(token) == location.column
+            {
+                end -= 1;
+            }
+        }
+
+        match end.checked_sub(1).and_then(|index| tokens.get(index)) {
+            Some(Token {
+                lexeme: Lexeme::Symbol(Symbol::DoubleColon),
+                ..
+            }) => Self::path_before(&tokens[..end]),
+            _ => vec![],
+        }
+    }
+
+    ///
+    /// Converts the 1-based compiler location into the 0-based language server protocol position.
+    ///
+    pub fn location_to_position(location: Location) -> Position {
+        Position::new(
+            location.line.saturating_sub(1) as u32,
+            location.column.saturating_sub(1) as u32,
+        )
+    }
+
+    ///
+    /// Converts the 0-based language server protocol position into the 1-based compiler location.
+    ///
+    pub fn position_to_location(position: Position) -> Location {
+        Location {
+            line: position.line as usize + 1,
+            column: position.character as usize + 1,
+            file: Self::FILE_ID,
+        }
+    }
+
+    ///
+    /// Collects the path elements, which are followed by the `::` operator at the end of `tokens`.
+    ///
+    fn path_before(tokens: &[Token]) -> Vec<Identifier> {
+        let mut elements = Vec::new();
+
+        let mut index = tokens.len();
+        while index >= 2 {
+            match (&tokens[index - 2], &tokens[index - 1].lexeme) {
+                (token, Lexeme::Symbol(Symbol::DoubleColon)) => match Self::path_element(token) {
+                    Some(identifier) => elements.push(identifier),
+                    None => break,
+                },
+                _ => break,
+            }
+            index -= 2;
+        }
+
+        elements.reverse();
+        elements
+    }
+
+    ///
+    /// Converts the token into a path element, if it is an identifier or a path alias keyword.
+    ///
+    fn path_element(token: &Token) -> Option<Identifier> {
+        match token.lexeme {
+            Lexeme::Identifier(ref identifier) => {
+                Some(Identifier::new(token.location, identifier.inner.to_owned()))
+            }
+            Lexeme::Keyword(ref keyword) if Keyword::is_alias(keyword.to_string().as_str()) => {
+                Some(Identifier::new(token.location, keyword.to_string()))
+            }
+            _ => None,
+        }
+    }
+
+    ///
+    /// The token length in characters.
+    ///
+    /// Only the identifiers, keywords, and symbols are measured precisely, since their
+    /// representation in the source code is always the same.
+    ///
+    fn length(token: &Token) -> usize {
+        match token.lexeme {
+            Lexeme::Identifier(ref identifier) => identifier.inner.chars().count(),
+            Lexeme::Keyword(ref keyword) => keyword.to_string().chars().count(),
+            Lexeme::Symbol(ref symbol) => symbol.to_string().chars().count(),
+            _ => 1,
+        }
+    }
+
+    ///
+    /// Splits the document text into tokens.
+    ///
+    /// Stops at the first lexical error, since the document is often incomplete while being edited.
+    ///
+    fn tokens(&self) -> Vec<Token> {
+        let mut stream = TokenStream::new(self.text.as_str(), Self::FILE_ID);
+
+        let mut tokens = Vec::new();
+        while let Ok(token) = stream.next() {
+            if let Lexeme::Eof = token.lexeme {
+                break;
+            }
+            tokens.push(token);
+        }
+        tokens
+    }
+}
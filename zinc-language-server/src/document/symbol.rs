@@ -0,0 +1,246 @@
+//!
+//! The language server document symbol builder.
+//!
+
+use lsp_types::DocumentSymbol;
+use lsp_types::Range;
+use lsp_types::SymbolKind;
+
+use zinc_lexical::Location;
+use zinc_syntax::ContractLocalStatement;
+use zinc_syntax::FnPrototype;
+use zinc_syntax::FnStatement;
+use zinc_syntax::Identifier;
+use zinc_syntax::ImplementationLocalStatement;
+use zinc_syntax::Module;
+use zinc_syntax::ModuleLocalStatement;
+
+use crate::document::Document;
+
+///
+/// The document symbol builder, which converts the syntax tree items into the symbol tree.
+///
+pub struct Builder {}
+
+impl Builder {
+    ///
+    /// Builds the symbols of the module-level items.
+    ///
+    /// The `use` and empty statements are skipped, since they do not declare anything.
+    ///
+    pub fn module(module: &Module) -> Vec<DocumentSymbol> {
+        module
+            .statements
+            .iter()
+            .filter_map(|statement| match statement {
+                ModuleLocalStatement::Const(inner) => Some(Self::symbol(
+                    inner.location,
+                    &inner.identifier,
+                    SymbolKind::CONSTANT,
+                    None,
+                    vec![],
+                )),
+                ModuleLocalStatement::Type(inner) => Some(Self::symbol(
+                    inner.location,
+                    &inner.identifier,
+                    SymbolKind::TYPE_PARAMETER,
+                    None,
+                    vec![],
+                )),
+                ModuleLocalStatement::Struct(inner) => Some(Self::symbol(
+                    inner.location,
+                    &inner.identifier,
+                    SymbolKind::STRUCT,
+                    None,
+                    inner
+                        .fields
+                        .iter()
+                        .map(|field| {
+                            Self::symbol(
+                                field.location,
+                                &field.identifier,
+                                SymbolKind::FIELD,
+                                None,
+                                vec![],
+                            )
+                        })
+                        .collect(),
+                )),
+                ModuleLocalStatement::Enum(inner) => Some(Self::symbol(
+                    inner.location,
+                    &inner.identifier,
+                    SymbolKind::ENUM,
+                    None,
+                    inner
+                        .variants
+                        .iter()
+                        .map(|variant| {
+                            Self::symbol(
+                                variant.location,
+                                &variant.identifier,
+                                SymbolKind::ENUM_MEMBER,
+                                variant
+                                    .literal
+                                    .as_ref()
+                                    .map(|literal| literal.inner.to_string()),
+                                vec![],
+                            )
+                        })
+                        .collect(),
+                )),
+                ModuleLocalStatement::Fn(inner) => {
+                    Some(Self::function(inner, SymbolKind::FUNCTION))
+                }
+                ModuleLocalStatement::Mod(inner) => Some(Self::symbol(
+                    inner.location,
+                    &inner.identifier,
+                    SymbolKind::MODULE,
+                    None,
+                    vec![],
+                )),
+                ModuleLocalStatement::Trait(inner) => Some(Self::symbol(
+                    inner.location,
+                    &inner.identifier,
+                    SymbolKind::INTERFACE,
+                    None,
+                    inner.methods.iter().map(Self::prototype).collect(),
+                )),
+                ModuleLocalStatement::Impl(inner) => Some(Self::symbol(
+                    inner.location,
+                    &inner.identifier,
+                    SymbolKind::OBJECT,
+                    inner
+                        .r#trait
+                        .as_ref()
+                        .map(|r#trait| r#trait.name.to_owned()),
+                    inner
+                        .statements
+                        .iter()
+                        .filter_map(|statement| match statement {
+                            ImplementationLocalStatement::Const(inner) => Some(Self::symbol(
+                                inner.location,
+                                &inner.identifier,
+                                SymbolKind::CONSTANT,
+                                None,
+                                vec![],
+                            )),
+                            ImplementationLocalStatement::Fn(inner) => {
+                                Some(Self::function(inner, SymbolKind::METHOD))
+                            }
+                            ImplementationLocalStatement::Empty(_location) => None,
+                        })
+                        .collect(),
+                )),
+                ModuleLocalStatement::Contract(inner) => Some(Self::symbol(
+                    inner.location,
+                    &inner.identifier,
+                    SymbolKind::CLASS,
+                    None,
+                    inner
+                        .statements
+                        .iter()
+                        .filter_map(|statement| match statement {
+                            ContractLocalStatement::Field(inner) => Some(Self::symbol(
+                                inner.location,
+                                &inner.identifier,
+                                SymbolKind::FIELD,
+                                None,
+                                vec![],
+                            )),
+                            ContractLocalStatement::Const(inner) => Some(Self::symbol(
+                                inner.location,
+                                &inner.identifier,
+                                SymbolKind::CONSTANT,
+                                None,
+                                vec![],
+                            )),
+                            ContractLocalStatement::Fn(inner) => {
+                                Some(Self::function(inner, SymbolKind::METHOD))
+                            }
+                            ContractLocalStatement::Empty(_location) => None,
+                        })
+                        .collect(),
+                )),
+                ModuleLocalStatement::Use(_) => None,
+                ModuleLocalStatement::Empty(_location) => None,
+            })
+            .collect()
+    }
+
+    ///
+    /// Builds the function symbol.
+    ///
+    fn function(statement: &FnStatement, kind: SymbolKind) -> DocumentSymbol {
+        Self::symbol(
+            statement.location,
+            &statement.identifier,
+            kind,
+            None,
+            vec![],
+        )
+    }
+
+    ///
+    /// Builds the trait method prototype symbol.
+    ///
+    fn prototype(prototype: &FnPrototype) -> DocumentSymbol {
+        Self::symbol(
+            prototype.location,
+            &prototype.identifier,
+            SymbolKind::METHOD,
+            None,
+            vec![],
+        )
+    }
+
+    ///
+    /// Builds a symbol declared at `location` with the name `identifier`.
+    ///
+    /// The syntax tree does not store the end locations, so the symbol range spans from the
+    /// declaration start to the end of its name or its last child, whichever is farther.
+    ///
+    #[allow(deprecated)]
+    fn symbol(
+        location: Location,
+        identifier: &Identifier,
+        kind: SymbolKind,
+        detail: Option<String>,
+        children: Vec<DocumentSymbol>,
+    ) -> DocumentSymbol {
+        let selection_range = Range::new(
+            Document::location_to_position(identifier.location),
+            Document::location_to_position(
+                identifier
+                    .location
+                    .shifted_right(identifier.name.chars().count()),
+            ),
+        );
+
+        let end =
+            children
+                .iter()
+                .map(|child| child.range.end)
+                .fold(selection_range.end, |end, child| {
+                    if (child.line, child.character) > (end.line, end.character) {
+                        child
+                    } else {
+                        end
+                    }
+                });
+
+        DocumentSymbol {
+            name: identifier.name.to_owned(),
+            detail,
+            kind,
+            tags: None,
+            deprecated: None,
+            range: Range::new(Document::location_to_position(location), end),
+            selection_range,
+            children: if children.is_empty() {
+                None
+            } else {
+                Some(children)
+            },
+        }
+    }
+}
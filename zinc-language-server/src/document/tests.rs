@@ -0,0 +1,190 @@
+//!
+//! The language server document tests.
+//!
+
+use lsp_types::Position;
+use lsp_types::Range;
+use lsp_types::SymbolKind;
+
+use crate::document::Document;
+
+#[test]
+fn ok_path_at_last_element() {
+    let document = Document::new(
+        r#"
+fn main() {
+    let hash = std::crypto::sha256(bits);
+}
+"#
+        .to_owned(),
+    );
+
+    let (elements, range) = document
+        .path_at(Position::new(2, 30))
+        .expect("Must be resolved");
+
+    let result: Vec<String> = elements.into_iter().map(|element| element.name).collect();
+
+    assert_eq!(result, vec!["std", "crypto", "sha256"]);
+    assert_eq!(
+        range,
+        Range::new(Position::new(2, 28), Position::new(2, 34))
+    );
+}
+
+#[test]
+fn ok_path_at_middle_element() {
+    let document = Document::new(
+        r#"
+fn main() {
+    let hash = std::crypto::sha256(bits);
+}
+"#
+        .to_owned(),
+    );
+
+    let (elements, range) = document
+        .path_at(Position::new(2, 22))
+        .expect("Must be resolved");
+
+    let result: Vec<String> = elements.into_iter().map(|element| element.name).collect();
+
+    assert_eq!(result, vec!["std", "crypto"]);
+    assert_eq!(
+        range,
+        Range::new(Position::new(2, 20), Position::new(2, 26))
+    );
+}
+
+#[test]
+fn ok_path_at_alias() {
+    let document = Document::new(
+        r#"
+fn main() -> u8 {
+    crate::VALUE
+}
+"#
+        .to_owned(),
+    );
+
+    let (elements, _range) = document
+        .path_at(Position::new(2, 12))
+        .expect("Must be resolved");
+
+    let result: Vec<String> = elements.into_iter().map(|element| element.name).collect();
+
+    assert_eq!(result, vec!["crate", "VALUE"]);
+}
+
+#[test]
+fn ok_completion_prefix_after_operator() {
+    let document = Document::new(
+        r#"
+fn main() {
+    let value = zksync::
+}
+"#
+        .to_owned(),
+    );
+
+    let result: Vec<String> = document
+        .completion_prefix(Position::new(2, 24))
+        .into_iter()
+        .map(|element| element.name)
+        .collect();
+
+    assert_eq!(result, vec!["zksync"]);
+}
+
+#[test]
+fn ok_completion_prefix_partial_identifier() {
+    let document = Document::new(
+        r#"
+fn main() {
+    let value = std::crypto::sh
+}
+"#
+        .to_owned(),
+    );
+
+    let result: Vec<String> = document
+        .completion_prefix(Position::new(2, 31))
+        .into_iter()
+        .map(|element| element.name)
+        .collect();
+
+    assert_eq!(result, vec!["std", "crypto"]);
+}
+
+#[test]
+fn ok_completion_prefix_empty() {
+    let document = Document::new(
+        r#"
+fn main() {
+    let value = va
+}
+"#
+        .to_owned(),
+    );
+
+    let result = document.completion_prefix(Position::new(2, 18));
+
+    assert!(result.is_empty());
+}
+
+#[test]
+fn ok_symbols() {
+    let document = Document::new(
+        r#"
+const VALUE: u8 = 42;
+
+struct Data {
+    a: u8,
+    b: field,
+}
+
+enum List {
+    First = 1,
+}
+
+fn main() {}
+"#
+        .to_owned(),
+    );
+
+    let result: Vec<(String, SymbolKind, usize)> = document
+        .symbols()
+        .into_iter()
+        .map(|symbol| {
+            (
+                symbol.name,
+                symbol.kind,
+                symbol.children.map(|children| children.len()).unwrap_or(0),
+            )
+        })
+        .collect();
+
+    assert_eq!(
+        result,
+        vec![
+            ("VALUE".to_owned(), SymbolKind::CONSTANT, 0),
+            ("Data".to_owned(), SymbolKind::STRUCT, 2),
+            ("List".to_owned(), SymbolKind::ENUM, 1),
+            ("main".to_owned(), SymbolKind::FUNCTION, 0),
+        ]
+    );
+}
+
+#[test]
+fn ok_symbols_syntax_error() {
+    let document = Document::new(
+        r#"
+fn main() {
+"#
+        .to_owned(),
+    );
+
+    let result = document.symbols();
+
+    assert!(result.is_empty());
+}
@@ -0,0 +1,11 @@
+//!
+//! The Zinc language server library.
+//!
+
+pub(crate) mod analysis;
+pub(crate) mod document;
+pub(crate) mod server;
+
+pub use self::analysis::Analysis;
+pub use self::document::Document;
+pub use self::server::Server;
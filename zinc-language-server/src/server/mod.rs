@@ -0,0 +1,388 @@
+//!
+//! The language server.
+//!
+
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::collections::HashSet;
+use std::path::PathBuf;
+use std::rc::Rc;
+
+use lsp_server::Connection;
+use lsp_server::ErrorCode;
+use lsp_server::Message;
+use lsp_server::Notification;
+use lsp_server::Request;
+use lsp_server::RequestId;
+use lsp_server::Response;
+use lsp_types::notification::DidChangeTextDocument;
+use lsp_types::notification::DidCloseTextDocument;
+use lsp_types::notification::DidOpenTextDocument;
+use lsp_types::notification::DidSaveTextDocument;
+use lsp_types::notification::Notification as _;
+use lsp_types::notification::PublishDiagnostics;
+use lsp_types::request::Completion;
+use lsp_types::request::DocumentSymbolRequest;
+use lsp_types::request::GotoDefinition;
+use lsp_types::request::HoverRequest;
+use lsp_types::request::Request as _;
+use lsp_types::CompletionItem;
+use lsp_types::CompletionItemKind;
+use lsp_types::CompletionOptions;
+use lsp_types::CompletionParams;
+use lsp_types::CompletionResponse;
+use lsp_types::DidChangeTextDocumentParams;
+use lsp_types::DidCloseTextDocumentParams;
+use lsp_types::DidOpenTextDocumentParams;
+use lsp_types::DidSaveTextDocumentParams;
+use lsp_types::DocumentSymbolParams;
+use lsp_types::DocumentSymbolResponse;
+use lsp_types::GotoDefinitionParams;
+use lsp_types::GotoDefinitionResponse;
+use lsp_types::Hover;
+use lsp_types::HoverContents;
+use lsp_types::HoverParams;
+use lsp_types::HoverProviderCapability;
+use lsp_types::MarkupContent;
+use lsp_types::MarkupKind;
+use lsp_types::OneOf;
+use lsp_types::Position;
+use lsp_types::PublishDiagnosticsParams;
+use lsp_types::Range;
+use lsp_types::ServerCapabilities;
+use lsp_types::TextDocumentSyncCapability;
+use lsp_types::TextDocumentSyncKind;
+use lsp_types::Url;
+
+use zinc_compiler::Scope;
+use zinc_compiler::ScopeItem;
+
+use crate::analysis::Analysis;
+use crate::document::Document;
+
+///
+/// The language server, which handles the client requests and notifications.
+///
+/// The projects are analyzed again on every document change, and the last successful analysis
+/// is used to answer the navigation requests while the project contains errors.
+///
+pub struct Server {
+    /// The client connection.
+    connection: Connection,
+    /// The documents opened in the editor.
+    documents: HashMap<Url, Document>,
+    /// The last successful project analyses with the project directory paths as keys.
+    analyses: HashMap<PathBuf, Analysis>,
+    /// The documents with published diagnostics with the project directory paths as keys.
+    published: HashMap<PathBuf, HashSet<Url>>,
+}
+
+impl Server {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(connection: Connection) -> Self {
+        Self {
+            connection,
+            documents: HashMap::new(),
+            analyses: HashMap::new(),
+            published: HashMap::new(),
+        }
+    }
+
+    ///
+    /// The server capabilities sent to the client during the initialization.
+    ///
+    pub fn capabilities() -> ServerCapabilities {
+        ServerCapabilities {
+            text_document_sync: Some(TextDocumentSyncCapability::Kind(TextDocumentSyncKind::FULL)),
+            definition_provider: Some(OneOf::Left(true)),
+            hover_provider: Some(HoverProviderCapability::Simple(true)),
+            document_symbol_provider: Some(OneOf::Left(true)),
+            completion_provider: Some(CompletionOptions {
+                trigger_characters: Some(vec![":".to_owned()]),
+                ..CompletionOptions::default()
+            }),
+            ..ServerCapabilities::default()
+        }
+    }
+
+    ///
+    /// Runs the server loop until the client requests a shutdown or closes the connection.
+    ///
+    pub fn run(mut self) -> anyhow::Result<()> {
+        while let Ok(message) = self.connection.receiver.recv() {
+            match message {
+                Message::Request(request) => {
+                    if self.connection.handle_shutdown(&request)? {
+                        return Ok(());
+                    }
+
+                    let response = self.request(request);
+                    self.connection.sender.send(Message::Response(response))?;
+                }
+                Message::Notification(notification) => self.notification(notification)?,
+                Message::Response(_response) => {}
+            }
+        }
+
+        Ok(())
+    }
+
+    ///
+    /// Handles a client request.
+    ///
+    fn request(&mut self, request: Request) -> Response {
+        let id = request.id.clone();
+
+        let result =
+            match request.method.as_str() {
+                GotoDefinition::METHOD => Self::params(request)
+                    .map(|params| serde_json::to_value(self.definition(params))),
+                HoverRequest::METHOD => {
+                    Self::params(request).map(|params| serde_json::to_value(self.hover(params)))
+                }
+                DocumentSymbolRequest::METHOD => {
+                    Self::params(request).map(|params| serde_json::to_value(self.symbols(params)))
+                }
+                Completion::METHOD => Self::params(request)
+                    .map(|params| serde_json::to_value(self.completion(params))),
+                method => {
+                    return Response::new_err(
+                        id,
+                        ErrorCode::MethodNotFound as i32,
+                        format!("method `{}` is not supported", method),
+                    )
+                }
+            };
+
+        match result {
+            Ok(Ok(value)) => Response {
+                id,
+                result: Some(value),
+                error: None,
+            },
+            Ok(Err(error)) => Self::error(id, ErrorCode::InternalError, error),
+            Err(error) => Self::error(id, ErrorCode::InvalidParams, error),
+        }
+    }
+
+    ///
+    /// Handles a client notification.
+    ///
+    fn notification(&mut self, notification: Notification) -> anyhow::Result<()> {
+        let url = match notification.method.as_str() {
+            DidOpenTextDocument::METHOD => {
+                let params: DidOpenTextDocumentParams =
+                    serde_json::from_value(notification.params)?;
+                self.documents.insert(
+                    params.text_document.uri.clone(),
+                    Document::new(params.text_document.text),
+                );
+                params.text_document.uri
+            }
+            DidChangeTextDocument::METHOD => {
+                let params: DidChangeTextDocumentParams =
+                    serde_json::from_value(notification.params)?;
+                if let Some(change) = params.content_changes.into_iter().last() {
+                    self.documents
+                        .insert(params.text_document.uri.clone(), Document::new(change.text));
+                }
+                params.text_document.uri
+            }
+            DidSaveTextDocument::METHOD => {
+                let params: DidSaveTextDocumentParams =
+                    serde_json::from_value(notification.params)?;
+                params.text_document.uri
+            }
+            DidCloseTextDocument::METHOD => {
+                let params: DidCloseTextDocumentParams =
+                    serde_json::from_value(notification.params)?;
+                self.documents.remove(&params.text_document.uri);
+                params.text_document.uri
+            }
+            method => {
+                log::debug!("Notification `{}` ignored", method);
+                return Ok(());
+            }
+        };
+
+        self.analyze(&url)
+    }
+
+    ///
+    /// Analyzes the project containing the document at `url` and publishes the diagnostics.
+    ///
+    /// The diagnostics of the documents, which have become valid, are cleared.
+    ///
+    fn analyze(&mut self, url: &Url) -> anyhow::Result<()> {
+        let project_path = match url
+            .to_file_path()
+            .ok()
+            .and_then(|path| Analysis::project_path(&path))
+        {
+            Some(project_path) => project_path,
+            None => return Ok(()),
+        };
+
+        log::info!("Analyzing {:?}", project_path);
+        let analysis = Analysis::run(&project_path, &self.documents);
+
+        let published = self.published.entry(project_path.clone()).or_default();
+        let mut notifications = Vec::with_capacity(published.len() + analysis.diagnostics.len());
+        for url in published.drain() {
+            if !analysis.diagnostics.contains_key(&url) {
+                notifications.push(PublishDiagnosticsParams::new(url, vec![], None));
+            }
+        }
+        for (url, diagnostics) in analysis.diagnostics.iter() {
+            published.insert(url.to_owned());
+            notifications.push(PublishDiagnosticsParams::new(
+                url.to_owned(),
+                diagnostics.to_owned(),
+                None,
+            ));
+        }
+        for params in notifications.into_iter() {
+            self.connection
+                .sender
+                .send(Message::Notification(Notification::new(
+                    PublishDiagnostics::METHOD.to_owned(),
+                    params,
+                )))?;
+        }
+
+        if analysis.scope.is_some() {
+            self.analyses.insert(project_path, analysis);
+        }
+
+        Ok(())
+    }
+
+    ///
+    /// Finds the declaration of the item under the cursor.
+    ///
+    fn definition(&self, params: GotoDefinitionParams) -> Option<GotoDefinitionResponse> {
+        let position = params.text_document_position_params;
+        let (project_path, item, _range) =
+            self.item_at(&position.text_document.uri, position.position)?;
+
+        let location = RefCell::borrow(&item).location()?;
+        Analysis::convert_location(&project_path, location).map(GotoDefinitionResponse::Scalar)
+    }
+
+    ///
+    /// Shows the resolved type of the item under the cursor.
+    ///
+    fn hover(&self, params: HoverParams) -> Option<Hover> {
+        let position = params.text_document_position_params;
+        let (_project_path, item, range) =
+            self.item_at(&position.text_document.uri, position.position)?;
+
+        let item = RefCell::borrow(&item);
+        let description = match item.r#type() {
+            Some(r#type) => r#type.to_string(),
+            None => item.to_string(),
+        };
+
+        Some(Hover {
+            contents: HoverContents::Markup(MarkupContent {
+                kind: MarkupKind::Markdown,
+                value: format!("```zinc\n{}\n```", description),
+            }),
+            range: Some(range),
+        })
+    }
+
+    ///
+    /// Returns the symbol tree of the document.
+    ///
+    fn symbols(&self, params: DocumentSymbolParams) -> Option<DocumentSymbolResponse> {
+        self.documents
+            .get(&params.text_document.uri)
+            .map(|document| DocumentSymbolResponse::Nested(document.symbols()))
+    }
+
+    ///
+    /// Lists the items accessible at the cursor, e.g. after `std::` or `zksync::`.
+    ///
+    fn completion(&self, params: CompletionParams) -> Option<CompletionResponse> {
+        let position = params.text_document_position;
+        let url = position.text_document.uri;
+
+        let document = self.documents.get(&url)?;
+        let (_project_path, scope) = self.scope(&url)?;
+
+        let elements = document.completion_prefix(position.position);
+        let items = Analysis::completions(scope, elements)
+            .into_iter()
+            .filter_map(|(name, item)| {
+                let item = RefCell::borrow(&item);
+                let kind = match *item {
+                    ScopeItem::Variable(_) => CompletionItemKind::VARIABLE,
+                    ScopeItem::Field(_) => CompletionItemKind::FIELD,
+                    ScopeItem::Constant(_) => CompletionItemKind::CONSTANT,
+                    ScopeItem::Variant(_) => CompletionItemKind::ENUM_MEMBER,
+                    ScopeItem::Type(_) => CompletionItemKind::STRUCT,
+                    ScopeItem::Module(_) => CompletionItemKind::MODULE,
+                    ScopeItem::Implementation(_) => return None,
+                };
+
+                Some(CompletionItem {
+                    label: name,
+                    kind: Some(kind),
+                    detail: item.r#type().map(|r#type| r#type.to_string()),
+                    ..CompletionItem::default()
+                })
+            })
+            .collect();
+
+        Some(CompletionResponse::Array(items))
+    }
+
+    ///
+    /// Resolves the item under the cursor and returns it with its project path and the range of
+    /// the identifier under the cursor.
+    ///
+    fn item_at(
+        &self,
+        url: &Url,
+        position: Position,
+    ) -> Option<(PathBuf, Rc<RefCell<ScopeItem>>, Range)> {
+        let document = self.documents.get(url)?;
+        let (elements, range) = document.path_at(position)?;
+
+        let (project_path, scope) = self.scope(url)?;
+        let item = Analysis::resolve(scope, elements)?;
+
+        Some((project_path, item, range))
+    }
+
+    ///
+    /// Returns the project path and the scope of the module stored in the document at `url`.
+    ///
+    fn scope(&self, url: &Url) -> Option<(PathBuf, Rc<RefCell<Scope>>)> {
+        let path = url.to_file_path().ok()?;
+        let project_path = Analysis::project_path(&path)?;
+        let scope = self
+            .analyses
+            .get(&project_path)?
+            .module_scope(&project_path, &path)?;
+
+        Some((project_path, scope))
+    }
+
+    ///
+    /// Extracts the request parameters.
+    ///
+    fn params<P: serde::de::DeserializeOwned>(request: Request) -> serde_json::Result<P> {
+        serde_json::from_value(request.params)
+    }
+
+    ///
+    /// Creates an error response.
+    ///
+    fn error(id: RequestId, code: ErrorCode, error: serde_json::Error) -> Response {
+        Response::new_err(id, code as i32, error.to_string())
+    }
+}
@@ -0,0 +1,61 @@
+//!
+//! The Zinc language server binary.
+//!
+
+pub(crate) mod arguments;
+
+use std::process;
+use std::thread;
+
+use lsp_server::Connection;
+
+use zinc_language_server::Server;
+
+use self::arguments::Arguments;
+
+///
+/// The application entry point.
+///
+fn main() {
+    process::exit(match main_inner(Arguments::new()) {
+        Ok(()) => zinc_const::exit_code::SUCCESS,
+        Err(error) => {
+            log::error!("{:?}", error);
+            zinc_const::exit_code::FAILURE
+        }
+    })
+}
+
+///
+/// The auxiliary `main` function to facilitate the `?` error conversion operator.
+///
+/// The server communicates with the client via the standard input and output, so the logs are
+/// written to the standard error stream.
+///
+fn main_inner(args: Arguments) -> anyhow::Result<()> {
+    zinc_logger::initialize(
+        zinc_const::app_name::LANGUAGE_SERVER,
+        args.verbosity,
+        args.quiet,
+    );
+
+    let (connection, io_threads) = Connection::stdio();
+
+    thread::Builder::new()
+        .stack_size(zinc_const::limit::COMPILER_STACK_SIZE)
+        .spawn(move || {
+            let params = connection.initialize(serde_json::to_value(Server::capabilities())?)?;
+            log::info!("Initialized with {}", params);
+
+            Server::new(connection).run()
+        })
+        .expect(zinc_const::panic::SYNCHRONIZATION)
+        .join()
+        .expect(zinc_const::panic::SYNCHRONIZATION)?;
+
+    io_threads.join()?;
+
+    log::info!("Shutdown");
+
+    Ok(())
+}
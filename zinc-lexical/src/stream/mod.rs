@@ -39,6 +39,8 @@ pub struct TokenStream<'a> {
     /// The queue buffer where the characters acquired with the look-ahead method are stored.
     /// If the queue is not empty, the next character will be taken therefrom.
     look_ahead: VecDeque<Token>,
+    /// Whether the comments are yielded as tokens instead of being skipped.
+    with_comments: bool,
 }
 
 impl<'a> TokenStream<'a> {
@@ -55,6 +57,19 @@ impl<'a> TokenStream<'a> {
             offset: 0,
             location: Location::new(file),
             look_ahead: VecDeque::with_capacity(Self::LOOK_AHEAD_INITIAL_CAPACITY),
+            with_comments: false,
+        }
+    }
+
+    ///
+    /// Initializes a stream, which yields the comments as tokens instead of skipping them.
+    /// The comment tokens are not expected by the syntax parser, so the stream is only useful
+    /// for tools working with the raw source code, like the formatter.
+    ///
+    pub fn new_with_comments(input: &'a str, file: usize) -> Self {
+        Self {
+            with_comments: true,
+            ..Self::new(input, file)
         }
     }
 
@@ -109,6 +124,7 @@ impl<'a> TokenStream<'a> {
             offset: 0,
             location: Location::new(0),
             look_ahead: VecDeque::with_capacity(Self::LOOK_AHEAD_INITIAL_CAPACITY),
+            with_comments: false,
         }
     }
 
@@ -116,7 +132,7 @@ impl<'a> TokenStream<'a> {
     /// The function checks if a character:
     /// 1. Is a whitespace -> skip
     /// 2. Starts a comment -> start the comment subparser
-    /// 3. Starts a string literal -> start the string subparser
+    /// 3. Starts a string or byte string literal -> start the string subparser
     /// 4. Starts a number -> start the number subparser
     /// 5. Starts a word -> start the word subparser
     /// 6. Starts a symbol -> start the operand subparser
@@ -140,12 +156,19 @@ impl<'a> TokenStream<'a> {
             if character == '/' {
                 match self::comment::parse(&self.input[self.offset..]) {
                     Ok(output) => {
+                        let location = self.location;
                         self.location.line += output.lines;
                         self.location.column = match output.comment {
                             Comment::Line { .. } => 1,
+                            Comment::Block { .. } if output.lines == 0 => {
+                                self.location.column + output.size
+                            }
                             Comment::Block { .. } => output.column,
                         };
                         self.offset += output.size;
+                        if self.with_comments {
+                            return Ok(Token::new(Lexeme::Comment(output.comment), location));
+                        }
                         continue;
                     }
                     Err(CommentParserError::NotAComment) => {}
@@ -179,6 +202,30 @@ impl<'a> TokenStream<'a> {
                 }
             }
 
+            if character == 'b' && self.input[self.offset + 1..].starts_with('\"') {
+                match self::string::parse(&self.input[self.offset + 1..]) {
+                    Ok(output) => {
+                        let location = self.location;
+                        self.location.column += output.size + 1;
+                        self.offset += output.size + 1;
+                        return Ok(Token::new(
+                            Lexeme::Literal(Literal::ByteString(StringLiteral::new(
+                                output.string,
+                            ))),
+                            location,
+                        ));
+                    }
+                    Err(StringParserError::NotAString) => {}
+                    Err(StringParserError::UnterminatedDoubleQuote { lines, column }) => {
+                        let column = if lines == 0 { column + 1 } else { column };
+                        return Err(Error::unterminated_double_quote_string(
+                            self.location,
+                            self.location.shifted_down(lines, column),
+                        ));
+                    }
+                }
+            }
+
             if character.is_ascii_digit() {
                 match self::integer::parse(&self.input[self.offset..]) {
                     Ok(output) => {
@@ -4,9 +4,11 @@
 
 use crate::error::Error;
 use crate::stream::TokenStream;
+use crate::token::lexeme::comment::Comment;
 use crate::token::lexeme::identifier::Identifier;
 use crate::token::lexeme::keyword::Keyword;
 use crate::token::lexeme::literal::integer::Integer;
+use crate::token::lexeme::literal::string::String as StringLiteral;
 use crate::token::lexeme::literal::Literal;
 use crate::token::lexeme::symbol::Symbol;
 use crate::token::lexeme::Lexeme;
@@ -82,6 +84,97 @@ let mut c: u8 = 2 + 2;
     assert_eq!(result, expected);
 }
 
+#[test]
+fn ok_with_comments() {
+    let input = r#"
+// line
+let /* block */ c;
+"#;
+
+    let expected = vec![
+        Token {
+            lexeme: Lexeme::Comment(Comment::new_line(" line".to_owned())),
+            location: Location::test(2, 1),
+        },
+        Token {
+            lexeme: Lexeme::Keyword(Keyword::Let),
+            location: Location::test(3, 1),
+        },
+        Token {
+            lexeme: Lexeme::Comment(Comment::new_block(" block ".to_owned())),
+            location: Location::test(3, 5),
+        },
+        Token {
+            lexeme: Lexeme::Identifier(Identifier::new("c".to_owned())),
+            location: Location::test(3, 17),
+        },
+        Token {
+            lexeme: Lexeme::Symbol(Symbol::Semicolon),
+            location: Location::test(3, 18),
+        },
+    ];
+
+    let mut result = Vec::with_capacity(expected.len());
+    let mut stream = TokenStream::new_with_comments(input, 0);
+    loop {
+        match stream.next().expect(zinc_const::panic::TEST_DATA_VALID) {
+            Token {
+                lexeme: Lexeme::Eof,
+                ..
+            } => break,
+            token => result.push(token),
+        }
+    }
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn ok_block_comment_column() {
+    let input = "let /* block */ c;";
+
+    let expected = Ok(Token {
+        lexeme: Lexeme::Identifier(Identifier::new("c".to_owned())),
+        location: Location::test(1, 17),
+    });
+
+    let mut stream = TokenStream::test(input);
+    stream.next().expect(zinc_const::panic::TEST_DATA_VALID);
+    let result = stream.next();
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn ok_byte_string() {
+    let input = r#"b"hello" b"#;
+
+    let expected = vec![
+        Token {
+            lexeme: Lexeme::Literal(Literal::ByteString(StringLiteral::new("hello".to_owned()))),
+            location: Location::test(1, 1),
+        },
+        Token {
+            lexeme: Lexeme::Identifier(Identifier::new("b".to_owned())),
+            location: Location::test(1, 10),
+        },
+    ];
+
+    let mut result = Vec::with_capacity(expected.len());
+    let mut stream = TokenStream::test(input);
+    loop {
+        match stream.next().expect(zinc_const::panic::TEST_DATA_VALID) {
+            Token {
+                lexeme: Lexeme::Eof,
+                ..
+            } => break,
+            token => result.push(token),
+        }
+    }
+
+    assert_eq!(result, expected);
+}
+
 #[test]
 fn error_unterminated_block_comment() {
     let input = "/*block comment";
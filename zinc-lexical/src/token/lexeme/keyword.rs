@@ -43,6 +43,8 @@ pub enum Keyword {
     In,
     /// The `while` control keyword.
     While,
+    /// The `bound` control keyword.
+    Bound,
     /// The `if` control keyword.
     If,
     /// The `else` control keyword.
@@ -176,6 +178,7 @@ impl TryFrom<&str> for Keyword {
             "for" => return Ok(Self::For),
             "in" => return Ok(Self::In),
             "while" => return Ok(Self::While),
+            "bound" => return Ok(Self::Bound),
             "if" => return Ok(Self::If),
             "else" => return Ok(Self::Else),
             "match" => return Ok(Self::Match),
@@ -274,6 +277,7 @@ impl fmt::Display for Keyword {
             Self::For => write!(f, "for"),
             Self::In => write!(f, "in"),
             Self::While => write!(f, "while"),
+            Self::Bound => write!(f, "bound"),
             Self::If => write!(f, "if"),
             Self::Else => write!(f, "else"),
             Self::Match => write!(f, "match"),
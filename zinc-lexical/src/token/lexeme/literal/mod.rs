@@ -23,6 +23,8 @@ pub enum Literal {
     Integer(Integer),
     /// A string literal, like `"message"`.
     String(String),
+    /// A byte string literal, like `b"message"`.
+    ByteString(String),
 }
 
 impl fmt::Display for Literal {
@@ -31,6 +33,7 @@ impl fmt::Display for Literal {
             Self::Boolean(inner) => write!(f, "{}", inner),
             Self::Integer(inner) => write!(f, "{}", inner),
             Self::String(inner) => write!(f, "{}", inner),
+            Self::ByteString(inner) => write!(f, "b{}", inner),
         }
     }
 }
@@ -42,11 +42,13 @@ pub fn initialize(app_name: &'static str, verbosity: usize, quiet: bool) {
         .filter_module(zinc_const::app_name::COMPILER, level)
         .filter_module(zinc_const::app_name::VIRTUAL_MACHINE, level)
         .filter_module(zinc_const::app_name::TESTER, level)
+        .filter_module(zinc_const::app_name::LANGUAGE_SERVER, level)
         .filter_module("zargo", level)
         .filter_module("zandbox", level)
         .filter_module("zinc_compiler", level)
         .filter_module("zinc_vm", level)
         .filter_module("zinc_tester", level)
+        .filter_module("zinc_language_server", level)
         .format(move |buffer, record| {
             if record.level() >= log::Level::Debug {
                 writeln!(
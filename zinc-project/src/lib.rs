@@ -3,14 +3,19 @@
 //!
 
 pub(crate) mod error;
+pub(crate) mod lock;
 pub(crate) mod manifest;
 pub(crate) mod project;
 pub(crate) mod source;
 
 pub use self::error::Error;
+pub use self::lock::Lock;
+pub use self::lock::Package as LockPackage;
+pub use self::manifest::Dependency as ManifestDependency;
 pub use self::manifest::Manifest;
 pub use self::manifest::Project as ManifestProject;
 pub use self::project::r#type::Type as ProjectType;
+pub use self::project::storage_hasher::StorageHasher;
 pub use self::project::Project;
 pub use self::source::directory::Directory;
 pub use self::source::file::File;
@@ -0,0 +1,332 @@
+//!
+//! The Zinc project lock file.
+//!
+
+use std::convert::TryFrom;
+use std::fs;
+use std::fs::File;
+use std::io::Read;
+use std::io::Write;
+use std::path::PathBuf;
+
+use anyhow::Context;
+use serde::Deserialize;
+use serde::Serialize;
+use sha2::Digest;
+
+///
+/// The Zinc project lock file representation.
+///
+/// Records the dependency versions chosen by the resolver, so the subsequent builds are
+/// reproducible and do not have to query the registry again.
+///
+#[derive(Debug, Default, Serialize, Deserialize, Clone, PartialEq)]
+pub struct Lock {
+    /// The resolved dependency packages, sorted by name.
+    #[serde(default)]
+    pub package: Vec<Package>,
+}
+
+///
+/// The `package` lock file entry representation.
+///
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct Package {
+    /// The dependency name.
+    pub name: String,
+    /// The resolved dependency version.
+    pub version: semver::Version,
+    /// The SHA-256 hash of the dependency manifest and source code.
+    pub checksum: String,
+    /// The names of the dependency's own dependencies.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub dependencies: Vec<String>,
+}
+
+impl Package {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(
+        name: String,
+        version: semver::Version,
+        checksum: String,
+        dependencies: Vec<String>,
+    ) -> Self {
+        Self {
+            name,
+            version,
+            checksum,
+            dependencies,
+        }
+    }
+}
+
+impl Lock {
+    ///
+    /// Creates a lock file instance from the `packages`, sorting them to keep the file stable.
+    ///
+    pub fn new(mut packages: Vec<Package>) -> Self {
+        packages.sort_by(|a, b| a.name.cmp(&b.name));
+        for package in packages.iter_mut() {
+            package.dependencies.sort();
+        }
+
+        Self { package: packages }
+    }
+
+    ///
+    /// Returns the locked package with the specified `name`.
+    ///
+    pub fn get(&self, name: &str) -> Option<&Package> {
+        self.package.iter().find(|package| package.name == name)
+    }
+
+    ///
+    /// Checks if the lock file exists in the project at the given `path`.
+    ///
+    pub fn exists_at(path: &PathBuf) -> bool {
+        let mut path = path.to_owned();
+        if path.is_dir() {
+            path.push(PathBuf::from(Self::file_name()));
+        }
+        path.exists()
+    }
+
+    ///
+    /// Writes the lock file to the project at the given `path`.
+    ///
+    pub fn write_to(&self, path: &PathBuf) -> anyhow::Result<()> {
+        let mut path = path.to_owned();
+        if path.is_dir() || !path.ends_with(Self::file_name()) {
+            path.push(PathBuf::from(Self::file_name()));
+        }
+
+        let mut file = File::create(&path).with_context(|| path.to_string_lossy().to_string())?;
+        file.write_all(
+            toml::to_string_pretty(self)
+                .expect(zinc_const::panic::DATA_CONVERSION)
+                .as_bytes(),
+        )
+        .with_context(|| path.to_string_lossy().to_string())?;
+
+        Ok(())
+    }
+
+    ///
+    /// Computes the content hash of the project at the given `path`.
+    ///
+    /// The manifest and all the files in the source code directory are hashed in the path order,
+    /// so the result does not depend on the file system traversal order.
+    ///
+    pub fn checksum(path: &PathBuf) -> anyhow::Result<String> {
+        let mut manifest_path = path.to_owned();
+        manifest_path.push(format!(
+            "{}.{}",
+            zinc_const::file_name::MANIFEST,
+            zinc_const::extension::MANIFEST
+        ));
+
+        let mut source_path = path.to_owned();
+        source_path.push(zinc_const::directory::SOURCE);
+
+        let mut files = vec![manifest_path];
+        Self::collect_files(&source_path, &mut files)?;
+        files.sort();
+
+        let mut hasher = sha2::Sha256::new();
+        for file_path in files.into_iter() {
+            let relative_path = file_path.strip_prefix(path).unwrap_or(&file_path);
+            let data =
+                fs::read(&file_path).with_context(|| file_path.to_string_lossy().to_string())?;
+
+            hasher.update(relative_path.to_string_lossy().as_bytes());
+            hasher.update(&[0]);
+            hasher.update(data.as_slice());
+            hasher.update(&[0]);
+        }
+
+        Ok(hex::encode(hasher.finalize()))
+    }
+
+    ///
+    /// Recursively collects the paths of all files in the `path` directory.
+    ///
+    fn collect_files(path: &PathBuf, files: &mut Vec<PathBuf>) -> anyhow::Result<()> {
+        let directory = fs::read_dir(&path).with_context(|| path.to_string_lossy().to_string())?;
+
+        for directory_entry in directory.into_iter() {
+            let directory_entry =
+                directory_entry.with_context(|| path.to_string_lossy().to_string())?;
+            let path = directory_entry.path();
+
+            if path.is_dir() {
+                Self::collect_files(&path, files)?;
+            } else {
+                files.push(path);
+            }
+        }
+
+        Ok(())
+    }
+
+    ///
+    /// Creates a string with the default file name.
+    ///
+    fn file_name() -> String {
+        format!(
+            "{}.{}",
+            zinc_const::file_name::LOCK,
+            zinc_const::extension::LOCK
+        )
+    }
+}
+
+impl TryFrom<&PathBuf> for Lock {
+    type Error = anyhow::Error;
+
+    fn try_from(path: &PathBuf) -> Result<Self, Self::Error> {
+        let mut path = path.to_owned();
+        if path.is_dir() {
+            path.push(PathBuf::from(Self::file_name()));
+        }
+
+        let mut file = File::open(&path).with_context(|| path.to_string_lossy().to_string())?;
+        let size = file
+            .metadata()
+            .with_context(|| path.to_string_lossy().to_string())?
+            .len() as usize;
+
+        let mut buffer = String::with_capacity(size);
+        file.read_to_string(&mut buffer)
+            .with_context(|| path.to_string_lossy().to_string())?;
+
+        Ok(toml::from_str(buffer.as_str()).with_context(|| path.to_string_lossy().to_string())?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::convert::TryFrom;
+    use std::fs;
+    use std::path::PathBuf;
+
+    use super::Lock;
+    use super::Package;
+
+    fn lock() -> Lock {
+        Lock::new(vec![
+            Package::new(
+                "utils".to_owned(),
+                semver::Version::new(0, 1, 0),
+                "ab".repeat(32),
+                vec!["math".to_owned()],
+            ),
+            Package::new(
+                "math".to_owned(),
+                semver::Version::new(1, 2, 3),
+                "cd".repeat(32),
+                vec![],
+            ),
+        ])
+    }
+
+    fn project(name: &str, source: &str) -> PathBuf {
+        let mut path = std::env::temp_dir();
+        path.push(format!("zinc-project-lock-{}-{}", name, std::process::id()));
+        let _ = fs::remove_dir_all(&path);
+
+        let mut source_path = path.clone();
+        source_path.push(zinc_const::directory::SOURCE);
+        source_path.push("nested");
+        fs::create_dir_all(&source_path).expect(zinc_const::panic::TEST_DATA_VALID);
+        source_path.push("lib.zn");
+        fs::write(&source_path, source).expect(zinc_const::panic::TEST_DATA_VALID);
+
+        let mut main_path = path.clone();
+        main_path.push(zinc_const::directory::SOURCE);
+        main_path.push("main.zn");
+        fs::write(&main_path, "fn main() {}\n").expect(zinc_const::panic::TEST_DATA_VALID);
+
+        let mut manifest_path = path.clone();
+        manifest_path.push(format!(
+            "{}.{}",
+            zinc_const::file_name::MANIFEST,
+            zinc_const::extension::MANIFEST
+        ));
+        fs::write(
+            &manifest_path,
+            "[project]\nname = 'test'\ntype = 'library'\nversion = '0.1.0'\n",
+        )
+        .expect(zinc_const::panic::TEST_DATA_VALID);
+
+        path
+    }
+
+    #[test]
+    fn ok_sorted() {
+        let lock = lock();
+
+        assert_eq!(lock.package[0].name, "math");
+        assert_eq!(lock.package[1].name, "utils");
+        assert_eq!(
+            lock.get("utils").map(|package| &package.version),
+            Some(&semver::Version::new(0, 1, 0))
+        );
+    }
+
+    #[test]
+    fn ok_round_trip() {
+        let lock = lock();
+
+        let serialized = toml::to_string_pretty(&lock).expect(zinc_const::panic::TEST_DATA_VALID);
+        let deserialized: Lock =
+            toml::from_str(serialized.as_str()).expect(zinc_const::panic::TEST_DATA_VALID);
+
+        assert_eq!(deserialized, lock);
+    }
+
+    #[test]
+    fn ok_round_trip_file() {
+        let path = project("file", "fn f() {}\n");
+        let lock = lock();
+
+        lock.write_to(&path)
+            .expect(zinc_const::panic::TEST_DATA_VALID);
+        assert!(Lock::exists_at(&path));
+        let read = Lock::try_from(&path).expect(zinc_const::panic::TEST_DATA_VALID);
+
+        fs::remove_dir_all(&path).expect(zinc_const::panic::TEST_DATA_VALID);
+        assert_eq!(read, lock);
+    }
+
+    #[test]
+    fn ok_checksum_stable() {
+        let first = project("stable-first", "fn f() {}\n");
+        let second = project("stable-second", "fn f() {}\n");
+
+        let first_checksum = Lock::checksum(&first).expect(zinc_const::panic::TEST_DATA_VALID);
+        let first_checksum_again =
+            Lock::checksum(&first).expect(zinc_const::panic::TEST_DATA_VALID);
+        let second_checksum = Lock::checksum(&second).expect(zinc_const::panic::TEST_DATA_VALID);
+
+        fs::remove_dir_all(&first).expect(zinc_const::panic::TEST_DATA_VALID);
+        fs::remove_dir_all(&second).expect(zinc_const::panic::TEST_DATA_VALID);
+        assert_eq!(first_checksum, first_checksum_again);
+        assert_eq!(first_checksum, second_checksum);
+        assert_eq!(first_checksum.len(), 64);
+    }
+
+    #[test]
+    fn ok_checksum_changed() {
+        let first = project("changed-first", "fn f() {}\n");
+        let second = project("changed-second", "fn g() {}\n");
+
+        let first_checksum = Lock::checksum(&first).expect(zinc_const::panic::TEST_DATA_VALID);
+        let second_checksum = Lock::checksum(&second).expect(zinc_const::panic::TEST_DATA_VALID);
+
+        fs::remove_dir_all(&first).expect(zinc_const::panic::TEST_DATA_VALID);
+        fs::remove_dir_all(&second).expect(zinc_const::panic::TEST_DATA_VALID);
+        assert_ne!(first_checksum, second_checksum);
+    }
+}
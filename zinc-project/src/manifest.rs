@@ -14,6 +14,7 @@ use serde::Deserialize;
 use serde::Serialize;
 
 use crate::project::r#type::Type as ProjectType;
+use crate::project::storage_hasher::StorageHasher;
 
 ///
 /// The Zinc project manifest file representation.
@@ -22,8 +23,23 @@ use crate::project::r#type::Type as ProjectType;
 pub struct Manifest {
     /// The `project` section.
     pub project: Project,
-    /// The `dependencies` section.
-    pub dependencies: Option<HashMap<String, semver::Version>>,
+    /// The `dependencies` section. See the inner element description.
+    pub dependencies: Option<HashMap<String, Dependency>>,
+}
+
+///
+/// The `dependencies` section element representation.
+///
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(untagged)]
+pub enum Dependency {
+    /// The dependency downloaded from the project registry, e.g. `callee = '^0.1'`.
+    Version(semver::VersionReq),
+    /// The local dependency, e.g. `common = { path = '../common' }`.
+    Path {
+        /// The dependency project directory path, relative to the dependent project root.
+        path: PathBuf,
+    },
 }
 
 ///
@@ -37,6 +53,11 @@ pub struct Project {
     pub r#type: ProjectType,
     /// The project version in the string format.
     pub version: semver::Version,
+    /// The contract storage Merkle tree hasher, which is `sha256` if unset.
+    ///
+    /// The field is never skipped, since the bytecode binary encoding requires all the fields.
+    #[serde(default)]
+    pub storage_hasher: Option<StorageHasher>,
 }
 
 impl Project {
@@ -48,6 +69,7 @@ impl Project {
             name,
             r#type,
             version,
+            storage_hasher: None,
         }
     }
 }
@@ -62,6 +84,7 @@ impl Manifest {
                 name: project_name.to_owned(),
                 r#type: project_type,
                 version: semver::Version::new(0, 1, 0),
+                storage_hasher: None,
             },
             dependencies: Some(HashMap::new()),
         }
@@ -0,0 +1,54 @@
+//!
+//! The Zinc project contract storage hasher.
+//!
+
+use std::fmt;
+use std::str::FromStr;
+
+use serde::Deserialize;
+use serde::Serialize;
+
+///
+/// The Zinc project contract storage Merkle tree hasher.
+///
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq)]
+pub enum StorageHasher {
+    /// The `sha256` hash, which is the default one.
+    #[serde(rename = "sha256")]
+    Sha256,
+    /// The Rescue algebraic hash over the BN256 scalar field.
+    #[serde(rename = "rescue")]
+    Rescue,
+    /// The Poseidon algebraic hash over the BN256 scalar field.
+    #[serde(rename = "poseidon")]
+    Poseidon,
+}
+
+impl Default for StorageHasher {
+    fn default() -> Self {
+        Self::Sha256
+    }
+}
+
+impl FromStr for StorageHasher {
+    type Err = String;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "sha256" => Ok(Self::Sha256),
+            "rescue" => Ok(Self::Rescue),
+            "poseidon" => Ok(Self::Poseidon),
+            another => Err(another.to_owned()),
+        }
+    }
+}
+
+impl fmt::Display for StorageHasher {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Sha256 => write!(f, "sha256"),
+            Self::Rescue => write!(f, "rescue"),
+            Self::Poseidon => write!(f, "poseidon"),
+        }
+    }
+}
@@ -292,6 +292,48 @@ impl Error {
         Self::ExpectedMatchPattern { location, found }
     }
 
+    ///
+    /// Returns the location of the invalid lexeme.
+    ///
+    pub fn location(&self) -> Location {
+        match self {
+            Self::ExpectedOneOf { location, .. } => *location,
+            Self::ExpectedOneOfOrOperator { location, .. } => *location,
+            Self::ExpectedIdentifier { location, .. } => *location,
+            Self::ExpectedMutOrIdentifier { location, .. } => *location,
+            Self::ExpectedFieldIdentifier { location, .. } => *location,
+            Self::ExpectedType { location, .. } => *location,
+            Self::ExpectedExpressionOrOperand { location, .. } => *location,
+            Self::ExpectedTypeOrValue { location, .. } => *location,
+            Self::ExpectedValue { location, .. } => *location,
+            Self::ExpectedLiteral { location, .. } => *location,
+            Self::ExpectedIntegerLiteral { location, .. } => *location,
+            Self::ExpectedBindingPattern { location, .. } => *location,
+            Self::ExpectedMatchPattern { location, .. } => *location,
+        }
+    }
+
+    ///
+    /// Returns the invalid lexeme.
+    ///
+    pub fn found(&self) -> &Lexeme {
+        match self {
+            Self::ExpectedOneOf { found, .. } => found,
+            Self::ExpectedOneOfOrOperator { found, .. } => found,
+            Self::ExpectedIdentifier { found, .. } => found,
+            Self::ExpectedMutOrIdentifier { found, .. } => found,
+            Self::ExpectedFieldIdentifier { found, .. } => found,
+            Self::ExpectedType { found, .. } => found,
+            Self::ExpectedExpressionOrOperand { found, .. } => found,
+            Self::ExpectedTypeOrValue { found, .. } => found,
+            Self::ExpectedValue { found, .. } => found,
+            Self::ExpectedLiteral { found, .. } => found,
+            Self::ExpectedIntegerLiteral { found, .. } => found,
+            Self::ExpectedBindingPattern { found, .. } => found,
+            Self::ExpectedMatchPattern { found, .. } => found,
+        }
+    }
+
     ///
     /// Converts a group of lexemes into a comma-separated list.
     ///
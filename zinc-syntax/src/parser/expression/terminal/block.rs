@@ -12,6 +12,7 @@ use zinc_lexical::TokenStream;
 
 use crate::error::Error as SyntaxError;
 use crate::error::ParsingError;
+use crate::parser::recovery::Boundary;
 use crate::parser::statement::local_fn::Parser as FunctionLocalStatementParser;
 use crate::tree::expression::block::builder::Builder as BlockExpressionBuilder;
 use crate::tree::expression::block::Expression as BlockExpression;
@@ -97,17 +98,33 @@ impl Parser {
                         } => return Ok((self.builder.finish(), self.next.take())),
                         token => {
                             let (statement, next, is_unterminated) =
-                                FunctionLocalStatementParser::default()
-                                    .parse(stream.clone(), Some(token))?;
+                                match FunctionLocalStatementParser::default()
+                                    .parse(stream.clone(), Some(token))
+                                {
+                                    Ok(result) => result,
+                                    Err(error) => {
+                                        self.next = crate::parser::recovery::recover(
+                                            stream.clone(),
+                                            error,
+                                            Boundary::Statement,
+                                        )?;
+                                        continue;
+                                    }
+                                };
                             self.next = next;
 
                             match statement {
                                 FunctionLocalStatement::Expression(expression) => {
                                     if is_unterminated {
-                                        let is_last = matches!(self.next.as_ref().unwrap_or(stream.borrow_mut().look_ahead(1)?), Token {
-                                            lexeme: Lexeme::Symbol(Symbol::BracketCurlyRight),
-                                            ..
-                                        });
+                                        let is_last = matches!(
+                                            self.next
+                                                .as_ref()
+                                                .unwrap_or(stream.borrow_mut().look_ahead(1)?),
+                                            Token {
+                                                lexeme: Lexeme::Symbol(Symbol::BracketCurlyRight),
+                                                ..
+                                            }
+                                        );
 
                                         if !is_last && expression.can_be_unterminated() {
                                             self.builder.push_statement(
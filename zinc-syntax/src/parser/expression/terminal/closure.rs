@@ -0,0 +1,255 @@
+//!
+//! The closure expression parser.
+//!
+
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use zinc_lexical::Lexeme;
+use zinc_lexical::Symbol;
+use zinc_lexical::Token;
+use zinc_lexical::TokenStream;
+
+use crate::error::Error as SyntaxError;
+use crate::error::ParsingError;
+use crate::parser::binding_list::Parser as BindingListParser;
+use crate::parser::expression::Parser as ExpressionParser;
+use crate::tree::expression::closure::builder::Builder as ClosureExpressionBuilder;
+use crate::tree::expression::closure::Expression as ClosureExpression;
+
+///
+/// The parser state.
+///
+#[derive(Debug, Clone, Copy)]
+pub enum State {
+    /// The initial state.
+    VerticalBarLeft,
+    /// The `|` has been parsed so far.
+    BindingList,
+    /// The `|{bindings}` has been parsed so far.
+    VerticalBarRight,
+    /// The `|{bindings}|` or `||` has been parsed so far.
+    Body,
+}
+
+impl Default for State {
+    fn default() -> Self {
+        Self::VerticalBarLeft
+    }
+}
+
+///
+/// The closure expression parser.
+///
+#[derive(Default)]
+pub struct Parser {
+    /// The parser state.
+    state: State,
+    /// The token returned from a subparser.
+    next: Option<Token>,
+    /// The builder of the parsed value.
+    builder: ClosureExpressionBuilder,
+}
+
+impl Parser {
+    ///
+    /// Parses a closure expression.
+    ///
+    /// '|acc, x: u8| acc + x'
+    /// '|| true'
+    ///
+    pub fn parse(
+        mut self,
+        stream: Rc<RefCell<TokenStream>>,
+        initial: Option<Token>,
+    ) -> Result<(ClosureExpression, Option<Token>), ParsingError> {
+        self.next = initial;
+
+        loop {
+            match self.state {
+                State::VerticalBarLeft => {
+                    match crate::parser::take_or_next(self.next.take(), stream.clone())? {
+                        Token {
+                            lexeme: Lexeme::Symbol(Symbol::VerticalBar),
+                            location,
+                        } => {
+                            self.builder.set_location(location);
+                            self.state = State::BindingList;
+                        }
+                        Token {
+                            lexeme: Lexeme::Symbol(Symbol::DoubleVerticalBar),
+                            location,
+                        } => {
+                            self.builder.set_location(location);
+                            self.state = State::Body;
+                        }
+                        Token { lexeme, location } => {
+                            return Err(ParsingError::Syntax(SyntaxError::expected_one_of(
+                                location,
+                                vec!["|", "||"],
+                                lexeme,
+                                None,
+                            )));
+                        }
+                    }
+                }
+                State::BindingList => {
+                    let (bindings, next) =
+                        BindingListParser::default().parse(stream.clone(), self.next.take())?;
+                    self.builder.set_bindings(bindings);
+                    self.next = next;
+                    self.state = State::VerticalBarRight;
+                }
+                State::VerticalBarRight => {
+                    match crate::parser::take_or_next(self.next.take(), stream.clone())? {
+                        Token {
+                            lexeme: Lexeme::Symbol(Symbol::VerticalBar),
+                            ..
+                        } => {
+                            self.state = State::Body;
+                        }
+                        Token { lexeme, location } => {
+                            return Err(ParsingError::Syntax(SyntaxError::expected_one_of(
+                                location,
+                                vec![",", "|"],
+                                lexeme,
+                                None,
+                            )));
+                        }
+                    }
+                }
+                State::Body => {
+                    let (expression, next) =
+                        ExpressionParser::default().parse(stream, self.next.take())?;
+                    self.builder.set_body(expression);
+                    return Ok((self.builder.finish(), next));
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use zinc_lexical::BooleanLiteral as LexicalBooleanLiteral;
+    use zinc_lexical::Lexeme;
+    use zinc_lexical::Location;
+    use zinc_lexical::Symbol;
+    use zinc_lexical::Token;
+    use zinc_lexical::TokenStream;
+
+    use super::Parser;
+    use crate::error::Error as SyntaxError;
+    use crate::error::ParsingError;
+    use crate::tree::binding::Binding;
+    use crate::tree::expression::closure::Expression as ClosureExpression;
+    use crate::tree::expression::tree::node::operand::Operand as ExpressionOperand;
+    use crate::tree::expression::tree::node::operator::Operator as ExpressionOperator;
+    use crate::tree::expression::tree::node::Node as ExpressionTreeNode;
+    use crate::tree::expression::tree::Tree as ExpressionTree;
+    use crate::tree::identifier::Identifier;
+    use crate::tree::literal::boolean::Literal as BooleanLiteral;
+    use crate::tree::pattern_binding::variant::Variant as BindingPatternVariant;
+    use crate::tree::pattern_binding::Pattern as BindingPattern;
+    use crate::tree::r#type::variant::Variant as TypeVariant;
+    use crate::tree::r#type::Type;
+
+    #[test]
+    fn ok_empty() {
+        let input = r#"|| true"#;
+
+        let expected = Ok((
+            ClosureExpression::new(
+                Location::test(1, 1),
+                vec![],
+                ExpressionTree::new(
+                    Location::test(1, 4),
+                    ExpressionTreeNode::operand(ExpressionOperand::LiteralBoolean(
+                        BooleanLiteral::new(Location::test(1, 4), LexicalBooleanLiteral::r#true()),
+                    )),
+                ),
+            ),
+            Some(Token::new(Lexeme::Eof, Location::test(1, 8))),
+        ));
+
+        let result = Parser::default().parse(TokenStream::test(input).wrap(), None);
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn ok_multiple() {
+        let input = r#"|acc, x: u8| acc + x"#;
+
+        let expected = Ok((
+            ClosureExpression::new(
+                Location::test(1, 1),
+                vec![
+                    Binding::new(
+                        Location::test(1, 2),
+                        BindingPattern::new(
+                            Location::test(1, 2),
+                            BindingPatternVariant::new_binding(
+                                Identifier::new(Location::test(1, 2), "acc".to_owned()),
+                                false,
+                            ),
+                        ),
+                        None,
+                    ),
+                    Binding::new(
+                        Location::test(1, 7),
+                        BindingPattern::new(
+                            Location::test(1, 7),
+                            BindingPatternVariant::new_binding(
+                                Identifier::new(Location::test(1, 7), "x".to_owned()),
+                                false,
+                            ),
+                        ),
+                        Some(Type::new(
+                            Location::test(1, 10),
+                            TypeVariant::integer_unsigned(zinc_const::bitlength::BYTE),
+                        )),
+                    ),
+                ],
+                ExpressionTree::new_with_leaves(
+                    Location::test(1, 18),
+                    ExpressionTreeNode::operator(ExpressionOperator::Addition),
+                    Some(ExpressionTree::new(
+                        Location::test(1, 14),
+                        ExpressionTreeNode::operand(ExpressionOperand::Identifier(
+                            Identifier::new(Location::test(1, 14), "acc".to_owned()),
+                        )),
+                    )),
+                    Some(ExpressionTree::new(
+                        Location::test(1, 20),
+                        ExpressionTreeNode::operand(ExpressionOperand::Identifier(
+                            Identifier::new(Location::test(1, 20), "x".to_owned()),
+                        )),
+                    )),
+                ),
+            ),
+            Some(Token::new(Lexeme::Eof, Location::test(1, 21))),
+        ));
+
+        let result = Parser::default().parse(TokenStream::test(input).wrap(), None);
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn error_expected_vertical_bar() {
+        let input = r#"|a; a"#;
+
+        let expected: Result<_, ParsingError> =
+            Err(ParsingError::Syntax(SyntaxError::expected_one_of(
+                Location::test(1, 3),
+                vec![",", "|"],
+                Lexeme::Symbol(Symbol::Semicolon),
+                None,
+            )));
+
+        let result = Parser::default().parse(TokenStream::test(input).wrap(), None);
+
+        assert_eq!(result, expected);
+    }
+}
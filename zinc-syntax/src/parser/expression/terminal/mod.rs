@@ -4,6 +4,7 @@
 
 pub mod array;
 pub mod block;
+pub mod closure;
 pub mod conditional;
 pub mod list;
 pub mod r#match;
@@ -31,6 +32,7 @@ use crate::tree::literal::string::Literal as StringLiteral;
 
 use self::array::Parser as ArrayExpressionParser;
 use self::block::Parser as BlockExpressionParser;
+use self::closure::Parser as ClosureExpressionParser;
 use self::conditional::Parser as ConditionalExpressionParser;
 use self::r#match::Parser as MatchExpressionParser;
 use self::tuple::Parser as TupleExpressionParser;
@@ -52,9 +54,10 @@ impl Parser {
     /// - array
     /// - conditional
     /// - match
+    /// - closure
     /// - alias (`crate`, `super`, `Self`, `self`)
     /// - identifier
-    /// - literal (boolean, integer, string)
+    /// - literal (boolean, integer, string, byte string)
     ///
     pub fn parse(
         mut self,
@@ -127,6 +130,25 @@ impl Parser {
                             (ExpressionOperand::Match(operand), location, token)
                         })
                 }
+                token
+                @
+                Token {
+                    lexeme: Lexeme::Symbol(Symbol::VerticalBar),
+                    ..
+                }
+                | token
+                @
+                Token {
+                    lexeme: Lexeme::Symbol(Symbol::DoubleVerticalBar),
+                    ..
+                } => {
+                    let location = token.location;
+                    ClosureExpressionParser::default()
+                        .parse(stream, Some(token))
+                        .map(|(operand, token)| {
+                            (ExpressionOperand::Closure(operand), location, token)
+                        })
+                }
                 Token {
                     lexeme: Lexeme::Keyword(keyword @ Keyword::Crate),
                     location,
@@ -189,6 +211,14 @@ impl Parser {
                     location,
                     None,
                 )),
+                Token {
+                    lexeme: Lexeme::Literal(LexicalLiteral::ByteString(string)),
+                    location,
+                } => Ok((
+                    ExpressionOperand::LiteralByteString(StringLiteral::new(location, string)),
+                    location,
+                    None,
+                )),
                 Token { lexeme, location } => Err(ParsingError::Syntax(
                     SyntaxError::expected_expression_or_operand(location, lexeme),
                 )),
@@ -283,6 +313,28 @@ mod tests {
         assert_eq!(result, expected);
     }
 
+    #[test]
+    fn ok_literal_byte_string() {
+        let input = r#"b"description""#;
+
+        let expected = Ok((
+            ExpressionTree::new(
+                Location::test(1, 1),
+                ExpressionTreeNode::Operand(ExpressionOperand::LiteralByteString(
+                    StringLiteral::new(
+                        Location::test(1, 1),
+                        LexicalStringLiteral::new("description".to_owned()),
+                    ),
+                )),
+            ),
+            None,
+        ));
+
+        let result = Parser::default().parse(TokenStream::test(input).wrap(), None);
+
+        assert_eq!(result, expected);
+    }
+
     #[test]
     fn ok_parenthesized() {
         let input = r#"(2 + 2)"#;
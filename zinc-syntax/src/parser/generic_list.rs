@@ -0,0 +1,364 @@
+//!
+//! The generic type parameter list parser.
+//!
+
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use zinc_lexical::Lexeme;
+use zinc_lexical::Symbol;
+use zinc_lexical::Token;
+use zinc_lexical::TokenStream;
+
+use crate::error::Error as SyntaxError;
+use crate::error::ParsingError;
+use crate::tree::generic_parameter::GenericParameter;
+use crate::tree::identifier::Identifier;
+
+/// The missing generic parameter identifier error hint.
+pub static HINT_EXPECTED_IDENTIFIER: &str =
+    "generic parameters must be identifiers, e.g. `fn max<T>(a: T, b: T) -> T { ... }`";
+/// The missing trait bound identifier error hint.
+pub static HINT_EXPECTED_BOUND: &str =
+    "generic parameter bounds must be trait identifiers, e.g. `fn area<T: Shape>(shape: T) { ... }`";
+
+///
+/// The parser state.
+///
+#[derive(Debug, Clone, Copy)]
+pub enum State {
+    /// The initial state.
+    Lesser,
+    /// The `<` or `<{parameter},` has been parsed so far.
+    IdentifierOrGreater,
+    /// The `< {identifier}` has been parsed so far.
+    ColonOrCommaOrGreater,
+    /// The `< {identifier}:` or `< {identifier}: {bound} +` has been parsed so far.
+    Bound,
+    /// The `< {identifier}: {bound}` has been parsed so far.
+    PlusOrCommaOrGreater,
+}
+
+impl Default for State {
+    fn default() -> Self {
+        Self::Lesser
+    }
+}
+
+///
+/// The generic type parameter list parser.
+///
+#[derive(Default)]
+pub struct Parser {
+    /// The parser state.
+    state: State,
+    /// The token returned from a subparser.
+    next: Option<Token>,
+    /// The parameter being parsed.
+    identifier: Option<Identifier>,
+    /// The trait bounds of the parameter being parsed.
+    bounds: Vec<Identifier>,
+    /// The parsed parameters.
+    parameters: Vec<GenericParameter>,
+}
+
+impl Parser {
+    ///
+    /// Parses a generic type parameter list.
+    ///
+    /// '<K: Hash + Eq, V>'
+    ///
+    pub fn parse(
+        mut self,
+        stream: Rc<RefCell<TokenStream>>,
+        initial: Option<Token>,
+    ) -> Result<(Vec<GenericParameter>, Option<Token>), ParsingError> {
+        self.next = initial;
+
+        loop {
+            match self.state {
+                State::Lesser => {
+                    match crate::parser::take_or_next(self.next.take(), stream.clone())? {
+                        Token {
+                            lexeme: Lexeme::Symbol(Symbol::Lesser),
+                            ..
+                        } => {
+                            self.state = State::IdentifierOrGreater;
+                        }
+                        Token { lexeme, location } => {
+                            return Err(ParsingError::Syntax(SyntaxError::expected_one_of(
+                                location,
+                                vec!["<"],
+                                lexeme,
+                                None,
+                            )))
+                        }
+                    }
+                }
+                State::IdentifierOrGreater => {
+                    match crate::parser::take_or_next(self.next.take(), stream.clone())? {
+                        Token {
+                            lexeme: Lexeme::Symbol(Symbol::Greater),
+                            ..
+                        } => {
+                            return Ok((self.parameters, None));
+                        }
+                        Token {
+                            lexeme: Lexeme::Identifier(identifier),
+                            location,
+                        } => {
+                            self.identifier = Some(Identifier::new(location, identifier.inner));
+                            self.state = State::ColonOrCommaOrGreater;
+                        }
+                        Token { lexeme, location } => {
+                            return Err(ParsingError::Syntax(SyntaxError::expected_identifier(
+                                location,
+                                lexeme,
+                                Some(HINT_EXPECTED_IDENTIFIER),
+                            )))
+                        }
+                    }
+                }
+                State::ColonOrCommaOrGreater => {
+                    match crate::parser::take_or_next(self.next.take(), stream.clone())? {
+                        Token {
+                            lexeme: Lexeme::Symbol(Symbol::Colon),
+                            ..
+                        } => self.state = State::Bound,
+                        Token {
+                            lexeme: Lexeme::Symbol(Symbol::Comma),
+                            ..
+                        } => {
+                            self.push_parameter();
+                            self.state = State::IdentifierOrGreater;
+                        }
+                        Token {
+                            lexeme: Lexeme::Symbol(Symbol::Greater),
+                            ..
+                        } => {
+                            self.push_parameter();
+                            return Ok((self.parameters, None));
+                        }
+                        Token { lexeme, location } => {
+                            return Err(ParsingError::Syntax(SyntaxError::expected_one_of(
+                                location,
+                                vec![":", ",", ">"],
+                                lexeme,
+                                None,
+                            )))
+                        }
+                    }
+                }
+                State::Bound => {
+                    match crate::parser::take_or_next(self.next.take(), stream.clone())? {
+                        Token {
+                            lexeme: Lexeme::Identifier(identifier),
+                            location,
+                        } => {
+                            self.bounds
+                                .push(Identifier::new(location, identifier.inner));
+                            self.state = State::PlusOrCommaOrGreater;
+                        }
+                        Token { lexeme, location } => {
+                            return Err(ParsingError::Syntax(SyntaxError::expected_identifier(
+                                location,
+                                lexeme,
+                                Some(HINT_EXPECTED_BOUND),
+                            )))
+                        }
+                    }
+                }
+                State::PlusOrCommaOrGreater => {
+                    match crate::parser::take_or_next(self.next.take(), stream.clone())? {
+                        Token {
+                            lexeme: Lexeme::Symbol(Symbol::Plus),
+                            ..
+                        } => self.state = State::Bound,
+                        Token {
+                            lexeme: Lexeme::Symbol(Symbol::Comma),
+                            ..
+                        } => {
+                            self.push_parameter();
+                            self.state = State::IdentifierOrGreater;
+                        }
+                        Token {
+                            lexeme: Lexeme::Symbol(Symbol::Greater),
+                            ..
+                        } => {
+                            self.push_parameter();
+                            return Ok((self.parameters, None));
+                        }
+                        Token { lexeme, location } => {
+                            return Err(ParsingError::Syntax(SyntaxError::expected_one_of(
+                                location,
+                                vec!["+", ",", ">"],
+                                lexeme,
+                                None,
+                            )))
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    ///
+    /// Finalizes the parameter being parsed and pushes it to the list.
+    ///
+    fn push_parameter(&mut self) {
+        let identifier = self
+            .identifier
+            .take()
+            .expect(zinc_const::panic::VALUE_ALWAYS_EXISTS);
+        let bounds = std::mem::take(&mut self.bounds);
+
+        self.parameters.push(GenericParameter::new(
+            identifier.location,
+            identifier,
+            bounds,
+        ));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use zinc_lexical::Keyword;
+    use zinc_lexical::Lexeme;
+    use zinc_lexical::Location;
+    use zinc_lexical::Symbol;
+    use zinc_lexical::TokenStream;
+
+    use super::Parser;
+    use crate::error::Error as SyntaxError;
+    use crate::error::ParsingError;
+    use crate::tree::generic_parameter::GenericParameter;
+    use crate::tree::identifier::Identifier;
+
+    #[test]
+    fn ok_empty() {
+        let input = r#"<>"#;
+
+        let expected = Ok((vec![], None));
+
+        let result = Parser::default().parse(TokenStream::test(input).wrap(), None);
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn ok_single() {
+        let input = r#"<T>"#;
+
+        let expected = Ok((
+            vec![GenericParameter::new(
+                Location::test(1, 2),
+                Identifier::new(Location::test(1, 2), "T".to_owned()),
+                vec![],
+            )],
+            None,
+        ));
+
+        let result = Parser::default().parse(TokenStream::test(input).wrap(), None);
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn ok_multiple_with_comma() {
+        let input = r#"<K, V,>"#;
+
+        let expected = Ok((
+            vec![
+                GenericParameter::new(
+                    Location::test(1, 2),
+                    Identifier::new(Location::test(1, 2), "K".to_owned()),
+                    vec![],
+                ),
+                GenericParameter::new(
+                    Location::test(1, 5),
+                    Identifier::new(Location::test(1, 5), "V".to_owned()),
+                    vec![],
+                ),
+            ],
+            None,
+        ));
+
+        let result = Parser::default().parse(TokenStream::test(input).wrap(), None);
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn ok_bounds() {
+        let input = r#"<K: Hash + Eq, V>"#;
+
+        let expected = Ok((
+            vec![
+                GenericParameter::new(
+                    Location::test(1, 2),
+                    Identifier::new(Location::test(1, 2), "K".to_owned()),
+                    vec![
+                        Identifier::new(Location::test(1, 5), "Hash".to_owned()),
+                        Identifier::new(Location::test(1, 12), "Eq".to_owned()),
+                    ],
+                ),
+                GenericParameter::new(
+                    Location::test(1, 16),
+                    Identifier::new(Location::test(1, 16), "V".to_owned()),
+                    vec![],
+                ),
+            ],
+            None,
+        ));
+
+        let result = Parser::default().parse(TokenStream::test(input).wrap(), None);
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn error_expected_identifier() {
+        let input = r#"<u8>"#;
+
+        let expected = Err(ParsingError::Syntax(SyntaxError::expected_identifier(
+            Location::test(1, 2),
+            Lexeme::Keyword(Keyword::IntegerUnsigned { bitlength: 8 }),
+            Some(super::HINT_EXPECTED_IDENTIFIER),
+        )));
+
+        let result = Parser::default().parse(TokenStream::test(input).wrap(), None);
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn error_expected_bound() {
+        let input = r#"<T: u8>"#;
+
+        let expected = Err(ParsingError::Syntax(SyntaxError::expected_identifier(
+            Location::test(1, 5),
+            Lexeme::Keyword(Keyword::IntegerUnsigned { bitlength: 8 }),
+            Some(super::HINT_EXPECTED_BOUND),
+        )));
+
+        let result = Parser::default().parse(TokenStream::test(input).wrap(), None);
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn error_expected_comma_or_greater() {
+        let input = r#"<T;>"#;
+
+        let expected = Err(ParsingError::Syntax(SyntaxError::expected_one_of(
+            Location::test(1, 3),
+            vec![":", ",", ">"],
+            Lexeme::Symbol(Symbol::Semicolon),
+            None,
+        )));
+
+        let result = Parser::default().parse(TokenStream::test(input).wrap(), None);
+
+        assert_eq!(result, expected);
+    }
+}
@@ -8,9 +8,11 @@ pub mod binding_list;
 pub mod expression;
 pub mod field;
 pub mod field_list;
+pub mod generic_list;
 pub mod identifier_path;
 pub mod pattern_binding;
 pub mod pattern_match;
+pub mod recovery;
 pub mod statement;
 pub mod r#type;
 pub mod variant;
@@ -24,6 +26,7 @@ use zinc_lexical::Token;
 use zinc_lexical::TokenStream;
 
 use crate::error::ParsingError;
+use crate::parser::recovery::Boundary;
 use crate::parser::statement::local_mod::Parser as ModuleLocalStatementParser;
 use crate::tree::module::Module;
 
@@ -40,26 +43,65 @@ impl Parser {
     ///
     /// Parses a list of module level statements.
     ///
-    pub fn parse(mut self, input: &str, file: usize) -> Result<Module, ParsingError> {
+    /// If a statement cannot be parsed, the error is saved and the parser recovers at the next
+    /// item boundary, so all the syntax errors of the module are returned at once. The nested
+    /// blocks and items recover at their own boundaries, which are described in `recovery`.
+    ///
+    pub fn parse(mut self, input: &str, file: usize) -> Result<Module, Vec<ParsingError>> {
         let stream = TokenStream::new(input, file).wrap();
 
+        crate::parser::recovery::start();
         let mut statements = Vec::new();
+        let mut errors = Vec::new();
         loop {
-            match crate::parser::take_or_next(self.next.take(), stream.clone())? {
+            let token = match crate::parser::take_or_next(self.next.take(), stream.clone()) {
+                Ok(token) => token,
+                Err(error) => {
+                    errors.push(error);
+                    break;
+                }
+            };
+
+            match token {
                 Token {
                     lexeme: Lexeme::Eof,
                     ..
                 } => break,
                 token => {
-                    let (statement, next) =
-                        ModuleLocalStatementParser::default().parse(stream.clone(), Some(token))?;
-                    self.next = next;
-                    statements.push(statement);
+                    let result =
+                        ModuleLocalStatementParser::default().parse(stream.clone(), Some(token));
+                    errors.extend(crate::parser::recovery::take());
+
+                    match result {
+                        Ok((statement, next)) => {
+                            self.next = next;
+                            statements.push(statement);
+                        }
+                        Err(error @ ParsingError::Lexical(_)) => {
+                            errors.push(error);
+                            break;
+                        }
+                        Err(error @ ParsingError::Syntax(_)) => {
+                            errors.push(error);
+                            match crate::parser::recovery::skip(stream.clone(), Boundary::Module) {
+                                Ok(next) => self.next = next,
+                                Err(error) => {
+                                    errors.push(error);
+                                    break;
+                                }
+                            }
+                        }
+                    }
                 }
             }
         }
+        errors.extend(crate::parser::recovery::finish());
 
-        Ok(Module::new(statements))
+        if errors.is_empty() {
+            Ok(Module::new(statements))
+        } else {
+            Err(errors)
+        }
     }
 }
 
@@ -75,3 +117,107 @@ pub fn take_or_next(
         None => Ok(stream.borrow_mut().next()?),
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use zinc_lexical::Keyword;
+    use zinc_lexical::Lexeme;
+    use zinc_lexical::Location;
+    use zinc_lexical::Symbol;
+
+    use super::Parser;
+    use crate::error::Error as SyntaxError;
+    use crate::error::ParsingError;
+    use crate::parser::statement::r#type::HINT_EXPECTED_IDENTIFIER;
+    use crate::parser::statement::r#type::HINT_EXPECTED_TYPE;
+
+    #[test]
+    fn error_recovered_nested() {
+        let input = r#"
+impl Data {
+    fn first() {
+        let a = ;
+        let b = 42;
+        let c: = b;
+    }
+
+    fn second( -> u8 {
+        42
+    }
+
+    fn third() {
+        let d = 1 +;
+    }
+}
+
+contract Test {
+    pub fn method(self) {
+        let e = ;
+    }
+}
+
+type = u8;
+"#;
+
+        let expected = Err(vec![
+            ParsingError::Syntax(SyntaxError::expected_expression_or_operand(
+                Location::test(4, 17),
+                Lexeme::Symbol(Symbol::Semicolon),
+            )),
+            ParsingError::Syntax(SyntaxError::expected_type(
+                Location::test(6, 16),
+                Lexeme::Symbol(Symbol::Equals),
+                None,
+            )),
+            ParsingError::Syntax(SyntaxError::expected_one_of(
+                Location::test(9, 16),
+                vec![")"],
+                Lexeme::Symbol(Symbol::MinusGreater),
+                None,
+            )),
+            ParsingError::Syntax(SyntaxError::expected_expression_or_operand(
+                Location::test(14, 20),
+                Lexeme::Symbol(Symbol::Semicolon),
+            )),
+            ParsingError::Syntax(SyntaxError::expected_expression_or_operand(
+                Location::test(20, 17),
+                Lexeme::Symbol(Symbol::Semicolon),
+            )),
+            ParsingError::Syntax(SyntaxError::expected_identifier(
+                Location::test(24, 6),
+                Lexeme::Symbol(Symbol::Equals),
+                Some(HINT_EXPECTED_IDENTIFIER),
+            )),
+        ]);
+
+        let result = Parser::default().parse(input, Location::test(1, 1).file);
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn error_multiple() {
+        let input = r#"
+type = u8;
+type B = field;
+type C u8;
+"#;
+
+        let expected = Err(vec![
+            ParsingError::Syntax(SyntaxError::expected_identifier(
+                Location::test(2, 6),
+                Lexeme::Symbol(Symbol::Equals),
+                Some(HINT_EXPECTED_IDENTIFIER),
+            )),
+            ParsingError::Syntax(SyntaxError::expected_type(
+                Location::test(4, 8),
+                Lexeme::Keyword(Keyword::IntegerUnsigned { bitlength: 8 }),
+                Some(HINT_EXPECTED_TYPE),
+            )),
+        ]);
+
+        let result = Parser::default().parse(input, Location::test(1, 1).file);
+
+        assert_eq!(result, expected);
+    }
+}
@@ -20,6 +20,7 @@ use crate::tree::identifier::Identifier;
 use crate::tree::literal::boolean::Literal as BooleanLiteral;
 use crate::tree::literal::integer::Literal as IntegerLiteral;
 use crate::tree::pattern_match::builder::Builder as MatchPatternBuilder;
+use crate::tree::pattern_match::variant::Variant as MatchPatternVariant;
 use crate::tree::pattern_match::Pattern as MatchPattern;
 
 ///
@@ -29,10 +30,24 @@ use crate::tree::pattern_match::Pattern as MatchPattern;
 pub enum State {
     /// The initial state.
     Start,
+    /// The integer literal has been parsed so far.
+    RangeOrEnd,
+    /// The integer literal and `..=` have been parsed so far.
+    RangeEnd,
     /// The first path operand has been parsed so far.
     PathOperatorOrEnd,
     /// The first path operand and a `::` path operator have been parsed so far.
     PathOperand,
+    /// The optional path and `(` or `( {pattern},` have been parsed so far.
+    TuplePatternOrParenthesisRight,
+    /// The optional path and `( {pattern}` have been parsed so far.
+    TupleCommaOrParenthesisRight,
+    /// The path and `{` or `{ {field},` have been parsed so far.
+    StructureFieldOrBracketCurlyRight,
+    /// The path and `{ {identifier}` have been parsed so far.
+    StructureColonOrCommaOrBracketCurlyRight,
+    /// The path and `{ {identifier}: {pattern}` have been parsed so far.
+    StructureCommaOrBracketCurlyRight,
 }
 
 impl Default for State {
@@ -50,21 +65,65 @@ pub struct Parser {
     state: State,
     /// The builder of the parsed value.
     builder: MatchPatternBuilder,
+    /// The structure field identifier, whose pattern is being parsed.
+    field: Option<Identifier>,
     /// The token returned from a subparser.
     next: Option<Token>,
 }
 
 impl Parser {
     ///
-    /// Parses a match pattern.
+    /// Parses a match pattern, which may consist of several alternatives.
     ///
     /// 'true'
     /// '42'
+    /// '1..=9'
     /// 'variable'
     /// 'Path::To::Item'
+    /// 'Option::Some(value)'
+    /// 'Shape::Circle { radius: r }'
+    /// '(a, 0, _)'
+    /// '1 | 2 | 3'
     /// '_'
     ///
     pub fn parse(
+        self,
+        stream: Rc<RefCell<TokenStream>>,
+        initial: Option<Token>,
+    ) -> Result<(MatchPattern, Option<Token>), ParsingError> {
+        let (pattern, mut next) = self.parse_alternative(stream.clone(), initial)?;
+        let location = pattern.location;
+        let mut patterns = vec![pattern];
+
+        loop {
+            match crate::parser::take_or_next(next.take(), stream.clone())? {
+                Token {
+                    lexeme: Lexeme::Symbol(Symbol::VerticalBar),
+                    ..
+                } => {
+                    let (pattern, next_token) =
+                        Self::default().parse_alternative(stream.clone(), None)?;
+                    next = next_token;
+                    patterns.push(pattern);
+                }
+                token if patterns.len() == 1 => return Ok((patterns.remove(0), Some(token))),
+                token => {
+                    return Ok((
+                        MatchPattern::new(
+                            location,
+                            MatchPatternVariant::new_alternatives(patterns),
+                        ),
+                        Some(token),
+                    ))
+                }
+            }
+        }
+    }
+
+    ///
+    /// Parses a single match pattern alternative.
+    ///
+    fn parse_alternative(
         mut self,
         stream: Rc<RefCell<TokenStream>>,
         initial: Option<Token>,
@@ -91,7 +150,7 @@ impl Parser {
                             self.builder.set_location(location);
                             self.builder
                                 .set_integer_literal(IntegerLiteral::new(location, integer));
-                            return Ok((self.builder.finish(), None));
+                            self.state = State::RangeOrEnd;
                         }
                         Token {
                             lexeme: Lexeme::Identifier(identifier),
@@ -123,6 +182,14 @@ impl Parser {
                                 .set_binding(Identifier::new(location, keyword.to_string()));
                             self.state = State::PathOperatorOrEnd;
                         }
+                        Token {
+                            lexeme: Lexeme::Symbol(Symbol::ParenthesisLeft),
+                            location,
+                        } => {
+                            self.builder.set_location(location);
+                            self.builder.set_tuple();
+                            self.state = State::TuplePatternOrParenthesisRight;
+                        }
                         Token {
                             lexeme: Lexeme::Symbol(Symbol::Underscore),
                             location,
@@ -138,6 +205,32 @@ impl Parser {
                         }
                     }
                 }
+                State::RangeOrEnd => {
+                    match crate::parser::take_or_next(self.next.take(), stream.clone())? {
+                        Token {
+                            lexeme: Lexeme::Symbol(Symbol::DoubleDotEquals),
+                            ..
+                        } => self.state = State::RangeEnd,
+                        token => return Ok((self.builder.finish(), Some(token))),
+                    }
+                }
+                State::RangeEnd => {
+                    match crate::parser::take_or_next(self.next.take(), stream.clone())? {
+                        Token {
+                            lexeme: Lexeme::Literal(LexicalLiteral::Integer(integer)),
+                            location,
+                        } => {
+                            self.builder
+                                .set_range_end(IntegerLiteral::new(location, integer));
+                            return Ok((self.builder.finish(), None));
+                        }
+                        Token { lexeme, location } => {
+                            return Err(ParsingError::Syntax(
+                                SyntaxError::expected_integer_literal(location, lexeme),
+                            ));
+                        }
+                    }
+                }
                 State::PathOperatorOrEnd => {
                     match crate::parser::take_or_next(self.next.take(), stream.clone())? {
                         Token {
@@ -148,6 +241,20 @@ impl Parser {
                                 .push_path_operator(ExpressionOperator::Path, location);
                             self.state = State::PathOperand;
                         }
+                        Token {
+                            lexeme: Lexeme::Symbol(Symbol::ParenthesisLeft),
+                            ..
+                        } => {
+                            self.builder.set_path_tuple();
+                            self.state = State::TuplePatternOrParenthesisRight;
+                        }
+                        Token {
+                            lexeme: Lexeme::Symbol(Symbol::BracketCurlyLeft),
+                            ..
+                        } => {
+                            self.builder.set_path_structure();
+                            self.state = State::StructureFieldOrBracketCurlyRight;
+                        }
                         token => return Ok((self.builder.finish(), Some(token))),
                     }
                 }
@@ -158,9 +265,131 @@ impl Parser {
                     self.builder.push_path_element(expression);
                     self.state = State::PathOperatorOrEnd;
                 }
+                State::TuplePatternOrParenthesisRight => {
+                    match crate::parser::take_or_next(self.next.take(), stream.clone())? {
+                        Token {
+                            lexeme: Lexeme::Symbol(Symbol::ParenthesisRight),
+                            ..
+                        } => return Ok((self.builder.finish(), None)),
+                        token => {
+                            let (pattern, next) =
+                                Self::default().parse(stream.clone(), Some(token))?;
+                            self.next = next;
+                            self.builder.push_tuple_pattern(pattern);
+                            self.state = State::TupleCommaOrParenthesisRight;
+                        }
+                    }
+                }
+                State::TupleCommaOrParenthesisRight => {
+                    match crate::parser::take_or_next(self.next.take(), stream.clone())? {
+                        Token {
+                            lexeme: Lexeme::Symbol(Symbol::Comma),
+                            ..
+                        } => {
+                            self.builder.set_comma();
+                            self.state = State::TuplePatternOrParenthesisRight;
+                        }
+                        Token {
+                            lexeme: Lexeme::Symbol(Symbol::ParenthesisRight),
+                            ..
+                        } => return Ok((self.builder.finish(), None)),
+                        Token { lexeme, location } => {
+                            return Err(ParsingError::Syntax(SyntaxError::expected_one_of(
+                                location,
+                                vec![",", ")"],
+                                lexeme,
+                                None,
+                            )));
+                        }
+                    }
+                }
+                State::StructureFieldOrBracketCurlyRight => {
+                    match crate::parser::take_or_next(self.next.take(), stream.clone())? {
+                        Token {
+                            lexeme: Lexeme::Identifier(identifier),
+                            location,
+                        } => {
+                            self.field = Some(Identifier::new(location, identifier.inner));
+                            self.state = State::StructureColonOrCommaOrBracketCurlyRight;
+                        }
+                        Token {
+                            lexeme: Lexeme::Symbol(Symbol::BracketCurlyRight),
+                            ..
+                        } => return Ok((self.builder.finish(), None)),
+                        Token { lexeme, location } => {
+                            return Err(ParsingError::Syntax(SyntaxError::expected_one_of(
+                                location,
+                                vec!["{identifier}", "}"],
+                                lexeme,
+                                None,
+                            )));
+                        }
+                    }
+                }
+                State::StructureColonOrCommaOrBracketCurlyRight => {
+                    match crate::parser::take_or_next(self.next.take(), stream.clone())? {
+                        Token {
+                            lexeme: Lexeme::Symbol(Symbol::Colon),
+                            ..
+                        } => {
+                            let (pattern, next) =
+                                Self::default().parse(stream.clone(), self.next.take())?;
+                            self.next = next;
+                            self.push_structure_field(Some(pattern));
+                            self.state = State::StructureCommaOrBracketCurlyRight;
+                        }
+                        token => {
+                            self.push_structure_field(None);
+                            self.next = Some(token);
+                            self.state = State::StructureCommaOrBracketCurlyRight;
+                        }
+                    }
+                }
+                State::StructureCommaOrBracketCurlyRight => {
+                    match crate::parser::take_or_next(self.next.take(), stream.clone())? {
+                        Token {
+                            lexeme: Lexeme::Symbol(Symbol::Comma),
+                            ..
+                        } => self.state = State::StructureFieldOrBracketCurlyRight,
+                        Token {
+                            lexeme: Lexeme::Symbol(Symbol::BracketCurlyRight),
+                            ..
+                        } => return Ok((self.builder.finish(), None)),
+                        Token { lexeme, location } => {
+                            return Err(ParsingError::Syntax(SyntaxError::expected_one_of(
+                                location,
+                                vec![",", "}"],
+                                lexeme,
+                                None,
+                            )));
+                        }
+                    }
+                }
             }
         }
     }
+
+    ///
+    /// Pushes the structure field pattern to the builder.
+    ///
+    /// If the pattern is omitted, the field is bound to a variable with the same name,
+    /// e.g. `Shape::Circle { radius }`.
+    ///
+    fn push_structure_field(&mut self, pattern: Option<MatchPattern>) {
+        let identifier = self
+            .field
+            .take()
+            .expect(zinc_const::panic::VALUE_ALWAYS_EXISTS);
+
+        let pattern = pattern.unwrap_or_else(|| {
+            MatchPattern::new(
+                identifier.location,
+                MatchPatternVariant::new_binding(identifier.clone()),
+            )
+        });
+
+        self.builder.push_structure_field(identifier, pattern);
+    }
 }
 
 #[cfg(test)]
@@ -196,7 +425,7 @@ mod tests {
                     LexicalBooleanLiteral::r#true(),
                 )),
             ),
-            None,
+            Some(Token::new(Lexeme::Eof, Location::test(1, 5))),
         ));
 
         let result = Parser::default().parse(TokenStream::test(input).wrap(), None);
@@ -216,7 +445,7 @@ mod tests {
                     LexicalIntegerLiteral::new_decimal("42".to_owned()),
                 )),
             ),
-            None,
+            Some(Token::new(Lexeme::Eof, Location::test(1, 3))),
         ));
 
         let result = Parser::default().parse(TokenStream::test(input).wrap(), None);
@@ -286,6 +515,90 @@ mod tests {
         assert_eq!(result, expected);
     }
 
+    #[test]
+    fn ok_path_tuple() {
+        let input = r#"Option::Some(value)"#;
+
+        let expected = Ok((
+            MatchPattern::new(
+                Location::test(1, 1),
+                MatchPatternVariant::new_path_tuple(
+                    ExpressionTree::new_with_leaves(
+                        Location::test(1, 7),
+                        ExpressionTreeNode::operator(ExpressionOperator::Path),
+                        Some(ExpressionTree::new(
+                            Location::test(1, 1),
+                            ExpressionTreeNode::operand(ExpressionOperand::Identifier(
+                                Identifier::new(Location::test(1, 1), "Option".to_owned()),
+                            )),
+                        )),
+                        Some(ExpressionTree::new(
+                            Location::test(1, 9),
+                            ExpressionTreeNode::operand(ExpressionOperand::Identifier(
+                                Identifier::new(Location::test(1, 9), "Some".to_owned()),
+                            )),
+                        )),
+                    ),
+                    vec![MatchPattern::new(
+                        Location::test(1, 14),
+                        MatchPatternVariant::new_binding(Identifier::new(
+                            Location::test(1, 14),
+                            "value".to_owned(),
+                        )),
+                    )],
+                ),
+            ),
+            Some(Token::new(Lexeme::Eof, Location::test(1, 20))),
+        ));
+
+        let result = Parser::default().parse(TokenStream::test(input).wrap(), None);
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn ok_path_structure() {
+        let input = r#"Circle { radius, center: _ }"#;
+
+        let expected = Ok((
+            MatchPattern::new(
+                Location::test(1, 1),
+                MatchPatternVariant::new_path_structure(
+                    ExpressionTree::new(
+                        Location::test(1, 1),
+                        ExpressionTreeNode::operand(ExpressionOperand::Identifier(
+                            Identifier::new(Location::test(1, 1), "Circle".to_owned()),
+                        )),
+                    ),
+                    vec![
+                        (
+                            Identifier::new(Location::test(1, 10), "radius".to_owned()),
+                            MatchPattern::new(
+                                Location::test(1, 10),
+                                MatchPatternVariant::new_binding(Identifier::new(
+                                    Location::test(1, 10),
+                                    "radius".to_owned(),
+                                )),
+                            ),
+                        ),
+                        (
+                            Identifier::new(Location::test(1, 18), "center".to_owned()),
+                            MatchPattern::new(
+                                Location::test(1, 26),
+                                MatchPatternVariant::new_wildcard(),
+                            ),
+                        ),
+                    ],
+                ),
+            ),
+            Some(Token::new(Lexeme::Eof, Location::test(1, 29))),
+        ));
+
+        let result = Parser::default().parse(TokenStream::test(input).wrap(), None);
+
+        assert_eq!(result, expected);
+    }
+
     #[test]
     fn ok_path_alias() {
         let input = r#"Self::Value"#;
@@ -321,13 +634,130 @@ mod tests {
         assert_eq!(result, expected);
     }
 
+    #[test]
+    fn ok_range() {
+        let input = r#"1..=9"#;
+
+        let expected = Ok((
+            MatchPattern::new(
+                Location::test(1, 1),
+                MatchPatternVariant::new_range(
+                    IntegerLiteral::new(
+                        Location::test(1, 1),
+                        LexicalIntegerLiteral::new_decimal("1".to_owned()),
+                    ),
+                    IntegerLiteral::new(
+                        Location::test(1, 5),
+                        LexicalIntegerLiteral::new_decimal("9".to_owned()),
+                    ),
+                ),
+            ),
+            Some(Token::new(Lexeme::Eof, Location::test(1, 6))),
+        ));
+
+        let result = Parser::default().parse(TokenStream::test(input).wrap(), None);
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn ok_tuple() {
+        let input = r#"(value, 42, _)"#;
+
+        let expected = Ok((
+            MatchPattern::new(
+                Location::test(1, 1),
+                MatchPatternVariant::new_tuple(vec![
+                    MatchPattern::new(
+                        Location::test(1, 2),
+                        MatchPatternVariant::new_binding(Identifier::new(
+                            Location::test(1, 2),
+                            "value".to_owned(),
+                        )),
+                    ),
+                    MatchPattern::new(
+                        Location::test(1, 9),
+                        MatchPatternVariant::new_integer_literal(IntegerLiteral::new(
+                            Location::test(1, 9),
+                            LexicalIntegerLiteral::new_decimal("42".to_owned()),
+                        )),
+                    ),
+                    MatchPattern::new(Location::test(1, 13), MatchPatternVariant::new_wildcard()),
+                ]),
+            ),
+            Some(Token::new(Lexeme::Eof, Location::test(1, 15))),
+        ));
+
+        let result = Parser::default().parse(TokenStream::test(input).wrap(), None);
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn ok_tuple_parenthesized() {
+        let input = r#"(42)"#;
+
+        let expected = Ok((
+            MatchPattern::new(
+                Location::test(1, 2),
+                MatchPatternVariant::new_integer_literal(IntegerLiteral::new(
+                    Location::test(1, 2),
+                    LexicalIntegerLiteral::new_decimal("42".to_owned()),
+                )),
+            ),
+            Some(Token::new(Lexeme::Eof, Location::test(1, 5))),
+        ));
+
+        let result = Parser::default().parse(TokenStream::test(input).wrap(), None);
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn ok_alternatives() {
+        let input = r#"1 | 2..=5"#;
+
+        let expected = Ok((
+            MatchPattern::new(
+                Location::test(1, 1),
+                MatchPatternVariant::new_alternatives(vec![
+                    MatchPattern::new(
+                        Location::test(1, 1),
+                        MatchPatternVariant::new_integer_literal(IntegerLiteral::new(
+                            Location::test(1, 1),
+                            LexicalIntegerLiteral::new_decimal("1".to_owned()),
+                        )),
+                    ),
+                    MatchPattern::new(
+                        Location::test(1, 5),
+                        MatchPatternVariant::new_range(
+                            IntegerLiteral::new(
+                                Location::test(1, 5),
+                                LexicalIntegerLiteral::new_decimal("2".to_owned()),
+                            ),
+                            IntegerLiteral::new(
+                                Location::test(1, 9),
+                                LexicalIntegerLiteral::new_decimal("5".to_owned()),
+                            ),
+                        ),
+                    ),
+                ]),
+            ),
+            Some(Token::new(Lexeme::Eof, Location::test(1, 10))),
+        ));
+
+        let result = Parser::default().parse(TokenStream::test(input).wrap(), None);
+
+        assert_eq!(result, expected);
+    }
+
     #[test]
     fn ok_wildcard() {
         let input = r#"_"#;
 
         let expected = Ok((
             MatchPattern::new(Location::test(1, 1), MatchPatternVariant::Wildcard),
-            None,
+            Some(Token::new(Lexeme::Eof, Location::test(1, 2))),
         ));
 
         let result = Parser::default().parse(TokenStream::test(input).wrap(), None);
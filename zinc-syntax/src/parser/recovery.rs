@@ -0,0 +1,151 @@
+//!
+//! The syntax error recovery.
+//!
+
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use zinc_lexical::Keyword;
+use zinc_lexical::Lexeme;
+use zinc_lexical::Symbol;
+use zinc_lexical::Token;
+use zinc_lexical::TokenStream;
+
+use crate::error::ParsingError;
+
+thread_local! {
+    /// The syntax errors the nested parsers have recovered from.
+    ///
+    /// The storage is only initialized while a whole module is being parsed, so the nested
+    /// parsers used on their own keep returning the first error as usual.
+    static ERRORS: RefCell<Option<Vec<ParsingError>>> = const { RefCell::new(None) };
+}
+
+///
+/// The point where the parsing can be resumed after an error.
+///
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Boundary {
+    /// The next statement after `;` or the end of the enclosing block at `}`.
+    Statement,
+    /// The next item of the enclosing `impl`, `contract`, or `trait`, or its end at `}`.
+    Item,
+    /// The next module item. The unmatched `}` are skipped.
+    Module,
+}
+
+///
+/// Starts collecting the recovered errors.
+///
+pub fn start() {
+    ERRORS.with(|errors| *errors.borrow_mut() = Some(Vec::new()));
+}
+
+///
+/// Takes the errors recovered so far.
+///
+pub fn take() -> Vec<ParsingError> {
+    ERRORS.with(|errors| {
+        errors
+            .borrow_mut()
+            .as_mut()
+            .map(std::mem::take)
+            .unwrap_or_default()
+    })
+}
+
+///
+/// Stops collecting the recovered errors, returning the ones which have not been taken yet.
+///
+pub fn finish() -> Vec<ParsingError> {
+    ERRORS.with(|errors| errors.borrow_mut().take().unwrap_or_default())
+}
+
+///
+/// Saves the `error` and skips the tokens until the `boundary`, where the parsing can be resumed.
+///
+/// Returns the token the caller must continue with, or `None` if it must continue with the next
+/// token from the `stream`.
+///
+/// If the recovery is disabled, the error is lexical, or the end of input has been reached,
+/// the `error` is returned back to be propagated as usual.
+///
+pub fn recover(
+    stream: Rc<RefCell<TokenStream>>,
+    error: ParsingError,
+    boundary: Boundary,
+) -> Result<Option<Token>, ParsingError> {
+    let is_enabled = ERRORS.with(|errors| errors.borrow().is_some());
+    let error = match error {
+        ParsingError::Syntax(error) if is_enabled => error,
+        error => return Err(error),
+    };
+
+    let next = match error.found() {
+        Lexeme::Symbol(Symbol::Semicolon) if boundary == Boundary::Statement => None,
+        Lexeme::Symbol(Symbol::BracketCurlyRight) if boundary != Boundary::Module => Some(
+            Token::new(Lexeme::Symbol(Symbol::BracketCurlyRight), error.location()),
+        ),
+        _ => skip(stream, boundary)?,
+    };
+
+    match next {
+        Some(Token {
+            lexeme: Lexeme::Eof,
+            ..
+        }) => Err(ParsingError::Syntax(error)),
+        next => {
+            ERRORS.with(|errors| {
+                if let Some(errors) = errors.borrow_mut().as_mut() {
+                    errors.push(ParsingError::Syntax(error));
+                }
+            });
+            Ok(next)
+        }
+    }
+}
+
+///
+/// Skips the tokens until the `boundary` at the current nesting level.
+///
+/// Returns the boundary token, or `None` if the boundary is a `;`, which is consumed.
+/// The end of input is returned as is, since it is the boundary of everything.
+///
+pub fn skip(
+    stream: Rc<RefCell<TokenStream>>,
+    boundary: Boundary,
+) -> Result<Option<Token>, ParsingError> {
+    let mut depth = 0usize;
+
+    loop {
+        let token = stream.borrow_mut().next()?;
+        match token.lexeme {
+            Lexeme::Eof => return Ok(Some(token)),
+            Lexeme::Symbol(Symbol::BracketCurlyLeft) => depth += 1,
+            Lexeme::Symbol(Symbol::BracketCurlyRight) if depth > 0 => depth -= 1,
+            Lexeme::Symbol(Symbol::BracketCurlyRight) if boundary != Boundary::Module => {
+                return Ok(Some(token))
+            }
+            Lexeme::Symbol(Symbol::Semicolon) if depth == 0 && boundary == Boundary::Statement => {
+                return Ok(None)
+            }
+            Lexeme::Symbol(Symbol::Number)
+            | Lexeme::Keyword(Keyword::Pub)
+            | Lexeme::Keyword(Keyword::Const)
+            | Lexeme::Keyword(Keyword::Type)
+            | Lexeme::Keyword(Keyword::Struct)
+            | Lexeme::Keyword(Keyword::Enum)
+            | Lexeme::Keyword(Keyword::Fn)
+            | Lexeme::Keyword(Keyword::Mod)
+            | Lexeme::Keyword(Keyword::Use)
+            | Lexeme::Keyword(Keyword::Impl)
+            | Lexeme::Keyword(Keyword::Trait)
+            | Lexeme::Keyword(Keyword::Contract)
+                if depth == 0 && boundary != Boundary::Statement =>
+            {
+                return Ok(Some(token))
+            }
+            _ => {}
+        }
+    }
+}
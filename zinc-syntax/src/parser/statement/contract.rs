@@ -13,6 +13,7 @@ use zinc_lexical::TokenStream;
 
 use crate::error::Error as SyntaxError;
 use crate::error::ParsingError;
+use crate::parser::recovery::Boundary;
 use crate::parser::statement::local_contract::Parser as ContractLocalStatementParser;
 use crate::tree::identifier::Identifier;
 use crate::tree::statement::contract::builder::Builder as ContractStatementBuilder;
@@ -131,10 +132,21 @@ impl Parser {
                             ..
                         } => return Ok((self.builder.finish(), None)),
                         token => {
-                            let (statement, next) = ContractLocalStatementParser::default()
-                                .parse(stream.clone(), Some(token))?;
-                            self.next = next;
-                            self.builder.push_statement(statement);
+                            match ContractLocalStatementParser::default()
+                                .parse(stream.clone(), Some(token))
+                            {
+                                Ok((statement, next)) => {
+                                    self.next = next;
+                                    self.builder.push_statement(statement);
+                                }
+                                Err(error) => {
+                                    self.next = crate::parser::recovery::recover(
+                                        stream.clone(),
+                                        error,
+                                        Boundary::Item,
+                                    )?;
+                                }
+                            }
                         }
                     }
                 }
@@ -404,6 +416,7 @@ mod tests {
                     false,
                     false,
                     Identifier::new(Location::test(3, 12), "f".to_owned()),
+                    None,
                     vec![Binding::new(
                         Location::test(3, 14),
                         BindingPattern::new(
@@ -450,6 +463,7 @@ mod tests {
                         false,
                         false,
                         Identifier::new(Location::test(3, 12), "f1".to_owned()),
+                        None,
                         vec![Binding::new(
                             Location::test(3, 15),
                             BindingPattern::new(
@@ -470,6 +484,7 @@ mod tests {
                         false,
                         false,
                         Identifier::new(Location::test(5, 12), "f2".to_owned()),
+                        None,
                         vec![Binding::new(
                             Location::test(5, 15),
                             BindingPattern::new(
@@ -490,6 +505,7 @@ mod tests {
                         false,
                         false,
                         Identifier::new(Location::test(7, 12), "f3".to_owned()),
+                        None,
                         vec![Binding::new(
                             Location::test(7, 15),
                             BindingPattern::new(
@@ -557,6 +573,7 @@ mod tests {
                         false,
                         false,
                         Identifier::new(Location::test(7, 12), "f1".to_owned()),
+                        None,
                         vec![Binding::new(
                             Location::test(7, 15),
                             BindingPattern::new(
@@ -672,6 +689,7 @@ mod tests {
                         false,
                         false,
                         Identifier::new(Location::test(11, 12), "f1".to_owned()),
+                        None,
                         vec![Binding::new(
                             Location::test(11, 15),
                             BindingPattern::new(
@@ -692,6 +710,7 @@ mod tests {
                         false,
                         false,
                         Identifier::new(Location::test(13, 12), "f2".to_owned()),
+                        None,
                         vec![Binding::new(
                             Location::test(13, 15),
                             BindingPattern::new(
@@ -712,6 +731,7 @@ mod tests {
                         false,
                         false,
                         Identifier::new(Location::test(15, 12), "f3".to_owned()),
+                        None,
                         vec![Binding::new(
                             Location::test(15, 15),
                             BindingPattern::new(
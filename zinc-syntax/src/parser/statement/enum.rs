@@ -13,6 +13,7 @@ use zinc_lexical::TokenStream;
 
 use crate::error::Error as SyntaxError;
 use crate::error::ParsingError;
+use crate::parser::generic_list::Parser as GenericListParser;
 use crate::parser::variant_list::Parser as VariantListParser;
 use crate::tree::identifier::Identifier;
 use crate::tree::statement::r#enum::builder::Builder as EnumStatementBuilder;
@@ -32,6 +33,8 @@ pub enum State {
     /// The `enum` has been parsed so far.
     Identifier,
     /// The `enum {identifier}` has been parsed so far.
+    GenericsOrBracketCurlyLeftOrEnd,
+    /// The `enum {identifier}` with optional `<{generics}>` has been parsed so far.
     BracketCurlyLeftOrEnd,
     /// The `enum {identifier} {` has been parsed so far.
     VariantList,
@@ -70,6 +73,13 @@ impl Parser {
     /// }
     /// '
     ///
+    /// '
+    /// enum Option<T> {
+    ///     None,
+    ///     Some(T),
+    /// }
+    /// '
+    ///
     pub fn parse(
         mut self,
         stream: Rc<RefCell<TokenStream>>,
@@ -106,7 +116,7 @@ impl Parser {
                         } => {
                             let identifier = Identifier::new(location, identifier.inner);
                             self.builder.set_identifier(identifier);
-                            self.state = State::BracketCurlyLeftOrEnd;
+                            self.state = State::GenericsOrBracketCurlyLeftOrEnd;
                         }
                         Token { lexeme, location } => {
                             return Err(ParsingError::Syntax(SyntaxError::expected_identifier(
@@ -117,6 +127,26 @@ impl Parser {
                         }
                     }
                 }
+                State::GenericsOrBracketCurlyLeftOrEnd => {
+                    match crate::parser::take_or_next(self.next.take(), stream.clone())? {
+                        token
+                        @
+                        Token {
+                            lexeme: Lexeme::Symbol(Symbol::Lesser),
+                            ..
+                        } => {
+                            let (generics, next) =
+                                GenericListParser::default().parse(stream.clone(), Some(token))?;
+                            self.builder.set_generics(generics);
+                            self.next = next;
+                            self.state = State::BracketCurlyLeftOrEnd;
+                        }
+                        token => {
+                            self.next = Some(token);
+                            self.state = State::BracketCurlyLeftOrEnd;
+                        }
+                    }
+                }
                 State::BracketCurlyLeftOrEnd => {
                     match crate::parser::take_or_next(self.next.take(), stream.clone())? {
                         Token {
@@ -161,9 +191,16 @@ mod tests {
     use super::Parser;
     use crate::error::Error as SyntaxError;
     use crate::error::ParsingError;
+    use crate::tree::expression::tree::node::operand::Operand as ExpressionOperand;
+    use crate::tree::expression::tree::node::Node as ExpressionTreeNode;
+    use crate::tree::expression::tree::Tree as ExpressionTree;
+    use crate::tree::generic_parameter::GenericParameter;
     use crate::tree::identifier::Identifier;
     use crate::tree::literal::integer::Literal as IntegerLiteral;
+    use crate::tree::r#type::variant::Variant as TypeVariant;
+    use crate::tree::r#type::Type;
     use crate::tree::statement::r#enum::Statement as EnumStatement;
+    use crate::tree::variant::payload::Payload as VariantPayload;
     use crate::tree::variant::Variant;
 
     #[test]
@@ -176,6 +213,7 @@ mod tests {
             EnumStatement::new(
                 Location::test(2, 5),
                 Identifier::new(Location::test(2, 10), "Test".to_owned()),
+                None,
                 vec![],
             ),
             None,
@@ -196,6 +234,7 @@ mod tests {
             EnumStatement::new(
                 Location::test(2, 5),
                 Identifier::new(Location::test(2, 10), "Test".to_owned()),
+                None,
                 vec![],
             ),
             Some(Token::new(
@@ -221,13 +260,15 @@ mod tests {
             EnumStatement::new(
                 Location::test(2, 5),
                 Identifier::new(Location::test(2, 10), "Test".to_owned()),
+                None,
                 vec![Variant::new(
                     Location::test(3, 9),
                     Identifier::new(Location::test(3, 9), "A".to_owned()),
-                    IntegerLiteral::new(
+                    Some(IntegerLiteral::new(
                         Location::test(3, 13),
                         LexicalIntegerLiteral::new_decimal("1".to_owned()),
-                    ),
+                    )),
+                    None,
                 )],
             ),
             None,
@@ -252,30 +293,86 @@ mod tests {
             EnumStatement::new(
                 Location::test(2, 5),
                 Identifier::new(Location::test(2, 10), "Test".to_owned()),
+                None,
                 vec![
                     Variant::new(
                         Location::test(3, 9),
                         Identifier::new(Location::test(3, 9), "A".to_owned()),
-                        IntegerLiteral::new(
+                        Some(IntegerLiteral::new(
                             Location::test(3, 13),
                             LexicalIntegerLiteral::new_decimal("1".to_owned()),
-                        ),
+                        )),
+                        None,
                     ),
                     Variant::new(
                         Location::test(4, 9),
                         Identifier::new(Location::test(4, 9), "B".to_owned()),
-                        IntegerLiteral::new(
+                        Some(IntegerLiteral::new(
                             Location::test(4, 13),
                             LexicalIntegerLiteral::new_decimal("2".to_owned()),
-                        ),
+                        )),
+                        None,
                     ),
                     Variant::new(
                         Location::test(5, 9),
                         Identifier::new(Location::test(5, 9), "C".to_owned()),
-                        IntegerLiteral::new(
+                        Some(IntegerLiteral::new(
                             Location::test(5, 13),
                             LexicalIntegerLiteral::new_decimal("3".to_owned()),
-                        ),
+                        )),
+                        None,
+                    ),
+                ],
+            ),
+            None,
+        ));
+
+        let result = Parser::default().parse(TokenStream::test(input).wrap(), None);
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn ok_generic_with_payload() {
+        let input = r#"
+    enum Test<T> {
+        None,
+        Some(T),
+    }
+"#;
+
+        let expected = Ok((
+            EnumStatement::new(
+                Location::test(2, 5),
+                Identifier::new(Location::test(2, 10), "Test".to_owned()),
+                Some(vec![GenericParameter::new(
+                    Location::test(2, 15),
+                    Identifier::new(Location::test(2, 15), "T".to_owned()),
+                    vec![],
+                )]),
+                vec![
+                    Variant::new(
+                        Location::test(3, 9),
+                        Identifier::new(Location::test(3, 9), "None".to_owned()),
+                        None,
+                        None,
+                    ),
+                    Variant::new(
+                        Location::test(4, 9),
+                        Identifier::new(Location::test(4, 9), "Some".to_owned()),
+                        None,
+                        Some(VariantPayload::new_tuple(vec![Type::new(
+                            Location::test(4, 14),
+                            TypeVariant::alias(
+                                ExpressionTree::new(
+                                    Location::test(4, 14),
+                                    ExpressionTreeNode::operand(ExpressionOperand::Identifier(
+                                        Identifier::new(Location::test(4, 14), "T".to_owned()),
+                                    )),
+                                ),
+                                None,
+                            ),
+                        )])),
                     ),
                 ],
             ),
@@ -15,6 +15,7 @@ use crate::error::Error as SyntaxError;
 use crate::error::ParsingError;
 use crate::parser::binding_list::Parser as BindingListParser;
 use crate::parser::expression::terminal::block::Parser as BlockExpressionParser;
+use crate::parser::generic_list::Parser as GenericListParser;
 use crate::parser::r#type::Parser as TypeParser;
 use crate::tree::identifier::Identifier;
 use crate::tree::statement::r#fn::builder::Builder as FnStatementBuilder;
@@ -36,6 +37,8 @@ pub enum State {
     /// The `fn` has been parsed so far.
     Identifier,
     /// The `fn {identifier}` has been parsed so far.
+    GenericsOrParenthesisLeft,
+    /// The `fn {identifier}` with optional `<{generics}>` has been parsed so far.
     ParenthesisLeft,
     /// The `fn {identifier} (` has been parsed so far.
     ArgumentBindingList,
@@ -46,6 +49,7 @@ pub enum State {
     /// The `fn {identifier} ( {arguments} ) ->` has been parsed so far.
     ReturnType,
     /// The `fn {identifier} ( {arguments} )` with optional `-> {type}` has been parsed so far.
+    /// Expects `;` instead of the body if the prototype is allowed.
     Body,
 }
 
@@ -66,9 +70,21 @@ pub struct Parser {
     builder: FnStatementBuilder,
     /// The token returned from a subparser.
     next: Option<Token>,
+    /// Whether the body may be replaced with `;`, which is allowed for trait method prototypes.
+    is_prototype_allowed: bool,
 }
 
 impl Parser {
+    ///
+    /// Creates a parser which also accepts the function prototype without a body.
+    ///
+    pub fn new_prototype() -> Self {
+        Self {
+            is_prototype_allowed: true,
+            ..Self::default()
+        }
+    }
+
     ///
     /// Parses an 'fn' statement.
     ///
@@ -78,6 +94,12 @@ impl Parser {
     /// }
     /// '
     ///
+    /// If the prototype is allowed, the body may be omitted:
+    ///
+    /// '
+    /// fn sum(a: u8, b: u8) -> u8;
+    /// '
+    ///
     pub fn parse(
         mut self,
         stream: Rc<RefCell<TokenStream>>,
@@ -114,7 +136,7 @@ impl Parser {
                         } => {
                             let identifier = Identifier::new(location, identifier.inner);
                             self.builder.set_identifier(identifier);
-                            self.state = State::ParenthesisLeft;
+                            self.state = State::GenericsOrParenthesisLeft;
                         }
                         Token { lexeme, location } => {
                             return Err(ParsingError::Syntax(SyntaxError::expected_identifier(
@@ -125,6 +147,26 @@ impl Parser {
                         }
                     }
                 }
+                State::GenericsOrParenthesisLeft => {
+                    match crate::parser::take_or_next(self.next.take(), stream.clone())? {
+                        token
+                        @
+                        Token {
+                            lexeme: Lexeme::Symbol(Symbol::Lesser),
+                            ..
+                        } => {
+                            let (generics, next) =
+                                GenericListParser::default().parse(stream.clone(), Some(token))?;
+                            self.builder.set_generics(generics);
+                            self.next = next;
+                            self.state = State::ParenthesisLeft;
+                        }
+                        token => {
+                            self.next = Some(token);
+                            self.state = State::ParenthesisLeft;
+                        }
+                    }
+                }
                 State::ParenthesisLeft => {
                     match crate::parser::take_or_next(self.next.take(), stream.clone())? {
                         Token {
@@ -184,6 +226,16 @@ impl Parser {
                     self.state = State::Body;
                 }
                 State::Body => {
+                    if self.is_prototype_allowed {
+                        match crate::parser::take_or_next(self.next.take(), stream.clone())? {
+                            Token {
+                                lexeme: Lexeme::Symbol(Symbol::Semicolon),
+                                ..
+                            } => return Ok((self.builder, None)),
+                            token => self.next = Some(token),
+                        }
+                    }
+
                     let (expression, next) =
                         BlockExpressionParser::default().parse(stream, self.next.take())?;
 
@@ -207,6 +259,10 @@ mod tests {
     use crate::error::ParsingError;
     use crate::tree::binding::Binding;
     use crate::tree::expression::block::Expression as BlockExpression;
+    use crate::tree::expression::tree::node::operand::Operand as ExpressionOperand;
+    use crate::tree::expression::tree::node::Node as ExpressionTreeNode;
+    use crate::tree::expression::tree::Tree as ExpressionTree;
+    use crate::tree::generic_parameter::GenericParameter;
     use crate::tree::identifier::Identifier;
     use crate::tree::pattern_binding::variant::Variant as BindingPatternVariant;
     use crate::tree::pattern_binding::Pattern as BindingPattern;
@@ -224,6 +280,7 @@ mod tests {
                 false,
                 false,
                 Identifier::new(Location::test(1, 4), "f".to_owned()),
+                None,
                 vec![Binding::new(
                     Location::test(1, 6),
                     BindingPattern::new(
@@ -259,6 +316,7 @@ mod tests {
                 false,
                 false,
                 Identifier::new(Location::test(1, 4), "f".to_owned()),
+                None,
                 vec![Binding::new(
                     Location::test(1, 6),
                     BindingPattern::new(
@@ -284,6 +342,68 @@ mod tests {
         assert_eq!(result, expected);
     }
 
+    #[test]
+    fn ok_generic() {
+        let input = r#"fn f<T>(a: T) -> T {}"#;
+
+        let expected = Ok((
+            FnStatement::new(
+                Location::test(1, 1),
+                false,
+                false,
+                Identifier::new(Location::test(1, 4), "f".to_owned()),
+                Some(vec![GenericParameter::new(
+                    Location::test(1, 6),
+                    Identifier::new(Location::test(1, 6), "T".to_owned()),
+                    vec![],
+                )]),
+                vec![Binding::new(
+                    Location::test(1, 9),
+                    BindingPattern::new(
+                        Location::test(1, 9),
+                        BindingPatternVariant::new_binding(
+                            Identifier::new(Location::test(1, 9), "a".to_owned()),
+                            false,
+                        ),
+                    ),
+                    Some(Type::new(
+                        Location::test(1, 12),
+                        TypeVariant::alias(
+                            ExpressionTree::new(
+                                Location::test(1, 12),
+                                ExpressionTreeNode::operand(ExpressionOperand::Identifier(
+                                    Identifier::new(Location::test(1, 12), "T".to_owned()),
+                                )),
+                            ),
+                            None,
+                        ),
+                    )),
+                )],
+                Some(Type::new(
+                    Location::test(1, 18),
+                    TypeVariant::alias(
+                        ExpressionTree::new(
+                            Location::test(1, 18),
+                            ExpressionTreeNode::operand(ExpressionOperand::Identifier(
+                                Identifier::new(Location::test(1, 18), "T".to_owned()),
+                            )),
+                        ),
+                        None,
+                    ),
+                )),
+                BlockExpression::new(Location::test(1, 20), vec![], None),
+                vec![],
+            ),
+            None,
+        ));
+
+        let result = Parser::default()
+            .parse(TokenStream::test(input).wrap(), None)
+            .map(|(builder, next)| (builder.finish(), next));
+
+        assert_eq!(result, expected);
+    }
+
     #[test]
     fn error_expected_identifier() {
         let input = r#"fn (a: u8) -> field {}"#;
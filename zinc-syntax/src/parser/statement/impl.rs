@@ -13,6 +13,7 @@ use zinc_lexical::TokenStream;
 
 use crate::error::Error as SyntaxError;
 use crate::error::ParsingError;
+use crate::parser::recovery::Boundary;
 use crate::parser::statement::local_impl::Parser as ImplementationLocalStatementParser;
 use crate::tree::identifier::Identifier;
 use crate::tree::statement::r#impl::builder::Builder as ImplStatementBuilder;
@@ -21,6 +22,9 @@ use crate::tree::statement::r#impl::Statement as ImplStatement;
 /// The missing identifier error hint.
 pub static HINT_EXPECTED_IDENTIFIER: &str =
     "type implementation must have an identifier, e.g. `impl Data { ... }`";
+/// The missing type identifier error hint.
+pub static HINT_EXPECTED_TYPE_IDENTIFIER: &str =
+    "trait implementation must have a type identifier, e.g. `impl Shape for Square { ... }`";
 
 ///
 /// The parser state.
@@ -32,6 +36,10 @@ pub enum State {
     /// The `impl` has been parsed so far.
     Identifier,
     /// The `impl {identifier}` has been parsed so far.
+    KeywordForOrBracketCurlyLeft,
+    /// The `impl {trait} for` has been parsed so far.
+    TypeIdentifier,
+    /// The `impl {identifier}` or `impl {trait} for {identifier}` has been parsed so far.
     BracketCurlyLeft,
     /// The `impl {identifier} {` has been parsed so far.
     StatementOrBracketCurlyRight,
@@ -52,6 +60,8 @@ pub struct Parser {
     state: State,
     /// The builder of the parsed value.
     builder: ImplStatementBuilder,
+    /// The first identifier, which is either the type or trait one.
+    identifier: Option<Identifier>,
     /// The token returned from a subparser.
     next: Option<Token>,
 }
@@ -68,6 +78,14 @@ impl Parser {
     /// }
     /// '
     ///
+    /// '
+    /// impl Shape for Square {
+    ///     fn area(self) -> u64 {
+    ///         self.side * self.side
+    ///     }
+    /// }
+    /// '
+    ///
     pub fn parse(
         mut self,
         stream: Rc<RefCell<TokenStream>>,
@@ -97,6 +115,46 @@ impl Parser {
                     }
                 }
                 State::Identifier => {
+                    match crate::parser::take_or_next(self.next.take(), stream.clone())? {
+                        Token {
+                            lexeme: Lexeme::Identifier(identifier),
+                            location,
+                        } => {
+                            let identifier = Identifier::new(location, identifier.inner);
+                            self.identifier = Some(identifier);
+                            self.state = State::KeywordForOrBracketCurlyLeft;
+                        }
+                        Token { lexeme, location } => {
+                            return Err(ParsingError::Syntax(SyntaxError::expected_identifier(
+                                location,
+                                lexeme,
+                                Some(HINT_EXPECTED_IDENTIFIER),
+                            )));
+                        }
+                    }
+                }
+                State::KeywordForOrBracketCurlyLeft => {
+                    let identifier = self
+                        .identifier
+                        .take()
+                        .expect(zinc_const::panic::VALUE_ALWAYS_EXISTS);
+
+                    match crate::parser::take_or_next(self.next.take(), stream.clone())? {
+                        Token {
+                            lexeme: Lexeme::Keyword(Keyword::For),
+                            ..
+                        } => {
+                            self.builder.set_trait(identifier);
+                            self.state = State::TypeIdentifier;
+                        }
+                        token => {
+                            self.builder.set_identifier(identifier);
+                            self.next = Some(token);
+                            self.state = State::BracketCurlyLeft;
+                        }
+                    }
+                }
+                State::TypeIdentifier => {
                     match crate::parser::take_or_next(self.next.take(), stream.clone())? {
                         Token {
                             lexeme: Lexeme::Identifier(identifier),
@@ -110,7 +168,7 @@ impl Parser {
                             return Err(ParsingError::Syntax(SyntaxError::expected_identifier(
                                 location,
                                 lexeme,
-                                Some(HINT_EXPECTED_IDENTIFIER),
+                                Some(HINT_EXPECTED_TYPE_IDENTIFIER),
                             )));
                         }
                     }
@@ -133,10 +191,21 @@ impl Parser {
                             ..
                         } => return Ok((self.builder.finish(), None)),
                         token => {
-                            let (statement, next) = ImplementationLocalStatementParser::default()
-                                .parse(stream.clone(), Some(token))?;
-                            self.next = next;
-                            self.builder.push_statement(statement);
+                            match ImplementationLocalStatementParser::default()
+                                .parse(stream.clone(), Some(token))
+                            {
+                                Ok((statement, next)) => {
+                                    self.next = next;
+                                    self.builder.push_statement(statement);
+                                }
+                                Err(error) => {
+                                    self.next = crate::parser::recovery::recover(
+                                        stream.clone(),
+                                        error,
+                                        Boundary::Item,
+                                    )?;
+                                }
+                            }
                         }
                     }
                 }
@@ -182,6 +251,28 @@ mod tests {
             ImplStatement::new(
                 Location::test(2, 5),
                 Identifier::new(Location::test(2, 10), "Test".to_owned()),
+                None,
+                vec![],
+            ),
+            None,
+        ));
+
+        let result = Parser::default().parse(TokenStream::test(input).wrap(), None);
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn ok_trait() {
+        let input = r#"
+    impl Shape for Test {}
+"#;
+
+        let expected = Ok((
+            ImplStatement::new(
+                Location::test(2, 5),
+                Identifier::new(Location::test(2, 20), "Test".to_owned()),
+                Some(Identifier::new(Location::test(2, 10), "Shape".to_owned())),
                 vec![],
             ),
             None,
@@ -204,6 +295,7 @@ mod tests {
             ImplStatement::new(
                 Location::test(2, 5),
                 Identifier::new(Location::test(2, 10), "Test".to_owned()),
+                None,
                 vec![ImplementationLocalStatement::Const(ConstStatement::new(
                     Location::test(3, 9),
                     Identifier::new(Location::test(3, 15), "VALUE".to_owned()),
@@ -243,6 +335,7 @@ mod tests {
             ImplStatement::new(
                 Location::test(2, 5),
                 Identifier::new(Location::test(2, 10), "Test".to_owned()),
+                None,
                 vec![
                     ImplementationLocalStatement::Const(ConstStatement::new(
                         Location::test(3, 9),
@@ -308,11 +401,13 @@ mod tests {
             ImplStatement::new(
                 Location::test(2, 5),
                 Identifier::new(Location::test(2, 10), "Test".to_owned()),
+                None,
                 vec![ImplementationLocalStatement::Fn(FnStatement::new(
                     Location::test(3, 9),
                     false,
                     false,
                     Identifier::new(Location::test(3, 12), "f".to_owned()),
+                    None,
                     vec![Binding::new(
                         Location::test(3, 14),
                         BindingPattern::new(
@@ -353,12 +448,14 @@ mod tests {
             ImplStatement::new(
                 Location::test(2, 5),
                 Identifier::new(Location::test(2, 10), "Test".to_owned()),
+                None,
                 vec![
                     ImplementationLocalStatement::Fn(FnStatement::new(
                         Location::test(3, 9),
                         false,
                         false,
                         Identifier::new(Location::test(3, 12), "f1".to_owned()),
+                        None,
                         vec![Binding::new(
                             Location::test(3, 15),
                             BindingPattern::new(
@@ -379,6 +476,7 @@ mod tests {
                         false,
                         false,
                         Identifier::new(Location::test(5, 12), "f2".to_owned()),
+                        None,
                         vec![Binding::new(
                             Location::test(5, 15),
                             BindingPattern::new(
@@ -399,6 +497,7 @@ mod tests {
                         false,
                         false,
                         Identifier::new(Location::test(7, 12), "f3".to_owned()),
+                        None,
                         vec![Binding::new(
                             Location::test(7, 15),
                             BindingPattern::new(
@@ -438,6 +537,7 @@ mod tests {
             ImplStatement::new(
                 Location::test(2, 5),
                 Identifier::new(Location::test(2, 10), "Test".to_owned()),
+                None,
                 vec![
                     ImplementationLocalStatement::Const(ConstStatement::new(
                         Location::test(3, 9),
@@ -458,6 +558,7 @@ mod tests {
                         false,
                         false,
                         Identifier::new(Location::test(5, 12), "f".to_owned()),
+                        None,
                         vec![Binding::new(
                             Location::test(5, 14),
                             BindingPattern::new(
@@ -505,6 +606,7 @@ mod tests {
             ImplStatement::new(
                 Location::test(2, 5),
                 Identifier::new(Location::test(2, 10), "Test".to_owned()),
+                None,
                 vec![
                     ImplementationLocalStatement::Const(ConstStatement::new(
                         Location::test(3, 9),
@@ -553,6 +655,7 @@ mod tests {
                         false,
                         false,
                         Identifier::new(Location::test(9, 12), "f1".to_owned()),
+                        None,
                         vec![Binding::new(
                             Location::test(9, 15),
                             BindingPattern::new(
@@ -573,6 +676,7 @@ mod tests {
                         false,
                         false,
                         Identifier::new(Location::test(11, 12), "f2".to_owned()),
+                        None,
                         vec![Binding::new(
                             Location::test(11, 15),
                             BindingPattern::new(
@@ -593,6 +697,7 @@ mod tests {
                         false,
                         false,
                         Identifier::new(Location::test(13, 12), "f3".to_owned()),
+                        None,
                         vec![Binding::new(
                             Location::test(13, 15),
                             BindingPattern::new(
@@ -632,4 +737,19 @@ mod tests {
 
         assert_eq!(result, expected);
     }
+
+    #[test]
+    fn error_expected_type_identifier() {
+        let input = r#"impl Shape for { const VALUE: u64 = 42; }"#;
+
+        let expected = Err(ParsingError::Syntax(SyntaxError::expected_identifier(
+            Location::test(1, 16),
+            Lexeme::Symbol(Symbol::BracketCurlyLeft),
+            Some(super::HINT_EXPECTED_TYPE_IDENTIFIER),
+        )));
+
+        let result = Parser::default().parse(TokenStream::test(input).wrap(), None);
+
+        assert_eq!(result, expected);
+    }
 }
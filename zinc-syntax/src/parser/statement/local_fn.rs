@@ -13,9 +13,13 @@ use zinc_lexical::TokenStream;
 
 use crate::error::ParsingError;
 use crate::parser::expression::Parser as ExpressionParser;
+use crate::parser::statement::r#break::Parser as BreakStatementParser;
 use crate::parser::statement::r#const::Parser as ConstStatementParser;
+use crate::parser::statement::r#continue::Parser as ContinueStatementParser;
 use crate::parser::statement::r#for::Parser as ForStatementParser;
 use crate::parser::statement::r#let::Parser as LetStatementParser;
+use crate::parser::statement::r#return::Parser as ReturnStatementParser;
+use crate::parser::statement::r#while::Parser as WhileStatementParser;
 use crate::tree::statement::local_fn::Statement as FunctionLocalStatement;
 
 ///
@@ -72,6 +76,50 @@ impl Parser {
                 self.next = next;
                 FunctionLocalStatement::For(statement)
             }
+            token
+            @
+            Token {
+                lexeme: Lexeme::Keyword(Keyword::While),
+                ..
+            } => {
+                let (statement, next) =
+                    WhileStatementParser::default().parse(stream.clone(), Some(token))?;
+                self.next = next;
+                FunctionLocalStatement::While(statement)
+            }
+            token
+            @
+            Token {
+                lexeme: Lexeme::Keyword(Keyword::Return),
+                ..
+            } => {
+                let (statement, next) =
+                    ReturnStatementParser::default().parse(stream.clone(), Some(token))?;
+                self.next = next;
+                FunctionLocalStatement::Return(statement)
+            }
+            token
+            @
+            Token {
+                lexeme: Lexeme::Keyword(Keyword::Break),
+                ..
+            } => {
+                let (statement, next) =
+                    BreakStatementParser::default().parse(stream.clone(), Some(token))?;
+                self.next = next;
+                FunctionLocalStatement::Break(statement)
+            }
+            token
+            @
+            Token {
+                lexeme: Lexeme::Keyword(Keyword::Continue),
+                ..
+            } => {
+                let (statement, next) =
+                    ContinueStatementParser::default().parse(stream.clone(), Some(token))?;
+                self.next = next;
+                FunctionLocalStatement::Continue(statement)
+            }
             Token {
                 lexeme: Lexeme::Symbol(Symbol::Semicolon),
                 location,
@@ -93,7 +141,7 @@ impl Parser {
                     token => Ok((statement, Some(token), true)),
                 }
             }
-            statement => Ok((statement, None, false)),
+            statement => Ok((statement, self.next.take(), false)),
         }
     }
 }
@@ -211,6 +211,7 @@ mod tests {
                 true,
                 false,
                 Identifier::new(Location::test(1, 8), "f".to_owned()),
+                None,
                 vec![Binding::new(
                     Location::test(1, 10),
                     BindingPattern::new(
@@ -244,6 +245,7 @@ mod tests {
                 false,
                 true,
                 Identifier::new(Location::test(1, 10), "f".to_owned()),
+                None,
                 vec![Binding::new(
                     Location::test(1, 12),
                     BindingPattern::new(
@@ -277,6 +279,7 @@ mod tests {
                 true,
                 true,
                 Identifier::new(Location::test(1, 14), "f".to_owned()),
+                None,
                 vec![Binding::new(
                     Location::test(1, 16),
                     BindingPattern::new(
@@ -313,6 +316,7 @@ fn test() {}
                 false,
                 false,
                 Identifier::new(Location::test(3, 4), "test".to_owned()),
+                None,
                 vec![],
                 None,
                 BlockExpression::new(Location::test(3, 11), vec![], None),
@@ -354,6 +358,7 @@ fn test() {}
                 false,
                 false,
                 Identifier::new(Location::test(5, 4), "test".to_owned()),
+                None,
                 vec![],
                 None,
                 BlockExpression::new(Location::test(5, 11), vec![], None),
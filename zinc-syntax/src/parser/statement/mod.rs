@@ -2,7 +2,9 @@
 //! The statement parser.
 //!
 
+pub mod r#break;
 pub mod r#const;
+pub mod r#continue;
 pub mod contract;
 pub mod r#enum;
 pub mod field;
@@ -15,6 +17,9 @@ pub mod local_fn;
 pub mod local_impl;
 pub mod local_mod;
 pub mod module;
+pub mod r#return;
 pub mod r#struct;
+pub mod r#trait;
 pub mod r#type;
 pub mod r#use;
+pub mod r#while;
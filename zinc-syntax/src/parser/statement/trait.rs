@@ -0,0 +1,321 @@
+//!
+//! The `trait` statement parser.
+//!
+
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use zinc_lexical::Keyword;
+use zinc_lexical::Lexeme;
+use zinc_lexical::Symbol;
+use zinc_lexical::Token;
+use zinc_lexical::TokenStream;
+
+use crate::error::Error as SyntaxError;
+use crate::error::ParsingError;
+use crate::parser::recovery::Boundary;
+use crate::parser::statement::r#fn::Parser as FnStatementParser;
+use crate::tree::identifier::Identifier;
+use crate::tree::statement::r#trait::builder::Builder as TraitStatementBuilder;
+use crate::tree::statement::r#trait::Statement as TraitStatement;
+
+/// The missing identifier error hint.
+pub static HINT_EXPECTED_IDENTIFIER: &str =
+    "trait must have an identifier, e.g. `trait Shape { ... }`";
+/// The invalid statement error hint.
+pub static HINT_ONLY_METHOD_PROTOTYPES: &str =
+    "only method prototypes may be declared within a trait, e.g. `fn area(self) -> u8;`";
+
+///
+/// The parser state.
+///
+#[derive(Debug, Clone, Copy)]
+pub enum State {
+    /// The initial state.
+    KeywordTrait,
+    /// The `trait` has been parsed so far.
+    Identifier,
+    /// The `trait {identifier}` has been parsed so far.
+    BracketCurlyLeft,
+    /// The `trait {identifier} {` has been parsed so far.
+    MethodOrBracketCurlyRight,
+}
+
+impl Default for State {
+    fn default() -> Self {
+        Self::KeywordTrait
+    }
+}
+
+///
+/// The `trait` statement parser.
+///
+#[derive(Default)]
+pub struct Parser {
+    /// The parser state.
+    state: State,
+    /// The builder of the parsed value.
+    builder: TraitStatementBuilder,
+    /// The token returned from a subparser.
+    next: Option<Token>,
+}
+
+impl Parser {
+    ///
+    /// Parses a 'trait' statement.
+    ///
+    /// '
+    /// trait Shape {
+    ///     fn area(self) -> u64;
+    ///     fn scale(mut self, factor: u8);
+    /// }
+    /// '
+    ///
+    pub fn parse(
+        mut self,
+        stream: Rc<RefCell<TokenStream>>,
+        initial: Option<Token>,
+    ) -> Result<(TraitStatement, Option<Token>), ParsingError> {
+        self.next = initial;
+
+        loop {
+            match self.state {
+                State::KeywordTrait => {
+                    match crate::parser::take_or_next(self.next.take(), stream.clone())? {
+                        Token {
+                            lexeme: Lexeme::Keyword(Keyword::Trait),
+                            location,
+                        } => {
+                            self.builder.set_location(location);
+                            self.state = State::Identifier;
+                        }
+                        Token { lexeme, location } => {
+                            return Err(ParsingError::Syntax(SyntaxError::expected_one_of(
+                                location,
+                                vec!["trait"],
+                                lexeme,
+                                None,
+                            )));
+                        }
+                    }
+                }
+                State::Identifier => {
+                    match crate::parser::take_or_next(self.next.take(), stream.clone())? {
+                        Token {
+                            lexeme: Lexeme::Identifier(identifier),
+                            location,
+                        } => {
+                            let identifier = Identifier::new(location, identifier.inner);
+                            self.builder.set_identifier(identifier);
+                            self.state = State::BracketCurlyLeft;
+                        }
+                        Token { lexeme, location } => {
+                            return Err(ParsingError::Syntax(SyntaxError::expected_identifier(
+                                location,
+                                lexeme,
+                                Some(HINT_EXPECTED_IDENTIFIER),
+                            )));
+                        }
+                    }
+                }
+                State::BracketCurlyLeft => {
+                    match crate::parser::take_or_next(self.next.take(), stream.clone())? {
+                        Token {
+                            lexeme: Lexeme::Symbol(Symbol::BracketCurlyLeft),
+                            ..
+                        } => {
+                            self.state = State::MethodOrBracketCurlyRight;
+                        }
+                        Token { lexeme, location } => {
+                            return Err(ParsingError::Syntax(SyntaxError::expected_one_of(
+                                location,
+                                vec!["{"],
+                                lexeme,
+                                None,
+                            )));
+                        }
+                    }
+                }
+                State::MethodOrBracketCurlyRight => {
+                    match crate::parser::take_or_next(self.next.take(), stream.clone())? {
+                        Token {
+                            lexeme: Lexeme::Symbol(Symbol::BracketCurlyRight),
+                            ..
+                        } => return Ok((self.builder.finish(), None)),
+                        Token {
+                            lexeme: Lexeme::Symbol(Symbol::Semicolon),
+                            ..
+                        } => {}
+                        token @ Token {
+                            lexeme: Lexeme::Keyword(Keyword::Fn),
+                            ..
+                        } => match FnStatementParser::new_prototype()
+                            .parse(stream.clone(), Some(token))
+                        {
+                            Ok((builder, next)) => {
+                                self.next = next;
+                                self.builder.push_method(builder.finish_prototype());
+                            }
+                            Err(error) => {
+                                self.next = crate::parser::recovery::recover(
+                                    stream.clone(),
+                                    error,
+                                    Boundary::Item,
+                                )?;
+                            }
+                        },
+                        Token { lexeme, location } => {
+                            self.next = crate::parser::recovery::recover(
+                                stream.clone(),
+                                ParsingError::Syntax(SyntaxError::expected_one_of(
+                                    location,
+                                    vec!["fn", "}"],
+                                    lexeme,
+                                    Some(HINT_ONLY_METHOD_PROTOTYPES),
+                                )),
+                                Boundary::Item,
+                            )?;
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use zinc_lexical::Keyword;
+    use zinc_lexical::Lexeme;
+    use zinc_lexical::Location;
+    use zinc_lexical::TokenStream;
+
+    use super::Parser;
+    use crate::error::Error as SyntaxError;
+    use crate::error::ParsingError;
+    use crate::tree::binding::Binding;
+    use crate::tree::identifier::Identifier;
+    use crate::tree::pattern_binding::variant::Variant as BindingPatternVariant;
+    use crate::tree::pattern_binding::Pattern as BindingPattern;
+    use crate::tree::r#type::variant::Variant as TypeVariant;
+    use crate::tree::r#type::Type;
+    use crate::tree::statement::r#fn::prototype::Prototype as FnPrototype;
+    use crate::tree::statement::r#trait::Statement as TraitStatement;
+
+    #[test]
+    fn ok_empty() {
+        let input = r#"
+    trait Shape {}
+"#;
+
+        let expected = Ok((
+            TraitStatement::new(
+                Location::test(2, 5),
+                Identifier::new(Location::test(2, 11), "Shape".to_owned()),
+                vec![],
+            ),
+            None,
+        ));
+
+        let result = Parser::default().parse(TokenStream::test(input).wrap(), None);
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn ok_multiple_methods() {
+        let input = r#"
+    trait Shape {
+        fn area(self) -> u64;
+        fn scale(mut self, factor: u8);
+    }
+"#;
+
+        let expected = Ok((
+            TraitStatement::new(
+                Location::test(2, 5),
+                Identifier::new(Location::test(2, 11), "Shape".to_owned()),
+                vec![
+                    FnPrototype::new(
+                        Location::test(3, 9),
+                        Identifier::new(Location::test(3, 12), "area".to_owned()),
+                        None,
+                        vec![Binding::new(
+                            Location::test(3, 17),
+                            BindingPattern::new(
+                                Location::test(3, 17),
+                                BindingPatternVariant::new_binding(
+                                    Identifier::new(Location::test(3, 17), "self".to_owned()),
+                                    false,
+                                ),
+                            ),
+                            None,
+                        )],
+                        Some(Type::new(
+                            Location::test(3, 26),
+                            TypeVariant::integer_unsigned(64),
+                        )),
+                    ),
+                    FnPrototype::new(
+                        Location::test(4, 9),
+                        Identifier::new(Location::test(4, 12), "scale".to_owned()),
+                        None,
+                        vec![
+                            Binding::new(
+                                Location::test(4, 18),
+                                BindingPattern::new(
+                                    Location::test(4, 18),
+                                    BindingPatternVariant::new_binding(
+                                        Identifier::new(Location::test(4, 22), "self".to_owned()),
+                                        true,
+                                    ),
+                                ),
+                                None,
+                            ),
+                            Binding::new(
+                                Location::test(4, 28),
+                                BindingPattern::new(
+                                    Location::test(4, 28),
+                                    BindingPatternVariant::new_binding(
+                                        Identifier::new(Location::test(4, 28), "factor".to_owned()),
+                                        false,
+                                    ),
+                                ),
+                                Some(Type::new(
+                                    Location::test(4, 36),
+                                    TypeVariant::integer_unsigned(8),
+                                )),
+                            ),
+                        ],
+                        None,
+                    ),
+                ],
+            ),
+            None,
+        ));
+
+        let result = Parser::default().parse(TokenStream::test(input).wrap(), None);
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn error_expected_method_prototype() {
+        let input = r#"
+    trait Shape {
+        const AREA: u64 = 0;
+    }
+"#;
+
+        let expected = Err(ParsingError::Syntax(SyntaxError::expected_one_of(
+            Location::test(3, 9),
+            vec!["fn", "}"],
+            Lexeme::Keyword(Keyword::Const),
+            Some(super::HINT_ONLY_METHOD_PROTOTYPES),
+        )));
+
+        let result = Parser::default().parse(TokenStream::test(input).wrap(), None);
+
+        assert_eq!(result, expected);
+    }
+}
@@ -0,0 +1,250 @@
+//!
+//! The `while` statement parser.
+//!
+
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use zinc_lexical::Keyword;
+use zinc_lexical::Lexeme;
+use zinc_lexical::Token;
+use zinc_lexical::TokenStream;
+
+use crate::error::Error as SyntaxError;
+use crate::error::ParsingError;
+use crate::parser::expression::terminal::block::Parser as BlockExpressionParser;
+use crate::parser::expression::Parser as ExpressionParser;
+use crate::tree::statement::r#while::builder::Builder as WhileStatementBuilder;
+use crate::tree::statement::r#while::Statement as WhileStatement;
+
+/// The missing iterations bound error hint.
+pub static HINT_EXPECTED_BOUND: &str =
+    "while-loops must have the iterations bound, e.g. `while i < n bound 10 { ... }`";
+
+///
+/// The parser state.
+///
+#[derive(Debug, Clone, Copy)]
+pub enum State {
+    /// The initial state.
+    KeywordWhile,
+    /// The `while` has been parsed so far.
+    ConditionExpression,
+    /// The `while {expression}` has been parsed so far.
+    KeywordBound,
+    /// The `while {expression} bound` has been parsed so far.
+    BoundExpression,
+    /// The `while {expression} bound {expression}` has been parsed so far.
+    BlockExpression,
+}
+
+impl Default for State {
+    fn default() -> Self {
+        Self::KeywordWhile
+    }
+}
+
+///
+/// The `while` statement parser.
+///
+#[derive(Default)]
+pub struct Parser {
+    /// The parser state.
+    state: State,
+    /// The builder of the parsed value.
+    builder: WhileStatementBuilder,
+    /// The token returned from a subparser.
+    next: Option<Token>,
+}
+
+impl Parser {
+    ///
+    /// Parses a while-loop statement.
+    ///
+    /// '
+    /// while low < high bound 8 {
+    ///     low += 1;
+    /// }
+    /// '
+    ///
+    pub fn parse(
+        mut self,
+        stream: Rc<RefCell<TokenStream>>,
+        initial: Option<Token>,
+    ) -> Result<(WhileStatement, Option<Token>), ParsingError> {
+        self.next = initial;
+
+        loop {
+            match self.state {
+                State::KeywordWhile => {
+                    match crate::parser::take_or_next(self.next.take(), stream.clone())? {
+                        Token {
+                            lexeme: Lexeme::Keyword(Keyword::While),
+                            location,
+                        } => {
+                            self.builder.set_location(location);
+                            self.state = State::ConditionExpression;
+                        }
+                        Token { lexeme, location } => {
+                            return Err(ParsingError::Syntax(SyntaxError::expected_one_of(
+                                location,
+                                vec!["while"],
+                                lexeme,
+                                None,
+                            )));
+                        }
+                    }
+                }
+                State::ConditionExpression => {
+                    let (expression, next) =
+                        ExpressionParser::default().parse(stream.clone(), self.next.take())?;
+                    self.next = next;
+                    self.builder.set_condition(expression);
+                    self.state = State::KeywordBound;
+                }
+                State::KeywordBound => {
+                    match crate::parser::take_or_next(self.next.take(), stream.clone())? {
+                        Token {
+                            lexeme: Lexeme::Keyword(Keyword::Bound),
+                            ..
+                        } => self.state = State::BoundExpression,
+                        Token { lexeme, location } => {
+                            return Err(ParsingError::Syntax(
+                                SyntaxError::expected_one_of_or_operator(
+                                    location,
+                                    vec!["bound"],
+                                    lexeme,
+                                    Some(HINT_EXPECTED_BOUND),
+                                ),
+                            ));
+                        }
+                    }
+                }
+                State::BoundExpression => {
+                    let (expression, next) =
+                        ExpressionParser::default().parse(stream.clone(), self.next.take())?;
+                    self.next = next;
+                    self.builder.set_bound(expression);
+                    self.state = State::BlockExpression;
+                }
+                State::BlockExpression => {
+                    let (expression, next) =
+                        BlockExpressionParser::default().parse(stream, self.next.take())?;
+                    self.builder.set_block(expression);
+                    return Ok((self.builder.finish(), next));
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use zinc_lexical::IntegerLiteral as LexicalIntegerLiteral;
+    use zinc_lexical::Lexeme;
+    use zinc_lexical::Location;
+    use zinc_lexical::Symbol;
+    use zinc_lexical::TokenStream;
+
+    use super::Parser;
+    use crate::error::Error as SyntaxError;
+    use crate::error::ParsingError;
+    use crate::tree::expression::block::Expression as BlockExpression;
+    use crate::tree::expression::tree::node::operand::Operand as ExpressionOperand;
+    use crate::tree::expression::tree::node::operator::Operator as ExpressionOperator;
+    use crate::tree::expression::tree::node::Node as ExpressionTreeNode;
+    use crate::tree::expression::tree::Tree as ExpressionTree;
+    use crate::tree::identifier::Identifier;
+    use crate::tree::literal::integer::Literal as IntegerLiteral;
+    use crate::tree::statement::r#while::Statement as WhileStatement;
+
+    #[test]
+    fn ok() {
+        let input = r#"while i < n bound 8 {}"#;
+
+        let expected = Ok((
+            WhileStatement::new(
+                Location::test(1, 1),
+                ExpressionTree::new_with_leaves(
+                    Location::test(1, 9),
+                    ExpressionTreeNode::operator(ExpressionOperator::Lesser),
+                    Some(ExpressionTree::new(
+                        Location::test(1, 7),
+                        ExpressionTreeNode::operand(ExpressionOperand::Identifier(
+                            Identifier::new(Location::test(1, 7), "i".to_owned()),
+                        )),
+                    )),
+                    Some(ExpressionTree::new(
+                        Location::test(1, 11),
+                        ExpressionTreeNode::operand(ExpressionOperand::Identifier(
+                            Identifier::new(Location::test(1, 11), "n".to_owned()),
+                        )),
+                    )),
+                ),
+                ExpressionTree::new(
+                    Location::test(1, 19),
+                    ExpressionTreeNode::operand(ExpressionOperand::LiteralInteger(
+                        IntegerLiteral::new(
+                            Location::test(1, 19),
+                            LexicalIntegerLiteral::new_decimal("8".to_owned()),
+                        ),
+                    )),
+                ),
+                BlockExpression::new(Location::test(1, 21), vec![], None),
+            ),
+            None,
+        ));
+
+        let result = Parser::default().parse(TokenStream::test(input).wrap(), None);
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn ok_constant_bound() {
+        let input = r#"while flag bound LIMIT {}"#;
+
+        let expected =
+            Ok((
+                WhileStatement::new(
+                    Location::test(1, 1),
+                    ExpressionTree::new(
+                        Location::test(1, 7),
+                        ExpressionTreeNode::operand(ExpressionOperand::Identifier(
+                            Identifier::new(Location::test(1, 7), "flag".to_owned()),
+                        )),
+                    ),
+                    ExpressionTree::new(
+                        Location::test(1, 18),
+                        ExpressionTreeNode::operand(ExpressionOperand::Identifier(
+                            Identifier::new(Location::test(1, 18), "LIMIT".to_owned()),
+                        )),
+                    ),
+                    BlockExpression::new(Location::test(1, 24), vec![], None),
+                ),
+                None,
+            ));
+
+        let result = Parser::default().parse(TokenStream::test(input).wrap(), None);
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn error_expected_keyword_bound() {
+        let input = r#"while i < n { i += 1; }"#;
+
+        let expected = Err(ParsingError::Syntax(
+            SyntaxError::expected_one_of_or_operator(
+                Location::test(1, 13),
+                vec!["bound"],
+                Lexeme::Symbol(Symbol::BracketCurlyLeft),
+                Some(super::HINT_EXPECTED_BOUND),
+            ),
+        ));
+
+        let result = Parser::default().parse(TokenStream::test(input).wrap(), None);
+
+        assert_eq!(result, expected);
+    }
+}
@@ -13,22 +13,51 @@ use zinc_lexical::TokenStream;
 
 use crate::error::Error as SyntaxError;
 use crate::error::ParsingError;
+use crate::parser::field_list::Parser as FieldListParser;
+use crate::parser::r#type::tuple::Parser as TupleTypeParser;
 use crate::tree::identifier::Identifier;
 use crate::tree::literal::integer::Literal as IntegerLiteral;
+use crate::tree::r#type::variant::Variant as TypeVariant;
 use crate::tree::variant::builder::Builder as VariantBuilder;
+use crate::tree::variant::payload::Payload as VariantPayload;
 use crate::tree::variant::Variant;
 
 /// The missing identifier error hint.
 pub static HINT_EXPECTED_IDENTIFIER: &str =
-    "enumeration variant must have an identifier, e.g. `Value = 42`";
-/// The missing value error hint.
-pub static HINT_EXPECTED_VALUE: &str = "enumeration variant must be initialized, e.g. `Value = 42`";
+    "enumeration variant must have an identifier, e.g. `Value = 42` or `Value(u8)`";
+
+///
+/// The parser state.
+///
+#[derive(Debug, Clone, Copy)]
+pub enum State {
+    /// The initial state.
+    Identifier,
+    /// The `{identifier}` has been parsed so far.
+    PayloadOrEqualsOrEnd,
+    /// The `{identifier} {` has been parsed so far.
+    FieldList,
+    /// The `{identifier} { {fields}` has been parsed so far.
+    BracketCurlyRight,
+    /// The `{identifier}` with an optional payload has been parsed so far.
+    EqualsOrEnd,
+    /// The `{identifier} =` has been parsed so far.
+    Literal,
+}
+
+impl Default for State {
+    fn default() -> Self {
+        Self::Identifier
+    }
+}
 
 ///
 /// The variant parser.
 ///
 #[derive(Default)]
 pub struct Parser {
+    /// The parser state.
+    state: State,
     /// The builder of the parsed value.
     builder: VariantBuilder,
     /// The token returned from a subparser.
@@ -40,6 +69,8 @@ impl Parser {
     /// Parses an enum variant.
     ///
     /// 'A = 1'
+    /// 'B(u8, field)'
+    /// 'C { a: u8, b: field }'
     ///
     pub fn parse(
         mut self,
@@ -48,51 +79,106 @@ impl Parser {
     ) -> Result<(Variant, Option<Token>), ParsingError> {
         self.next = initial;
 
-        match crate::parser::take_or_next(self.next.take(), stream.clone())? {
-            Token {
-                lexeme: Lexeme::Identifier(identifier),
-                location,
-            } => {
-                let identifier = Identifier::new(location, identifier.inner);
-                self.builder.set_location(location);
-                self.builder.set_identifier(identifier);
-            }
-            Token { lexeme, location } => {
-                return Err(ParsingError::Syntax(SyntaxError::expected_identifier(
-                    location,
-                    lexeme,
-                    Some(HINT_EXPECTED_IDENTIFIER),
-                )));
+        loop {
+            match self.state {
+                State::Identifier => {
+                    match crate::parser::take_or_next(self.next.take(), stream.clone())? {
+                        Token {
+                            lexeme: Lexeme::Identifier(identifier),
+                            location,
+                        } => {
+                            let identifier = Identifier::new(location, identifier.inner);
+                            self.builder.set_location(location);
+                            self.builder.set_identifier(identifier);
+                            self.state = State::PayloadOrEqualsOrEnd;
+                        }
+                        Token { lexeme, location } => {
+                            return Err(ParsingError::Syntax(SyntaxError::expected_identifier(
+                                location,
+                                lexeme,
+                                Some(HINT_EXPECTED_IDENTIFIER),
+                            )));
+                        }
+                    }
+                }
+                State::PayloadOrEqualsOrEnd => {
+                    match crate::parser::take_or_next(self.next.take(), stream.clone())? {
+                        token
+                        @
+                        Token {
+                            lexeme: Lexeme::Symbol(Symbol::ParenthesisLeft),
+                            ..
+                        } => {
+                            let (r#type, next) =
+                                TupleTypeParser::default().parse(stream.clone(), Some(token))?;
+                            let types = match r#type.variant {
+                                TypeVariant::Tuple { inners } => inners,
+                                _ => vec![],
+                            };
+                            self.builder.set_payload(VariantPayload::new_tuple(types));
+                            self.next = next;
+                            self.state = State::EqualsOrEnd;
+                        }
+                        Token {
+                            lexeme: Lexeme::Symbol(Symbol::BracketCurlyLeft),
+                            ..
+                        } => self.state = State::FieldList,
+                        token => {
+                            self.next = Some(token);
+                            self.state = State::EqualsOrEnd;
+                        }
+                    }
+                }
+                State::FieldList => {
+                    let (fields, next) =
+                        FieldListParser::default().parse(stream.clone(), self.next.take())?;
+                    self.builder
+                        .set_payload(VariantPayload::new_structure(fields));
+                    self.next = next;
+                    self.state = State::BracketCurlyRight;
+                }
+                State::BracketCurlyRight => {
+                    match crate::parser::take_or_next(self.next.take(), stream.clone())? {
+                        Token {
+                            lexeme: Lexeme::Symbol(Symbol::BracketCurlyRight),
+                            ..
+                        } => self.state = State::EqualsOrEnd,
+                        Token { lexeme, location } => {
+                            return Err(ParsingError::Syntax(SyntaxError::expected_one_of(
+                                location,
+                                vec!["}"],
+                                lexeme,
+                                None,
+                            )));
+                        }
+                    }
+                }
+                State::EqualsOrEnd => {
+                    match crate::parser::take_or_next(self.next.take(), stream.clone())? {
+                        Token {
+                            lexeme: Lexeme::Symbol(Symbol::Equals),
+                            ..
+                        } => self.state = State::Literal,
+                        token => return Ok((self.builder.finish(), Some(token))),
+                    }
+                }
+                State::Literal => {
+                    return match crate::parser::take_or_next(self.next.take(), stream)? {
+                        Token {
+                            lexeme: Lexeme::Literal(LexicalLiteral::Integer(literal)),
+                            location,
+                        } => {
+                            self.builder
+                                .set_literal(IntegerLiteral::new(location, literal));
+                            Ok((self.builder.finish(), self.next.take()))
+                        }
+                        Token { lexeme, location } => Err(ParsingError::Syntax(
+                            SyntaxError::expected_integer_literal(location, lexeme),
+                        )),
+                    };
+                }
             }
         }
-
-        match crate::parser::take_or_next(self.next.take(), stream.clone())? {
-            Token {
-                lexeme: Lexeme::Symbol(Symbol::Equals),
-                ..
-            } => {}
-            Token { lexeme, location } => {
-                return Err(ParsingError::Syntax(SyntaxError::expected_value(
-                    location,
-                    lexeme,
-                    Some(HINT_EXPECTED_VALUE),
-                )));
-            }
-        }
-
-        match crate::parser::take_or_next(self.next.take(), stream)? {
-            Token {
-                lexeme: Lexeme::Literal(LexicalLiteral::Integer(literal)),
-                location,
-            } => {
-                self.builder
-                    .set_literal(IntegerLiteral::new(location, literal));
-                Ok((self.builder.finish(), self.next.take()))
-            }
-            Token { lexeme, location } => Err(ParsingError::Syntax(
-                SyntaxError::expected_integer_literal(location, lexeme),
-            )),
-        }
     }
 }
 
@@ -102,13 +188,19 @@ mod tests {
     use zinc_lexical::IntegerLiteral as LexicalIntegerLiteral;
     use zinc_lexical::Lexeme;
     use zinc_lexical::Location;
+    use zinc_lexical::Symbol;
+    use zinc_lexical::Token;
     use zinc_lexical::TokenStream;
 
     use super::Parser;
     use crate::error::Error as SyntaxError;
     use crate::error::ParsingError;
+    use crate::tree::field::Field;
     use crate::tree::identifier::Identifier;
     use crate::tree::literal::integer::Literal as IntegerLiteral;
+    use crate::tree::r#type::variant::Variant as TypeVariant;
+    use crate::tree::r#type::Type;
+    use crate::tree::variant::payload::Payload as VariantPayload;
     use crate::tree::variant::Variant;
 
     #[test]
@@ -119,10 +211,11 @@ mod tests {
             Variant::new(
                 Location::test(1, 1),
                 Identifier::new(Location::test(1, 1), "A".to_owned()),
-                IntegerLiteral::new(
+                Some(IntegerLiteral::new(
                     Location::test(1, 5),
                     LexicalIntegerLiteral::new_decimal("1".to_owned()),
-                ),
+                )),
+                None,
             ),
             None,
         ));
@@ -133,13 +226,81 @@ mod tests {
     }
 
     #[test]
-    fn error_expected_value() {
+    fn ok_without_value() {
         let input = r#"A"#;
 
-        let expected = Err(ParsingError::Syntax(SyntaxError::expected_value(
-            Location::test(1, 2),
-            Lexeme::Eof,
-            Some(super::HINT_EXPECTED_VALUE),
+        let expected = Ok((
+            Variant::new(
+                Location::test(1, 1),
+                Identifier::new(Location::test(1, 1), "A".to_owned()),
+                None,
+                None,
+            ),
+            Some(Token::new(Lexeme::Eof, Location::test(1, 2))),
+        ));
+
+        let result = Parser::default().parse(TokenStream::test(input).wrap(), None);
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn ok_payload_tuple() {
+        let input = r#"A(u8, field)"#;
+
+        let expected = Ok((
+            Variant::new(
+                Location::test(1, 1),
+                Identifier::new(Location::test(1, 1), "A".to_owned()),
+                None,
+                Some(VariantPayload::new_tuple(vec![
+                    Type::new(Location::test(1, 3), TypeVariant::integer_unsigned(8)),
+                    Type::new(Location::test(1, 7), TypeVariant::field()),
+                ])),
+            ),
+            Some(Token::new(Lexeme::Eof, Location::test(1, 13))),
+        ));
+
+        let result = Parser::default().parse(TokenStream::test(input).wrap(), None);
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn ok_payload_structure() {
+        let input = r#"A { a: u8 } = 42"#;
+
+        let expected = Ok((
+            Variant::new(
+                Location::test(1, 1),
+                Identifier::new(Location::test(1, 1), "A".to_owned()),
+                Some(IntegerLiteral::new(
+                    Location::test(1, 15),
+                    LexicalIntegerLiteral::new_decimal("42".to_owned()),
+                )),
+                Some(VariantPayload::new_structure(vec![Field::new(
+                    Location::test(1, 5),
+                    Identifier::new(Location::test(1, 5), "a".to_owned()),
+                    Type::new(Location::test(1, 8), TypeVariant::integer_unsigned(8)),
+                )])),
+            ),
+            None,
+        ));
+
+        let result = Parser::default().parse(TokenStream::test(input).wrap(), None);
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn error_expected_bracket_curly_right() {
+        let input = r#"A { a: u8 )"#;
+
+        let expected = Err(ParsingError::Syntax(SyntaxError::expected_one_of(
+            Location::test(1, 11),
+            vec!["}"],
+            Lexeme::Symbol(Symbol::ParenthesisRight),
+            None,
         )));
 
         let result = Parser::default().parse(TokenStream::test(input).wrap(), None);
@@ -100,10 +100,11 @@ mod tests {
             vec![Variant::new(
                 Location::test(1, 1),
                 Identifier::new(Location::test(1, 1), "A".to_owned()),
-                IntegerLiteral::new(
+                Some(IntegerLiteral::new(
                     Location::test(1, 5),
                     LexicalIntegerLiteral::new_decimal("1".to_owned()),
-                ),
+                )),
+                None,
             )],
             Some(Token::new(Lexeme::Eof, Location::test(1, 6))),
         ));
@@ -121,10 +122,11 @@ mod tests {
             vec![Variant::new(
                 Location::test(1, 1),
                 Identifier::new(Location::test(1, 1), "A".to_owned()),
-                IntegerLiteral::new(
+                Some(IntegerLiteral::new(
                     Location::test(1, 5),
                     LexicalIntegerLiteral::new_decimal("1".to_owned()),
-                ),
+                )),
+                None,
             )],
             Some(Token::new(Lexeme::Eof, Location::test(1, 7))),
         ));
@@ -143,26 +145,29 @@ mod tests {
                 Variant::new(
                     Location::test(1, 1),
                     Identifier::new(Location::test(1, 1), "A".to_owned()),
-                    IntegerLiteral::new(
+                    Some(IntegerLiteral::new(
                         Location::test(1, 5),
                         LexicalIntegerLiteral::new_decimal("1".to_owned()),
-                    ),
+                    )),
+                    None,
                 ),
                 Variant::new(
                     Location::test(1, 8),
                     Identifier::new(Location::test(1, 8), "B".to_owned()),
-                    IntegerLiteral::new(
+                    Some(IntegerLiteral::new(
                         Location::test(1, 12),
                         LexicalIntegerLiteral::new_decimal("2".to_owned()),
-                    ),
+                    )),
+                    None,
                 ),
                 Variant::new(
                     Location::test(1, 15),
                     Identifier::new(Location::test(1, 15), "C".to_owned()),
-                    IntegerLiteral::new(
+                    Some(IntegerLiteral::new(
                         Location::test(1, 19),
                         LexicalIntegerLiteral::new_decimal("3".to_owned()),
-                    ),
+                    )),
+                    None,
                 ),
             ],
             Some(Token::new(Lexeme::Eof, Location::test(1, 20))),
@@ -0,0 +1,67 @@
+//!
+//! The closure expression builder.
+//!
+
+use zinc_lexical::Location;
+
+use crate::tree::binding::Binding;
+use crate::tree::expression::closure::Expression as ClosureExpression;
+use crate::tree::expression::tree::Tree as ExpressionTree;
+
+///
+/// The closure expression builder.
+///
+#[derive(Default)]
+pub struct Builder {
+    /// The location of the syntax construction.
+    location: Option<Location>,
+    /// The closure parameter bindings.
+    bindings: Vec<Binding>,
+    /// The closure body expression.
+    body: Option<ExpressionTree>,
+}
+
+impl Builder {
+    ///
+    /// Sets the corresponding builder value.
+    ///
+    pub fn set_location(&mut self, value: Location) {
+        self.location = Some(value);
+    }
+
+    ///
+    /// Sets the corresponding builder value.
+    ///
+    pub fn set_bindings(&mut self, value: Vec<Binding>) {
+        self.bindings = value;
+    }
+
+    ///
+    /// Sets the corresponding builder value.
+    ///
+    pub fn set_body(&mut self, value: ExpressionTree) {
+        self.body = Some(value);
+    }
+
+    ///
+    /// Finalizes the builder and returns the built value.
+    ///
+    /// # Panics
+    /// If some of the required items has not been set.
+    ///
+    pub fn finish(mut self) -> ClosureExpression {
+        ClosureExpression::new(
+            self.location.take().unwrap_or_else(|| {
+                panic!(
+                    "{}{}",
+                    zinc_const::panic::BUILDER_REQUIRES_VALUE,
+                    "location"
+                )
+            }),
+            self.bindings,
+            self.body.take().unwrap_or_else(|| {
+                panic!("{}{}", zinc_const::panic::BUILDER_REQUIRES_VALUE, "body")
+            }),
+        )
+    }
+}
@@ -0,0 +1,36 @@
+//!
+//! The closure expression.
+//!
+
+pub mod builder;
+
+use zinc_lexical::Location;
+
+use crate::tree::binding::Binding;
+use crate::tree::expression::tree::Tree as ExpressionTree;
+
+///
+/// The closure expression.
+///
+#[derive(Debug, Clone, PartialEq)]
+pub struct Expression {
+    /// The location of the syntax construction.
+    pub location: Location,
+    /// The closure parameter bindings.
+    pub bindings: Vec<Binding>,
+    /// The closure body expression.
+    pub body: Box<ExpressionTree>,
+}
+
+impl Expression {
+    ///
+    /// Creates a closure expression.
+    ///
+    pub fn new(location: Location, bindings: Vec<Binding>, body: ExpressionTree) -> Self {
+        Self {
+            location,
+            bindings,
+            body: Box::new(body),
+        }
+    }
+}
@@ -8,6 +8,7 @@ use zinc_lexical::Location;
 
 use crate::tree::expression::array::Expression as ArrayExpression;
 use crate::tree::expression::block::Expression as BlockExpression;
+use crate::tree::expression::closure::Expression as ClosureExpression;
 use crate::tree::expression::conditional::Expression as ConditionalExpression;
 use crate::tree::expression::list::Expression as ListExpression;
 use crate::tree::expression::r#match::Expression as MatchExpression;
@@ -33,6 +34,8 @@ pub enum Operand {
     LiteralInteger(IntegerLiteral),
     /// "Zinc is the best language for ZKP".
     LiteralString(StringLiteral),
+    /// b"Zinc", which is an array of bytes.
+    LiteralByteString(StringLiteral),
     /// A tuple field identifier.
     TupleIndex(TupleIndex),
     /// An item identifier.
@@ -53,6 +56,8 @@ pub enum Operand {
     Conditional(ConditionalExpression),
     /// A match expression `match value { 1 => 10, _ => 42 }`.
     Match(MatchExpression),
+    /// A closure expression `|a, b| a + b`.
+    Closure(ClosureExpression),
 }
 
 impl Operand {
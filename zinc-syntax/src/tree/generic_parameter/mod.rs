@@ -0,0 +1,33 @@
+//!
+//! The generic type parameter.
+//!
+
+use zinc_lexical::Location;
+
+use crate::tree::identifier::Identifier;
+
+///
+/// The generic type parameter with its optional trait bounds.
+///
+#[derive(Debug, Clone, PartialEq)]
+pub struct GenericParameter {
+    /// The location of the syntax construction.
+    pub location: Location,
+    /// The generic parameter identifier.
+    pub identifier: Identifier,
+    /// The traits the generic argument must implement, e.g. `T: Shape + Named`.
+    pub bounds: Vec<Identifier>,
+}
+
+impl GenericParameter {
+    ///
+    /// Creates a generic type parameter.
+    ///
+    pub fn new(location: Location, identifier: Identifier, bounds: Vec<Identifier>) -> Self {
+        Self {
+            location,
+            identifier,
+            bounds,
+        }
+    }
+}
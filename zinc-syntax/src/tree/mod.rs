@@ -6,6 +6,7 @@ pub mod attribute;
 pub mod binding;
 pub mod expression;
 pub mod field;
+pub mod generic_parameter;
 pub mod identifier;
 pub mod literal;
 pub mod module;
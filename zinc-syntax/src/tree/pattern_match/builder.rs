@@ -25,10 +25,20 @@ pub struct Builder {
     boolean_literal: Option<BooleanLiteral>,
     /// The integer literal variant, which means that the pattern is an integer constant.
     integer_literal: Option<IntegerLiteral>,
+    /// The range end literal, which means that the pattern is an integer range.
+    range_end: Option<IntegerLiteral>,
     /// The binding variant, which means that the pattern is a variable binding.
     binding: Option<Identifier>,
     /// The path builder variant, which means that the pattern is a path expression.
     path_builder: ExpressionTreeBuilder,
+    /// The element patterns, which means that the pattern is a tuple or tuple variant path.
+    tuple_patterns: Option<Vec<MatchPattern>>,
+    /// If the tuple has a comma after the first element.
+    /// If the comma is present, the pattern is definitely a tuple.
+    /// If the comma is absent, the pattern is an ordinar parenthesized pattern.
+    has_comma: bool,
+    /// The payload field patterns, which means that the pattern is a structure variant path.
+    structure_fields: Option<Vec<(Identifier, MatchPattern)>>,
     /// If the pattern variant is a wildcard.
     is_wildcard: bool,
 }
@@ -55,6 +65,13 @@ impl Builder {
         self.integer_literal = Some(value);
     }
 
+    ///
+    /// Sets the corresponding builder value.
+    ///
+    pub fn set_range_end(&mut self, value: IntegerLiteral) {
+        self.range_end = Some(value);
+    }
+
     ///
     /// Sets the corresponding builder value.
     ///
@@ -78,6 +95,74 @@ impl Builder {
         self.path_builder.eat(tree);
     }
 
+    ///
+    /// Sets the corresponding builder value.
+    ///
+    pub fn set_path_tuple(&mut self) {
+        self.move_binding_to_path();
+        self.tuple_patterns = Some(Vec::new());
+    }
+
+    ///
+    /// Sets the corresponding builder value.
+    ///
+    pub fn set_tuple(&mut self) {
+        self.tuple_patterns = Some(Vec::new());
+    }
+
+    ///
+    /// Sets the corresponding builder value.
+    ///
+    pub fn set_comma(&mut self) {
+        self.has_comma = true;
+    }
+
+    ///
+    /// Pushes the corresponding builder value.
+    ///
+    /// # Panics
+    /// If the pattern has not been marked as a tuple or tuple variant path.
+    ///
+    pub fn push_tuple_pattern(&mut self, value: MatchPattern) {
+        self.tuple_patterns
+            .as_mut()
+            .unwrap_or_else(|| {
+                panic!(
+                    "{}{}",
+                    zinc_const::panic::BUILDER_REQUIRES_VALUE,
+                    "tuple patterns"
+                )
+            })
+            .push(value);
+    }
+
+    ///
+    /// Sets the corresponding builder value.
+    ///
+    pub fn set_path_structure(&mut self) {
+        self.move_binding_to_path();
+        self.structure_fields = Some(Vec::new());
+    }
+
+    ///
+    /// Pushes the corresponding builder value.
+    ///
+    /// # Panics
+    /// If the pattern has not been marked as a structure variant path.
+    ///
+    pub fn push_structure_field(&mut self, identifier: Identifier, value: MatchPattern) {
+        self.structure_fields
+            .as_mut()
+            .unwrap_or_else(|| {
+                panic!(
+                    "{}{}",
+                    zinc_const::panic::BUILDER_REQUIRES_VALUE,
+                    "structure fields"
+                )
+            })
+            .push((identifier, value));
+    }
+
     ///
     /// Sets the corresponding builder value.
     ///
@@ -105,16 +190,38 @@ impl Builder {
         } else if let Some(boolean_literal) = self.boolean_literal.take() {
             MatchPatternVariant::BooleanLiteral(boolean_literal)
         } else if let Some(integer_literal) = self.integer_literal.take() {
-            MatchPatternVariant::IntegerLiteral(integer_literal)
+            match self.range_end.take() {
+                Some(end) => MatchPatternVariant::Range {
+                    start: integer_literal,
+                    end,
+                },
+                None => MatchPatternVariant::IntegerLiteral(integer_literal),
+            }
         } else if let Some(identifier) = self.binding.take() {
             MatchPatternVariant::Binding(identifier)
+        } else if let Some(mut patterns) = self.tuple_patterns.take() {
+            if !self.path_builder.is_empty() {
+                MatchPatternVariant::PathTuple {
+                    path: self.path_builder.finish(),
+                    patterns,
+                }
+            } else if patterns.len() == 1 && !self.has_comma {
+                return patterns.remove(0);
+            } else {
+                MatchPatternVariant::Tuple(patterns)
+            }
+        } else if let Some(fields) = self.structure_fields.take() {
+            MatchPatternVariant::PathStructure {
+                path: self.path_builder.finish(),
+                fields,
+            }
         } else if !self.path_builder.is_empty() {
             MatchPatternVariant::Path(self.path_builder.finish())
         } else {
             panic!(
                 "{}{}",
                 zinc_const::panic::BUILDER_REQUIRES_VALUE,
-                "boolean | integer | binding | path | wildcard"
+                "boolean | integer | range | binding | path | path tuple | path structure | tuple | wildcard"
             );
         };
 
@@ -6,6 +6,7 @@ use crate::tree::expression::tree::Tree as ExpressionTree;
 use crate::tree::identifier::Identifier;
 use crate::tree::literal::boolean::Literal as BooleanLiteral;
 use crate::tree::literal::integer::Literal as IntegerLiteral;
+use crate::tree::pattern_match::Pattern;
 
 ///
 /// The match pattern variant.
@@ -20,6 +21,32 @@ pub enum Variant {
     Binding(Identifier),
     /// An expression path refutable pattern, usually points to a constant or enumeration variant.
     Path(ExpressionTree),
+    /// An enumeration variant refutable pattern with a tuple payload, e.g. `Option::Some(value)`.
+    PathTuple {
+        /// The enumeration variant path expression.
+        path: ExpressionTree,
+        /// The payload element patterns.
+        patterns: Vec<Pattern>,
+    },
+    /// An enumeration variant refutable pattern with a structure payload, e.g. `Shape::Circle { radius }`,
+    /// or a structure destructuring pattern, e.g. `Point { x, y: 0 }`.
+    PathStructure {
+        /// The enumeration variant or structure type path expression.
+        path: ExpressionTree,
+        /// The payload field patterns.
+        fields: Vec<(Identifier, Pattern)>,
+    },
+    /// A tuple destructuring pattern, e.g. `(a, 0, _)`.
+    Tuple(Vec<Pattern>),
+    /// An inclusive integer range refutable pattern, e.g. `1..=9`.
+    Range {
+        /// The range start literal.
+        start: IntegerLiteral,
+        /// The range inclusive end literal.
+        end: IntegerLiteral,
+    },
+    /// The alternative patterns, e.g. `1 | 2`, which match if any of them matches.
+    Alternatives(Vec<Pattern>),
     /// A wildcard irrefutable pattern.
     Wildcard,
 }
@@ -53,6 +80,41 @@ impl Variant {
         Self::Path(expression)
     }
 
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new_path_tuple(path: ExpressionTree, patterns: Vec<Pattern>) -> Self {
+        Self::PathTuple { path, patterns }
+    }
+
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new_path_structure(path: ExpressionTree, fields: Vec<(Identifier, Pattern)>) -> Self {
+        Self::PathStructure { path, fields }
+    }
+
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new_tuple(patterns: Vec<Pattern>) -> Self {
+        Self::Tuple(patterns)
+    }
+
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new_range(start: IntegerLiteral, end: IntegerLiteral) -> Self {
+        Self::Range { start, end }
+    }
+
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new_alternatives(patterns: Vec<Pattern>) -> Self {
+        Self::Alternatives(patterns)
+    }
+
     ///
     /// A shortcut constructor.
     ///
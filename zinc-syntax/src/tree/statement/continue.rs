@@ -0,0 +1,23 @@
+//!
+//! The `continue` statement.
+//!
+
+use zinc_lexical::Location;
+
+///
+/// The `continue` statement.
+///
+#[derive(Debug, Clone, PartialEq)]
+pub struct Statement {
+    /// The location of the syntax construction.
+    pub location: Location,
+}
+
+impl Statement {
+    ///
+    /// Creates a `continue` statement.
+    ///
+    pub fn new(location: Location) -> Self {
+        Self { location }
+    }
+}
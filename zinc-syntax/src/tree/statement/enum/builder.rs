@@ -4,6 +4,7 @@
 
 use zinc_lexical::Location;
 
+use crate::tree::generic_parameter::GenericParameter;
 use crate::tree::identifier::Identifier;
 use crate::tree::statement::r#enum::Statement as EnumStatement;
 use crate::tree::variant::Variant;
@@ -17,6 +18,8 @@ pub struct Builder {
     location: Option<Location>,
     /// The enumeration type identifier.
     identifier: Option<Identifier>,
+    /// The enumeration generic type parameters.
+    generics: Option<Vec<GenericParameter>>,
     /// The enumeration type variants.
     variants: Vec<Variant>,
 }
@@ -36,6 +39,13 @@ impl Builder {
         self.identifier = Some(value);
     }
 
+    ///
+    /// Sets the corresponding builder value.
+    ///
+    pub fn set_generics(&mut self, value: Vec<GenericParameter>) {
+        self.generics = Some(value);
+    }
+
     ///
     /// Sets the corresponding builder value.
     ///
@@ -65,6 +75,7 @@ impl Builder {
                     "identifier"
                 )
             }),
+            self.generics.take(),
             self.variants,
         )
     }
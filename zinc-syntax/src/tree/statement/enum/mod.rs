@@ -6,18 +6,23 @@ pub mod builder;
 
 use zinc_lexical::Location;
 
+use crate::tree::generic_parameter::GenericParameter;
 use crate::tree::identifier::Identifier;
 use crate::tree::variant::Variant;
 
 ///
 /// The `enum` statement.
 ///
+/// If any of the variants carries a payload, the enumeration is a data-carrying one.
+///
 #[derive(Debug, Clone, PartialEq)]
 pub struct Statement {
     /// The location of the syntax construction.
     pub location: Location,
     /// The enumeration type identifier.
     pub identifier: Identifier,
+    /// The enumeration generic type parameters.
+    pub generics: Option<Vec<GenericParameter>>,
     /// The enumeration type variants.
     pub variants: Vec<Variant>,
 }
@@ -26,10 +31,16 @@ impl Statement {
     ///
     /// Creates an `enum` statement.
     ///
-    pub fn new(location: Location, identifier: Identifier, variants: Vec<Variant>) -> Self {
+    pub fn new(
+        location: Location,
+        identifier: Identifier,
+        generics: Option<Vec<GenericParameter>>,
+        variants: Vec<Variant>,
+    ) -> Self {
         Self {
             location,
             identifier,
+            generics,
             variants,
         }
     }
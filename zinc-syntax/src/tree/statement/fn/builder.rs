@@ -7,8 +7,10 @@ use zinc_lexical::Location;
 use crate::tree::attribute::Attribute;
 use crate::tree::binding::Binding;
 use crate::tree::expression::block::Expression as BlockExpression;
+use crate::tree::generic_parameter::GenericParameter;
 use crate::tree::identifier::Identifier;
 use crate::tree::r#type::Type;
+use crate::tree::statement::r#fn::prototype::Prototype as FnPrototype;
 use crate::tree::statement::r#fn::Statement as FnStatement;
 
 ///
@@ -24,6 +26,8 @@ pub struct Builder {
     is_constant: bool,
     /// The function identifier.
     identifier: Option<Identifier>,
+    /// The function generic type parameters.
+    generics: Option<Vec<GenericParameter>>,
     /// The function argument bindings.
     argument_bindings: Vec<Binding>,
     /// The optional function return type, which is `()` if not specified.
@@ -63,6 +67,13 @@ impl Builder {
         self.identifier = Some(value);
     }
 
+    ///
+    /// Sets the corresponding builder value.
+    ///
+    pub fn set_generics(&mut self, value: Vec<GenericParameter>) {
+        self.generics = Some(value);
+    }
+
     ///
     /// Sets the corresponding builder value.
     ///
@@ -115,6 +126,7 @@ impl Builder {
                     "identifier"
                 )
             }),
+            self.generics.take(),
             self.argument_bindings,
             self.return_type.take(),
             self.body.take().unwrap_or_else(|| {
@@ -123,4 +135,32 @@ impl Builder {
             self.attributes,
         )
     }
+
+    ///
+    /// Finalizes the builder and returns the built function prototype, ignoring the body.
+    ///
+    /// # Panics
+    /// If some of the required items has not been set.
+    ///
+    pub fn finish_prototype(mut self) -> FnPrototype {
+        FnPrototype::new(
+            self.location.take().unwrap_or_else(|| {
+                panic!(
+                    "{}{}",
+                    zinc_const::panic::BUILDER_REQUIRES_VALUE,
+                    "location"
+                )
+            }),
+            self.identifier.take().unwrap_or_else(|| {
+                panic!(
+                    "{}{}",
+                    zinc_const::panic::BUILDER_REQUIRES_VALUE,
+                    "identifier"
+                )
+            }),
+            self.generics.take(),
+            self.argument_bindings,
+            self.return_type.take(),
+        )
+    }
 }
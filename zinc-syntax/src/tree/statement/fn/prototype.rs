@@ -0,0 +1,50 @@
+//!
+//! The `fn` prototype.
+//!
+
+use zinc_lexical::Location;
+
+use crate::tree::binding::Binding;
+use crate::tree::generic_parameter::GenericParameter;
+use crate::tree::identifier::Identifier;
+use crate::tree::r#type::Type;
+
+///
+/// The `fn` prototype, that is, the function signature without a body.
+///
+/// Is used to declare the trait methods.
+///
+#[derive(Debug, Clone, PartialEq)]
+pub struct Prototype {
+    /// The location of the syntax construction.
+    pub location: Location,
+    /// The function identifier.
+    pub identifier: Identifier,
+    /// The function generic type parameters.
+    pub generics: Option<Vec<GenericParameter>>,
+    /// The function argument bindings list.
+    pub argument_bindings: Vec<Binding>,
+    /// The optional function return type, which is `()` if not specified.
+    pub return_type: Option<Type>,
+}
+
+impl Prototype {
+    ///
+    /// Creates an `fn` prototype.
+    ///
+    pub fn new(
+        location: Location,
+        identifier: Identifier,
+        generics: Option<Vec<GenericParameter>>,
+        argument_bindings: Vec<Binding>,
+        return_type: Option<Type>,
+    ) -> Self {
+        Self {
+            location,
+            identifier,
+            generics,
+            argument_bindings,
+            return_type,
+        }
+    }
+}
@@ -17,6 +17,8 @@ pub struct Builder {
     location: Option<Location>,
     /// The identifier of the implemented type.
     identifier: Option<Identifier>,
+    /// The identifier of the implemented trait.
+    r#trait: Option<Identifier>,
     /// The implementation statements.
     statements: Vec<ImplementationLocalStatement>,
 }
@@ -36,6 +38,13 @@ impl Builder {
         self.identifier = Some(value);
     }
 
+    ///
+    /// Sets the corresponding builder value.
+    ///
+    pub fn set_trait(&mut self, value: Identifier) {
+        self.r#trait = Some(value);
+    }
+
     ///
     /// Pushes the corresponding builder value.
     ///
@@ -65,6 +74,7 @@ impl Builder {
                     "identifier"
                 )
             }),
+            self.r#trait.take(),
             self.statements,
         )
     }
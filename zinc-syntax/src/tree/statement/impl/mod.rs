@@ -18,6 +18,8 @@ pub struct Statement {
     pub location: Location,
     /// The identifier of the implemented type.
     pub identifier: Identifier,
+    /// The identifier of the implemented trait, if the statement is `impl Trait for Type`.
+    pub r#trait: Option<Identifier>,
     /// The implementation statements.
     pub statements: Vec<ImplementationLocalStatement>,
 }
@@ -29,11 +31,13 @@ impl Statement {
     pub fn new(
         location: Location,
         identifier: Identifier,
+        r#trait: Option<Identifier>,
         statements: Vec<ImplementationLocalStatement>,
     ) -> Self {
         Self {
             location,
             identifier,
+            r#trait,
             statements,
         }
     }
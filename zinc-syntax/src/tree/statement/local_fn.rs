@@ -5,9 +5,13 @@
 use zinc_lexical::Location;
 
 use crate::tree::expression::tree::Tree as ExpressionTree;
+use crate::tree::statement::r#break::Statement as BreakStatement;
 use crate::tree::statement::r#const::Statement as ConstStatement;
+use crate::tree::statement::r#continue::Statement as ContinueStatement;
 use crate::tree::statement::r#for::Statement as ForStatement;
 use crate::tree::statement::r#let::Statement as LetStatement;
+use crate::tree::statement::r#return::Statement as ReturnStatement;
+use crate::tree::statement::r#while::Statement as WhileStatement;
 
 ///
 /// The function-or-block-level statement.
@@ -20,6 +24,14 @@ pub enum Statement {
     Const(ConstStatement),
     /// The `for` statement.
     For(ForStatement),
+    /// The `while` statement.
+    While(WhileStatement),
+    /// The `return` statement.
+    Return(ReturnStatement),
+    /// The `break` statement.
+    Break(BreakStatement),
+    /// The `continue` statement.
+    Continue(ContinueStatement),
     /// The empty `;` statement.
     Empty(Location),
     /// The expression statement.
@@ -35,6 +47,10 @@ impl Statement {
             Self::Let(inner) => inner.location,
             Self::Const(inner) => inner.location,
             Self::For(inner) => inner.location,
+            Self::While(inner) => inner.location,
+            Self::Return(inner) => inner.location,
+            Self::Break(inner) => inner.location,
+            Self::Continue(inner) => inner.location,
             Self::Empty(location) => *location,
             Self::Expression(inner) => inner.location,
         }
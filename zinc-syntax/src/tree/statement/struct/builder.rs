@@ -5,6 +5,7 @@
 use zinc_lexical::Location;
 
 use crate::tree::field::Field;
+use crate::tree::generic_parameter::GenericParameter;
 use crate::tree::identifier::Identifier;
 use crate::tree::statement::r#struct::Statement as StructStatement;
 
@@ -17,6 +18,8 @@ pub struct Builder {
     location: Option<Location>,
     /// The structure type identifier.
     identifier: Option<Identifier>,
+    /// The structure generic type parameters.
+    generics: Option<Vec<GenericParameter>>,
     /// The structure type fields.
     fields: Vec<Field>,
 }
@@ -36,6 +39,13 @@ impl Builder {
         self.identifier = Some(value);
     }
 
+    ///
+    /// Sets the corresponding builder value.
+    ///
+    pub fn set_generics(&mut self, value: Vec<GenericParameter>) {
+        self.generics = Some(value);
+    }
+
     ///
     /// Sets the corresponding builder value.
     ///
@@ -65,6 +75,7 @@ impl Builder {
                     "identifier"
                 )
             }),
+            self.generics.take(),
             self.fields,
         )
     }
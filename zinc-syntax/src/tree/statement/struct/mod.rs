@@ -7,6 +7,7 @@ pub mod builder;
 use zinc_lexical::Location;
 
 use crate::tree::field::Field;
+use crate::tree::generic_parameter::GenericParameter;
 use crate::tree::identifier::Identifier;
 
 ///
@@ -18,6 +19,8 @@ pub struct Statement {
     pub location: Location,
     /// The structure type identifier.
     pub identifier: Identifier,
+    /// The structure generic type parameters.
+    pub generics: Option<Vec<GenericParameter>>,
     /// The structure type fields.
     pub fields: Vec<Field>,
 }
@@ -26,10 +29,16 @@ impl Statement {
     ///
     /// Creates a `struct` statement.
     ///
-    pub fn new(location: Location, identifier: Identifier, fields: Vec<Field>) -> Self {
+    pub fn new(
+        location: Location,
+        identifier: Identifier,
+        generics: Option<Vec<GenericParameter>>,
+        fields: Vec<Field>,
+    ) -> Self {
         Self {
             location,
             identifier,
+            generics,
             fields,
         }
     }
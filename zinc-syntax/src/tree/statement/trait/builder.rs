@@ -0,0 +1,71 @@
+//!
+//! The `trait` statement builder.
+//!
+
+use zinc_lexical::Location;
+
+use crate::tree::identifier::Identifier;
+use crate::tree::statement::r#fn::prototype::Prototype as FnPrototype;
+use crate::tree::statement::r#trait::Statement as TraitStatement;
+
+///
+/// The `trait` statement builder.
+///
+#[derive(Default)]
+pub struct Builder {
+    /// The location of the syntax construction.
+    location: Option<Location>,
+    /// The trait identifier.
+    identifier: Option<Identifier>,
+    /// The trait method prototypes.
+    methods: Vec<FnPrototype>,
+}
+
+impl Builder {
+    ///
+    /// Sets the corresponding builder value.
+    ///
+    pub fn set_location(&mut self, value: Location) {
+        self.location = Some(value);
+    }
+
+    ///
+    /// Sets the corresponding builder value.
+    ///
+    pub fn set_identifier(&mut self, value: Identifier) {
+        self.identifier = Some(value);
+    }
+
+    ///
+    /// Pushes the corresponding builder value.
+    ///
+    pub fn push_method(&mut self, value: FnPrototype) {
+        self.methods.push(value);
+    }
+
+    ///
+    /// Finalizes the builder and returns the built value.
+    ///
+    /// # Panics
+    /// If some of the required items has not been set.
+    ///
+    pub fn finish(mut self) -> TraitStatement {
+        TraitStatement::new(
+            self.location.take().unwrap_or_else(|| {
+                panic!(
+                    "{}{}",
+                    zinc_const::panic::BUILDER_REQUIRES_VALUE,
+                    "location"
+                )
+            }),
+            self.identifier.take().unwrap_or_else(|| {
+                panic!(
+                    "{}{}",
+                    zinc_const::panic::BUILDER_REQUIRES_VALUE,
+                    "identifier"
+                )
+            }),
+            self.methods,
+        )
+    }
+}
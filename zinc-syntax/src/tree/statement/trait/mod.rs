@@ -0,0 +1,36 @@
+//!
+//! The `trait` statement.
+//!
+
+pub mod builder;
+
+use zinc_lexical::Location;
+
+use crate::tree::identifier::Identifier;
+use crate::tree::statement::r#fn::prototype::Prototype as FnPrototype;
+
+///
+/// The `trait` statement.
+///
+#[derive(Debug, Clone, PartialEq)]
+pub struct Statement {
+    /// The location of the syntax construction.
+    pub location: Location,
+    /// The trait identifier.
+    pub identifier: Identifier,
+    /// The trait method prototypes.
+    pub methods: Vec<FnPrototype>,
+}
+
+impl Statement {
+    ///
+    /// Creates a `trait` statement.
+    ///
+    pub fn new(location: Location, identifier: Identifier, methods: Vec<FnPrototype>) -> Self {
+        Self {
+            location,
+            identifier,
+            methods,
+        }
+    }
+}
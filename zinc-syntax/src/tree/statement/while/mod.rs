@@ -0,0 +1,44 @@
+//!
+//! The `while` statement.
+//!
+
+pub mod builder;
+
+use zinc_lexical::Location;
+
+use crate::tree::expression::block::Expression as BlockExpression;
+use crate::tree::expression::tree::Tree as ExpressionTree;
+
+///
+/// The `while` statement.
+///
+#[derive(Debug, Clone, PartialEq)]
+pub struct Statement {
+    /// The location of the syntax construction.
+    pub location: Location,
+    /// The loop condition expression.
+    pub condition: ExpressionTree,
+    /// The loop iterations bound expression.
+    pub bound: ExpressionTree,
+    /// The loop block.
+    pub block: BlockExpression,
+}
+
+impl Statement {
+    ///
+    /// Creates a `while` statement.
+    ///
+    pub fn new(
+        location: Location,
+        condition: ExpressionTree,
+        bound: ExpressionTree,
+        block: BlockExpression,
+    ) -> Self {
+        Self {
+            location,
+            condition,
+            bound,
+            block,
+        }
+    }
+}
@@ -3,12 +3,15 @@
 //!
 
 pub mod builder;
+pub mod payload;
 
 use zinc_lexical::Location;
 
 use crate::tree::identifier::Identifier;
 use crate::tree::literal::integer::Literal as IntegerLiteral;
 
+use self::payload::Payload;
+
 ///
 /// The enumeration variant.
 ///
@@ -18,19 +21,27 @@ pub struct Variant {
     pub location: Location,
     /// The enumeration variant identifier.
     pub identifier: Identifier,
-    /// The enumeration variant integer value.
-    pub literal: IntegerLiteral,
+    /// The enumeration variant integer value. Defaults to the variant index if omitted.
+    pub literal: Option<IntegerLiteral>,
+    /// The enumeration variant payload, which makes the enumeration a data-carrying one.
+    pub payload: Option<Payload>,
 }
 
 impl Variant {
     ///
     /// Creates an enumeration variant.
     ///
-    pub fn new(location: Location, identifier: Identifier, literal: IntegerLiteral) -> Self {
+    pub fn new(
+        location: Location,
+        identifier: Identifier,
+        literal: Option<IntegerLiteral>,
+        payload: Option<Payload>,
+    ) -> Self {
         Self {
             location,
             identifier,
             literal,
+            payload,
         }
     }
 }
@@ -0,0 +1,33 @@
+//!
+//! The enumeration variant payload.
+//!
+
+use crate::tree::field::Field;
+use crate::tree::r#type::Type;
+
+///
+/// The enumeration variant payload, which is carried by the variant value.
+///
+#[derive(Debug, Clone, PartialEq)]
+pub enum Payload {
+    /// `{variant}({type1}, {type2}, ...)` in the source code.
+    Tuple(Vec<Type>),
+    /// `{variant} { {field1}: {type1}, {field2}: {type2}, ... }` in the source code.
+    Structure(Vec<Field>),
+}
+
+impl Payload {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new_tuple(types: Vec<Type>) -> Self {
+        Self::Tuple(types)
+    }
+
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new_structure(fields: Vec<Field>) -> Self {
+        Self::Structure(fields)
+    }
+}
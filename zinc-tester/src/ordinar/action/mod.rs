@@ -3,6 +3,7 @@
 //!
 
 pub mod call;
+pub mod proof_check;
 pub mod publish;
 pub mod query;
 
@@ -11,6 +12,7 @@ use std::path::PathBuf;
 use serde::Deserialize;
 
 use self::call::Call as CallAction;
+use self::proof_check::ProofCheck as ProofCheckAction;
 use self::publish::Publish as PublishAction;
 use self::query::Query as QueryAction;
 
@@ -26,6 +28,9 @@ pub enum Action {
     Query(QueryAction),
     /// The `zargo call` command.
     Call(CallAction),
+    /// The `zargo proof-check` command.
+    #[serde(rename = "proof-check")]
+    ProofCheck(ProofCheckAction),
 }
 
 impl Action {
@@ -37,6 +42,7 @@ impl Action {
             Self::Publish(inner) => inner.input_path.to_owned(),
             Self::Query(inner) => inner.input_path.to_owned(),
             Self::Call(inner) => inner.input_path.to_owned(),
+            Self::ProofCheck(inner) => inner.input_path.to_owned(),
         }
     }
 
@@ -48,6 +54,7 @@ impl Action {
             Self::Publish(ref mut inner) => inner.input_path = path,
             Self::Query(ref mut inner) => inner.input_path = path,
             Self::Call(ref mut inner) => inner.input_path = path,
+            Self::ProofCheck(ref mut inner) => inner.input_path = path,
         }
     }
 }
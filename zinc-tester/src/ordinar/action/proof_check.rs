@@ -0,0 +1,18 @@
+//!
+//! The ordinar integration test `proof-check` action.
+//!
+
+use std::path::PathBuf;
+
+use serde::Deserialize;
+
+///
+/// The ordinar integration test `proof-check` action.
+///
+#[derive(Debug, Deserialize)]
+pub struct ProofCheck {
+    /// The input JSON template file path.
+    pub input_path: PathBuf,
+    /// The method name to run, prove and verify.
+    pub method: String,
+}
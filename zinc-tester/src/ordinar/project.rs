@@ -15,6 +15,7 @@ use colored::Colorize;
 use tokio::runtime::Runtime;
 
 use crate::ordinar::action::call::Call as CallAction;
+use crate::ordinar::action::proof_check::ProofCheck as ProofCheckAction;
 use crate::ordinar::action::publish::Publish as PublishAction;
 use crate::ordinar::action::query::Query as QueryAction;
 use crate::ordinar::action::Action;
@@ -108,6 +109,12 @@ impl Project {
                             return Ok(());
                         }
                     }
+                    Action::ProofCheck(inner) => {
+                        if self.proof_check(summary.clone(), inner).is_err() {
+                            self.clean()?;
+                            return Ok(());
+                        }
+                    }
                 }
             }
         }
@@ -437,7 +444,10 @@ impl Project {
             .execute(),
         ) {
             Ok(output) => {
-                if output != action.expect {
+                // the storage root hash depends on the random instance address, so only the
+                // method result is compared, and the root hashes are checked by `proof-check`
+                let result = |json: &serde_json::Value| json["output"]["result"].to_owned();
+                if result(&output) != result(&action.expect) {
                     println!(
                         "[INTEGRATION] {} {} (call failure): (expected `{}`, found `{}`)",
                         "FAILED".bright_red(),
@@ -470,6 +480,42 @@ impl Project {
         }
     }
 
+    ///
+    /// Runs, proves and verifies a method of the test project contract locally.
+    ///
+    fn proof_check(
+        &mut self,
+        summary: Arc<Mutex<Summary>>,
+        action: ProofCheckAction,
+    ) -> anyhow::Result<()> {
+        self.copy_scenario_input(summary.clone(), action.input_path)?;
+
+        if let Err(error) = zargo::ProofCheckCommand::new(
+            self.verbosity,
+            self.verbosity <= 1,
+            self.path.clone(),
+            Some(action.method.clone()),
+            false,
+        )
+        .execute()
+        {
+            println!(
+                "[INTEGRATION] {} {} (proof check failure of `{}`): {:?}",
+                "FAILED".bright_red(),
+                self.path.to_string_lossy(),
+                action.method,
+                error,
+            );
+            summary
+                .lock()
+                .expect(zinc_const::panic::SYNCHRONIZATION)
+                .failed += 1;
+            return Err(error);
+        }
+
+        Ok(())
+    }
+
     ///
     /// Cleans up the test project.
     ///
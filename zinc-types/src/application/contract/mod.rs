@@ -30,6 +30,8 @@ pub struct Contract {
     pub unit_tests: HashMap<String, UnitTest>,
     /// The contract bytecode instructions.
     pub instructions: Vec<Instruction>,
+    /// The contract storage Merkle tree hasher.
+    pub storage_hasher: zinc_project::StorageHasher,
 }
 
 impl Contract {
@@ -42,6 +44,7 @@ impl Contract {
         methods: HashMap<String, Method>,
         unit_tests: HashMap<String, UnitTest>,
         instructions: Vec<Instruction>,
+        storage_hasher: zinc_project::StorageHasher,
     ) -> Self {
         Self {
             name,
@@ -49,6 +52,39 @@ impl Contract {
             methods,
             unit_tests,
             instructions,
+            storage_hasher,
         }
     }
 }
+
+///
+/// The bytecode contract application layout without the storage Merkle tree hasher.
+///
+/// The contracts serialized with this layout use the default hasher.
+///
+#[derive(Debug, Deserialize)]
+pub struct Legacy {
+    /// The contract name.
+    pub name: String,
+    /// The contract storage structure.
+    pub storage: Vec<ContractFieldType>,
+    /// The contract methods.
+    pub methods: HashMap<String, Method>,
+    /// The contract unit tests.
+    pub unit_tests: HashMap<String, UnitTest>,
+    /// The contract bytecode instructions.
+    pub instructions: Vec<Instruction>,
+}
+
+impl From<Legacy> for Contract {
+    fn from(legacy: Legacy) -> Self {
+        Self::new(
+            legacy.name,
+            legacy.storage,
+            legacy.methods,
+            legacy.unit_tests,
+            legacy.instructions,
+            zinc_project::StorageHasher::default(),
+        )
+    }
+}
@@ -23,6 +23,7 @@ use crate::instructions::Instruction;
 use self::circuit::Circuit;
 use self::contract::method::Method as ContractMethod;
 use self::contract::Contract;
+use self::contract::Legacy as LegacyContract;
 use self::library::Library;
 
 ///
@@ -69,6 +70,7 @@ impl Application {
         methods: HashMap<String, ContractMethod>,
         unit_tests: HashMap<String, UnitTest>,
         instructions: Vec<Instruction>,
+        storage_hasher: zinc_project::StorageHasher,
     ) -> Self {
         Self::Contract(Contract::new(
             name,
@@ -76,6 +78,7 @@ impl Application {
             methods,
             unit_tests,
             instructions,
+            storage_hasher,
         ))
     }
 
@@ -159,8 +162,17 @@ impl Application {
     ///
     /// Deserializes an application from the byte `slice`.
     ///
+    /// If the `slice` cannot be deserialized, it is tried with the legacy layout, where the
+    /// contract has no storage Merkle tree hasher, so the bytecode stored before still works.
+    ///
     pub fn try_from_slice(slice: &[u8]) -> Result<Self, String> {
-        bincode::deserialize(slice).map_err(|error| format!("{:?}", error))
+        bincode::deserialize(slice)
+            .or_else(|error| {
+                bincode::deserialize::<LegacyApplication>(slice)
+                    .map(Self::from)
+                    .map_err(|_legacy_error| error)
+            })
+            .map_err(|error| format!("{:?}", error))
     }
 
     ///
@@ -170,3 +182,78 @@ impl Application {
         bincode::serialize(&self).expect(zinc_const::panic::DATA_CONVERSION)
     }
 }
+
+///
+/// The bytecode application layout with the legacy contract.
+///
+#[derive(Debug, Deserialize)]
+enum LegacyApplication {
+    /// The circuit application variant.
+    Circuit(Circuit),
+    /// The legacy contract application variant.
+    Contract(LegacyContract),
+    /// The library variant.
+    Library(Library),
+}
+
+impl From<LegacyApplication> for Application {
+    fn from(legacy: LegacyApplication) -> Self {
+        match legacy {
+            LegacyApplication::Circuit(inner) => Self::Circuit(inner),
+            LegacyApplication::Contract(inner) => Self::Contract(inner.into()),
+            LegacyApplication::Library(inner) => Self::Library(inner),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use super::Application;
+
+    fn contract(storage_hasher: zinc_project::StorageHasher) -> Application {
+        Application::new_contract(
+            "test".to_owned(),
+            vec![],
+            HashMap::new(),
+            HashMap::new(),
+            vec![],
+            storage_hasher,
+        )
+    }
+
+    #[test]
+    fn ok_storage_hasher() {
+        let bytecode = contract(zinc_project::StorageHasher::Rescue).into_vec();
+
+        match Application::try_from_slice(bytecode.as_slice()) {
+            Ok(Application::Contract(contract)) => {
+                assert_eq!(contract.storage_hasher, zinc_project::StorageHasher::Rescue)
+            }
+            _ => panic!("{}", zinc_const::panic::TEST_DATA_VALID),
+        }
+    }
+
+    #[test]
+    fn ok_legacy_contract() {
+        let mut bytecode = contract(zinc_project::StorageHasher::Rescue).into_vec();
+        bytecode.truncate(bytecode.len() - std::mem::size_of::<u32>());
+
+        match Application::try_from_slice(bytecode.as_slice()) {
+            Ok(Application::Contract(contract)) => {
+                assert_eq!(contract.name, "test");
+                assert_eq!(
+                    contract.storage_hasher,
+                    zinc_project::StorageHasher::default()
+                );
+            }
+            _ => panic!("{}", zinc_const::panic::TEST_DATA_VALID),
+        }
+    }
+
+    #[test]
+    fn error_invalid() {
+        assert!(Application::try_from_slice(&[0xff; 4]).is_err());
+    }
+}
@@ -0,0 +1,55 @@
+//!
+//! The bytecode assembly error.
+//!
+
+use thiserror::Error;
+
+///
+/// The bytecode assembly parsing error.
+///
+#[derive(Debug, Error, PartialEq)]
+pub enum Error {
+    /// The line could not be parsed.
+    #[error("line {line}: {message}")]
+    Syntax {
+        /// The line number, starting from 1.
+        line: usize,
+        /// The error description.
+        message: String,
+    },
+
+    /// The explicit instruction address does not match its actual position.
+    #[error("line {line}: the instruction address is {found}, but it must be {expected}")]
+    AddressMismatch {
+        /// The line number, starting from 1.
+        line: usize,
+        /// The actual instruction address.
+        expected: usize,
+        /// The address written in the source text.
+        found: usize,
+    },
+
+    /// The directive is not applicable to the declared application kind.
+    #[error("line {line}: the `.{name}` directive is not allowed in a {kind}")]
+    DirectiveUnexpected {
+        /// The line number, starting from 1.
+        line: usize,
+        /// The directive name.
+        name: String,
+        /// The application kind.
+        kind: &'static str,
+    },
+
+    /// None of the `.circuit`, `.contract`, or `.library` directives is specified.
+    #[error(
+        "the application kind is missing: expected one of `.circuit`, `.contract`, `.library`"
+    )]
+    ApplicationKindMissing,
+
+    /// A mandatory directive is not specified.
+    #[error("the `.{name}` directive is missing")]
+    DirectiveMissing {
+        /// The directive name.
+        name: &'static str,
+    },
+}
@@ -0,0 +1,14 @@
+//!
+//! The bytecode assembly.
+//!
+//! The assembly is the textual form of the bytecode application. It consists of the header
+//! directives, which describe the application metadata, and the instructions, one per line.
+//! The strings are written as JSON string literals, and the comments start with `//`.
+//!
+
+#[cfg(test)]
+mod tests;
+
+pub mod error;
+pub mod parser;
+pub mod writer;
@@ -0,0 +1,869 @@
+//!
+//! The bytecode assembly parser.
+//!
+
+use std::collections::HashMap;
+use std::str::FromStr;
+
+use num::BigInt;
+use serde::de::DeserializeOwned;
+
+use crate::application::contract::method::Method;
+use crate::application::unit_test::UnitTest;
+use crate::application::Application;
+use crate::data::r#type::contract_field::ContractField;
+use crate::data::r#type::scalar::integer::Type as IntegerType;
+use crate::data::r#type::scalar::Type as ScalarType;
+use crate::data::r#type::Type;
+use crate::instructions::call_library::CallLibrary;
+use crate::instructions::contract::fetch::StorageFetch;
+use crate::instructions::contract::init::StorageInit;
+use crate::instructions::contract::load::StorageLoad;
+use crate::instructions::contract::store::StorageStore;
+use crate::instructions::data_stack::load::Load;
+use crate::instructions::data_stack::load_by_index::LoadByIndex;
+use crate::instructions::data_stack::store::Store;
+use crate::instructions::data_stack::store_by_index::StoreByIndex;
+use crate::instructions::dbg::Dbg;
+use crate::instructions::evaluation_stack::copy::Copy;
+use crate::instructions::evaluation_stack::push::Push;
+use crate::instructions::evaluation_stack::slice::Slice;
+use crate::instructions::flow::call::Call;
+use crate::instructions::flow::loop_begin::LoopBegin;
+use crate::instructions::flow::loop_end::LoopEnd;
+use crate::instructions::flow::r#else::Else;
+use crate::instructions::flow::r#endif::EndIf;
+use crate::instructions::flow::r#if::If;
+use crate::instructions::flow::r#return::Return;
+use crate::instructions::marker::column::ColumnMarker;
+use crate::instructions::marker::file::FileMarker;
+use crate::instructions::marker::function::FunctionMarker;
+use crate::instructions::marker::line::LineMarker;
+use crate::instructions::marker::variable::VariableMarker;
+use crate::instructions::noop::NoOperation;
+use crate::instructions::operator::arithmetic::add::Add;
+use crate::instructions::operator::arithmetic::div::Div;
+use crate::instructions::operator::arithmetic::mul::Mul;
+use crate::instructions::operator::arithmetic::neg::Neg;
+use crate::instructions::operator::arithmetic::rem::Rem;
+use crate::instructions::operator::arithmetic::sub::Sub;
+use crate::instructions::operator::bitwise::and::BitwiseAnd;
+use crate::instructions::operator::bitwise::not::BitwiseNot;
+use crate::instructions::operator::bitwise::or::BitwiseOr;
+use crate::instructions::operator::bitwise::shift_left::BitwiseShiftLeft;
+use crate::instructions::operator::bitwise::shift_right::BitwiseShiftRight;
+use crate::instructions::operator::bitwise::xor::BitwiseXor;
+use crate::instructions::operator::cast::Cast;
+use crate::instructions::operator::comparison::eq::Eq;
+use crate::instructions::operator::comparison::ge::Ge;
+use crate::instructions::operator::comparison::gt::Gt;
+use crate::instructions::operator::comparison::le::Le;
+use crate::instructions::operator::comparison::lt::Lt;
+use crate::instructions::operator::comparison::ne::Ne;
+use crate::instructions::operator::logical::and::And;
+use crate::instructions::operator::logical::not::Not;
+use crate::instructions::operator::logical::or::Or;
+use crate::instructions::operator::logical::xor::Xor;
+use crate::instructions::require::Require;
+use crate::instructions::Instruction;
+
+use super::error::Error;
+
+///
+/// Parses the textual assembly form written by `disassemble` into an application.
+///
+pub fn assemble(text: &str) -> Result<Application, Error> {
+    let mut assembler = Assembler::default();
+    for (index, line) in text.lines().enumerate() {
+        assembler.line(index + 1, line)?;
+    }
+    assembler.finish()
+}
+
+///
+/// The application kind, which is declared by the first directive.
+///
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Kind {
+    /// The `.circuit` directive.
+    Circuit,
+    /// The `.contract` directive.
+    Contract,
+    /// The `.library` directive.
+    Library,
+}
+
+impl Kind {
+    ///
+    /// The kind name used in error messages.
+    ///
+    fn name(self) -> &'static str {
+        match self {
+            Self::Circuit => "circuit",
+            Self::Contract => "contract",
+            Self::Library => "library",
+        }
+    }
+}
+
+///
+/// The application being assembled line by line.
+///
+#[derive(Default)]
+struct Assembler {
+    /// The application kind and name.
+    kind: Option<(Kind, String)>,
+    /// The circuit entry address.
+    entry: Option<usize>,
+    /// The circuit input type.
+    input: Option<Type>,
+    /// The circuit output type.
+    output: Option<Type>,
+    /// The contract storage fields.
+    storage: Option<Vec<ContractField>>,
+    /// The contract storage hasher.
+    storage_hasher: Option<zinc_project::StorageHasher>,
+    /// The contract methods.
+    methods: HashMap<String, Method>,
+    /// The unit tests.
+    unit_tests: HashMap<String, UnitTest>,
+    /// The instructions parsed so far.
+    instructions: Vec<Instruction>,
+}
+
+impl Assembler {
+    ///
+    /// Parses a line, which is either empty, a directive, or an instruction.
+    ///
+    fn line(&mut self, number: usize, text: &str) -> Result<(), Error> {
+        let mut parser = Parser::new(number, text)?;
+
+        match parser.peek() {
+            None => Ok(()),
+            Some(Token::Word(word)) if word.starts_with('.') => {
+                let name = word[1..].to_owned();
+                parser.next();
+                self.directive(&mut parser, name)
+            }
+            Some(_) => {
+                if let (Some(Token::Word(address)), Some(Token::Symbol(':'))) =
+                    (parser.peek(), parser.peek_nth(1))
+                {
+                    let found = parser.parse::<usize>(address.as_str(), "an address")?;
+                    if found != self.instructions.len() {
+                        return Err(Error::AddressMismatch {
+                            line: number,
+                            expected: self.instructions.len(),
+                            found,
+                        });
+                    }
+                    parser.next();
+                    parser.next();
+                }
+
+                let instruction = parser.instruction()?;
+                parser.end()?;
+                self.instructions.push(instruction);
+                Ok(())
+            }
+        }
+    }
+
+    ///
+    /// Parses a header directive.
+    ///
+    fn directive(&mut self, parser: &mut Parser, name: String) -> Result<(), Error> {
+        let kind = match name.as_str() {
+            "circuit" => Some(Kind::Circuit),
+            "contract" => Some(Kind::Contract),
+            "library" => Some(Kind::Library),
+            _ => None,
+        };
+        if let Some(kind) = kind {
+            if self.kind.is_some() {
+                return Err(parser.error("the application kind is already declared"));
+            }
+            let name = parser.string()?;
+            parser.end()?;
+            self.kind = Some((kind, name));
+            return Ok(());
+        }
+
+        let kind = match self.kind {
+            Some((kind, _)) => kind,
+            None => return Err(Error::ApplicationKindMissing),
+        };
+        let expected = match name.as_str() {
+            "entry" | "input" | "output" => Some(Kind::Circuit),
+            "storage_hasher" | "storage" | "method" => Some(Kind::Contract),
+            "test" => None,
+            _ => return Err(parser.error(format!("unknown directive `.{}`", name))),
+        };
+        if matches!(expected, Some(expected) if expected != kind) {
+            return Err(Error::DirectiveUnexpected {
+                line: parser.line,
+                name,
+                kind: kind.name(),
+            });
+        }
+
+        match name.as_str() {
+            "entry" => self.entry = Some(parser.usize()?),
+            "input" => self.input = Some(parser.r#type()?),
+            "output" => self.output = Some(parser.r#type()?),
+            "storage_hasher" => self.storage_hasher = Some(parser.named("a storage hasher")?),
+            "storage" => self.storage = Some(parser.contract_fields()?),
+            "method" => {
+                let name = parser.string()?;
+                let address = parser.usize()?;
+                let type_id = parser.usize()?;
+                let is_mutable = parser.keyword("mut");
+                let input = parser.r#type()?;
+                parser.arrow()?;
+                let output = parser.r#type()?;
+
+                if self.methods.contains_key(name.as_str()) {
+                    return Err(parser.error(format!("method `{}` is already declared", name)));
+                }
+                self.methods.insert(
+                    name.clone(),
+                    Method::new(type_id, name, address, is_mutable, input, output),
+                );
+            }
+            "test" => {
+                let name = parser.string()?;
+                let address = parser.usize()?;
+                let should_panic = parser.keyword("should_panic");
+                let is_ignored = parser.keyword("ignored");
+                let zksync_msg = if parser.keyword("msg") {
+                    let msg = serde_json::from_str(parser.rest())
+                        .map_err(|error| parser.error(format!("invalid message: {}", error)))?;
+                    parser.skip_rest();
+                    Some(msg)
+                } else {
+                    None
+                };
+
+                if self.unit_tests.contains_key(name.as_str()) {
+                    return Err(parser.error(format!("test `{}` is already declared", name)));
+                }
+                self.unit_tests.insert(
+                    name,
+                    UnitTest::new(address, should_panic, is_ignored, zksync_msg),
+                );
+            }
+            _ => unreachable!(),
+        }
+
+        parser.end()
+    }
+
+    ///
+    /// Checks the mandatory directives and builds the application.
+    ///
+    fn finish(self) -> Result<Application, Error> {
+        let (kind, name) = self.kind.ok_or(Error::ApplicationKindMissing)?;
+
+        Ok(match kind {
+            Kind::Circuit => Application::new_circuit(
+                name,
+                self.entry
+                    .ok_or(Error::DirectiveMissing { name: "entry" })?,
+                self.input
+                    .ok_or(Error::DirectiveMissing { name: "input" })?,
+                self.output
+                    .ok_or(Error::DirectiveMissing { name: "output" })?,
+                self.unit_tests,
+                self.instructions,
+            ),
+            Kind::Contract => Application::new_contract(
+                name,
+                self.storage
+                    .ok_or(Error::DirectiveMissing { name: "storage" })?,
+                self.methods,
+                self.unit_tests,
+                self.instructions,
+                self.storage_hasher.unwrap_or_default(),
+            ),
+            Kind::Library => Application::new_library(name, self.unit_tests, self.instructions),
+        })
+    }
+}
+
+///
+/// The assembly line token.
+///
+#[derive(Debug, Clone, PartialEq)]
+enum Token {
+    /// A mnemonic, directive, keyword, name, or number.
+    Word(String),
+    /// A JSON-escaped string literal.
+    String(String),
+    /// A punctuation symbol.
+    Symbol(char),
+    /// The `->` symbol.
+    Arrow,
+}
+
+///
+/// The single line parser.
+///
+struct Parser<'a> {
+    /// The line number, starting from 1.
+    line: usize,
+    /// The line text.
+    text: &'a str,
+    /// The tokens with their byte offsets in the line.
+    tokens: Vec<(usize, Token)>,
+    /// The byte offset where the trailing comment starts, or the line length.
+    end: usize,
+    /// The current token index.
+    position: usize,
+}
+
+impl<'a> Parser<'a> {
+    /// The symbols, which are tokens on their own.
+    const SYMBOLS: &'static str = "{}[]()<>,:;=";
+
+    ///
+    /// Splits the line into tokens.
+    ///
+    fn new(line: usize, text: &'a str) -> Result<Self, Error> {
+        let mut parser = Self {
+            line,
+            text,
+            tokens: vec![],
+            end: text.len(),
+            position: 0,
+        };
+
+        let mut characters = text.char_indices().peekable();
+        while let Some((offset, character)) = characters.next() {
+            let next = characters.peek().map(|(_, character)| *character);
+            match character {
+                character if character.is_whitespace() => {}
+                '/' if next == Some('/') => {
+                    parser.end = offset;
+                    break;
+                }
+                '-' if next == Some('>') => {
+                    characters.next();
+                    parser.tokens.push((offset, Token::Arrow));
+                }
+                '"' => {
+                    let mut is_escaped = false;
+                    let mut end = None;
+                    for (offset, character) in characters.by_ref() {
+                        match character {
+                            '"' if !is_escaped => {
+                                end = Some(offset);
+                                break;
+                            }
+                            '\\' => is_escaped = !is_escaped,
+                            _ => is_escaped = false,
+                        }
+                    }
+                    let end = end.ok_or_else(|| parser.error("unterminated string"))?;
+                    let string = serde_json::from_str(&text[offset..=end])
+                        .map_err(|error| parser.error(format!("invalid string: {}", error)))?;
+                    parser.tokens.push((offset, Token::String(string)));
+                }
+                character if Self::SYMBOLS.contains(character) => {
+                    parser.tokens.push((offset, Token::Symbol(character)));
+                }
+                character if Self::is_word_character(character) => {
+                    let mut word = character.to_string();
+                    while let Some((_, character)) = characters.peek() {
+                        if !Self::is_word_character(*character) {
+                            break;
+                        }
+                        word.push(*character);
+                        characters.next();
+                    }
+                    parser.tokens.push((offset, Token::Word(word)));
+                }
+                character => {
+                    return Err(parser.error(format!("unexpected character `{}`", character)))
+                }
+            }
+        }
+
+        Ok(parser)
+    }
+
+    ///
+    /// Parses an instruction, starting from its mnemonic.
+    ///
+    fn instruction(&mut self) -> Result<Instruction, Error> {
+        let mnemonic = self.word()?;
+
+        Ok(match mnemonic.as_str() {
+            "noop" => NoOperation.into(),
+
+            "push" => {
+                let value = self.bigint()?;
+                self.expect_keyword("as")?;
+                Push::new(value, self.scalar_type()?).into()
+            }
+            "slice" => Slice::new(self.usize()?, self.usize()?).into(),
+            "copy" => Copy.into(),
+
+            "load" => Load::new(self.usize()?, self.usize()?).into(),
+            "load_by_index" => LoadByIndex::new(self.usize()?, self.usize()?, self.usize()?).into(),
+            "store" => Store::new(self.usize()?, self.usize()?).into(),
+            "store_by_index" => {
+                StoreByIndex::new(self.usize()?, self.usize()?, self.usize()?).into()
+            }
+
+            "storage_init" => {
+                let name = self.string()?;
+                let r#type = self.named("a project type")?;
+                let version = self.word()?;
+                let version = semver::Version::parse(version.as_str())
+                    .map_err(|error| self.error(format!("invalid version: {}", error)))?;
+                let mut project = zinc_project::ManifestProject::new(name, r#type, version);
+                project.storage_hasher = if self.keyword("-") {
+                    None
+                } else {
+                    Some(self.named("a storage hasher")?)
+                };
+                StorageInit::new(project, self.contract_fields()?).into()
+            }
+            "storage_fetch" => StorageFetch::new(self.contract_fields()?).into(),
+            "storage_store" => StorageStore::new(self.usize()?).into(),
+            "storage_load" => StorageLoad::new(self.usize()?).into(),
+
+            "add" => Add.into(),
+            "sub" => Sub.into(),
+            "mul" => Mul.into(),
+            "div" => Div.into(),
+            "rem" => Rem.into(),
+            "neg" => Neg.into(),
+
+            "not" => Not.into(),
+            "and" => And.into(),
+            "or" => Or.into(),
+            "xor" => Xor.into(),
+
+            "lt" => Lt.into(),
+            "le" => Le.into(),
+            "eq" => Eq.into(),
+            "ne" => Ne.into(),
+            "ge" => Ge.into(),
+            "gt" => Gt.into(),
+
+            "bitwise_shift_left" => BitwiseShiftLeft.into(),
+            "bitwise_shift_right" => BitwiseShiftRight.into(),
+            "bitwise_and" => BitwiseAnd.into(),
+            "bitwise_or" => BitwiseOr.into(),
+            "bitwise_xor" => BitwiseXor.into(),
+            "bitwise_not" => BitwiseNot.into(),
+
+            "cast" => Cast::new(self.scalar_type()?).into(),
+
+            "if" => If.into(),
+            "else" => Else.into(),
+            "endif" => EndIf.into(),
+            "loop_begin" => LoopBegin::new(self.usize()?).into(),
+            "loop_end" => LoopEnd.into(),
+            "call" => Call::new(self.usize()?, self.usize()?).into(),
+            "return" => Return::new(self.usize()?).into(),
+
+            "dbg" => {
+                let format = self.string()?;
+                self.symbol('(')?;
+                let mut argument_types = vec![];
+                while !self.eat_symbol(')') {
+                    if !argument_types.is_empty() {
+                        self.symbol(',')?;
+                    }
+                    argument_types.push(self.r#type()?);
+                }
+                Dbg::new(format, argument_types).into()
+            }
+            "require" => match self.peek() {
+                Some(Token::String(_)) => {
+                    let message = self.string()?;
+                    let mut argument_types = vec![];
+                    if self.eat_symbol('(') {
+                        while !self.eat_symbol(')') {
+                            if !argument_types.is_empty() {
+                                self.symbol(',')?;
+                            }
+                            argument_types.push(self.r#type()?);
+                        }
+                    }
+                    Require::new(Some(message), argument_types).into()
+                }
+                _ => Require::new(None, vec![]).into(),
+            },
+            "call_library" => CallLibrary::new(
+                self.named("a library function identifier")?,
+                self.usize()?,
+                self.usize()?,
+            )
+            .into(),
+
+            "file" => FileMarker::new(self.string()?).into(),
+            "function" => FunctionMarker::new(self.string()?).into(),
+            "line" => LineMarker::new(self.usize()?).into(),
+            "column" => ColumnMarker::new(self.usize()?).into(),
+            "variable" => VariableMarker::new(self.string()?, self.usize()?, self.r#type()?).into(),
+
+            mnemonic => return Err(self.error(format!("unknown instruction `{}`", mnemonic))),
+        })
+    }
+
+    ///
+    /// Parses a type written by the assembly writer.
+    ///
+    fn r#type(&mut self) -> Result<Type, Error> {
+        match self.next() {
+            Some(Token::Symbol('(')) => {
+                if self.eat_symbol(')') {
+                    return Ok(Type::Unit);
+                }
+                if self.eat_symbol(',') {
+                    self.symbol(')')?;
+                    return Ok(Type::Tuple(vec![]));
+                }
+
+                let mut types = vec![self.r#type()?];
+                self.symbol(',')?;
+                while !self.eat_symbol(')') {
+                    types.push(self.r#type()?);
+                    if !self.eat_symbol(',') {
+                        self.symbol(')')?;
+                        break;
+                    }
+                }
+                Ok(Type::Tuple(types))
+            }
+            Some(Token::Symbol('[')) => {
+                let r#type = self.r#type()?;
+                self.symbol(';')?;
+                let size = self.usize()?;
+                self.symbol(']')?;
+                Ok(Type::Array(Box::new(r#type), size))
+            }
+            Some(Token::Symbol('{')) => {
+                let mut fields = vec![];
+                while !self.eat_symbol('}') {
+                    if !fields.is_empty() {
+                        self.symbol(',')?;
+                    }
+                    let name = self.word()?;
+                    self.symbol(':')?;
+                    fields.push((name, self.r#type()?));
+                }
+                Ok(Type::Structure(fields))
+            }
+            Some(Token::Word(word)) if word == "enum" => {
+                self.symbol('<')?;
+                let bitlength = self.usize()?;
+                self.symbol('>')?;
+                self.symbol('{')?;
+                let mut variants = vec![];
+                let mut payloads = vec![];
+                while !self.eat_symbol('}') {
+                    if !variants.is_empty() {
+                        self.symbol(',')?;
+                    }
+                    let name = self.word()?;
+                    self.symbol('=')?;
+                    variants.push((name, self.bigint()?));
+                    if self.eat_symbol(':') {
+                        payloads.push(self.r#type()?);
+                    }
+                }
+                Ok(Type::Enumeration {
+                    bitlength,
+                    variants,
+                    payloads,
+                })
+            }
+            Some(Token::Word(word)) if word == "contract" => {
+                Ok(Type::Contract(self.contract_fields()?))
+            }
+            Some(Token::Word(word)) if word == "map" => {
+                self.symbol('<')?;
+                let key_type = self.r#type()?;
+                self.symbol(',')?;
+                let value_type = self.r#type()?;
+                self.symbol('>')?;
+                Ok(Type::Map {
+                    key_type: Box::new(key_type),
+                    value_type: Box::new(value_type),
+                })
+            }
+            Some(Token::Word(word)) => Ok(Type::Scalar(self.scalar_type_from(word.as_str())?)),
+            token => Err(self.unexpected("a type", token)),
+        }
+    }
+
+    ///
+    /// Parses a scalar type.
+    ///
+    fn scalar_type(&mut self) -> Result<ScalarType, Error> {
+        let word = self.word()?;
+        self.scalar_type_from(word.as_str())
+    }
+
+    ///
+    /// Converts the `word` into a scalar type.
+    ///
+    fn scalar_type_from(&self, word: &str) -> Result<ScalarType, Error> {
+        match word {
+            "bool" => Ok(ScalarType::Boolean),
+            "field" => Ok(ScalarType::Field),
+            word => {
+                let is_signed = match word.chars().next() {
+                    Some('u') => false,
+                    Some('i') => true,
+                    _ => return Err(self.error(format!("unknown type `{}`", word))),
+                };
+                let bitlength = word[1..]
+                    .parse()
+                    .map_err(|_| self.error(format!("unknown type `{}`", word)))?;
+                Ok(ScalarType::Integer(IntegerType::new(is_signed, bitlength)))
+            }
+        }
+    }
+
+    ///
+    /// Parses the contract storage fields in braces.
+    ///
+    fn contract_fields(&mut self) -> Result<Vec<ContractField>, Error> {
+        self.symbol('{')?;
+
+        let mut fields = vec![];
+        while !self.eat_symbol('}') {
+            if !fields.is_empty() {
+                self.symbol(',')?;
+            }
+
+            let mut is_public = false;
+            let mut is_implicit = false;
+            while let Some(Token::Word(word)) = self.peek() {
+                if self.peek_nth(1) == Some(&Token::Symbol(':')) {
+                    break;
+                }
+                match word.as_str() {
+                    "pub" => is_public = true,
+                    "implicit" => is_implicit = true,
+                    _ => break,
+                }
+                self.next();
+            }
+
+            let name = self.word()?;
+            self.symbol(':')?;
+            fields.push(ContractField::new(
+                name,
+                self.r#type()?,
+                is_public,
+                is_implicit,
+            ));
+        }
+
+        Ok(fields)
+    }
+
+    ///
+    /// Parses a unit enumeration variant by its name as it is deserialized by `serde`.
+    ///
+    fn named<T: DeserializeOwned>(&mut self, expected: &str) -> Result<T, Error> {
+        let word = self.word()?;
+        serde_json::from_value(serde_json::Value::String(word.clone()))
+            .map_err(|_| self.error(format!("expected {}, found `{}`", expected, word)))
+    }
+
+    ///
+    /// Parses a decimal unsigned integer.
+    ///
+    fn usize(&mut self) -> Result<usize, Error> {
+        let word = self.word()?;
+        self.parse(word.as_str(), "an unsigned integer")
+    }
+
+    ///
+    /// Parses a decimal integer.
+    ///
+    fn bigint(&mut self) -> Result<BigInt, Error> {
+        let word = self.word()?;
+        self.parse(word.as_str(), "an integer")
+    }
+
+    ///
+    /// Parses the `word` as a value of type `T`.
+    ///
+    fn parse<T: FromStr>(&self, word: &str, expected: &str) -> Result<T, Error> {
+        word.parse()
+            .map_err(|_| self.error(format!("expected {}, found `{}`", expected, word)))
+    }
+
+    ///
+    /// Parses a string literal.
+    ///
+    fn string(&mut self) -> Result<String, Error> {
+        match self.next() {
+            Some(Token::String(string)) => Ok(string),
+            token => Err(self.unexpected("a string", token)),
+        }
+    }
+
+    ///
+    /// Parses a word.
+    ///
+    fn word(&mut self) -> Result<String, Error> {
+        match self.next() {
+            Some(Token::Word(word)) => Ok(word),
+            token => Err(self.unexpected("a word", token)),
+        }
+    }
+
+    ///
+    /// Consumes the `keyword` if it is the next token.
+    ///
+    fn keyword(&mut self, keyword: &str) -> bool {
+        match self.peek() {
+            Some(Token::Word(word)) if word == keyword => {
+                self.next();
+                true
+            }
+            _ => false,
+        }
+    }
+
+    ///
+    /// Consumes the mandatory `keyword`.
+    ///
+    fn expect_keyword(&mut self, keyword: &str) -> Result<(), Error> {
+        if self.keyword(keyword) {
+            Ok(())
+        } else {
+            let token = self.next();
+            Err(self.unexpected(format!("`{}`", keyword).as_str(), token))
+        }
+    }
+
+    ///
+    /// Consumes the mandatory `symbol`.
+    ///
+    fn symbol(&mut self, symbol: char) -> Result<(), Error> {
+        if self.eat_symbol(symbol) {
+            Ok(())
+        } else {
+            let token = self.next();
+            Err(self.unexpected(format!("`{}`", symbol).as_str(), token))
+        }
+    }
+
+    ///
+    /// Consumes the `symbol` if it is the next token.
+    ///
+    fn eat_symbol(&mut self, symbol: char) -> bool {
+        if self.peek() == Some(&Token::Symbol(symbol)) {
+            self.next();
+            true
+        } else {
+            false
+        }
+    }
+
+    ///
+    /// Consumes the mandatory `->` symbol.
+    ///
+    fn arrow(&mut self) -> Result<(), Error> {
+        match self.next() {
+            Some(Token::Arrow) => Ok(()),
+            token => Err(self.unexpected("`->`", token)),
+        }
+    }
+
+    ///
+    /// Checks that the line has no more tokens.
+    ///
+    fn end(&mut self) -> Result<(), Error> {
+        match self.next() {
+            None => Ok(()),
+            token => Err(self.unexpected("the end of line", token)),
+        }
+    }
+
+    ///
+    /// Returns the raw line text from the current token to the comment or the line end.
+    ///
+    fn rest(&self) -> &'a str {
+        match self.tokens.get(self.position) {
+            Some((offset, _)) => &self.text[*offset..self.end],
+            None => "",
+        }
+    }
+
+    ///
+    /// Skips the remaining tokens, which have been parsed from the raw text.
+    ///
+    fn skip_rest(&mut self) {
+        self.position = self.tokens.len();
+    }
+
+    ///
+    /// Returns the next token without consuming it.
+    ///
+    fn peek(&self) -> Option<&Token> {
+        self.peek_nth(0)
+    }
+
+    ///
+    /// Returns the token `n` positions ahead without consuming it.
+    ///
+    fn peek_nth(&self, n: usize) -> Option<&Token> {
+        self.tokens.get(self.position + n).map(|(_, token)| token)
+    }
+
+    ///
+    /// Consumes the next token.
+    ///
+    fn next(&mut self) -> Option<Token> {
+        let token = self
+            .tokens
+            .get(self.position)
+            .map(|(_, token)| token.to_owned());
+        if token.is_some() {
+            self.position += 1;
+        }
+        token
+    }
+
+    ///
+    /// Creates an unexpected token error.
+    ///
+    fn unexpected(&self, expected: &str, found: Option<Token>) -> Error {
+        let found = match found {
+            Some(Token::Word(word)) => format!("`{}`", word),
+            Some(Token::String(string)) => format!("string {:?}", string),
+            Some(Token::Symbol(symbol)) => format!("`{}`", symbol),
+            Some(Token::Arrow) => "`->`".to_owned(),
+            None => "the end of line".to_owned(),
+        };
+        self.error(format!("expected {}, found {}", expected, found))
+    }
+
+    ///
+    /// Creates a syntax error at the current line.
+    ///
+    fn error<S: Into<String>>(&self, message: S) -> Error {
+        Error::Syntax {
+            line: self.line,
+            message: message.into(),
+        }
+    }
+
+    ///
+    /// Checks whether the `character` can be a part of a word.
+    ///
+    fn is_word_character(character: char) -> bool {
+        character.is_alphanumeric() || "_.-+".contains(character)
+    }
+}
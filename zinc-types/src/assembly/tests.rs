@@ -0,0 +1,241 @@
+//!
+//! The bytecode assembly tests.
+//!
+
+use std::collections::HashMap;
+
+use num::BigInt;
+
+use crate::application::contract::method::Method;
+use crate::application::unit_test::UnitTest;
+use crate::application::Application;
+use crate::data::r#type::contract_field::ContractField;
+use crate::data::r#type::scalar::integer::Type as IntegerType;
+use crate::data::r#type::scalar::Type as ScalarType;
+use crate::data::r#type::Type;
+use crate::instructions::call_library::function_identifier::LibraryFunctionIdentifier;
+use crate::instructions::call_library::CallLibrary;
+use crate::instructions::contract::fetch::StorageFetch;
+use crate::instructions::contract::init::StorageInit;
+use crate::instructions::dbg::Dbg;
+use crate::instructions::evaluation_stack::push::Push;
+use crate::instructions::flow::call::Call;
+use crate::instructions::flow::r#return::Return;
+use crate::instructions::marker::file::FileMarker;
+use crate::instructions::marker::function::FunctionMarker;
+use crate::instructions::marker::line::LineMarker;
+use crate::instructions::marker::variable::VariableMarker;
+use crate::instructions::operator::arithmetic::add::Add;
+use crate::instructions::require::Require;
+use crate::instructions::Instruction;
+use crate::transaction::msg::Msg;
+
+use super::error::Error;
+use super::parser::assemble;
+use super::writer::disassemble;
+
+fn u8() -> Type {
+    Type::Scalar(ScalarType::Integer(IntegerType::new(false, 8)))
+}
+
+fn storage() -> Vec<ContractField> {
+    vec![
+        ContractField::new("address".to_owned(), Type::eth_address(), true, true),
+        ContractField::new("value".to_owned(), u8(), false, false),
+        ContractField::new(
+            "balances".to_owned(),
+            Type::Map {
+                key_type: Box::new(Type::eth_address()),
+                value_type: Box::new(Type::Scalar(ScalarType::Field)),
+            },
+            false,
+            false,
+        ),
+    ]
+}
+
+fn contract() -> Application {
+    let input = Type::Structure(vec![
+        ("self".to_owned(), Type::Contract(storage())),
+        (
+            "pair".to_owned(),
+            Type::Tuple(vec![Type::Scalar(ScalarType::Boolean), Type::Unit]),
+        ),
+        ("single".to_owned(), Type::Tuple(vec![u8()])),
+        ("bytes".to_owned(), Type::Array(Box::new(u8()), 4)),
+        (
+            "kind".to_owned(),
+            Type::Enumeration {
+                bitlength: 8,
+                variants: vec![
+                    ("A".to_owned(), BigInt::from(0)),
+                    ("B".to_owned(), BigInt::from(5)),
+                ],
+                payloads: vec![],
+            },
+        ),
+        (
+            "maybe".to_owned(),
+            Type::Enumeration {
+                bitlength: 1,
+                variants: vec![
+                    ("None".to_owned(), BigInt::from(0)),
+                    ("Some".to_owned(), BigInt::from(1)),
+                ],
+                payloads: vec![Type::Unit, Type::Tuple(vec![u8()])],
+            },
+        ),
+    ]);
+
+    let mut project = zinc_project::ManifestProject::new(
+        "test".to_owned(),
+        zinc_project::ProjectType::Contract,
+        semver::Version::new(0, 1, 0),
+    );
+    project.storage_hasher = Some(zinc_project::StorageHasher::Rescue);
+
+    let instructions: Vec<Instruction> = vec![
+        FileMarker::new("./src/main.zn".to_owned()).into(),
+        FunctionMarker::new("add".to_owned()).into(),
+        VariableMarker::new("value".to_owned(), 0, u8()).into(),
+        LineMarker::new(2).into(),
+        Push::new(
+            BigInt::from(-42),
+            ScalarType::Integer(IntegerType::new(true, 8)),
+        )
+        .into(),
+        Dbg::new("{} and \"{}\"".to_owned(), vec![u8(), Type::Unit]).into(),
+        Dbg::new("plain".to_owned(), vec![]).into(),
+        Require::new(Some("a // b \"quoted\" {}".to_owned()), vec![u8()]).into(),
+        Require::new(None, vec![]).into(),
+        CallLibrary::new(LibraryFunctionIdentifier::CryptoSha256, 8, 256).into(),
+        StorageInit::new(project, storage()).into(),
+        StorageFetch::new(storage()).into(),
+        Call::new(14, 1).into(),
+        Return::new(1).into(),
+        Add.into(),
+        Return::new(1).into(),
+    ];
+
+    let mut methods = HashMap::new();
+    methods.insert(
+        "add".to_owned(),
+        Method::new(3, "add".to_owned(), 0, true, input, u8()),
+    );
+    let mut unit_tests = HashMap::new();
+    unit_tests.insert(
+        "default".to_owned(),
+        UnitTest::new(0, true, true, Some(Msg::default())),
+    );
+
+    Application::new_contract(
+        "test".to_owned(),
+        storage(),
+        methods,
+        unit_tests,
+        instructions,
+        zinc_project::StorageHasher::Poseidon,
+    )
+}
+
+#[test]
+fn ok_round_trip() {
+    let application = contract();
+    let mut sources = HashMap::new();
+    sources.insert(
+        "./src/main.zn".to_owned(),
+        vec!["fn add() {".to_owned(), "    dbg!(\"{}\", 1);".to_owned()],
+    );
+
+    let text = disassemble(&application, &sources);
+    assert!(text.contains("line 2"));
+    assert!(text.contains("// dbg!(\"{}\", 1);"));
+    assert!(text.contains("// function_14"));
+
+    let result = assemble(text.as_str()).expect(zinc_const::panic::TEST_DATA_VALID);
+    assert_eq!(result.instructions(), application.instructions());
+    assert_eq!(disassemble(&result, &sources), text);
+}
+
+#[test]
+fn ok_without_addresses() {
+    let text = r#"
+.circuit "test"
+.entry 0
+.input ()
+.output field
+
+push 1 as field // the comment
+return 1
+"#;
+
+    let result = assemble(text).expect(zinc_const::panic::TEST_DATA_VALID);
+    assert_eq!(
+        result.instructions(),
+        &[
+            Push::new(BigInt::from(1), ScalarType::Field).into(),
+            Return::new(1).into()
+        ]
+    );
+}
+
+#[test]
+fn error_address_mismatch() {
+    let text = r#".library "test"
+    0: add
+    2: add
+"#;
+
+    assert_eq!(
+        assemble(text).err(),
+        Some(Error::AddressMismatch {
+            line: 3,
+            expected: 1,
+            found: 2,
+        })
+    );
+}
+
+#[test]
+fn error_unknown_instruction() {
+    let text = r#".library "test"
+jump 5
+"#;
+
+    assert_eq!(
+        assemble(text).err(),
+        Some(Error::Syntax {
+            line: 2,
+            message: "unknown instruction `jump`".to_owned(),
+        })
+    );
+}
+
+#[test]
+fn error_directive_unexpected() {
+    let text = r#".library "test"
+.entry 0
+"#;
+
+    assert_eq!(
+        assemble(text).err(),
+        Some(Error::DirectiveUnexpected {
+            line: 2,
+            name: "entry".to_owned(),
+            kind: "library",
+        })
+    );
+}
+
+#[test]
+fn error_directive_missing() {
+    let text = r#".circuit "test"
+.entry 0
+.input ()
+"#;
+
+    assert_eq!(
+        assemble(text).err(),
+        Some(Error::DirectiveMissing { name: "output" })
+    );
+}
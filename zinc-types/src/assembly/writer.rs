@@ -0,0 +1,366 @@
+//!
+//! The bytecode assembly writer.
+//!
+
+use std::collections::BTreeMap;
+use std::collections::HashMap;
+use std::fmt::Write;
+
+use serde::Serialize;
+
+use crate::application::unit_test::UnitTest;
+use crate::application::Application;
+use crate::data::r#type::contract_field::ContractField;
+use crate::data::r#type::Type;
+use crate::instructions::Instruction;
+
+/// The column where the instruction comments start.
+const COMMENT_COLUMN: usize = 40;
+
+///
+/// Converts the `application` into the textual assembly form, which can be parsed back with
+/// `assemble`.
+///
+/// The `sources` map contains the source code file lines by the file marker paths. If a file is
+/// found there, its lines are printed as comments next to the line markers.
+///
+pub fn disassemble(application: &Application, sources: &HashMap<String, Vec<String>>) -> String {
+    let mut output = String::new();
+
+    match application {
+        Application::Circuit(circuit) => {
+            directive(&mut output, "circuit", string(circuit.name.as_str()));
+            directive(&mut output, "entry", circuit.address.to_string());
+            directive(&mut output, "input", r#type(&circuit.input));
+            directive(&mut output, "output", r#type(&circuit.output));
+            unit_tests(&mut output, &circuit.unit_tests);
+        }
+        Application::Contract(contract) => {
+            directive(&mut output, "contract", string(contract.name.as_str()));
+            directive(
+                &mut output,
+                "storage_hasher",
+                name(&contract.storage_hasher),
+            );
+            directive(&mut output, "storage", contract_fields(&contract.storage));
+
+            let mut methods: Vec<_> = contract.methods.values().collect();
+            methods.sort_by_key(|method| (method.address, method.name.as_str()));
+            for method in methods.into_iter() {
+                directive(
+                    &mut output,
+                    "method",
+                    format!(
+                        "{} {} {}{} {} -> {}",
+                        string(method.name.as_str()),
+                        method.address,
+                        method.type_id,
+                        if method.is_mutable { " mut" } else { "" },
+                        r#type(&method.input),
+                        r#type(&method.output),
+                    ),
+                );
+            }
+            unit_tests(&mut output, &contract.unit_tests);
+        }
+        Application::Library(library) => {
+            directive(&mut output, "library", string(library.name.as_str()));
+            unit_tests(&mut output, &library.unit_tests);
+        }
+    }
+
+    let functions = functions(application);
+    let mut file = None;
+    for (address, instruction) in application.instructions().iter().enumerate() {
+        if let Some(function) = functions.get(&address) {
+            writeln!(output, "\n// fn {}", function).expect(zinc_const::panic::DATA_CONVERSION);
+        }
+
+        let comment = match instruction {
+            Instruction::FileMarker(inner) => {
+                file = Some(inner.file.as_str());
+                None
+            }
+            Instruction::LineMarker(inner) => file
+                .and_then(|file| sources.get(file))
+                .and_then(|lines| lines.get(inner.line.saturating_sub(1)))
+                .map(|line| line.trim())
+                .filter(|line| !line.is_empty())
+                .map(str::to_owned),
+            Instruction::Call(inner) => functions.get(&inner.address).cloned(),
+            _ => None,
+        };
+
+        let line = format!("{:>5}: {}", address, self::instruction(instruction));
+        match comment {
+            Some(comment) => writeln!(
+                output,
+                "{:<width$} // {}",
+                line,
+                comment,
+                width = COMMENT_COLUMN
+            ),
+            None => writeln!(output, "{}", line),
+        }
+        .expect(zinc_const::panic::DATA_CONVERSION);
+    }
+
+    output
+}
+
+///
+/// Writes the instruction in the assembly form.
+///
+/// The instructions whose `Display` implementation is lossless are written as is.
+///
+pub fn instruction(instruction: &Instruction) -> String {
+    match instruction {
+        Instruction::StorageInit(inner) => format!(
+            "storage_init {} {} {} {} {}",
+            string(inner.project.name.as_str()),
+            name(&inner.project.r#type),
+            inner.project.version,
+            inner
+                .project
+                .storage_hasher
+                .as_ref()
+                .map(name)
+                .unwrap_or_else(|| "-".to_owned()),
+            contract_fields(&inner.field_types),
+        ),
+        Instruction::StorageFetch(inner) => {
+            format!("storage_fetch {}", contract_fields(&inner.field_types))
+        }
+
+        Instruction::Dbg(inner) => format!(
+            "dbg {} ({})",
+            string(inner.format.as_str()),
+            inner
+                .argument_types
+                .iter()
+                .map(r#type)
+                .collect::<Vec<String>>()
+                .join(", ")
+        ),
+        Instruction::Require(inner) => match inner.message {
+            Some(ref message) if inner.argument_types.is_empty() => {
+                format!("require {}", string(message.as_str()))
+            }
+            Some(ref message) => format!(
+                "require {} ({})",
+                string(message.as_str()),
+                inner
+                    .argument_types
+                    .iter()
+                    .map(r#type)
+                    .collect::<Vec<String>>()
+                    .join(", ")
+            ),
+            None => "require".to_owned(),
+        },
+        Instruction::CallLibrary(inner) => format!(
+            "call_library {} {} {}",
+            name(&inner.identifier),
+            inner.input_size,
+            inner.output_size
+        ),
+
+        Instruction::FileMarker(inner) => format!("file {}", string(inner.file.as_str())),
+        Instruction::FunctionMarker(inner) => {
+            format!("function {}", string(inner.function.as_str()))
+        }
+        Instruction::LineMarker(inner) => format!("line {}", inner.line),
+        Instruction::ColumnMarker(inner) => format!("column {}", inner.column),
+        Instruction::VariableMarker(inner) => format!(
+            "variable {} {} {}",
+            string(inner.name.as_str()),
+            inner.address,
+            r#type(&inner.r#type)
+        ),
+
+        instruction => instruction.to_string(),
+    }
+}
+
+///
+/// Writes the type in the assembly form.
+///
+/// Unlike the `Display` implementation, the form keeps all the data required to restore the type.
+///
+pub fn r#type(r#type: &Type) -> String {
+    match r#type {
+        Type::Unit => "()".to_owned(),
+        Type::Scalar(inner) => inner.to_string(),
+        Type::Enumeration {
+            bitlength,
+            variants,
+            payloads,
+        } => format!(
+            "enum<{}> {{{}}}",
+            bitlength,
+            variants
+                .iter()
+                .enumerate()
+                .map(|(index, (name, value))| match payloads.get(index) {
+                    Some(payload) => format!("{} = {}: {}", name, value, self::r#type(payload)),
+                    None => format!("{} = {}", name, value),
+                })
+                .collect::<Vec<String>>()
+                .join(", ")
+        ),
+
+        Type::Array(inner, size) => format!("[{}; {}]", self::r#type(inner), size),
+        Type::Tuple(types) => format!(
+            "({}{})",
+            types
+                .iter()
+                .map(self::r#type)
+                .collect::<Vec<String>>()
+                .join(", "),
+            if types.len() <= 1 { "," } else { "" },
+        ),
+        Type::Structure(fields) => format!(
+            "{{{}}}",
+            fields
+                .iter()
+                .map(|(name, r#type)| format!("{}: {}", name, self::r#type(r#type)))
+                .collect::<Vec<String>>()
+                .join(", ")
+        ),
+        Type::Contract(fields) => format!("contract {}", contract_fields(fields)),
+
+        Type::Map {
+            key_type,
+            value_type,
+        } => format!(
+            "map<{}, {}>",
+            self::r#type(key_type),
+            self::r#type(value_type)
+        ),
+    }
+}
+
+///
+/// Writes the contract storage fields in the assembly form.
+///
+fn contract_fields(fields: &[ContractField]) -> String {
+    format!(
+        "{{{}}}",
+        fields
+            .iter()
+            .map(|field| format!(
+                "{}{}{}: {}",
+                if field.is_public { "pub " } else { "" },
+                if field.is_implicit { "implicit " } else { "" },
+                field.name,
+                r#type(&field.r#type)
+            ))
+            .collect::<Vec<String>>()
+            .join(", ")
+    )
+}
+
+///
+/// Writes the unit test directives sorted by name.
+///
+fn unit_tests(output: &mut String, unit_tests: &HashMap<String, UnitTest>) {
+    let mut unit_tests: Vec<_> = unit_tests.iter().collect();
+    unit_tests.sort_by_key(|(name, _)| name.as_str());
+
+    for (name, unit_test) in unit_tests.into_iter() {
+        let mut arguments = format!("{} {}", string(name.as_str()), unit_test.address);
+        if unit_test.should_panic {
+            arguments.push_str(" should_panic");
+        }
+        if unit_test.is_ignored {
+            arguments.push_str(" ignored");
+        }
+        if let Some(ref msg) = unit_test.zksync_msg {
+            arguments.push_str(" msg ");
+            arguments.push_str(
+                serde_json::to_string(msg)
+                    .expect(zinc_const::panic::DATA_CONVERSION)
+                    .as_str(),
+            );
+        }
+        directive(output, "test", arguments);
+    }
+}
+
+///
+/// Collects the function names by their start addresses.
+///
+/// The functions are found by the function markers, the application entries, and the call
+/// targets. The compiler puts a file marker before the function marker, so the file marker
+/// is considered the function start.
+///
+fn functions(application: &Application) -> BTreeMap<usize, String> {
+    let instructions = application.instructions();
+    let mut functions = BTreeMap::new();
+
+    for (address, instruction) in instructions.iter().enumerate() {
+        if let Instruction::FunctionMarker(inner) = instruction {
+            let address = match address.checked_sub(1).map(|address| &instructions[address]) {
+                Some(Instruction::FileMarker(_)) => address - 1,
+                _ => address,
+            };
+            functions.insert(address, inner.function.to_owned());
+        }
+    }
+
+    let entries: Vec<(usize, &str)> = match application {
+        Application::Circuit(circuit) => vec![(circuit.address, circuit.name.as_str())],
+        Application::Contract(contract) => contract
+            .methods
+            .values()
+            .map(|method| (method.address, method.name.as_str()))
+            .collect(),
+        Application::Library(_) => vec![],
+    };
+    let unit_tests = match application {
+        Application::Circuit(circuit) => &circuit.unit_tests,
+        Application::Contract(contract) => &contract.unit_tests,
+        Application::Library(library) => &library.unit_tests,
+    };
+    for (address, name) in entries.into_iter().chain(
+        unit_tests
+            .iter()
+            .map(|(name, unit_test)| (unit_test.address, name.as_str())),
+    ) {
+        functions.entry(address).or_insert_with(|| name.to_owned());
+    }
+
+    for instruction in instructions.iter() {
+        if let Instruction::Call(inner) = instruction {
+            functions
+                .entry(inner.address)
+                .or_insert_with(|| format!("function_{}", inner.address));
+        }
+    }
+
+    functions
+}
+
+///
+/// Writes a header directive line.
+///
+fn directive(output: &mut String, name: &str, arguments: String) {
+    writeln!(output, ".{} {}", name, arguments).expect(zinc_const::panic::DATA_CONVERSION);
+}
+
+///
+/// Writes the string as a JSON string literal, which is how the assembly strings are escaped.
+///
+fn string(value: &str) -> String {
+    serde_json::to_string(value).expect(zinc_const::panic::DATA_CONVERSION)
+}
+
+///
+/// Writes the name of a unit enumeration variant as it is serialized by `serde`.
+///
+fn name<T: Serialize>(value: &T) -> String {
+    match serde_json::to_value(value).expect(zinc_const::panic::DATA_CONVERSION) {
+        serde_json::Value::String(name) => name,
+        value => panic!("{}{}", zinc_const::panic::DATA_CONVERSION, value),
+    }
+}
@@ -28,11 +28,16 @@ pub enum Type {
     /// See the inner element description.
     Scalar(ScalarType),
     /// The `enum` type, which is specified in the input JSON file using variant name strings.
+    ///
+    /// A data-carrying enumeration is laid out as the variant tag followed by a union of
+    /// `field` cells, which is large enough to hold the largest variant payload.
     Enumeration {
         /// The enumeration type bitlength.
         bitlength: usize,
         /// The variant list.
         variants: Vec<(String, BigInt)>,
+        /// The variant payload types, which are empty for the C-like enumerations.
+        payloads: Vec<Type>,
     },
 
     /// The array type.
@@ -76,8 +81,17 @@ impl Type {
         match self {
             Self::Unit => vec![],
             Self::Scalar(scalar_type) => vec![scalar_type],
-            Self::Enumeration { bitlength, .. } => {
-                vec![ScalarType::Integer(IntegerType::new(false, bitlength))]
+            Self::Enumeration {
+                bitlength,
+                payloads,
+                ..
+            } => {
+                let mut types = vec![ScalarType::Integer(IntegerType::new(false, bitlength))];
+                types.extend(vec![
+                    ScalarType::Field;
+                    Self::enumeration_union_size(payloads.as_slice())
+                ]);
+                types
             }
 
             Self::Array(r#type, size) => vec![Self::into_flat_scalar_types(*r#type); size]
@@ -117,6 +131,21 @@ impl Type {
         ])
     }
 
+    ///
+    /// Wraps the type into a structure, which consists of the contract storage root hashes before
+    /// and after the method call and the method output result between them, which is the order of
+    /// the contract method proof public input.
+    ///
+    /// Used only for the contract method proofs.
+    ///
+    pub fn into_contract_proof_output(self) -> Self {
+        Self::Structure(vec![
+            ("initial_root_hash".to_owned(), Self::Scalar(ScalarType::Field)),
+            ("result".to_owned(), self),
+            ("root_hash".to_owned(), Self::Scalar(ScalarType::Field)),
+        ])
+    }
+
     ///
     /// Returns the type size.
     ///
@@ -124,7 +153,9 @@ impl Type {
         match self {
             Self::Unit => 0,
             Self::Scalar(_) => 1,
-            Self::Enumeration { .. } => 1,
+            Self::Enumeration { payloads, .. } => {
+                1 + Self::enumeration_union_size(payloads.as_slice())
+            }
 
             Self::Array(r#type, size) => r#type.size() * *size,
             Self::Tuple(fields) => fields.iter().map(Self::size).sum(),
@@ -135,6 +166,14 @@ impl Type {
         }
     }
 
+    ///
+    /// Returns the size of the data-carrying enumeration payload union, which is the size of the
+    /// largest variant payload.
+    ///
+    pub fn enumeration_union_size(payloads: &[Self]) -> usize {
+        payloads.iter().map(Self::size).max().unwrap_or_default()
+    }
+
     ///
     /// Changes the first argument from the contract instance to a contract address.
     ///
@@ -157,12 +196,28 @@ impl fmt::Display for Type {
         match self {
             Self::Unit => write!(f, "()"),
             Self::Scalar(inner) => write!(f, "{}", inner),
-            Self::Enumeration { variants, .. } => write!(
+            Self::Enumeration {
+                variants, payloads, ..
+            } => write!(
                 f,
                 "enum {}",
                 variants
                     .iter()
-                    .map(|(name, value)| format!("{} = {}", name, value))
+                    .enumerate()
+                    .map(|(index, (name, value))| match payloads.get(index) {
+                        Some(Self::Unit) | None => format!("{} = {}", name, value),
+                        Some(Self::Structure(fields)) => format!(
+                            "{} {{ {} }} = {}",
+                            name,
+                            fields
+                                .iter()
+                                .map(|(name, r#type)| format!("{}: {}", name, r#type))
+                                .collect::<Vec<String>>()
+                                .join(", "),
+                            value
+                        ),
+                        Some(payload) => format!("{}{} = {}", name, payload, value),
+                    })
                     .collect::<Vec<String>>()
                     .join(", ")
             ),
@@ -42,6 +42,18 @@ pub enum Value {
         /// The enumeration variant value.
         value: ScalarValue,
     },
+    /// A data-carrying enumeration value, represented with its variant name string if the
+    /// variant has no payload, or with a single-key JSON object `{ "Variant": payload }`.
+    EnumerationData {
+        /// The enumeration variant name.
+        name: String,
+        /// The enumeration variant tag value.
+        tag: ScalarValue,
+        /// The enumeration variant payload value.
+        payload: Box<Value>,
+        /// The number of unused union cells after the payload.
+        padding: usize,
+    },
 
     /// Represented with JSON array.
     Array(Vec<Value>),
@@ -71,18 +83,22 @@ impl Value {
             Type::Enumeration {
                 bitlength,
                 mut variants,
+                mut payloads,
             } => {
+                let union_size = Type::enumeration_union_size(payloads.as_slice());
                 let (name, value) = variants.remove(0);
+                let value = Self::enumeration_tag(value, bitlength);
 
-                match bitlength {
-                    zinc_const::bitlength::FIELD => Self::Enumeration {
+                if payloads.is_empty() {
+                    Self::Enumeration { name, value }
+                } else {
+                    let payload = payloads.remove(0);
+                    Self::EnumerationData {
                         name,
-                        value: ScalarValue::Field(value),
-                    },
-                    bitlength => Self::Enumeration {
-                        name,
-                        value: ScalarValue::Integer(value, IntegerType::new(false, bitlength)),
-                    },
+                        tag: value,
+                        padding: union_size - payload.size(),
+                        payload: Box::new(Self::new(payload)),
+                    }
                 }
             }
 
@@ -115,7 +131,14 @@ impl Value {
             Type::Enumeration {
                 bitlength,
                 variants,
-            } => Self::enumeration_from_json(value, bitlength, variants),
+                payloads,
+            } => {
+                if payloads.is_empty() {
+                    Self::enumeration_from_json(value, bitlength, variants)
+                } else {
+                    Self::enumeration_data_from_json(value, bitlength, variants, payloads)
+                }
+            }
 
             Type::Array(inner, size) => Self::array_from_json(value, *inner, size),
             Type::Tuple(inner) => Self::tuple_from_json(value, inner),
@@ -154,15 +177,36 @@ impl Value {
                     .map(Self::Scalar),
             }
             .expect(zinc_const::panic::VALUE_ALWAYS_EXISTS),
-            Type::Enumeration { bitlength, .. } => flat_values
-                .first()
-                .cloned()
-                .map(|value| match bitlength {
-                    zinc_const::bitlength::FIELD => ScalarValue::Field(value),
-                    bitlength => ScalarValue::Integer(value, IntegerType::new(false, bitlength)),
-                })
-                .map(Self::Scalar)
-                .expect(zinc_const::panic::VALUE_ALWAYS_EXISTS),
+            Type::Enumeration {
+                bitlength,
+                variants,
+                mut payloads,
+            } => {
+                let tag = flat_values
+                    .first()
+                    .cloned()
+                    .expect(zinc_const::panic::VALUE_ALWAYS_EXISTS);
+
+                if payloads.is_empty() {
+                    return Self::Scalar(Self::enumeration_tag(tag, bitlength));
+                }
+
+                let union_size = Type::enumeration_union_size(payloads.as_slice());
+                let index = variants
+                    .iter()
+                    .position(|(_name, value)| value == &tag)
+                    .unwrap_or_default();
+                let name = variants[index].0.to_owned();
+                let payload = payloads.remove(index);
+                let padding = union_size - payload.size();
+
+                Self::EnumerationData {
+                    name,
+                    tag: Self::enumeration_tag(tag, bitlength),
+                    payload: Box::new(Self::from_flat_values(payload, &flat_values[1..])),
+                    padding,
+                }
+            }
             Type::Array(r#type, size) => {
                 let mut offset = 0;
                 let mut result = Vec::with_capacity(size);
@@ -224,6 +268,17 @@ impl Value {
             Self::Unit => vec![],
             Self::Scalar(value) => vec![value.to_bigint()],
             Self::Enumeration { name: _, value } => vec![value.to_bigint()],
+            Self::EnumerationData {
+                name: _,
+                tag,
+                payload,
+                padding,
+            } => {
+                let mut values = vec![tag.to_bigint()];
+                values.extend(payload.into_flat_values());
+                values.extend(vec![BigInt::zero(); padding]);
+                values
+            }
 
             Self::Array(values) => values
                 .into_iter()
@@ -268,6 +323,14 @@ impl Value {
                 ScalarValue::Boolean(value) => serde_json::Value::Bool(value),
             },
             Self::Enumeration { name, value: _ } => serde_json::Value::String(name),
+            Self::EnumerationData { name, payload, .. } => match *payload {
+                Self::Unit => serde_json::Value::String(name),
+                payload => {
+                    let mut object = serde_json::Map::<String, serde_json::Value>::with_capacity(1);
+                    object.insert(name, payload.into_json());
+                    serde_json::Value::Object(object)
+                }
+            },
 
             Self::Array(values) => {
                 serde_json::Value::Array(values.into_iter().map(Self::into_json).collect())
@@ -416,6 +479,66 @@ impl Value {
         }
     }
 
+    ///
+    /// Creates a data-carrying enumeration value from the JSON `value`.
+    ///
+    fn enumeration_data_from_json(
+        value: serde_json::Value,
+        bitlength: usize,
+        mut variants: Vec<(String, BigInt)>,
+        mut payloads: Vec<Type>,
+    ) -> anyhow::Result<Self> {
+        let expected = variants
+            .iter()
+            .map(|(name, _value)| name.to_owned())
+            .collect::<Vec<String>>()
+            .join(" | ");
+
+        let (name, payload) = match value {
+            serde_json::Value::String(name) => (name, serde_json::Value::Null),
+            serde_json::Value::Object(object) if object.len() == 1 => object
+                .into_iter()
+                .next()
+                .expect(zinc_const::panic::VALUE_ALWAYS_EXISTS),
+            value => anyhow::bail!(Error::TypeError {
+                expected,
+                found: value.to_string(),
+            }),
+        };
+
+        let index = match variants
+            .iter()
+            .position(|(variant_name, _value)| variant_name == &name)
+        {
+            Some(index) => index,
+            None => anyhow::bail!(Error::UnexpectedVariant(name)),
+        };
+
+        let union_size = Type::enumeration_union_size(payloads.as_slice());
+        let payload_type = payloads.remove(index);
+        let padding = union_size - payload_type.size();
+        let payload =
+            Self::try_from_typed_json(payload, payload_type).with_context(|| name.clone())?;
+        let tag = variants.swap_remove(index).1;
+
+        Ok(Self::EnumerationData {
+            name,
+            tag: Self::enumeration_tag(tag, bitlength),
+            payload: Box::new(payload),
+            padding,
+        })
+    }
+
+    ///
+    /// Creates an enumeration tag scalar value of the enumeration `bitlength`.
+    ///
+    fn enumeration_tag(value: BigInt, bitlength: usize) -> ScalarValue {
+        match bitlength {
+            zinc_const::bitlength::FIELD => ScalarValue::Field(value),
+            bitlength => ScalarValue::Integer(value, IntegerType::new(false, bitlength)),
+        }
+    }
+
     ///
     /// Creates a field value from the JSON `value`.
     ///
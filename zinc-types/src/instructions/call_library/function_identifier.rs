@@ -16,6 +16,30 @@ pub enum LibraryFunctionIdentifier {
     CryptoPedersen,
     /// The `std::crypto::schnorr::Signature::verify` function identifier.
     CryptoSchnorrSignatureVerify,
+    /// The `std::crypto::blake2s` function identifier.
+    CryptoBlake2s,
+    /// The `std::crypto::poseidon` function identifier.
+    CryptoPoseidon,
+    /// The `std::crypto::rescue` function identifier.
+    CryptoRescue,
+    /// The `std::crypto::keccak256` function identifier.
+    CryptoKeccak256,
+    /// The `std::crypto::ecdsa::verify` function identifier.
+    CryptoEcdsaVerify,
+    /// The `std::crypto::eddsa::Signature::verify` function identifier.
+    CryptoEddsaSignatureVerify,
+    /// The `std::crypto::ecc::Point::add` function identifier.
+    CryptoEccPointAdd,
+    /// The `std::crypto::ecc::Point::double` function identifier.
+    CryptoEccPointDouble,
+    /// The `std::crypto::ecc::Point::mul` function identifier.
+    CryptoEccPointMul,
+    /// The `std::crypto::ecc::Point::generator` function identifier.
+    CryptoEccPointGenerator,
+    /// The `std::crypto::ecc::Point::is_on_curve` function identifier.
+    CryptoEccPointIsOnCurve,
+    /// The `std::crypto::ecc::Point::negate` function identifier.
+    CryptoEccPointNegate,
 
     /// The `std::convert::to_bits` function identifier.
     ConvertToBits,
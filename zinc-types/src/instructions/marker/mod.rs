@@ -6,3 +6,4 @@ pub mod column;
 pub mod file;
 pub mod function;
 pub mod line;
+pub mod variable;
@@ -0,0 +1,63 @@
+//!
+//! The `variable marker` instruction.
+//!
+
+use std::fmt;
+
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::data::r#type::Type;
+use crate::instructions::Instruction;
+
+///
+/// The `variable marker` debug instruction.
+///
+/// Is written where a named variable is allocated, so the debuggers may find and print
+/// the variables of the current function.
+///
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct VariableMarker {
+    /// The source code variable name.
+    pub name: String,
+    /// The variable address in the function data stack frame.
+    pub address: usize,
+    /// The variable type.
+    pub r#type: Type,
+}
+
+impl VariableMarker {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(name: String, address: usize, r#type: Type) -> Self {
+        Self {
+            name,
+            address,
+            r#type,
+        }
+    }
+
+    ///
+    /// If the instruction is for the debug mode only.
+    ///
+    pub fn is_debug(&self) -> bool {
+        true
+    }
+}
+
+impl Into<Instruction> for VariableMarker {
+    fn into(self) -> Instruction {
+        Instruction::VariableMarker(self)
+    }
+}
+
+impl fmt::Display for VariableMarker {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "marker: variable = \"{}\", address = {}, type = {}",
+            self.name, self.address, self.r#type
+        )
+    }
+}
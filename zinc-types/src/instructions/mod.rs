@@ -42,6 +42,7 @@ use self::marker::column::ColumnMarker;
 use self::marker::file::FileMarker;
 use self::marker::function::FunctionMarker;
 use self::marker::line::LineMarker;
+use self::marker::variable::VariableMarker;
 use self::noop::NoOperation;
 use self::operator::arithmetic::add::Add;
 use self::operator::arithmetic::div::Div;
@@ -182,6 +183,8 @@ pub enum Instruction {
     LineMarker(LineMarker),
     /// A debug location marker instruction.
     ColumnMarker(ColumnMarker),
+    /// A debug variable marker instruction.
+    VariableMarker(VariableMarker),
 }
 
 impl Instruction {
@@ -250,6 +253,7 @@ impl Instruction {
             Self::FunctionMarker(inner) => inner.is_debug(),
             Self::LineMarker(inner) => inner.is_debug(),
             Self::ColumnMarker(inner) => inner.is_debug(),
+            Self::VariableMarker(inner) => inner.is_debug(),
         }
     }
 }
@@ -317,6 +321,7 @@ impl fmt::Display for Instruction {
             Self::FunctionMarker(inner) => write!(f, "{}", inner),
             Self::LineMarker(inner) => write!(f, "{}", inner),
             Self::ColumnMarker(inner) => write!(f, "{}", inner),
+            Self::VariableMarker(inner) => write!(f, "{}", inner),
         }
     }
 }
@@ -7,6 +7,7 @@ use std::fmt;
 use serde::Deserialize;
 use serde::Serialize;
 
+use crate::data::r#type::Type;
 use crate::instructions::Instruction;
 
 ///
@@ -14,16 +15,21 @@ use crate::instructions::Instruction;
 ///
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct Require {
-    /// The optional error message.
+    /// The optional error message with `{}` placeholders.
     pub message: Option<String>,
+    /// The types of the values that must be interpolated into the message on failure.
+    pub argument_types: Vec<Type>,
 }
 
 impl Require {
     ///
     /// A shortcut constructor.
     ///
-    pub fn new(message: Option<String>) -> Self {
-        Self { message }
+    pub fn new(message: Option<String>, argument_types: Vec<Type>) -> Self {
+        Self {
+            message,
+            argument_types,
+        }
     }
 
     ///
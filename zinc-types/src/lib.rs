@@ -3,6 +3,7 @@
 //!
 
 pub(crate) mod application;
+pub(crate) mod assembly;
 pub(crate) mod build;
 pub(crate) mod data;
 pub(crate) mod error;
@@ -18,6 +19,9 @@ pub use self::application::contract::Contract;
 pub use self::application::library::Library;
 pub use self::application::unit_test::UnitTest;
 pub use self::application::Application;
+pub use self::assembly::error::Error as AssemblyError;
+pub use self::assembly::parser::assemble;
+pub use self::assembly::writer::disassemble;
 pub use self::build::input::Input as InputBuild;
 pub use self::build::Build;
 pub use self::data::r#type::contract_field::ContractField as ContractFieldType;
@@ -53,6 +57,7 @@ pub use self::instructions::marker::column::ColumnMarker;
 pub use self::instructions::marker::file::FileMarker;
 pub use self::instructions::marker::function::FunctionMarker;
 pub use self::instructions::marker::line::LineMarker;
+pub use self::instructions::marker::variable::VariableMarker;
 pub use self::instructions::noop::NoOperation;
 pub use self::instructions::operator::arithmetic::add::Add;
 pub use self::instructions::operator::arithmetic::div::Div;
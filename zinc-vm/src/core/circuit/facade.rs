@@ -2,16 +2,27 @@
 //! The virtual machine circuit facade.
 //!
 
+use std::marker::PhantomData;
+
 use colored::Colorize;
 use num::BigInt;
 
+use franklin_crypto::bellman::groth16;
+use franklin_crypto::bellman::groth16::Parameters;
+use franklin_crypto::bellman::groth16::Proof;
 use franklin_crypto::bellman::pairing::bn256::Bn256;
 
 use zinc_const::UnitTestExitCode;
 
 use crate::constraint_systems::main::Main as MainCS;
 use crate::core::circuit::output::Output as CircuitOutput;
+use crate::core::circuit::synthesizer::Synthesizer as CircuitSynthesizer;
 use crate::core::circuit::State as CircuitState;
+use crate::core::debugger::Debugger;
+use crate::core::debugger::IDebuggee;
+use crate::core::debugger::IFrontend;
+use crate::core::profiler::Profile;
+use crate::core::profiler::Profiler;
 use crate::core::virtual_machine::IVirtualMachine;
 use crate::error::Error;
 use crate::IEngine;
@@ -26,6 +37,29 @@ impl Facade {
     }
 
     pub fn run<E: IEngine>(self, input: zinc_types::Value) -> Result<CircuitOutput, Error> {
+        self.run_with_callback::<E, _>(input, |_state| Ok(()))
+    }
+
+    ///
+    /// Runs the circuit, stopping at the source code lines according to the `debugger`.
+    ///
+    pub fn debug<E: IEngine, F: IFrontend>(
+        self,
+        input: zinc_types::Value,
+        debugger: &mut Debugger<F>,
+    ) -> Result<CircuitOutput, Error> {
+        self.run_with_callback::<E, _>(input, |state| debugger.on_instruction(state))
+    }
+
+    fn run_with_callback<E, CB>(
+        self,
+        input: zinc_types::Value,
+        mut callback: CB,
+    ) -> Result<CircuitOutput, Error>
+    where
+        E: IEngine,
+        CB: FnMut(&dyn IDebuggee<Bn256>) -> Result<(), Error>,
+    {
         let cs = MainCS::<Bn256>::new();
 
         let inputs_flat = input.into_flat_values();
@@ -37,10 +71,11 @@ impl Facade {
         let result = state.run(
             self.inner,
             Some(&inputs_flat),
-            |cs| {
+            |cs, _address, state| {
                 let num = cs.num_constraints() - num_constraints;
                 num_constraints += num;
                 log::trace!("Constraints: {}", num);
+                callback(state)
             },
             |cs| {
                 if !cs.is_satisfied() {
@@ -62,6 +97,118 @@ impl Facade {
         Ok(CircuitOutput::new(output_value))
     }
 
+    ///
+    /// Runs the circuit, attributing the constraints to the source code lines.
+    ///
+    pub fn profile<E: IEngine>(self, input: zinc_types::Value) -> Result<Profile, Error> {
+        let cs = MainCS::<Bn256>::new();
+
+        let inputs_flat = input.into_flat_values();
+
+        let mut state = CircuitState::new(cs);
+
+        let mut profiler = Profiler::new(self.inner.instructions.as_slice());
+        state.run(
+            self.inner,
+            Some(&inputs_flat),
+            |cs, address, _state| {
+                profiler.record(address, cs.num_constraints(), cs.num_witnesses());
+                Ok(())
+            },
+            |cs| {
+                if !cs.is_satisfied() {
+                    return Err(Error::UnsatisfiedConstraint);
+                }
+
+                Ok(())
+            },
+        )?;
+
+        Ok(profiler.finish())
+    }
+
+    pub fn setup<E: IEngine>(self) -> Result<Parameters<E>, Error> {
+        let rng = &mut rand::thread_rng();
+        let mut result = None;
+        let synthesizable = CircuitSynthesizer {
+            inputs: None,
+            output: &mut result,
+            bytecode: self.inner,
+
+            _pd: PhantomData,
+        };
+
+        let params = groth16::generate_random_parameters::<E, _, _>(synthesizable, rng)?;
+
+        match result.expect(zinc_const::panic::VALUE_ALWAYS_EXISTS) {
+            Ok(_) => Ok(params),
+            Err(error) => Err(error),
+        }
+    }
+
+    pub fn prove<E: IEngine>(
+        self,
+        params: Parameters<E>,
+        input: zinc_types::Value,
+    ) -> Result<(zinc_types::Value, Proof<E>), Error> {
+        let rng = &mut rand::thread_rng();
+        let mut result = None;
+
+        let inputs_flat = input.into_flat_values();
+        let output_type = self.inner.output.clone();
+
+        let synthesizable = CircuitSynthesizer {
+            inputs: Some(inputs_flat),
+            output: &mut result,
+            bytecode: self.inner,
+
+            _pd: PhantomData,
+        };
+
+        let proof = groth16::create_random_proof(synthesizable, &params, rng)
+            .map_err(Error::SynthesisError)?;
+
+        match result {
+            None => Err(Error::InternalError(
+                "circuit hasn't generated outputs".into(),
+            )),
+            Some(Err(error)) => Err(error),
+            Some(Ok(result)) => {
+                let output_flat: Vec<BigInt> =
+                    result.into_iter().filter_map(|value| value).collect();
+                let output_value = zinc_types::Value::from_flat_values(output_type, &output_flat);
+
+                Ok((output_value, proof))
+            }
+        }
+    }
+
+    ///
+    /// Runs the unit test `name`, stopping at the source code lines according to the `debugger`.
+    ///
+    pub fn debug_test<E: IEngine, F: IFrontend>(
+        self,
+        name: &str,
+        debugger: &mut Debugger<F>,
+    ) -> Result<(), Error> {
+        let unit_test =
+            self.inner
+                .unit_tests
+                .get(name)
+                .cloned()
+                .ok_or_else(|| Error::UnitTestNotFound {
+                    name: name.to_owned(),
+                })?;
+
+        let cs = MainCS::<Bn256>::new();
+
+        let mut state = CircuitState::new(cs);
+
+        state.test(self.inner, unit_test.address, |_, _, state| {
+            debugger.on_instruction(state)
+        })
+    }
+
     pub fn test<E: IEngine>(self) -> Result<UnitTestExitCode, Error> {
         let mut exit_code = UnitTestExitCode::Passed;
 
@@ -75,7 +222,7 @@ impl Facade {
 
             let mut state = CircuitState::new(cs);
 
-            match state.test(self.inner.clone(), unit_test.address) {
+            match state.test(self.inner.clone(), unit_test.address, |_, _, _| Ok(())) {
                 Err(_) if unit_test.should_panic => {
                     log::info!("test {} ... {} (failed)", name, "ok".green());
                 }
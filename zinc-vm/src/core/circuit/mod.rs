@@ -15,6 +15,7 @@ use franklin_crypto::bellman::ConstraintSystem;
 use crate::core::contract::storage::leaf::LeafVariant;
 use crate::core::contract::storage::setup::Storage as SetupStorage;
 use crate::core::counter::NamespaceCounter;
+use crate::core::debugger::IDebuggee;
 use crate::core::execution_state::block::branch::Branch;
 use crate::core::execution_state::block::r#loop::Loop;
 use crate::core::execution_state::block::Block;
@@ -26,6 +27,7 @@ use crate::core::virtual_machine::IVirtualMachine;
 use crate::error::Error;
 use crate::error::MalformedBytecode;
 use crate::gadgets;
+use crate::gadgets::contract::merkle_tree::hasher::sha256::Hasher as Sha256Hasher;
 use crate::gadgets::scalar::Scalar;
 use crate::instructions::call_library::INativeCallable;
 use crate::instructions::IExecutable;
@@ -66,7 +68,7 @@ where
         mut check_cs: F,
     ) -> Result<Vec<Option<BigInt>>, Error>
     where
-        CB: FnMut(&CS),
+        CB: FnMut(&CS, usize, &Self) -> Result<(), Error>,
         F: FnMut(&CS) -> Result<(), Error>,
     {
         self.counter.cs.enforce(
@@ -96,6 +98,7 @@ where
                 step, self.execution_state.instruction_counter
             );
             self.counter.cs.push_namespace(|| namespace);
+            let address = self.execution_state.instruction_counter;
             let instruction =
                 circuit.instructions[self.execution_state.instruction_counter].clone();
 
@@ -113,7 +116,7 @@ where
             }
 
             log::trace!("{}", self.execution_state);
-            instruction_callback(&self.counter.cs);
+            instruction_callback(&self.counter.cs, address, self)?;
             self.counter.cs.pop_namespace();
             step += 1;
         }
@@ -121,7 +124,15 @@ where
         self.get_outputs()
     }
 
-    pub fn test(&mut self, circuit: zinc_types::Circuit, address: usize) -> Result<(), Error> {
+    pub fn test<CB>(
+        &mut self,
+        circuit: zinc_types::Circuit,
+        address: usize,
+        mut instruction_callback: CB,
+    ) -> Result<(), Error>
+    where
+        CB: FnMut(&CS, usize, &Self) -> Result<(), Error>,
+    {
         self.counter.cs.enforce(
             || "ONE * ONE = ONE (do this to avoid `unconstrained` error)",
             |zero| zero + CS::one(),
@@ -145,6 +156,7 @@ where
                 step, self.execution_state.instruction_counter
             );
             self.counter.cs.push_namespace(|| namespace);
+            let address = self.execution_state.instruction_counter;
             let instruction =
                 circuit.instructions[self.execution_state.instruction_counter].clone();
 
@@ -162,6 +174,7 @@ where
             }
 
             log::trace!("{}", self.execution_state);
+            instruction_callback(&self.counter.cs, address, self)?;
             self.counter.cs.pop_namespace();
             step += 1;
         }
@@ -225,6 +238,20 @@ where
     }
 }
 
+impl<E, CS> IDebuggee<E> for State<E, CS>
+where
+    E: IEngine,
+    CS: ConstraintSystem<E>,
+{
+    fn execution_state(&self) -> &ExecutionState<E> {
+        &self.execution_state
+    }
+
+    fn storages(&self) -> Vec<(BigInt, zinc_types::Value)> {
+        vec![]
+    }
+}
+
 impl<E, CS> IVirtualMachine for State<E, CS>
 where
     E: IEngine,
@@ -232,7 +259,8 @@ where
 {
     type E = E;
     type CS = CS;
-    type S = SetupStorage<E>;
+    type S = SetupStorage<E, Sha256Hasher>;
+    type H = Sha256Hasher;
 
     fn push(&mut self, cell: Cell<E>) -> Result<(), Error> {
         self.execution_state.evaluation_stack.push(cell)
@@ -468,7 +496,7 @@ where
         Ok(())
     }
 
-    fn call_native<F: INativeCallable<E, SetupStorage<E>>>(
+    fn call_native<F: INativeCallable<E, SetupStorage<E, Sha256Hasher>, Sha256Hasher>>(
         &mut self,
         function: F,
     ) -> Result<(), Error> {
@@ -3,12 +3,16 @@
 //!
 
 use std::collections::HashMap;
+use std::marker::PhantomData;
 
 use colored::Colorize;
 use num::BigInt;
+use num::Zero;
 
+use franklin_crypto::bellman::groth16;
+use franklin_crypto::bellman::groth16::Parameters;
+use franklin_crypto::bellman::groth16::Proof;
 use franklin_crypto::bellman::pairing::bn256::Bn256;
-use franklin_crypto::bellman::ConstraintSystem;
 
 use zinc_const::UnitTestExitCode;
 
@@ -19,10 +23,20 @@ use crate::core::contract::output::Output as ContractOutput;
 use crate::core::contract::storage::database::Storage as DatabaseStorage;
 use crate::core::contract::storage::keeper::DummyKeeper;
 use crate::core::contract::storage::keeper::IKeeper;
+use crate::core::contract::storage::setup::Storage as SetupStorage;
+use crate::core::contract::synthesizer::Synthesizer as ContractSynthesizer;
 use crate::core::contract::State as ContractState;
+use crate::core::debugger::Debugger;
+use crate::core::debugger::IDebuggee;
+use crate::core::debugger::IFrontend;
+use crate::core::profiler::Profile;
+use crate::core::profiler::Profiler;
 use crate::core::virtual_machine::IVirtualMachine;
 use crate::error::Error;
+use crate::gadgets::contract::merkle_tree::hasher::poseidon::Hasher as PoseidonHasher;
+use crate::gadgets::contract::merkle_tree::hasher::rescue::Hasher as RescueHasher;
 use crate::gadgets::contract::merkle_tree::hasher::sha256::Hasher as Sha256Hasher;
+use crate::gadgets::contract::merkle_tree::hasher::IHasher as IMerkleTreeHasher;
 use crate::gadgets::contract::merkle_tree::IMerkleTree;
 use crate::gadgets::contract::storage::StorageGadget;
 use crate::IEngine;
@@ -51,7 +65,53 @@ impl Facade {
     }
 
     pub fn run<E: IEngine>(self, input: ContractInput) -> Result<ContractOutput, Error> {
-        let mut cs = ConstantCS {};
+        match self.inner.storage_hasher {
+            zinc_project::StorageHasher::Sha256 => {
+                self.run_with_hasher::<E, Sha256Hasher, _>(input, |_state| Ok(()))
+            }
+            zinc_project::StorageHasher::Rescue => {
+                self.run_with_hasher::<E, RescueHasher, _>(input, |_state| Ok(()))
+            }
+            zinc_project::StorageHasher::Poseidon => {
+                self.run_with_hasher::<E, PoseidonHasher, _>(input, |_state| Ok(()))
+            }
+        }
+    }
+
+    ///
+    /// Runs the contract method, stopping at the source code lines according to the `debugger`.
+    ///
+    pub fn debug<E: IEngine, F: IFrontend>(
+        self,
+        input: ContractInput,
+        debugger: &mut Debugger<F>,
+    ) -> Result<ContractOutput, Error> {
+        let callback = |state: &dyn IDebuggee<Bn256>| debugger.on_instruction(state);
+
+        match self.inner.storage_hasher {
+            zinc_project::StorageHasher::Sha256 => {
+                self.run_with_hasher::<E, Sha256Hasher, _>(input, callback)
+            }
+            zinc_project::StorageHasher::Rescue => {
+                self.run_with_hasher::<E, RescueHasher, _>(input, callback)
+            }
+            zinc_project::StorageHasher::Poseidon => {
+                self.run_with_hasher::<E, PoseidonHasher, _>(input, callback)
+            }
+        }
+    }
+
+    fn run_with_hasher<E, H, CB>(
+        self,
+        input: ContractInput,
+        mut callback: CB,
+    ) -> Result<ContractOutput, Error>
+    where
+        E: IEngine,
+        H: IMerkleTreeHasher<Bn256>,
+        CB: FnMut(&dyn IDebuggee<Bn256>) -> Result<(), Error>,
+    {
+        let cs = ConstantCS {};
 
         let method = self
             .inner
@@ -62,6 +122,7 @@ impl Facade {
                 found: input.method_name.clone(),
             })?;
         let arguments_flat = input.arguments.into_flat_values();
+        let address = Self::instance_address(&method, arguments_flat.as_slice());
         let output_type = if method.is_mutable {
             method.output.into_mutable_method_output()
         } else if method.name.as_str() == zinc_const::contract::CONSTRUCTOR_IDENTIFIER {
@@ -75,24 +136,24 @@ impl Facade {
             for (address, storage) in input.storages.into_iter() {
                 let address = BigInt::from_bytes_be(num::bigint::Sign::Plus, address.as_bytes());
                 let storage =
-                    DatabaseStorage::<Bn256>::from_build(self.inner.storage.clone(), storage)?;
-                let storage_gadget =
-                    StorageGadget::<_, _, Sha256Hasher>::new(cs.namespace(|| "storage"), storage)?;
-                storages.insert(address, storage_gadget);
+                    DatabaseStorage::<Bn256, H>::from_build(self.inner.storage.clone(), storage)?;
+                storages.insert(address, StorageGadget::<_, _, H>::new(storage));
             }
         }
 
-        let mut state = ContractState::new(cs, storages, self.keeper, input.transaction);
+        let mut state =
+            ContractState::new(cs, storages, self.keeper, Some(input.transaction), address);
 
         let mut num_constraints = 0;
         let result = state.run(
             self.inner,
             method.input,
             Some(&arguments_flat),
-            |cs| {
+            |cs, _address, state| {
                 let num = cs.num_constraints() - num_constraints;
                 num_constraints += num;
                 log::trace!("Constraints: {}", num);
+                callback(state)
             },
             |cs| {
                 if !cs.is_satisfied() {
@@ -109,7 +170,12 @@ impl Facade {
             return Err(Error::UnsatisfiedConstraint);
         }
 
-        let output_value: Vec<BigInt> = result.into_iter().filter_map(|value| value).collect();
+        // the initial storage root hash is only a part of the proof public input
+        let output_value: Vec<BigInt> = result
+            .into_iter()
+            .skip(1)
+            .filter_map(|value| value)
+            .collect();
         let output_value = zinc_types::Value::from_flat_values(output_type, &output_value);
 
         let storages = state
@@ -129,7 +195,281 @@ impl Facade {
         ))
     }
 
+    ///
+    /// Runs the contract method, attributing the constraints to the source code lines.
+    ///
+    pub fn profile<E: IEngine>(self, input: ContractInput) -> Result<Profile, Error> {
+        match self.inner.storage_hasher {
+            zinc_project::StorageHasher::Sha256 => {
+                self.profile_with_hasher::<E, Sha256Hasher>(input)
+            }
+            zinc_project::StorageHasher::Rescue => {
+                self.profile_with_hasher::<E, RescueHasher>(input)
+            }
+            zinc_project::StorageHasher::Poseidon => {
+                self.profile_with_hasher::<E, PoseidonHasher>(input)
+            }
+        }
+    }
+
+    fn profile_with_hasher<E: IEngine, H: IMerkleTreeHasher<Bn256>>(
+        self,
+        input: ContractInput,
+    ) -> Result<Profile, Error> {
+        let cs = MainCS::<Bn256>::new();
+
+        let method = self
+            .inner
+            .methods
+            .get(input.method_name.as_str())
+            .cloned()
+            .ok_or(Error::MethodNotFound {
+                found: input.method_name.clone(),
+            })?;
+        let arguments_flat = input.arguments.into_flat_values();
+
+        let mut storages = HashMap::with_capacity(1);
+        if method.name.as_str() != zinc_const::contract::CONSTRUCTOR_IDENTIFIER {
+            for (address, storage) in input.storages.into_iter() {
+                let address = BigInt::from_bytes_be(num::bigint::Sign::Plus, address.as_bytes());
+                let storage =
+                    DatabaseStorage::<Bn256, H>::from_build(self.inner.storage.clone(), storage)?;
+                storages.insert(address, StorageGadget::<_, _, H>::new(storage));
+            }
+        }
+
+        let address = Self::instance_address(&method, arguments_flat.as_slice());
+        let mut state =
+            ContractState::new(cs, storages, self.keeper, Some(input.transaction), address);
+
+        let mut profiler = Profiler::new(self.inner.instructions.as_slice());
+        state.run(
+            self.inner,
+            method.input,
+            Some(&arguments_flat),
+            |cs, address, _state| {
+                profiler.record(address, cs.num_constraints(), cs.num_witnesses());
+                Ok(())
+            },
+            |cs| {
+                if !cs.is_satisfied() {
+                    return Err(Error::UnsatisfiedConstraint);
+                }
+
+                Ok(())
+            },
+            method.address,
+        )?;
+
+        Ok(profiler.finish())
+    }
+
+    pub fn setup<E: IEngine>(self, method_name: String) -> Result<Parameters<E>, Error> {
+        match self.inner.storage_hasher {
+            zinc_project::StorageHasher::Sha256 => {
+                self.setup_with_hasher::<E, Sha256Hasher>(method_name)
+            }
+            zinc_project::StorageHasher::Rescue => {
+                self.setup_with_hasher::<E, RescueHasher>(method_name)
+            }
+            zinc_project::StorageHasher::Poseidon => {
+                self.setup_with_hasher::<E, PoseidonHasher>(method_name)
+            }
+        }
+    }
+
+    fn setup_with_hasher<E: IEngine, H: IMerkleTreeHasher<E>>(
+        self,
+        method_name: String,
+    ) -> Result<Parameters<E>, Error> {
+        let rng = &mut rand::thread_rng();
+        let mut result = None;
+
+        let method = self
+            .inner
+            .methods
+            .get(method_name.as_str())
+            .cloned()
+            .ok_or(Error::MethodNotFound { found: method_name })?;
+
+        // the instance address is unknown during the setup, so the storage is bound to zero
+        let mut storages = HashMap::with_capacity(1);
+        let mut address = None;
+        if method.name.as_str() != zinc_const::contract::CONSTRUCTOR_IDENTIFIER {
+            let storage = SetupStorage::<E, H>::from_build(
+                self.inner.storage.clone(),
+                zinc_types::Value::Unit,
+            )?;
+            storages.insert(BigInt::zero(), StorageGadget::<_, _, H>::new(storage));
+            address = Some(BigInt::zero());
+        }
+
+        let synthesizable = ContractSynthesizer {
+            inputs: None,
+            output: &mut result,
+            bytecode: self.inner,
+            method,
+            storages,
+            address,
+            keeper: self.keeper,
+            transaction: None,
+
+            _pd: PhantomData,
+        };
+
+        let params = groth16::generate_random_parameters::<E, _, _>(synthesizable, rng)?;
+
+        match result.expect(zinc_const::panic::VALUE_ALWAYS_EXISTS) {
+            Ok(_) => Ok(params),
+            Err(error) => Err(error),
+        }
+    }
+
+    pub fn prove<E: IEngine>(
+        self,
+        params: Parameters<E>,
+        input: ContractInput,
+    ) -> Result<(zinc_types::Value, Proof<E>), Error> {
+        match self.inner.storage_hasher {
+            zinc_project::StorageHasher::Sha256 => {
+                self.prove_with_hasher::<E, Sha256Hasher>(params, input)
+            }
+            zinc_project::StorageHasher::Rescue => {
+                self.prove_with_hasher::<E, RescueHasher>(params, input)
+            }
+            zinc_project::StorageHasher::Poseidon => {
+                self.prove_with_hasher::<E, PoseidonHasher>(params, input)
+            }
+        }
+    }
+
+    fn prove_with_hasher<E: IEngine, H: IMerkleTreeHasher<E>>(
+        self,
+        params: Parameters<E>,
+        input: ContractInput,
+    ) -> Result<(zinc_types::Value, Proof<E>), Error> {
+        let rng = &mut rand::thread_rng();
+        let mut result = None;
+
+        let method = self
+            .inner
+            .methods
+            .get(input.method_name.as_str())
+            .cloned()
+            .ok_or(Error::MethodNotFound {
+                found: input.method_name.clone(),
+            })?;
+        let arguments_flat = input.arguments.into_flat_values();
+        let output_type = if method.name.as_str() == zinc_const::contract::CONSTRUCTOR_IDENTIFIER {
+            zinc_types::Type::eth_address()
+        } else {
+            method.output.clone()
+        }
+        .into_contract_proof_output();
+        let address = Self::instance_address(&method, arguments_flat.as_slice());
+
+        let mut storages = HashMap::with_capacity(1);
+        if method.name.as_str() != zinc_const::contract::CONSTRUCTOR_IDENTIFIER {
+            for (address, storage) in input.storages.into_iter() {
+                let address = BigInt::from_bytes_be(num::bigint::Sign::Plus, address.as_bytes());
+                let storage =
+                    DatabaseStorage::<E, H>::from_build(self.inner.storage.clone(), storage)?;
+                storages.insert(address, StorageGadget::<_, _, H>::new(storage));
+            }
+        }
+
+        let synthesizable = ContractSynthesizer {
+            inputs: Some(arguments_flat),
+            output: &mut result,
+            bytecode: self.inner,
+            method,
+            storages,
+            address,
+            keeper: self.keeper,
+            transaction: Some(input.transaction),
+
+            _pd: PhantomData,
+        };
+
+        let proof = groth16::create_random_proof(synthesizable, &params, rng)
+            .map_err(Error::SynthesisError)?;
+
+        match result {
+            None => Err(Error::InternalError(
+                "contract hasn't generated outputs".into(),
+            )),
+            Some(Err(error)) => Err(error),
+            Some(Ok(result)) => {
+                let output_flat: Vec<BigInt> =
+                    result.into_iter().filter_map(|value| value).collect();
+                let output_value = zinc_types::Value::from_flat_values(output_type, &output_flat);
+
+                Ok((output_value, proof))
+            }
+        }
+    }
+
     pub fn test<E: IEngine>(self) -> Result<UnitTestExitCode, Error> {
+        match self.inner.storage_hasher {
+            zinc_project::StorageHasher::Sha256 => self.test_with_hasher::<E, Sha256Hasher>(),
+            zinc_project::StorageHasher::Rescue => self.test_with_hasher::<E, RescueHasher>(),
+            zinc_project::StorageHasher::Poseidon => self.test_with_hasher::<E, PoseidonHasher>(),
+        }
+    }
+
+    ///
+    /// Runs the unit test `name`, stopping at the source code lines according to the `debugger`.
+    ///
+    pub fn debug_test<E: IEngine, F: IFrontend>(
+        self,
+        name: &str,
+        debugger: &mut Debugger<F>,
+    ) -> Result<(), Error> {
+        match self.inner.storage_hasher {
+            zinc_project::StorageHasher::Sha256 => {
+                self.debug_test_with_hasher::<E, Sha256Hasher, F>(name, debugger)
+            }
+            zinc_project::StorageHasher::Rescue => {
+                self.debug_test_with_hasher::<E, RescueHasher, F>(name, debugger)
+            }
+            zinc_project::StorageHasher::Poseidon => {
+                self.debug_test_with_hasher::<E, PoseidonHasher, F>(name, debugger)
+            }
+        }
+    }
+
+    fn debug_test_with_hasher<E: IEngine, H: IMerkleTreeHasher<Bn256>, F: IFrontend>(
+        self,
+        name: &str,
+        debugger: &mut Debugger<F>,
+    ) -> Result<(), Error> {
+        let unit_test =
+            self.inner
+                .unit_tests
+                .get(name)
+                .cloned()
+                .ok_or_else(|| Error::UnitTestNotFound {
+                    name: name.to_owned(),
+                })?;
+
+        let cs = MainCS::<Bn256>::new();
+
+        let mut state = ContractState::<_, _, DatabaseStorage<_, H>, H>::new(
+            cs,
+            HashMap::with_capacity(1),
+            Box::new(DummyKeeper::default()),
+            Some(unit_test.zksync_msg.unwrap_or_default()),
+            None,
+        );
+
+        state.test(self.inner, unit_test.address, |_, _, state| {
+            debugger.on_instruction(state)
+        })
+    }
+
+    fn test_with_hasher<E: IEngine, H: IMerkleTreeHasher<Bn256>>(
+        self,
+    ) -> Result<UnitTestExitCode, Error> {
         let mut exit_code = UnitTestExitCode::Passed;
 
         for (name, unit_test) in self.inner.unit_tests.clone().into_iter() {
@@ -140,14 +480,15 @@ impl Facade {
 
             let cs = MainCS::<Bn256>::new();
 
-            let mut state = ContractState::<_, _, DatabaseStorage<_>, Sha256Hasher>::new(
+            let mut state = ContractState::<_, _, DatabaseStorage<_, H>, H>::new(
                 cs,
                 HashMap::with_capacity(1),
                 Box::new(DummyKeeper::default()),
-                unit_test.zksync_msg.unwrap_or_default(),
+                Some(unit_test.zksync_msg.unwrap_or_default()),
+                None,
             );
 
-            match state.test(self.inner.clone(), unit_test.address) {
+            match state.test(self.inner.clone(), unit_test.address, |_, _, _| Ok(())) {
                 Err(_) if unit_test.should_panic => {
                     log::info!("test {} ... {} (failed)", name, "ok".green());
                 }
@@ -172,4 +513,161 @@ impl Facade {
 
         Ok(exit_code)
     }
+
+    ///
+    /// Returns the address of the contract instance whose `method` is called, which is the first
+    /// method argument.
+    ///
+    /// The constructor creates the instance, so its address is not known before the call.
+    ///
+    fn instance_address(
+        method: &zinc_types::ContractMethod,
+        arguments_flat: &[BigInt],
+    ) -> Option<BigInt> {
+        if method.name.as_str() == zinc_const::contract::CONSTRUCTOR_IDENTIFIER {
+            return None;
+        }
+
+        arguments_flat.first().cloned()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use num::BigInt;
+    use num::Zero;
+
+    use franklin_crypto::bellman::pairing::bn256::Bn256;
+
+    use crate::core::contract::input::Input as ContractInput;
+    use crate::core::facade::Facade as VerifierFacade;
+
+    use super::Facade;
+
+    const ASSEMBLY: &str = r#"
+.contract "counter"
+.storage_hasher sha256
+.storage {pub implicit address: u160, pub implicit balances: map<u160, u248>, pub value: u64}
+.method "add" 0 0 mut {self: u160, amount: u64} -> u64
+
+    0: load 4 1
+    1: push 2 as field
+    2: storage_load 1
+    3: load 5 1
+    4: add
+    5: store 6 1
+    6: load 6 1
+    7: load 4 1
+    8: push 2 as field
+    9: storage_store 1
+   10: load 4 1
+   11: push 2 as field
+   12: storage_load 1
+   13: return 1
+"#;
+
+    const METHOD: &str = "add";
+
+    fn contract() -> zinc_types::Contract {
+        match zinc_types::assemble(ASSEMBLY).expect(zinc_const::panic::TEST_DATA_VALID) {
+            zinc_types::Application::Contract(contract) => contract,
+            _ => panic!("{}", zinc_const::panic::TEST_DATA_VALID),
+        }
+    }
+
+    fn input(contract: &zinc_types::Contract) -> ContractInput {
+        let method = contract
+            .methods
+            .get(METHOD)
+            .cloned()
+            .expect(zinc_const::panic::TEST_DATA_VALID);
+        let mut arguments = zinc_types::Value::try_from_typed_json(
+            serde_json::json!({ "amount": "5" }),
+            method.input,
+        )
+        .expect(zinc_const::panic::TEST_DATA_VALID);
+        arguments.insert_contract_instance(BigInt::zero());
+
+        let storage_values = vec![
+            serde_json::json!("0x0"),
+            serde_json::json!([]),
+            serde_json::json!("42"),
+        ];
+        let mut fields = Vec::with_capacity(contract.storage.len());
+        for (field, value) in contract.storage.clone().into_iter().zip(storage_values) {
+            fields.push(zinc_types::ContractFieldValue::new(
+                field.name,
+                zinc_types::Value::try_from_typed_json(value, field.r#type)
+                    .expect(zinc_const::panic::TEST_DATA_VALID),
+                field.is_public,
+                field.is_implicit,
+            ));
+        }
+        let mut storages = HashMap::with_capacity(1);
+        storages.insert(
+            zksync_types::Address::default(),
+            zinc_types::Value::Contract(fields),
+        );
+
+        ContractInput::new(
+            arguments,
+            storages,
+            METHOD.to_owned(),
+            zinc_types::TransactionMsg::default(),
+        )
+    }
+
+    fn prove_and_verify<F>(tamper: F) -> bool
+    where
+        F: FnOnce(&mut serde_json::Value),
+    {
+        let contract = contract();
+        let input = input(&contract);
+
+        let params = Facade::new(contract.clone())
+            .setup::<Bn256>(METHOD.to_owned())
+            .expect(zinc_const::panic::TEST_DATA_VALID);
+        let (output, proof) = Facade::new(contract.clone())
+            .prove::<Bn256>(params.clone(), input)
+            .expect(zinc_const::panic::TEST_DATA_VALID);
+
+        let mut output_json = output.into_json();
+        assert_eq!(output_json["result"], serde_json::json!("47"));
+        tamper(&mut output_json);
+
+        let method = contract
+            .methods
+            .get(METHOD)
+            .cloned()
+            .expect(zinc_const::panic::TEST_DATA_VALID);
+        let output = zinc_types::Value::try_from_typed_json(
+            output_json,
+            method.output.into_contract_proof_output(),
+        )
+        .expect(zinc_const::panic::TEST_DATA_VALID);
+
+        VerifierFacade::verify_contract(params.vk, proof, output)
+            .expect(zinc_const::panic::TEST_DATA_VALID)
+    }
+
+    #[test]
+    fn ok_setup_prove_verify() {
+        assert!(prove_and_verify(|_output| {}));
+    }
+
+    #[test]
+    fn error_tampered_initial_root_hash() {
+        assert!(!prove_and_verify(|output| {
+            output["initial_root_hash"] = serde_json::json!("0x1");
+        }));
+    }
+
+    #[test]
+    fn error_tampered_root_hash() {
+        assert!(!prove_and_verify(|output| {
+            output["root_hash"] = serde_json::json!("0x1");
+        }));
+    }
 }
@@ -22,6 +22,7 @@ use crate::core::contract::output::initializer::Initializer;
 use crate::core::contract::storage::keeper::IKeeper;
 use crate::core::contract::storage::leaf::LeafVariant;
 use crate::core::counter::NamespaceCounter;
+use crate::core::debugger::IDebuggee;
 use crate::core::execution_state::block::branch::Branch;
 use crate::core::execution_state::block::r#loop::Loop;
 use crate::core::execution_state::block::Block;
@@ -53,8 +54,9 @@ where
     outputs: Vec<Scalar<E>>,
 
     storages: HashMap<BigInt, StorageGadget<E, S, H>>,
+    address: Option<BigInt>,
     keeper: Box<dyn IKeeper>,
-    transaction: zinc_types::TransactionMsg,
+    transaction: Option<zinc_types::TransactionMsg>,
 
     pub(crate) location: Location,
 }
@@ -66,17 +68,28 @@ where
     S: IMerkleTree<E>,
     H: IMerkleTreeHasher<E>,
 {
+    ///
+    /// Creates the contract state.
+    ///
+    /// The `address` is the address of the contract whose method is executed. It is not known
+    /// before the constructor call, so it is set by the first storage initialization.
+    ///
+    /// The `transaction` is not known during the setup, where its fields are allocated without
+    /// values.
+    ///
     pub fn new(
         cs: CS,
         storages: HashMap<BigInt, StorageGadget<E, S, H>>,
         keeper: Box<dyn IKeeper>,
-        transaction: zinc_types::TransactionMsg,
+        transaction: Option<zinc_types::TransactionMsg>,
+        address: Option<BigInt>,
     ) -> Self {
         Self {
             counter: NamespaceCounter::new(cs),
             execution_state: ExecutionState::new(),
             outputs: vec![],
 
+            address,
             storages,
             keeper,
             transaction,
@@ -95,7 +108,7 @@ where
         address: usize,
     ) -> Result<Vec<Option<BigInt>>, Error>
     where
-        CB: FnMut(&CS),
+        CB: FnMut(&CS, usize, &Self) -> Result<(), Error>,
         F: FnMut(&CS) -> Result<(), Error>,
     {
         self.counter.cs.enforce(
@@ -107,6 +120,10 @@ where
         let one = Scalar::new_constant_usize(1, zinc_types::ScalarType::Boolean);
         self.condition_push(one)?;
 
+        // the storage is bound to its initial root hash, which is the first public input
+        let initial_root_hash = self.root_hash()?;
+        self.outputs.push(initial_root_hash);
+
         let input_size = input_type.size();
         self.init_root_frame(input_type, input_values)?;
 
@@ -126,6 +143,7 @@ where
                 step, self.execution_state.instruction_counter
             );
             self.counter.cs.push_namespace(|| namespace);
+            let address = self.execution_state.instruction_counter;
             let instruction =
                 contract.instructions[self.execution_state.instruction_counter].clone();
 
@@ -143,7 +161,7 @@ where
             }
 
             log::trace!("{}", self.execution_state);
-            instruction_callback(&self.counter.cs);
+            instruction_callback(&self.counter.cs, address, self)?;
             self.counter.cs.pop_namespace();
             step += 1;
         }
@@ -156,7 +174,15 @@ where
         self.get_outputs()
     }
 
-    pub fn test(&mut self, contract: zinc_types::Contract, address: usize) -> Result<(), Error> {
+    pub fn test<CB>(
+        &mut self,
+        contract: zinc_types::Contract,
+        address: usize,
+        mut instruction_callback: CB,
+    ) -> Result<(), Error>
+    where
+        CB: FnMut(&CS, usize, &Self) -> Result<(), Error>,
+    {
         self.counter.cs.enforce(
             || "ONE * ONE = ONE (do this to avoid `unconstrained` error)",
             |zero| zero + CS::one(),
@@ -180,6 +206,7 @@ where
                 step, self.execution_state.instruction_counter
             );
             self.counter.cs.push_namespace(|| namespace);
+            let address = self.execution_state.instruction_counter;
             let instruction =
                 contract.instructions[self.execution_state.instruction_counter].clone();
 
@@ -197,6 +224,7 @@ where
             }
 
             log::trace!("{}", self.execution_state);
+            instruction_callback(&self.counter.cs, address, self)?;
             self.counter.cs.pop_namespace();
             step += 1;
         }
@@ -237,19 +265,41 @@ where
             let output = gadgets::output::output(self.counter.next(), output)?;
             outputs_bigint.push(output.to_bigint());
         }
-        let root_hash = gadgets::output::output(
-            self.counter.next(),
-            Scalar::new_constant_usize(0, zinc_types::ScalarType::Field),
-        )?;
-        outputs_bigint.push(Some(
-            root_hash
-                .to_bigint()
-                .expect(zinc_const::panic::DATA_CONVERSION),
-        ));
+        let root_hash = self.root_hash()?;
+        let root_hash = gadgets::output::output(self.counter.next(), root_hash)?;
+        outputs_bigint.push(root_hash.to_bigint());
 
         Ok(outputs_bigint)
     }
 
+    ///
+    /// Computes the storage root hash of the contract whose method is executed.
+    ///
+    /// The root hash is zero if there is no such storage, e.g. before the constructor call.
+    ///
+    ///
+    /// Returns the storage address value, which is unknown during the setup, where the executing
+    /// contract address is used instead.
+    ///
+    fn storage_address(&self, eth_address: Scalar<E>) -> BigInt {
+        eth_address
+            .to_bigint()
+            .or_else(|| self.address.clone())
+            .expect(zinc_const::panic::DATA_CONVERSION)
+    }
+
+    fn root_hash(&mut self) -> Result<Scalar<E>, Error> {
+        let storage = match self.address {
+            Some(ref address) => self.storages.get_mut(address),
+            None => None,
+        };
+
+        match storage {
+            Some(storage) => storage.root_hash(self.counter.next()),
+            None => Ok(Scalar::new_constant_usize(0, zinc_types::ScalarType::Field)),
+        }
+    }
+
     pub fn condition_push(&mut self, element: Scalar<E>) -> Result<(), Error> {
         self.execution_state.conditions_stack.push(element);
         Ok(())
@@ -270,6 +320,30 @@ where
     }
 }
 
+impl<E, CS, S, H> IDebuggee<E> for State<E, CS, S, H>
+where
+    E: IEngine,
+    CS: ConstraintSystem<E>,
+    S: IMerkleTree<E>,
+    H: IMerkleTreeHasher<E>,
+{
+    fn execution_state(&self) -> &ExecutionState<E> {
+        &self.execution_state
+    }
+
+    fn storages(&self) -> Vec<(BigInt, zinc_types::Value)> {
+        self.storages
+            .iter()
+            .filter_map(|(address, storage)| {
+                storage
+                    .to_build()
+                    .ok()
+                    .map(|value| (address.to_owned(), value))
+            })
+            .collect()
+    }
+}
+
 impl<E, CS, S, H> IVirtualMachine for State<E, CS, S, H>
 where
     E: IEngine,
@@ -280,6 +354,7 @@ where
     type E = E;
     type CS = CS;
     type S = S;
+    type H = H;
 
     fn push(&mut self, cell: Cell<E>) -> Result<(), Error> {
         self.execution_state.evaluation_stack.push(cell)
@@ -338,8 +413,11 @@ where
         values.insert(0, eth_address_scalar.clone());
 
         let storage = Self::S::from_evaluation_stack(field_types.clone(), values)?;
-        let storage_gadget = StorageGadget::new(self.counter.next(), storage)?;
-        self.storages.insert(eth_address_bigint, storage_gadget);
+        self.storages
+            .insert(eth_address_bigint.clone(), StorageGadget::new(storage));
+        if self.address.is_none() {
+            self.address = Some(eth_address_bigint);
+        }
 
         self.execution_state.initializers.push(Initializer::new(
             project.name,
@@ -371,8 +449,8 @@ where
             .keeper
             .fetch(eth_address.clone(), field_types.clone())?;
         let storage = Self::S::from_build(field_types, storage)?;
-        let storage_gadget = StorageGadget::new(self.counter.next(), storage)?;
-        self.storages.insert(eth_address, storage_gadget);
+        self.storages
+            .insert(eth_address, StorageGadget::new(storage));
 
         Ok(())
     }
@@ -383,12 +461,9 @@ where
         index: Scalar<Self::E>,
         size: usize,
     ) -> Result<Vec<Scalar<Self::E>>, Error> {
+        let eth_address = self.storage_address(eth_address);
         self.storages
-            .get_mut(
-                &eth_address
-                    .to_bigint()
-                    .expect(zinc_const::panic::DATA_CONVERSION),
-            )
+            .get_mut(&eth_address)
             .expect(zinc_const::panic::VALUE_ALWAYS_EXISTS)
             .load(self.counter.next(), index, size)
     }
@@ -399,12 +474,9 @@ where
         index: Scalar<Self::E>,
         values: LeafVariant<Self::E>,
     ) -> Result<(), Error> {
+        let eth_address = self.storage_address(eth_address);
         self.storages
-            .get_mut(
-                &eth_address
-                    .to_bigint()
-                    .expect(zinc_const::panic::DATA_CONVERSION),
-            )
+            .get_mut(&eth_address)
             .expect(zinc_const::panic::VALUE_ALWAYS_EXISTS)
             .store(self.counter.next(), index, values)
     }
@@ -456,13 +528,13 @@ where
 
         let mut transaction_field_iter = 0..4;
 
-        let sender: [u8; zinc_const::size::ETH_ADDRESS] = self.transaction.sender.into();
+        let sender = self.transaction.as_ref().map(|transaction| {
+            let sender: [u8; zinc_const::size::ETH_ADDRESS] = transaction.sender.into();
+            BigInt::from_bytes_be(Sign::Plus, sender.to_vec().as_slice())
+        });
         let sender = gadgets::witness::allocate(
             self.counter.next(),
-            Some(&BigInt::from_bytes_be(
-                Sign::Plus,
-                sender.to_vec().as_slice(),
-            )),
+            sender.as_ref(),
             zinc_types::ScalarType::Integer(zinc_types::IntegerType::ETH_ADDRESS),
         )?;
         self.store(
@@ -472,13 +544,13 @@ where
             Cell::Value(sender),
         )?;
 
-        let recipient: [u8; zinc_const::size::ETH_ADDRESS] = self.transaction.recipient.into();
+        let recipient = self.transaction.as_ref().map(|transaction| {
+            let recipient: [u8; zinc_const::size::ETH_ADDRESS] = transaction.recipient.into();
+            BigInt::from_bytes_be(Sign::Plus, recipient.to_vec().as_slice())
+        });
         let recipient = gadgets::witness::allocate(
             self.counter.next(),
-            Some(&BigInt::from_bytes_be(
-                Sign::Plus,
-                recipient.to_vec().as_slice(),
-            )),
+            recipient.as_ref(),
             zinc_types::ScalarType::Integer(zinc_types::IntegerType::ETH_ADDRESS),
         )?;
         self.store(
@@ -488,14 +560,14 @@ where
             Cell::Value(recipient),
         )?;
 
-        let token_address: [u8; zinc_const::size::ETH_ADDRESS] =
-            self.transaction.token_address.into();
+        let token_address = self.transaction.as_ref().map(|transaction| {
+            let token_address: [u8; zinc_const::size::ETH_ADDRESS] =
+                transaction.token_address.into();
+            BigInt::from_bytes_be(Sign::Plus, token_address.to_vec().as_slice())
+        });
         let token_address = gadgets::witness::allocate(
             self.counter.next(),
-            Some(&BigInt::from_bytes_be(
-                Sign::Plus,
-                token_address.to_vec().as_slice(),
-            )),
+            token_address.as_ref(),
             zinc_types::ScalarType::Integer(zinc_types::IntegerType::ETH_ADDRESS),
         )?;
         self.store(
@@ -505,13 +577,14 @@ where
             Cell::Value(token_address),
         )?;
 
+        let amount = self.transaction.as_ref().map(|transaction| {
+            zinc_types::num_compat_forward(transaction.amount.to_owned())
+                .to_bigint()
+                .expect(zinc_const::panic::DATA_CONVERSION)
+        });
         let amount = gadgets::witness::allocate(
             self.counter.next(),
-            Some(
-                &zinc_types::num_compat_forward(self.transaction.amount.to_owned())
-                    .to_bigint()
-                    .expect(zinc_const::panic::DATA_CONVERSION),
-            ),
+            amount.as_ref(),
             zinc_types::ScalarType::Integer(zinc_types::IntegerType::BALANCE),
         )?;
         self.store(
@@ -652,7 +725,7 @@ where
         Ok(())
     }
 
-    fn call_native<F: INativeCallable<E, S>>(&mut self, function: F) -> Result<(), Error> {
+    fn call_native<F: INativeCallable<E, S, H>>(&mut self, function: F) -> Result<(), Error> {
         let state = &mut self.execution_state;
         let cs = &mut self.counter.cs;
 
@@ -662,7 +735,7 @@ where
             Some(
                 self.storages
                     .iter_mut()
-                    .map(|(key, value)| (key.to_owned(), value.as_mut()))
+                    .map(|(key, value)| (key.to_owned(), value))
                     .collect(),
             ),
         )
@@ -2,26 +2,32 @@ use num::bigint::ToBigInt;
 use num::BigInt;
 use num::ToPrimitive;
 
-use franklin_crypto::bellman::pairing::ff::PrimeField;
-use franklin_crypto::bellman::pairing::ff::PrimeFieldRepr;
-
 use crate::core::contract::storage::leaf::Leaf;
 use crate::core::contract::storage::leaf::LeafInput;
 use crate::core::contract::storage::leaf::LeafOutput;
 use crate::core::contract::storage::leaf::LeafVariant;
 use crate::error::Error;
+use crate::gadgets::contract::merkle_tree::hasher;
+use crate::gadgets::contract::merkle_tree::hasher::IHasher as IMerkleTreeHasher;
 use crate::gadgets::contract::merkle_tree::IMerkleTree;
 use crate::gadgets::scalar::Scalar;
 use crate::IEngine;
 
-pub struct Storage<E: IEngine> {
+///
+/// The contract storage with the values, whose leaves and root are hashed with `H`.
+///
+pub struct Storage<E: IEngine, H: IMerkleTreeHasher<E>> {
     field_types: Vec<zinc_types::ContractFieldType>,
-    hash_tree: Vec<Vec<u8>>,
     leaf_values: Vec<LeafVariant<E>>,
     depth: usize,
+    hasher: H,
 }
 
-impl<E: IEngine> IMerkleTree<E> for Storage<E> {
+impl<E, H> IMerkleTree<E> for Storage<E, H>
+where
+    E: IEngine,
+    H: IMerkleTreeHasher<E>,
+{
     fn from_evaluation_stack(
         field_types: Vec<zinc_types::ContractFieldType>,
         mut values: Vec<Scalar<E>>,
@@ -53,7 +59,6 @@ impl<E: IEngine> IMerkleTree<E> for Storage<E> {
         }
 
         let depth = (storage_leaves.len() as f64).log2().ceil() as usize;
-        let hash_tree_size = 1 << (depth + 1);
 
         let leaf_values = storage_leaves
             .into_iter()
@@ -62,9 +67,9 @@ impl<E: IEngine> IMerkleTree<E> for Storage<E> {
 
         Ok(Self {
             field_types,
-            hash_tree: vec![vec![]; hash_tree_size],
             leaf_values,
             depth,
+            hasher: H::default(),
         })
     }
 
@@ -113,7 +118,6 @@ impl<E: IEngine> IMerkleTree<E> for Storage<E> {
         };
 
         let depth = (storage_leaves.len() as f64).log2().ceil() as usize;
-        let hash_tree_size = 1 << (depth + 1);
 
         let leaf_values = storage_leaves
             .into_iter()
@@ -122,20 +126,21 @@ impl<E: IEngine> IMerkleTree<E> for Storage<E> {
 
         Ok(Self {
             field_types,
-            hash_tree: vec![vec![]; hash_tree_size],
             leaf_values,
             depth,
+            hasher: H::default(),
         })
     }
 
     fn load(&self, index: BigInt) -> Result<Leaf<E>, Error> {
         let index = index.to_usize().ok_or(Error::ExpectedUsize(index))?;
 
-        Ok(Leaf::new(
+        Leaf::new(
+            &self.hasher,
             self.leaf_values[index].to_owned(),
             None,
             self.depth,
-        ))
+        )
     }
 
     fn store(&mut self, index: BigInt, value: LeafVariant<E>) -> Result<(), Error> {
@@ -188,26 +193,26 @@ impl<E: IEngine> IMerkleTree<E> for Storage<E> {
         self.field_types.as_slice()
     }
 
-    fn root_hash(&self) -> E::Fr {
-        let mut hash_buffer = self.hash_tree[1].to_owned();
-        hash_buffer.truncate(zinc_const::size::SHA256_HASH - 1);
-        hash_buffer.resize(zinc_const::size::SHA256_HASH, 0);
-
-        let mut hash_le = Vec::with_capacity(hash_buffer.len());
-        for i in hash_buffer.iter() {
-            let mut current_byte: u8 = 0;
-            for j in 0..zinc_const::bitlength::BYTE {
-                current_byte <<= 1;
-                current_byte += (i >> j) & 1u8;
-            }
-            hash_le.push(current_byte);
+    fn root_hash(&self) -> Result<E::Fr, Error> {
+        let mut level = Vec::with_capacity(1 << self.depth);
+        for leaf in self.leaf_values.iter() {
+            level.push(leaf.hash(&self.hasher)?);
+        }
+        level.resize(1 << self.depth, vec![false; self.hasher.hash_width()]);
+
+        for _ in 0..self.depth {
+            level = level
+                .chunks(2)
+                .map(|children| {
+                    self.hasher
+                        .node_hash_native(children[0].as_slice(), children[1].as_slice())
+                })
+                .collect();
         }
 
-        let mut hash_repr = <E::Fr as PrimeField>::Repr::default();
-        hash_repr
-            .read_le(hash_le.as_slice())
-            .expect(zinc_const::panic::VALUE_ALWAYS_EXISTS);
-        E::Fr::from_repr(hash_repr).expect(zinc_const::panic::VALUE_ALWAYS_EXISTS)
+        let mut root_hash_bits = level.remove(0);
+        root_hash_bits.truncate(zinc_const::bitlength::SHA256_HASH - zinc_const::bitlength::BYTE);
+        Ok(hasher::bits_into_fr::<E>(root_hash_bits.as_slice()))
     }
 
     fn depth(&self) -> usize {
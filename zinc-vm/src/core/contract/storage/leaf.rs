@@ -1,6 +1,8 @@
 use num::BigInt;
 
-use crate::core::contract::storage::sha256;
+use crate::error::Error;
+use crate::gadgets::contract::merkle_tree::hasher::IHasher as IMerkleTreeHasher;
+use crate::gadgets::contract::merkle_tree::sparse::SparseMerkleTree;
 use crate::gadgets::scalar::Scalar;
 use crate::IEngine;
 
@@ -75,6 +77,31 @@ impl<E: IEngine> LeafVariant<E> {
             }
         }
     }
+
+    ///
+    /// Computes the leaf value hash outside of the circuit.
+    ///
+    /// The map leaf value is its sparse Merkle tree root hash.
+    ///
+    pub fn hash<H>(&self, hasher: &H) -> Result<Vec<bool>, Error>
+    where
+        H: IMerkleTreeHasher<E>,
+    {
+        match self {
+            Self::Array(array) => Ok(hasher.leaf_value_hash_native(array.as_slice())),
+            Self::Map { data, .. } => {
+                let root_hash = SparseMerkleTree::<E, H>::root_hash_native(
+                    hasher,
+                    zinc_const::limit::MTREEMAP_DEPTH,
+                    data.as_slice(),
+                )?;
+                Ok(hasher.leaf_value_hash_native(&[Scalar::new_constant_fr(
+                    root_hash,
+                    zinc_types::ScalarType::Field,
+                )]))
+            }
+        }
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -96,31 +123,20 @@ pub enum LeafOutput {
 }
 
 impl<E: IEngine> Leaf<E> {
-    pub fn new(
+    pub fn new<H>(
+        hasher: &H,
         leaf_values: LeafVariant<E>,
         authentication_path: Option<Vec<Vec<bool>>>,
         depth: usize,
-    ) -> Self {
-        Self {
-            leaf_values: leaf_values.clone(),
-            leaf_value_hash: {
-                let mut hash = Vec::with_capacity(
-                    zinc_const::bitlength::SHA256_HASH * zinc_const::bitlength::BYTE,
-                );
-                let values = match leaf_values {
-                    LeafVariant::Array(array) => array,
-                    LeafVariant::Map { .. } => vec![],
-                };
-                for i in sha256::leaf_value_hash::<E>(values).into_iter() {
-                    for j in (0..zinc_const::bitlength::BYTE).rev() {
-                        let bit = ((i >> j) & 1u8) == 1u8;
-                        hash.push(bit);
-                    }
-                }
-                hash
-            },
+    ) -> Result<Self, Error>
+    where
+        H: IMerkleTreeHasher<E>,
+    {
+        Ok(Self {
+            leaf_value_hash: leaf_values.hash(hasher)?,
+            leaf_values,
             authentication_path: authentication_path
-                .unwrap_or_else(|| vec![vec![false; zinc_const::bitlength::SHA256_HASH]; depth]),
-        }
+                .unwrap_or_else(|| vec![vec![false; hasher.hash_width()]; depth]),
+        })
     }
 }
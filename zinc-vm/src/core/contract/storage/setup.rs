@@ -8,17 +8,23 @@ use crate::core::contract::storage::leaf::Leaf;
 use crate::core::contract::storage::leaf::LeafOutput;
 use crate::core::contract::storage::leaf::LeafVariant;
 use crate::error::Error;
+use crate::gadgets::contract::merkle_tree::hasher::IHasher as IMerkleTreeHasher;
 use crate::gadgets::contract::merkle_tree::IMerkleTree;
 use crate::gadgets::scalar::Scalar;
 use crate::IEngine;
 
-pub struct Storage<E: IEngine> {
+pub struct Storage<E: IEngine, H: IMerkleTreeHasher<E>> {
     field_types: Vec<zinc_types::ContractFieldType>,
     leaf_values: Vec<Vec<Scalar<E>>>,
     depth: usize,
+    hasher: H,
 }
 
-impl<E: IEngine> IMerkleTree<E> for Storage<E> {
+impl<E, H> IMerkleTree<E> for Storage<E, H>
+where
+    E: IEngine,
+    H: IMerkleTreeHasher<E>,
+{
     fn from_evaluation_stack(
         field_types: Vec<zinc_types::ContractFieldType>,
         _values: Vec<Scalar<E>>,
@@ -30,6 +36,7 @@ impl<E: IEngine> IMerkleTree<E> for Storage<E> {
             field_types: field_types.clone(),
             leaf_values: vec![vec![]; leaf_values_count],
             depth,
+            hasher: H::default(),
         };
 
         for (index, field) in field_types.into_iter().enumerate() {
@@ -56,6 +63,7 @@ impl<E: IEngine> IMerkleTree<E> for Storage<E> {
             field_types: field_types.clone(),
             leaf_values: vec![vec![]; leaf_values_count],
             depth,
+            hasher: H::default(),
         };
 
         for (index, field) in field_types.into_iter().enumerate() {
@@ -74,11 +82,12 @@ impl<E: IEngine> IMerkleTree<E> for Storage<E> {
     fn load(&self, index: BigInt) -> Result<Leaf<E>, Error> {
         let index = index.to_usize().ok_or(Error::ExpectedUsize(index))?;
 
-        Ok(Leaf::new(
+        Leaf::new(
+            &self.hasher,
             LeafVariant::Array(self.leaf_values[index].to_owned()),
             None,
             self.depth,
-        ))
+        )
     }
 
     fn store(&mut self, index: BigInt, value: LeafVariant<E>) -> Result<(), Error> {
@@ -113,8 +122,8 @@ impl<E: IEngine> IMerkleTree<E> for Storage<E> {
         self.field_types.as_slice()
     }
 
-    fn root_hash(&self) -> E::Fr {
-        E::Fr::zero()
+    fn root_hash(&self) -> Result<E::Fr, Error> {
+        Ok(E::Fr::zero())
     }
 
     fn depth(&self) -> usize {
@@ -16,27 +16,29 @@ use crate::constraint_systems::logging::Logging as LoggingCS;
 use crate::core::contract::storage::keeper::IKeeper;
 use crate::core::contract::State;
 use crate::error::Error;
-use crate::gadgets::contract::merkle_tree::hasher::sha256::Hasher as Sha256Hasher;
+use crate::gadgets::contract::merkle_tree::hasher::IHasher as IMerkleTreeHasher;
 use crate::gadgets::contract::merkle_tree::IMerkleTree;
 use crate::gadgets::contract::storage::StorageGadget;
 use crate::IEngine;
 
-pub struct Synthesizer<'a, E: IEngine, S: IMerkleTree<E>> {
+pub struct Synthesizer<'a, E: IEngine, S: IMerkleTree<E>, H: IMerkleTreeHasher<E>> {
     pub inputs: Option<Vec<BigInt>>,
     pub output: &'a mut Option<Result<Vec<Option<BigInt>>, Error>>,
     pub bytecode: zinc_types::Contract,
     pub method: zinc_types::ContractMethod,
-    pub storages: HashMap<BigInt, StorageGadget<E, S, Sha256Hasher>>,
+    pub storages: HashMap<BigInt, StorageGadget<E, S, H>>,
+    pub address: Option<BigInt>,
     pub keeper: Box<dyn IKeeper>,
-    pub transaction: zinc_types::TransactionMsg,
+    pub transaction: Option<zinc_types::TransactionMsg>,
 
     pub _pd: PhantomData<E>,
 }
 
-impl<E, S> bellman::Circuit<E> for Synthesizer<'_, E, S>
+impl<E, S, H> bellman::Circuit<E> for Synthesizer<'_, E, S, H>
 where
     E: IEngine,
     S: IMerkleTree<E>,
+    H: IMerkleTreeHasher<E>,
 {
     fn synthesize<CS: ConstraintSystem<E>>(self, cs: &mut CS) -> Result<(), SynthesisError> {
         let mut contract = State::new(
@@ -44,13 +46,14 @@ where
             self.storages,
             self.keeper,
             self.transaction,
+            self.address,
         );
 
         *self.output = Some(contract.run(
             self.bytecode,
             self.method.input,
             self.inputs.as_deref(),
-            |_| {},
+            |_, _, _| Ok(()),
             |_| Ok(()),
             self.method.address,
         ));
@@ -0,0 +1,265 @@
+//!
+//! The virtual machine bytecode debug information.
+//!
+
+///
+/// The source code line of an instruction.
+///
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Line {
+    /// The source file path.
+    pub file: Option<String>,
+    /// The function identifier.
+    pub function: Option<String>,
+    /// The line number.
+    pub line: Option<usize>,
+}
+
+impl Line {
+    ///
+    /// Returns the `file:line` string, where the unknown parts are replaced with `?`.
+    ///
+    pub fn position(&self) -> String {
+        format!(
+            "{}:{}",
+            self.file.as_deref().unwrap_or("?"),
+            self.line
+                .map(|line| line.to_string())
+                .unwrap_or_else(|| "?".to_owned()),
+        )
+    }
+
+    ///
+    /// Returns the function identifier, which is replaced with `?` if unknown.
+    ///
+    pub fn function_name(&self) -> &str {
+        self.function.as_deref().unwrap_or("?")
+    }
+
+    ///
+    /// Checks if the line is in the `file` and has the number `line`.
+    ///
+    /// Either path may be a suffix of the other, e.g. `main.zn` and `/home/user/project/src/main.zn`
+    /// both match `./src/main.zn`.
+    ///
+    pub fn matches(&self, file: &str, line: usize) -> bool {
+        match (self.file.as_deref(), self.line) {
+            (Some(own_file), Some(own_line)) => {
+                own_line == line
+                    && (Self::is_path_suffix(own_file, file)
+                        || Self::is_path_suffix(file, own_file))
+            }
+            _ => false,
+        }
+    }
+
+    ///
+    /// Checks if the `suffix` path, stripped of the leading `./`, ends the `path` at a
+    /// directory boundary.
+    ///
+    fn is_path_suffix(path: &str, suffix: &str) -> bool {
+        let suffix = suffix.trim_start_matches("./");
+        if suffix.is_empty() {
+            return false;
+        }
+
+        path.strip_suffix(suffix)
+            .map(|prefix| prefix.is_empty() || prefix.ends_with('/') || prefix.ends_with('\\'))
+            .unwrap_or_default()
+    }
+}
+
+///
+/// The named variable allocated in a function data stack frame.
+///
+#[derive(Debug, Clone, PartialEq)]
+pub struct Variable {
+    /// The source code variable name.
+    pub name: String,
+    /// The variable address in the function data stack frame.
+    pub address: usize,
+    /// The variable type.
+    pub r#type: zinc_types::Type,
+}
+
+///
+/// The debug information restored from the marker instructions written by the compiler.
+///
+/// The markers are only emitted when the location changes, so the bytecode is scanned
+/// in advance to find the location of every instruction.
+///
+#[derive(Debug, Clone)]
+pub struct DebugInfo {
+    /// The source code lines of the instructions.
+    pub lines: Vec<Line>,
+    /// The addresses of the functions containing the instructions.
+    function_starts: Vec<usize>,
+    /// The variables with the addresses of their markers.
+    variables: Vec<(usize, Variable)>,
+}
+
+impl DebugInfo {
+    ///
+    /// Scans the bytecode `instructions`.
+    ///
+    pub fn new(instructions: &[zinc_types::Instruction]) -> Self {
+        let mut lines = Vec::with_capacity(instructions.len());
+        let mut function_starts = Vec::with_capacity(instructions.len());
+        let mut variables = Vec::new();
+
+        let mut current = Line {
+            file: None,
+            function: None,
+            line: None,
+        };
+        let mut function_start = 0;
+        for (address, instruction) in instructions.iter().enumerate() {
+            match instruction {
+                zinc_types::Instruction::FileMarker(inner) => {
+                    current.file = Some(inner.file.to_owned());
+                    current.line = None;
+                }
+                zinc_types::Instruction::FunctionMarker(inner) => {
+                    current.function = Some(inner.function.to_owned());
+
+                    // the function marker follows the file marker at the function start
+                    function_start = address.saturating_sub(1);
+                    if let Some(line) = lines.last_mut() {
+                        *line = current.clone();
+                    }
+                    if let Some(start) = function_starts.last_mut() {
+                        *start = function_start;
+                    }
+                }
+                zinc_types::Instruction::LineMarker(inner) => {
+                    current.line = Some(inner.line);
+                }
+                zinc_types::Instruction::VariableMarker(inner) => {
+                    variables.push((
+                        address,
+                        Variable {
+                            name: inner.name.to_owned(),
+                            address: inner.address,
+                            r#type: inner.r#type.to_owned(),
+                        },
+                    ));
+                }
+                _ => {}
+            }
+
+            lines.push(current.clone());
+            function_starts.push(function_start);
+        }
+
+        Self {
+            lines,
+            function_starts,
+            variables,
+        }
+    }
+
+    ///
+    /// Returns the source code line of the instruction at `address`.
+    ///
+    pub fn line(&self, address: usize) -> Option<&Line> {
+        self.lines.get(address)
+    }
+
+    ///
+    /// Returns the variables of the function, which are defined before the instruction
+    /// at `address`.
+    ///
+    /// If a variable is shadowed, only the last definition is returned.
+    ///
+    pub fn variables(&self, address: usize) -> Vec<&Variable> {
+        let function_start = match self.function_starts.get(address) {
+            Some(function_start) => *function_start,
+            None => return vec![],
+        };
+
+        let mut variables: Vec<&Variable> = Vec::new();
+        for (marker_address, variable) in self.variables.iter() {
+            if *marker_address < function_start {
+                continue;
+            }
+            if *marker_address >= address {
+                break;
+            }
+
+            variables.retain(|defined| defined.name != variable.name);
+            variables.push(variable);
+        }
+
+        variables
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::DebugInfo;
+
+    fn bytecode() -> Vec<zinc_types::Instruction> {
+        vec![
+            zinc_types::Instruction::FileMarker(zinc_types::FileMarker::new(
+                "./src/main.zn".to_owned(),
+            )),
+            zinc_types::Instruction::FunctionMarker(zinc_types::FunctionMarker::new(
+                "main".to_owned(),
+            )),
+            zinc_types::Instruction::VariableMarker(zinc_types::VariableMarker::new(
+                "a".to_owned(),
+                0,
+                zinc_types::Type::Scalar(zinc_types::ScalarType::Field),
+            )),
+            zinc_types::Instruction::LineMarker(zinc_types::LineMarker::new(2)),
+            zinc_types::Instruction::VariableMarker(zinc_types::VariableMarker::new(
+                "a".to_owned(),
+                1,
+                zinc_types::Type::Scalar(zinc_types::ScalarType::Boolean),
+            )),
+            zinc_types::Instruction::Return(zinc_types::Return::new(0)),
+            zinc_types::Instruction::FileMarker(zinc_types::FileMarker::new(
+                "./src/main.zn".to_owned(),
+            )),
+            zinc_types::Instruction::FunctionMarker(zinc_types::FunctionMarker::new(
+                "helper".to_owned(),
+            )),
+            zinc_types::Instruction::Return(zinc_types::Return::new(0)),
+        ]
+    }
+
+    #[test]
+    fn ok_lines() {
+        let info = DebugInfo::new(bytecode().as_slice());
+
+        let line = info.line(0).expect(zinc_const::panic::TEST_DATA_VALID);
+        assert_eq!(line.function_name(), "main");
+        assert_eq!(line.position(), "./src/main.zn:?");
+
+        let line = info.line(4).expect(zinc_const::panic::TEST_DATA_VALID);
+        assert!(line.matches("main.zn", 2));
+        assert!(line.matches("src/main.zn", 2));
+        assert!(line.matches("/home/user/project/src/main.zn", 2));
+        assert!(!line.matches("ain.zn", 2));
+        assert!(!line.matches("/home/user/project/lib/main.zn", 2));
+        assert!(!line.matches("main.zn", 3));
+
+        let line = info.line(6).expect(zinc_const::panic::TEST_DATA_VALID);
+        assert_eq!(line.function_name(), "helper");
+    }
+
+    #[test]
+    fn ok_variables_shadowed() {
+        let info = DebugInfo::new(bytecode().as_slice());
+
+        let variables = info.variables(4);
+        assert_eq!(variables.len(), 1);
+        assert_eq!(variables[0].address, 0);
+
+        let variables = info.variables(5);
+        assert_eq!(variables.len(), 1);
+        assert_eq!(variables[0].address, 1);
+
+        assert!(info.variables(8).is_empty());
+    }
+}
@@ -0,0 +1,54 @@
+//!
+//! The virtual machine debugger breakpoint.
+//!
+
+use std::fmt;
+use std::str::FromStr;
+
+///
+/// The source code line breakpoint.
+///
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Breakpoint {
+    /// The source file path or its suffix, e.g. `main.zn`.
+    pub file: String,
+    /// The line number.
+    pub line: usize,
+}
+
+impl Breakpoint {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(file: String, line: usize) -> Self {
+        Self { file, line }
+    }
+}
+
+impl FromStr for Breakpoint {
+    type Err = String;
+
+    ///
+    /// Parses the `file:line` string.
+    ///
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        let (file, line) = match value.rfind(':') {
+            Some(index) => (&value[..index], &value[index + 1..]),
+            None => return Err(format!("expected `file:line`, found `{}`", value)),
+        };
+        if file.is_empty() {
+            return Err(format!("expected a file name, found `{}`", value));
+        }
+        let line = line
+            .parse::<usize>()
+            .map_err(|_| format!("expected a line number, found `{}`", line))?;
+
+        Ok(Self::new(file.to_owned(), line))
+    }
+}
+
+impl fmt::Display for Breakpoint {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}:{}", self.file, self.line)
+    }
+}
@@ -0,0 +1,201 @@
+//!
+//! The virtual machine source-level debugger.
+//!
+
+pub mod breakpoint;
+pub mod repl;
+pub mod session;
+
+use num::BigInt;
+
+use crate::core::debug_info::DebugInfo;
+use crate::core::debug_info::Line;
+use crate::core::execution_state::ExecutionState;
+use crate::error::Error;
+use crate::IEngine;
+
+use self::breakpoint::Breakpoint;
+use self::session::Session;
+
+///
+/// The debugger front-end, which is asked what to do when the execution stops.
+///
+pub trait IFrontend {
+    ///
+    /// Called when the execution stops before a source code line.
+    ///
+    /// The front-end may inspect the `session` and edit its breakpoints.
+    ///
+    fn stop<E: IEngine>(&mut self, session: &mut Session<E>) -> Result<Resume, Error>;
+
+    ///
+    /// Called before every source code line while the execution is running.
+    ///
+    /// The front-end may edit the `breakpoints` and request a stop by returning `true`.
+    ///
+    fn poll(&mut self, _breakpoints: &mut Vec<Breakpoint>) -> Result<bool, Error> {
+        Ok(false)
+    }
+}
+
+///
+/// The virtual machine state inspected by the debugger.
+///
+pub trait IDebuggee<E: IEngine> {
+    ///
+    /// Returns the execution state with the stacks and function frames.
+    ///
+    fn execution_state(&self) -> &ExecutionState<E>;
+
+    ///
+    /// Returns the contract storages with their ETH addresses.
+    ///
+    /// Circuits have no storages, so the list is empty for them.
+    ///
+    fn storages(&self) -> Vec<(BigInt, zinc_types::Value)>;
+}
+
+///
+/// The reason of the execution stop.
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Reason {
+    /// The first source code line of the entry function is reached.
+    Entry,
+    /// A breakpoint is hit.
+    Breakpoint,
+    /// The stepping command is completed.
+    Step,
+    /// The front-end has requested a stop.
+    Pause,
+}
+
+///
+/// The way the execution is resumed after a stop.
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Resume {
+    /// Stop at the next source code line, entering the called functions.
+    Step,
+    /// Stop at the next source code line of the current or a calling function.
+    Next,
+    /// Stop after the current function returns.
+    Finish,
+    /// Stop only at breakpoints.
+    Continue,
+}
+
+///
+/// The stepping mode with the call stack depth it has been started at.
+///
+#[derive(Debug, Clone, Copy)]
+enum Mode {
+    /// Stop at every source code line.
+    Step,
+    /// Stop at a source code line at the same or a lower depth.
+    Next { depth: usize },
+    /// Stop at a source code line at a lower depth.
+    Finish { depth: usize },
+    /// Stop only at breakpoints.
+    Continue,
+}
+
+///
+/// Stops the execution before the source code lines according to the breakpoints and
+/// the stepping commands, handing the control to the front-end.
+///
+pub struct Debugger<F: IFrontend> {
+    /// The source code lines and variables of the instructions.
+    debug_info: DebugInfo,
+    /// The active breakpoints.
+    breakpoints: Vec<Breakpoint>,
+    /// The current stepping mode.
+    mode: Mode,
+    /// The call stack depth and the source code line of the previous instruction.
+    last: Option<(usize, Line)>,
+    /// The front-end which controls the execution.
+    frontend: F,
+}
+
+impl<F: IFrontend> Debugger<F> {
+    ///
+    /// Scans the bytecode `instructions` and creates a debugger.
+    ///
+    /// If `stop_on_entry` is set, the execution stops at the first source code line.
+    ///
+    pub fn new(
+        instructions: &[zinc_types::Instruction],
+        breakpoints: Vec<Breakpoint>,
+        stop_on_entry: bool,
+        frontend: F,
+    ) -> Self {
+        Self {
+            debug_info: DebugInfo::new(instructions),
+            breakpoints,
+            mode: if stop_on_entry {
+                Mode::Step
+            } else {
+                Mode::Continue
+            },
+            last: None,
+            frontend,
+        }
+    }
+
+    ///
+    /// Checks the next instruction to execute and stops if it starts a source code line,
+    /// which is either a breakpoint or the target of the current stepping command.
+    ///
+    pub fn on_instruction<E: IEngine>(&mut self, debuggee: &dyn IDebuggee<E>) -> Result<(), Error> {
+        let state = debuggee.execution_state();
+        let line = match self.debug_info.line(state.instruction_counter) {
+            Some(line) if line.line.is_some() => line,
+            _ => return Ok(()),
+        };
+        let depth = state.frames_stack.len();
+
+        if let Some((last_depth, last_line)) = self.last.as_ref() {
+            if *last_depth == depth && last_line == line {
+                return Ok(());
+            }
+        }
+        let is_entry = self.last.is_none();
+        self.last = Some((depth, line.to_owned()));
+
+        let is_paused = self.frontend.poll(&mut self.breakpoints)?;
+        let reason = if is_paused {
+            Reason::Pause
+        } else if self
+            .breakpoints
+            .iter()
+            .any(|breakpoint| line.matches(breakpoint.file.as_str(), breakpoint.line))
+        {
+            Reason::Breakpoint
+        } else {
+            match self.mode {
+                Mode::Step if is_entry => Reason::Entry,
+                Mode::Step => Reason::Step,
+                Mode::Next { depth: initial } if depth <= initial => Reason::Step,
+                Mode::Finish { depth: initial } if depth < initial => Reason::Step,
+                _ => return Ok(()),
+            }
+        };
+
+        let mut session = Session::new(reason, &mut self.breakpoints, &self.debug_info, debuggee);
+        self.mode = match self.frontend.stop(&mut session)? {
+            Resume::Step => Mode::Step,
+            Resume::Next => Mode::Next { depth },
+            Resume::Finish => Mode::Finish { depth },
+            Resume::Continue => Mode::Continue,
+        };
+
+        Ok(())
+    }
+
+    ///
+    /// Returns the front-end.
+    ///
+    pub fn into_frontend(self) -> F {
+        self.frontend
+    }
+}
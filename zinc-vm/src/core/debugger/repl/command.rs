@@ -0,0 +1,103 @@
+//!
+//! The virtual machine debugger REPL command.
+//!
+
+use std::str::FromStr;
+
+use crate::core::debugger::breakpoint::Breakpoint;
+
+///
+/// The debugger REPL command.
+///
+#[derive(Debug, Clone, PartialEq)]
+pub enum Command {
+    /// Sets a breakpoint.
+    Break(Breakpoint),
+    /// Deletes the breakpoint with the index, or all the breakpoints.
+    Delete(Option<usize>),
+    /// Lists the breakpoints.
+    Breakpoints,
+    /// Steps to the next line, entering the called functions.
+    Step,
+    /// Steps to the next line of the current function.
+    Next,
+    /// Runs until the current function returns.
+    Finish,
+    /// Runs until a breakpoint is hit.
+    Continue,
+    /// Prints the variables of the current function.
+    Locals,
+    /// Prints the variable with the name.
+    Print(String),
+    /// Prints the evaluation stack.
+    Stack,
+    /// Prints the call stack.
+    Backtrace,
+    /// Prints the command list.
+    Help,
+    /// Interrupts the execution.
+    Quit,
+}
+
+impl Command {
+    /// The command list printed by the `help` command.
+    pub const HELP: &'static str = r#"break, b FILE:LINE    set a breakpoint, e.g. `b main.zn:12`
+delete, d [INDEX]     delete a breakpoint or all breakpoints
+breakpoints           list the breakpoints
+step, s               step to the next line, entering the called functions
+next, n               step to the next line of the current function
+finish, f             run until the current function returns
+continue, c           run until a breakpoint is hit
+locals, l             print the variables of the current function
+print, p NAME         print a variable
+stack                 print the evaluation stack
+backtrace, bt         print the call stack
+help, h               print this list
+quit, q               interrupt the execution
+An empty line repeats the previous command."#;
+}
+
+impl FromStr for Command {
+    type Err = String;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        let mut words = value.split_whitespace();
+        let command = words.next().unwrap_or_default();
+        let argument = words.next();
+        if let Some(extra) = words.next() {
+            return Err(format!("unexpected argument `{}`", extra));
+        }
+
+        match (command, argument) {
+            ("break", Some(argument)) | ("b", Some(argument)) => Ok(Self::Break(argument.parse()?)),
+            ("delete", argument) | ("d", argument) => match argument {
+                Some(index) => index
+                    .parse::<usize>()
+                    .map(|index| Self::Delete(Some(index)))
+                    .map_err(|_| format!("expected a breakpoint index, found `{}`", index)),
+                None => Ok(Self::Delete(None)),
+            },
+            ("breakpoints", None) => Ok(Self::Breakpoints),
+            ("step", None) | ("s", None) => Ok(Self::Step),
+            ("next", None) | ("n", None) => Ok(Self::Next),
+            ("finish", None) | ("f", None) => Ok(Self::Finish),
+            ("continue", None) | ("c", None) => Ok(Self::Continue),
+            ("locals", None) | ("l", None) => Ok(Self::Locals),
+            ("print", Some(name)) | ("p", Some(name)) => Ok(Self::Print(name.to_owned())),
+            ("stack", None) => Ok(Self::Stack),
+            ("backtrace", None) | ("bt", None) => Ok(Self::Backtrace),
+            ("help", None) | ("h", None) => Ok(Self::Help),
+            ("quit", None) | ("q", None) => Ok(Self::Quit),
+            ("break", None) | ("b", None) | ("print", None) | ("p", None) => {
+                Err(format!("the `{}` command expects an argument", command))
+            }
+            (command, Some(argument)) if Self::from_str(command).is_ok() => {
+                Err(format!("unexpected argument `{}`", argument))
+            }
+            (command, _) => Err(format!(
+                "unknown command `{}`, type `help` for the command list",
+                command
+            )),
+        }
+    }
+}
@@ -0,0 +1,309 @@
+//!
+//! The virtual machine debugger REPL.
+//!
+
+pub mod command;
+
+use std::collections::HashMap;
+use std::fs;
+use std::io::BufRead;
+use std::io::Write;
+
+use crate::core::debugger::session::Session;
+use crate::core::debugger::IFrontend;
+use crate::core::debugger::Reason;
+use crate::core::debugger::Resume;
+use crate::error::Error;
+use crate::IEngine;
+
+use self::command::Command;
+
+///
+/// The debugger front-end, which reads the commands from `input` and writes the
+/// execution state to `output`.
+///
+pub struct Repl<R: BufRead, W: Write> {
+    /// The command input, usually the standard input.
+    input: R,
+    /// The response output, usually the standard output.
+    output: W,
+    /// The previous command, which is repeated on an empty line.
+    previous: Option<Command>,
+    /// The source code file lines cache. The value is `None` if the file cannot be read.
+    sources: HashMap<String, Option<Vec<String>>>,
+}
+
+impl<R: BufRead, W: Write> Repl<R, W> {
+    /// The prompt printed before reading a command.
+    const PROMPT: &'static str = "(zdb) ";
+
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(input: R, output: W) -> Self {
+        Self {
+            input,
+            output,
+            previous: None,
+            sources: HashMap::new(),
+        }
+    }
+
+    ///
+    /// Returns the response output.
+    ///
+    pub fn into_output(self) -> W {
+        self.output
+    }
+
+    ///
+    /// Prints the stop reason, the current location, and the source code line if the
+    /// source file is available.
+    ///
+    fn print_location<E: IEngine>(&mut self, session: &Session<E>) -> Result<(), Error> {
+        let line = match session.line() {
+            Some(line) => line,
+            None => return Ok(()),
+        };
+
+        let reason = match session.reason {
+            Reason::Entry => "Entered",
+            Reason::Breakpoint => "Breakpoint at",
+            Reason::Step => "Stopped at",
+            Reason::Pause => "Paused at",
+        };
+        writeln!(
+            self.output,
+            "{} {} in {}",
+            reason,
+            line.position(),
+            line.function_name()
+        )?;
+
+        if let (Some(file), Some(number)) = (line.file.as_ref(), line.line) {
+            let source = self
+                .sources
+                .entry(file.to_owned())
+                .or_insert_with(|| {
+                    fs::read_to_string(file)
+                        .ok()
+                        .map(|source| source.lines().map(str::to_owned).collect())
+                })
+                .as_ref()
+                .and_then(|lines| lines.get(number.saturating_sub(1)));
+            if let Some(source) = source {
+                writeln!(self.output, "{:>5} | {}", number, source)?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl<R: BufRead, W: Write> IFrontend for Repl<R, W> {
+    fn stop<E: IEngine>(&mut self, session: &mut Session<E>) -> Result<Resume, Error> {
+        self.print_location(session)?;
+
+        loop {
+            write!(self.output, "{}", Self::PROMPT)?;
+            self.output.flush()?;
+
+            let mut line = String::new();
+            if self.input.read_line(&mut line)? == 0 {
+                return Err(Error::DebuggerInterrupted);
+            }
+
+            let command = if line.trim().is_empty() {
+                match self.previous.take() {
+                    Some(command) => command,
+                    None => continue,
+                }
+            } else {
+                match line.parse::<Command>() {
+                    Ok(command) => command,
+                    Err(error) => {
+                        writeln!(self.output, "error: {}", error)?;
+                        continue;
+                    }
+                }
+            };
+            self.previous = Some(command.clone());
+
+            match command {
+                Command::Break(breakpoint) => {
+                    writeln!(
+                        self.output,
+                        "Breakpoint {} at {}",
+                        session.breakpoints.len(),
+                        breakpoint
+                    )?;
+                    session.breakpoints.push(breakpoint);
+                }
+                Command::Delete(Some(index)) => {
+                    if index < session.breakpoints.len() {
+                        let breakpoint = session.breakpoints.remove(index);
+                        writeln!(self.output, "Deleted breakpoint at {}", breakpoint)?;
+                    } else {
+                        writeln!(self.output, "error: breakpoint {} does not exist", index)?;
+                    }
+                }
+                Command::Delete(None) => {
+                    session.breakpoints.clear();
+                    writeln!(self.output, "Deleted all breakpoints")?;
+                }
+                Command::Breakpoints => {
+                    for (index, breakpoint) in session.breakpoints.iter().enumerate() {
+                        writeln!(self.output, "{:>4}: {}", index, breakpoint)?;
+                    }
+                }
+                Command::Step => return Ok(Resume::Step),
+                Command::Next => return Ok(Resume::Next),
+                Command::Finish => return Ok(Resume::Finish),
+                Command::Continue => return Ok(Resume::Continue),
+                Command::Locals => {
+                    for (variable, value) in session.locals().into_iter() {
+                        let value = value
+                            .map(|value| value.into_json().to_string())
+                            .unwrap_or_else(|| "<unknown>".to_owned());
+                        writeln!(
+                            self.output,
+                            "{}: {} = {}",
+                            variable.name, variable.r#type, value
+                        )?;
+                    }
+                }
+                Command::Print(name) => match session.local(name.as_str()) {
+                    Some((variable, value)) => {
+                        let value = value
+                            .map(|value| {
+                                serde_json::to_string_pretty(&value.into_json())
+                                    .expect(zinc_const::panic::DATA_CONVERSION)
+                            })
+                            .unwrap_or_else(|| "<unknown>".to_owned());
+                        writeln!(
+                            self.output,
+                            "{}: {} = {}",
+                            variable.name, variable.r#type, value
+                        )?;
+                    }
+                    None => writeln!(self.output, "error: variable `{}` is not found", name)?,
+                },
+                Command::Stack => {
+                    for (index, value) in session.evaluation_stack().into_iter().enumerate() {
+                        writeln!(self.output, "{:>4}: {}", index, value)?;
+                    }
+                }
+                Command::Backtrace => {
+                    for (index, line) in session.backtrace().into_iter().enumerate() {
+                        writeln!(
+                            self.output,
+                            "#{} {} at {}",
+                            index,
+                            line.function_name(),
+                            line.position()
+                        )?;
+                    }
+                }
+                Command::Help => writeln!(self.output, "{}", Command::HELP)?,
+                Command::Quit => return Err(Error::DebuggerInterrupted),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use num::BigInt;
+
+    use franklin_crypto::bellman::pairing::bn256::Bn256;
+    use franklin_crypto::circuit::test::TestConstraintSystem;
+
+    use crate::core::circuit::State;
+    use crate::core::debugger::breakpoint::Breakpoint;
+    use crate::core::debugger::Debugger;
+    use crate::error::Error;
+
+    use super::Repl;
+
+    fn circuit() -> zinc_types::Circuit {
+        let instructions = vec![
+            zinc_types::FileMarker::new("main.zn".to_owned()).into(),
+            zinc_types::FunctionMarker::new("main".to_owned()).into(),
+            zinc_types::VariableMarker::new(
+                "a".to_owned(),
+                0,
+                zinc_types::Type::Scalar(zinc_types::ScalarType::Field),
+            )
+            .into(),
+            zinc_types::LineMarker::new(2).into(),
+            zinc_types::Push::new_field(BigInt::from(5)).into(),
+            zinc_types::Store::new(0, 1).into(),
+            zinc_types::LineMarker::new(3).into(),
+            zinc_types::Load::new(0, 1).into(),
+            zinc_types::Return::new(1).into(),
+        ];
+
+        zinc_types::Circuit::new(
+            "test".to_owned(),
+            0,
+            zinc_types::Type::Unit,
+            zinc_types::Type::Scalar(zinc_types::ScalarType::Field),
+            HashMap::new(),
+            instructions,
+        )
+    }
+
+    fn debug(breakpoints: Vec<Breakpoint>, commands: &str) -> (Result<(), Error>, String) {
+        let circuit = circuit();
+        let repl = Repl::new(commands.as_bytes(), Vec::new());
+        let mut debugger = Debugger::new(circuit.instructions.as_slice(), breakpoints, true, repl);
+
+        let mut state = State::<Bn256, _>::new(TestConstraintSystem::new());
+        let result = state
+            .run(
+                circuit,
+                Some(&[]),
+                |_, _, state| debugger.on_instruction(state),
+                |_| Ok(()),
+            )
+            .map(|_| ());
+
+        let output = debugger.into_frontend().into_output();
+        (
+            result,
+            String::from_utf8(output).expect(zinc_const::panic::TEST_DATA_VALID),
+        )
+    }
+
+    #[test]
+    fn ok_step_and_print() {
+        let (result, output) = debug(vec![], "p a\nn\nl\n\nc\n");
+
+        assert!(result.is_ok());
+        assert_eq!(
+            output,
+            r#"Entered main.zn:2 in main
+(zdb) a: field = <unknown>
+(zdb) Stopped at main.zn:3 in main
+(zdb) a: field = "0x5"
+(zdb) a: field = "0x5"
+(zdb) "#
+        );
+    }
+
+    #[test]
+    fn ok_breakpoint() {
+        let (result, output) = debug(vec![Breakpoint::new("main.zn".to_owned(), 3)], "c\nbt\nq\n");
+
+        assert!(matches!(result, Err(Error::DebuggerInterrupted)));
+        assert_eq!(
+            output,
+            r#"Entered main.zn:2 in main
+(zdb) Breakpoint at main.zn:3 in main
+(zdb) #0 main at main.zn:3
+(zdb) "#
+        );
+    }
+}
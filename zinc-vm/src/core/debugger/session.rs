@@ -0,0 +1,198 @@
+//!
+//! The virtual machine debugger session.
+//!
+
+use num::BigInt;
+
+use crate::core::debug_info::DebugInfo;
+use crate::core::debug_info::Line;
+use crate::core::debug_info::Variable;
+use crate::core::debugger::breakpoint::Breakpoint;
+use crate::core::debugger::IDebuggee;
+use crate::core::debugger::Reason;
+use crate::core::execution_state::cell::Cell;
+use crate::core::execution_state::ExecutionState;
+use crate::gadgets::scalar::fr_bigint;
+use crate::IEngine;
+
+///
+/// The function frame of the stopped execution.
+///
+pub struct StackFrame<'a> {
+    /// The source code line of the next instruction to execute in the frame.
+    pub line: &'a Line,
+    /// The variables of the function with their values.
+    pub locals: Vec<(&'a Variable, Option<zinc_types::Value>)>,
+    /// The data stack cells of the frame with their absolute addresses.
+    pub data_stack: Vec<(usize, Option<String>)>,
+}
+
+///
+/// The view of the stopped execution given to the debugger front-end.
+///
+pub struct Session<'a, E: IEngine> {
+    /// The reason of the execution stop.
+    pub reason: Reason,
+    /// The active breakpoints, which may be edited by the front-end.
+    pub breakpoints: &'a mut Vec<Breakpoint>,
+
+    /// The source code lines and variables of the instructions.
+    debug_info: &'a DebugInfo,
+    /// The stopped virtual machine.
+    debuggee: &'a dyn IDebuggee<E>,
+}
+
+impl<'a, E: IEngine> Session<'a, E> {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(
+        reason: Reason,
+        breakpoints: &'a mut Vec<Breakpoint>,
+        debug_info: &'a DebugInfo,
+        debuggee: &'a dyn IDebuggee<E>,
+    ) -> Self {
+        Self {
+            reason,
+            breakpoints,
+
+            debug_info,
+            debuggee,
+        }
+    }
+
+    ///
+    /// Returns the source code line of the next instruction to execute.
+    ///
+    pub fn line(&self) -> Option<&'a Line> {
+        self.debug_info.line(self.state().instruction_counter)
+    }
+
+    ///
+    /// Returns the function frames, starting from the current one.
+    ///
+    /// The caller frames are stopped at their call instructions. The root frame is skipped,
+    /// since it only holds the entry function arguments.
+    ///
+    pub fn frames(&self) -> Vec<StackFrame<'a>> {
+        let state = self.state();
+
+        let mut frames = Vec::with_capacity(state.frames_stack.len());
+        let mut address = state.instruction_counter;
+        let mut frame_end = state.data_stack.memory.len();
+        for frame in state.frames_stack.iter().skip(1).rev() {
+            let line = match self.debug_info.line(address) {
+                Some(line) => line,
+                None => break,
+            };
+
+            let locals = self
+                .debug_info
+                .variables(address)
+                .into_iter()
+                .map(|variable| (variable, self.value(variable, frame.stack_frame_start)))
+                .collect();
+
+            let data_stack = (frame.stack_frame_start..frame_end.max(frame.stack_frame_start))
+                .map(|address| {
+                    let value = match state.data_stack.memory.get(address) {
+                        Some(Some(Cell::Value(value))) => Some(value.to_string()),
+                        _ => None,
+                    };
+                    (address, value)
+                })
+                .collect();
+
+            frames.push(StackFrame {
+                line,
+                locals,
+                data_stack,
+            });
+
+            address = frame.return_address.saturating_sub(1);
+            frame_end = frame.stack_frame_start;
+        }
+
+        frames
+    }
+
+    ///
+    /// Returns the source code lines of the call stack, starting from the current one.
+    ///
+    pub fn backtrace(&self) -> Vec<&'a Line> {
+        self.frames().into_iter().map(|frame| frame.line).collect()
+    }
+
+    ///
+    /// Returns the variables of the current function with their values.
+    ///
+    /// The value is `None` if it is unknown, e.g. if the variable is not initialized yet.
+    ///
+    pub fn locals(&self) -> Vec<(&'a Variable, Option<zinc_types::Value>)> {
+        self.frames()
+            .into_iter()
+            .next()
+            .map(|frame| frame.locals)
+            .unwrap_or_default()
+    }
+
+    ///
+    /// Returns the variable with the `name` and its value.
+    ///
+    pub fn local(&self, name: &str) -> Option<(&'a Variable, Option<zinc_types::Value>)> {
+        self.locals()
+            .into_iter()
+            .find(|(variable, _value)| variable.name == name)
+    }
+
+    ///
+    /// Returns the evaluation stack values, starting from the top of the stack.
+    ///
+    pub fn evaluation_stack(&self) -> Vec<String> {
+        self.state()
+            .evaluation_stack
+            .cells()
+            .map(|cell| match cell {
+                Cell::Value(value) => value.to_string(),
+            })
+            .collect()
+    }
+
+    ///
+    /// Returns the contract storages with their ETH addresses.
+    ///
+    pub fn storages(&self) -> Vec<(BigInt, zinc_types::Value)> {
+        self.debuggee.storages()
+    }
+
+    ///
+    /// Returns the stopped execution state.
+    ///
+    fn state(&self) -> &'a ExecutionState<E> {
+        self.debuggee.execution_state()
+    }
+
+    ///
+    /// Reads the `variable` value from the data stack frame starting at `frame_start`.
+    ///
+    fn value(&self, variable: &Variable, frame_start: usize) -> Option<zinc_types::Value> {
+        let start = frame_start + variable.address;
+        let size = variable.r#type.size();
+
+        let mut values = Vec::with_capacity(size);
+        for address in start..start + size {
+            let value: BigInt = match self.state().data_stack.memory.get(address) {
+                Some(Some(Cell::Value(value))) => {
+                    fr_bigint::fr_to_bigint::<E>(&value.get_value()?, value.is_signed())
+                }
+                _ => return None,
+            };
+            values.push(value);
+        }
+
+        Some(zinc_types::Value::from_flat_values(
+            variable.r#type.to_owned(),
+            values.as_slice(),
+        ))
+    }
+}
@@ -170,6 +170,20 @@ impl<E: IEngine> DataStack<E> {
             }
         }
 
+        for (addr, diff) in delta_else.iter() {
+            if delta_then.contains_key(addr) {
+                continue;
+            }
+
+            if let (Some(Some(Cell::Value(old))), Cell::Value(new)) =
+                (&self.memory.get(*addr), &diff.new)
+            {
+                let cs = cs.namespace(|| format!("merge else address {}", addr));
+                let value = gadgets::select::conditional(cs, &condition, old, new)?;
+                self.set(*addr, Cell::Value(value))?;
+            }
+        }
+
         Ok(())
     }
 
@@ -97,3 +97,32 @@ fn test_fork_merge_false() {
         BigInt::from(42),
     );
 }
+
+#[test]
+fn test_fork_else_merge_false() {
+    let mut ds = DataStack::new();
+    let cs = TestConstraintSystem::<Bn256>::new();
+    let value = Scalar::new_constant_usize(42, zinc_types::ScalarType::Field);
+    ds.set(4, Cell::Value(value))
+        .expect(zinc_const::panic::TEST_DATA_VALID);
+
+    ds.fork();
+    ds.switch_branch()
+        .expect(zinc_const::panic::TEST_DATA_VALID);
+
+    let value2 = Scalar::new_constant_usize(13, zinc_types::ScalarType::Field);
+    ds.set(4, Cell::Value(value2))
+        .expect(zinc_const::panic::TEST_DATA_VALID);
+    assert_cell_eq(
+        ds.get(4).expect(zinc_const::panic::TEST_DATA_VALID),
+        BigInt::from(13),
+    );
+
+    let condition = Scalar::new_constant_bool(false);
+    ds.merge(cs, condition)
+        .expect(zinc_const::panic::TEST_DATA_VALID);
+    assert_cell_eq(
+        ds.get(4).expect(zinc_const::panic::TEST_DATA_VALID),
+        BigInt::from(13),
+    );
+}
@@ -96,6 +96,13 @@ impl<E: IEngine> EvaluationStack<E> {
         self.stack.pop().ok_or(MalformedBytecode::StackUnderflow)?;
         Ok(())
     }
+
+    ///
+    /// Returns the cells of all the branch frames, starting from the top of the stack.
+    ///
+    pub fn cells(&self) -> impl Iterator<Item = &Cell<E>> {
+        self.stack.iter().rev().flat_map(|frame| frame.iter().rev())
+    }
 }
 
 impl<E: IEngine> fmt::Display for EvaluationStack<E> {
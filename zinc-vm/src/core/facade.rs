@@ -2,6 +2,8 @@
 //! The virtual machine core facade.
 //!
 
+use num::BigInt;
+
 use franklin_crypto::bellman::groth16;
 use franklin_crypto::bellman::groth16::Proof;
 use franklin_crypto::bellman::groth16::VerifyingKey;
@@ -18,8 +20,29 @@ impl Facade {
         proof: Proof<E>,
         public_input: zinc_types::Value,
     ) -> Result<bool, VerificationError> {
-        let public_input_flat = public_input
-            .into_flat_values()
+        Self::verify_flat(verifying_key, proof, public_input.into_flat_values())
+    }
+
+    ///
+    /// Verifies a contract method proof.
+    ///
+    /// The `public_input` is the contract method proof output, where the method result is
+    /// surrounded by the contract storage root hashes before and after the call.
+    ///
+    pub fn verify_contract<E: IEngine>(
+        verifying_key: VerifyingKey<E>,
+        proof: Proof<E>,
+        public_input: zinc_types::Value,
+    ) -> Result<bool, VerificationError> {
+        Self::verify_flat(verifying_key, proof, public_input.into_flat_values())
+    }
+
+    fn verify_flat<E: IEngine>(
+        verifying_key: VerifyingKey<E>,
+        proof: Proof<E>,
+        public_input_flat: Vec<BigInt>,
+    ) -> Result<bool, VerificationError> {
+        let public_input_flat = public_input_flat
             .into_iter()
             .map(|value| {
                 gadgets::scalar::fr_bigint::bigint_to_fr::<E>(&value)
@@ -23,6 +23,7 @@ use crate::core::virtual_machine::IVirtualMachine;
 use crate::error::Error;
 use crate::error::MalformedBytecode;
 use crate::gadgets;
+use crate::gadgets::contract::merkle_tree::hasher::sha256::Hasher as Sha256Hasher;
 use crate::gadgets::scalar::Scalar;
 use crate::instructions::call_library::INativeCallable;
 use crate::instructions::IExecutable;
@@ -152,7 +153,8 @@ where
 {
     type E = E;
     type CS = CS;
-    type S = SetupStorage<E>;
+    type S = SetupStorage<E, Sha256Hasher>;
+    type H = Sha256Hasher;
 
     fn push(&mut self, cell: Cell<E>) -> Result<(), Error> {
         self.execution_state.evaluation_stack.push(cell)
@@ -384,7 +386,7 @@ where
         Ok(())
     }
 
-    fn call_native<F: INativeCallable<E, SetupStorage<E>>>(
+    fn call_native<F: INativeCallable<E, SetupStorage<E, Sha256Hasher>, Sha256Hasher>>(
         &mut self,
         function: F,
     ) -> Result<(), Error> {
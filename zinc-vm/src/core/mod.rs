@@ -5,8 +5,11 @@
 pub mod circuit;
 pub mod contract;
 pub mod counter;
+pub mod debug_info;
+pub mod debugger;
 pub mod execution_state;
 pub mod facade;
 pub mod library;
 pub mod location;
+pub mod profiler;
 pub mod virtual_machine;
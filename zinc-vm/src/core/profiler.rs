@@ -0,0 +1,280 @@
+//!
+//! The virtual machine constraint profiler.
+//!
+
+use std::collections::BTreeMap;
+use std::collections::HashMap;
+use std::fmt::Write;
+
+use crate::core::debug_info::DebugInfo;
+use crate::core::debug_info::Line;
+
+///
+/// The constraint system growth attributed to a source code line.
+///
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct Counts {
+    /// The number of constraints.
+    pub constraints: usize,
+    /// The number of auxiliary variables.
+    pub witnesses: usize,
+}
+
+///
+/// The call stack change made by an instruction.
+///
+#[derive(Debug, Clone, Copy)]
+enum StackChange {
+    /// The instruction does not change the call stack.
+    None,
+    /// The instruction calls a function.
+    Call,
+    /// The instruction returns from a function.
+    Return,
+}
+
+///
+/// Attributes the constraint system growth after every executed instruction to the source
+/// code line and the call stack of the instruction.
+///
+pub struct Profiler {
+    /// The source code lines of the instructions.
+    debug_info: DebugInfo,
+    /// The call stack changes of the instructions.
+    changes: Vec<StackChange>,
+
+    /// The addresses of the active call instructions.
+    call_sites: Vec<usize>,
+    /// The constraint system size after the previous instruction.
+    last: Counts,
+
+    /// The counts attributed to the source code lines.
+    by_line: HashMap<Line, Counts>,
+    /// The counts attributed to the folded call stacks.
+    by_stack: BTreeMap<String, Counts>,
+}
+
+impl Profiler {
+    ///
+    /// Scans the bytecode `instructions` and creates a profiler.
+    ///
+    pub fn new(instructions: &[zinc_types::Instruction]) -> Self {
+        let changes = instructions
+            .iter()
+            .map(|instruction| match instruction {
+                zinc_types::Instruction::Call(_) => StackChange::Call,
+                zinc_types::Instruction::Return(_) => StackChange::Return,
+                _ => StackChange::None,
+            })
+            .collect();
+
+        Self {
+            debug_info: DebugInfo::new(instructions),
+            changes,
+
+            call_sites: Vec::new(),
+            last: Counts::default(),
+
+            by_line: HashMap::new(),
+            by_stack: BTreeMap::new(),
+        }
+    }
+
+    ///
+    /// Records the constraint system size after executing the instruction at `address`.
+    ///
+    /// The growth before the first instruction, e.g. the entry function arguments allocation,
+    /// is attributed to the first instruction.
+    ///
+    pub fn record(&mut self, address: usize, constraints: usize, witnesses: usize) {
+        let delta = Counts {
+            constraints: constraints - self.last.constraints,
+            witnesses: witnesses - self.last.witnesses,
+        };
+        self.last = Counts {
+            constraints,
+            witnesses,
+        };
+
+        let line = self.debug_info.lines[address].to_owned();
+
+        let mut stack = String::new();
+        for call_site in self.call_sites.iter() {
+            write!(
+                stack,
+                "{};",
+                self.debug_info.lines[*call_site].function_name()
+            )
+            .expect(zinc_const::panic::DATA_CONVERSION);
+        }
+        write!(stack, "{};{}", line.function_name(), line.position())
+            .expect(zinc_const::panic::DATA_CONVERSION);
+
+        if delta != Counts::default() {
+            let counts = self.by_line.entry(line).or_default();
+            counts.constraints += delta.constraints;
+            counts.witnesses += delta.witnesses;
+
+            let counts = self.by_stack.entry(stack).or_default();
+            counts.constraints += delta.constraints;
+            counts.witnesses += delta.witnesses;
+        }
+
+        match self.changes[address] {
+            StackChange::None => {}
+            StackChange::Call => self.call_sites.push(address),
+            StackChange::Return => {
+                self.call_sites.pop();
+            }
+        }
+    }
+
+    ///
+    /// Finishes the profiling and returns the result.
+    ///
+    pub fn finish(self) -> Profile {
+        let mut lines: Vec<(Line, Counts)> = self.by_line.into_iter().collect();
+        lines.sort_by(|(line_1, counts_1), (line_2, counts_2)| {
+            counts_2
+                .constraints
+                .cmp(&counts_1.constraints)
+                .then(counts_2.witnesses.cmp(&counts_1.witnesses))
+                .then(line_1.cmp(line_2))
+        });
+
+        Profile {
+            lines,
+            stacks: self.by_stack,
+            total: self.last,
+        }
+    }
+}
+
+///
+/// The constraint profiling result.
+///
+#[derive(Debug)]
+pub struct Profile {
+    /// The source code lines sorted by the number of constraints in descending order.
+    pub lines: Vec<(Line, Counts)>,
+    /// The folded call stacks.
+    pub stacks: BTreeMap<String, Counts>,
+    /// The total size of the constraint system.
+    pub total: Counts,
+}
+
+impl Profile {
+    ///
+    /// Formats the profile as a human-readable table.
+    ///
+    pub fn to_table(&self) -> String {
+        let mut table = String::new();
+
+        writeln!(
+            table,
+            "{:>12} {:>12}  {:<32} function",
+            "constraints", "witnesses", "location"
+        )
+        .expect(zinc_const::panic::DATA_CONVERSION);
+        for (line, counts) in self.lines.iter() {
+            writeln!(
+                table,
+                "{:>12} {:>12}  {:<32} {}",
+                counts.constraints,
+                counts.witnesses,
+                line.position(),
+                line.function_name(),
+            )
+            .expect(zinc_const::panic::DATA_CONVERSION);
+        }
+        writeln!(
+            table,
+            "{:>12} {:>12}  total",
+            self.total.constraints, self.total.witnesses
+        )
+        .expect(zinc_const::panic::DATA_CONVERSION);
+
+        table
+    }
+
+    ///
+    /// Formats the constraints as the folded call stacks, which are accepted by the
+    /// flamegraph tools, e.g. `flamegraph.pl` or `inferno-flamegraph`.
+    ///
+    /// The frames are the function identifiers, followed by the `file:line` leaf frame.
+    ///
+    pub fn to_folded(&self) -> String {
+        let mut folded = String::new();
+
+        for (stack, counts) in self.stacks.iter() {
+            if counts.constraints == 0 {
+                continue;
+            }
+
+            writeln!(folded, "{} {}", stack, counts.constraints)
+                .expect(zinc_const::panic::DATA_CONVERSION);
+        }
+
+        folded
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Profiler;
+
+    fn bytecode() -> Vec<zinc_types::Instruction> {
+        vec![
+            zinc_types::Instruction::FileMarker(zinc_types::FileMarker::new("main.zn".to_owned())),
+            zinc_types::Instruction::FunctionMarker(zinc_types::FunctionMarker::new(
+                "main".to_owned(),
+            )),
+            zinc_types::Instruction::LineMarker(zinc_types::LineMarker::new(2)),
+            zinc_types::Instruction::Call(zinc_types::Call::new(6, 0)),
+            zinc_types::Instruction::LineMarker(zinc_types::LineMarker::new(3)),
+            zinc_types::Instruction::Return(zinc_types::Return::new(0)),
+            zinc_types::Instruction::FileMarker(zinc_types::FileMarker::new("main.zn".to_owned())),
+            zinc_types::Instruction::FunctionMarker(zinc_types::FunctionMarker::new(
+                "helper".to_owned(),
+            )),
+            zinc_types::Instruction::LineMarker(zinc_types::LineMarker::new(7)),
+            zinc_types::Instruction::Return(zinc_types::Return::new(0)),
+        ]
+    }
+
+    #[test]
+    fn ok_attribution() {
+        let mut profiler = Profiler::new(bytecode().as_slice());
+        for (address, constraints, witnesses) in vec![
+            (0, 1, 0),
+            (1, 1, 0),
+            (2, 1, 0),
+            (3, 1, 0),
+            (6, 1, 0),
+            (7, 1, 0),
+            (8, 6, 4),
+            (9, 6, 4),
+            (4, 6, 4),
+            (5, 8, 5),
+        ]
+        .into_iter()
+        {
+            profiler.record(address, constraints, witnesses);
+        }
+        let profile = profiler.finish();
+
+        assert_eq!(profile.total.constraints, 8);
+        assert_eq!(profile.total.witnesses, 5);
+
+        let (line, counts) = &profile.lines[0];
+        assert_eq!(line.function.as_deref(), Some("helper"));
+        assert_eq!(line.line, Some(7));
+        assert_eq!(counts.constraints, 5);
+        assert_eq!(counts.witnesses, 4);
+
+        assert_eq!(
+            profile.to_folded(),
+            "main;helper;main.zn:7 5\nmain;main.zn:3 2\nmain;main.zn:? 1\n"
+        );
+    }
+}
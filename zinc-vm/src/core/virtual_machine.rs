@@ -8,6 +8,7 @@ use crate::core::contract::storage::leaf::LeafVariant;
 use crate::core::execution_state::cell::Cell;
 use crate::core::location::Location;
 use crate::error::Error;
+use crate::gadgets::contract::merkle_tree::hasher::IHasher as IMerkleTreeHasher;
 use crate::gadgets::contract::merkle_tree::IMerkleTree;
 use crate::gadgets::scalar::Scalar;
 use crate::instructions::call_library::INativeCallable;
@@ -20,6 +21,7 @@ pub trait IVirtualMachine {
     type E: IEngine;
     type CS: ConstraintSystem<Self::E>;
     type S: IMerkleTree<Self::E>;
+    type H: IMerkleTreeHasher<Self::E>;
 
     // Operations with evaluation stack
 
@@ -70,7 +72,7 @@ pub trait IVirtualMachine {
     fn branch_else(&mut self) -> Result<(), Error>;
     fn branch_end(&mut self) -> Result<(), Error>;
 
-    fn call_native<F: INativeCallable<Self::E, Self::S>>(
+    fn call_native<F: INativeCallable<Self::E, Self::S, Self::H>>(
         &mut self,
         function: F,
     ) -> Result<(), Error>;
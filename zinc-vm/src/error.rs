@@ -109,12 +109,18 @@ pub enum Error {
     #[error("the unit test data is missing")]
     UnitTestDataMissing,
 
+    #[error("unit test `{name}` does not exist")]
+    UnitTestNotFound { name: String },
+
     #[error("the instruction is available only for contracts")]
     OnlyForContracts,
 
     #[error("invalid storage value")]
     InvalidStorageValue,
 
+    #[error("map key slot {0} is already occupied by another key")]
+    MapSlotCollision(u64),
+
     #[error("contract {address} does not exist")]
     ContractNotFound { address: String },
 
@@ -126,4 +132,10 @@ pub enum Error {
 
     #[error("contract method `{found}` does not exist")]
     MethodNotFound { found: String },
+
+    #[error("debugger input/output: {0}")]
+    DebuggerInputOutput(#[from] std::io::Error),
+
+    #[error("the execution has been interrupted by the debugger")]
+    DebuggerInterrupted,
 }
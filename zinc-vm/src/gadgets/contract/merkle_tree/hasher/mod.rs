@@ -1,5 +1,9 @@
+pub mod poseidon;
+pub mod rescue;
 pub mod sha256;
 
+use franklin_crypto::bellman::pairing::ff::Field;
+use franklin_crypto::bellman::pairing::ff::PrimeField;
 use franklin_crypto::bellman::ConstraintSystem;
 use franklin_crypto::circuit::boolean::Boolean;
 
@@ -22,4 +26,42 @@ pub trait IHasher<E: IEngine>: Default {
     ) -> Result<Vec<Boolean>, Error>
     where
         CS: ConstraintSystem<E>;
+
+    ///
+    /// Computes the leaf value hash outside of the circuit, that is, for the witness generation.
+    ///
+    fn leaf_value_hash_native(&self, leaf_value: &[Scalar<E>]) -> Vec<bool>;
+
+    ///
+    /// Computes the node hash outside of the circuit, that is, for the witness generation.
+    ///
+    fn node_hash_native(&self, left_node: &[bool], right_node: &[bool]) -> Vec<bool>;
+}
+
+///
+/// Converts the field element into its little-endian bits, the same way `into_bits_le_strict`
+/// does in the circuit.
+///
+pub fn fr_into_bits<E: IEngine>(value: E::Fr) -> Vec<bool> {
+    let repr = value.into_repr();
+    let limbs = repr.as_ref();
+
+    (0..zinc_const::bitlength::FIELD)
+        .map(|index| (limbs[index / 64] >> (index % 64)) & 1 == 1)
+        .collect()
+}
+
+///
+/// Packs the little-endian bits into a field element, the same way `pack_bits_to_element` does
+/// in the circuit.
+///
+pub fn bits_into_fr<E: IEngine>(bits: &[bool]) -> E::Fr {
+    let mut value = E::Fr::zero();
+    for bit in bits.iter().rev() {
+        value.double();
+        if *bit {
+            value.add_assign(&E::Fr::one());
+        }
+    }
+    value
 }
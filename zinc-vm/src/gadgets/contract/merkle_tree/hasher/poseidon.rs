@@ -0,0 +1,102 @@
+use franklin_crypto::bellman::pairing::ff::Field;
+use franklin_crypto::bellman::ConstraintSystem;
+use franklin_crypto::circuit::boolean::Boolean;
+use franklin_crypto::circuit::num::AllocatedNum;
+use franklin_crypto::circuit::poseidon_hash;
+
+use crate::error::Error;
+use crate::gadgets::contract::merkle_tree::hasher::IHasher as IMerkleTreeHasher;
+use crate::gadgets::scalar::Scalar;
+use crate::IEngine;
+
+///
+/// The Poseidon algebraic hasher over the engine scalar field.
+///
+/// The leaf value fields are hashed as field elements, and the node hashes are packed into
+/// field elements before hashing, so the hash width is the field bitlength.
+///
+#[derive(Default)]
+pub struct Hasher {}
+
+impl<E: IEngine> IMerkleTreeHasher<E> for Hasher {
+    fn hash_width(&self) -> usize {
+        zinc_const::bitlength::FIELD
+    }
+
+    fn leaf_value_hash<CS>(
+        &self,
+        mut cs: CS,
+        leaf_value: &[Scalar<E>],
+    ) -> Result<Vec<Boolean>, Error>
+    where
+        CS: ConstraintSystem<E>,
+    {
+        let mut preimage = Vec::with_capacity(leaf_value.len());
+        for (index, field) in leaf_value.iter().enumerate() {
+            preimage.push(
+                field
+                    .to_expression::<CS>()
+                    .into_number(cs.namespace(|| format!("{} field of leaf value", index)))?,
+            );
+        }
+
+        let hash = poseidon_hash::poseidon_hash(
+            cs.namespace(|| "leaf_value_poseidon"),
+            preimage.as_slice(),
+            E::poseidon_params(),
+        )?;
+
+        Ok(hash[0].into_bits_le_strict(cs.namespace(|| "leaf_value_poseidon into bits"))?)
+    }
+
+    fn node_hash<CS>(
+        &self,
+        mut cs: CS,
+        left_node: &[Boolean],
+        right_node: &[Boolean],
+    ) -> Result<Vec<Boolean>, Error>
+    where
+        CS: ConstraintSystem<E>,
+    {
+        if left_node.len() != zinc_const::bitlength::FIELD
+            || right_node.len() != zinc_const::bitlength::FIELD
+        {
+            return Err(Error::RequireError("Incorrect node hash width".into()));
+        }
+
+        let left_node =
+            AllocatedNum::pack_bits_to_element(cs.namespace(|| "left node"), left_node)?;
+        let right_node =
+            AllocatedNum::pack_bits_to_element(cs.namespace(|| "right node"), right_node)?;
+
+        let hash = poseidon_hash::poseidon_hash(
+            cs.namespace(|| "node_poseidon"),
+            &[left_node, right_node],
+            E::poseidon_params(),
+        )?;
+
+        Ok(hash[0].into_bits_le_strict(cs.namespace(|| "node_poseidon into bits"))?)
+    }
+
+    fn leaf_value_hash_native(&self, leaf_value: &[Scalar<E>]) -> Vec<bool> {
+        let preimage: Vec<E::Fr> = leaf_value
+            .iter()
+            .map(|field| field.get_value().unwrap_or_else(E::Fr::zero))
+            .collect();
+
+        let hash = franklin_crypto::poseidon::poseidon_hash::<E>(E::poseidon_params(), &preimage);
+
+        super::fr_into_bits::<E>(hash[0])
+    }
+
+    fn node_hash_native(&self, left_node: &[bool], right_node: &[bool]) -> Vec<bool> {
+        let preimage = [
+            super::bits_into_fr::<E>(left_node),
+            super::bits_into_fr::<E>(right_node),
+        ];
+
+        let hash = franklin_crypto::poseidon::poseidon_hash::<E>(E::poseidon_params(), &preimage);
+
+        super::fr_into_bits::<E>(hash[0])
+    }
+}
@@ -2,6 +2,7 @@ use franklin_crypto::bellman::ConstraintSystem;
 use franklin_crypto::circuit::boolean::Boolean;
 use franklin_crypto::circuit::sha256;
 
+use crate::core::contract::storage::sha256 as native_sha256;
 use crate::error::Error;
 use crate::gadgets::contract::merkle_tree::hasher::IHasher as IMerkleTreeHasher;
 use crate::gadgets::scalar::Scalar;
@@ -63,4 +64,39 @@ impl<E: IEngine> IMerkleTreeHasher<E> for Hasher {
             &[left_node, right_node].concat(),
         )?)
     }
+
+    fn leaf_value_hash_native(&self, leaf_value: &[Scalar<E>]) -> Vec<bool> {
+        bytes_to_bits(native_sha256::leaf_value_hash::<E>(leaf_value.to_vec()).as_slice())
+    }
+
+    fn node_hash_native(&self, left_node: &[bool], right_node: &[bool]) -> Vec<bool> {
+        let preimage = bits_to_bytes(&[left_node, right_node].concat());
+        bytes_to_bits(native_sha256::sha256::<E>(preimage.as_slice()).as_slice())
+    }
+}
+
+///
+/// Converts the bytes into bits in the `sha256` gadget order, that is, big-endian in each byte.
+///
+fn bytes_to_bits(bytes: &[u8]) -> Vec<bool> {
+    let mut bits = Vec::with_capacity(bytes.len() * zinc_const::bitlength::BYTE);
+    for byte in bytes.iter() {
+        for index in (0..zinc_const::bitlength::BYTE).rev() {
+            bits.push((byte >> index) & 1 == 1);
+        }
+    }
+    bits
+}
+
+///
+/// Converts the bits in the `sha256` gadget order into bytes.
+///
+fn bits_to_bytes(bits: &[bool]) -> Vec<u8> {
+    bits.chunks(zinc_const::bitlength::BYTE)
+        .map(|chunk| {
+            chunk
+                .iter()
+                .fold(0u8, |byte, bit| (byte << 1) | if *bit { 1 } else { 0 })
+        })
+        .collect()
 }
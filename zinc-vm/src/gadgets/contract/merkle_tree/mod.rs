@@ -1,5 +1,6 @@
 pub mod allocated_leaf;
 pub mod hasher;
+pub mod sparse;
 
 use num::BigInt;
 
@@ -54,7 +55,7 @@ pub trait IMerkleTree<E: IEngine> {
     ///
     /// Returns the Merkle tree root hash.
     ///
-    fn root_hash(&self) -> E::Fr;
+    fn root_hash(&self) -> Result<E::Fr, Error>;
 
     ///
     /// Returns the depth of the Merkle tree.
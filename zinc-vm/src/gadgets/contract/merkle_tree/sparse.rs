@@ -0,0 +1,701 @@
+//!
+//! The sparse Merkle tree gadget.
+//!
+
+use std::collections::HashMap;
+
+use franklin_crypto::bellman::pairing::ff::Field;
+use franklin_crypto::bellman::ConstraintSystem;
+use franklin_crypto::circuit::boolean::AllocatedBit;
+use franklin_crypto::circuit::boolean::Boolean;
+
+use crate::error::Error;
+use crate::gadgets;
+use crate::gadgets::contract::merkle_tree::allocated_leaf::AllocatedLeaf;
+use crate::gadgets::contract::merkle_tree::hasher::IHasher as IMerkleTreeHasher;
+use crate::gadgets::scalar::Scalar;
+use crate::IEngine;
+
+///
+/// The native map entries, that is, the flattened keys with their flattened values.
+///
+pub type Entries<E> = [(Vec<Scalar<E>>, Vec<Scalar<E>>)];
+
+///
+/// The sparse Merkle tree, which backs the `MTreeMap` contract storage fields.
+///
+/// The entry slot is given by the first `depth` bits of the flattened key hash. The occupied
+/// leaves contain the hash of the flattened key and value, and the empty leaves are zero.
+///
+/// The native entries are passed to each operation as the witness, and the operation proves
+/// the membership or non-membership of the key against the root, which is allocated once when
+/// the tree is created. The updating operations also prove the new root over the same path.
+///
+pub struct SparseMerkleTree<E: IEngine, H: IMerkleTreeHasher<E>> {
+    depth: usize,
+    hasher: H,
+    root_hash: Scalar<E>,
+}
+
+///
+/// The opened tree slot, which is shared by all the operations.
+///
+struct Opening<E: IEngine> {
+    slot: u64,
+    is_occupied: Scalar<E>,
+    found: Scalar<E>,
+    value: Vec<Scalar<E>>,
+    leaf_hash: Vec<Boolean>,
+    index_bits: Vec<Scalar<E>>,
+    authentication_path: Vec<Vec<Scalar<E>>>,
+}
+
+impl<E, H> SparseMerkleTree<E, H>
+where
+    E: IEngine,
+    H: IMerkleTreeHasher<E>,
+{
+    ///
+    /// Allocates the root hash of the tree with `entries`.
+    ///
+    pub fn new<CS>(mut cs: CS, depth: usize, entries: &Entries<E>) -> Result<Self, Error>
+    where
+        CS: ConstraintSystem<E>,
+    {
+        let hasher = H::default();
+
+        let root_hash_value = Self::root_hash_native(&hasher, depth, entries)?;
+        let root_hash_variable = cs.alloc(|| "root hash variable", || Ok(root_hash_value))?;
+        let root_hash = Scalar::<E>::new_unchecked_variable(
+            Some(root_hash_value),
+            root_hash_variable,
+            zinc_types::ScalarType::Field,
+        );
+
+        Ok(Self {
+            depth,
+            hasher,
+            root_hash,
+        })
+    }
+
+    ///
+    /// Proves whether the `key` is in the tree.
+    ///
+    /// Returns the `found` flag and the value, which is zero if the key is not found.
+    ///
+    pub fn get<CS>(
+        &self,
+        cs: CS,
+        entries: &Entries<E>,
+        key: &[Scalar<E>],
+        value_size: usize,
+    ) -> Result<(Scalar<E>, Vec<Scalar<E>>), Error>
+    where
+        CS: ConstraintSystem<E>,
+    {
+        let opening = self.open(cs, entries, key, value_size)?;
+
+        Ok((opening.found, opening.value))
+    }
+
+    ///
+    /// Proves the insertion of the `key` with `value`, if the `condition` is true.
+    ///
+    /// Returns the `found` flag and the previous value, which is zero if the key is not found.
+    ///
+    pub fn insert<CS>(
+        &mut self,
+        mut cs: CS,
+        entries: &Entries<E>,
+        key: &[Scalar<E>],
+        value: &[Scalar<E>],
+        condition: &Scalar<E>,
+    ) -> Result<(Scalar<E>, Vec<Scalar<E>>), Error>
+    where
+        CS: ConstraintSystem<E>,
+    {
+        let opening = self.open(cs.namespace(|| "opening"), entries, key, value.len())?;
+
+        let is_not_found =
+            gadgets::logical::not::not(cs.namespace(|| "not found"), &opening.found)?;
+        let is_foreign = gadgets::logical::and::and(
+            cs.namespace(|| "is foreign"),
+            &opening.is_occupied,
+            &is_not_found,
+        )?;
+        let is_collision =
+            gadgets::logical::and::and(cs.namespace(|| "is collision"), &is_foreign, condition)?;
+        if is_collision
+            .get_value()
+            .map(|value| !value.is_zero())
+            .unwrap_or_default()
+        {
+            return Err(Error::MapSlotCollision(opening.slot));
+        }
+        cs.enforce(
+            || "slot collision",
+            |zero| zero + &is_collision.to_linear_combination::<CS>(),
+            |zero| zero + CS::one(),
+            |zero| zero,
+        );
+
+        let leaf_hash = self
+            .hasher
+            .leaf_value_hash(cs.namespace(|| "leaf hash"), &[key, value].concat())?;
+        self.update(cs.namespace(|| "update"), leaf_hash, &opening, condition)?;
+
+        Ok((opening.found, opening.value))
+    }
+
+    ///
+    /// Proves the removal of the `key`, if the `condition` is true.
+    ///
+    /// Returns the `found` flag and the removed value, which is zero if the key is not found.
+    ///
+    pub fn remove<CS>(
+        &mut self,
+        mut cs: CS,
+        entries: &Entries<E>,
+        key: &[Scalar<E>],
+        value_size: usize,
+        condition: &Scalar<E>,
+    ) -> Result<(Scalar<E>, Vec<Scalar<E>>), Error>
+    where
+        CS: ConstraintSystem<E>,
+    {
+        let opening = self.open(cs.namespace(|| "opening"), entries, key, value_size)?;
+
+        let is_not_found = opening
+            .found
+            .to_boolean(cs.namespace(|| "found to boolean"))?
+            .not();
+        let mut leaf_hash = Vec::with_capacity(opening.leaf_hash.len());
+        for (index, bit) in opening.leaf_hash.iter().enumerate() {
+            leaf_hash.push(Boolean::and(
+                cs.namespace(|| format!("leaf hash bit {}", index)),
+                bit,
+                &is_not_found,
+            )?);
+        }
+        self.update(cs.namespace(|| "update"), leaf_hash, &opening, condition)?;
+
+        Ok((opening.found, opening.value))
+    }
+
+    ///
+    /// The root hash, which is a field element packed from the root hash bits.
+    ///
+    pub fn root_hash(&self) -> &Scalar<E> {
+        &self.root_hash
+    }
+
+    ///
+    /// Computes the root hash of the tree with `entries` outside of the circuit.
+    ///
+    pub fn root_hash_native(
+        hasher: &H,
+        depth: usize,
+        entries: &Entries<E>,
+    ) -> Result<E::Fr, Error> {
+        let leaves = Self::leaves(hasher, depth, entries)?
+            .into_iter()
+            .map(|(slot, (_position, hash))| (slot, hash))
+            .collect();
+        let (_authentication_path, root_hash_bits) =
+            Self::authentication_path(hasher, depth, leaves, 0);
+
+        Ok(Self::pack_native(root_hash_bits.as_slice()))
+    }
+
+    ///
+    /// Allocates the slot witness for the `key` and enforces it against the root hash.
+    ///
+    fn open<CS>(
+        &self,
+        mut cs: CS,
+        entries: &Entries<E>,
+        key: &[Scalar<E>],
+        value_size: usize,
+    ) -> Result<Opening<E>, Error>
+    where
+        CS: ConstraintSystem<E>,
+    {
+        let key_hash = self
+            .hasher
+            .leaf_value_hash(cs.namespace(|| "key hash"), key)?;
+        let mut slot = 0;
+        let mut index_bits = Vec::with_capacity(self.depth);
+        for (index, bit) in key_hash.into_iter().take(self.depth).enumerate() {
+            if bit.get_value().unwrap_or_default() {
+                slot |= 1 << index;
+            }
+            index_bits.push(Scalar::from_boolean(
+                cs.namespace(|| format!("index bit {}", index)),
+                bit,
+            )?);
+        }
+
+        let leaves = Self::leaves(&self.hasher, self.depth, entries)?;
+        let (occupant_key, occupant_value) = match leaves.get(&slot) {
+            Some((position, _hash)) => entries[*position].to_owned(),
+            None => (
+                key.iter()
+                    .map(|field| Scalar::new_constant_usize(0, field.get_type()))
+                    .collect(),
+                vec![Scalar::new_constant_usize(0, zinc_types::ScalarType::Field); value_size],
+            ),
+        };
+        if occupant_key.len() != key.len() || occupant_value.len() != value_size {
+            return Err(Error::InvalidStorageValue);
+        }
+
+        let is_occupied = Boolean::from(AllocatedBit::alloc(
+            cs.namespace(|| "is occupied"),
+            Some(leaves.contains_key(&slot)),
+        )?);
+        let occupant_key =
+            AllocatedLeaf::alloc_leaf_fields(cs.namespace(|| "occupant key"), occupant_key)?;
+        let occupant_value =
+            AllocatedLeaf::alloc_leaf_fields(cs.namespace(|| "occupant value"), occupant_value)?;
+        let occupant_hash = self.hasher.leaf_value_hash(
+            cs.namespace(|| "occupant hash"),
+            &[occupant_key.as_slice(), occupant_value.as_slice()].concat(),
+        )?;
+        let mut leaf_hash = Vec::with_capacity(occupant_hash.len());
+        for (index, bit) in occupant_hash.iter().enumerate() {
+            leaf_hash.push(Boolean::and(
+                cs.namespace(|| format!("leaf hash bit {}", index)),
+                bit,
+                &is_occupied,
+            )?);
+        }
+
+        let leaves = leaves
+            .into_iter()
+            .map(|(slot, (_position, hash))| (slot, hash))
+            .collect();
+        let (authentication_path, _root_hash_bits) =
+            Self::authentication_path(&self.hasher, self.depth, leaves, slot);
+        let authentication_path = AllocatedLeaf::<E>::alloc_authentication_path(
+            cs.namespace(|| "authentication path"),
+            self.depth,
+            authentication_path,
+        )?;
+
+        let root_hash = AllocatedLeaf::LeafHash(leaf_hash.clone()).enforce_merkle_tree_path(
+            cs.namespace(|| "membership path"),
+            self.depth,
+            &self.hasher,
+            index_bits.as_slice(),
+            authentication_path.as_slice(),
+        )?;
+        cs.enforce(
+            || "root hash equality",
+            |zero| {
+                zero + &root_hash.to_linear_combination::<CS>()
+                    - &self.root_hash.to_linear_combination::<CS>()
+            },
+            |zero| zero + CS::one(),
+            |zero| zero,
+        );
+
+        let is_occupied = Scalar::from_boolean(cs.namespace(|| "is occupied scalar"), is_occupied)?;
+        let mut found = is_occupied.clone();
+        for (index, (field, occupant_field)) in key.iter().zip(occupant_key.iter()).enumerate() {
+            let is_equal = gadgets::comparison::equals(
+                cs.namespace(|| format!("key field {} equals", index)),
+                field,
+                occupant_field,
+            )?;
+            found = gadgets::logical::and::and(
+                cs.namespace(|| format!("key field {} found", index)),
+                &found,
+                &is_equal,
+            )?;
+        }
+
+        let mut value = Vec::with_capacity(occupant_value.len());
+        for (index, field) in occupant_value.into_iter().enumerate() {
+            let zero = Scalar::new_constant_usize(0, field.get_type());
+            value.push(gadgets::select::conditional(
+                cs.namespace(|| format!("value field {}", index)),
+                &found,
+                &field,
+                &zero,
+            )?);
+        }
+
+        Ok(Opening {
+            slot,
+            is_occupied,
+            found,
+            value,
+            leaf_hash,
+            index_bits,
+            authentication_path,
+        })
+    }
+
+    ///
+    /// Computes the new root hash with `leaf_hash` at the opened slot, and sets it if the
+    /// `condition` is true.
+    ///
+    fn update<CS>(
+        &mut self,
+        mut cs: CS,
+        leaf_hash: Vec<Boolean>,
+        opening: &Opening<E>,
+        condition: &Scalar<E>,
+    ) -> Result<(), Error>
+    where
+        CS: ConstraintSystem<E>,
+    {
+        let root_hash = AllocatedLeaf::LeafHash(leaf_hash).enforce_merkle_tree_path(
+            cs.namespace(|| "update path"),
+            self.depth,
+            &self.hasher,
+            opening.index_bits.as_slice(),
+            opening.authentication_path.as_slice(),
+        )?;
+
+        self.root_hash = gadgets::select::conditional(
+            cs.namespace(|| "root hash"),
+            condition,
+            &root_hash,
+            &self.root_hash,
+        )?;
+
+        Ok(())
+    }
+
+    ///
+    /// Computes the native leaf hashes with the entry positions, using the slots as keys.
+    ///
+    fn leaves(
+        hasher: &H,
+        depth: usize,
+        entries: &Entries<E>,
+    ) -> Result<HashMap<u64, (usize, Vec<bool>)>, Error> {
+        let mut leaves = HashMap::with_capacity(entries.len());
+        for (position, (key, value)) in entries.iter().enumerate() {
+            let slot = hasher
+                .leaf_value_hash_native(key.as_slice())
+                .into_iter()
+                .take(depth)
+                .enumerate()
+                .fold(0, |slot, (index, bit)| slot | ((bit as u64) << index));
+            let hash = hasher.leaf_value_hash_native(&[key.as_slice(), value.as_slice()].concat());
+
+            if leaves.insert(slot, (position, hash)).is_some() {
+                return Err(Error::MapSlotCollision(slot));
+            }
+        }
+        Ok(leaves)
+    }
+
+    ///
+    /// Computes the native authentication path of the `slot` and the root hash.
+    ///
+    fn authentication_path(
+        hasher: &H,
+        depth: usize,
+        mut level: HashMap<u64, Vec<bool>>,
+        mut slot: u64,
+    ) -> (Vec<Vec<bool>>, Vec<bool>) {
+        let mut empty = vec![false; hasher.hash_width()];
+        let mut authentication_path = Vec::with_capacity(depth);
+
+        for _ in 0..depth {
+            authentication_path.push(
+                level
+                    .get(&(slot ^ 1))
+                    .cloned()
+                    .unwrap_or_else(|| empty.clone()),
+            );
+
+            let mut parents = HashMap::with_capacity(level.len());
+            for node in level.keys() {
+                let parent = node >> 1;
+                if parents.contains_key(&parent) {
+                    continue;
+                }
+
+                let left = level.get(&(parent << 1)).unwrap_or(&empty);
+                let right = level.get(&((parent << 1) | 1)).unwrap_or(&empty);
+                parents.insert(parent, hasher.node_hash_native(left, right));
+            }
+
+            empty = hasher.node_hash_native(empty.as_slice(), empty.as_slice());
+            level = parents;
+            slot >>= 1;
+        }
+
+        let root_hash = level.remove(&0).unwrap_or(empty);
+        (authentication_path, root_hash)
+    }
+
+    ///
+    /// Packs the root hash bits into a field element the same way the circuit does.
+    ///
+    fn pack_native(root_hash_bits: &[bool]) -> E::Fr {
+        let mut root_hash = E::Fr::zero();
+        for bit in root_hash_bits
+            .iter()
+            .take(zinc_const::bitlength::SHA256_HASH - zinc_const::bitlength::BYTE)
+            .rev()
+        {
+            root_hash.double();
+            if *bit {
+                root_hash.add_assign(&E::Fr::one());
+            }
+        }
+        root_hash
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use franklin_crypto::bellman::pairing::bn256::Bn256;
+    use franklin_crypto::bellman::ConstraintSystem;
+    use franklin_crypto::circuit::test::TestConstraintSystem;
+
+    use crate::gadgets::contract::merkle_tree::hasher::poseidon::Hasher as PoseidonHasher;
+    use crate::gadgets::contract::merkle_tree::hasher::rescue::Hasher as RescueHasher;
+    use crate::gadgets::contract::merkle_tree::hasher::sha256::Hasher as Sha256Hasher;
+    use crate::gadgets::contract::merkle_tree::hasher::IHasher as IMerkleTreeHasher;
+    use crate::gadgets::scalar::Scalar;
+
+    use super::SparseMerkleTree;
+
+    const DEPTH: usize = 2;
+
+    type Tree = SparseMerkleTree<Bn256, Sha256Hasher>;
+
+    fn field(value: usize) -> Scalar<Bn256> {
+        Scalar::new_constant_usize(value, zinc_types::ScalarType::Field)
+    }
+
+    fn entry(key: usize, value: usize) -> (Vec<Scalar<Bn256>>, Vec<Scalar<Bn256>>) {
+        (vec![field(key)], vec![field(value)])
+    }
+
+    fn root_hash(entries: &[(Vec<Scalar<Bn256>>, Vec<Scalar<Bn256>>)]) -> Scalar<Bn256> {
+        let mut cs = TestConstraintSystem::<Bn256>::new();
+        Tree::new(cs.namespace(|| "tree"), DEPTH, entries)
+            .expect(zinc_const::panic::TEST_DATA_VALID)
+            .root_hash()
+            .to_owned()
+    }
+
+    #[test]
+    fn ok_membership() {
+        let mut cs = TestConstraintSystem::<Bn256>::new();
+        let entries = vec![entry(1, 42)];
+        let tree = Tree::new(cs.namespace(|| "tree"), DEPTH, entries.as_slice())
+            .expect(zinc_const::panic::TEST_DATA_VALID);
+
+        let (found, value) = tree
+            .get(cs.namespace(|| "get"), entries.as_slice(), &[field(1)], 1)
+            .expect(zinc_const::panic::TEST_DATA_VALID);
+
+        assert_eq!(found.get_value(), field(1).get_value());
+        assert_eq!(value[0].get_value(), field(42).get_value());
+        assert!(cs.is_satisfied());
+    }
+
+    fn membership_and_insert<H: IMerkleTreeHasher<Bn256>>() {
+        let mut cs = TestConstraintSystem::<Bn256>::new();
+        let entries = vec![entry(1, 42)];
+        let mut tree =
+            SparseMerkleTree::<Bn256, H>::new(cs.namespace(|| "tree"), DEPTH, entries.as_slice())
+                .expect(zinc_const::panic::TEST_DATA_VALID);
+
+        let (found, value) = tree
+            .get(cs.namespace(|| "get"), entries.as_slice(), &[field(1)], 1)
+            .expect(zinc_const::panic::TEST_DATA_VALID);
+        assert_eq!(found.get_value(), field(1).get_value());
+        assert_eq!(value[0].get_value(), field(42).get_value());
+
+        let condition = Scalar::new_constant_bool(true);
+        tree.insert(
+            cs.namespace(|| "insert"),
+            entries.as_slice(),
+            &[field(1)],
+            &[field(43)],
+            &condition,
+        )
+        .expect(zinc_const::panic::TEST_DATA_VALID);
+
+        let updated = vec![entry(1, 43)];
+        let mut expected_cs = TestConstraintSystem::<Bn256>::new();
+        let expected = SparseMerkleTree::<Bn256, H>::new(
+            expected_cs.namespace(|| "tree"),
+            DEPTH,
+            updated.as_slice(),
+        )
+        .expect(zinc_const::panic::TEST_DATA_VALID);
+        assert_eq!(
+            tree.root_hash().get_value(),
+            expected.root_hash().get_value()
+        );
+        assert!(cs.is_satisfied());
+    }
+
+    #[test]
+    fn ok_rescue_hasher() {
+        membership_and_insert::<RescueHasher>();
+    }
+
+    #[test]
+    fn ok_poseidon_hasher() {
+        membership_and_insert::<PoseidonHasher>();
+    }
+
+    #[test]
+    fn ok_non_membership() {
+        let mut cs = TestConstraintSystem::<Bn256>::new();
+        let entries = vec![entry(1, 42)];
+        let tree = Tree::new(cs.namespace(|| "tree"), DEPTH, entries.as_slice())
+            .expect(zinc_const::panic::TEST_DATA_VALID);
+
+        let (found, value) = tree
+            .get(cs.namespace(|| "get"), entries.as_slice(), &[field(2)], 1)
+            .expect(zinc_const::panic::TEST_DATA_VALID);
+
+        assert_eq!(found.get_value(), field(0).get_value());
+        assert_eq!(value[0].get_value(), field(0).get_value());
+        assert!(cs.is_satisfied());
+    }
+
+    #[test]
+    fn ok_insert() {
+        let mut cs = TestConstraintSystem::<Bn256>::new();
+        let condition = Scalar::new_constant_bool(true);
+        let mut tree = Tree::new(cs.namespace(|| "tree"), DEPTH, &[])
+            .expect(zinc_const::panic::TEST_DATA_VALID);
+
+        let (found, _value) = tree
+            .insert(
+                cs.namespace(|| "insert"),
+                &[],
+                &[field(1)],
+                &[field(42)],
+                &condition,
+            )
+            .expect(zinc_const::panic::TEST_DATA_VALID);
+
+        assert_eq!(found.get_value(), field(0).get_value());
+        assert_eq!(
+            tree.root_hash().get_value(),
+            root_hash(&[entry(1, 42)]).get_value()
+        );
+        assert!(cs.is_satisfied());
+    }
+
+    #[test]
+    fn ok_insert_false_condition() {
+        let mut cs = TestConstraintSystem::<Bn256>::new();
+        let condition = Scalar::new_constant_bool(false);
+        let mut tree = Tree::new(cs.namespace(|| "tree"), DEPTH, &[])
+            .expect(zinc_const::panic::TEST_DATA_VALID);
+
+        tree.insert(
+            cs.namespace(|| "insert"),
+            &[],
+            &[field(1)],
+            &[field(42)],
+            &condition,
+        )
+        .expect(zinc_const::panic::TEST_DATA_VALID);
+
+        assert_eq!(tree.root_hash().get_value(), root_hash(&[]).get_value());
+        assert!(cs.is_satisfied());
+    }
+
+    #[test]
+    fn ok_remove() {
+        let mut cs = TestConstraintSystem::<Bn256>::new();
+        let condition = Scalar::new_constant_bool(true);
+        let entries = vec![entry(1, 42)];
+        let mut tree = Tree::new(cs.namespace(|| "tree"), DEPTH, entries.as_slice())
+            .expect(zinc_const::panic::TEST_DATA_VALID);
+
+        let (found, value) = tree
+            .remove(
+                cs.namespace(|| "remove"),
+                entries.as_slice(),
+                &[field(1)],
+                1,
+                &condition,
+            )
+            .expect(zinc_const::panic::TEST_DATA_VALID);
+
+        assert_eq!(found.get_value(), field(1).get_value());
+        assert_eq!(value[0].get_value(), field(42).get_value());
+        assert_eq!(tree.root_hash().get_value(), root_hash(&[]).get_value());
+        assert!(cs.is_satisfied());
+    }
+
+    #[test]
+    fn error_tampered_absence() {
+        let mut cs = TestConstraintSystem::<Bn256>::new();
+        let entries = vec![entry(1, 42)];
+        let tree = Tree::new(cs.namespace(|| "tree"), DEPTH, entries.as_slice())
+            .expect(zinc_const::panic::TEST_DATA_VALID);
+
+        let (found, _value) = tree
+            .get(cs.namespace(|| "get"), &[], &[field(1)], 1)
+            .expect(zinc_const::panic::TEST_DATA_VALID);
+
+        assert_eq!(found.get_value(), field(0).get_value());
+        assert!(!cs.is_satisfied());
+    }
+
+    #[test]
+    fn error_tampered_value() {
+        let mut cs = TestConstraintSystem::<Bn256>::new();
+        let entries = vec![entry(1, 42)];
+        let tree = Tree::new(cs.namespace(|| "tree"), DEPTH, entries.as_slice())
+            .expect(zinc_const::panic::TEST_DATA_VALID);
+
+        let (_found, value) = tree
+            .get(cs.namespace(|| "get"), &[entry(1, 43)], &[field(1)], 1)
+            .expect(zinc_const::panic::TEST_DATA_VALID);
+
+        assert_eq!(value[0].get_value(), field(43).get_value());
+        assert!(!cs.is_satisfied());
+    }
+
+    #[test]
+    fn error_tampered_removal() {
+        let mut cs = TestConstraintSystem::<Bn256>::new();
+        let condition = Scalar::new_constant_bool(true);
+        let entries = vec![entry(1, 42)];
+        let mut tree = Tree::new(cs.namespace(|| "tree"), DEPTH, entries.as_slice())
+            .expect(zinc_const::panic::TEST_DATA_VALID);
+
+        tree.remove(cs.namespace(|| "remove"), &[], &[field(1)], 1, &condition)
+            .expect(zinc_const::panic::TEST_DATA_VALID);
+
+        assert!(!cs.is_satisfied());
+    }
+
+    #[test]
+    fn error_tampered_root() {
+        let mut cs = TestConstraintSystem::<Bn256>::new();
+        let entries = vec![entry(1, 42)];
+        let tree = Tree::new(cs.namespace(|| "tree"), DEPTH, entries.as_slice())
+            .expect(zinc_const::panic::TEST_DATA_VALID);
+
+        tree.get(cs.namespace(|| "get"), entries.as_slice(), &[field(1)], 1)
+            .expect(zinc_const::panic::TEST_DATA_VALID);
+        assert!(cs.is_satisfied());
+
+        let tampered = root_hash(&[entry(1, 43)])
+            .get_value()
+            .expect(zinc_const::panic::TEST_DATA_VALID);
+        cs.set("tree/root hash variable", tampered);
+        assert!(!cs.is_satisfied());
+    }
+}
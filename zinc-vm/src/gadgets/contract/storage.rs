@@ -1,8 +1,14 @@
 use std::borrow::BorrowMut;
-use std::marker::PhantomData;
+use std::collections::HashMap;
 
+use num::bigint::ToBigInt;
+use num::BigInt;
+use num::ToPrimitive;
+
+use franklin_crypto::bellman::pairing::ff::Field;
 use franklin_crypto::bellman::ConstraintSystem;
-use franklin_crypto::bellman::SynthesisError;
+use franklin_crypto::circuit::boolean::Boolean;
+use franklin_crypto::circuit::num::AllocatedNum;
 
 use crate::core::contract::storage::leaf::LeafOutput;
 use crate::core::contract::storage::leaf::LeafVariant;
@@ -10,15 +16,24 @@ use crate::error::Error;
 use crate::gadgets;
 use crate::gadgets::contract::merkle_tree::allocated_leaf::AllocatedLeaf;
 use crate::gadgets::contract::merkle_tree::hasher::IHasher as IMerkleTreeHasher;
+use crate::gadgets::contract::merkle_tree::sparse::SparseMerkleTree;
 use crate::gadgets::contract::merkle_tree::IMerkleTree;
 use crate::gadgets::scalar::Scalar;
 use crate::IEngine;
 
+///
+/// The contract storage gadget.
+///
+/// The storage fields and the map roots are allocated on the first access, and the root hash
+/// is computed from their current values. The contract state computes the root hash before
+/// executing the method and after it, and both are the public input of the contract method proof,
+/// so the loaded values and the map roots are bound to the committed storage state.
+///
 pub struct StorageGadget<E: IEngine, S: IMerkleTree<E>, H: IMerkleTreeHasher<E>> {
     storage: S,
-    root_hash: Scalar<E>,
-
-    _pd: PhantomData<H>,
+    hasher: H,
+    fields: Option<Vec<Vec<Scalar<E>>>>,
+    maps: HashMap<BigInt, SparseMerkleTree<E, H>>,
 }
 
 impl<E, S, H> StorageGadget<E, S, H>
@@ -27,83 +42,251 @@ where
     S: IMerkleTree<E>,
     H: IMerkleTreeHasher<E>,
 {
-    pub fn new<CS>(mut cs: CS, storage: S) -> Result<Self, SynthesisError>
-    where
-        CS: ConstraintSystem<E>,
-    {
-        let root_hash_value = storage.root_hash();
-        let root_hash_variable = cs.alloc(|| "root hash variable", || Ok(root_hash_value))?;
-        let root_hash = Scalar::<E>::new_unchecked_variable(
-            Some(root_hash_value),
-            root_hash_variable,
-            zinc_types::ScalarType::Field,
-        );
-
-        Ok(StorageGadget {
+    pub fn new(storage: S) -> Self {
+        Self {
             storage,
-            root_hash,
-            _pd: PhantomData,
-        })
+            hasher: H::default(),
+            fields: None,
+            maps: HashMap::new(),
+        }
     }
 
     pub fn load<CS>(
-        &self,
-        mut cs: CS,
+        &mut self,
+        cs: CS,
         index: Scalar<E>,
         _size: usize,
     ) -> Result<Vec<Scalar<E>>, Error>
     where
         CS: ConstraintSystem<E>,
     {
-        let depth = self.storage.depth();
-        let mut index_bits = index.get_bits_le(cs.namespace(|| "index into bits"))?;
-        index_bits.truncate(depth);
-
-        let index = index
-            .get_value()
-            .map(|field| gadgets::scalar::fr_bigint::fr_to_bigint::<E>(&field, false))
-            .expect(zinc_const::panic::TEST_DATA_VALID);
-        let merkle_tree_leaf = self.storage.load(index)?;
+        let position = Self::position(Self::index(&index))?;
 
-        let leaf_value = match merkle_tree_leaf.leaf_values {
-            LeafVariant::Array(array) => array,
-            LeafVariant::Map { .. } => vec![],
-        };
-        let leaf_fields =
-            AllocatedLeaf::alloc_leaf_fields(cs.namespace(|| "alloc leaf fields"), leaf_value)?;
-
-        Ok(leaf_fields)
+        Ok(self.fields(cs)?[position].to_owned())
     }
 
     pub fn store<CS>(
         &mut self,
-        mut cs: CS,
+        cs: CS,
         index: Scalar<E>,
         values: LeafVariant<E>,
     ) -> Result<(), Error>
     where
         CS: ConstraintSystem<E>,
     {
-        let depth = self.storage.depth();
-        let mut index_bits = index.get_bits_le(cs.namespace(|| "index into bits"))?;
-        index_bits.truncate(depth);
+        let index = Self::index(&index);
+        let position = Self::position(index.clone())?;
 
-        let _merkle_tree_leaf = self.storage.store(
-            index
-                .get_value()
-                .map(|field| gadgets::scalar::fr_bigint::fr_to_bigint::<E>(&field, false))
-                .expect(zinc_const::panic::TEST_DATA_VALID),
-            values,
-        )?;
+        if let LeafVariant::Array(ref array) = values {
+            self.fields(cs)?[position] = array.to_owned();
+        }
+        self.storage.store(index, values)?;
 
         Ok(())
     }
 
+    ///
+    /// Proves whether the `key` is in the map at `index`.
+    ///
+    /// Returns the `found` flag and the value, which is zero if the key is not found.
+    ///
+    pub fn map_get<CS>(
+        &mut self,
+        mut cs: CS,
+        index: BigInt,
+        key: &[Scalar<E>],
+    ) -> Result<(Scalar<E>, Vec<Scalar<E>>), Error>
+    where
+        CS: ConstraintSystem<E>,
+    {
+        let (entries, _key_size, value_size) = self.map_entries(index.clone())?;
+
+        self.map_tree(cs.namespace(|| "map tree"), &index)?.get(
+            cs.namespace(|| "map get"),
+            entries.as_slice(),
+            key,
+            value_size,
+        )
+    }
+
+    ///
+    /// Proves the insertion of the `key` with `value` into the map at `index`, and inserts it if
+    /// the `condition` is true.
+    ///
+    /// Returns the `found` flag and the previous value, which is zero if the key is not found.
+    ///
+    pub fn map_insert<CS>(
+        &mut self,
+        mut cs: CS,
+        index: BigInt,
+        key: Vec<Scalar<E>>,
+        value: Vec<Scalar<E>>,
+        condition: &Scalar<E>,
+    ) -> Result<(Scalar<E>, Vec<Scalar<E>>), Error>
+    where
+        CS: ConstraintSystem<E>,
+    {
+        let (mut entries, key_size, value_size) = self.map_entries(index.clone())?;
+
+        let result = self.map_tree(cs.namespace(|| "map tree"), &index)?.insert(
+            cs.namespace(|| "map insert"),
+            entries.as_slice(),
+            key.as_slice(),
+            value.as_slice(),
+            condition,
+        )?;
+
+        if Self::is_executed(condition) {
+            match entries
+                .iter()
+                .position(|(map_key, _value)| map_key.as_slice() == key.as_slice())
+            {
+                Some(position) => entries[position].1 = value,
+                None => entries.push((key, value)),
+            }
+            self.storage.store(
+                index,
+                LeafVariant::Map {
+                    data: entries,
+                    key_size,
+                    value_size,
+                },
+            )?;
+        }
+
+        Ok(result)
+    }
+
+    ///
+    /// Proves the removal of the `key` from the map at `index`, and removes it if the
+    /// `condition` is true.
+    ///
+    /// Returns the `found` flag and the removed value, which is zero if the key is not found.
+    ///
+    pub fn map_remove<CS>(
+        &mut self,
+        mut cs: CS,
+        index: BigInt,
+        key: Vec<Scalar<E>>,
+        condition: &Scalar<E>,
+    ) -> Result<(Scalar<E>, Vec<Scalar<E>>), Error>
+    where
+        CS: ConstraintSystem<E>,
+    {
+        let (mut entries, key_size, value_size) = self.map_entries(index.clone())?;
+
+        let result = self.map_tree(cs.namespace(|| "map tree"), &index)?.remove(
+            cs.namespace(|| "map remove"),
+            entries.as_slice(),
+            key.as_slice(),
+            value_size,
+            condition,
+        )?;
+
+        if Self::is_executed(condition) {
+            entries.retain(|(map_key, _value)| map_key.as_slice() != key.as_slice());
+            self.storage.store(
+                index,
+                LeafVariant::Map {
+                    data: entries,
+                    key_size,
+                    value_size,
+                },
+            )?;
+        }
+
+        Ok(result)
+    }
+
     pub fn into_build(self) -> zinc_types::Value {
         let field_types = self.storage.types().to_owned();
-        let fields = self
-            .storage
-            .into_values()
+        Self::leaves_into_build(self.storage.into_values(), field_types)
+    }
+
+    ///
+    /// Returns the current storage values without consuming the storage.
+    ///
+    pub fn to_build(&self) -> Result<zinc_types::Value, Error> {
+        let field_types = self.storage.types().to_owned();
+
+        let mut leaves = Vec::with_capacity(field_types.len());
+        for index in 0..field_types.len() {
+            let leaf = match self.storage.load(BigInt::from(index))?.leaf_values {
+                LeafVariant::Array(array) => LeafOutput::Array(Self::scalars_to_bigints(array)?),
+                LeafVariant::Map { data, .. } => {
+                    let mut entries = Vec::with_capacity(data.len());
+                    for (key, value) in data.into_iter() {
+                        entries.push((
+                            Self::scalars_to_bigints(key)?,
+                            Self::scalars_to_bigints(value)?,
+                        ));
+                    }
+                    LeafOutput::Map(entries)
+                }
+            };
+            leaves.push(leaf);
+        }
+
+        Ok(Self::leaves_into_build(leaves, field_types))
+    }
+
+    ///
+    /// Computes the root hash from the current storage fields and map roots.
+    ///
+    /// The map leaves contain the hash of the map root. The padding leaves are zero.
+    ///
+    pub fn root_hash<CS>(&mut self, mut cs: CS) -> Result<Scalar<E>, Error>
+    where
+        CS: ConstraintSystem<E>,
+    {
+        let depth = self.storage.depth();
+        let fields = self.fields(cs.namespace(|| "fields"))?.to_owned();
+
+        let mut level = Vec::with_capacity(1 << depth);
+        for (index, field) in fields.into_iter().enumerate() {
+            let leaf_value = match self.maps.get(&BigInt::from(index)) {
+                Some(tree) => vec![tree.root_hash().to_owned()],
+                None => field,
+            };
+            level.push(self.hasher.leaf_value_hash(
+                cs.namespace(|| format!("leaf hash {}", index)),
+                leaf_value.as_slice(),
+            )?);
+        }
+        level.resize(
+            1 << depth,
+            vec![Boolean::constant(false); self.hasher.hash_width()],
+        );
+
+        for height in 1..=depth {
+            let mut parents = Vec::with_capacity(level.len() / 2);
+            for (index, children) in level.chunks(2).enumerate() {
+                parents.push(self.hasher.node_hash(
+                    cs.namespace(|| format!("node hash {} (height equals {})", index, height)),
+                    children[0].as_slice(),
+                    children[1].as_slice(),
+                )?);
+            }
+            level = parents;
+        }
+
+        let mut root_hash_bits = level.remove(0);
+        root_hash_bits.truncate(zinc_const::bitlength::SHA256_HASH - zinc_const::bitlength::BYTE);
+
+        Ok(Scalar::<E>::from(AllocatedNum::<E>::pack_bits_to_element(
+            cs.namespace(|| "pack root hash bits into AllocatedNum"),
+            &root_hash_bits,
+        )?))
+    }
+
+    ///
+    /// Converts the storage `leaves` into the metadata representation.
+    ///
+    fn leaves_into_build(
+        leaves: Vec<LeafOutput>,
+        field_types: Vec<zinc_types::ContractFieldType>,
+    ) -> zinc_types::Value {
+        let fields = leaves
             .into_iter()
             .zip(field_types)
             .map(|(leaf, field)| {
@@ -148,8 +331,139 @@ where
         zinc_types::Value::Contract(fields)
     }
 
-    pub fn root_hash(&self) -> Result<Scalar<E>, Error> {
-        Ok(self.root_hash.clone())
+    ///
+    /// Converts the storage `scalars` into their values.
+    ///
+    fn scalars_to_bigints(scalars: Vec<Scalar<E>>) -> Result<Vec<BigInt>, Error> {
+        scalars
+            .into_iter()
+            .map(|scalar| scalar.to_bigint().ok_or(Error::InvalidStorageValue))
+            .collect()
+    }
+
+    ///
+    /// Loads the native entries of the map at `index` with its key and value sizes.
+    ///
+    /// The setup storage has no map entries, so they are empty there.
+    ///
+    #[allow(clippy::type_complexity)]
+    fn map_entries(
+        &self,
+        index: BigInt,
+    ) -> Result<(Vec<(Vec<Scalar<E>>, Vec<Scalar<E>>)>, usize, usize), Error> {
+        let field_type = self
+            .storage
+            .types()
+            .get(Self::position(index.clone())?)
+            .map(|field| field.r#type.to_owned());
+
+        match (self.storage.load(index)?.leaf_values, field_type) {
+            (
+                LeafVariant::Map {
+                    data,
+                    key_size,
+                    value_size,
+                },
+                _,
+            ) => Ok((data, key_size, value_size)),
+            (
+                LeafVariant::Array(_array),
+                Some(zinc_types::Type::Map {
+                    key_type,
+                    value_type,
+                }),
+            ) => Ok((vec![], key_type.size(), value_type.size())),
+            _ => Err(Error::InvalidStorageValue),
+        }
+    }
+
+    ///
+    /// Returns the sparse Merkle tree of the map at `index`.
+    ///
+    fn map_tree<CS>(&mut self, cs: CS, index: &BigInt) -> Result<&mut SparseMerkleTree<E, H>, Error>
+    where
+        CS: ConstraintSystem<E>,
+    {
+        self.fields(cs)?;
+
+        self.maps.get_mut(index).ok_or(Error::InvalidStorageValue)
+    }
+
+    ///
+    /// Returns the storage fields, allocating them with the map roots on the first access.
+    ///
+    /// The map fields are empty, since their values are the map roots.
+    ///
+    fn fields<CS>(&mut self, mut cs: CS) -> Result<&mut Vec<Vec<Scalar<E>>>, Error>
+    where
+        CS: ConstraintSystem<E>,
+    {
+        if self.fields.is_none() {
+            let field_types = self.storage.types().to_owned();
+
+            let mut fields = Vec::with_capacity(field_types.len());
+            for (index, field) in field_types.into_iter().enumerate() {
+                let index = BigInt::from(index);
+
+                match field.r#type {
+                    zinc_types::Type::Map { .. } => {
+                        let (entries, _key_size, _value_size) = self.map_entries(index.clone())?;
+                        let tree = SparseMerkleTree::new(
+                            cs.namespace(|| format!("map {}", index)),
+                            zinc_const::limit::MTREEMAP_DEPTH,
+                            entries.as_slice(),
+                        )?;
+                        self.maps.insert(index, tree);
+                        fields.push(vec![]);
+                    }
+                    _ => {
+                        let values = match self.storage.load(index.clone())?.leaf_values {
+                            LeafVariant::Array(array) => array,
+                            LeafVariant::Map { .. } => return Err(Error::InvalidStorageValue),
+                        };
+                        fields.push(AllocatedLeaf::alloc_leaf_fields(
+                            cs.namespace(|| format!("field {}", index)),
+                            values,
+                        )?);
+                    }
+                }
+            }
+
+            self.fields = Some(fields);
+        }
+
+        Ok(self
+            .fields
+            .as_mut()
+            .expect(zinc_const::panic::VALUE_ALWAYS_EXISTS))
+    }
+
+    ///
+    /// Converts the storage `index` scalar into its value.
+    ///
+    fn index(index: &Scalar<E>) -> BigInt {
+        index
+            .get_value()
+            .map(|field| gadgets::scalar::fr_bigint::fr_to_bigint::<E>(&field, false))
+            .expect(zinc_const::panic::TEST_DATA_VALID)
+    }
+
+    ///
+    /// Converts the storage `index` into the field position.
+    ///
+    fn position(index: BigInt) -> Result<usize, Error> {
+        index.to_usize().ok_or(Error::ExpectedUsize(index))
+    }
+
+    ///
+    /// Checks whether the code under the `condition` is executed, that is, the storage must be
+    /// updated.
+    ///
+    fn is_executed(condition: &Scalar<E>) -> bool {
+        condition
+            .get_value()
+            .map(|value| !value.is_zero())
+            .unwrap_or_default()
     }
 }
 
@@ -163,3 +477,167 @@ where
         self.storage.borrow_mut()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use franklin_crypto::bellman::pairing::bn256::Bn256;
+    use franklin_crypto::bellman::ConstraintSystem;
+    use franklin_crypto::circuit::test::TestConstraintSystem;
+
+    use num::BigInt;
+
+    use crate::core::contract::storage::database::Storage as DatabaseStorage;
+    use crate::core::contract::storage::leaf::LeafVariant;
+    use crate::gadgets::contract::merkle_tree::hasher::poseidon::Hasher as PoseidonHasher;
+    use crate::gadgets::contract::merkle_tree::hasher::rescue::Hasher as RescueHasher;
+    use crate::gadgets::contract::merkle_tree::hasher::sha256::Hasher as Sha256Hasher;
+    use crate::gadgets::contract::merkle_tree::hasher::IHasher as IMerkleTreeHasher;
+    use crate::gadgets::contract::merkle_tree::IMerkleTree;
+    use crate::gadgets::scalar::Scalar;
+
+    use super::StorageGadget;
+
+    type Gadget = StorageGadget<Bn256, DatabaseStorage<Bn256, Sha256Hasher>, Sha256Hasher>;
+
+    const MAP_INDEX: usize = 1;
+
+    fn field(value: usize) -> Scalar<Bn256> {
+        Scalar::new_constant_usize(value, zinc_types::ScalarType::Field)
+    }
+
+    fn field_types() -> Vec<zinc_types::ContractFieldType> {
+        vec![
+            zinc_types::ContractFieldType::new(
+                "address".to_owned(),
+                zinc_types::Type::eth_address(),
+                true,
+                true,
+            ),
+            zinc_types::ContractFieldType::new(
+                "balances".to_owned(),
+                zinc_types::Type::Map {
+                    key_type: Box::new(zinc_types::Type::Scalar(zinc_types::ScalarType::Field)),
+                    value_type: Box::new(zinc_types::Type::Scalar(zinc_types::ScalarType::Field)),
+                },
+                true,
+                false,
+            ),
+            zinc_types::ContractFieldType::new(
+                "total".to_owned(),
+                zinc_types::Type::Scalar(zinc_types::ScalarType::Field),
+                true,
+                false,
+            ),
+        ]
+    }
+
+    fn storage<H: IMerkleTreeHasher<Bn256>>() -> DatabaseStorage<Bn256, H> {
+        DatabaseStorage::from_evaluation_stack(
+            field_types(),
+            vec![
+                Scalar::new_constant_usize(42, zinc_types::ScalarType::eth_address()),
+                field(25),
+            ],
+        )
+        .expect(zinc_const::panic::TEST_DATA_VALID)
+    }
+
+    fn gadget() -> Gadget {
+        Gadget::new(storage())
+    }
+
+    fn root_hash_native<H: IMerkleTreeHasher<Bn256>>() {
+        let mut storage = storage::<H>();
+        storage
+            .store(
+                BigInt::from(MAP_INDEX),
+                LeafVariant::Map {
+                    data: vec![(vec![field(1)], vec![field(42)])],
+                    key_size: 1,
+                    value_size: 1,
+                },
+            )
+            .expect(zinc_const::panic::TEST_DATA_VALID);
+        let expected = storage
+            .root_hash()
+            .expect(zinc_const::panic::TEST_DATA_VALID);
+
+        let mut cs = TestConstraintSystem::<Bn256>::new();
+        let root_hash = StorageGadget::<Bn256, DatabaseStorage<Bn256, H>, H>::new(storage)
+            .root_hash(cs.namespace(|| "root hash"))
+            .expect(zinc_const::panic::TEST_DATA_VALID);
+
+        assert_eq!(root_hash.get_value(), Some(expected));
+        assert!(cs.is_satisfied());
+    }
+
+    #[test]
+    fn ok_sha256_hasher() {
+        root_hash_native::<Sha256Hasher>();
+    }
+
+    #[test]
+    fn ok_rescue_hasher() {
+        root_hash_native::<RescueHasher>();
+    }
+
+    #[test]
+    fn ok_poseidon_hasher() {
+        root_hash_native::<PoseidonHasher>();
+    }
+
+    #[test]
+    fn ok_map_root_carried() {
+        let mut cs = TestConstraintSystem::<Bn256>::new();
+        let mut gadget = gadget();
+        let condition = Scalar::new_constant_bool(true);
+
+        let initial = gadget
+            .root_hash(cs.namespace(|| "initial root hash"))
+            .expect(zinc_const::panic::TEST_DATA_VALID);
+        gadget
+            .map_insert(
+                cs.namespace(|| "insert"),
+                BigInt::from(MAP_INDEX),
+                vec![field(1)],
+                vec![field(42)],
+                &condition,
+            )
+            .expect(zinc_const::panic::TEST_DATA_VALID);
+        let updated = gadget
+            .root_hash(cs.namespace(|| "updated root hash"))
+            .expect(zinc_const::panic::TEST_DATA_VALID);
+        assert_ne!(initial.get_value(), updated.get_value());
+        assert!(cs.is_satisfied());
+
+        let storage = DatabaseStorage::from_build(
+            field_types(),
+            gadget.to_build().expect(zinc_const::panic::TEST_DATA_VALID),
+        )
+        .expect(zinc_const::panic::TEST_DATA_VALID);
+        let mut expected_cs = TestConstraintSystem::<Bn256>::new();
+        let expected = Gadget::new(storage)
+            .root_hash(expected_cs.namespace(|| "root hash"))
+            .expect(zinc_const::panic::TEST_DATA_VALID);
+        assert_eq!(updated.get_value(), expected.get_value());
+    }
+
+    #[test]
+    fn error_tampered_map_root() {
+        let mut cs = TestConstraintSystem::<Bn256>::new();
+        let mut gadget = gadget();
+
+        gadget
+            .root_hash(cs.namespace(|| "root hash"))
+            .expect(zinc_const::panic::TEST_DATA_VALID);
+        assert!(cs.is_satisfied());
+
+        cs.set(
+            format!("root hash/fields/map {}/root hash variable", MAP_INDEX).as_str(),
+            field(1)
+                .get_value()
+                .expect(zinc_const::panic::TEST_DATA_VALID),
+        );
+        assert!(!cs.is_satisfied());
+    }
+}
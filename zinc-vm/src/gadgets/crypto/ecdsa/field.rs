@@ -0,0 +1,680 @@
+//!
+//! The secp256k1 base field element gadget.
+//!
+//! The elements are represented with four 64-bit limbs of the circuit field. The arithmetic
+//! relations are checked with a witnessed quotient and a chain of witnessed column carries,
+//! so that no intermediate value wraps around the circuit field modulus.
+//!
+
+use num::BigInt;
+use num::Integer;
+use num::One;
+use num::Signed;
+use num::Zero;
+
+use franklin_crypto::bellman::pairing::ff::Field;
+use franklin_crypto::bellman::ConstraintSystem;
+use franklin_crypto::bellman::LinearCombination;
+use franklin_crypto::circuit::boolean::AllocatedBit;
+use franklin_crypto::circuit::boolean::Boolean;
+use franklin_crypto::circuit::Assignment;
+
+use crate::error::Error;
+use crate::gadgets::crypto::ecdsa::native;
+use crate::gadgets::scalar::fr_bigint;
+use crate::IEngine;
+
+/// The element limb bitlength.
+pub const LIMB_BITLENGTH: usize = 64;
+
+/// The number of limbs in an element.
+pub const LIMBS: usize = 4;
+
+/// The element bitlength.
+pub const BITLENGTH: usize = LIMB_BITLENGTH * LIMBS;
+
+/// The witnessed relation quotient bitlength.
+const QUOTIENT_BITLENGTH: usize = 264;
+
+/// The relation quotient offset bitlength, which keeps the witnessed quotient non-negative.
+const QUOTIENT_OFFSET_BITLENGTH: usize = 262;
+
+/// The witnessed column carry bitlength.
+const CARRY_BITLENGTH: usize = 77;
+
+/// The column carry offset bitlength, which keeps the witnessed carries non-negative.
+const CARRY_OFFSET_BITLENGTH: usize = 76;
+
+/// The maximal absolute value of a relation term coefficient.
+const COEFFICIENT_MAX: i64 = 4;
+
+/// The maximal number of terms in a relation.
+const TERMS_MAX: usize = 8;
+
+///
+/// The secp256k1 base field element.
+///
+/// The represented integer is less than `2^256`, but not necessarily less than the modulus.
+///
+#[derive(Clone)]
+pub struct FieldElement<E: IEngine> {
+    /// The little-endian limbs.
+    limbs: Vec<LinearCombination<E>>,
+    /// The represented integer value.
+    value: Option<BigInt>,
+    /// The little-endian bits, if the element has been decomposed.
+    bits: Option<Vec<Boolean>>,
+}
+
+impl<E: IEngine> FieldElement<E> {
+    ///
+    /// Creates a constant element.
+    ///
+    pub fn constant<CS>(value: &BigInt) -> Self
+    where
+        CS: ConstraintSystem<E>,
+    {
+        let value = native::reduce(value);
+
+        let bits = (0..BITLENGTH)
+            .map(|index| Boolean::constant((&value >> index).is_odd()))
+            .collect::<Vec<Boolean>>();
+
+        Self::from_bits_le::<CS>(bits)
+    }
+
+    ///
+    /// Allocates an element, decomposing it into bits.
+    ///
+    pub fn alloc<CS>(cs: CS, value: Option<BigInt>) -> Result<Self, Error>
+    where
+        CS: ConstraintSystem<E>,
+    {
+        let bits = alloc_bits(cs, value.as_ref(), BITLENGTH)?;
+
+        Ok(Self::from_bits_le::<CS>(bits))
+    }
+
+    ///
+    /// Allocates an element, enforcing it to be less than the modulus.
+    ///
+    pub fn alloc_canonical<CS>(mut cs: CS, value: Option<BigInt>) -> Result<Self, Error>
+    where
+        CS: ConstraintSystem<E>,
+    {
+        let element = Self::alloc(cs.namespace(|| "element"), value)?;
+        element.enforce_canonical(cs.namespace(|| "canonical"))?;
+        Ok(element)
+    }
+
+    ///
+    /// Creates an element from `BITLENGTH` little-endian bits.
+    ///
+    pub fn from_bits_le<CS>(bits: Vec<Boolean>) -> Self
+    where
+        CS: ConstraintSystem<E>,
+    {
+        assert_eq!(bits.len(), BITLENGTH);
+
+        let limbs = bits
+            .chunks(LIMB_BITLENGTH)
+            .map(|limb| pack_bits::<E, CS>(limb))
+            .collect();
+
+        let value = bits.iter().rev().try_fold(BigInt::zero(), |value, bit| {
+            bit.get_value()
+                .map(|bit| (value << 1) + BigInt::from(bit as u8))
+        });
+
+        Self {
+            limbs,
+            value,
+            bits: Some(bits),
+        }
+    }
+
+    ///
+    /// Returns the represented integer value.
+    ///
+    pub fn get_value(&self) -> Option<BigInt> {
+        self.value.clone()
+    }
+
+    ///
+    /// Returns the little-endian bits, if the element has been decomposed.
+    ///
+    pub fn get_bits_le(&self) -> Option<&[Boolean]> {
+        self.bits.as_deref()
+    }
+
+    ///
+    /// Enforces the element to be less than the modulus.
+    ///
+    pub fn enforce_canonical<CS>(&self, mut cs: CS) -> Result<(), Error>
+    where
+        CS: ConstraintSystem<E>,
+    {
+        let bits = self
+            .bits
+            .as_ref()
+            .expect(zinc_const::panic::VALUE_ALWAYS_EXISTS);
+
+        let is_less = less_than(cs.namespace(|| "less than modulus"), bits, &native::MODULUS)?;
+        Boolean::enforce_equal(
+            cs.namespace(|| "is less than modulus"),
+            &is_less,
+            &Boolean::constant(true),
+        )?;
+
+        Ok(())
+    }
+
+    ///
+    /// Allocates the canonical element which is congruent to `self`.
+    ///
+    pub fn reduce<CS>(&self, mut cs: CS) -> Result<Self, Error>
+    where
+        CS: ConstraintSystem<E>,
+    {
+        let reduced = Self::alloc_canonical(
+            cs.namespace(|| "reduced"),
+            self.value.as_ref().map(native::reduce),
+        )?;
+
+        Relation::new()
+            .linear(1, self)
+            .linear(-1, &reduced)
+            .enforce(cs.namespace(|| "congruence"))?;
+
+        Ok(reduced)
+    }
+
+    ///
+    /// Checks whether two canonical elements are equal.
+    ///
+    pub fn is_equal<CS>(&self, mut cs: CS, other: &Self) -> Result<Boolean, Error>
+    where
+        CS: ConstraintSystem<E>,
+    {
+        let mut result = Boolean::constant(true);
+        for (index, (left, right)) in self.limbs.iter().zip(other.limbs.iter()).enumerate() {
+            let difference = self.limb_value(index).and_then(|left| {
+                other.limb_value(index).map(|right| {
+                    let mut difference = left;
+                    difference.sub_assign(&right);
+                    difference
+                })
+            });
+
+            let is_limb_equal = is_zero(
+                cs.namespace(|| format!("limb {} is equal", index)),
+                left.clone() - right,
+                difference,
+            )?;
+
+            result = Boolean::and(
+                cs.namespace(|| format!("limb {} and", index)),
+                &result,
+                &is_limb_equal,
+            )?;
+        }
+
+        Ok(result)
+    }
+
+    ///
+    /// Enforces two elements to have equal limbs if the `condition` is true.
+    ///
+    pub fn enforce_equal_if<CS>(
+        &self,
+        mut cs: CS,
+        condition: &Boolean,
+        other: &Self,
+    ) -> Result<(), Error>
+    where
+        CS: ConstraintSystem<E>,
+    {
+        for (index, (left, right)) in self.limbs.iter().zip(other.limbs.iter()).enumerate() {
+            cs.enforce(
+                || format!("limb {} is equal", index),
+                |zero| zero + left - right,
+                |_| condition.lc(CS::one(), E::Fr::one()),
+                |zero| zero,
+            );
+        }
+
+        Ok(())
+    }
+
+    ///
+    /// Selects `first` if the `condition` is true, and `second` otherwise.
+    ///
+    pub fn conditionally_select<CS>(
+        mut cs: CS,
+        condition: &Boolean,
+        first: &Self,
+        second: &Self,
+    ) -> Result<Self, Error>
+    where
+        CS: ConstraintSystem<E>,
+    {
+        let value = match condition.get_value() {
+            Some(true) => first.value.clone(),
+            Some(false) => second.value.clone(),
+            None => None,
+        };
+
+        let mut limbs = Vec::with_capacity(LIMBS);
+        for (index, (first_limb, second_limb)) in
+            first.limbs.iter().zip(second.limbs.iter()).enumerate()
+        {
+            let limb_value = match condition.get_value() {
+                Some(true) => first.limb_value(index),
+                Some(false) => second.limb_value(index),
+                None => None,
+            };
+            let limb = cs.alloc(|| format!("limb {}", index), || limb_value.grab())?;
+
+            cs.enforce(
+                || format!("limb {} select", index),
+                |zero| zero + first_limb - second_limb,
+                |_| condition.lc(CS::one(), E::Fr::one()),
+                |zero| zero + limb - second_limb,
+            );
+
+            limbs.push(LinearCombination::zero() + limb);
+        }
+
+        Ok(Self {
+            limbs,
+            value,
+            bits: None,
+        })
+    }
+
+    ///
+    /// Returns the limb value as a circuit field element.
+    ///
+    fn limb_value(&self, index: usize) -> Option<E::Fr> {
+        self.value
+            .as_ref()
+            .map(|value| bigint_to_fr::<E>(&limb(value, index)))
+    }
+}
+
+///
+/// The secp256k1 base field relation builder.
+///
+/// Describes the relation `sum(products) + sum(linear) + constant = 0 (mod p)`, where every
+/// term has a small signed coefficient.
+///
+pub struct Relation<'a, E: IEngine> {
+    /// The element products with their coefficients.
+    products: Vec<(i64, &'a FieldElement<E>, &'a FieldElement<E>)>,
+    /// The elements with their coefficients.
+    linear: Vec<(i64, &'a FieldElement<E>)>,
+    /// The constant term.
+    constant: BigInt,
+}
+
+impl<'a, E: IEngine> Relation<'a, E> {
+    ///
+    /// Creates an empty relation.
+    ///
+    pub fn new() -> Self {
+        Self {
+            products: Vec::new(),
+            linear: Vec::new(),
+            constant: BigInt::zero(),
+        }
+    }
+
+    ///
+    /// Adds the `coefficient * left * right` term.
+    ///
+    pub fn product(
+        mut self,
+        coefficient: i64,
+        left: &'a FieldElement<E>,
+        right: &'a FieldElement<E>,
+    ) -> Self {
+        self.products.push((coefficient, left, right));
+        self
+    }
+
+    ///
+    /// Adds the `coefficient * element` term.
+    ///
+    pub fn linear(mut self, coefficient: i64, element: &'a FieldElement<E>) -> Self {
+        self.linear.push((coefficient, element));
+        self
+    }
+
+    ///
+    /// Adds the constant term.
+    ///
+    pub fn constant(mut self, value: i64) -> Self {
+        self.constant += value;
+        self
+    }
+
+    ///
+    /// Enforces the relation.
+    ///
+    /// The integer `sum + offset * p` is split into `quotient * p` with a witnessed quotient,
+    /// and the equality is checked column by column with witnessed carries.
+    ///
+    pub fn enforce<CS>(self, mut cs: CS) -> Result<(), Error>
+    where
+        CS: ConstraintSystem<E>,
+    {
+        assert!(self.products.len() + self.linear.len() <= TERMS_MAX);
+        assert!(self
+            .products
+            .iter()
+            .map(|(coefficient, _, _)| coefficient)
+            .chain(self.linear.iter().map(|(coefficient, _)| coefficient))
+            .all(|coefficient| coefficient.abs() <= COEFFICIENT_MAX));
+
+        let modulus_limbs = limbs(&native::MODULUS, LIMBS);
+        let constant =
+            &self.constant + (BigInt::one() << QUOTIENT_OFFSET_BITLENGTH) * &*native::MODULUS;
+        let constant_limbs = limbs(
+            &constant,
+            (constant.bits() as usize + LIMB_BITLENGTH - 1) / LIMB_BITLENGTH,
+        );
+        let quotient_limbs_count = (QUOTIENT_BITLENGTH + LIMB_BITLENGTH - 1) / LIMB_BITLENGTH;
+
+        let columns_count = std::cmp::max(
+            2 * LIMBS - 1,
+            std::cmp::max(quotient_limbs_count + LIMBS - 1, constant_limbs.len()),
+        );
+        let mut columns = vec![LinearCombination::<E>::zero(); columns_count];
+        let mut column_values = vec![Some(BigInt::zero()); columns_count];
+
+        let add_value = |column: &mut Option<BigInt>, value: Option<BigInt>| {
+            *column = match (column.take(), value) {
+                (Some(column), Some(value)) => Some(column + value),
+                _ => None,
+            };
+        };
+
+        let mut total = Some(constant);
+        for (index, (coefficient, left, right)) in self.products.iter().enumerate() {
+            total = match (total, left.value.as_ref(), right.value.as_ref()) {
+                (Some(total), Some(left), Some(right)) => {
+                    Some(total + BigInt::from(*coefficient) * left * right)
+                }
+                _ => None,
+            };
+
+            for i in 0..LIMBS {
+                for j in 0..LIMBS {
+                    let product_value = match (left.value.as_ref(), right.value.as_ref()) {
+                        (Some(left), Some(right)) => Some(limb(left, i) * limb(right, j)),
+                        _ => None,
+                    };
+                    let product_fr = product_value.as_ref().map(bigint_to_fr::<E>);
+                    let product = cs.alloc(
+                        || format!("product {} limbs {} {}", index, i, j),
+                        || product_fr.grab(),
+                    )?;
+                    cs.enforce(
+                        || format!("product {} limbs {} {} multiplication", index, i, j),
+                        |zero| zero + &left.limbs[i],
+                        |zero| zero + &right.limbs[j],
+                        |zero| zero + product,
+                    );
+
+                    columns[i + j] =
+                        std::mem::replace(&mut columns[i + j], LinearCombination::zero())
+                            + (bigint_to_fr::<E>(&BigInt::from(*coefficient)), product);
+                    add_value(
+                        &mut column_values[i + j],
+                        product_value.map(|value| value * coefficient),
+                    );
+                }
+            }
+        }
+
+        for (coefficient, element) in self.linear.iter() {
+            total = match (total, element.value.as_ref()) {
+                (Some(total), Some(value)) => Some(total + BigInt::from(*coefficient) * value),
+                _ => None,
+            };
+
+            for i in 0..LIMBS {
+                columns[i] = std::mem::replace(&mut columns[i], LinearCombination::zero())
+                    + (
+                        bigint_to_fr::<E>(&BigInt::from(*coefficient)),
+                        &element.limbs[i],
+                    );
+                add_value(
+                    &mut column_values[i],
+                    element
+                        .value
+                        .as_ref()
+                        .map(|value| limb(value, i) * coefficient),
+                );
+            }
+        }
+
+        for (index, constant_limb) in constant_limbs.iter().enumerate() {
+            columns[index] = std::mem::replace(&mut columns[index], LinearCombination::zero())
+                + (bigint_to_fr::<E>(constant_limb), CS::one());
+            add_value(&mut column_values[index], Some(constant_limb.to_owned()));
+        }
+
+        let quotient = total.map(|total| {
+            let quotient = total.div_floor(&*native::MODULUS);
+            if quotient.is_negative() {
+                BigInt::zero()
+            } else {
+                quotient
+            }
+        });
+        let quotient_bits = alloc_bits(
+            cs.namespace(|| "quotient"),
+            quotient.as_ref(),
+            QUOTIENT_BITLENGTH,
+        )?;
+        for (i, quotient_limb_bits) in quotient_bits.chunks(LIMB_BITLENGTH).enumerate() {
+            let quotient_limb = pack_bits::<E, CS>(quotient_limb_bits);
+            for (j, modulus_limb) in modulus_limbs.iter().enumerate() {
+                columns[i + j] = std::mem::replace(&mut columns[i + j], LinearCombination::zero())
+                    - (bigint_to_fr::<E>(modulus_limb), &quotient_limb);
+                add_value(
+                    &mut column_values[i + j],
+                    quotient
+                        .as_ref()
+                        .map(|quotient| -(limb(quotient, i) * modulus_limb)),
+                );
+            }
+        }
+
+        let carry_offset = BigInt::one() << CARRY_OFFSET_BITLENGTH;
+        let carry_offset_fr = bigint_to_fr::<E>(&carry_offset);
+        let limb_base = BigInt::one() << LIMB_BITLENGTH;
+        let limb_base_fr = bigint_to_fr::<E>(&limb_base);
+        let carry_offset_scaled_fr = bigint_to_fr::<E>(&(&carry_offset * &limb_base));
+
+        let mut carry_value = Some(BigInt::zero());
+        let mut previous_carry: Option<LinearCombination<E>> = None;
+        for (index, column) in columns.into_iter().enumerate() {
+            let mut column = column;
+            if let Some(previous_carry) = previous_carry.take() {
+                column = column + &previous_carry - (carry_offset_fr, CS::one());
+            }
+
+            if index == columns_count - 1 {
+                cs.enforce(
+                    || format!("column {}", index),
+                    |zero| zero + &column,
+                    |zero| zero + CS::one(),
+                    |zero| zero,
+                );
+                break;
+            }
+
+            carry_value = match (carry_value, column_values[index].take()) {
+                (Some(carry), Some(column)) => Some((carry + column).div_floor(&limb_base)),
+                _ => None,
+            };
+            let carry_bits = alloc_bits(
+                cs.namespace(|| format!("column {} carry", index)),
+                carry_value
+                    .as_ref()
+                    .map(|carry| carry + &carry_offset)
+                    .as_ref(),
+                CARRY_BITLENGTH,
+            )?;
+            let carry = pack_bits::<E, CS>(&carry_bits);
+
+            cs.enforce(
+                || format!("column {}", index),
+                |zero| zero + &column,
+                |zero| zero + CS::one(),
+                |zero| zero + (limb_base_fr, &carry) - (carry_offset_scaled_fr, CS::one()),
+            );
+
+            previous_carry = Some(carry);
+        }
+
+        Ok(())
+    }
+}
+
+impl<'a, E: IEngine> Default for Relation<'a, E> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+///
+/// Checks whether the little-endian `bits` represent a number less than the `constant`.
+///
+pub fn less_than<E, CS>(mut cs: CS, bits: &[Boolean], constant: &BigInt) -> Result<Boolean, Error>
+where
+    E: IEngine,
+    CS: ConstraintSystem<E>,
+{
+    let mut result = Boolean::constant(false);
+    for (index, bit) in bits.iter().enumerate() {
+        result = if (constant >> index).is_odd() {
+            Boolean::and(
+                cs.namespace(|| format!("bit {}", index)),
+                bit,
+                &result.not(),
+            )?
+            .not()
+        } else {
+            Boolean::and(
+                cs.namespace(|| format!("bit {}", index)),
+                &bit.not(),
+                &result,
+            )?
+        };
+    }
+
+    if constant.bits() as usize > bits.len() {
+        return Ok(Boolean::constant(true));
+    }
+
+    Ok(result)
+}
+
+///
+/// Checks whether the linear combination `lc` with the `value` equals zero.
+///
+pub fn is_zero<E, CS>(
+    mut cs: CS,
+    lc: LinearCombination<E>,
+    value: Option<E::Fr>,
+) -> Result<Boolean, Error>
+where
+    E: IEngine,
+    CS: ConstraintSystem<E>,
+{
+    let is_zero = AllocatedBit::alloc(
+        cs.namespace(|| "is zero"),
+        value.map(|value| value.is_zero()),
+    )?;
+    let inverse_value = value.map(|value| value.inverse().unwrap_or_else(E::Fr::zero));
+    let inverse = cs.alloc(|| "inverse", || inverse_value.grab())?;
+
+    cs.enforce(
+        || "inverse",
+        |zero| zero + &lc,
+        |zero| zero + inverse,
+        |zero| zero + CS::one() - is_zero.get_variable(),
+    );
+    cs.enforce(
+        || "zero",
+        |zero| zero + &lc,
+        |zero| zero + is_zero.get_variable(),
+        |zero| zero,
+    );
+
+    Ok(Boolean::from(is_zero))
+}
+
+///
+/// Allocates the little-endian bits of the non-negative `value`.
+///
+pub fn alloc_bits<E, CS>(
+    mut cs: CS,
+    value: Option<&BigInt>,
+    bitlength: usize,
+) -> Result<Vec<Boolean>, Error>
+where
+    E: IEngine,
+    CS: ConstraintSystem<E>,
+{
+    let mut bits = Vec::with_capacity(bitlength);
+    for index in 0..bitlength {
+        let bit = AllocatedBit::alloc(
+            cs.namespace(|| format!("bit {}", index)),
+            value.map(|value| (value >> index).is_odd()),
+        )?;
+        bits.push(Boolean::from(bit));
+    }
+    Ok(bits)
+}
+
+///
+/// Packs the little-endian `bits` into a linear combination.
+///
+pub fn pack_bits<E, CS>(bits: &[Boolean]) -> LinearCombination<E>
+where
+    E: IEngine,
+    CS: ConstraintSystem<E>,
+{
+    let mut lc = LinearCombination::zero();
+    let mut coefficient = E::Fr::one();
+    for bit in bits.iter() {
+        lc = lc + &bit.lc(CS::one(), coefficient);
+        coefficient.double();
+    }
+    lc
+}
+
+///
+/// Returns the `index`-th 64-bit limb of the non-negative `value`.
+///
+fn limb(value: &BigInt, index: usize) -> BigInt {
+    (value >> (index * LIMB_BITLENGTH)) & ((BigInt::one() << LIMB_BITLENGTH) - BigInt::one())
+}
+
+///
+/// Splits the non-negative `value` into `count` 64-bit limbs.
+///
+fn limbs(value: &BigInt, count: usize) -> Vec<BigInt> {
+    (0..count).map(|index| limb(value, index)).collect()
+}
+
+///
+/// Converts a big integer into the circuit field element.
+///
+fn bigint_to_fr<E: IEngine>(value: &BigInt) -> E::Fr {
+    fr_bigint::bigint_to_fr::<E>(value).expect(zinc_const::panic::DATA_CONVERSION)
+}
@@ -0,0 +1,426 @@
+//!
+//! The secp256k1 ECDSA signature verification gadget.
+//!
+//! The signature is checked the way Ethereum `ecrecover` does it: the public key is recovered
+//! from the message hash and the signature, and its Keccak-256 based address is compared
+//! with the expected one.
+//!
+
+pub mod field;
+pub mod native;
+pub mod point;
+
+use num::BigInt;
+use num::One;
+use num::Zero;
+
+use franklin_crypto::bellman::pairing::ff::Field;
+use franklin_crypto::bellman::ConstraintSystem;
+use franklin_crypto::bellman::LinearCombination;
+use franklin_crypto::circuit::boolean::AllocatedBit;
+use franklin_crypto::circuit::boolean::Boolean;
+
+use crate::error::Error;
+use crate::gadgets::crypto::keccak256;
+use crate::gadgets::scalar::fr_bigint;
+use crate::gadgets::scalar::Scalar;
+use crate::IEngine;
+
+use self::field::FieldElement;
+use self::field::Relation;
+use self::point::AffinePoint;
+
+/// The recovery byte value for the even `R.y` coordinate.
+const RECOVERY_ID_EVEN: u64 = 27;
+
+/// The recovery byte value for the odd `R.y` coordinate.
+const RECOVERY_ID_ODD: u64 = 28;
+
+///
+/// Verifies the `signature` of the `msg_hash` against the `eth_address`.
+///
+/// The hash and the signature bits are big-endian. The signature consists of `r`, `s` and
+/// the recovery byte `v`, which must be either 27 or 28, as in Ethereum transactions.
+///
+/// Returns `false` for malformed signatures instead of making the constraint system
+/// unsatisfiable.
+///
+pub fn verify<E, CS>(
+    mut cs: CS,
+    msg_hash: &[Boolean],
+    signature: &[Boolean],
+    eth_address: &Scalar<E>,
+) -> Result<Boolean, Error>
+where
+    E: IEngine,
+    CS: ConstraintSystem<E>,
+{
+    assert_eq!(msg_hash.len(), zinc_const::bitlength::KECCAK256_HASH);
+    assert_eq!(signature.len(), zinc_const::bitlength::ETH_SIGNATURE);
+
+    let r_be = &signature[..field::BITLENGTH];
+    let s_be = &signature[field::BITLENGTH..2 * field::BITLENGTH];
+    let v_be = &signature[2 * field::BITLENGTH..];
+
+    let r_le: Vec<Boolean> = r_be.iter().rev().cloned().collect();
+    let s_le: Vec<Boolean> = s_be.iter().rev().cloned().collect();
+    let z_le: Vec<Boolean> = msg_hash.iter().rev().cloned().collect();
+
+    let r_is_valid = is_scalar_valid(cs.namespace(|| "r is valid"), &r_le)?;
+    let s_is_valid = is_scalar_valid(cs.namespace(|| "s is valid"), &s_le)?;
+
+    let v_le: Vec<Boolean> = v_be.iter().rev().cloned().collect();
+    let v = field::pack_bits::<E, CS>(&v_le);
+    let v_value = bits_value(&v_le).map(|value| bigint_to_fr::<E>(&value));
+    let is_v_even = is_equal_constant(cs.namespace(|| "v is even"), &v, v_value, RECOVERY_ID_EVEN)?;
+    let is_v_odd = is_equal_constant(cs.namespace(|| "v is odd"), &v, v_value, RECOVERY_ID_ODD)?;
+    let is_v_valid = or(cs.namespace(|| "v is valid"), &is_v_even, &is_v_odd)?;
+
+    let r = FieldElement::from_bits_le::<CS>(r_le.clone());
+    let r_squared = FieldElement::alloc(
+        cs.namespace(|| "r squared"),
+        r.get_value().map(|r| native::reduce(&(&r * &r))),
+    )?;
+    Relation::new()
+        .product(1, &r, &r)
+        .linear(-1, &r_squared)
+        .enforce(cs.namespace(|| "r squared relation"))?;
+
+    // `c = r^3 + 7` and its negation. Since `-1` is not a square modulo `p`, exactly one of
+    // them is a square, and `c` is never zero, because the curve has no points with `y = 0`.
+    let c_value = r
+        .get_value()
+        .map(|r| native::reduce(&(&r * &r * &r + BigInt::from(7))));
+    let c = FieldElement::alloc(cs.namespace(|| "c"), c_value.clone())?;
+    Relation::new()
+        .product(1, &r_squared, &r)
+        .constant(7)
+        .linear(-1, &c)
+        .enforce(cs.namespace(|| "c relation"))?;
+    let c_negated = FieldElement::alloc(
+        cs.namespace(|| "c negated"),
+        c_value.as_ref().map(|c| native::reduce(&-c)),
+    )?;
+    Relation::new()
+        .linear(1, &c)
+        .linear(1, &c_negated)
+        .enforce(cs.namespace(|| "c negated relation"))?;
+
+    let is_on_curve = Boolean::from(AllocatedBit::alloc(
+        cs.namespace(|| "r is on curve"),
+        c_value.as_ref().map(native::is_square),
+    )?);
+    let square = FieldElement::conditionally_select(
+        cs.namespace(|| "square"),
+        &is_on_curve,
+        &c,
+        &c_negated,
+    )?;
+
+    let y_value = match (
+        c_value.as_ref(),
+        is_on_curve.get_value(),
+        is_v_odd.get_value(),
+    ) {
+        (Some(c), Some(is_on_curve), Some(is_odd)) => {
+            let square = if is_on_curve {
+                c.to_owned()
+            } else {
+                native::reduce(&-c)
+            };
+            native::sqrt(&square).map(|y| {
+                if (&y % BigInt::from(2)).is_one() == is_odd {
+                    y
+                } else {
+                    native::reduce(&-y)
+                }
+            })
+        }
+        _ => None,
+    };
+    let y = FieldElement::alloc_canonical(cs.namespace(|| "y"), y_value)?;
+    Relation::new()
+        .product(1, &y, &y)
+        .linear(-1, &square)
+        .enforce(cs.namespace(|| "y relation"))?;
+    Boolean::enforce_equal(
+        cs.namespace(|| "y parity"),
+        &y.get_bits_le()
+            .expect(zinc_const::panic::VALUE_ALWAYS_EXISTS)[0],
+        &is_v_odd,
+    )?;
+
+    let mut is_valid = Boolean::and(cs.namespace(|| "r and s"), &r_is_valid, &s_is_valid)?;
+    is_valid = Boolean::and(cs.namespace(|| "and v"), &is_valid, &is_v_valid)?;
+    is_valid = Boolean::and(cs.namespace(|| "and on curve"), &is_valid, &is_on_curve)?;
+
+    let generator = AffinePoint::constant::<CS>(&native::GENERATOR);
+    let point_r = AffinePoint::conditionally_select(
+        cs.namespace(|| "point r"),
+        &is_on_curve,
+        &AffinePoint::from_coordinates(r, y),
+        &generator,
+    )?;
+
+    let public_key_value = match (
+        is_valid.get_value(),
+        bits_value(&z_le),
+        bits_value(&r_le),
+        bits_value(&s_le),
+        is_v_odd.get_value(),
+    ) {
+        (Some(true), Some(z), Some(r), Some(s), Some(is_odd)) => {
+            Some(native::recover(&z, &r, &s, is_odd).unwrap_or_else(|| native::GENERATOR.clone()))
+        }
+        (Some(false), ..) => Some(native::GENERATOR.clone()),
+        _ => None,
+    };
+    let public_key = AffinePoint::alloc(cs.namespace(|| "public key"), public_key_value)?;
+
+    // `r * Q + z * G == s * R`, with the same accumulator offset on both sides.
+    let left = public_key
+        .mul(cs.namespace(|| "r * Q"), r_be)?
+        .add_fixed_base_mul(cs.namespace(|| "z * G"), &native::GENERATOR, &z_le)?
+        .reduce(cs.namespace(|| "left reduced"))?;
+    let right = point_r
+        .mul(cs.namespace(|| "s * R"), s_be)?
+        .reduce(cs.namespace(|| "right reduced"))?;
+    left.x
+        .enforce_equal_if(cs.namespace(|| "x is equal"), &is_valid, &right.x)?;
+    left.y
+        .enforce_equal_if(cs.namespace(|| "y is equal"), &is_valid, &right.y)?;
+
+    let mut public_key_bits = Vec::with_capacity(2 * field::BITLENGTH);
+    for coordinate in [&public_key.x, &public_key.y].iter() {
+        public_key_bits.extend(
+            coordinate
+                .get_bits_le()
+                .expect(zinc_const::panic::VALUE_ALWAYS_EXISTS)
+                .iter()
+                .rev()
+                .cloned(),
+        );
+    }
+    let public_key_hash = keccak256::keccak256(cs.namespace(|| "keccak256"), &public_key_bits)?;
+    let address_le: Vec<Boolean> = public_key_hash
+        [zinc_const::bitlength::KECCAK256_HASH - zinc_const::bitlength::ETH_ADDRESS..]
+        .iter()
+        .rev()
+        .cloned()
+        .collect();
+    let address = field::pack_bits::<E, CS>(&address_le);
+    let address_difference_value = match (bits_value(&address_le), eth_address.get_value()) {
+        (Some(address), Some(expected)) => {
+            let mut difference = bigint_to_fr::<E>(&address);
+            difference.sub_assign(&expected);
+            Some(difference)
+        }
+        _ => None,
+    };
+    let is_address_valid = field::is_zero(
+        cs.namespace(|| "address is equal"),
+        address - &eth_address.to_linear_combination::<CS>(),
+        address_difference_value,
+    )?;
+
+    let is_verified = Boolean::and(cs.namespace(|| "is verified"), &is_valid, &is_address_valid)?;
+
+    Ok(is_verified)
+}
+
+///
+/// Checks whether the scalar given with little-endian bits is in the range `[1; n)`.
+///
+fn is_scalar_valid<E, CS>(mut cs: CS, bits: &[Boolean]) -> Result<Boolean, Error>
+where
+    E: IEngine,
+    CS: ConstraintSystem<E>,
+{
+    let is_less = field::less_than(cs.namespace(|| "less than order"), bits, &native::ORDER)?;
+    let is_zero = field::less_than(cs.namespace(|| "is zero"), bits, &BigInt::one())?;
+
+    let is_valid = Boolean::and(cs.namespace(|| "is valid"), &is_less, &is_zero.not())?;
+
+    Ok(is_valid)
+}
+
+///
+/// Checks whether the linear combination `lc` with the `value` equals the `constant`.
+///
+fn is_equal_constant<E, CS>(
+    cs: CS,
+    lc: &LinearCombination<E>,
+    value: Option<E::Fr>,
+    constant: u64,
+) -> Result<Boolean, Error>
+where
+    E: IEngine,
+    CS: ConstraintSystem<E>,
+{
+    let constant_fr = bigint_to_fr::<E>(&BigInt::from(constant));
+    let difference = value.map(|mut value| {
+        value.sub_assign(&constant_fr);
+        value
+    });
+
+    field::is_zero(cs, lc.clone() - (constant_fr, CS::one()), difference)
+}
+
+///
+/// Computes the logical disjunction.
+///
+fn or<E, CS>(cs: CS, left: &Boolean, right: &Boolean) -> Result<Boolean, Error>
+where
+    E: IEngine,
+    CS: ConstraintSystem<E>,
+{
+    Ok(Boolean::and(cs, &left.not(), &right.not())?.not())
+}
+
+///
+/// Returns the integer value of the little-endian `bits`.
+///
+fn bits_value(bits: &[Boolean]) -> Option<BigInt> {
+    bits.iter().rev().try_fold(BigInt::zero(), |value, bit| {
+        bit.get_value()
+            .map(|bit| (value << 1) + BigInt::from(bit as u8))
+    })
+}
+
+///
+/// Converts a big integer into the circuit field element.
+///
+fn bigint_to_fr<E: IEngine>(value: &BigInt) -> E::Fr {
+    fr_bigint::bigint_to_fr::<E>(value).expect(zinc_const::panic::DATA_CONVERSION)
+}
+
+#[cfg(test)]
+mod tests {
+    use num::BigInt;
+    use num::Num;
+
+    use franklin_crypto::bellman::pairing::bn256::Bn256;
+    use franklin_crypto::bellman::ConstraintSystem;
+    use franklin_crypto::circuit::boolean::AllocatedBit;
+    use franklin_crypto::circuit::boolean::Boolean;
+
+    use crate::constraint_systems::main::Main as MainCS;
+    use crate::gadgets::scalar::Scalar;
+
+    /// The `keccak256("hello")` hash.
+    const MESSAGE_HASH: &str = "1c8aff950685c2ed4bc3174f3472287b56d9517b9c948127319a09a7a36deac8";
+
+    fn verify(msg_hash: &str, r: &str, s: &str, v: u8, eth_address: &str) -> (bool, bool) {
+        let mut cs = MainCS::<Bn256>::new();
+
+        let mut alloc_bits = |name: &str, value: BigInt, bitlength: usize| {
+            (0..bitlength)
+                .rev()
+                .map(|index| {
+                    Boolean::from(
+                        AllocatedBit::alloc(
+                            cs.namespace(|| format!("{} bit {}", name, index)),
+                            Some((&value >> index) % BigInt::from(2) == BigInt::from(1)),
+                        )
+                        .expect(zinc_const::panic::TEST_DATA_VALID),
+                    )
+                })
+                .collect::<Vec<Boolean>>()
+        };
+
+        let msg_hash = alloc_bits(
+            "msg_hash",
+            BigInt::from_str_radix(msg_hash, 16).expect(zinc_const::panic::TEST_DATA_VALID),
+            zinc_const::bitlength::KECCAK256_HASH,
+        );
+        let mut signature = alloc_bits(
+            "r",
+            BigInt::from_str_radix(r, 16).expect(zinc_const::panic::TEST_DATA_VALID),
+            super::field::BITLENGTH,
+        );
+        signature.extend(alloc_bits(
+            "s",
+            BigInt::from_str_radix(s, 16).expect(zinc_const::panic::TEST_DATA_VALID),
+            super::field::BITLENGTH,
+        ));
+        signature.extend(alloc_bits(
+            "v",
+            BigInt::from(v),
+            zinc_const::bitlength::BYTE,
+        ));
+
+        let eth_address = Scalar::new_constant_bigint(
+            BigInt::from_str_radix(eth_address, 16).expect(zinc_const::panic::TEST_DATA_VALID),
+            zinc_types::ScalarType::eth_address(),
+        )
+        .expect(zinc_const::panic::TEST_DATA_VALID);
+
+        let is_verified = super::verify(
+            cs.namespace(|| "verify"),
+            &msg_hash,
+            &signature,
+            &eth_address,
+        )
+        .expect(zinc_const::panic::TEST_DATA_VALID)
+        .get_value()
+        .expect(zinc_const::panic::TEST_DATA_VALID);
+
+        (is_verified, cs.is_satisfied())
+    }
+
+    #[test]
+    fn ok_even() {
+        assert_eq!(
+            verify(
+                MESSAGE_HASH,
+                "f01d6b9018ab421dd410404cb869072065522bf85734008f105cf385a023a80f",
+                "262d11a37d157ace1066872da68aa179bb6ecc8ce57647b7c189f54923ea13b3",
+                27,
+                "7e5f4552091a69125d5dfcb7b8c2659029395bdf",
+            ),
+            (true, true)
+        );
+    }
+
+    #[test]
+    fn ok_odd() {
+        assert_eq!(
+            verify(
+                MESSAGE_HASH,
+                "e1fe434d345bf33083abb6280f4f44ac5fb22934977813c20c015f2b43d3fab8",
+                "1802cf6929a81c391979f05acf5851f0033fd4ceddde9c8826b5866d1217c407",
+                28,
+                "2b5ad5c4795c026514f8317c7a215e218dccd6cf",
+            ),
+            (true, true)
+        );
+    }
+
+    #[test]
+    fn error_address_mismatch() {
+        assert_eq!(
+            verify(
+                MESSAGE_HASH,
+                "f01d6b9018ab421dd410404cb869072065522bf85734008f105cf385a023a80f",
+                "262d11a37d157ace1066872da68aa179bb6ecc8ce57647b7c189f54923ea13b3",
+                27,
+                "2b5ad5c4795c026514f8317c7a215e218dccd6cf",
+            ),
+            (false, true)
+        );
+    }
+
+    #[test]
+    fn error_recovery_id_invalid() {
+        assert_eq!(
+            verify(
+                MESSAGE_HASH,
+                "f01d6b9018ab421dd410404cb869072065522bf85734008f105cf385a023a80f",
+                "262d11a37d157ace1066872da68aa179bb6ecc8ce57647b7c189f54923ea13b3",
+                0,
+                "7e5f4552091a69125d5dfcb7b8c2659029395bdf",
+            ),
+            (false, true)
+        );
+    }
+}
@@ -0,0 +1,207 @@
+//!
+//! The secp256k1 curve arithmetic over big integers.
+//!
+//! Used to compute the ECDSA gadget witness values.
+//!
+
+use lazy_static::lazy_static;
+use num::BigInt;
+use num::Integer;
+use num::One;
+use num::Zero;
+
+lazy_static! {
+    /// The secp256k1 base field modulus.
+    pub static ref MODULUS: BigInt = BigInt::parse_bytes(
+        b"fffffffffffffffffffffffffffffffffffffffffffffffffffffffefffffc2f",
+        16
+    )
+    .expect(zinc_const::panic::DATA_CONVERSION);
+
+    /// The secp256k1 group order.
+    pub static ref ORDER: BigInt = BigInt::parse_bytes(
+        b"fffffffffffffffffffffffffffffffebaaedce6af48a03bbfd25e8cd0364141",
+        16
+    )
+    .expect(zinc_const::panic::DATA_CONVERSION);
+
+    /// The secp256k1 generator.
+    pub static ref GENERATOR: Point = Point::new(
+        BigInt::parse_bytes(
+            b"79be667ef9dcbbac55a06295ce870b07029bfcdb2dce28d959f2815b16f81798",
+            16
+        )
+        .expect(zinc_const::panic::DATA_CONVERSION),
+        BigInt::parse_bytes(
+            b"483ada7726a3c4655da4fbfc0e1108a8fd17b448a68554199c47d08ffb10d4b8",
+            16
+        )
+        .expect(zinc_const::panic::DATA_CONVERSION),
+    );
+
+    ///
+    /// The scalar multiplication accumulator starting point.
+    ///
+    /// It is the point with the smallest possible `x` coordinate and an even `y` coordinate,
+    /// so nobody knows its discrete logarithm, which makes the incomplete addition formulas
+    /// safe to use in the accumulator.
+    ///
+    pub static ref OFFSET: Point = {
+        let x = BigInt::one();
+        let y = sqrt(&(&x * &x * &x + BigInt::from(7))).expect(zinc_const::panic::DATA_CONVERSION);
+        let y = if y.is_odd() { &*MODULUS - y } else { y };
+        Point::new(x, y)
+    };
+}
+
+///
+/// The secp256k1 affine point. The point at infinity is represented with `None` where needed.
+///
+#[derive(Debug, Clone, PartialEq)]
+pub struct Point {
+    /// The `x` coordinate.
+    pub x: BigInt,
+    /// The `y` coordinate.
+    pub y: BigInt,
+}
+
+impl Point {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(x: BigInt, y: BigInt) -> Self {
+        Self { x, y }
+    }
+
+    ///
+    /// Checks whether the point satisfies the curve equation `y^2 = x^3 + 7`.
+    ///
+    pub fn is_on_curve(&self) -> bool {
+        reduce(&(&self.y * &self.y)) == reduce(&(&self.x * &self.x * &self.x + BigInt::from(7)))
+    }
+
+    ///
+    /// Adds two points. Returns `None` if the result is the point at infinity.
+    ///
+    pub fn add(&self, other: &Self) -> Option<Self> {
+        if self.x == other.x {
+            return if self.y == other.y {
+                self.double()
+            } else {
+                None
+            };
+        }
+
+        let lambda = reduce(&((&other.y - &self.y) * inverse(&(&other.x - &self.x))));
+        let x = reduce(&(&lambda * &lambda - &self.x - &other.x));
+        let y = reduce(&(&lambda * (&self.x - &x) - &self.y));
+
+        Some(Self::new(x, y))
+    }
+
+    ///
+    /// Doubles the point. Returns `None` if the result is the point at infinity.
+    ///
+    pub fn double(&self) -> Option<Self> {
+        if self.y.is_zero() {
+            return None;
+        }
+
+        let lambda =
+            reduce(&(BigInt::from(3) * &self.x * &self.x * inverse(&(BigInt::from(2) * &self.y))));
+        let x = reduce(&(&lambda * &lambda - BigInt::from(2) * &self.x));
+        let y = reduce(&(&lambda * (&self.x - &x) - &self.y));
+
+        Some(Self::new(x, y))
+    }
+
+    ///
+    /// Multiplies the point by a non-negative `scalar`. Returns `None` if the result is
+    /// the point at infinity.
+    ///
+    pub fn mul(&self, scalar: &BigInt) -> Option<Self> {
+        let mut result: Option<Self> = None;
+        for index in (0..scalar.bits()).rev() {
+            result = result.and_then(|point| point.double());
+            if (scalar >> index).is_odd() {
+                result = match result {
+                    Some(point) => point.add(self),
+                    None => Some(self.clone()),
+                };
+            }
+        }
+        result
+    }
+
+    ///
+    /// Returns the point with the opposite `y` coordinate.
+    ///
+    pub fn negate(&self) -> Self {
+        Self::new(self.x.clone(), reduce(&-&self.y))
+    }
+}
+
+///
+/// Recovers the public key from the message hash and the signature as Ethereum does.
+///
+/// Returns `None` if the signature components are out of range or there is no such key.
+///
+pub fn recover(msg_hash: &BigInt, r: &BigInt, s: &BigInt, is_y_odd: bool) -> Option<Point> {
+    if r.is_zero() || r >= &*ORDER || s.is_zero() || s >= &*ORDER {
+        return None;
+    }
+
+    let y = sqrt(&(r * r * r + BigInt::from(7)))?;
+    let y = if y.is_odd() == is_y_odd {
+        y
+    } else {
+        &*MODULUS - y
+    };
+    let point_r = Point::new(r.to_owned(), y);
+
+    let r_inverse = r.modpow(&(&*ORDER - BigInt::from(2)), &ORDER);
+    let u1 = (&*ORDER - msg_hash.mod_floor(&*ORDER)) * &r_inverse % &*ORDER;
+    let u2 = s * &r_inverse % &*ORDER;
+
+    match (GENERATOR.mul(&u1), point_r.mul(&u2)) {
+        (Some(left), Some(right)) => left.add(&right),
+        (None, right) => right,
+        (left, None) => left,
+    }
+}
+
+///
+/// Reduces the value modulo the base field modulus, returning a non-negative result.
+///
+pub fn reduce(value: &BigInt) -> BigInt {
+    value.mod_floor(&*MODULUS)
+}
+
+///
+/// Computes the base field inverse. The zero value is mapped to zero.
+///
+pub fn inverse(value: &BigInt) -> BigInt {
+    reduce(value).modpow(&(&*MODULUS - BigInt::from(2)), &MODULUS)
+}
+
+///
+/// Computes the base field square root, if it exists.
+///
+/// The modulus is `3 mod 4`, so the root is `value ^ ((p + 1) / 4)`.
+///
+pub fn sqrt(value: &BigInt) -> Option<BigInt> {
+    let value = reduce(value);
+    let root = value.modpow(&((&*MODULUS + BigInt::one()) >> 2), &MODULUS);
+    if reduce(&(&root * &root)) == value {
+        Some(root)
+    } else {
+        None
+    }
+}
+
+///
+/// Checks whether the value is a quadratic residue in the base field.
+///
+pub fn is_square(value: &BigInt) -> bool {
+    sqrt(value).is_some()
+}
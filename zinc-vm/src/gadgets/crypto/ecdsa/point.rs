@@ -0,0 +1,336 @@
+//!
+//! The secp256k1 affine point gadget.
+//!
+//! The addition formulas are incomplete, so the point at infinity is never represented.
+//! The scalar multiplication accumulator starts at a point with unknown discrete logarithm,
+//! which makes the exceptional cases unreachable for honest witnesses.
+//!
+
+use num::BigInt;
+use num::Zero;
+
+use franklin_crypto::bellman::ConstraintSystem;
+use franklin_crypto::circuit::boolean::Boolean;
+
+use crate::error::Error;
+use crate::gadgets::crypto::ecdsa::field::FieldElement;
+use crate::gadgets::crypto::ecdsa::field::Relation;
+use crate::gadgets::crypto::ecdsa::native;
+use crate::gadgets::crypto::ecdsa::native::Point;
+use crate::IEngine;
+
+///
+/// The secp256k1 affine point.
+///
+#[derive(Clone)]
+pub struct AffinePoint<E: IEngine> {
+    /// The `x` coordinate.
+    pub x: FieldElement<E>,
+    /// The `y` coordinate.
+    pub y: FieldElement<E>,
+    /// The point value.
+    value: Option<Point>,
+}
+
+impl<E: IEngine> AffinePoint<E> {
+    ///
+    /// Creates a constant point.
+    ///
+    pub fn constant<CS>(point: &Point) -> Self
+    where
+        CS: ConstraintSystem<E>,
+    {
+        Self {
+            x: FieldElement::constant::<CS>(&point.x),
+            y: FieldElement::constant::<CS>(&point.y),
+            value: Some(point.to_owned()),
+        }
+    }
+
+    ///
+    /// Creates a point from its coordinates, which are checked by the caller.
+    ///
+    pub fn from_coordinates(x: FieldElement<E>, y: FieldElement<E>) -> Self {
+        let value = match (x.get_value(), y.get_value()) {
+            (Some(x), Some(y)) => Some(Point::new(native::reduce(&x), native::reduce(&y))),
+            _ => None,
+        };
+
+        Self { x, y, value }
+    }
+
+    ///
+    /// Allocates a point with canonical coordinates, enforcing it to be on the curve.
+    ///
+    pub fn alloc<CS>(mut cs: CS, point: Option<Point>) -> Result<Self, Error>
+    where
+        CS: ConstraintSystem<E>,
+    {
+        let x = FieldElement::alloc_canonical(
+            cs.namespace(|| "x"),
+            point.as_ref().map(|point| point.x.to_owned()),
+        )?;
+        let y = FieldElement::alloc_canonical(
+            cs.namespace(|| "y"),
+            point.as_ref().map(|point| point.y.to_owned()),
+        )?;
+
+        let x_squared = FieldElement::alloc(
+            cs.namespace(|| "x squared"),
+            point
+                .as_ref()
+                .map(|point| native::reduce(&(&point.x * &point.x))),
+        )?;
+        Relation::new()
+            .product(1, &x, &x)
+            .linear(-1, &x_squared)
+            .enforce(cs.namespace(|| "x squared relation"))?;
+        Relation::new()
+            .product(1, &y, &y)
+            .product(-1, &x_squared, &x)
+            .constant(-7)
+            .enforce(cs.namespace(|| "curve equation"))?;
+
+        Ok(Self { x, y, value: point })
+    }
+
+    ///
+    /// Returns the point value.
+    ///
+    pub fn get_value(&self) -> Option<Point> {
+        self.value.clone()
+    }
+
+    ///
+    /// Doubles the point.
+    ///
+    pub fn double<CS>(&self, mut cs: CS) -> Result<Self, Error>
+    where
+        CS: ConstraintSystem<E>,
+    {
+        let value = self.value.as_ref().and_then(Point::double);
+        let lambda_value = self.value.as_ref().map(|point| {
+            native::reduce(
+                &(BigInt::from(3)
+                    * &point.x
+                    * &point.x
+                    * native::inverse(&(BigInt::from(2) * &point.y))),
+            )
+        });
+
+        let lambda = FieldElement::alloc(cs.namespace(|| "lambda"), lambda_value)?;
+        let (x, y) = self.alloc_result(cs.namespace(|| "result"), value.as_ref())?;
+
+        Relation::new()
+            .product(2, &lambda, &self.y)
+            .product(-3, &self.x, &self.x)
+            .enforce(cs.namespace(|| "lambda relation"))?;
+        Relation::new()
+            .product(1, &lambda, &lambda)
+            .linear(-2, &self.x)
+            .linear(-1, &x)
+            .enforce(cs.namespace(|| "x relation"))?;
+        Relation::new()
+            .product(1, &lambda, &self.x)
+            .product(-1, &lambda, &x)
+            .linear(-1, &self.y)
+            .linear(-1, &y)
+            .enforce(cs.namespace(|| "y relation"))?;
+
+        Ok(Self { x, y, value })
+    }
+
+    ///
+    /// Adds two points, enforcing their `x` coordinates to be different.
+    ///
+    pub fn add<CS>(&self, mut cs: CS, other: &Self) -> Result<Self, Error>
+    where
+        CS: ConstraintSystem<E>,
+    {
+        let value = match (self.value.as_ref(), other.value.as_ref()) {
+            (Some(left), Some(right)) if left.x != right.x => left.add(right),
+            _ => None,
+        };
+        let (lambda_value, inverse_value) = match (self.value.as_ref(), other.value.as_ref()) {
+            (Some(left), Some(right)) => {
+                let inverse = native::inverse(&(&right.x - &left.x));
+                (
+                    Some(native::reduce(&((&right.y - &left.y) * &inverse))),
+                    Some(inverse),
+                )
+            }
+            _ => (None, None),
+        };
+
+        let lambda = FieldElement::alloc(cs.namespace(|| "lambda"), lambda_value)?;
+        let inverse = FieldElement::alloc(cs.namespace(|| "inverse"), inverse_value)?;
+        let (x, y) = self.alloc_result(cs.namespace(|| "result"), value.as_ref())?;
+
+        Relation::new()
+            .product(1, &inverse, &other.x)
+            .product(-1, &inverse, &self.x)
+            .constant(-1)
+            .enforce(cs.namespace(|| "x difference is not zero"))?;
+        Relation::new()
+            .product(1, &lambda, &other.x)
+            .product(-1, &lambda, &self.x)
+            .linear(-1, &other.y)
+            .linear(1, &self.y)
+            .enforce(cs.namespace(|| "lambda relation"))?;
+        Relation::new()
+            .product(1, &lambda, &lambda)
+            .linear(-1, &self.x)
+            .linear(-1, &other.x)
+            .linear(-1, &x)
+            .enforce(cs.namespace(|| "x relation"))?;
+        Relation::new()
+            .product(1, &lambda, &self.x)
+            .product(-1, &lambda, &x)
+            .linear(-1, &self.y)
+            .linear(-1, &y)
+            .enforce(cs.namespace(|| "y relation"))?;
+
+        Ok(Self { x, y, value })
+    }
+
+    ///
+    /// Selects `first` if the `condition` is true, and `second` otherwise.
+    ///
+    pub fn conditionally_select<CS>(
+        mut cs: CS,
+        condition: &Boolean,
+        first: &Self,
+        second: &Self,
+    ) -> Result<Self, Error>
+    where
+        CS: ConstraintSystem<E>,
+    {
+        let x = FieldElement::conditionally_select(
+            cs.namespace(|| "x"),
+            condition,
+            &first.x,
+            &second.x,
+        )?;
+        let y = FieldElement::conditionally_select(
+            cs.namespace(|| "y"),
+            condition,
+            &first.y,
+            &second.y,
+        )?;
+        let value = match condition.get_value() {
+            Some(true) => first.value.clone(),
+            Some(false) => second.value.clone(),
+            None => None,
+        };
+
+        Ok(Self { x, y, value })
+    }
+
+    ///
+    /// Allocates the point with canonical coordinates which is equal to `self`.
+    ///
+    pub fn reduce<CS>(&self, mut cs: CS) -> Result<Self, Error>
+    where
+        CS: ConstraintSystem<E>,
+    {
+        Ok(Self {
+            x: self.x.reduce(cs.namespace(|| "x"))?,
+            y: self.y.reduce(cs.namespace(|| "y"))?,
+            value: self.value.clone(),
+        })
+    }
+
+    ///
+    /// Computes `OFFSET * 2^n + self * scalar`, where `scalar` is given with `n` big-endian bits.
+    ///
+    /// The offset is cancelled out by the caller comparing two results with the same `n`.
+    ///
+    pub fn mul<CS>(&self, mut cs: CS, scalar_be: &[Boolean]) -> Result<Self, Error>
+    where
+        CS: ConstraintSystem<E>,
+    {
+        let mut result = Self::constant::<CS>(&native::OFFSET);
+        for (index, bit) in scalar_be.iter().enumerate() {
+            let doubled = result.double(cs.namespace(|| format!("bit {} double", index)))?;
+            let added = doubled.add(cs.namespace(|| format!("bit {} add", index)), self)?;
+            result = Self::conditionally_select(
+                cs.namespace(|| format!("bit {} select", index)),
+                bit,
+                &added,
+                &doubled,
+            )?;
+        }
+
+        Ok(result)
+    }
+
+    ///
+    /// Computes `self + base * scalar`, where `scalar` is given with little-endian bits.
+    ///
+    /// The multiples of the constant `base` are precomputed, so every bit costs one addition.
+    ///
+    pub fn add_fixed_base_mul<CS>(
+        &self,
+        mut cs: CS,
+        base: &Point,
+        scalar_le: &[Boolean],
+    ) -> Result<Self, Error>
+    where
+        CS: ConstraintSystem<E>,
+    {
+        let mut result = self.to_owned();
+        let mut multiple = base.to_owned();
+        for (index, bit) in scalar_le.iter().enumerate() {
+            let multiple_point = Self::constant::<CS>(&multiple);
+            let added = result.add(
+                cs.namespace(|| format!("bit {} add", index)),
+                &multiple_point,
+            )?;
+            result = Self::conditionally_select(
+                cs.namespace(|| format!("bit {} select", index)),
+                bit,
+                &added,
+                &result,
+            )?;
+
+            multiple = multiple
+                .double()
+                .expect(zinc_const::panic::VALUE_ALWAYS_EXISTS);
+        }
+
+        Ok(result)
+    }
+
+    ///
+    /// Allocates the coordinates of an operation result.
+    ///
+    /// The point at infinity is only reachable with a malicious witness, so its coordinates
+    /// are replaced with zeros, which do not satisfy the relations.
+    ///
+    fn alloc_result<CS>(
+        &self,
+        mut cs: CS,
+        value: Option<&Point>,
+    ) -> Result<(FieldElement<E>, FieldElement<E>), Error>
+    where
+        CS: ConstraintSystem<E>,
+    {
+        let is_known = self.value.is_some();
+        let coordinate = |coordinate: Option<&BigInt>| match coordinate {
+            Some(coordinate) => Some(coordinate.to_owned()),
+            None if is_known => Some(BigInt::zero()),
+            None => None,
+        };
+
+        let x = FieldElement::alloc(
+            cs.namespace(|| "x"),
+            coordinate(value.map(|point| &point.x)),
+        )?;
+        let y = FieldElement::alloc(
+            cs.namespace(|| "y"),
+            coordinate(value.map(|point| &point.y)),
+        )?;
+
+        Ok((x, y))
+    }
+}
@@ -0,0 +1,253 @@
+//!
+//! The zkSync EdDSA signature verification gadget.
+//!
+//! The signature is the Rescue-based MuSig variant produced by the zkSync signing keys.
+//! The message is padded and hashed before signing, and the challenge is computed over
+//! the public key, the signature commitment and the message hash.
+//!
+
+use franklin_crypto::bellman::pairing::ff::PrimeField;
+use franklin_crypto::bellman::ConstraintSystem;
+use franklin_crypto::circuit::baby_eddsa::EddsaSignature;
+use franklin_crypto::circuit::boolean::Boolean;
+use franklin_crypto::circuit::ecc;
+use franklin_crypto::circuit::num::AllocatedNum;
+use franklin_crypto::circuit::rescue;
+use franklin_crypto::jubjub::FixedGenerators;
+
+use crate::error::Error;
+use crate::IEngine;
+
+/// The serialized point coordinate and message hash bitlength.
+const SERIALIZED_BITLENGTH: usize = 256;
+
+/// The number of doublings which clear the curve cofactor 8.
+const COFACTOR_DOUBLINGS: usize = 3;
+
+///
+/// Verifies the zkSync EdDSA `signature` of the `message`.
+///
+/// The message bits are big-endian within every byte, as everywhere in Zinc.
+///
+pub fn verify<E, CS>(
+    mut cs: CS,
+    message: &[Boolean],
+    signature: &EddsaSignature<E>,
+) -> Result<Boolean, Error>
+where
+    E: IEngine,
+    CS: ConstraintSystem<E>,
+{
+    assert!(message.len() <= zinc_const::limit::EDDSA_MESSAGE_BITS);
+
+    let params = E::jubjub_params();
+
+    let mut message = message.to_vec();
+    message.resize(
+        zinc_const::limit::EDDSA_MESSAGE_BITS,
+        Boolean::constant(false),
+    );
+    let message_hash = hash_bits(cs.namespace(|| "message hash"), &message)?;
+    let mut message_hash_bits =
+        message_hash.into_bits_le_strict(cs.namespace(|| "message hash bits"))?;
+    message_hash_bits.resize(SERIALIZED_BITLENGTH, Boolean::constant(false));
+
+    let mut public_key_x_bits = signature
+        .pk
+        .get_x()
+        .into_bits_le_strict(cs.namespace(|| "public key x bits"))?;
+    public_key_x_bits.resize(SERIALIZED_BITLENGTH, Boolean::constant(false));
+    let mut commitment_x_bits = signature
+        .r
+        .get_x()
+        .into_bits_le_strict(cs.namespace(|| "commitment x bits"))?;
+    commitment_x_bits.resize(SERIALIZED_BITLENGTH, Boolean::constant(false));
+
+    let mut challenge_preimage = public_key_x_bits;
+    challenge_preimage.extend(commitment_x_bits);
+    challenge_preimage.extend(message_hash_bits);
+    let challenge = hash_bits(cs.namespace(|| "challenge"), &challenge_preimage)?;
+    let mut challenge_bits = challenge.into_bits_le_strict(cs.namespace(|| "challenge bits"))?;
+    challenge_bits.truncate(E::Fs::CAPACITY as usize);
+
+    let s_bits = signature.s.into_bits_le_strict(cs.namespace(|| "s bits"))?;
+
+    // the relation is `s * G = R + c * PK`, both sides are multiplied by the cofactor
+    let mut left = ecc::fixed_base_multiplication(
+        cs.namespace(|| "s * G"),
+        FixedGenerators::SpendingKeyGenerator,
+        &s_bits,
+        params,
+    )?;
+    let mut right = signature
+        .pk
+        .mul(cs.namespace(|| "c * PK"), &challenge_bits, params)?
+        .add(cs.namespace(|| "R + c * PK"), &signature.r, params)?;
+    for index in 0..COFACTOR_DOUBLINGS {
+        left = left.double(cs.namespace(|| format!("left double {}", index)), params)?;
+        right = right.double(cs.namespace(|| format!("right double {}", index)), params)?;
+    }
+
+    let is_x_equal =
+        AllocatedNum::equals(cs.namespace(|| "x equals"), left.get_x(), right.get_x())?;
+    let is_y_equal =
+        AllocatedNum::equals(cs.namespace(|| "y equals"), left.get_y(), right.get_y())?;
+
+    let is_verified = Boolean::and(
+        cs.namespace(|| "is verified"),
+        &Boolean::from(is_x_equal),
+        &Boolean::from(is_y_equal),
+    )?;
+
+    Ok(is_verified)
+}
+
+///
+/// Packs the little-endian `bits` into field elements and hashes them with Rescue.
+///
+fn hash_bits<E, CS>(mut cs: CS, bits: &[Boolean]) -> Result<AllocatedNum<E>, Error>
+where
+    E: IEngine,
+    CS: ConstraintSystem<E>,
+{
+    let preimage = bits
+        .chunks(E::Fr::CAPACITY as usize)
+        .enumerate()
+        .map(|(index, chunk)| {
+            AllocatedNum::pack_bits_to_element(cs.namespace(|| format!("chunk {}", index)), chunk)
+                .map_err(Error::from)
+        })
+        .collect::<Result<Vec<AllocatedNum<E>>, Error>>()?;
+
+    let mut digest = rescue::rescue_hash(
+        cs.namespace(|| "rescue"),
+        preimage.as_slice(),
+        E::rescue_params(),
+    )?;
+
+    Ok(digest.remove(0))
+}
+
+#[cfg(test)]
+mod tests {
+    use franklin_crypto::bellman::pairing::bn256::Bn256;
+    use franklin_crypto::bellman::pairing::bn256::Fr;
+    use franklin_crypto::bellman::pairing::ff::Field;
+    use franklin_crypto::bellman::pairing::ff::PrimeField;
+    use franklin_crypto::bellman::pairing::ff::PrimeFieldRepr;
+    use franklin_crypto::bellman::ConstraintSystem;
+    use franklin_crypto::circuit::baby_eddsa::EddsaSignature;
+    use franklin_crypto::circuit::boolean::AllocatedBit;
+    use franklin_crypto::circuit::boolean::Boolean;
+    use franklin_crypto::circuit::ecc::EdwardsPoint;
+    use franklin_crypto::circuit::num::AllocatedNum;
+
+    use zksync_types::tx::TxSignature;
+
+    use crate::constraint_systems::main::Main as MainCS;
+    use crate::IEngine;
+
+    /// The signing key seed.
+    const SEED: [u8; 32] = [0x42; 32];
+
+    fn verify(message: &[u8], signature: &TxSignature) -> bool {
+        verify_with_s(message, signature, s_value(signature))
+    }
+
+    fn verify_with_s(message: &[u8], signature: &TxSignature, s_value: Fr) -> bool {
+        let mut cs = MainCS::<Bn256>::new();
+
+        let mut message_bits = Vec::with_capacity(message.len() * zinc_const::bitlength::BYTE);
+        for (byte_index, byte) in message.iter().enumerate() {
+            for bit_index in (0..zinc_const::bitlength::BYTE).rev() {
+                let bit = AllocatedBit::alloc(
+                    cs.namespace(|| format!("message {} {}", byte_index, bit_index)),
+                    Some((byte >> bit_index) & 1 == 1),
+                )
+                .expect(zinc_const::panic::TEST_DATA_VALID);
+                message_bits.push(Boolean::from(bit));
+            }
+        }
+
+        let mut alloc_point = |name: &str, (x, y): (Fr, Fr)| {
+            let x = AllocatedNum::alloc(cs.namespace(|| format!("{} x", name)), || Ok(x))
+                .expect(zinc_const::panic::TEST_DATA_VALID);
+            let y = AllocatedNum::alloc(cs.namespace(|| format!("{} y", name)), || Ok(y))
+                .expect(zinc_const::panic::TEST_DATA_VALID);
+            EdwardsPoint::interpret(
+                cs.namespace(|| format!("{} interpret", name)),
+                &x,
+                &y,
+                Bn256::jubjub_params(),
+            )
+            .expect(zinc_const::panic::TEST_DATA_VALID)
+        };
+        let r = alloc_point("r", signature.signature.0.r.into_xy());
+        let pk = alloc_point("pk", (signature.pub_key.0).0.into_xy());
+
+        let s = AllocatedNum::alloc(cs.namespace(|| "s"), || Ok(s_value))
+            .expect(zinc_const::panic::TEST_DATA_VALID);
+
+        let is_verified = super::verify(
+            cs.namespace(|| "verify"),
+            &message_bits,
+            &EddsaSignature { r, s, pk },
+        )
+        .expect(zinc_const::panic::TEST_DATA_VALID);
+        assert!(cs.is_satisfied());
+
+        is_verified
+            .get_value()
+            .expect(zinc_const::panic::VALUE_ALWAYS_EXISTS)
+    }
+
+    fn s_value(signature: &TxSignature) -> Fr {
+        let mut s_bytes = Vec::new();
+        signature
+            .signature
+            .0
+            .s
+            .into_repr()
+            .write_le(&mut s_bytes)
+            .expect(zinc_const::panic::TEST_DATA_VALID);
+        let mut s_repr = <Fr as PrimeField>::Repr::default();
+        s_repr
+            .read_le(s_bytes.as_slice())
+            .expect(zinc_const::panic::TEST_DATA_VALID);
+        Fr::from_repr(s_repr).expect(zinc_const::panic::TEST_DATA_VALID)
+    }
+
+    fn sign(message: &[u8]) -> TxSignature {
+        let private_key =
+            zksync::utils::private_key_from_seed(&SEED).expect(zinc_const::panic::TEST_DATA_VALID);
+        let signature = TxSignature::sign_musig(&private_key, message);
+        assert!(signature.verify_musig(message).is_some());
+        signature
+    }
+
+    #[test]
+    fn ok_short() {
+        let message = b"zinc";
+        assert!(verify(message, &sign(message)));
+    }
+
+    #[test]
+    fn ok_maximal_length() {
+        let message = [0xa5; zinc_const::limit::EDDSA_MESSAGE_BYTES];
+        assert!(verify(&message, &sign(&message)));
+    }
+
+    #[test]
+    fn error_message_mismatch() {
+        assert!(!verify(b"zinc", &sign(b"zync")));
+    }
+
+    #[test]
+    fn error_tampered_s() {
+        let message = b"zinc";
+        let signature = sign(message);
+        let mut s = s_value(&signature);
+        s.add_assign(&Fr::one());
+        assert!(!verify_with_s(message, &signature, s));
+    }
+}
@@ -0,0 +1,350 @@
+//!
+//! The Keccak-256 hash gadget.
+//!
+//! Implements the original Keccak padding used by Ethereum, which differs from the
+//! standardized SHA3-256 one.
+//!
+
+use franklin_crypto::bellman::ConstraintSystem;
+use franklin_crypto::circuit::boolean::Boolean;
+
+use crate::error::Error;
+use crate::IEngine;
+
+/// The Keccak-f[1600] lane bitlength.
+const LANE_BITLENGTH: usize = 64;
+
+/// The number of lanes in the Keccak state.
+const LANES: usize = 25;
+
+/// The sponge rate in bytes for the 256-bit output.
+const RATE_BYTES: usize = 136;
+
+/// The number of Keccak-f[1600] rounds.
+const ROUNDS: usize = 24;
+
+/// The Keccak-f[1600] round constants.
+const ROUND_CONSTANTS: [u64; ROUNDS] = [
+    0x0000_0000_0000_0001,
+    0x0000_0000_0000_8082,
+    0x8000_0000_0000_808A,
+    0x8000_0000_8000_8000,
+    0x0000_0000_0000_808B,
+    0x0000_0000_8000_0001,
+    0x8000_0000_8000_8081,
+    0x8000_0000_0000_8009,
+    0x0000_0000_0000_008A,
+    0x0000_0000_0000_0088,
+    0x0000_0000_8000_8009,
+    0x0000_0000_8000_000A,
+    0x0000_0000_8000_808B,
+    0x8000_0000_0000_008B,
+    0x8000_0000_0000_8089,
+    0x8000_0000_0000_8003,
+    0x8000_0000_0000_8002,
+    0x8000_0000_0000_0080,
+    0x0000_0000_0000_800A,
+    0x8000_0000_8000_000A,
+    0x8000_0000_8000_8081,
+    0x8000_0000_0000_8080,
+    0x0000_0000_8000_0001,
+    0x8000_0000_8000_8008,
+];
+
+/// The rho step rotation offsets, indexed with `x + 5 * y`.
+const ROTATIONS: [usize; LANES] = [
+    0, 1, 62, 28, 27, 36, 44, 6, 55, 20, 3, 10, 43, 25, 39, 41, 45, 15, 21, 8, 18, 2, 61, 56, 14,
+];
+
+/// A state lane with little-endian bits.
+type Lane = Vec<Boolean>;
+
+///
+/// Computes the Keccak-256 digest of the `input` bits.
+///
+/// The input and output bits are big-endian within every byte, as everywhere in Zinc.
+///
+pub fn keccak256<E, CS>(mut cs: CS, input: &[Boolean]) -> Result<Vec<Boolean>, Error>
+where
+    E: IEngine,
+    CS: ConstraintSystem<E>,
+{
+    assert_eq!(input.len() % 8, 0);
+
+    let mut bytes: Vec<Vec<Boolean>> = input
+        .chunks(8)
+        .map(|byte| byte.iter().rev().cloned().collect())
+        .collect();
+
+    let padding_length = RATE_BYTES - bytes.len() % RATE_BYTES;
+    for index in 0..padding_length {
+        let mut value = 0u8;
+        if index == 0 {
+            value |= 0x01;
+        }
+        if index == padding_length - 1 {
+            value |= 0x80;
+        }
+        bytes.push(
+            (0..8)
+                .map(|bit| Boolean::constant((value >> bit) & 1 == 1))
+                .collect(),
+        );
+    }
+
+    let mut state: Vec<Lane> = vec![vec![Boolean::constant(false); LANE_BITLENGTH]; LANES];
+    for (block_index, block) in bytes.chunks(RATE_BYTES).enumerate() {
+        let mut cs = cs.namespace(|| format!("block {}", block_index));
+
+        for (lane_index, lane_bytes) in block.chunks(LANE_BITLENGTH / 8).enumerate() {
+            let lane_bits: Vec<&Boolean> = lane_bytes.iter().flatten().collect();
+            for (bit_index, bit) in lane_bits.into_iter().enumerate() {
+                state[lane_index][bit_index] = Boolean::xor(
+                    cs.namespace(|| format!("absorb lane {} bit {}", lane_index, bit_index)),
+                    &state[lane_index][bit_index],
+                    bit,
+                )?;
+            }
+        }
+
+        state = keccak_f(cs.namespace(|| "permutation"), state)?;
+    }
+
+    let digest = state
+        .iter()
+        .flat_map(|lane| lane.chunks(8))
+        .take(zinc_const::size::KECCAK256_HASH)
+        .flat_map(|byte| byte.iter().rev().cloned())
+        .collect();
+
+    Ok(digest)
+}
+
+///
+/// The Keccak-f[1600] permutation.
+///
+fn keccak_f<E, CS>(mut cs: CS, mut state: Vec<Lane>) -> Result<Vec<Lane>, Error>
+where
+    E: IEngine,
+    CS: ConstraintSystem<E>,
+{
+    for (round, constant) in ROUND_CONSTANTS.iter().enumerate() {
+        let mut cs = cs.namespace(|| format!("round {}", round));
+
+        let mut columns = Vec::with_capacity(5);
+        for x in 0..5 {
+            let mut column = state[x].clone();
+            for y in 1..5 {
+                column = xor_lanes(
+                    cs.namespace(|| format!("theta column {} {}", x, y)),
+                    &column,
+                    &state[x + 5 * y],
+                )?;
+            }
+            columns.push(column);
+        }
+        for x in 0..5 {
+            let rotated = rotate_left(&columns[(x + 1) % 5], 1);
+            let difference = xor_lanes(
+                cs.namespace(|| format!("theta difference {}", x)),
+                &columns[(x + 4) % 5],
+                &rotated,
+            )?;
+            for y in 0..5 {
+                state[x + 5 * y] = xor_lanes(
+                    cs.namespace(|| format!("theta lane {} {}", x, y)),
+                    &state[x + 5 * y],
+                    &difference,
+                )?;
+            }
+        }
+
+        let mut permuted = vec![Vec::new(); LANES];
+        for x in 0..5 {
+            for y in 0..5 {
+                let index = x + 5 * y;
+                permuted[y + 5 * ((2 * x + 3 * y) % 5)] =
+                    rotate_left(&state[index], ROTATIONS[index]);
+            }
+        }
+
+        for y in 0..5 {
+            for x in 0..5 {
+                let first = &permuted[(x + 1) % 5 + 5 * y];
+                let second = &permuted[(x + 2) % 5 + 5 * y];
+                let mut lane = Vec::with_capacity(LANE_BITLENGTH);
+                for bit_index in 0..LANE_BITLENGTH {
+                    let product = Boolean::and(
+                        cs.namespace(|| format!("chi and {} {} {}", x, y, bit_index)),
+                        &first[bit_index].not(),
+                        &second[bit_index],
+                    )?;
+                    lane.push(Boolean::xor(
+                        cs.namespace(|| format!("chi xor {} {} {}", x, y, bit_index)),
+                        &permuted[x + 5 * y][bit_index],
+                        &product,
+                    )?);
+                }
+                state[x + 5 * y] = lane;
+            }
+        }
+
+        for (bit_index, bit) in state[0].iter_mut().enumerate() {
+            if (constant >> bit_index) & 1 == 1 {
+                *bit = bit.not();
+            }
+        }
+    }
+
+    Ok(state)
+}
+
+///
+/// XORs two lanes bit by bit.
+///
+fn xor_lanes<E, CS>(mut cs: CS, left: &[Boolean], right: &[Boolean]) -> Result<Lane, Error>
+where
+    E: IEngine,
+    CS: ConstraintSystem<E>,
+{
+    left.iter()
+        .zip(right.iter())
+        .enumerate()
+        .map(|(index, (left, right))| {
+            Boolean::xor(cs.namespace(|| format!("bit {}", index)), left, right)
+                .map_err(Error::from)
+        })
+        .collect()
+}
+
+///
+/// Rotates a lane with little-endian bits to the left.
+///
+fn rotate_left(lane: &[Boolean], offset: usize) -> Lane {
+    (0..LANE_BITLENGTH)
+        .map(|index| lane[(index + LANE_BITLENGTH - offset) % LANE_BITLENGTH].clone())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use franklin_crypto::bellman::pairing::bn256::Bn256;
+    use franklin_crypto::bellman::ConstraintSystem;
+    use franklin_crypto::circuit::boolean::AllocatedBit;
+    use franklin_crypto::circuit::boolean::Boolean;
+    use franklin_crypto::circuit::test::TestConstraintSystem;
+
+    fn digest(message: &[u8]) -> String {
+        let mut cs = TestConstraintSystem::<Bn256>::new();
+
+        let mut bits = Vec::new();
+        for (byte_index, byte) in message.iter().enumerate() {
+            for bit_index in (0..8).rev() {
+                let bit = AllocatedBit::alloc(
+                    cs.namespace(|| format!("input {} {}", byte_index, bit_index)),
+                    Some((byte >> bit_index) & 1 == 1),
+                )
+                .expect("Always valid");
+                bits.push(Boolean::from(bit));
+            }
+        }
+
+        let digest = super::keccak256(cs.namespace(|| "keccak256"), &bits).expect("Always valid");
+        assert!(cs.is_satisfied());
+
+        digest
+            .chunks(8)
+            .map(|byte| {
+                let value = byte.iter().fold(0u8, |value, bit| {
+                    (value << 1) | bit.get_value().expect("Always exists") as u8
+                });
+                format!("{:02x}", value)
+            })
+            .collect()
+    }
+
+    #[test]
+    fn ok_empty() {
+        assert_eq!(
+            digest(b""),
+            "c5d2460186f7233c927e7db2dcc703c0e500b653ca82273b7bfad8045d85a470"
+        );
+    }
+
+    #[test]
+    fn ok_abc() {
+        assert_eq!(
+            digest(b"abc"),
+            "4e03657aea45a94fc7d47ba826c8d667c0d1e6e33a64a036ec44f58fa12d6c45"
+        );
+    }
+
+    #[test]
+    fn ok_two_blocks() {
+        let message = vec![0x61u8; 200];
+        assert_eq!(digest(&message), reference(&message));
+    }
+
+    ///
+    /// The plain Keccak-256 implementation used as the reference.
+    ///
+    fn reference(message: &[u8]) -> String {
+        let mut bytes = message.to_vec();
+        let padding_length = super::RATE_BYTES - bytes.len() % super::RATE_BYTES;
+        for index in 0..padding_length {
+            let mut value = 0u8;
+            if index == 0 {
+                value |= 0x01;
+            }
+            if index == padding_length - 1 {
+                value |= 0x80;
+            }
+            bytes.push(value);
+        }
+
+        let mut state = [0u64; super::LANES];
+        for block in bytes.chunks(super::RATE_BYTES) {
+            for (index, lane) in block.chunks(8).enumerate() {
+                let mut value = [0u8; 8];
+                value.copy_from_slice(lane);
+                state[index] ^= u64::from_le_bytes(value);
+            }
+
+            for constant in super::ROUND_CONSTANTS.iter() {
+                let columns: Vec<u64> = (0..5)
+                    .map(|x| (0..5).fold(0, |column, y| column ^ state[x + 5 * y]))
+                    .collect();
+                for x in 0..5 {
+                    let difference = columns[(x + 4) % 5] ^ columns[(x + 1) % 5].rotate_left(1);
+                    for y in 0..5 {
+                        state[x + 5 * y] ^= difference;
+                    }
+                }
+
+                let mut permuted = [0u64; super::LANES];
+                for x in 0..5 {
+                    for y in 0..5 {
+                        permuted[y + 5 * ((2 * x + 3 * y) % 5)] =
+                            state[x + 5 * y].rotate_left(super::ROTATIONS[x + 5 * y] as u32);
+                    }
+                }
+
+                for y in 0..5 {
+                    for x in 0..5 {
+                        state[x + 5 * y] = permuted[x + 5 * y]
+                            ^ (!permuted[(x + 1) % 5 + 5 * y] & permuted[(x + 2) % 5 + 5 * y]);
+                    }
+                }
+
+                state[0] ^= constant;
+            }
+        }
+
+        state
+            .iter()
+            .flat_map(|lane| lane.to_le_bytes().to_vec())
+            .take(32)
+            .map(|byte| format!("{:02x}", byte))
+            .collect()
+    }
+}
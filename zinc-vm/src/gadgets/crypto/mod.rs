@@ -0,0 +1,7 @@
+//!
+//! The cryptographic gadgets.
+//!
+
+pub mod ecdsa;
+pub mod eddsa;
+pub mod keccak256;
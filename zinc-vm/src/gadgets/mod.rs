@@ -10,6 +10,7 @@ pub mod auto_const;
 pub mod bitwise;
 pub mod comparison;
 pub mod contract;
+pub mod crypto;
 pub mod logical;
 pub mod output;
 pub mod require;
@@ -11,7 +11,9 @@ use franklin_crypto::bellman::ConstraintSystem;
 use crate::core::execution_state::ExecutionState;
 use crate::error::Error;
 use crate::error::MalformedBytecode;
+use crate::gadgets::contract::merkle_tree::hasher::IHasher as IMerkleTreeHasher;
 use crate::gadgets::contract::merkle_tree::IMerkleTree;
+use crate::gadgets::contract::storage::StorageGadget;
 use crate::instructions::call_library::INativeCallable;
 use crate::IEngine;
 
@@ -33,12 +35,12 @@ impl Pad {
     }
 }
 
-impl<E: IEngine, S: IMerkleTree<E>> INativeCallable<E, S> for Pad {
+impl<E: IEngine, S: IMerkleTree<E>, H: IMerkleTreeHasher<E>> INativeCallable<E, S, H> for Pad {
     fn call<CS: ConstraintSystem<E>>(
         &self,
         _cs: CS,
         state: &mut ExecutionState<E>,
-        _storages: Option<HashMap<BigInt, &mut S>>,
+        _storages: Option<HashMap<BigInt, &mut StorageGadget<E, S, H>>>,
     ) -> Result<(), Error> {
         let filler = state.evaluation_stack.pop()?.try_into_value()?;
         let new_length = state
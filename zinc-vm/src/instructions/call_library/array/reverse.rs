@@ -10,7 +10,9 @@ use franklin_crypto::bellman::ConstraintSystem;
 
 use crate::core::execution_state::ExecutionState;
 use crate::error::Error;
+use crate::gadgets::contract::merkle_tree::hasher::IHasher as IMerkleTreeHasher;
 use crate::gadgets::contract::merkle_tree::IMerkleTree;
+use crate::gadgets::contract::storage::StorageGadget;
 use crate::instructions::call_library::INativeCallable;
 use crate::IEngine;
 
@@ -26,12 +28,12 @@ impl Reverse {
     }
 }
 
-impl<E: IEngine, S: IMerkleTree<E>> INativeCallable<E, S> for Reverse {
+impl<E: IEngine, S: IMerkleTree<E>, H: IMerkleTreeHasher<E>> INativeCallable<E, S, H> for Reverse {
     fn call<CS: ConstraintSystem<E>>(
         &self,
         _cs: CS,
         state: &mut ExecutionState<E>,
-        _storages: Option<HashMap<BigInt, &mut S>>,
+        _storages: Option<HashMap<BigInt, &mut StorageGadget<E, S, H>>>,
     ) -> Result<(), Error> {
         let mut array = Vec::with_capacity(self.array_length);
 
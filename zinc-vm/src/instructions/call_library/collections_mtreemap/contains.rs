@@ -9,12 +9,12 @@ use num::BigInt;
 
 use franklin_crypto::bellman::ConstraintSystem;
 
-use crate::core::contract::storage::leaf::LeafVariant;
 use crate::core::execution_state::cell::Cell;
 use crate::core::execution_state::ExecutionState;
 use crate::error::Error;
+use crate::gadgets::contract::merkle_tree::hasher::IHasher as IMerkleTreeHasher;
 use crate::gadgets::contract::merkle_tree::IMerkleTree;
-use crate::gadgets::scalar::Scalar;
+use crate::gadgets::contract::storage::StorageGadget;
 use crate::instructions::call_library::INativeCallable;
 use crate::IEngine;
 
@@ -28,17 +28,17 @@ impl Contains {
     }
 }
 
-impl<E: IEngine, S: IMerkleTree<E>> INativeCallable<E, S> for Contains {
+impl<E: IEngine, S: IMerkleTree<E>, H: IMerkleTreeHasher<E>> INativeCallable<E, S, H> for Contains {
     fn call<CS>(
         &self,
-        _cs: CS,
+        mut cs: CS,
         state: &mut ExecutionState<E>,
-        storages: Option<HashMap<BigInt, &mut S>>,
+        storages: Option<HashMap<BigInt, &mut StorageGadget<E, S, H>>>,
     ) -> Result<(), Error>
     where
         CS: ConstraintSystem<E>,
     {
-        let storages = storages.ok_or(Error::OnlyForContracts)?;
+        let mut storages = storages.ok_or(Error::OnlyForContracts)?;
 
         let mut input = Vec::with_capacity(self.input_size);
         for _ in 0..self.input_size {
@@ -59,20 +59,12 @@ impl<E: IEngine, S: IMerkleTree<E>> INativeCallable<E, S> for Contains {
             .to_bigint()
             .expect(zinc_const::panic::DATA_CONVERSION);
 
-        let data = match storages
-            .get(&eth_address)
+        let (found, _value) = storages
+            .get_mut(&eth_address)
             .expect(zinc_const::panic::VALUE_ALWAYS_EXISTS)
-            .load(index)?
-            .leaf_values
-        {
-            LeafVariant::Map { data, .. } => data,
-            LeafVariant::Array(_array) => return Err(Error::InvalidStorageValue),
-        };
-        let found = data.into_iter().any(|(map_key, _value)| map_key == input);
+            .map_get(cs.namespace(|| "map contains"), index, input.as_slice())?;
 
-        state
-            .evaluation_stack
-            .push(Cell::Value(Scalar::new_constant_bool(found)))?;
+        state.evaluation_stack.push(Cell::Value(found))?;
 
         Ok(())
     }
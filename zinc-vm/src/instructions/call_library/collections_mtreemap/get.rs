@@ -9,12 +9,12 @@ use num::BigInt;
 
 use franklin_crypto::bellman::ConstraintSystem;
 
-use crate::core::contract::storage::leaf::LeafVariant;
 use crate::core::execution_state::cell::Cell;
 use crate::core::execution_state::ExecutionState;
 use crate::error::Error;
+use crate::gadgets::contract::merkle_tree::hasher::IHasher as IMerkleTreeHasher;
 use crate::gadgets::contract::merkle_tree::IMerkleTree;
-use crate::gadgets::scalar::Scalar;
+use crate::gadgets::contract::storage::StorageGadget;
 use crate::instructions::call_library::INativeCallable;
 use crate::IEngine;
 
@@ -32,17 +32,17 @@ impl Get {
     }
 }
 
-impl<E: IEngine, S: IMerkleTree<E>> INativeCallable<E, S> for Get {
+impl<E: IEngine, S: IMerkleTree<E>, H: IMerkleTreeHasher<E>> INativeCallable<E, S, H> for Get {
     fn call<CS>(
         &self,
-        _cs: CS,
+        mut cs: CS,
         state: &mut ExecutionState<E>,
-        storages: Option<HashMap<BigInt, &mut S>>,
+        storages: Option<HashMap<BigInt, &mut StorageGadget<E, S, H>>>,
     ) -> Result<(), Error>
     where
         CS: ConstraintSystem<E>,
     {
-        let storages = storages.ok_or(Error::OnlyForContracts)?;
+        let mut storages = storages.ok_or(Error::OnlyForContracts)?;
 
         let mut input = Vec::with_capacity(self.input_size);
         for _ in 0..self.input_size {
@@ -63,30 +63,19 @@ impl<E: IEngine, S: IMerkleTree<E>> INativeCallable<E, S> for Get {
             .to_bigint()
             .expect(zinc_const::panic::DATA_CONVERSION);
 
-        let data = match storages
-            .get(&eth_address)
+        let (found, output) = storages
+            .get_mut(&eth_address)
             .expect(zinc_const::panic::VALUE_ALWAYS_EXISTS)
-            .load(index)?
-            .leaf_values
-        {
-            LeafVariant::Map { data, .. } => data,
-            LeafVariant::Array(_array) => return Err(Error::InvalidStorageValue),
-        };
-        let (output, found) = data
-            .into_iter()
-            .find(|(map_key, _value)| map_key == &input)
-            .map(|(_key, value)| (value, true))
-            .unwrap_or((
-                vec![Scalar::new_constant_bool(false); self.output_size - 1],
-                false,
-            ));
+            .map_get(cs.namespace(|| "map get"), index, input.as_slice())?;
+        if output.len() + 1 != self.output_size {
+            return Err(Error::InvalidStorageValue);
+        }
 
+        // the presence flag is the `std::option::Option<V>` variant tag
+        state.evaluation_stack.push(Cell::Value(found))?;
         for value in output.into_iter() {
             state.evaluation_stack.push(Cell::Value(value))?;
         }
-        state
-            .evaluation_stack
-            .push(Cell::Value(Scalar::new_constant_bool(found)))?;
 
         Ok(())
     }
@@ -7,14 +7,14 @@ use std::collections::HashMap;
 use num::bigint::ToBigInt;
 use num::BigInt;
 
-use franklin_crypto::bellman::pairing::ff::Field;
 use franklin_crypto::bellman::ConstraintSystem;
 
-use crate::core::contract::storage::leaf::LeafVariant;
 use crate::core::execution_state::cell::Cell;
 use crate::core::execution_state::ExecutionState;
 use crate::error::Error;
+use crate::gadgets::contract::merkle_tree::hasher::IHasher as IMerkleTreeHasher;
 use crate::gadgets::contract::merkle_tree::IMerkleTree;
+use crate::gadgets::contract::storage::StorageGadget;
 use crate::gadgets::scalar::Scalar;
 use crate::instructions::call_library::INativeCallable;
 use crate::IEngine;
@@ -33,12 +33,12 @@ impl Insert {
     }
 }
 
-impl<E: IEngine, S: IMerkleTree<E>> INativeCallable<E, S> for Insert {
+impl<E: IEngine, S: IMerkleTree<E>, H: IMerkleTreeHasher<E>> INativeCallable<E, S, H> for Insert {
     fn call<CS>(
         &self,
-        _cs: CS,
+        mut cs: CS,
         state: &mut ExecutionState<E>,
-        storages: Option<HashMap<BigInt, &mut S>>,
+        storages: Option<HashMap<BigInt, &mut StorageGadget<E, S, H>>>,
     ) -> Result<(), Error>
     where
         CS: ConstraintSystem<E>,
@@ -63,63 +63,28 @@ impl<E: IEngine, S: IMerkleTree<E>> INativeCallable<E, S> for Insert {
             .to_bigint()
             .expect(zinc_const::panic::DATA_CONVERSION);
 
-        let (mut data, key_size, value_size) = match storages
-            .get(&eth_address)
-            .expect(zinc_const::panic::VALUE_ALWAYS_EXISTS)
-            .load(index.clone())?
-            .leaf_values
-        {
-            LeafVariant::Map {
-                data,
-                key_size,
-                value_size,
-            } => (data, key_size, value_size),
-            LeafVariant::Array(_array) => return Err(Error::InvalidStorageValue),
-        };
-
-        let mut key: Vec<Scalar<E>> = input.drain(value_size..).collect();
+        let mut key: Vec<Scalar<E>> = input.drain(self.output_size - 1..).collect();
         key.reverse();
         let mut value = input;
         value.reverse();
 
-        let position = data
-            .iter()
-            .position(|(map_key, _value)| map_key.as_slice() == key.as_slice());
-        let output = match position {
-            Some(position) => data[position].1.to_owned(),
-            None => vec![Scalar::new_constant_bool(false); self.output_size - 1],
-        };
+        let condition = state
+            .conditions_stack
+            .last()
+            .cloned()
+            .unwrap_or_else(|| Scalar::new_constant_bool(true));
+        let (found, output) = storages
+            .get_mut(&eth_address)
+            .expect(zinc_const::panic::VALUE_ALWAYS_EXISTS)
+            .map_insert(cs.namespace(|| "map insert"), index, key, value, &condition)?;
+        if output.len() + 1 != self.output_size {
+            return Err(Error::InvalidStorageValue);
+        }
 
         for value in output.into_iter() {
             state.evaluation_stack.push(Cell::Value(value))?;
         }
-        state
-            .evaluation_stack
-            .push(Cell::Value(Scalar::new_constant_bool(position.is_some())))?;
-
-        match position {
-            Some(position) => data[position].1 = value,
-            None => data.push((key, value)),
-        }
-
-        if state
-            .conditions_stack
-            .iter()
-            .map(|value| value.get_value().expect(zinc_const::panic::DATA_CONVERSION))
-            .all(|value| !value.is_zero())
-        {
-            storages
-                .get_mut(&eth_address)
-                .expect(zinc_const::panic::VALUE_ALWAYS_EXISTS)
-                .store(
-                    index,
-                    LeafVariant::Map {
-                        data,
-                        key_size,
-                        value_size,
-                    },
-                )?;
-        }
+        state.evaluation_stack.push(Cell::Value(found))?;
 
         Ok(())
     }
@@ -9,11 +9,12 @@ use num::BigInt;
 
 use franklin_crypto::bellman::ConstraintSystem;
 
-use crate::core::contract::storage::leaf::LeafVariant;
 use crate::core::execution_state::cell::Cell;
 use crate::core::execution_state::ExecutionState;
 use crate::error::Error;
+use crate::gadgets::contract::merkle_tree::hasher::IHasher as IMerkleTreeHasher;
 use crate::gadgets::contract::merkle_tree::IMerkleTree;
+use crate::gadgets::contract::storage::StorageGadget;
 use crate::gadgets::scalar::Scalar;
 use crate::instructions::call_library::INativeCallable;
 use crate::IEngine;
@@ -32,12 +33,12 @@ impl Remove {
     }
 }
 
-impl<E: IEngine, S: IMerkleTree<E>> INativeCallable<E, S> for Remove {
+impl<E: IEngine, S: IMerkleTree<E>, H: IMerkleTreeHasher<E>> INativeCallable<E, S, H> for Remove {
     fn call<CS>(
         &self,
-        _cs: CS,
+        mut cs: CS,
         state: &mut ExecutionState<E>,
-        storages: Option<HashMap<BigInt, &mut S>>,
+        storages: Option<HashMap<BigInt, &mut StorageGadget<E, S, H>>>,
     ) -> Result<(), Error>
     where
         CS: ConstraintSystem<E>,
@@ -63,57 +64,23 @@ impl<E: IEngine, S: IMerkleTree<E>> INativeCallable<E, S> for Remove {
             .to_bigint()
             .expect(zinc_const::panic::DATA_CONVERSION);
 
-        let (mut data, key_size, value_size) = match storages
-            .get(&eth_address)
+        let condition = state
+            .conditions_stack
+            .last()
+            .cloned()
+            .unwrap_or_else(|| Scalar::new_constant_bool(true));
+        let (found, output) = storages
+            .get_mut(&eth_address)
             .expect(zinc_const::panic::VALUE_ALWAYS_EXISTS)
-            .load(index.clone())?
-            .leaf_values
-        {
-            LeafVariant::Map {
-                data,
-                key_size,
-                value_size,
-            } => (data, key_size, value_size),
-            LeafVariant::Array(_array) => return Err(Error::InvalidStorageValue),
-        };
-        let (output, position, found) = data
-            .iter()
-            .enumerate()
-            .find_map(|(index, (map_key, value))| {
-                let found = map_key.as_slice() == input.as_slice();
-                if found {
-                    Some((value.to_owned(), index, found))
-                } else {
-                    None
-                }
-            })
-            .unwrap_or((
-                vec![Scalar::new_constant_bool(false); self.output_size - 1],
-                0,
-                false,
-            ));
+            .map_remove(cs.namespace(|| "map remove"), index, input, &condition)?;
+        if output.len() + 1 != self.output_size {
+            return Err(Error::InvalidStorageValue);
+        }
 
         for value in output.into_iter() {
             state.evaluation_stack.push(Cell::Value(value))?;
         }
-        state
-            .evaluation_stack
-            .push(Cell::Value(Scalar::new_constant_bool(found)))?;
-
-        if found {
-            data.remove(position);
-            storages
-                .get_mut(&eth_address)
-                .expect(zinc_const::panic::VALUE_ALWAYS_EXISTS)
-                .store(
-                    index,
-                    LeafVariant::Map {
-                        data,
-                        key_size,
-                        value_size,
-                    },
-                )?;
-        }
+        state.evaluation_stack.push(Cell::Value(found))?;
 
         Ok(())
     }
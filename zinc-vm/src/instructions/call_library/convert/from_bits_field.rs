@@ -12,19 +12,23 @@ use franklin_crypto::circuit::num::AllocatedNum;
 
 use crate::core::execution_state::ExecutionState;
 use crate::error::Error;
+use crate::gadgets::contract::merkle_tree::hasher::IHasher as IMerkleTreeHasher;
 use crate::gadgets::contract::merkle_tree::IMerkleTree;
+use crate::gadgets::contract::storage::StorageGadget;
 use crate::gadgets::scalar::Scalar;
 use crate::instructions::call_library::INativeCallable;
 use crate::IEngine;
 
 pub struct FromBitsField;
 
-impl<E: IEngine, S: IMerkleTree<E>> INativeCallable<E, S> for FromBitsField {
+impl<E: IEngine, S: IMerkleTree<E>, H: IMerkleTreeHasher<E>> INativeCallable<E, S, H>
+    for FromBitsField
+{
     fn call<CS: ConstraintSystem<E>>(
         &self,
         mut cs: CS,
         state: &mut ExecutionState<E>,
-        _storages: Option<HashMap<BigInt, &mut S>>,
+        _storages: Option<HashMap<BigInt, &mut StorageGadget<E, S, H>>>,
     ) -> Result<(), Error> {
         let mut bits = Vec::with_capacity(E::Fr::NUM_BITS as usize);
         for i in 0..E::Fr::NUM_BITS {
@@ -15,7 +15,9 @@ use crate::core::execution_state::ExecutionState;
 use crate::error::Error;
 use crate::error::MalformedBytecode;
 use crate::gadgets;
+use crate::gadgets::contract::merkle_tree::hasher::IHasher as IMerkleTreeHasher;
 use crate::gadgets::contract::merkle_tree::IMerkleTree;
+use crate::gadgets::contract::storage::StorageGadget;
 use crate::gadgets::scalar::Scalar;
 use crate::instructions::call_library::INativeCallable;
 use crate::IEngine;
@@ -32,12 +34,14 @@ impl FromBitsSigned {
     }
 }
 
-impl<E: IEngine, S: IMerkleTree<E>> INativeCallable<E, S> for FromBitsSigned {
+impl<E: IEngine, S: IMerkleTree<E>, H: IMerkleTreeHasher<E>> INativeCallable<E, S, H>
+    for FromBitsSigned
+{
     fn call<CS: ConstraintSystem<E>>(
         &self,
         mut cs: CS,
         state: &mut ExecutionState<E>,
-        _storages: Option<HashMap<BigInt, &mut S>>,
+        _storages: Option<HashMap<BigInt, &mut StorageGadget<E, S, H>>>,
     ) -> Result<(), Error> {
         if self.bitlength >= E::Fr::CAPACITY as usize {
             return Err(MalformedBytecode::InvalidArguments(format!(
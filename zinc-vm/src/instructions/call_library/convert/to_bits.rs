@@ -12,19 +12,21 @@ use franklin_crypto::circuit::boolean::Boolean;
 use crate::core::execution_state::ExecutionState;
 use crate::error::Error;
 use crate::gadgets;
+use crate::gadgets::contract::merkle_tree::hasher::IHasher as IMerkleTreeHasher;
 use crate::gadgets::contract::merkle_tree::IMerkleTree;
+use crate::gadgets::contract::storage::StorageGadget;
 use crate::gadgets::scalar::Scalar;
 use crate::instructions::call_library::INativeCallable;
 use crate::IEngine;
 
 pub struct ToBits;
 
-impl<E: IEngine, S: IMerkleTree<E>> INativeCallable<E, S> for ToBits {
+impl<E: IEngine, S: IMerkleTree<E>, H: IMerkleTreeHasher<E>> INativeCallable<E, S, H> for ToBits {
     fn call<CS: ConstraintSystem<E>>(
         &self,
         mut cs: CS,
         state: &mut ExecutionState<E>,
-        _storages: Option<HashMap<BigInt, &mut S>>,
+        _storages: Option<HashMap<BigInt, &mut StorageGadget<E, S, H>>>,
     ) -> Result<(), Error> {
         let scalar = state.evaluation_stack.pop()?.try_into_value()?;
         let expr = scalar.to_expression::<CS>();
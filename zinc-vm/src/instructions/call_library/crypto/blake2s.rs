@@ -0,0 +1,85 @@
+//!
+//! The `std::crypto::blake2s` function call.
+//!
+
+use std::collections::HashMap;
+
+use num::BigInt;
+
+use franklin_crypto::bellman::ConstraintSystem;
+use franklin_crypto::circuit::blake2s;
+
+use crate::core::execution_state::ExecutionState;
+use crate::error::Error;
+use crate::error::MalformedBytecode;
+use crate::gadgets::contract::merkle_tree::hasher::IHasher as IMerkleTreeHasher;
+use crate::gadgets::contract::merkle_tree::IMerkleTree;
+use crate::gadgets::contract::storage::StorageGadget;
+use crate::gadgets::scalar::Scalar;
+use crate::instructions::call_library::INativeCallable;
+use crate::IEngine;
+
+///
+/// The empty personalization, which makes the digest equal to the standard BLAKE2s one.
+///
+const PERSONALIZATION: [u8; 8] = [0; 8];
+
+pub struct Blake2s {
+    message_length: usize,
+}
+
+impl Blake2s {
+    pub fn new(message_length: usize) -> Result<Self, Error> {
+        if message_length % 8 == 0 {
+            Ok(Self { message_length })
+        } else {
+            Err(MalformedBytecode::InvalidArguments(format!(
+                "message length for blake2s must be a multiple of 8, got {}",
+                message_length
+            ))
+            .into())
+        }
+    }
+}
+
+impl<E: IEngine, S: IMerkleTree<E>, H: IMerkleTreeHasher<E>> INativeCallable<E, S, H> for Blake2s {
+    fn call<CS: ConstraintSystem<E>>(
+        &self,
+        mut cs: CS,
+        state: &mut ExecutionState<E>,
+        _storages: Option<HashMap<BigInt, &mut StorageGadget<E, S, H>>>,
+    ) -> Result<(), Error> {
+        let mut bits = Vec::new();
+        for i in 0..self.message_length {
+            let bit = state
+                .evaluation_stack
+                .pop()?
+                .try_into_value()?
+                .to_boolean(cs.namespace(|| format!("bit {}", i)))?;
+
+            bits.push(bit);
+        }
+        bits.reverse();
+
+        // the gadget expects the little-endian bits in each byte, unlike `sha256`
+        for byte in bits.chunks_mut(zinc_const::bitlength::BYTE) {
+            byte.reverse();
+        }
+
+        let mut digest_bits =
+            blake2s::blake2s(cs.namespace(|| "blake2s"), &bits, &PERSONALIZATION)?;
+
+        assert_eq!(digest_bits.len(), zinc_const::bitlength::BLAKE2S_HASH);
+
+        for byte in digest_bits.chunks_mut(zinc_const::bitlength::BYTE) {
+            byte.reverse();
+        }
+
+        for bit in digest_bits {
+            let scalar = Scalar::from_boolean(cs.namespace(|| "from_boolean"), bit)?;
+            state.evaluation_stack.push(scalar.into())?;
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,38 @@
+//!
+//! The `std::crypto::ecc::Point::add` function call.
+//!
+
+use std::collections::HashMap;
+
+use num::BigInt;
+
+use franklin_crypto::bellman::ConstraintSystem;
+
+use crate::core::execution_state::ExecutionState;
+use crate::error::Error;
+use crate::gadgets::contract::merkle_tree::hasher::IHasher as IMerkleTreeHasher;
+use crate::gadgets::contract::merkle_tree::IMerkleTree;
+use crate::gadgets::contract::storage::StorageGadget;
+use crate::instructions::call_library::INativeCallable;
+use crate::IEngine;
+
+pub struct Add;
+
+impl<E: IEngine, S: IMerkleTree<E>, H: IMerkleTreeHasher<E>> INativeCallable<E, S, H> for Add {
+    fn call<CS>(
+        &self,
+        mut cs: CS,
+        state: &mut ExecutionState<E>,
+        _storages: Option<HashMap<BigInt, &mut StorageGadget<E, S, H>>>,
+    ) -> Result<(), Error>
+    where
+        CS: ConstraintSystem<E>,
+    {
+        let other = super::pop_point(cs.namespace(|| "other"), state)?;
+        let point = super::pop_point(cs.namespace(|| "self"), state)?;
+
+        let result = point.add(cs.namespace(|| "add"), &other, E::jubjub_params())?;
+
+        super::push_point(state, result)
+    }
+}
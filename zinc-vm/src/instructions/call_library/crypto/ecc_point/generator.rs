@@ -0,0 +1,47 @@
+//!
+//! The `std::crypto::ecc::Point::generator` function call.
+//!
+
+use std::collections::HashMap;
+
+use num::BigInt;
+
+use franklin_crypto::bellman::ConstraintSystem;
+use franklin_crypto::jubjub::FixedGenerators;
+use franklin_crypto::jubjub::JubjubParams;
+
+use crate::core::execution_state::ExecutionState;
+use crate::error::Error;
+use crate::gadgets::contract::merkle_tree::hasher::IHasher as IMerkleTreeHasher;
+use crate::gadgets::contract::merkle_tree::IMerkleTree;
+use crate::gadgets::contract::storage::StorageGadget;
+use crate::gadgets::scalar::Scalar;
+use crate::instructions::call_library::INativeCallable;
+use crate::IEngine;
+
+pub struct Generator;
+
+impl<E: IEngine, S: IMerkleTree<E>, H: IMerkleTreeHasher<E>> INativeCallable<E, S, H>
+    for Generator
+{
+    fn call<CS>(
+        &self,
+        _cs: CS,
+        state: &mut ExecutionState<E>,
+        _storages: Option<HashMap<BigInt, &mut StorageGadget<E, S, H>>>,
+    ) -> Result<(), Error>
+    where
+        CS: ConstraintSystem<E>,
+    {
+        let (x, y) = E::jubjub_params()
+            .generator(FixedGenerators::SpendingKeyGenerator)
+            .into_xy();
+
+        state
+            .evaluation_stack
+            .push(Scalar::new_constant_fr(x, zinc_types::ScalarType::Field).into())?;
+        state
+            .evaluation_stack
+            .push(Scalar::new_constant_fr(y, zinc_types::ScalarType::Field).into())
+    }
+}
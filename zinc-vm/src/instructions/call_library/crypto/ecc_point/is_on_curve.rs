@@ -0,0 +1,60 @@
+//!
+//! The `std::crypto::ecc::Point::is_on_curve` function call.
+//!
+
+use std::collections::HashMap;
+
+use num::BigInt;
+
+use franklin_crypto::alt_babyjubjub::JubjubEngine;
+use franklin_crypto::bellman::pairing::ff::Field;
+use franklin_crypto::bellman::ConstraintSystem;
+use franklin_crypto::jubjub::JubjubParams;
+
+use crate::core::execution_state::ExecutionState;
+use crate::error::Error;
+use crate::gadgets;
+use crate::gadgets::contract::merkle_tree::hasher::IHasher as IMerkleTreeHasher;
+use crate::gadgets::contract::merkle_tree::IMerkleTree;
+use crate::gadgets::contract::storage::StorageGadget;
+use crate::gadgets::scalar::Scalar;
+use crate::instructions::call_library::INativeCallable;
+use crate::IEngine;
+
+pub struct IsOnCurve;
+
+impl<E: IEngine, S: IMerkleTree<E>, H: IMerkleTreeHasher<E>> INativeCallable<E, S, H>
+    for IsOnCurve
+{
+    fn call<CS>(
+        &self,
+        mut cs: CS,
+        state: &mut ExecutionState<E>,
+        _storages: Option<HashMap<BigInt, &mut StorageGadget<E, S, H>>>,
+    ) -> Result<(), Error>
+    where
+        CS: ConstraintSystem<E>,
+    {
+        let (x, y) = super::pop_coordinates(state)?;
+
+        let d = Scalar::new_constant_fr(
+            *<E as JubjubEngine>::Params::edwards_d(E::jubjub_params()),
+            zinc_types::ScalarType::Field,
+        );
+        let one = Scalar::new_constant_fr(E::Fr::one(), zinc_types::ScalarType::Field);
+
+        // the curve equation is `-x^2 + y^2 = 1 + d * x^2 * y^2`
+        let x_squared = gadgets::arithmetic::mul::mul(cs.namespace(|| "x^2"), &x, &x)?;
+        let y_squared = gadgets::arithmetic::mul::mul(cs.namespace(|| "y^2"), &y, &y)?;
+        let left = gadgets::arithmetic::sub::sub(cs.namespace(|| "left"), &y_squared, &x_squared)?;
+        let product =
+            gadgets::arithmetic::mul::mul(cs.namespace(|| "x^2 * y^2"), &x_squared, &y_squared)?;
+        let product =
+            gadgets::arithmetic::mul::mul(cs.namespace(|| "d * x^2 * y^2"), &d, &product)?;
+        let right = gadgets::arithmetic::add::add(cs.namespace(|| "right"), &one, &product)?;
+
+        let is_on_curve = gadgets::comparison::equals(cs.namespace(|| "equals"), &left, &right)?;
+
+        state.evaluation_stack.push(is_on_curve.into())
+    }
+}
@@ -0,0 +1,73 @@
+//!
+//! The `std::crypto::ecc::Point` function calls.
+//!
+
+pub mod add;
+pub mod double;
+pub mod generator;
+pub mod is_on_curve;
+pub mod mul;
+pub mod negate;
+
+use franklin_crypto::bellman::ConstraintSystem;
+use franklin_crypto::circuit::ecc::EdwardsPoint;
+
+use crate::core::execution_state::ExecutionState;
+use crate::error::Error;
+use crate::gadgets::scalar::Scalar;
+use crate::IEngine;
+
+/// The number of stack values taken by a point, which are its `x` and `y` coordinates.
+pub const POINT_SIZE: usize = 2;
+
+///
+/// Pops the point coordinates from the evaluation stack.
+///
+pub fn pop_coordinates<E>(state: &mut ExecutionState<E>) -> Result<(Scalar<E>, Scalar<E>), Error>
+where
+    E: IEngine,
+{
+    let y = state.evaluation_stack.pop()?.try_into_value()?;
+    let x = state.evaluation_stack.pop()?.try_into_value()?;
+
+    Ok((x, y))
+}
+
+///
+/// Pops the point from the evaluation stack, enforcing it to be on the curve.
+///
+pub fn pop_point<E, CS>(mut cs: CS, state: &mut ExecutionState<E>) -> Result<EdwardsPoint<E>, Error>
+where
+    E: IEngine,
+    CS: ConstraintSystem<E>,
+{
+    let (x, y) = pop_coordinates(state)?;
+
+    let x = x
+        .to_expression::<CS>()
+        .into_number(cs.namespace(|| "to_number x"))?;
+    let y = y
+        .to_expression::<CS>()
+        .into_number(cs.namespace(|| "to_number y"))?;
+
+    let point = EdwardsPoint::interpret(cs.namespace(|| "interpret"), &x, &y, E::jubjub_params())?;
+
+    Ok(point)
+}
+
+///
+/// Pushes the point coordinates onto the evaluation stack.
+///
+pub fn push_point<E>(state: &mut ExecutionState<E>, point: EdwardsPoint<E>) -> Result<(), Error>
+where
+    E: IEngine,
+{
+    state
+        .evaluation_stack
+        .push(Scalar::from(point.get_x()).into())?;
+    state
+        .evaluation_stack
+        .push(Scalar::from(point.get_y()).into())?;
+
+    Ok(())
+}
@@ -0,0 +1,66 @@
+//!
+//! The `std::crypto::ecc::Point::mul` function call.
+//!
+
+use std::collections::HashMap;
+
+use num::BigInt;
+
+use franklin_crypto::bellman::ConstraintSystem;
+
+use crate::core::execution_state::ExecutionState;
+use crate::error::Error;
+use crate::error::MalformedBytecode;
+use crate::gadgets::contract::merkle_tree::hasher::IHasher as IMerkleTreeHasher;
+use crate::gadgets::contract::merkle_tree::IMerkleTree;
+use crate::gadgets::contract::storage::StorageGadget;
+use crate::instructions::call_library::INativeCallable;
+use crate::IEngine;
+
+pub struct Mul {
+    scalar_length: usize,
+}
+
+impl Mul {
+    pub fn new(args_count: usize) -> Result<Self, Error> {
+        if args_count <= super::POINT_SIZE {
+            return Err(MalformedBytecode::InvalidArguments(
+                "ecc::Point::mul needs at least one scalar bit".into(),
+            )
+            .into());
+        }
+
+        Ok(Self {
+            scalar_length: args_count - super::POINT_SIZE,
+        })
+    }
+}
+
+impl<E: IEngine, S: IMerkleTree<E>, H: IMerkleTreeHasher<E>> INativeCallable<E, S, H> for Mul {
+    fn call<CS>(
+        &self,
+        mut cs: CS,
+        state: &mut ExecutionState<E>,
+        _storages: Option<HashMap<BigInt, &mut StorageGadget<E, S, H>>>,
+    ) -> Result<(), Error>
+    where
+        CS: ConstraintSystem<E>,
+    {
+        // the scalar bits are big-endian, so they are popped in the little-endian order
+        let mut scalar = Vec::with_capacity(self.scalar_length);
+        for index in 0..self.scalar_length {
+            let bit = state
+                .evaluation_stack
+                .pop()?
+                .try_into_value()?
+                .to_boolean(cs.namespace(|| format!("scalar bit {}", index)))?;
+            scalar.push(bit);
+        }
+
+        let point = super::pop_point(cs.namespace(|| "self"), state)?;
+
+        let result = point.mul(cs.namespace(|| "mul"), &scalar, E::jubjub_params())?;
+
+        super::push_point(state, result)
+    }
+}
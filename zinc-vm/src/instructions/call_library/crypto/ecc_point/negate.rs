@@ -0,0 +1,40 @@
+//!
+//! The `std::crypto::ecc::Point::negate` function call.
+//!
+
+use std::collections::HashMap;
+
+use num::BigInt;
+
+use franklin_crypto::bellman::ConstraintSystem;
+
+use crate::core::execution_state::ExecutionState;
+use crate::error::Error;
+use crate::gadgets;
+use crate::gadgets::contract::merkle_tree::hasher::IHasher as IMerkleTreeHasher;
+use crate::gadgets::contract::merkle_tree::IMerkleTree;
+use crate::gadgets::contract::storage::StorageGadget;
+use crate::instructions::call_library::INativeCallable;
+use crate::IEngine;
+
+pub struct Negate;
+
+impl<E: IEngine, S: IMerkleTree<E>, H: IMerkleTreeHasher<E>> INativeCallable<E, S, H> for Negate {
+    fn call<CS>(
+        &self,
+        mut cs: CS,
+        state: &mut ExecutionState<E>,
+        _storages: Option<HashMap<BigInt, &mut StorageGadget<E, S, H>>>,
+    ) -> Result<(), Error>
+    where
+        CS: ConstraintSystem<E>,
+    {
+        // the twisted Edwards negation `(-x, y)` keeps the point on the curve
+        let (x, y) = super::pop_coordinates(state)?;
+
+        let x = gadgets::arithmetic::neg::neg(cs.namespace(|| "neg x"), &x)?;
+
+        state.evaluation_stack.push(x.into())?;
+        state.evaluation_stack.push(y.into())
+    }
+}
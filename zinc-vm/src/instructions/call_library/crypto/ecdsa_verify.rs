@@ -0,0 +1,91 @@
+//!
+//! The `std::crypto::ecdsa::verify` function call.
+//!
+
+use std::collections::HashMap;
+
+use num::BigInt;
+
+use franklin_crypto::bellman::ConstraintSystem;
+
+use crate::core::execution_state::ExecutionState;
+use crate::error::Error;
+use crate::error::MalformedBytecode;
+use crate::gadgets::contract::merkle_tree::hasher::IHasher as IMerkleTreeHasher;
+use crate::gadgets::contract::merkle_tree::IMerkleTree;
+use crate::gadgets::contract::storage::StorageGadget;
+use crate::gadgets::crypto::ecdsa;
+use crate::gadgets::scalar::Scalar;
+use crate::instructions::call_library::INativeCallable;
+use crate::IEngine;
+
+pub struct EcdsaVerify;
+
+impl EcdsaVerify {
+    /// The expected number of arguments: the hash bits, the signature bits and the address.
+    const ARGUMENTS_COUNT: usize =
+        zinc_const::bitlength::KECCAK256_HASH + zinc_const::bitlength::ETH_SIGNATURE + 1;
+
+    pub fn new(args_count: usize) -> Result<Self, Error> {
+        if args_count != Self::ARGUMENTS_COUNT {
+            return Err(MalformedBytecode::InvalidArguments(format!(
+                "ecdsa::verify needs exactly {} arguments, got {}",
+                Self::ARGUMENTS_COUNT,
+                args_count
+            ))
+            .into());
+        }
+
+        Ok(Self)
+    }
+}
+
+impl<E: IEngine, S: IMerkleTree<E>, H: IMerkleTreeHasher<E>> INativeCallable<E, S, H>
+    for EcdsaVerify
+{
+    fn call<CS>(
+        &self,
+        mut cs: CS,
+        state: &mut ExecutionState<E>,
+        _storages: Option<HashMap<BigInt, &mut StorageGadget<E, S, H>>>,
+    ) -> Result<(), Error>
+    where
+        CS: ConstraintSystem<E>,
+    {
+        let eth_address = state.evaluation_stack.pop()?.try_into_value()?;
+
+        let mut signature = Vec::with_capacity(zinc_const::bitlength::ETH_SIGNATURE);
+        for i in 0..zinc_const::bitlength::ETH_SIGNATURE {
+            let bit = state
+                .evaluation_stack
+                .pop()?
+                .try_into_value()?
+                .to_boolean(cs.namespace(|| format!("signature bit {}", i)))?;
+
+            signature.push(bit);
+        }
+        signature.reverse();
+
+        let mut msg_hash = Vec::with_capacity(zinc_const::bitlength::KECCAK256_HASH);
+        for i in 0..zinc_const::bitlength::KECCAK256_HASH {
+            let bit = state
+                .evaluation_stack
+                .pop()?
+                .try_into_value()?
+                .to_boolean(cs.namespace(|| format!("msg_hash bit {}", i)))?;
+
+            msg_hash.push(bit);
+        }
+        msg_hash.reverse();
+
+        let is_verified = ecdsa::verify(
+            cs.namespace(|| "ecdsa"),
+            &msg_hash,
+            &signature,
+            &eth_address,
+        )?;
+
+        let is_verified = Scalar::from_boolean(cs.namespace(|| "from_boolean"), is_verified)?;
+        state.evaluation_stack.push(is_verified.into())
+    }
+}
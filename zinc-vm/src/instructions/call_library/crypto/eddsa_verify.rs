@@ -0,0 +1,100 @@
+//!
+//! The `std::crypto::eddsa::Signature::verify` function call.
+//!
+
+use std::collections::HashMap;
+
+use num::BigInt;
+
+use franklin_crypto::bellman::ConstraintSystem;
+use franklin_crypto::circuit::baby_eddsa::EddsaSignature;
+use franklin_crypto::circuit::ecc::EdwardsPoint;
+
+use crate::core::execution_state::ExecutionState;
+use crate::error::Error;
+use crate::error::MalformedBytecode;
+use crate::gadgets;
+use crate::gadgets::contract::merkle_tree::hasher::IHasher as IMerkleTreeHasher;
+use crate::gadgets::contract::merkle_tree::IMerkleTree;
+use crate::gadgets::contract::storage::StorageGadget;
+use crate::gadgets::scalar::Scalar;
+use crate::instructions::call_library::INativeCallable;
+use crate::IEngine;
+
+/// The number of stack values taken by the signature structure.
+const SIGNATURE_SIZE: usize = 5;
+
+pub struct EddsaSignatureVerify {
+    message_length: usize,
+}
+
+impl EddsaSignatureVerify {
+    pub fn new(args_count: usize) -> Result<Self, Error> {
+        if args_count <= SIGNATURE_SIZE
+            || args_count - SIGNATURE_SIZE > zinc_const::limit::EDDSA_MESSAGE_BITS
+        {
+            return Err(MalformedBytecode::InvalidArguments(format!(
+                "eddsa::verify needs from {} to {} arguments",
+                SIGNATURE_SIZE + 1,
+                SIGNATURE_SIZE + zinc_const::limit::EDDSA_MESSAGE_BITS,
+            ))
+            .into());
+        }
+
+        Ok(Self {
+            message_length: args_count - SIGNATURE_SIZE,
+        })
+    }
+}
+
+impl<E: IEngine, S: IMerkleTree<E>, H: IMerkleTreeHasher<E>> INativeCallable<E, S, H>
+    for EddsaSignatureVerify
+{
+    fn call<CS>(
+        &self,
+        mut cs: CS,
+        state: &mut ExecutionState<E>,
+        _storages: Option<HashMap<BigInt, &mut StorageGadget<E, S, H>>>,
+    ) -> Result<(), Error>
+    where
+        CS: ConstraintSystem<E>,
+    {
+        let mut message = Vec::with_capacity(self.message_length);
+        for index in 0..self.message_length {
+            let bit = state
+                .evaluation_stack
+                .pop()?
+                .try_into_value()?
+                .to_boolean(cs.namespace(|| format!("message bit {}", index)))?;
+            message.push(bit);
+        }
+        message.reverse();
+
+        let mut pop_number = |name: &str| -> Result<_, Error> {
+            state
+                .evaluation_stack
+                .pop()?
+                .try_into_value()?
+                .to_expression::<CS>()
+                .into_number(cs.namespace(|| format!("to_number {}", name)))
+                .map_err(Error::from)
+        };
+        let pk_y = pop_number("pk_y")?;
+        let pk_x = pop_number("pk_x")?;
+        let s = pop_number("s")?;
+        let r_y = pop_number("r_y")?;
+        let r_x = pop_number("r_x")?;
+
+        let r = EdwardsPoint::interpret(cs.namespace(|| "r"), &r_x, &r_y, E::jubjub_params())?;
+        let pk = EdwardsPoint::interpret(cs.namespace(|| "pk"), &pk_x, &pk_y, E::jubjub_params())?;
+
+        let is_verified = gadgets::crypto::eddsa::verify(
+            cs.namespace(|| "eddsa"),
+            &message,
+            &EddsaSignature { r, s, pk },
+        )?;
+
+        let is_verified = Scalar::from_boolean(cs.namespace(|| "from_boolean"), is_verified)?;
+        state.evaluation_stack.push(is_verified.into())
+    }
+}
@@ -0,0 +1,72 @@
+//!
+//! The `std::crypto::keccak256` function call.
+//!
+
+use std::collections::HashMap;
+
+use num::BigInt;
+
+use franklin_crypto::bellman::ConstraintSystem;
+
+use crate::core::execution_state::ExecutionState;
+use crate::error::Error;
+use crate::error::MalformedBytecode;
+use crate::gadgets::contract::merkle_tree::hasher::IHasher as IMerkleTreeHasher;
+use crate::gadgets::contract::merkle_tree::IMerkleTree;
+use crate::gadgets::contract::storage::StorageGadget;
+use crate::gadgets::crypto::keccak256;
+use crate::gadgets::scalar::Scalar;
+use crate::instructions::call_library::INativeCallable;
+use crate::IEngine;
+
+pub struct Keccak256 {
+    message_length: usize,
+}
+
+impl Keccak256 {
+    pub fn new(message_length: usize) -> Result<Self, Error> {
+        if message_length % 8 == 0 {
+            Ok(Self { message_length })
+        } else {
+            Err(MalformedBytecode::InvalidArguments(format!(
+                "message length for keccak256 must be a multiple of 8, got {}",
+                message_length
+            ))
+            .into())
+        }
+    }
+}
+
+impl<E: IEngine, S: IMerkleTree<E>, H: IMerkleTreeHasher<E>> INativeCallable<E, S, H>
+    for Keccak256
+{
+    fn call<CS: ConstraintSystem<E>>(
+        &self,
+        mut cs: CS,
+        state: &mut ExecutionState<E>,
+        _storages: Option<HashMap<BigInt, &mut StorageGadget<E, S, H>>>,
+    ) -> Result<(), Error> {
+        let mut bits = Vec::new();
+        for i in 0..self.message_length {
+            let bit = state
+                .evaluation_stack
+                .pop()?
+                .try_into_value()?
+                .to_boolean(cs.namespace(|| format!("bit {}", i)))?;
+
+            bits.push(bit);
+        }
+        bits.reverse();
+
+        let digest_bits = keccak256::keccak256(cs.namespace(|| "keccak256"), &bits)?;
+
+        assert_eq!(digest_bits.len(), zinc_const::bitlength::KECCAK256_HASH);
+
+        for bit in digest_bits {
+            let scalar = Scalar::from_boolean(cs.namespace(|| "from_boolean"), bit)?;
+            state.evaluation_stack.push(scalar.into())?;
+        }
+
+        Ok(())
+    }
+}
@@ -2,6 +2,13 @@
 //! The `std::crypto` module calls.
 //!
 
+pub mod blake2s;
+pub mod ecc_point;
+pub mod ecdsa_verify;
+pub mod eddsa_verify;
+pub mod keccak256;
 pub mod pedersen;
+pub mod poseidon;
+pub mod rescue;
 pub mod schnorr_verify;
 pub mod sha256;
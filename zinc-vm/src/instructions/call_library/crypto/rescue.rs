@@ -0,0 +1,110 @@
+//!
+//! The `std::crypto::rescue` function call.
+//!
+
+use std::collections::HashMap;
+
+use num::BigInt;
+
+use franklin_crypto::bellman::ConstraintSystem;
+use franklin_crypto::circuit::rescue;
+
+use crate::core::execution_state::ExecutionState;
+use crate::error::Error;
+use crate::gadgets::contract::merkle_tree::hasher::IHasher as IMerkleTreeHasher;
+use crate::gadgets::contract::merkle_tree::IMerkleTree;
+use crate::gadgets::contract::storage::StorageGadget;
+use crate::gadgets::scalar::Scalar;
+use crate::instructions::call_library::INativeCallable;
+use crate::IEngine;
+
+pub struct Rescue {
+    message_length: usize,
+}
+
+impl Rescue {
+    pub fn new(message_length: usize) -> Result<Self, Error> {
+        Ok(Self { message_length })
+    }
+}
+
+impl<E: IEngine, S: IMerkleTree<E>, H: IMerkleTreeHasher<E>> INativeCallable<E, S, H> for Rescue {
+    fn call<CS: ConstraintSystem<E>>(
+        &self,
+        mut cs: CS,
+        state: &mut ExecutionState<E>,
+        _storages: Option<HashMap<BigInt, &mut StorageGadget<E, S, H>>>,
+    ) -> Result<(), Error> {
+        let mut preimage = Vec::with_capacity(self.message_length);
+        for i in 0..self.message_length {
+            let field = state
+                .evaluation_stack
+                .pop()?
+                .try_into_value()?
+                .to_expression::<CS>()
+                .into_number(cs.namespace(|| format!("field {}", i)))?;
+
+            preimage.push(field);
+        }
+        preimage.reverse();
+
+        let digest = rescue::rescue_hash(
+            cs.namespace(|| "rescue"),
+            preimage.as_slice(),
+            E::rescue_params(),
+        )?;
+
+        state
+            .evaluation_stack
+            .push(Scalar::from(digest[0].clone()).into())?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use num::BigInt;
+
+    use franklin_crypto::bellman::pairing::bn256::Bn256;
+
+    use crate::gadgets::scalar::fr_bigint;
+    use crate::tests::TestRunner;
+    use crate::tests::TestingError;
+    use crate::IEngine;
+
+    fn digest(preimage: &[usize]) -> BigInt {
+        let preimage: Vec<_> = preimage
+            .iter()
+            .map(|value| {
+                fr_bigint::bigint_to_fr::<Bn256>(&BigInt::from(*value))
+                    .expect(zinc_const::panic::TEST_DATA_VALID)
+            })
+            .collect();
+        let digest = franklin_crypto::rescue::rescue_hash::<Bn256>(
+            Bn256::rescue_params(),
+            preimage.as_slice(),
+        );
+
+        fr_bigint::fr_to_bigint::<Bn256>(&digest[0], false)
+    }
+
+    #[test]
+    fn test_rescue() -> Result<(), TestingError> {
+        TestRunner::new()
+            .push(zinc_types::Push::new_field(BigInt::from(42)))
+            .push(zinc_types::Push::new_field(BigInt::from(25)))
+            .push(zinc_types::CallLibrary::new(
+                zinc_types::LibraryFunctionIdentifier::CryptoRescue,
+                2,
+                1,
+            ))
+            .push(zinc_types::Push::new_field(BigInt::from(42)))
+            .push(zinc_types::CallLibrary::new(
+                zinc_types::LibraryFunctionIdentifier::CryptoRescue,
+                1,
+                1,
+            ))
+            .test(&[digest(&[42]), digest(&[42, 25])])
+    }
+}
@@ -6,6 +6,7 @@ use std::collections::HashMap;
 
 use num::BigInt;
 
+use franklin_crypto::alt_babyjubjub::JubjubEngine;
 use franklin_crypto::bellman::pairing::ff::PrimeField;
 use franklin_crypto::bellman::ConstraintSystem;
 use franklin_crypto::circuit::baby_eddsa::EddsaSignature;
@@ -16,7 +17,9 @@ use franklin_crypto::jubjub::JubjubParams;
 use crate::core::execution_state::ExecutionState;
 use crate::error::Error;
 use crate::error::MalformedBytecode;
+use crate::gadgets::contract::merkle_tree::hasher::IHasher as IMerkleTreeHasher;
 use crate::gadgets::contract::merkle_tree::IMerkleTree;
+use crate::gadgets::contract::storage::StorageGadget;
 use crate::gadgets::scalar::Scalar;
 use crate::instructions::call_library::INativeCallable;
 use crate::IEngine;
@@ -40,12 +43,14 @@ impl SchnorrSignatureVerify {
     }
 }
 
-impl<E: IEngine, S: IMerkleTree<E>> INativeCallable<E, S> for SchnorrSignatureVerify {
+impl<E: IEngine, S: IMerkleTree<E>, H: IMerkleTreeHasher<E>> INativeCallable<E, S, H>
+    for SchnorrSignatureVerify
+{
     fn call<CS>(
         &self,
         mut cs: CS,
         state: &mut ExecutionState<E>,
-        _storages: Option<HashMap<BigInt, &mut S>>,
+        _storages: Option<HashMap<BigInt, &mut StorageGadget<E, S, H>>>,
     ) -> Result<(), Error>
     where
         CS: ConstraintSystem<E>,
@@ -115,7 +120,7 @@ pub fn verify_signature<E, CS>(
     mut cs: CS,
     message: &[Scalar<E>],
     signature: &EddsaSignature<E>,
-    params: &E::Params,
+    params: &<E as JubjubEngine>::Params,
 ) -> Result<Scalar<E>, Error>
 where
     E: IEngine,
@@ -11,18 +11,20 @@ use franklin_crypto::bellman::ConstraintSystem;
 use crate::core::execution_state::ExecutionState;
 use crate::error::Error;
 use crate::gadgets;
+use crate::gadgets::contract::merkle_tree::hasher::IHasher as IMerkleTreeHasher;
 use crate::gadgets::contract::merkle_tree::IMerkleTree;
+use crate::gadgets::contract::storage::StorageGadget;
 use crate::instructions::call_library::INativeCallable;
 use crate::IEngine;
 
 pub struct Inverse;
 
-impl<E: IEngine, S: IMerkleTree<E>> INativeCallable<E, S> for Inverse {
+impl<E: IEngine, S: IMerkleTree<E>, H: IMerkleTreeHasher<E>> INativeCallable<E, S, H> for Inverse {
     fn call<CS>(
         &self,
         cs: CS,
         state: &mut ExecutionState<E>,
-        _storages: Option<HashMap<BigInt, &mut S>>,
+        _storages: Option<HashMap<BigInt, &mut StorageGadget<E, S, H>>>,
     ) -> Result<(), Error>
     where
         CS: ConstraintSystem<E>,
@@ -21,7 +21,9 @@ use zinc_types::LibraryFunctionIdentifier;
 use crate::core::execution_state::ExecutionState;
 use crate::core::virtual_machine::IVirtualMachine;
 use crate::error::Error;
+use crate::gadgets::contract::merkle_tree::hasher::IHasher as IMerkleTreeHasher;
 use crate::gadgets::contract::merkle_tree::IMerkleTree;
+use crate::gadgets::contract::storage::StorageGadget;
 use crate::instructions::IExecutable;
 use crate::IEngine;
 
@@ -37,17 +39,29 @@ use self::convert::from_bits_field::FromBitsField as ConvertFromBitsField;
 use self::convert::from_bits_signed::FromBitsSigned as ConvertFromBitsSigned;
 use self::convert::from_bits_unsigned::FromBitsUnsigned as ConvertFromBitsUnsigned;
 use self::convert::to_bits::ToBits as ConvertToBits;
+use self::crypto::blake2s::Blake2s as CryptoBlake2s;
+use self::crypto::ecc_point::add::Add as CryptoEccPointAdd;
+use self::crypto::ecc_point::double::Double as CryptoEccPointDouble;
+use self::crypto::ecc_point::generator::Generator as CryptoEccPointGenerator;
+use self::crypto::ecc_point::is_on_curve::IsOnCurve as CryptoEccPointIsOnCurve;
+use self::crypto::ecc_point::mul::Mul as CryptoEccPointMul;
+use self::crypto::ecc_point::negate::Negate as CryptoEccPointNegate;
+use self::crypto::ecdsa_verify::EcdsaVerify as CryptoEcdsaVerify;
+use self::crypto::eddsa_verify::EddsaSignatureVerify as CryptoEddsaSignatureVerify;
+use self::crypto::keccak256::Keccak256 as CryptoKeccak256;
 use self::crypto::pedersen::Pedersen as CryptoPedersen;
+use self::crypto::poseidon::Poseidon as CryptoPoseidon;
+use self::crypto::rescue::Rescue as CryptoRescue;
 use self::crypto::schnorr_verify::SchnorrSignatureVerify as CryptoSchnorrSignatureVerify;
 use self::crypto::sha256::Sha256 as CryptoSha256;
 use self::ff::invert::Inverse as FfInverse;
 
-pub trait INativeCallable<E: IEngine, S: IMerkleTree<E>> {
+pub trait INativeCallable<E: IEngine, S: IMerkleTree<E>, H: IMerkleTreeHasher<E>> {
     fn call<CS: ConstraintSystem<E>>(
         &self,
         cs: CS,
         state: &mut ExecutionState<E>,
-        storages: Option<HashMap<BigInt, &mut S>>,
+        storages: Option<HashMap<BigInt, &mut StorageGadget<E, S, H>>>,
     ) -> Result<(), Error>;
 }
 
@@ -63,6 +77,36 @@ impl<VM: IVirtualMachine> IExecutable<VM> for CallLibrary {
             LibraryFunctionIdentifier::CryptoSchnorrSignatureVerify => {
                 vm.call_native(CryptoSchnorrSignatureVerify::new(self.input_size)?)
             }
+            LibraryFunctionIdentifier::CryptoBlake2s => {
+                vm.call_native(CryptoBlake2s::new(self.input_size)?)
+            }
+            LibraryFunctionIdentifier::CryptoPoseidon => {
+                vm.call_native(CryptoPoseidon::new(self.input_size)?)
+            }
+            LibraryFunctionIdentifier::CryptoRescue => {
+                vm.call_native(CryptoRescue::new(self.input_size)?)
+            }
+            LibraryFunctionIdentifier::CryptoKeccak256 => {
+                vm.call_native(CryptoKeccak256::new(self.input_size)?)
+            }
+            LibraryFunctionIdentifier::CryptoEcdsaVerify => {
+                vm.call_native(CryptoEcdsaVerify::new(self.input_size)?)
+            }
+            LibraryFunctionIdentifier::CryptoEddsaSignatureVerify => {
+                vm.call_native(CryptoEddsaSignatureVerify::new(self.input_size)?)
+            }
+            LibraryFunctionIdentifier::CryptoEccPointAdd => vm.call_native(CryptoEccPointAdd),
+            LibraryFunctionIdentifier::CryptoEccPointDouble => vm.call_native(CryptoEccPointDouble),
+            LibraryFunctionIdentifier::CryptoEccPointMul => {
+                vm.call_native(CryptoEccPointMul::new(self.input_size)?)
+            }
+            LibraryFunctionIdentifier::CryptoEccPointGenerator => {
+                vm.call_native(CryptoEccPointGenerator)
+            }
+            LibraryFunctionIdentifier::CryptoEccPointIsOnCurve => {
+                vm.call_native(CryptoEccPointIsOnCurve)
+            }
+            LibraryFunctionIdentifier::CryptoEccPointNegate => vm.call_native(CryptoEccPointNegate),
 
             LibraryFunctionIdentifier::ConvertToBits => vm.call_native(ConvertToBits),
             LibraryFunctionIdentifier::ConvertFromBitsUnsigned => {
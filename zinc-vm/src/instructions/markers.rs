@@ -6,6 +6,7 @@ use zinc_types::ColumnMarker;
 use zinc_types::FileMarker;
 use zinc_types::FunctionMarker;
 use zinc_types::LineMarker;
+use zinc_types::VariableMarker;
 
 use crate::core::location::Location;
 use crate::core::virtual_machine::IVirtualMachine;
@@ -51,3 +52,9 @@ impl<VM: IVirtualMachine> IExecutable<VM> for ColumnMarker {
         Ok(())
     }
 }
+
+impl<VM: IVirtualMachine> IExecutable<VM> for VariableMarker {
+    fn execute(self, _vm: &mut VM) -> Result<(), Error> {
+        Ok(())
+    }
+}
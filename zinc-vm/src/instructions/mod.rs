@@ -85,6 +85,7 @@ impl<VM: IVirtualMachine> IExecutable<VM> for Instruction {
             Self::FunctionMarker(inner) => inner.execute(vm),
             Self::LineMarker(inner) => inner.execute(vm),
             Self::ColumnMarker(inner) => inner.execute(vm),
+            Self::VariableMarker(inner) => inner.execute(vm),
         }
     }
 }
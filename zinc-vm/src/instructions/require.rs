@@ -2,6 +2,8 @@
 //! The `Require` instruction.
 //!
 
+use num::bigint::ToBigInt;
+
 use franklin_crypto::bellman::ConstraintSystem;
 
 use zinc_types::Require;
@@ -13,6 +15,31 @@ use crate::instructions::IExecutable;
 
 impl<VM: IVirtualMachine> IExecutable<VM> for Require {
     fn execute(self, vm: &mut VM) -> Result<(), Error> {
+        let mut values = Some(Vec::with_capacity(self.argument_types.len()));
+        for argument_type in self.argument_types.into_iter().rev() {
+            let argument_type = match argument_type {
+                zinc_types::Type::Contract(_) => zinc_types::Type::eth_address(),
+                r#type => r#type,
+            };
+
+            let mut flat = Vec::with_capacity(argument_type.size());
+            for _ in 0..argument_type.size() {
+                flat.push(vm.pop()?.try_into_value()?.to_bigint());
+            }
+            flat.reverse();
+
+            values = match (values, flat.into_iter().collect::<Option<Vec<_>>>()) {
+                (Some(mut values), Some(flat)) => {
+                    values.push(zinc_types::Value::from_flat_values(
+                        argument_type,
+                        flat.as_slice(),
+                    ));
+                    Some(values)
+                }
+                _ => None,
+            };
+        }
+
         let value = vm.pop()?.try_into_value()?;
         let condition = vm.condition_top()?;
 
@@ -21,11 +48,24 @@ impl<VM: IVirtualMachine> IExecutable<VM> for Require {
         let not_c = gadgets::logical::not::not(cs.namespace(|| "not"), &condition)?;
         let condition = gadgets::logical::or::or(cs.namespace(|| "or"), &value, &not_c)?;
 
-        let message = match &self.message {
-            Some(message) => Some(message.as_str()),
-            None => None,
+        let message = match (self.message, values) {
+            (Some(message), Some(values)) => {
+                // the message is split once, so the placeholders in the values are not replaced
+                let mut values = values
+                    .into_iter()
+                    .rev()
+                    .map(|value| serde_json::to_string(&value.into_json()).unwrap_or_default());
+                let mut pieces = message.split("{}");
+                let mut result = pieces.next().unwrap_or_default().to_owned();
+                for piece in pieces {
+                    result.push_str(values.next().as_deref().unwrap_or("{}"));
+                    result.push_str(piece);
+                }
+                Some(result)
+            }
+            (message, _) => message,
         };
-        gadgets::require::require(cs, condition, message)
+        gadgets::require::require(cs, condition, message.as_deref())
     }
 }
 
@@ -46,7 +86,7 @@ mod tests {
                 BigInt::one(),
                 zinc_types::ScalarType::Boolean,
             ))
-            .push(zinc_types::Require::new(None))
+            .push(zinc_types::Require::new(None, vec![]))
             .test::<i32>(&[])
     }
 
@@ -57,7 +97,7 @@ mod tests {
                 BigInt::zero(),
                 zinc_types::ScalarType::Boolean,
             ))
-            .push(zinc_types::Require::new(None))
+            .push(zinc_types::Require::new(None, vec![]))
             .test::<i32>(&[]);
 
         match res {
@@ -66,6 +106,70 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_require_fail_formatted() {
+        let res = TestRunner::new()
+            .push(zinc_types::Push::new(
+                BigInt::zero(),
+                zinc_types::ScalarType::Boolean,
+            ))
+            .push(zinc_types::Push::new(
+                BigInt::from(42),
+                zinc_types::ScalarType::Integer(zinc_types::IntegerType::U8),
+            ))
+            .push(zinc_types::Push::new(
+                BigInt::one(),
+                zinc_types::ScalarType::Boolean,
+            ))
+            .push(zinc_types::Require::new(
+                Some("value {} with flag {}".to_owned()),
+                vec![
+                    zinc_types::Type::Scalar(zinc_types::ScalarType::Integer(
+                        zinc_types::IntegerType::U8,
+                    )),
+                    zinc_types::Type::Scalar(zinc_types::ScalarType::Boolean),
+                ],
+            ))
+            .test::<i32>(&[]);
+
+        match res {
+            Err(TestingError::Error(Error::RequireError(message))) => {
+                assert_eq!(message, "value \"42\" with flag true")
+            }
+            _ => panic!("Expected require error"),
+        }
+    }
+
+    #[test]
+    fn test_require_fail_formatted_placeholder_value() {
+        let res = TestRunner::new()
+            .push(zinc_types::Push::new(
+                BigInt::zero(),
+                zinc_types::ScalarType::Boolean,
+            ))
+            .push(zinc_types::Push::new(
+                BigInt::from(42),
+                zinc_types::ScalarType::Integer(zinc_types::IntegerType::U8),
+            ))
+            .push(zinc_types::Require::new(
+                Some("empty {} and value {}".to_owned()),
+                vec![
+                    zinc_types::Type::Structure(vec![]),
+                    zinc_types::Type::Scalar(zinc_types::ScalarType::Integer(
+                        zinc_types::IntegerType::U8,
+                    )),
+                ],
+            ))
+            .test::<i32>(&[]);
+
+        match res {
+            Err(TestingError::Error(Error::RequireError(message))) => {
+                assert_eq!(message, "empty {} and value \"42\"")
+            }
+            _ => panic!("Expected require error"),
+        }
+    }
+
     #[test]
     fn test_require_in_condition() -> Result<(), TestingError> {
         TestRunner::new()
@@ -78,7 +182,7 @@ mod tests {
                 BigInt::zero(),
                 zinc_types::ScalarType::Boolean,
             ))
-            .push(zinc_types::Require::new(None))
+            .push(zinc_types::Require::new(None, vec![]))
             .push(zinc_types::EndIf)
             .test::<i32>(&[])
     }
@@ -23,8 +23,17 @@ pub use self::core::contract::input::Input as ContractInput;
 pub use self::core::contract::output::initializer::Initializer as ContractOutputInitializer;
 pub use self::core::contract::output::Output as ContractOutput;
 pub use self::core::contract::storage::keeper::IKeeper as IContractStorageKeeper;
+pub use self::core::debug_info::DebugInfo;
+pub use self::core::debugger::breakpoint::Breakpoint as DebuggerBreakpoint;
+pub use self::core::debugger::repl::Repl as DebuggerRepl;
+pub use self::core::debugger::session::Session as DebuggerSession;
+pub use self::core::debugger::Debugger;
+pub use self::core::debugger::IFrontend as IDebuggerFrontend;
+pub use self::core::debugger::Reason as DebuggerReason;
+pub use self::core::debugger::Resume as DebuggerResume;
 pub use self::core::facade::Facade;
 pub use self::core::library::facade::Facade as LibraryFacade;
+pub use self::core::profiler::Profile;
 pub use self::error::Error;
 pub use self::error::VerificationError;
 
@@ -34,17 +43,36 @@ use lazy_static::lazy_static;
 
 use franklin_crypto::alt_babyjubjub::AltJubjubBn256;
 use franklin_crypto::alt_babyjubjub::JubjubEngine;
+use franklin_crypto::poseidon::bn256::Bn256PoseidonParams;
+use franklin_crypto::poseidon::PoseidonEngine;
+use franklin_crypto::rescue::bn256::Bn256RescueParams;
+use franklin_crypto::rescue::RescueEngine;
 
-pub trait IEngine: fmt::Debug + JubjubEngine {
-    fn jubjub_params<'a>() -> &'a Self::Params;
+pub trait IEngine: fmt::Debug + JubjubEngine + RescueEngine + PoseidonEngine {
+    fn jubjub_params<'a>() -> &'a <Self as JubjubEngine>::Params;
+
+    fn rescue_params<'a>() -> &'a <Self as RescueEngine>::Params;
+
+    fn poseidon_params<'a>() -> &'a <Self as PoseidonEngine>::Params;
 }
 
 lazy_static! {
     static ref JUBJUB_BN256_PARAMS: AltJubjubBn256 = AltJubjubBn256::new();
+    static ref RESCUE_BN256_PARAMS: Bn256RescueParams = Bn256RescueParams::new_checked_2_into_1();
+    static ref POSEIDON_BN256_PARAMS: Bn256PoseidonParams =
+        Bn256PoseidonParams::new_checked_2_into_1();
 }
 
 impl IEngine for Bn256 {
-    fn jubjub_params<'a>() -> &'a Self::Params {
+    fn jubjub_params<'a>() -> &'a <Self as JubjubEngine>::Params {
         &JUBJUB_BN256_PARAMS
     }
+
+    fn rescue_params<'a>() -> &'a <Self as RescueEngine>::Params {
+        &RESCUE_BN256_PARAMS
+    }
+
+    fn poseidon_params<'a>() -> &'a <Self as PoseidonEngine>::Params {
+        &POSEIDON_BN256_PARAMS
+    }
 }
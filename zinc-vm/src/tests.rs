@@ -29,7 +29,7 @@ fn new_test_constrained_vm() -> TestVirtualMachine {
 fn assert_stack_eq<VM, BI>(vm: &mut VM, expected_stack: &[BI])
 where
     VM: IVirtualMachine,
-    BI: Into<BigInt> + Copy,
+    BI: Into<BigInt> + Clone,
 {
     for (i, expected) in expected_stack.iter().enumerate() {
         let value = vm
@@ -75,14 +75,14 @@ impl TestRunner {
         self
     }
 
-    pub fn test<T: Into<BigInt> + Copy>(self, expected_stack: &[T]) -> Result<(), TestingError> {
+    pub fn test<T: Into<BigInt> + Clone>(self, expected_stack: &[T]) -> Result<(), TestingError> {
         self.test_constrained(expected_stack).map_err(|error| {
             println!("{}: {}", "error".bold().red(), error);
             error
         })
     }
 
-    fn test_constrained<T: Into<BigInt> + Copy>(
+    fn test_constrained<T: Into<BigInt> + Clone>(
         self,
         expected_stack: &[T],
     ) -> Result<(), TestingError> {
@@ -97,7 +97,7 @@ impl TestRunner {
             self.instructions,
         );
 
-        vm.run(circuit, Some(&[]), |_| {}, |_| Ok(()))
+        vm.run(circuit, Some(&[]), |_, _, _| Ok(()), |_| Ok(()))
             .map_err(TestingError::Error)?;
 
         let cs = vm.constraint_system();
@@ -0,0 +1,45 @@
+//!
+//! The Zinc virtual machine `asm` subcommand.
+//!
+
+use std::fs;
+use std::path::PathBuf;
+
+use structopt::StructOpt;
+
+use crate::arguments::command::IExecutable;
+use crate::error::Error;
+use crate::error::IErrorPath;
+
+///
+/// The Zinc virtual machine `asm` subcommand.
+///
+#[derive(Debug, StructOpt)]
+#[structopt(
+    name = "asm",
+    about = "Converts the textual assembly form printed by `disasm` into the binary bytecode"
+)]
+pub struct Command {
+    /// The path to the assembly text file.
+    #[structopt(long = "assembly")]
+    pub assembly_path: PathBuf,
+
+    /// The path to the binary bytecode file to write.
+    #[structopt(long = "binary")]
+    pub binary_path: PathBuf,
+}
+
+impl IExecutable for Command {
+    type Error = Error;
+
+    fn execute(self) -> Result<i32, Self::Error> {
+        let assembly = fs::read_to_string(&self.assembly_path)
+            .error_with_path(|| self.assembly_path.to_string_lossy())?;
+        let application = zinc_types::assemble(assembly.as_str())?;
+
+        fs::write(&self.binary_path, application.into_vec())
+            .error_with_path(|| self.binary_path.to_string_lossy())?;
+
+        Ok(zinc_const::exit_code::SUCCESS as i32)
+    }
+}
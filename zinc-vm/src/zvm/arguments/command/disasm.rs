@@ -0,0 +1,64 @@
+//!
+//! The Zinc virtual machine `disasm` subcommand.
+//!
+
+use std::collections::HashMap;
+use std::fs;
+use std::path::PathBuf;
+
+use structopt::StructOpt;
+
+use crate::arguments::command::IExecutable;
+use crate::error::Error;
+use crate::error::IErrorPath;
+
+///
+/// The Zinc virtual machine `disasm` subcommand.
+///
+#[derive(Debug, StructOpt)]
+#[structopt(
+    name = "disasm",
+    about = "Prints the bytecode in the textual assembly form, which is accepted by `asm`"
+)]
+pub struct Command {
+    /// The path to the binary bytecode file.
+    #[structopt(long = "binary")]
+    pub binary_path: PathBuf,
+
+    /// The project root, which the source code file markers are relative to.
+    #[structopt(long = "source-root", default_value = ".")]
+    pub source_root: PathBuf,
+}
+
+impl IExecutable for Command {
+    type Error = Error;
+
+    fn execute(self) -> Result<i32, Self::Error> {
+        // Read the bytecode
+        let bytecode =
+            fs::read(&self.binary_path).error_with_path(|| self.binary_path.to_string_lossy())?;
+        let application = zinc_types::Application::try_from_slice(bytecode.as_slice())
+            .map_err(Error::ApplicationDecoding)?;
+
+        // Read the source code files, which are optional and only used for comments
+        let mut sources = HashMap::new();
+        for instruction in application.instructions().iter() {
+            if let zinc_types::Instruction::FileMarker(marker) = instruction {
+                if sources.contains_key(marker.file.as_str()) {
+                    continue;
+                }
+                if let Ok(source) = fs::read_to_string(self.source_root.join(marker.file.as_str()))
+                {
+                    sources.insert(
+                        marker.file.to_owned(),
+                        source.lines().map(str::to_owned).collect::<Vec<String>>(),
+                    );
+                }
+            }
+        }
+
+        print!("{}", zinc_types::disassemble(&application, &sources));
+
+        Ok(zinc_const::exit_code::SUCCESS as i32)
+    }
+}
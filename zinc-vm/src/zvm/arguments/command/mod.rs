@@ -2,15 +2,29 @@
 //! The Zinc virtual machine subcommand.
 //!
 
+pub mod asm;
+pub mod debug;
+pub mod disasm;
+pub mod profile;
+pub mod prove;
 pub mod run;
+pub mod setup;
 pub mod test;
+pub mod verify;
 
 use structopt::StructOpt;
 
 use crate::error::Error;
 
+use self::asm::Command as AsmCommand;
+use self::debug::Command as DebugCommand;
+use self::disasm::Command as DisasmCommand;
+use self::profile::Command as ProfileCommand;
+use self::prove::Command as ProveCommand;
 use self::run::Command as RunCommand;
+use self::setup::Command as SetupCommand;
 use self::test::Command as TestCommand;
+use self::verify::Command as VerifyCommand;
 
 ///
 /// The generic trait used for commands.
@@ -35,6 +49,20 @@ pub enum Command {
     Run(RunCommand),
     /// Executes a unit test.
     Test(TestCommand),
+    /// Attributes the constraints to the source code lines.
+    Profile(ProfileCommand),
+    /// Executes the bytecode step by step in the source-level debugger.
+    Debug(DebugCommand),
+    /// Prints the bytecode in the textual assembly form.
+    Disasm(DisasmCommand),
+    /// Converts the textual assembly form into the bytecode.
+    Asm(AsmCommand),
+    /// Generates a pair of proving and verifying keys.
+    Setup(SetupCommand),
+    /// Generates the zero-knowledge proof for given input data.
+    Prove(ProveCommand),
+    /// Verifies the zero-knowledge proof.
+    Verify(VerifyCommand),
 }
 
 impl IExecutable for Command {
@@ -44,6 +72,13 @@ impl IExecutable for Command {
         match self {
             Command::Run(inner) => inner.execute(),
             Command::Test(inner) => inner.execute(),
+            Command::Profile(inner) => inner.execute(),
+            Command::Debug(inner) => inner.execute(),
+            Command::Disasm(inner) => inner.execute(),
+            Command::Asm(inner) => inner.execute(),
+            Command::Setup(inner) => inner.execute(),
+            Command::Prove(inner) => inner.execute(),
+            Command::Verify(inner) => inner.execute(),
         }
     }
 }
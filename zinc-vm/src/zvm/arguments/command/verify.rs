@@ -0,0 +1,118 @@
+//!
+//! The Zinc virtual machine `verify` subcommand.
+//!
+
+use std::fs;
+use std::io;
+use std::io::Read;
+use std::path::PathBuf;
+
+use colored::Colorize;
+use structopt::StructOpt;
+
+use franklin_crypto::bellman::groth16::Proof;
+use franklin_crypto::bellman::groth16::VerifyingKey;
+use franklin_crypto::bellman::pairing::bn256::Bn256;
+
+use zinc_vm::Facade;
+
+use crate::arguments::command::IExecutable;
+use crate::error::Error;
+use crate::error::IErrorPath;
+
+///
+/// The Zinc virtual machine `verify` subcommand.
+///
+#[derive(Debug, StructOpt)]
+#[structopt(
+    name = "verify",
+    about = "Verifies the zero-knowledge proof read from the standard input"
+)]
+pub struct Command {
+    /// The path to the binary bytecode file.
+    #[structopt(long = "binary")]
+    pub binary_path: PathBuf,
+
+    /// The path to the verifying key file.
+    #[structopt(long = "verifying-key")]
+    pub verifying_key_path: PathBuf,
+
+    /// The path to the output JSON file, which contains the public data.
+    #[structopt(long = "output")]
+    pub output_path: PathBuf,
+
+    /// The method name to verify, if the application is a contract.
+    #[structopt(long = "method")]
+    pub method: Option<String>,
+}
+
+impl IExecutable for Command {
+    type Error = Error;
+
+    fn execute(self) -> Result<i32, Self::Error> {
+        // Read the bytecode
+        let bytecode =
+            fs::read(&self.binary_path).error_with_path(|| self.binary_path.to_string_lossy())?;
+        let application = zinc_types::Application::try_from_slice(bytecode.as_slice())
+            .map_err(Error::ApplicationDecoding)?;
+
+        // Read the verifying key
+        let verifying_key_path = self.verifying_key_path;
+        let verifying_key_file = fs::File::open(&verifying_key_path)
+            .error_with_path(|| verifying_key_path.to_string_lossy())?;
+        let verifying_key = VerifyingKey::<Bn256>::read(verifying_key_file)
+            .error_with_path(|| verifying_key_path.to_string_lossy())?;
+
+        // Read the proof
+        let mut proof_hex = String::new();
+        io::stdin()
+            .read_to_string(&mut proof_hex)
+            .error_with_path(|| "<stdin>")?;
+        let proof_bytes =
+            hex::decode(proof_hex.trim()).map_err(|error| Error::ProofDecoding(error.to_string()))?;
+        let proof = Proof::<Bn256>::read(proof_bytes.as_slice())
+            .map_err(|error| Error::ProofDecoding(error.to_string()))?;
+
+        // Read the public data
+        let output_path = self.output_path;
+        let output_text =
+            fs::read_to_string(&output_path).error_with_path(|| output_path.to_string_lossy())?;
+        let output_json: serde_json::Value = serde_json::from_str(output_text.as_str())?;
+
+        let is_verified = match application {
+            zinc_types::Application::Circuit(circuit) => {
+                let output = zinc_types::Value::try_from_typed_json(output_json, circuit.output)?;
+
+                Facade::verify::<Bn256>(verifying_key, proof, output)?
+            }
+            zinc_types::Application::Contract(contract) => {
+                let method_name = self.method.ok_or(Error::MethodNameNotFound)?;
+                let method = contract
+                    .methods
+                    .get(method_name.as_str())
+                    .cloned()
+                    .ok_or(Error::MethodNotFound { name: method_name })?;
+
+                let output_type =
+                    if method.name.as_str() == zinc_const::contract::CONSTRUCTOR_IDENTIFIER {
+                        zinc_types::Type::eth_address()
+                    } else {
+                        method.output
+                    }
+                    .into_contract_proof_output();
+                let output = zinc_types::Value::try_from_typed_json(output_json, output_type)?;
+
+                Facade::verify_contract::<Bn256>(verifying_key, proof, output)?
+            }
+            zinc_types::Application::Library(_library) => return Err(Error::CannotRunLibrary),
+        };
+
+        if !is_verified {
+            return Err(Error::VerificationFailed);
+        }
+
+        println!("{}", "✔  Verified".bold().green());
+
+        Ok(zinc_const::exit_code::SUCCESS as i32)
+    }
+}
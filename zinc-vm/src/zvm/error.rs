@@ -26,6 +26,14 @@ pub enum Error {
     #[error("failed to verify")]
     Verification(#[from] zinc_vm::VerificationError),
 
+    /// The proof is invalid for the specified verifying key and public data.
+    #[error("verification failed: the proof is invalid")]
+    VerificationFailed,
+
+    /// The proof hexadecimal string decoding error.
+    #[error("failed to decode the proof: {0}")]
+    ProofDecoding(String),
+
     /// The JSON template file decoding error.
     #[error("failed to parse json: {0}")]
     JsonDecoding(#[from] serde_json::Error),
@@ -40,6 +48,10 @@ pub enum Error {
     #[error("failed to decode an application: {0}")]
     ApplicationDecoding(String),
 
+    /// The bytecode assembly text parsing error.
+    #[error("failed to parse the assembly: {0}")]
+    AssemblyParsing(#[from] zinc_types::AssemblyError),
+
     /// The input data is invalid.
     #[error("the input data is invalid: expected `{expected}`, found `{found}`")]
     InputDataInvalid {